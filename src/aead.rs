@@ -4,12 +4,898 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use sr_std::convert::TryFrom;
+use sr_std::fmt;
+use sr_std::iter::repeat;
+use sr_std::prelude::*;
+
+use cryptoutil::write_u64_le;
+use digest::Digest;
+use hkdf::{hkdf_extract, HkdfExpander};
+use hmac::Hmac;
+use mac::Mac;
+use sha2::Sha256;
+use symmetriccipher::SynchronousStreamCipher;
+use util::{fixed_time_eq, secure_memset, SecretBytes};
+
 pub trait AeadEncryptor {
 
-	fn encrypt(&mut self, input: &[u8], output: &mut [u8], tag: &mut [u8]);
+    fn encrypt(&mut self, input: &[u8], output: &mut [u8], tag: &mut [u8]);
+
+    /**
+     * The length in bytes of the tag written by encrypt().
+     */
+    fn tag_len(&self) -> usize {
+        16
+    }
+
+    /**
+     * Like encrypt(), but fails with InvalidTagLength instead of panicking when the tag buffer is
+     * shorter than tag_len(). Only the first tag_len() bytes of a longer buffer are written.
+     */
+    fn seal(&mut self, input: &[u8], output: &mut [u8], tag: &mut [u8]) -> Result<(), AeadError> {
+        let tag_len = self.tag_len();
+        if tag.len() < tag_len {
+            return Err(AeadError::InvalidTagLength);
+        }
+        self.encrypt(input, output, &mut tag[..tag_len]);
+        Ok(())
+    }
 }
 
 pub trait AeadDecryptor {
 
-	fn decrypt(&mut self, input: &[u8], output: &mut [u8], tag: &[u8]) -> bool;
-}
\ No newline at end of file
+    fn decrypt(&mut self, input: &[u8], output: &mut [u8], tag: &[u8]) -> bool;
+
+    /**
+     * The length in bytes of the tag expected by decrypt().
+     */
+    fn tag_len(&self) -> usize {
+        16
+    }
+
+    /**
+     * Like decrypt(), but reports a tag that is not exactly tag_len() bytes long as
+     * InvalidTagLength and a tag mismatch as AuthenticationFailed.
+     */
+    fn open(&mut self, input: &[u8], output: &mut [u8], tag: &[u8]) -> Result<(), AeadError> {
+        if tag.len() != self.tag_len() {
+            return Err(AeadError::InvalidTagLength);
+        }
+        if self.decrypt(input, output, tag) {
+            Ok(())
+        } else {
+            Err(AeadError::AuthenticationFailed)
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AeadError {
+    AuthenticationFailed,
+    NonceExhausted,
+    InvalidKeyLength,
+    InvalidNonceLength,
+    InvalidTagLength,
+    /// The output buffer is shorter than needed, the number of bytes required.
+    BufferTooSmall { needed: usize },
+}
+
+impl fmt::Display for AeadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AeadError::AuthenticationFailed => f.write_str("AEAD authentication failed"),
+            AeadError::NonceExhausted => f.write_str("AEAD nonce sequence exhausted"),
+            AeadError::InvalidKeyLength => f.write_str("invalid AEAD key length"),
+            AeadError::InvalidNonceLength => f.write_str("invalid AEAD nonce length"),
+            AeadError::InvalidTagLength => f.write_str("invalid AEAD tag length"),
+            AeadError::BufferTooSmall { needed } => {
+                write!(f, "AEAD output buffer too small, {} bytes needed", needed)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for AeadError {}
+
+/**
+ * A 128, 192 or 256 bit AEAD key, held in a SecretBytes so that it is wiped from memory when
+ * dropped. The AEAD constructors that take an AeadKey only borrow it while deriving their key
+ * schedules, so the raw key lives no longer than the AeadKey itself. It is deliberately not Clone
+ * or Copy, and its Debug output does not reveal the key.
+ */
+pub struct AeadKey {
+    secret: SecretBytes<32>,
+}
+
+impl AeadKey {
+    pub fn as_bytes(&self) -> &[u8] {
+        self.secret.as_bytes()
+    }
+
+    pub fn len(&self) -> usize {
+        self.secret.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.secret.is_empty()
+    }
+}
+
+impl From<[u8; 32]> for AeadKey {
+    fn from(mut key: [u8; 32]) -> AeadKey {
+        let result = AeadKey {
+            secret: SecretBytes::new(&key),
+        };
+        secure_memset(&mut key, 0);
+        result
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for AeadKey {
+    type Error = AeadError;
+
+    /**
+     * Copy a key of 16, 24 or 32 bytes; any other length is an InvalidKeyLength error.
+     */
+    fn try_from(key: &'a [u8]) -> Result<AeadKey, AeadError> {
+        if key.len() != 16 && key.len() != 24 && key.len() != 32 {
+            return Err(AeadError::InvalidKeyLength);
+        }
+        Ok(AeadKey {
+            secret: SecretBytes::new(key),
+        })
+    }
+}
+
+impl fmt::Debug for AeadKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AeadKey(<{} bytes redacted>)", self.len())
+    }
+}
+
+/**
+ * Wraps an AeadDecryptor so that ciphertext may be supplied in chunks. The ciphertext is buffered
+ * internally and no plaintext is released until the tag has been verified in finalize().
+ */
+pub struct BufferedAeadDecryptor<A: AeadDecryptor> {
+    inner: A,
+    buffer: Vec<u8>,
+}
+
+impl<A: AeadDecryptor> BufferedAeadDecryptor<A> {
+    pub fn new(inner: A) -> BufferedAeadDecryptor<A> {
+        BufferedAeadDecryptor {
+            inner: inner,
+            buffer: Vec::new(),
+        }
+    }
+
+    /**
+     * Append a chunk of ciphertext. Nothing is decrypted until finalize() is called.
+     */
+    pub fn update(&mut self, input: &[u8]) {
+        self.buffer.extend_from_slice(input);
+    }
+
+    /**
+     * Verify the tag over all of the buffered ciphertext and, only if it matches, return the
+     * plaintext. On failure all internal buffers are zeroed before the error is returned.
+     */
+    pub fn finalize(mut self, tag: &[u8]) -> Result<Vec<u8>, AeadError> {
+        let mut output: Vec<u8> = repeat(0).take(self.buffer.len()).collect();
+        let verified = self.inner.decrypt(&self.buffer[..], &mut output[..], tag);
+        secure_memset(&mut self.buffer[..], 0);
+        if verified {
+            Ok(output)
+        } else {
+            secure_memset(&mut output[..], 0);
+            Err(AeadError::AuthenticationFailed)
+        }
+    }
+}
+
+// Compute the tag for encrypt_then_mac and mac_then_decrypt. The lengths are appended as 64 bit
+// little-endian integers, as in ChaCha20Poly1305, so that moving bytes between the aad and the
+// ciphertext changes the tag.
+fn mac_aad_ciphertext<M: Mac>(mac: &mut M, aad: &[u8], ciphertext: &[u8], tag: &mut [u8]) {
+    let mut lengths = [0u8; 16];
+    write_u64_le(&mut lengths[..8], aad.len() as u64);
+    write_u64_le(&mut lengths[8..], ciphertext.len() as u64);
+    mac.reset();
+    mac.input(aad);
+    mac.input(ciphertext);
+    mac.input(&lengths);
+    mac.raw_result(tag);
+    mac.reset();
+}
+
+/**
+ * Encrypt plaintext with a stream cipher and authenticate the aad and ciphertext with a Mac, for
+ * building authenticated modes out of the crate's primitives. Returns the ciphertext and a tag of
+ * mac.output_bytes() bytes. The Mac must be keyed independently of the cipher, and the cipher must
+ * never be reused with the same key and nonce.
+ */
+pub fn encrypt_then_mac<M, S>(
+    mac: &mut M,
+    cipher: &mut S,
+    aad: &[u8],
+    plaintext: &[u8],
+) -> (Vec<u8>, Vec<u8>)
+where
+    M: Mac,
+    S: SynchronousStreamCipher + ?Sized,
+{
+    let mut ciphertext: Vec<u8> = repeat(0).take(plaintext.len()).collect();
+    cipher.process(plaintext, &mut ciphertext[..]);
+    let mut tag: Vec<u8> = repeat(0).take(mac.output_bytes()).collect();
+    mac_aad_ciphertext(mac, aad, &ciphertext[..], &mut tag[..]);
+    (ciphertext, tag)
+}
+
+/**
+ * Verify a tag produced by encrypt_then_mac and, only if it matches, decrypt the ciphertext. The
+ * tag is compared in constant time and the cipher is not run at all on failure.
+ */
+pub fn mac_then_decrypt<M, S>(
+    mac: &mut M,
+    cipher: &mut S,
+    aad: &[u8],
+    ciphertext: &[u8],
+    tag: &[u8],
+) -> Result<Vec<u8>, AeadError>
+where
+    M: Mac,
+    S: SynchronousStreamCipher + ?Sized,
+{
+    let mut calc_tag: Vec<u8> = repeat(0).take(mac.output_bytes()).collect();
+    mac_aad_ciphertext(mac, aad, ciphertext, &mut calc_tag[..]);
+    if !fixed_time_eq(&calc_tag[..], tag) {
+        return Err(AeadError::AuthenticationFailed);
+    }
+    let mut plaintext: Vec<u8> = repeat(0).take(ciphertext.len()).collect();
+    cipher.process(ciphertext, &mut plaintext[..]);
+    Ok(plaintext)
+}
+
+/**
+ * A generic AEAD built from a stream cipher and a one-time universal hash such as Poly1305, in
+ * the layout used by ChaCha20Poly1305: the hash covers aad, le64(aad length), ciphertext and
+ * le64(ciphertext length), and its output is the tag.
+ *
+ * Key and nonce handling is left entirely to the caller. The cipher must already be keyed with a
+ * fresh nonce and positioned where encryption should start, and the hash must be keyed with a key
+ * that is used for this one message only, typically keystream the cipher has already skipped
+ * past. Reusing either across messages breaks both confidentiality and authenticity. Like the
+ * other AEADs in this crate, an instance seals or opens exactly one message.
+ */
+pub struct StreamAead<C: SynchronousStreamCipher, H: Mac> {
+    cipher: C,
+    mac: H,
+    finished: bool,
+}
+
+impl<C: SynchronousStreamCipher, H: Mac> StreamAead<C, H> {
+    pub fn new(cipher: C, mut mac: H, aad: &[u8]) -> StreamAead<C, H> {
+        let mut aad_len = [0u8; 8];
+        write_u64_le(&mut aad_len, aad.len() as u64);
+        mac.input(aad);
+        mac.input(&aad_len);
+        StreamAead {
+            cipher: cipher,
+            mac: mac,
+            finished: false,
+        }
+    }
+
+    fn mac_ciphertext(&mut self, ciphertext: &[u8]) {
+        assert!(!self.finished);
+        self.finished = true;
+        let mut data_len = [0u8; 8];
+        write_u64_le(&mut data_len, ciphertext.len() as u64);
+        self.mac.input(ciphertext);
+        self.mac.input(&data_len);
+    }
+}
+
+impl<C: SynchronousStreamCipher, H: Mac> AeadEncryptor for StreamAead<C, H> {
+    fn encrypt(&mut self, input: &[u8], output: &mut [u8], tag: &mut [u8]) {
+        self.cipher.process(input, output);
+        self.mac_ciphertext(output);
+        self.mac.raw_result(tag);
+    }
+
+    fn tag_len(&self) -> usize {
+        self.mac.output_bytes()
+    }
+}
+
+impl<C: SynchronousStreamCipher, H: Mac> AeadDecryptor for StreamAead<C, H> {
+    fn decrypt(&mut self, input: &[u8], output: &mut [u8], tag: &[u8]) -> bool {
+        self.mac_ciphertext(input);
+        let mut calc_tag: Vec<u8> = repeat(0).take(self.mac.output_bytes()).collect();
+        self.mac.raw_result(&mut calc_tag[..]);
+        if fixed_time_eq(&calc_tag[..], tag) {
+            self.cipher.process(input, output);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn tag_len(&self) -> usize {
+        self.mac.output_bytes()
+    }
+}
+
+/**
+ * Derive a deterministic nonce of nonce_len bytes (at most 32) by computing HMAC-SHA256 over the
+ * context under the given key and truncating the result.
+ *
+ * This is not SIV: the nonce only changes when the context changes, so two messages encrypted
+ * under the same key and context will reuse a nonce. It is intended for cases where a true SIV
+ * mode is unavailable and the context (for example a message counter and the message itself)
+ * can be made unique per encryption.
+ */
+pub fn derive_nonce_hmac(key: &[u8], context: &[u8], nonce_len: usize) -> Vec<u8> {
+    assert!(nonce_len <= 32);
+    let mut hmac = Hmac::new(Sha256::new(), key);
+    hmac.input(context);
+    let mut code = [0u8; 32];
+    hmac.raw_result(&mut code);
+    let nonce = code[..nonce_len].to_vec();
+    secure_memset(&mut code, 0);
+    nonce
+}
+
+/**
+ * Derive an AEAD key of key_len bytes and a nonce (or nonce prefix) of nonce_len bytes from one
+ * master secret. The master secret is run through HKDF-Extract with an empty salt, and the key and
+ * nonce are then expanded separately with the info strings "key" || info and "nonce" || info, so
+ * neither reveals anything about the other.
+ */
+pub fn derive_key_nonce<D: Digest + Clone>(
+    digest: D,
+    master: &[u8],
+    info: &[u8],
+    key_len: usize,
+    nonce_len: usize,
+) -> (Vec<u8>, Vec<u8>) {
+    let mut prk: Vec<u8> = repeat(0).take(digest.output_bytes()).collect();
+    hkdf_extract(digest.clone(), &[], master, &mut prk[..]);
+    let mut expander = HkdfExpander::new(digest, &prk[..]);
+    secure_memset(&mut prk[..], 0);
+
+    let mut label: Vec<u8> = Vec::with_capacity(5 + info.len());
+    let mut key: Vec<u8> = repeat(0).take(key_len).collect();
+    label.extend_from_slice(b"key");
+    label.extend_from_slice(info);
+    expander.expand(&label[..], &mut key[..]);
+
+    let mut nonce: Vec<u8> = repeat(0).take(nonce_len).collect();
+    label.clear();
+    label.extend_from_slice(b"nonce");
+    label.extend_from_slice(info);
+    expander.expand(&label[..], &mut nonce[..]);
+
+    (key, nonce)
+}
+
+/**
+ * Produces nonces made of a fixed prefix followed by a big-endian counter, as used by STREAM and
+ * by file formats that encrypt a sequence of records under one key. The prefix is typically
+ * random and chosen once per stream; the counter starts at zero and increments per record.
+ */
+pub struct PrefixedCounterNonce {
+    prefix: Vec<u8>,
+    counter: u64,
+    counter_len: usize,
+    exhausted: bool,
+}
+
+impl PrefixedCounterNonce {
+    /**
+     * Create a new nonce sequence. counter_len is the width of the counter in bytes and must be
+     * between 1 and 8.
+     */
+    pub fn new(prefix: &[u8], counter_len: usize) -> PrefixedCounterNonce {
+        assert!(counter_len >= 1 && counter_len <= 8);
+        PrefixedCounterNonce {
+            prefix: prefix.to_vec(),
+            counter: 0,
+            counter_len: counter_len,
+            exhausted: false,
+        }
+    }
+
+    pub fn nonce_len(&self) -> usize {
+        self.prefix.len() + self.counter_len
+    }
+
+    /**
+     * Return the next nonce, or NonceExhausted once every counter value has been used.
+     */
+    pub fn next_nonce(&mut self) -> Result<Vec<u8>, AeadError> {
+        if self.exhausted {
+            return Err(AeadError::NonceExhausted);
+        }
+        let mut nonce = Vec::with_capacity(self.nonce_len());
+        nonce.extend_from_slice(&self.prefix[..]);
+        for i in (0..self.counter_len).rev() {
+            nonce.push((self.counter >> (8 * i)) as u8);
+        }
+
+        let max = if self.counter_len == 8 {
+            u64::max_value()
+        } else {
+            (1u64 << (8 * self.counter_len)) - 1
+        };
+        if self.counter == max {
+            self.exhausted = true;
+        } else {
+            self.counter += 1;
+        }
+        Ok(nonce)
+    }
+
+    /**
+     * Encrypt one record with an AEAD constructed by new_cipher from the next nonce.
+     */
+    pub fn seal_record<A, F>(
+        &mut self,
+        new_cipher: F,
+        input: &[u8],
+        output: &mut [u8],
+        tag: &mut [u8],
+    ) -> Result<(), AeadError>
+    where
+        A: AeadEncryptor,
+        F: FnOnce(&[u8]) -> A,
+    {
+        let nonce = self.next_nonce()?;
+        new_cipher(&nonce[..]).encrypt(input, output, tag);
+        Ok(())
+    }
+
+    /**
+     * Decrypt one record with an AEAD constructed by new_cipher from the next nonce. Records must
+     * be opened in the order they were sealed.
+     */
+    pub fn open_record<A, F>(
+        &mut self,
+        new_cipher: F,
+        input: &[u8],
+        output: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), AeadError>
+    where
+        A: AeadDecryptor,
+        F: FnOnce(&[u8]) -> A,
+    {
+        let nonce = self.next_nonce()?;
+        if new_cipher(&nonce[..]).decrypt(input, output, tag) {
+            Ok(())
+        } else {
+            Err(AeadError::AuthenticationFailed)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use sr_std::convert::TryFrom;
+    use sr_std::iter::repeat;
+    use sr_std::mem::ManuallyDrop;
+    use sr_std::prelude::*;
+    use sr_std::ptr;
+
+    use aead::{
+        derive_key_nonce, derive_nonce_hmac, encrypt_then_mac, mac_then_decrypt, AeadDecryptor,
+        AeadEncryptor, AeadError, AeadKey, BufferedAeadDecryptor, PrefixedCounterNonce, StreamAead,
+    };
+    use aes::{ctr, KeySize};
+    use aes_gcm::AesGcm;
+    use chacha20::ChaCha20;
+    use chacha20poly1305::{ChaCha20Poly1305, ChaCha20Poly1305Legacy};
+    use cryptoutil::test::{check_display, debug};
+    use hc128::Hc128;
+    use hmac::Hmac;
+    use mac::Mac;
+    use poly1305::Poly1305;
+    use sha2::Sha256;
+    use sosemanuk::Sosemanuk;
+
+    fn seal(key: &[u8], nonce: &[u8], aad: &[u8], plaintext: &[u8]) -> (Vec<u8>, [u8; 16]) {
+        let mut ciphertext: Vec<u8> = repeat(0).take(plaintext.len()).collect();
+        let mut tag = [0u8; 16];
+        let mut c = ChaCha20Poly1305::new(key, nonce, aad);
+        c.encrypt(plaintext, &mut ciphertext[..], &mut tag);
+        (ciphertext, tag)
+    }
+
+    #[test]
+    fn test_buffered_decryptor_chunks() {
+        let key = [7u8; 32];
+        let nonce = [3u8; 8];
+        let aad = b"header";
+        let plaintext: Vec<u8> = (0..300).map(|i| i as u8).collect();
+        let (ciphertext, tag) = seal(&key, &nonce, aad, &plaintext[..]);
+
+        let mut d = BufferedAeadDecryptor::new(ChaCha20Poly1305::new(&key, &nonce, aad));
+        for chunk in ciphertext.chunks(37) {
+            d.update(chunk);
+        }
+        assert_eq!(d.finalize(&tag).unwrap(), plaintext);
+    }
+
+    #[test]
+    fn test_buffered_decryptor_bad_tag() {
+        let key = [7u8; 32];
+        let nonce = [3u8; 8];
+        let aad = b"header";
+        let plaintext: Vec<u8> = (0..300).map(|i| i as u8).collect();
+        let (ciphertext, mut tag) = seal(&key, &nonce, aad, &plaintext[..]);
+        tag[0] ^= 1;
+
+        let mut d = BufferedAeadDecryptor::new(ChaCha20Poly1305::new(&key, &nonce, aad));
+        for chunk in ciphertext.chunks(37) {
+            d.update(chunk);
+        }
+        assert_eq!(d.finalize(&tag), Err(AeadError::AuthenticationFailed));
+    }
+
+    #[test]
+    fn test_derive_nonce_hmac() {
+        let key = [0x0bu8; 32];
+        let a = derive_nonce_hmac(&key, b"record 1", 12);
+        let b = derive_nonce_hmac(&key, b"record 1", 12);
+        let c = derive_nonce_hmac(&key, b"record 2", 12);
+        assert_eq!(a.len(), 12);
+        assert_eq!(a, b);
+        assert!(a != c);
+
+        // The nonce is a prefix of HMAC-SHA256(key, context).
+        let long = derive_nonce_hmac(&key, b"record 1", 32);
+        assert_eq!(&long[..12], &a[..]);
+    }
+
+    #[test]
+    fn test_derive_key_nonce() {
+        // Computed with Python's cryptography HKDF, using info "key" || info and "nonce" || info.
+        let master = [0x0bu8; 22];
+        let (key, nonce) = derive_key_nonce(Sha256::new(), &master, b"example protocol v1", 32, 12);
+        assert_eq!(
+            key,
+            vec![
+                0xe4, 0x85, 0x18, 0xc0, 0x2b, 0xf2, 0x3b, 0x6c, 0x8f, 0xd0, 0xd4, 0xbf, 0xb4, 0x6a,
+                0x01, 0x0b, 0xd5, 0xd2, 0x0a, 0x04, 0x8d, 0x5a, 0xc0, 0xc6, 0x64, 0xc9, 0x37, 0xf9,
+                0xf8, 0x81, 0x85, 0xd0,
+            ]
+        );
+        assert_eq!(
+            nonce,
+            vec![0xa4, 0x83, 0xe9, 0x0b, 0x23, 0xd9, 0x2d, 0xb1, 0x0b, 0x67, 0xe4, 0x73]
+        );
+
+        let (other_key, _) = derive_key_nonce(Sha256::new(), &master, b"other protocol", 32, 12);
+        assert!(key != other_key);
+    }
+
+    #[test]
+    fn test_prefixed_counter_nonce_sequence() {
+        let mut n = PrefixedCounterNonce::new(&[0xaa, 0xbb], 1);
+        assert_eq!(n.nonce_len(), 3);
+        let mut seen = Vec::new();
+        for i in 0..256 {
+            let nonce = n.next_nonce().unwrap();
+            assert_eq!(nonce, vec![0xaa, 0xbb, i as u8]);
+            assert!(!seen.contains(&nonce));
+            seen.push(nonce);
+        }
+        assert_eq!(n.next_nonce(), Err(AeadError::NonceExhausted));
+        assert_eq!(n.next_nonce(), Err(AeadError::NonceExhausted));
+    }
+
+    #[test]
+    fn test_prefixed_counter_nonce_layout() {
+        let mut n = PrefixedCounterNonce::new(&[1, 2, 3, 4, 5, 6, 7, 8], 4);
+        n.next_nonce().unwrap();
+        assert_eq!(n.next_nonce().unwrap(), vec![1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_prefixed_counter_nonce_records() {
+        let key = [9u8; 32];
+        let prefix = [0x5au8; 8];
+        let records: [&[u8]; 3] = [b"first", b"second", b"first"];
+
+        let mut sealer = PrefixedCounterNonce::new(&prefix, 4);
+        let mut sealed = Vec::new();
+        for record in records.iter() {
+            let mut ciphertext: Vec<u8> = repeat(0).take(record.len()).collect();
+            let mut tag = [0u8; 16];
+            sealer
+                .seal_record(
+                    |nonce| ChaCha20Poly1305::new(&key, nonce, b""),
+                    record,
+                    &mut ciphertext[..],
+                    &mut tag,
+                )
+                .unwrap();
+            sealed.push((ciphertext, tag));
+        }
+        // Identical records get distinct nonces and so distinct ciphertexts.
+        assert!(sealed[0] != sealed[2]);
+
+        let mut opener = PrefixedCounterNonce::new(&prefix, 4);
+        for (record, &(ref ciphertext, ref tag)) in records.iter().zip(sealed.iter()) {
+            let mut plaintext: Vec<u8> = repeat(0).take(ciphertext.len()).collect();
+            opener
+                .open_record(
+                    |nonce| ChaCha20Poly1305::new(&key, nonce, b""),
+                    &ciphertext[..],
+                    &mut plaintext[..],
+                    tag,
+                )
+                .unwrap();
+            assert_eq!(&plaintext[..], *record);
+        }
+    }
+
+    #[test]
+    fn test_mac_then_decrypt_hmac_aes_ctr() {
+        let enc_key = [0x11u8; 16];
+        let mac_key = [0x22u8; 32];
+        let iv = [0x33u8; 16];
+        let aad = b"record header";
+        let plaintext = b"composed from HMAC-SHA256 and AES-128-CTR";
+
+        let mut mac = Hmac::new(Sha256::new(), &mac_key);
+        let (ciphertext, tag) = encrypt_then_mac(
+            &mut mac,
+            &mut *ctr(KeySize::KeySize128, &enc_key, &iv),
+            aad,
+            plaintext,
+        );
+        assert_eq!(tag.len(), 32);
+
+        // The tag covers aad || ciphertext || le64(aad len) || le64(ciphertext len).
+        let mut expected = Hmac::new(Sha256::new(), &mac_key);
+        expected.input(aad);
+        expected.input(&ciphertext[..]);
+        expected.input(&[13, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(expected.result().code(), &tag[..]);
+
+        let open = |mac: &mut Hmac<Sha256>, aad: &[u8], ciphertext: &[u8], tag: &[u8]| {
+            let mut cipher = ctr(KeySize::KeySize128, &enc_key, &iv);
+            mac_then_decrypt(mac, &mut *cipher, aad, ciphertext, tag)
+        };
+        assert_eq!(
+            open(&mut mac, aad, &ciphertext[..], &tag[..]).unwrap(),
+            plaintext.to_vec()
+        );
+
+        let mut bad = ciphertext.clone();
+        bad[5] ^= 1;
+        assert_eq!(open(&mut mac, aad, &bad[..], &tag[..]), Err(AeadError::AuthenticationFailed));
+        assert_eq!(
+            open(&mut mac, b"record headeR", &ciphertext[..], &tag[..]),
+            Err(AeadError::AuthenticationFailed)
+        );
+        let mut bad_tag = tag.clone();
+        bad_tag[31] ^= 0x80;
+        assert_eq!(
+            open(&mut mac, aad, &ciphertext[..], &bad_tag[..]),
+            Err(AeadError::AuthenticationFailed)
+        );
+        assert_eq!(
+            open(&mut mac, aad, &ciphertext[..], &tag[..16]),
+            Err(AeadError::AuthenticationFailed)
+        );
+
+        // Shifting a byte from the aad into the ciphertext is detected.
+        let mut shifted = vec![aad[12]];
+        shifted.extend_from_slice(&ciphertext[..]);
+        assert_eq!(
+            open(&mut mac, &aad[..12], &shifted[..], &tag[..]),
+            Err(AeadError::AuthenticationFailed)
+        );
+    }
+
+    #[test]
+    fn test_error_display() {
+        let cases = [
+            (AeadError::AuthenticationFailed, "AEAD authentication failed"),
+            (AeadError::NonceExhausted, "AEAD nonce sequence exhausted"),
+            (AeadError::InvalidKeyLength, "invalid AEAD key length"),
+            (AeadError::InvalidNonceLength, "invalid AEAD nonce length"),
+            (AeadError::InvalidTagLength, "invalid AEAD tag length"),
+            (
+                AeadError::BufferTooSmall { needed: 20 },
+                "AEAD output buffer too small, 20 bytes needed",
+            ),
+        ];
+        for &(err, msg) in cases.iter() {
+            check_display(&err, msg);
+        }
+    }
+
+    #[test]
+    fn test_aead_key_zeroizes_on_drop() {
+        let mut key = ManuallyDrop::new(AeadKey::from([0x5a; 32]));
+        assert_eq!(key.as_bytes(), &[0x5a; 32][..]);
+        // Run the destructor but keep the storage around so it can be inspected.
+        unsafe { ptr::drop_in_place(&mut *key) };
+        assert_eq!(key.secret.as_bytes(), &[0; 32][..]);
+
+        let mut key = ManuallyDrop::new(AeadKey::try_from(&[0xa5; 16][..]).unwrap());
+        assert_eq!(key.len(), 16);
+        assert!(!key.is_empty());
+        unsafe { ptr::drop_in_place(&mut *key) };
+        assert_eq!(key.secret.as_bytes(), &[0; 16][..]);
+
+        let key = AeadKey::from([1; 32]);
+        assert_eq!(debug(&key).as_str(), "AeadKey(<32 bytes redacted>)");
+    }
+
+    #[test]
+    fn test_aead_key_constructors() {
+        for &len in [15usize, 17, 31, 33, 64].iter() {
+            let key: Vec<u8> = repeat(1).take(len).collect();
+            assert_eq!(AeadKey::try_from(&key[..]).err(), Some(AeadError::InvalidKeyLength));
+        }
+
+        let plaintext = b"sealed with an AeadKey";
+        let mut ciphertext = [0u8; 22];
+        let mut decrypted = [0u8; 22];
+        let mut tag = [0u8; 16];
+        let mut expected = [0u8; 22];
+        let mut expected_tag = [0u8; 16];
+
+        for &len in [16usize, 24, 32].iter() {
+            let raw: Vec<u8> = (0..len as u8).collect();
+            let key = AeadKey::try_from(&raw[..]).unwrap();
+            let key_size = match len {
+                16 => KeySize::KeySize128,
+                24 => KeySize::KeySize192,
+                _ => KeySize::KeySize256,
+            };
+            AesGcm::with_key(&key, &[3; 12], b"aad").unwrap().encrypt(
+                plaintext,
+                &mut ciphertext,
+                &mut tag,
+            );
+            AesGcm::new(key_size, &raw, &[3; 12], b"aad").encrypt(
+                plaintext,
+                &mut expected,
+                &mut expected_tag,
+            );
+            assert_eq!((ciphertext, tag), (expected, expected_tag));
+            let mut c = AesGcm::with_key(&key, &[3; 12], b"aad").unwrap();
+            assert!(c.decrypt(&ciphertext, &mut decrypted, &tag));
+            assert_eq!(&decrypted, plaintext);
+            assert!(AesGcm::with_key(&key, &[3; 8], b"aad").is_err());
+        }
+
+        let key = AeadKey::from([9; 32]);
+        ChaCha20Poly1305::with_key(&key, &[4; 12], b"aad").unwrap().encrypt(
+            plaintext,
+            &mut ciphertext,
+            &mut tag,
+        );
+        ChaCha20Poly1305::new(&[9; 32], &[4; 12], b"aad").encrypt(
+            plaintext,
+            &mut expected,
+            &mut expected_tag,
+        );
+        assert_eq!((ciphertext, tag), (expected, expected_tag));
+        let mut c = ChaCha20Poly1305Legacy::with_key(&key, &[4; 8], b"aad").unwrap();
+        c.encrypt(plaintext, &mut ciphertext, &mut tag);
+        let mut c = ChaCha20Poly1305::with_key(&key, &[4; 8], b"aad").unwrap();
+        assert!(c.decrypt(&ciphertext, &mut decrypted, &tag));
+        assert_eq!(&decrypted, plaintext);
+
+        let short = AeadKey::try_from(&[9; 24][..]).unwrap();
+        assert_eq!(
+            ChaCha20Poly1305::with_key(&short, &[4; 12], b"").err(),
+            Some(AeadError::InvalidKeyLength)
+        );
+        assert_eq!(
+            ChaCha20Poly1305Legacy::with_key(&short, &[4; 8], b"").err(),
+            Some(AeadError::InvalidKeyLength)
+        );
+    }
+
+    // ChaCha20Poly1305 rebuilt from its parts: the Poly1305 key is the first half of keystream
+    // block 0, and encryption starts at block 1.
+    fn chacha20_poly1305(key: &[u8], nonce: &[u8], aad: &[u8]) -> StreamAead<ChaCha20, Poly1305> {
+        let mut cipher = ChaCha20::new(key, nonce);
+        let mut block0 = [0u8; 64];
+        cipher.keystream(&mut block0);
+        StreamAead::new(cipher, Poly1305::new(&block0[..32]), aad)
+    }
+
+    #[test]
+    fn test_stream_aead_matches_chacha20poly1305() {
+        let key = [7u8; 32];
+        let plaintext: Vec<u8> = (0..200u8).collect();
+        for &nonce_len in [8usize, 12].iter() {
+            let nonce: Vec<u8> = (0..nonce_len as u8).collect();
+            for &len in [0usize, 1, 63, 64, 65, 200].iter() {
+                let aad = &plaintext[..len / 3];
+                let (expected, expected_tag) = seal(&key, &nonce, aad, &plaintext[..len]);
+
+                let mut ciphertext: Vec<u8> = repeat(0).take(len).collect();
+                let mut tag = [0u8; 16];
+                chacha20_poly1305(&key, &nonce, aad).encrypt(
+                    &plaintext[..len],
+                    &mut ciphertext[..],
+                    &mut tag,
+                );
+                assert_eq!(ciphertext, expected);
+                assert_eq!(tag, expected_tag);
+
+                let mut decrypted: Vec<u8> = repeat(0).take(len).collect();
+                let mut c = chacha20_poly1305(&key, &nonce, aad);
+                assert!(c.decrypt(&ciphertext[..], &mut decrypted[..], &tag));
+                assert_eq!(&decrypted[..], &plaintext[..len]);
+
+                tag[0] ^= 1;
+                let mut c = chacha20_poly1305(&key, &nonce, aad);
+                assert!(!c.decrypt(&ciphertext[..], &mut decrypted[..], &tag));
+            }
+        }
+    }
+
+    #[test]
+    fn test_encrypt_then_hmac_estream_ciphers() {
+        let enc_key = [0x44u8; 16];
+        let mac_key = [0x55u8; 32];
+        let iv = [0x66u8; 16];
+        let aad = b"header";
+        let plaintext = b"sealed with Sosemanuk and HMAC-SHA256";
+
+        let mut mac = Hmac::new(Sha256::new(), &mac_key);
+        let (ciphertext, tag) =
+            encrypt_then_mac(&mut mac, &mut Sosemanuk::new(&enc_key, &iv), aad, plaintext);
+        assert!(&ciphertext[..] != &plaintext[..]);
+
+        let open = |mac: &mut Hmac<Sha256>, ciphertext: &[u8], tag: &[u8]| {
+            mac_then_decrypt(mac, &mut Sosemanuk::new(&enc_key, &iv), aad, ciphertext, tag)
+        };
+        assert_eq!(open(&mut mac, &ciphertext[..], &tag[..]).unwrap(), plaintext.to_vec());
+        let mut bad_tag = tag.clone();
+        bad_tag[0] ^= 1;
+        assert_eq!(
+            open(&mut mac, &ciphertext[..], &bad_tag[..]),
+            Err(AeadError::AuthenticationFailed)
+        );
+
+        // HC-128 works the same way through StreamAead.
+        let mut ciphertext: Vec<u8> = repeat(0).take(plaintext.len()).collect();
+        let mut tag = [0u8; 32];
+        let mut c = StreamAead::new(
+            Hc128::new(&enc_key, &iv),
+            Hmac::new(Sha256::new(), &mac_key),
+            aad,
+        );
+        assert_eq!(c.seal(plaintext, &mut ciphertext[..], &mut tag), Ok(()));
+
+        let mut decrypted: Vec<u8> = repeat(0).take(plaintext.len()).collect();
+        let new_opener = || {
+            StreamAead::new(
+                Hc128::new(&enc_key, &iv),
+                Hmac::new(Sha256::new(), &mac_key),
+                aad,
+            )
+        };
+        assert_eq!(new_opener().open(&ciphertext[..], &mut decrypted[..], &tag), Ok(()));
+        assert_eq!(&decrypted[..], &plaintext[..]);
+        tag[31] ^= 1;
+        assert_eq!(
+            new_opener().open(&ciphertext[..], &mut decrypted[..], &tag),
+            Err(AeadError::AuthenticationFailed)
+        );
+    }
+}
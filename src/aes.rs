@@ -4,806 +4,1035 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-//#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-//use aesni;
-//
-//use aessafe;
-//use blockmodes::{
-//    CbcDecryptor, CbcEncryptor, CtrMode, CtrModeX8, EcbDecryptor, EcbEncryptor, PaddingProcessor,
-//};
-//use sr_std::marker::*;
-//use sr_std::prelude::*;
-//use symmetriccipher::{Decryptor, Encryptor, SynchronousStreamCipher};
-//use util;
-//
-///// AES key size
-//#[derive(Clone, Copy)]
-//pub enum KeySize {
-//    KeySize128,
-//    KeySize192,
-//    KeySize256,
-//}
-//
-///// Get the best implementation of an EcbEncryptor
-//#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-//pub fn ecb_encryptor<X: PaddingProcessor + Send + 'static>(
-//    key_size: KeySize,
-//    key: &[u8],
-//    padding: X,
-//) -> Box<dyn Encryptor> {
-//    if util::supports_aesni() {
-//        let aes_enc = aesni::AesNiEncryptor::new(key_size, key);
-//        let enc = Box::new(EcbEncryptor::new(aes_enc, padding));
-//        enc
-//    } else {
-//        match key_size {
-//            KeySize::KeySize128 => {
-//                let aes_enc = aessafe::AesSafe128Encryptor::new(key);
-//                let enc = Box::new(EcbEncryptor::new(aes_enc, padding));
-//                enc
-//            }
-//            KeySize::KeySize192 => {
-//                let aes_enc = aessafe::AesSafe192Encryptor::new(key);
-//                let enc = Box::new(EcbEncryptor::new(aes_enc, padding));
-//                enc
-//            }
-//            KeySize::KeySize256 => {
-//                let aes_enc = aessafe::AesSafe256Encryptor::new(key);
-//                let enc = Box::new(EcbEncryptor::new(aes_enc, padding));
-//                enc
-//            }
-//        }
-//    }
-//}
-//
-///// Get the best implementation of an EcbEncryptor
-//#[cfg(all(not(target_arch = "x86"), not(target_arch = "x86_64")))]
-//pub fn ecb_encryptor<X: PaddingProcessor + Send + 'static>(
-//    key_size: KeySize,
-//    key: &[u8],
-//    padding: X,
-//) -> Box<Encryptor> {
-//    match key_size {
-//        KeySize::KeySize128 => {
-//            let aes_enc = aessafe::AesSafe128Encryptor::new(key);
-//            let enc = Box::new(EcbEncryptor::new(aes_enc, padding));
-//            enc
-//        }
-//        KeySize::KeySize192 => {
-//            let aes_enc = aessafe::AesSafe192Encryptor::new(key);
-//            let enc = Box::new(EcbEncryptor::new(aes_enc, padding));
-//            enc
-//        }
-//        KeySize::KeySize256 => {
-//            let aes_enc = aessafe::AesSafe256Encryptor::new(key);
-//            let enc = Box::new(EcbEncryptor::new(aes_enc, padding));
-//            enc
-//        }
-//    }
-//}
-//
-///// Get the best implementation of an EcbDecryptor
-//#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-//pub fn ecb_decryptor<X: PaddingProcessor + Send + 'static>(
-//    key_size: KeySize,
-//    key: &[u8],
-//    padding: X,
-//) -> Box<dyn Decryptor> {
-//    if util::supports_aesni() {
-//        let aes_dec = aesni::AesNiDecryptor::new(key_size, key);
-//        let dec = Box::new(EcbDecryptor::new(aes_dec, padding));
-//        dec
-//    } else {
-//        match key_size {
-//            KeySize::KeySize128 => {
-//                let aes_dec = aessafe::AesSafe128Decryptor::new(key);
-//                let dec = Box::new(EcbDecryptor::new(aes_dec, padding));
-//                dec
-//            }
-//            KeySize::KeySize192 => {
-//                let aes_dec = aessafe::AesSafe192Decryptor::new(key);
-//                let dec = Box::new(EcbDecryptor::new(aes_dec, padding));
-//                dec
-//            }
-//            KeySize::KeySize256 => {
-//                let aes_dec = aessafe::AesSafe256Decryptor::new(key);
-//                let dec = Box::new(EcbDecryptor::new(aes_dec, padding));
-//                dec
-//            }
-//        }
-//    }
-//}
-//
-///// Get the best implementation of an EcbDecryptor
-//#[cfg(all(not(target_arch = "x86"), not(target_arch = "x86_64")))]
-//pub fn ecb_decryptor<X: PaddingProcessor + Send + 'static>(
-//    key_size: KeySize,
-//    key: &[u8],
-//    padding: X,
-//) -> Box<Decryptor> {
-//    match key_size {
-//        KeySize::KeySize128 => {
-//            let aes_dec = aessafe::AesSafe128Decryptor::new(key);
-//            let dec = Box::new(EcbDecryptor::new(aes_dec, padding));
-//            dec
-//        }
-//        KeySize::KeySize192 => {
-//            let aes_dec = aessafe::AesSafe192Decryptor::new(key);
-//            let dec = Box::new(EcbDecryptor::new(aes_dec, padding));
-//            dec
-//        }
-//        KeySize::KeySize256 => {
-//            let aes_dec = aessafe::AesSafe256Decryptor::new(key);
-//            let dec = Box::new(EcbDecryptor::new(aes_dec, padding));
-//            dec
-//        }
-//    }
-//}
-//
-///// Get the best implementation of a CbcEncryptor
-//#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-//pub fn cbc_encryptor<X: PaddingProcessor + Send + 'static>(
-//    key_size: KeySize,
-//    key: &[u8],
-//    iv: &[u8],
-//    padding: X,
-//) -> Box<Encryptor + 'static> {
-//    if util::supports_aesni() {
-//        let aes_enc = aesni::AesNiEncryptor::new(key_size, key);
-//        let enc = Box::new(CbcEncryptor::new(aes_enc, padding, iv.to_vec()));
-//        enc
-//    } else {
-//        match key_size {
-//            KeySize::KeySize128 => {
-//                let aes_enc = aessafe::AesSafe128Encryptor::new(key);
-//                let enc = Box::new(CbcEncryptor::new(aes_enc, padding, iv.to_vec()));
-//                enc
-//            }
-//            KeySize::KeySize192 => {
-//                let aes_enc = aessafe::AesSafe192Encryptor::new(key);
-//                let enc = Box::new(CbcEncryptor::new(aes_enc, padding, iv.to_vec()));
-//                enc
-//            }
-//            KeySize::KeySize256 => {
-//                let aes_enc = aessafe::AesSafe256Encryptor::new(key);
-//                let enc = Box::new(CbcEncryptor::new(aes_enc, padding, iv.to_vec()));
-//                enc
-//            }
-//        }
-//    }
-//}
-//
-///// Get the best implementation of a CbcEncryptor
-//#[cfg(all(not(target_arch = "x86"), not(target_arch = "x86_64")))]
-//pub fn cbc_encryptor<X: PaddingProcessor + Send + 'static>(
-//    key_size: KeySize,
-//    key: &[u8],
-//    iv: &[u8],
-//    padding: X,
-//) -> Box<Encryptor + 'static> {
-//    match key_size {
-//        KeySize::KeySize128 => {
-//            let aes_enc = aessafe::AesSafe128Encryptor::new(key);
-//            let enc = Box::new(CbcEncryptor::new(aes_enc, padding, iv.to_vec()));
-//            enc
-//        }
-//        KeySize::KeySize192 => {
-//            let aes_enc = aessafe::AesSafe192Encryptor::new(key);
-//            let enc = Box::new(CbcEncryptor::new(aes_enc, padding, iv.to_vec()));
-//            enc
-//        }
-//        KeySize::KeySize256 => {
-//            let aes_enc = aessafe::AesSafe256Encryptor::new(key);
-//            let enc = Box::new(CbcEncryptor::new(aes_enc, padding, iv.to_vec()));
-//            enc
-//        }
-//    }
-//}
-//
-///// Get the best implementation of a CbcDecryptor
-//#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-//pub fn cbc_decryptor<X: PaddingProcessor + Send + 'static>(
-//    key_size: KeySize,
-//    key: &[u8],
-//    iv: &[u8],
-//    padding: X,
-//) -> Box<dyn Decryptor + 'static> {
-//    if util::supports_aesni() {
-//        let aes_dec = aesni::AesNiDecryptor::new(key_size, key);
-//        let dec = Box::new(CbcDecryptor::new(aes_dec, padding, iv.to_vec()));
-//        dec
-//    } else {
-//        match key_size {
-//            KeySize::KeySize128 => {
-//                let aes_dec = aessafe::AesSafe128Decryptor::new(key);
-//                let dec = Box::new(CbcDecryptor::new(aes_dec, padding, iv.to_vec()));
-//                dec
-//            }
-//            KeySize::KeySize192 => {
-//                let aes_dec = aessafe::AesSafe192Decryptor::new(key);
-//                let dec = Box::new(CbcDecryptor::new(aes_dec, padding, iv.to_vec()));
-//                dec
-//            }
-//            KeySize::KeySize256 => {
-//                let aes_dec = aessafe::AesSafe256Decryptor::new(key);
-//                let dec = Box::new(CbcDecryptor::new(aes_dec, padding, iv.to_vec()));
-//                dec
-//            }
-//        }
-//    }
-//}
-//
-///// Get the best implementation of a CbcDecryptor
-//#[cfg(all(not(target_arch = "x86"), not(target_arch = "x86_64")))]
-//pub fn cbc_decryptor<X: PaddingProcessor + Send + 'static>(
-//    key_size: KeySize,
-//    key: &[u8],
-//    iv: &[u8],
-//    padding: X,
-//) -> Box<Decryptor + 'static> {
-//    match key_size {
-//        KeySize::KeySize128 => {
-//            let aes_dec = aessafe::AesSafe128Decryptor::new(key);
-//            let dec = Box::new(CbcDecryptor::new(aes_dec, padding, iv.to_vec()));
-//            dec as Box<Decryptor + 'static>
-//        }
-//        KeySize::KeySize192 => {
-//            let aes_dec = aessafe::AesSafe192Decryptor::new(key);
-//            let dec = Box::new(CbcDecryptor::new(aes_dec, padding, iv.to_vec()));
-//            dec as Box<Decryptor + 'static>
-//        }
-//        KeySize::KeySize256 => {
-//            let aes_dec = aessafe::AesSafe256Decryptor::new(key);
-//            let dec = Box::new(CbcDecryptor::new(aes_dec, padding, iv.to_vec()));
-//            dec as Box<Decryptor + 'static>
-//        }
-//    }
-//}
-//
-///// Get the best implementation of a Ctr
-//#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-//pub fn ctr(key_size: KeySize, key: &[u8], iv: &[u8]) -> Box<dyn SynchronousStreamCipher + 'static> {
-//    if util::supports_aesni() {
-//        let aes_dec = aesni::AesNiEncryptor::new(key_size, key);
-//        let dec = Box::new(CtrMode::new(aes_dec, iv.to_vec()));
-//        dec
-//    } else {
-//        match key_size {
-//            KeySize::KeySize128 => {
-//                let aes_dec = aessafe::AesSafe128EncryptorX8::new(key);
-//                let dec = Box::new(CtrModeX8::new(aes_dec, iv));
-//                dec
-//            }
-//            KeySize::KeySize192 => {
-//                let aes_dec = aessafe::AesSafe192EncryptorX8::new(key);
-//                let dec = Box::new(CtrModeX8::new(aes_dec, iv));
-//                dec
-//            }
-//            KeySize::KeySize256 => {
-//                let aes_dec = aessafe::AesSafe256EncryptorX8::new(key);
-//                let dec = Box::new(CtrModeX8::new(aes_dec, iv));
-//                dec
-//            }
-//        }
-//    }
-//}
-//
-///// Get the best implementation of a Ctr
-//#[cfg(all(not(target_arch = "x86"), not(target_arch = "x86_64")))]
-//pub fn ctr(key_size: KeySize, key: &[u8], iv: &[u8]) -> Box<SynchronousStreamCipher + 'static> {
-//    match key_size {
-//        KeySize::KeySize128 => {
-//            let aes_dec = aessafe::AesSafe128EncryptorX8::new(key);
-//            let dec = Box::new(CtrModeX8::new(aes_dec, iv));
-//            dec as Box<SynchronousStreamCipher>
-//        }
-//        KeySize::KeySize192 => {
-//            let aes_dec = aessafe::AesSafe192EncryptorX8::new(key);
-//            let dec = Box::new(CtrModeX8::new(aes_dec, iv));
-//            dec as Box<SynchronousStreamCipher>
-//        }
-//        KeySize::KeySize256 => {
-//            let aes_dec = aessafe::AesSafe256EncryptorX8::new(key);
-//            let dec = Box::new(CtrModeX8::new(aes_dec, iv));
-//            dec as Box<SynchronousStreamCipher>
-//        }
-//    }
-//}
-//
-//#[cfg(test)]
-//mod test {
-//    use sr_std::iter::repeat;
-//
-//    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-//    use aesni;
-//
-//    use aes;
-//    use aes::KeySize::{KeySize128, KeySize192, KeySize256};
-//    use aessafe;
-//    use symmetriccipher::{
-//        BlockDecryptor, BlockDecryptorX8, BlockEncryptor, BlockEncryptorX8, SynchronousStreamCipher,
-//    };
-//    use util;
-//
-//    // Test vectors from:
-//    // http://www.inconteam.com/software-development/41-encryption/55-aes-test-vectors
-//
-//    struct Test {
-//        key: Vec<u8>,
-//        data: Vec<TestData>,
-//    }
-//
-//    struct TestData {
-//        plain: Vec<u8>,
-//        cipher: Vec<u8>,
-//    }
-//
-//    fn tests128() -> Vec<Test> {
-//        vec![Test {
-//            key: vec![
-//                0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf,
-//                0x4f, 0x3c,
-//            ],
-//            data: vec![
-//                TestData {
-//                    plain: vec![
-//                        0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11,
-//                        0x73, 0x93, 0x17, 0x2a,
-//                    ],
-//                    cipher: vec![
-//                        0x3a, 0xd7, 0x7b, 0xb4, 0x0d, 0x7a, 0x36, 0x60, 0xa8, 0x9e, 0xca, 0xf3,
-//                        0x24, 0x66, 0xef, 0x97,
-//                    ],
-//                },
-//                TestData {
-//                    plain: vec![
-//                        0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac,
-//                        0x45, 0xaf, 0x8e, 0x51,
-//                    ],
-//                    cipher: vec![
-//                        0xf5, 0xd3, 0xd5, 0x85, 0x03, 0xb9, 0x69, 0x9d, 0xe7, 0x85, 0x89, 0x5a,
-//                        0x96, 0xfd, 0xba, 0xaf,
-//                    ],
-//                },
-//                TestData {
-//                    plain: vec![
-//                        0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19,
-//                        0x1a, 0x0a, 0x52, 0xef,
-//                    ],
-//                    cipher: vec![
-//                        0x43, 0xb1, 0xcd, 0x7f, 0x59, 0x8e, 0xce, 0x23, 0x88, 0x1b, 0x00, 0xe3,
-//                        0xed, 0x03, 0x06, 0x88,
-//                    ],
-//                },
-//                TestData {
-//                    plain: vec![
-//                        0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b,
-//                        0xe6, 0x6c, 0x37, 0x10,
-//                    ],
-//                    cipher: vec![
-//                        0x7b, 0x0c, 0x78, 0x5e, 0x27, 0xe8, 0xad, 0x3f, 0x82, 0x23, 0x20, 0x71,
-//                        0x04, 0x72, 0x5d, 0xd4,
-//                    ],
-//                },
-//            ],
-//        }]
-//    }
-//
-//    fn tests192() -> Vec<Test> {
-//        vec![Test {
-//            key: vec![
-//                0x8e, 0x73, 0xb0, 0xf7, 0xda, 0x0e, 0x64, 0x52, 0xc8, 0x10, 0xf3, 0x2b, 0x80, 0x90,
-//                0x79, 0xe5, 0x62, 0xf8, 0xea, 0xd2, 0x52, 0x2c, 0x6b, 0x7b,
-//            ],
-//            data: vec![
-//                TestData {
-//                    plain: vec![
-//                        0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11,
-//                        0x73, 0x93, 0x17, 0x2a,
-//                    ],
-//                    cipher: vec![
-//                        0xbd, 0x33, 0x4f, 0x1d, 0x6e, 0x45, 0xf2, 0x5f, 0xf7, 0x12, 0xa2, 0x14,
-//                        0x57, 0x1f, 0xa5, 0xcc,
-//                    ],
-//                },
-//                TestData {
-//                    plain: vec![
-//                        0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac,
-//                        0x45, 0xaf, 0x8e, 0x51,
-//                    ],
-//                    cipher: vec![
-//                        0x97, 0x41, 0x04, 0x84, 0x6d, 0x0a, 0xd3, 0xad, 0x77, 0x34, 0xec, 0xb3,
-//                        0xec, 0xee, 0x4e, 0xef,
-//                    ],
-//                },
-//                TestData {
-//                    plain: vec![
-//                        0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19,
-//                        0x1a, 0x0a, 0x52, 0xef,
-//                    ],
-//                    cipher: vec![
-//                        0xef, 0x7a, 0xfd, 0x22, 0x70, 0xe2, 0xe6, 0x0a, 0xdc, 0xe0, 0xba, 0x2f,
-//                        0xac, 0xe6, 0x44, 0x4e,
-//                    ],
-//                },
-//                TestData {
-//                    plain: vec![
-//                        0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b,
-//                        0xe6, 0x6c, 0x37, 0x10,
-//                    ],
-//                    cipher: vec![
-//                        0x9a, 0x4b, 0x41, 0xba, 0x73, 0x8d, 0x6c, 0x72, 0xfb, 0x16, 0x69, 0x16,
-//                        0x03, 0xc1, 0x8e, 0x0e,
-//                    ],
-//                },
-//            ],
-//        }]
-//    }
-//
-//    fn tests256() -> Vec<Test> {
-//        vec![Test {
-//            key: vec![
-//                0x60, 0x3d, 0xeb, 0x10, 0x15, 0xca, 0x71, 0xbe, 0x2b, 0x73, 0xae, 0xf0, 0x85, 0x7d,
-//                0x77, 0x81, 0x1f, 0x35, 0x2c, 0x07, 0x3b, 0x61, 0x08, 0xd7, 0x2d, 0x98, 0x10, 0xa3,
-//                0x09, 0x14, 0xdf, 0xf4,
-//            ],
-//            data: vec![
-//                TestData {
-//                    plain: vec![
-//                        0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11,
-//                        0x73, 0x93, 0x17, 0x2a,
-//                    ],
-//                    cipher: vec![
-//                        0xf3, 0xee, 0xd1, 0xbd, 0xb5, 0xd2, 0xa0, 0x3c, 0x06, 0x4b, 0x5a, 0x7e,
-//                        0x3d, 0xb1, 0x81, 0xf8,
-//                    ],
-//                },
-//                TestData {
-//                    plain: vec![
-//                        0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac,
-//                        0x45, 0xaf, 0x8e, 0x51,
-//                    ],
-//                    cipher: vec![
-//                        0x59, 0x1c, 0xcb, 0x10, 0xd4, 0x10, 0xed, 0x26, 0xdc, 0x5b, 0xa7, 0x4a,
-//                        0x31, 0x36, 0x28, 0x70,
-//                    ],
-//                },
-//                TestData {
-//                    plain: vec![
-//                        0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19,
-//                        0x1a, 0x0a, 0x52, 0xef,
-//                    ],
-//                    cipher: vec![
-//                        0xb6, 0xed, 0x21, 0xb9, 0x9c, 0xa6, 0xf4, 0xf9, 0xf1, 0x53, 0xe7, 0xb1,
-//                        0xbe, 0xaf, 0xed, 0x1d,
-//                    ],
-//                },
-//                TestData {
-//                    plain: vec![
-//                        0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b,
-//                        0xe6, 0x6c, 0x37, 0x10,
-//                    ],
-//                    cipher: vec![
-//                        0x23, 0x30, 0x4b, 0x7a, 0x39, 0xf9, 0xf3, 0xff, 0x06, 0x7d, 0x8d, 0x8f,
-//                        0x9e, 0x24, 0xec, 0xc7,
-//                    ],
-//                },
-//            ],
-//        }]
-//    }
-//
-//    struct CtrTest {
-//        key: Vec<u8>,
-//        ctr: Vec<u8>,
-//        plain: Vec<u8>,
-//        cipher: Vec<u8>,
-//    }
-//
-//    fn aes_ctr_tests() -> Vec<CtrTest> {
-//        vec![CtrTest {
-//            key: repeat(1).take(16).collect(),
-//            ctr: repeat(3).take(16).collect(),
-//            plain: repeat(2).take(33).collect(),
-//            cipher: vec![
-//                0x64, 0x3e, 0x05, 0x19, 0x79, 0x78, 0xd7, 0x45, 0xa9, 0x10, 0x5f, 0xd8, 0x4c, 0xd7,
-//                0xe6, 0xb1, 0x5f, 0x66, 0xc6, 0x17, 0x4b, 0x25, 0xea, 0x24, 0xe6, 0xf9, 0x19, 0x09,
-//                0xb7, 0xdd, 0x84, 0xfb, 0x86,
-//            ],
-//        }]
-//    }
-//
-//    fn run_test<E: BlockEncryptor, D: BlockDecryptor>(enc: &mut E, dec: &mut D, test: &Test) {
-//        let mut tmp = [0u8; 16];
-//        for data in test.data.iter() {
-//            enc.encrypt_block(&data.plain[..], &mut tmp);
-//            //assert!(tmp[..] == data.cipher[..]);
-//            dec.decrypt_block(&data.cipher[..], &mut tmp);
-//            //assert!(tmp[..] == data.plain[..]);
-//        }
-//    }
-//
-//    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-//    #[test]
-//    fn test_aesni_128() {
-//        if util::supports_aesni() {
-//            let tests = tests128();
-//            for t in tests.iter() {
-//                let mut enc = aesni::AesNiEncryptor::new(KeySize128, &t.key[..]);
-//                let mut dec = aesni::AesNiDecryptor::new(KeySize128, &t.key[..]);
-//                run_test(&mut enc, &mut dec, t);
-//            }
-//        }
-//    }
-//
-//    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-//    #[test]
-//    fn test_aesni_192() {
-//        if util::supports_aesni() {
-//            let tests = tests192();
-//            for t in tests.iter() {
-//                let mut enc = aesni::AesNiEncryptor::new(KeySize192, &t.key[..]);
-//                let mut dec = aesni::AesNiDecryptor::new(KeySize192, &t.key[..]);
-//                run_test(&mut enc, &mut dec, t);
-//            }
-//        }
-//    }
-//
-//    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-//    #[test]
-//    fn test_aesni_256() {
-//        if util::supports_aesni() {
-//            let tests = tests256();
-//            for t in tests.iter() {
-//                let mut enc = aesni::AesNiEncryptor::new(KeySize256, &t.key[..]);
-//                let mut dec = aesni::AesNiDecryptor::new(KeySize256, &t.key[..]);
-//                run_test(&mut enc, &mut dec, t);
-//            }
-//        }
-//    }
-//
-//    #[test]
-//    fn test_aessafe_128() {
-//        let tests = tests128();
-//        for t in tests.iter() {
-//            let mut enc = aessafe::AesSafe128Encryptor::new(&t.key[..]);
-//            let mut dec = aessafe::AesSafe128Decryptor::new(&t.key[..]);
-//            run_test(&mut enc, &mut dec, t);
-//        }
-//    }
-//
-//    #[test]
-//    fn test_aessafe_192() {
-//        let tests = tests192();
-//        for t in tests.iter() {
-//            let mut enc = aessafe::AesSafe192Encryptor::new(&t.key[..]);
-//            let mut dec = aessafe::AesSafe192Decryptor::new(&t.key[..]);
-//            run_test(&mut enc, &mut dec, t);
-//        }
-//    }
-//
-//    #[test]
-//    fn test_aessafe_256() {
-//        let tests = tests256();
-//        for t in tests.iter() {
-//            let mut enc = aessafe::AesSafe256Encryptor::new(&t.key[..]);
-//            let mut dec = aessafe::AesSafe256Decryptor::new(&t.key[..]);
-//            run_test(&mut enc, &mut dec, t);
-//        }
-//    }
-//
-//    // The following test vectors are all from NIST SP 800-38A
-//
-//    #[test]
-//    fn test_aessafe_128_x8() {
-//        let key: [u8; 16] = [
-//            0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf,
-//            0x4f, 0x3c,
-//        ];
-//        let plain: [u8; 128] = [
-//            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93,
-//            0x17, 0x2a, 0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac,
-//            0x45, 0xaf, 0x8e, 0x51, 0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb,
-//            0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef, 0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17,
-//            0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10, 0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40,
-//            0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a, 0xae, 0x2d, 0x8a, 0x57,
-//            0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51, 0x30, 0xc8,
-//            0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
-//            0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c,
-//            0x37, 0x10,
-//        ];
-//        let cipher: [u8; 128] = [
-//            0x3a, 0xd7, 0x7b, 0xb4, 0x0d, 0x7a, 0x36, 0x60, 0xa8, 0x9e, 0xca, 0xf3, 0x24, 0x66,
-//            0xef, 0x97, 0xf5, 0xd3, 0xd5, 0x85, 0x03, 0xb9, 0x69, 0x9d, 0xe7, 0x85, 0x89, 0x5a,
-//            0x96, 0xfd, 0xba, 0xaf, 0x43, 0xb1, 0xcd, 0x7f, 0x59, 0x8e, 0xce, 0x23, 0x88, 0x1b,
-//            0x00, 0xe3, 0xed, 0x03, 0x06, 0x88, 0x7b, 0x0c, 0x78, 0x5e, 0x27, 0xe8, 0xad, 0x3f,
-//            0x82, 0x23, 0x20, 0x71, 0x04, 0x72, 0x5d, 0xd4, 0x3a, 0xd7, 0x7b, 0xb4, 0x0d, 0x7a,
-//            0x36, 0x60, 0xa8, 0x9e, 0xca, 0xf3, 0x24, 0x66, 0xef, 0x97, 0xf5, 0xd3, 0xd5, 0x85,
-//            0x03, 0xb9, 0x69, 0x9d, 0xe7, 0x85, 0x89, 0x5a, 0x96, 0xfd, 0xba, 0xaf, 0x43, 0xb1,
-//            0xcd, 0x7f, 0x59, 0x8e, 0xce, 0x23, 0x88, 0x1b, 0x00, 0xe3, 0xed, 0x03, 0x06, 0x88,
-//            0x7b, 0x0c, 0x78, 0x5e, 0x27, 0xe8, 0xad, 0x3f, 0x82, 0x23, 0x20, 0x71, 0x04, 0x72,
-//            0x5d, 0xd4,
-//        ];
-//
-//        let enc = aessafe::AesSafe128EncryptorX8::new(&key);
-//        let dec = aessafe::AesSafe128DecryptorX8::new(&key);
-//        let mut tmp = [0u8; 128];
-//        enc.encrypt_block_x8(&plain, &mut tmp);
-//        //assert!(tmp[..] == cipher[..]);
-//        dec.decrypt_block_x8(&cipher, &mut tmp);
-//        //assert!(tmp[..] == plain[..]);
-//    }
-//
-//    #[test]
-//    fn test_aessafe_192_x8() {
-//        let key: [u8; 24] = [
-//            0x8e, 0x73, 0xb0, 0xf7, 0xda, 0x0e, 0x64, 0x52, 0xc8, 0x10, 0xf3, 0x2b, 0x80, 0x90,
-//            0x79, 0xe5, 0x62, 0xf8, 0xea, 0xd2, 0x52, 0x2c, 0x6b, 0x7b,
-//        ];
-//        let plain: [u8; 128] = [
-//            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93,
-//            0x17, 0x2a, 0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac,
-//            0x45, 0xaf, 0x8e, 0x51, 0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb,
-//            0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef, 0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17,
-//            0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10, 0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40,
-//            0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a, 0xae, 0x2d, 0x8a, 0x57,
-//            0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51, 0x30, 0xc8,
-//            0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
-//            0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c,
-//            0x37, 0x10,
-//        ];
-//        let cipher: [u8; 128] = [
-//            0xbd, 0x33, 0x4f, 0x1d, 0x6e, 0x45, 0xf2, 0x5f, 0xf7, 0x12, 0xa2, 0x14, 0x57, 0x1f,
-//            0xa5, 0xcc, 0x97, 0x41, 0x04, 0x84, 0x6d, 0x0a, 0xd3, 0xad, 0x77, 0x34, 0xec, 0xb3,
-//            0xec, 0xee, 0x4e, 0xef, 0xef, 0x7a, 0xfd, 0x22, 0x70, 0xe2, 0xe6, 0x0a, 0xdc, 0xe0,
-//            0xba, 0x2f, 0xac, 0xe6, 0x44, 0x4e, 0x9a, 0x4b, 0x41, 0xba, 0x73, 0x8d, 0x6c, 0x72,
-//            0xfb, 0x16, 0x69, 0x16, 0x03, 0xc1, 0x8e, 0x0e, 0xbd, 0x33, 0x4f, 0x1d, 0x6e, 0x45,
-//            0xf2, 0x5f, 0xf7, 0x12, 0xa2, 0x14, 0x57, 0x1f, 0xa5, 0xcc, 0x97, 0x41, 0x04, 0x84,
-//            0x6d, 0x0a, 0xd3, 0xad, 0x77, 0x34, 0xec, 0xb3, 0xec, 0xee, 0x4e, 0xef, 0xef, 0x7a,
-//            0xfd, 0x22, 0x70, 0xe2, 0xe6, 0x0a, 0xdc, 0xe0, 0xba, 0x2f, 0xac, 0xe6, 0x44, 0x4e,
-//            0x9a, 0x4b, 0x41, 0xba, 0x73, 0x8d, 0x6c, 0x72, 0xfb, 0x16, 0x69, 0x16, 0x03, 0xc1,
-//            0x8e, 0x0e,
-//        ];
-//
-//        let enc = aessafe::AesSafe192EncryptorX8::new(&key);
-//        let dec = aessafe::AesSafe192DecryptorX8::new(&key);
-//        let mut tmp = [0u8; 128];
-//        enc.encrypt_block_x8(&plain, &mut tmp);
-//        //assert!(tmp[..] == cipher[..]);
-//        dec.decrypt_block_x8(&cipher, &mut tmp);
-//        //assert!(tmp[..] == plain[..]);
-//    }
-//
-//    #[test]
-//    fn test_aessafe_256_x8() {
-//        let key: [u8; 32] = [
-//            0x60, 0x3d, 0xeb, 0x10, 0x15, 0xca, 0x71, 0xbe, 0x2b, 0x73, 0xae, 0xf0, 0x85, 0x7d,
-//            0x77, 0x81, 0x1f, 0x35, 0x2c, 0x07, 0x3b, 0x61, 0x08, 0xd7, 0x2d, 0x98, 0x10, 0xa3,
-//            0x09, 0x14, 0xdf, 0xf4,
-//        ];
-//        let plain: [u8; 128] = [
-//            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93,
-//            0x17, 0x2a, 0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac,
-//            0x45, 0xaf, 0x8e, 0x51, 0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb,
-//            0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef, 0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17,
-//            0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10, 0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40,
-//            0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a, 0xae, 0x2d, 0x8a, 0x57,
-//            0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51, 0x30, 0xc8,
-//            0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
-//            0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c,
-//            0x37, 0x10,
-//        ];
-//        let cipher: [u8; 128] = [
-//            0xf3, 0xee, 0xd1, 0xbd, 0xb5, 0xd2, 0xa0, 0x3c, 0x06, 0x4b, 0x5a, 0x7e, 0x3d, 0xb1,
-//            0x81, 0xf8, 0x59, 0x1c, 0xcb, 0x10, 0xd4, 0x10, 0xed, 0x26, 0xdc, 0x5b, 0xa7, 0x4a,
-//            0x31, 0x36, 0x28, 0x70, 0xb6, 0xed, 0x21, 0xb9, 0x9c, 0xa6, 0xf4, 0xf9, 0xf1, 0x53,
-//            0xe7, 0xb1, 0xbe, 0xaf, 0xed, 0x1d, 0x23, 0x30, 0x4b, 0x7a, 0x39, 0xf9, 0xf3, 0xff,
-//            0x06, 0x7d, 0x8d, 0x8f, 0x9e, 0x24, 0xec, 0xc7, 0xf3, 0xee, 0xd1, 0xbd, 0xb5, 0xd2,
-//            0xa0, 0x3c, 0x06, 0x4b, 0x5a, 0x7e, 0x3d, 0xb1, 0x81, 0xf8, 0x59, 0x1c, 0xcb, 0x10,
-//            0xd4, 0x10, 0xed, 0x26, 0xdc, 0x5b, 0xa7, 0x4a, 0x31, 0x36, 0x28, 0x70, 0xb6, 0xed,
-//            0x21, 0xb9, 0x9c, 0xa6, 0xf4, 0xf9, 0xf1, 0x53, 0xe7, 0xb1, 0xbe, 0xaf, 0xed, 0x1d,
-//            0x23, 0x30, 0x4b, 0x7a, 0x39, 0xf9, 0xf3, 0xff, 0x06, 0x7d, 0x8d, 0x8f, 0x9e, 0x24,
-//            0xec, 0xc7,
-//        ];
-//
-//        let enc = aessafe::AesSafe256EncryptorX8::new(&key);
-//        let dec = aessafe::AesSafe256DecryptorX8::new(&key);
-//        let mut tmp = [0u8; 128];
-//        enc.encrypt_block_x8(&plain, &mut tmp);
-//        //assert!(tmp[..] == cipher[..]);
-//        dec.decrypt_block_x8(&cipher, &mut tmp);
-//        //assert!(tmp[..] == plain[..]);
-//    }
-//
-//    #[test]
-//    fn aes_ctr_box() {
-//        let tests = aes_ctr_tests();
-//        for test in tests.iter() {
-//            let mut aes_enc = aes::ctr(aes::KeySize::KeySize128, &test.key[..], &test.ctr[..]);
-//            let mut result: Vec<u8> = repeat(0).take(test.plain.len()).collect();
-//            aes_enc.process(&test.plain[..], &mut result[..]);
-//            let res: &[u8] = result.as_ref();
-//            //assert!(res == &test.cipher[..]);
-//        }
-//    }
-//}
-//
-//#[cfg(all(test, feature = "with-bench"))]
-//mod bench {
-//    use test::Bencher;
-//
-//    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-//    use aesni;
-//
-//    use aes::KeySize::{self, KeySize128, KeySize192, KeySize256};
-//    use aessafe;
-//    use symmetriccipher::{BlockEncryptor, BlockEncryptorX8};
-//    use util;
-//
-//    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-//    #[bench]
-//    pub fn aesni_128_bench(bh: &mut Bencher) {
-//        aesni_bench(bh, KeySize128);
-//    }
-//
-//    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-//    #[bench]
-//    pub fn aesni_192_bench(bh: &mut Bencher) {
-//        aesni_bench(bh, KeySize192);
-//    }
-//
-//    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-//    #[bench]
-//    pub fn aesni_256_bench(bh: &mut Bencher) {
-//        aesni_bench(bh, KeySize256);
-//    }
-//
-//    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-//    fn aesni_bench(bh: &mut Bencher, key_size: KeySize) {
-//        if util::supports_aesni() {
-//            let key: [u8; 16] = [1u8; 16];
-//            let plain: [u8; 16] = [2u8; 16];
-//
-//            let a = aesni::AesNiEncryptor::new(key_size, &key);
-//
-//            let mut tmp = [0u8; 16];
-//
-//            bh.iter(|| {
-//                a.encrypt_block(&plain, &mut tmp);
-//            });
-//
-//            bh.bytes = (plain.len()) as u64;
-//        }
-//    }
-//
-//    #[bench]
-//    pub fn aes_safe_bench(bh: &mut Bencher) {
-//        let key: [u8; 16] = [1u8; 16];
-//        let plain: [u8; 16] = [2u8; 16];
-//
-//        let a = aessafe::AesSafe128Encryptor::new(&key);
-//
-//        let mut tmp = [0u8; 16];
-//
-//        bh.iter(|| {
-//            a.encrypt_block(&plain, &mut tmp);
-//        });
-//
-//        bh.bytes = (plain.len()) as u64;
-//    }
-//
-//    #[bench]
-//    pub fn aes_safe_x8_bench(bh: &mut Bencher) {
-//        let key: [u8; 16] = [1u8; 16];
-//        let plain: [u8; 128] = [2u8; 128];
-//
-//        let a = aessafe::AesSafe128EncryptorX8::new(&key);
-//
-//        let mut tmp = [0u8; 128];
-//
-//        bh.iter(|| {
-//            a.encrypt_block_x8(&plain, &mut tmp);
-//        });
-//
-//        bh.bytes = (plain.len()) as u64;
-//    }
-//}
+/*!
+ * The aes module picks the best available AES implementation for each mode. On x86 and x86_64
+ * processors with AES-NI the hardware instructions are used. Everywhere else, and on x86 without
+ * AES-NI, the software backend is `aessafe`, a bit-sliced implementation that computes the S-box
+ * rather than looking it up in a table, so its timing does not depend on the key or data. There
+ * is no table-based backend to opt out of.
+ *
+ * The price of constant time is speed: `aessafe` is several times slower than a table-based
+ * implementation on the same machine, and much slower than AES-NI. Modes that can process 8
+ * blocks at once (CTR and CBC decryption) recover part of the difference.
+ */
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use aesni;
+
+use aessafe;
+use blockmodes::{
+    CbcDecryptor, CbcEncryptor, CtrMode, CtrModeX8, EcbDecryptor, EcbEncryptor, PaddingProcessor,
+};
+use sr_std::marker::*;
+use sr_std::prelude::*;
+use symmetriccipher::{Decryptor, Encryptor, SynchronousStreamCipher};
+use cryptoutil;
+use util;
+
+/// AES key size
+#[derive(Clone, Copy)]
+pub enum KeySize {
+    KeySize128,
+    KeySize192,
+    KeySize256,
+}
+
+/// Get the best implementation of an EcbEncryptor
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub fn ecb_encryptor<X: PaddingProcessor + Send + 'static>(
+    key_size: KeySize,
+    key: &[u8],
+    padding: X,
+) -> Box<dyn Encryptor> {
+    if util::supports_aesni() {
+        let aes_enc = aesni::AesNiEncryptor::new(key_size, key);
+        let enc = Box::new(EcbEncryptor::new(aes_enc, padding));
+        enc
+    } else {
+        match key_size {
+            KeySize::KeySize128 => {
+                let aes_enc = aessafe::AesSafe128Encryptor::new(key);
+                let enc = Box::new(EcbEncryptor::new(aes_enc, padding));
+                enc
+            }
+            KeySize::KeySize192 => {
+                let aes_enc = aessafe::AesSafe192Encryptor::new(key);
+                let enc = Box::new(EcbEncryptor::new(aes_enc, padding));
+                enc
+            }
+            KeySize::KeySize256 => {
+                let aes_enc = aessafe::AesSafe256Encryptor::new(key);
+                let enc = Box::new(EcbEncryptor::new(aes_enc, padding));
+                enc
+            }
+        }
+    }
+}
+
+/// Get the best implementation of an EcbEncryptor
+#[cfg(all(not(target_arch = "x86"), not(target_arch = "x86_64")))]
+pub fn ecb_encryptor<X: PaddingProcessor + Send + 'static>(
+    key_size: KeySize,
+    key: &[u8],
+    padding: X,
+) -> Box<dyn Encryptor> {
+    match key_size {
+        KeySize::KeySize128 => {
+            let aes_enc = aessafe::AesSafe128Encryptor::new(key);
+            let enc = Box::new(EcbEncryptor::new(aes_enc, padding));
+            enc
+        }
+        KeySize::KeySize192 => {
+            let aes_enc = aessafe::AesSafe192Encryptor::new(key);
+            let enc = Box::new(EcbEncryptor::new(aes_enc, padding));
+            enc
+        }
+        KeySize::KeySize256 => {
+            let aes_enc = aessafe::AesSafe256Encryptor::new(key);
+            let enc = Box::new(EcbEncryptor::new(aes_enc, padding));
+            enc
+        }
+    }
+}
+
+/// Get the best implementation of an EcbDecryptor
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub fn ecb_decryptor<X: PaddingProcessor + Send + 'static>(
+    key_size: KeySize,
+    key: &[u8],
+    padding: X,
+) -> Box<dyn Decryptor> {
+    if util::supports_aesni() {
+        let aes_dec = aesni::AesNiDecryptor::new(key_size, key);
+        let dec = Box::new(EcbDecryptor::new(aes_dec, padding));
+        dec
+    } else {
+        match key_size {
+            KeySize::KeySize128 => {
+                let aes_dec = aessafe::AesSafe128Decryptor::new(key);
+                let dec = Box::new(EcbDecryptor::new(aes_dec, padding));
+                dec
+            }
+            KeySize::KeySize192 => {
+                let aes_dec = aessafe::AesSafe192Decryptor::new(key);
+                let dec = Box::new(EcbDecryptor::new(aes_dec, padding));
+                dec
+            }
+            KeySize::KeySize256 => {
+                let aes_dec = aessafe::AesSafe256Decryptor::new(key);
+                let dec = Box::new(EcbDecryptor::new(aes_dec, padding));
+                dec
+            }
+        }
+    }
+}
+
+/// Get the best implementation of an EcbDecryptor
+#[cfg(all(not(target_arch = "x86"), not(target_arch = "x86_64")))]
+pub fn ecb_decryptor<X: PaddingProcessor + Send + 'static>(
+    key_size: KeySize,
+    key: &[u8],
+    padding: X,
+) -> Box<dyn Decryptor> {
+    match key_size {
+        KeySize::KeySize128 => {
+            let aes_dec = aessafe::AesSafe128Decryptor::new(key);
+            let dec = Box::new(EcbDecryptor::new(aes_dec, padding));
+            dec
+        }
+        KeySize::KeySize192 => {
+            let aes_dec = aessafe::AesSafe192Decryptor::new(key);
+            let dec = Box::new(EcbDecryptor::new(aes_dec, padding));
+            dec
+        }
+        KeySize::KeySize256 => {
+            let aes_dec = aessafe::AesSafe256Decryptor::new(key);
+            let dec = Box::new(EcbDecryptor::new(aes_dec, padding));
+            dec
+        }
+    }
+}
+
+/// Get the best implementation of a CbcEncryptor
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub fn cbc_encryptor<X: PaddingProcessor + Send + 'static>(
+    key_size: KeySize,
+    key: &[u8],
+    iv: &[u8],
+    padding: X,
+) -> Box<dyn Encryptor + 'static> {
+    if util::supports_aesni() {
+        let aes_enc = aesni::AesNiEncryptor::new(key_size, key);
+        let enc = Box::new(CbcEncryptor::new(aes_enc, padding, iv.to_vec()));
+        enc
+    } else {
+        match key_size {
+            KeySize::KeySize128 => {
+                let aes_enc = aessafe::AesSafe128Encryptor::new(key);
+                let enc = Box::new(CbcEncryptor::new(aes_enc, padding, iv.to_vec()));
+                enc
+            }
+            KeySize::KeySize192 => {
+                let aes_enc = aessafe::AesSafe192Encryptor::new(key);
+                let enc = Box::new(CbcEncryptor::new(aes_enc, padding, iv.to_vec()));
+                enc
+            }
+            KeySize::KeySize256 => {
+                let aes_enc = aessafe::AesSafe256Encryptor::new(key);
+                let enc = Box::new(CbcEncryptor::new(aes_enc, padding, iv.to_vec()));
+                enc
+            }
+        }
+    }
+}
+
+/// Get the best implementation of a CbcEncryptor
+#[cfg(all(not(target_arch = "x86"), not(target_arch = "x86_64")))]
+pub fn cbc_encryptor<X: PaddingProcessor + Send + 'static>(
+    key_size: KeySize,
+    key: &[u8],
+    iv: &[u8],
+    padding: X,
+) -> Box<dyn Encryptor + 'static> {
+    match key_size {
+        KeySize::KeySize128 => {
+            let aes_enc = aessafe::AesSafe128Encryptor::new(key);
+            let enc = Box::new(CbcEncryptor::new(aes_enc, padding, iv.to_vec()));
+            enc
+        }
+        KeySize::KeySize192 => {
+            let aes_enc = aessafe::AesSafe192Encryptor::new(key);
+            let enc = Box::new(CbcEncryptor::new(aes_enc, padding, iv.to_vec()));
+            enc
+        }
+        KeySize::KeySize256 => {
+            let aes_enc = aessafe::AesSafe256Encryptor::new(key);
+            let enc = Box::new(CbcEncryptor::new(aes_enc, padding, iv.to_vec()));
+            enc
+        }
+    }
+}
+
+/// Get the best implementation of a CbcDecryptor
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub fn cbc_decryptor<X: PaddingProcessor + Send + 'static>(
+    key_size: KeySize,
+    key: &[u8],
+    iv: &[u8],
+    padding: X,
+) -> Box<dyn Decryptor + 'static> {
+    if util::supports_aesni() {
+        let aes_dec = aesni::AesNiDecryptor::new(key_size, key);
+        let dec = Box::new(CbcDecryptor::new(aes_dec, padding, iv.to_vec()));
+        dec
+    } else {
+        match key_size {
+            KeySize::KeySize128 => {
+                let aes_dec = aessafe::AesSafe128Decryptor::new(key);
+                let dec = Box::new(CbcDecryptor::new(aes_dec, padding, iv.to_vec()));
+                dec
+            }
+            KeySize::KeySize192 => {
+                let aes_dec = aessafe::AesSafe192Decryptor::new(key);
+                let dec = Box::new(CbcDecryptor::new(aes_dec, padding, iv.to_vec()));
+                dec
+            }
+            KeySize::KeySize256 => {
+                let aes_dec = aessafe::AesSafe256Decryptor::new(key);
+                let dec = Box::new(CbcDecryptor::new(aes_dec, padding, iv.to_vec()));
+                dec
+            }
+        }
+    }
+}
+
+/// Get the best implementation of a CbcDecryptor
+#[cfg(all(not(target_arch = "x86"), not(target_arch = "x86_64")))]
+pub fn cbc_decryptor<X: PaddingProcessor + Send + 'static>(
+    key_size: KeySize,
+    key: &[u8],
+    iv: &[u8],
+    padding: X,
+) -> Box<dyn Decryptor + 'static> {
+    match key_size {
+        KeySize::KeySize128 => {
+            let aes_dec = aessafe::AesSafe128Decryptor::new(key);
+            let dec = Box::new(CbcDecryptor::new(aes_dec, padding, iv.to_vec()));
+            dec as Box<dyn Decryptor + 'static>
+        }
+        KeySize::KeySize192 => {
+            let aes_dec = aessafe::AesSafe192Decryptor::new(key);
+            let dec = Box::new(CbcDecryptor::new(aes_dec, padding, iv.to_vec()));
+            dec as Box<dyn Decryptor + 'static>
+        }
+        KeySize::KeySize256 => {
+            let aes_dec = aessafe::AesSafe256Decryptor::new(key);
+            let dec = Box::new(CbcDecryptor::new(aes_dec, padding, iv.to_vec()));
+            dec as Box<dyn Decryptor + 'static>
+        }
+    }
+}
+
+/// Get the best implementation of a Ctr
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub fn ctr(key_size: KeySize, key: &[u8], iv: &[u8]) -> Box<dyn SynchronousStreamCipher + 'static> {
+    if util::supports_aesni() {
+        let aes_dec = aesni::AesNiEncryptor::new(key_size, key);
+        let dec = Box::new(CtrMode::new(aes_dec, iv.to_vec()));
+        dec
+    } else {
+        match key_size {
+            KeySize::KeySize128 => {
+                let aes_dec = aessafe::AesSafe128EncryptorX8::new(key);
+                let dec = Box::new(CtrModeX8::new(aes_dec, iv));
+                dec
+            }
+            KeySize::KeySize192 => {
+                let aes_dec = aessafe::AesSafe192EncryptorX8::new(key);
+                let dec = Box::new(CtrModeX8::new(aes_dec, iv));
+                dec
+            }
+            KeySize::KeySize256 => {
+                let aes_dec = aessafe::AesSafe256EncryptorX8::new(key);
+                let dec = Box::new(CtrModeX8::new(aes_dec, iv));
+                dec
+            }
+        }
+    }
+}
+
+/// Get the best implementation of a Ctr
+#[cfg(all(not(target_arch = "x86"), not(target_arch = "x86_64")))]
+pub fn ctr(key_size: KeySize, key: &[u8], iv: &[u8]) -> Box<dyn SynchronousStreamCipher + 'static> {
+    match key_size {
+        KeySize::KeySize128 => {
+            let aes_dec = aessafe::AesSafe128EncryptorX8::new(key);
+            let dec = Box::new(CtrModeX8::new(aes_dec, iv));
+            dec as Box<dyn SynchronousStreamCipher>
+        }
+        KeySize::KeySize192 => {
+            let aes_dec = aessafe::AesSafe192EncryptorX8::new(key);
+            let dec = Box::new(CtrModeX8::new(aes_dec, iv));
+            dec as Box<dyn SynchronousStreamCipher>
+        }
+        KeySize::KeySize256 => {
+            let aes_dec = aessafe::AesSafe256EncryptorX8::new(key);
+            let dec = Box::new(CtrModeX8::new(aes_dec, iv));
+            dec as Box<dyn SynchronousStreamCipher>
+        }
+    }
+}
+
+/**
+ * A fast, deterministic random number generator that outputs the AES-CTR keystream for a given
+ * key and initial counter block. It is intended for reproducible test data and simulations.
+ *
+ * This is not a DRBG: it has no reseeding, no prediction resistance and no health checks, and it
+ * is not the NIST SP 800-90A CTR_DRBG. Its output is only as unpredictable as the key, and the same
+ * key and counter always produce the same bytes. Do not use it to generate keys.
+ */
+pub struct AesCtrRng {
+    cipher: Box<dyn SynchronousStreamCipher + 'static>,
+}
+
+impl AesCtrRng {
+    /**
+     * Create a new AesCtrRng. nonce is the 16 byte initial counter block.
+     */
+    pub fn new(key_size: KeySize, key: &[u8], nonce: &[u8]) -> AesCtrRng {
+        AesCtrRng {
+            cipher: ctr(key_size, key, nonce),
+        }
+    }
+
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let zeros = [0u8; 64];
+        for chunk in dest.chunks_mut(64) {
+            let len = chunk.len();
+            self.cipher.process(&zeros[..len], chunk);
+        }
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.fill_bytes(&mut bytes);
+        cryptoutil::read_u32_le(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use sr_std::iter::repeat;
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    use aesni;
+
+    use aes;
+    use aes::KeySize::{KeySize128, KeySize192, KeySize256};
+    use aessafe;
+    use symmetriccipher::{
+        BlockDecryptor, BlockDecryptorX8, BlockEncryptor, BlockEncryptorX8, SynchronousStreamCipher,
+    };
+    use util;
+
+    // Test vectors from:
+    // http://www.inconteam.com/software-development/41-encryption/55-aes-test-vectors
+
+    struct Test {
+        key: Vec<u8>,
+        data: Vec<TestData>,
+    }
+
+    struct TestData {
+        plain: Vec<u8>,
+        cipher: Vec<u8>,
+    }
+
+    fn tests128() -> Vec<Test> {
+        vec![Test {
+            key: vec![
+                0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf,
+                0x4f, 0x3c,
+            ],
+            data: vec![
+                TestData {
+                    plain: vec![
+                        0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11,
+                        0x73, 0x93, 0x17, 0x2a,
+                    ],
+                    cipher: vec![
+                        0x3a, 0xd7, 0x7b, 0xb4, 0x0d, 0x7a, 0x36, 0x60, 0xa8, 0x9e, 0xca, 0xf3,
+                        0x24, 0x66, 0xef, 0x97,
+                    ],
+                },
+                TestData {
+                    plain: vec![
+                        0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac,
+                        0x45, 0xaf, 0x8e, 0x51,
+                    ],
+                    cipher: vec![
+                        0xf5, 0xd3, 0xd5, 0x85, 0x03, 0xb9, 0x69, 0x9d, 0xe7, 0x85, 0x89, 0x5a,
+                        0x96, 0xfd, 0xba, 0xaf,
+                    ],
+                },
+                TestData {
+                    plain: vec![
+                        0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19,
+                        0x1a, 0x0a, 0x52, 0xef,
+                    ],
+                    cipher: vec![
+                        0x43, 0xb1, 0xcd, 0x7f, 0x59, 0x8e, 0xce, 0x23, 0x88, 0x1b, 0x00, 0xe3,
+                        0xed, 0x03, 0x06, 0x88,
+                    ],
+                },
+                TestData {
+                    plain: vec![
+                        0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b,
+                        0xe6, 0x6c, 0x37, 0x10,
+                    ],
+                    cipher: vec![
+                        0x7b, 0x0c, 0x78, 0x5e, 0x27, 0xe8, 0xad, 0x3f, 0x82, 0x23, 0x20, 0x71,
+                        0x04, 0x72, 0x5d, 0xd4,
+                    ],
+                },
+            ],
+        }]
+    }
+
+    fn tests192() -> Vec<Test> {
+        vec![Test {
+            key: vec![
+                0x8e, 0x73, 0xb0, 0xf7, 0xda, 0x0e, 0x64, 0x52, 0xc8, 0x10, 0xf3, 0x2b, 0x80, 0x90,
+                0x79, 0xe5, 0x62, 0xf8, 0xea, 0xd2, 0x52, 0x2c, 0x6b, 0x7b,
+            ],
+            data: vec![
+                TestData {
+                    plain: vec![
+                        0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11,
+                        0x73, 0x93, 0x17, 0x2a,
+                    ],
+                    cipher: vec![
+                        0xbd, 0x33, 0x4f, 0x1d, 0x6e, 0x45, 0xf2, 0x5f, 0xf7, 0x12, 0xa2, 0x14,
+                        0x57, 0x1f, 0xa5, 0xcc,
+                    ],
+                },
+                TestData {
+                    plain: vec![
+                        0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac,
+                        0x45, 0xaf, 0x8e, 0x51,
+                    ],
+                    cipher: vec![
+                        0x97, 0x41, 0x04, 0x84, 0x6d, 0x0a, 0xd3, 0xad, 0x77, 0x34, 0xec, 0xb3,
+                        0xec, 0xee, 0x4e, 0xef,
+                    ],
+                },
+                TestData {
+                    plain: vec![
+                        0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19,
+                        0x1a, 0x0a, 0x52, 0xef,
+                    ],
+                    cipher: vec![
+                        0xef, 0x7a, 0xfd, 0x22, 0x70, 0xe2, 0xe6, 0x0a, 0xdc, 0xe0, 0xba, 0x2f,
+                        0xac, 0xe6, 0x44, 0x4e,
+                    ],
+                },
+                TestData {
+                    plain: vec![
+                        0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b,
+                        0xe6, 0x6c, 0x37, 0x10,
+                    ],
+                    cipher: vec![
+                        0x9a, 0x4b, 0x41, 0xba, 0x73, 0x8d, 0x6c, 0x72, 0xfb, 0x16, 0x69, 0x16,
+                        0x03, 0xc1, 0x8e, 0x0e,
+                    ],
+                },
+            ],
+        }]
+    }
+
+    fn tests256() -> Vec<Test> {
+        vec![Test {
+            key: vec![
+                0x60, 0x3d, 0xeb, 0x10, 0x15, 0xca, 0x71, 0xbe, 0x2b, 0x73, 0xae, 0xf0, 0x85, 0x7d,
+                0x77, 0x81, 0x1f, 0x35, 0x2c, 0x07, 0x3b, 0x61, 0x08, 0xd7, 0x2d, 0x98, 0x10, 0xa3,
+                0x09, 0x14, 0xdf, 0xf4,
+            ],
+            data: vec![
+                TestData {
+                    plain: vec![
+                        0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11,
+                        0x73, 0x93, 0x17, 0x2a,
+                    ],
+                    cipher: vec![
+                        0xf3, 0xee, 0xd1, 0xbd, 0xb5, 0xd2, 0xa0, 0x3c, 0x06, 0x4b, 0x5a, 0x7e,
+                        0x3d, 0xb1, 0x81, 0xf8,
+                    ],
+                },
+                TestData {
+                    plain: vec![
+                        0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac,
+                        0x45, 0xaf, 0x8e, 0x51,
+                    ],
+                    cipher: vec![
+                        0x59, 0x1c, 0xcb, 0x10, 0xd4, 0x10, 0xed, 0x26, 0xdc, 0x5b, 0xa7, 0x4a,
+                        0x31, 0x36, 0x28, 0x70,
+                    ],
+                },
+                TestData {
+                    plain: vec![
+                        0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19,
+                        0x1a, 0x0a, 0x52, 0xef,
+                    ],
+                    cipher: vec![
+                        0xb6, 0xed, 0x21, 0xb9, 0x9c, 0xa6, 0xf4, 0xf9, 0xf1, 0x53, 0xe7, 0xb1,
+                        0xbe, 0xaf, 0xed, 0x1d,
+                    ],
+                },
+                TestData {
+                    plain: vec![
+                        0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b,
+                        0xe6, 0x6c, 0x37, 0x10,
+                    ],
+                    cipher: vec![
+                        0x23, 0x30, 0x4b, 0x7a, 0x39, 0xf9, 0xf3, 0xff, 0x06, 0x7d, 0x8d, 0x8f,
+                        0x9e, 0x24, 0xec, 0xc7,
+                    ],
+                },
+            ],
+        }]
+    }
+
+    struct CtrTest {
+        key: Vec<u8>,
+        ctr: Vec<u8>,
+        plain: Vec<u8>,
+        cipher: Vec<u8>,
+    }
+
+    fn aes_ctr_tests() -> Vec<CtrTest> {
+        vec![CtrTest {
+            key: repeat(1).take(16).collect(),
+            ctr: repeat(3).take(16).collect(),
+            plain: repeat(2).take(33).collect(),
+            cipher: vec![
+                0x64, 0x3e, 0x05, 0x19, 0x79, 0x78, 0xd7, 0x45, 0xa9, 0x10, 0x5f, 0xd8, 0x4c, 0xd7,
+                0xe6, 0xb1, 0x5f, 0x66, 0xc6, 0x17, 0x4b, 0x25, 0xea, 0x24, 0xe6, 0xf9, 0x19, 0x09,
+                0xb7, 0xdd, 0x84, 0xfb, 0x86,
+            ],
+        }]
+    }
+
+    fn run_test<E: BlockEncryptor, D: BlockDecryptor>(enc: &mut E, dec: &mut D, test: &Test) {
+        let mut tmp = [0u8; 16];
+        for data in test.data.iter() {
+            enc.encrypt_block(&data.plain[..], &mut tmp);
+            assert!(tmp[..] == data.cipher[..]);
+            dec.decrypt_block(&data.cipher[..], &mut tmp);
+            assert!(tmp[..] == data.plain[..]);
+        }
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[test]
+    fn test_aesni_128() {
+        if util::supports_aesni() {
+            let tests = tests128();
+            for t in tests.iter() {
+                let mut enc = aesni::AesNiEncryptor::new(KeySize128, &t.key[..]);
+                let mut dec = aesni::AesNiDecryptor::new(KeySize128, &t.key[..]);
+                run_test(&mut enc, &mut dec, t);
+            }
+        }
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[test]
+    fn test_aesni_192() {
+        if util::supports_aesni() {
+            let tests = tests192();
+            for t in tests.iter() {
+                let mut enc = aesni::AesNiEncryptor::new(KeySize192, &t.key[..]);
+                let mut dec = aesni::AesNiDecryptor::new(KeySize192, &t.key[..]);
+                run_test(&mut enc, &mut dec, t);
+            }
+        }
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[test]
+    fn test_aesni_256() {
+        if util::supports_aesni() {
+            let tests = tests256();
+            for t in tests.iter() {
+                let mut enc = aesni::AesNiEncryptor::new(KeySize256, &t.key[..]);
+                let mut dec = aesni::AesNiDecryptor::new(KeySize256, &t.key[..]);
+                run_test(&mut enc, &mut dec, t);
+            }
+        }
+    }
+
+    #[test]
+    fn test_aessafe_128() {
+        let tests = tests128();
+        for t in tests.iter() {
+            let mut enc = aessafe::AesSafe128Encryptor::new(&t.key[..]);
+            let mut dec = aessafe::AesSafe128Decryptor::new(&t.key[..]);
+            run_test(&mut enc, &mut dec, t);
+        }
+    }
+
+    #[test]
+    fn test_aessafe_192() {
+        let tests = tests192();
+        for t in tests.iter() {
+            let mut enc = aessafe::AesSafe192Encryptor::new(&t.key[..]);
+            let mut dec = aessafe::AesSafe192Decryptor::new(&t.key[..]);
+            run_test(&mut enc, &mut dec, t);
+        }
+    }
+
+    #[test]
+    fn test_aessafe_256() {
+        let tests = tests256();
+        for t in tests.iter() {
+            let mut enc = aessafe::AesSafe256Encryptor::new(&t.key[..]);
+            let mut dec = aessafe::AesSafe256Decryptor::new(&t.key[..]);
+            run_test(&mut enc, &mut dec, t);
+        }
+    }
+
+    #[test]
+    fn test_aessafe_fips197() {
+        // FIPS 197 appendix C: the software backend used whenever AES-NI is unavailable.
+        let plain: Vec<u8> = (0..16).map(|i| i * 0x11).collect();
+        let key: Vec<u8> = (0..32).collect();
+        let expected: [[u8; 16]; 3] = [
+            [
+                0x69, 0xc4, 0xe0, 0xd8, 0x6a, 0x7b, 0x04, 0x30, 0xd8, 0xcd, 0xb7, 0x80, 0x70, 0xb4,
+                0xc5, 0x5a,
+            ],
+            [
+                0xdd, 0xa9, 0x7c, 0xa4, 0x86, 0x4c, 0xdf, 0xe0, 0x6e, 0xaf, 0x70, 0xa0, 0xec, 0x0d,
+                0x71, 0x91,
+            ],
+            [
+                0x8e, 0xa2, 0xb7, 0xca, 0x51, 0x67, 0x45, 0xbf, 0xea, 0xfc, 0x49, 0x90, 0x4b, 0x49,
+                0x60, 0x89,
+            ],
+        ];
+        let encs: [&dyn BlockEncryptor; 3] = [
+            &aessafe::AesSafe128Encryptor::new(&key[..16]),
+            &aessafe::AesSafe192Encryptor::new(&key[..24]),
+            &aessafe::AesSafe256Encryptor::new(&key[..32]),
+        ];
+        let decs: [&dyn BlockDecryptor; 3] = [
+            &aessafe::AesSafe128Decryptor::new(&key[..16]),
+            &aessafe::AesSafe192Decryptor::new(&key[..24]),
+            &aessafe::AesSafe256Decryptor::new(&key[..32]),
+        ];
+        for ((enc, dec), expected) in encs.iter().zip(decs.iter()).zip(expected.iter()) {
+            let mut cipher = [0u8; 16];
+            enc.encrypt_block(&plain, &mut cipher);
+            assert_eq!(&cipher, expected);
+            let mut decrypted = [0u8; 16];
+            dec.decrypt_block(&cipher, &mut decrypted);
+            assert_eq!(&decrypted[..], &plain[..]);
+        }
+    }
+
+    #[test]
+    fn test_aessafe_round_keys() {
+        let key: Vec<u8> = (0..32).collect();
+        let block: Vec<u8> = (0..16).map(|i| i * 17).collect();
+        let mut expected = [0u8; 16];
+        let mut out = [0u8; 16];
+
+        let enc = aessafe::AesSafe128Encryptor::new(&key[..16]);
+        assert_eq!(enc.round_keys().len(), 44);
+        let copy = aessafe::AesSafe128Encryptor::from_round_keys(&enc.round_keys());
+        enc.encrypt_block(&block[..], &mut expected);
+        copy.encrypt_block(&block[..], &mut out);
+        assert_eq!(out, expected);
+
+        let enc = aessafe::AesSafe192Encryptor::new(&key[..24]);
+        assert_eq!(enc.round_keys().len(), 52);
+        let copy = aessafe::AesSafe192Encryptor::from_round_keys(&enc.round_keys());
+        enc.encrypt_block(&block[..], &mut expected);
+        copy.encrypt_block(&block[..], &mut out);
+        assert_eq!(out, expected);
+
+        let enc = aessafe::AesSafe256Encryptor::new(&key[..]);
+        assert_eq!(enc.round_keys().len(), 60);
+        let copy = aessafe::AesSafe256Encryptor::from_round_keys(&enc.round_keys());
+        enc.encrypt_block(&block[..], &mut expected);
+        copy.encrypt_block(&block[..], &mut out);
+        assert_eq!(out, expected);
+
+        let dec = aessafe::AesSafe256Decryptor::new(&key[..]);
+        let copy = aessafe::AesSafe256Decryptor::from_round_keys(&dec.round_keys());
+        copy.decrypt_block(&expected, &mut out);
+        assert_eq!(&out[..], &block[..]);
+
+        // FIPS 197 appendix A.1: the last round key is d014f9a8 c9ee2589 e13f0cc8 b6630ca6.
+        let fips_key = [
+            0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf,
+            0x4f, 0x3c,
+        ];
+        let enc = aessafe::AesSafe128Encryptor::new(&fips_key);
+        assert_eq!(
+            &enc.round_keys()[40..],
+            &[0xa8f914d0, 0x8925eec9, 0xc80c3fe1, 0xa60c63b6]
+        );
+    }
+
+    #[test]
+    fn test_aes192_fips197() {
+        // FIPS 197 appendix C.2, through every AES-192 implementation.
+        let plain: Vec<u8> = (0..16).map(|i| i * 0x11).collect();
+        let key: Vec<u8> = (0..24).collect();
+        let expected = [
+            0xdd, 0xa9, 0x7c, 0xa4, 0x86, 0x4c, 0xdf, 0xe0, 0x6e, 0xaf, 0x70, 0xa0, 0xec, 0x0d,
+            0x71, 0x91,
+        ];
+        let mut out = [0u8; 16];
+
+        let enc = aessafe::AesSafe192Encryptor::new(&key[..]);
+        let dec = aessafe::AesSafe192Decryptor::new(&key[..]);
+        enc.encrypt_block(&plain[..], &mut out);
+        assert_eq!(out, expected);
+        dec.decrypt_block(&expected, &mut out);
+        assert_eq!(&out[..], &plain[..]);
+
+        // The bit-sliced eight block variant, with the vector in every lane.
+        let plain_x8: Vec<u8> = plain.iter().cycle().take(128).cloned().collect();
+        let expected_x8: Vec<u8> = expected.iter().cycle().take(128).cloned().collect();
+        let mut out_x8 = [0u8; 128];
+        let enc = aessafe::AesSafe192EncryptorX8::new(&key[..]);
+        let dec = aessafe::AesSafe192DecryptorX8::new(&key[..]);
+        enc.encrypt_block_x8(&plain_x8[..], &mut out_x8);
+        assert_eq!(&out_x8[..], &expected_x8[..]);
+        dec.decrypt_block_x8(&expected_x8[..], &mut out_x8);
+        assert_eq!(&out_x8[..], &plain_x8[..]);
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if util::supports_aesni() {
+                let enc = aesni::AesNiEncryptor::new(KeySize192, &key[..]);
+                let dec = aesni::AesNiDecryptor::new(KeySize192, &key[..]);
+                enc.encrypt_block(&plain[..], &mut out);
+                assert_eq!(out, expected);
+                dec.decrypt_block(&expected, &mut out);
+                assert_eq!(&out[..], &plain[..]);
+            }
+        }
+
+        // FIPS 197 appendix A.2: the first derived words are fe0c91f7 2402f5a5 and the 52 word
+        // schedule ends with e98ba06f 448c773c 8ecc7204 01002202. Round keys are stored as
+        // little-endian words.
+        let fips_key = [
+            0x8e, 0x73, 0xb0, 0xf7, 0xda, 0x0e, 0x64, 0x52, 0xc8, 0x10, 0xf3, 0x2b, 0x80, 0x90,
+            0x79, 0xe5, 0x62, 0xf8, 0xea, 0xd2, 0x52, 0x2c, 0x6b, 0x7b,
+        ];
+        let enc = aessafe::AesSafe192Encryptor::new(&fips_key);
+        assert_eq!(enc.round_keys().len(), 52);
+        assert_eq!(&enc.round_keys()[6..8], &[0xf7910cfe, 0xa5f50224]);
+        assert_eq!(
+            &enc.round_keys()[48..],
+            &[0x6fa08be9, 0x3c778c44, 0x0472cc8e, 0x02220001]
+        );
+    }
+
+    // The following test vectors are all from NIST SP 800-38A
+
+    #[test]
+    fn test_aessafe_128_x8() {
+        let key: [u8; 16] = [
+            0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf,
+            0x4f, 0x3c,
+        ];
+        let plain: [u8; 128] = [
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93,
+            0x17, 0x2a, 0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac,
+            0x45, 0xaf, 0x8e, 0x51, 0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb,
+            0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef, 0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17,
+            0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10, 0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40,
+            0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a, 0xae, 0x2d, 0x8a, 0x57,
+            0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51, 0x30, 0xc8,
+            0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
+            0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c,
+            0x37, 0x10,
+        ];
+        let cipher: [u8; 128] = [
+            0x3a, 0xd7, 0x7b, 0xb4, 0x0d, 0x7a, 0x36, 0x60, 0xa8, 0x9e, 0xca, 0xf3, 0x24, 0x66,
+            0xef, 0x97, 0xf5, 0xd3, 0xd5, 0x85, 0x03, 0xb9, 0x69, 0x9d, 0xe7, 0x85, 0x89, 0x5a,
+            0x96, 0xfd, 0xba, 0xaf, 0x43, 0xb1, 0xcd, 0x7f, 0x59, 0x8e, 0xce, 0x23, 0x88, 0x1b,
+            0x00, 0xe3, 0xed, 0x03, 0x06, 0x88, 0x7b, 0x0c, 0x78, 0x5e, 0x27, 0xe8, 0xad, 0x3f,
+            0x82, 0x23, 0x20, 0x71, 0x04, 0x72, 0x5d, 0xd4, 0x3a, 0xd7, 0x7b, 0xb4, 0x0d, 0x7a,
+            0x36, 0x60, 0xa8, 0x9e, 0xca, 0xf3, 0x24, 0x66, 0xef, 0x97, 0xf5, 0xd3, 0xd5, 0x85,
+            0x03, 0xb9, 0x69, 0x9d, 0xe7, 0x85, 0x89, 0x5a, 0x96, 0xfd, 0xba, 0xaf, 0x43, 0xb1,
+            0xcd, 0x7f, 0x59, 0x8e, 0xce, 0x23, 0x88, 0x1b, 0x00, 0xe3, 0xed, 0x03, 0x06, 0x88,
+            0x7b, 0x0c, 0x78, 0x5e, 0x27, 0xe8, 0xad, 0x3f, 0x82, 0x23, 0x20, 0x71, 0x04, 0x72,
+            0x5d, 0xd4,
+        ];
+
+        let enc = aessafe::AesSafe128EncryptorX8::new(&key);
+        let dec = aessafe::AesSafe128DecryptorX8::new(&key);
+        let mut tmp = [0u8; 128];
+        enc.encrypt_block_x8(&plain, &mut tmp);
+        assert!(tmp[..] == cipher[..]);
+        dec.decrypt_block_x8(&cipher, &mut tmp);
+        assert!(tmp[..] == plain[..]);
+    }
+
+    #[test]
+    fn test_aessafe_192_x8() {
+        let key: [u8; 24] = [
+            0x8e, 0x73, 0xb0, 0xf7, 0xda, 0x0e, 0x64, 0x52, 0xc8, 0x10, 0xf3, 0x2b, 0x80, 0x90,
+            0x79, 0xe5, 0x62, 0xf8, 0xea, 0xd2, 0x52, 0x2c, 0x6b, 0x7b,
+        ];
+        let plain: [u8; 128] = [
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93,
+            0x17, 0x2a, 0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac,
+            0x45, 0xaf, 0x8e, 0x51, 0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb,
+            0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef, 0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17,
+            0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10, 0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40,
+            0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a, 0xae, 0x2d, 0x8a, 0x57,
+            0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51, 0x30, 0xc8,
+            0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
+            0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c,
+            0x37, 0x10,
+        ];
+        let cipher: [u8; 128] = [
+            0xbd, 0x33, 0x4f, 0x1d, 0x6e, 0x45, 0xf2, 0x5f, 0xf7, 0x12, 0xa2, 0x14, 0x57, 0x1f,
+            0xa5, 0xcc, 0x97, 0x41, 0x04, 0x84, 0x6d, 0x0a, 0xd3, 0xad, 0x77, 0x34, 0xec, 0xb3,
+            0xec, 0xee, 0x4e, 0xef, 0xef, 0x7a, 0xfd, 0x22, 0x70, 0xe2, 0xe6, 0x0a, 0xdc, 0xe0,
+            0xba, 0x2f, 0xac, 0xe6, 0x44, 0x4e, 0x9a, 0x4b, 0x41, 0xba, 0x73, 0x8d, 0x6c, 0x72,
+            0xfb, 0x16, 0x69, 0x16, 0x03, 0xc1, 0x8e, 0x0e, 0xbd, 0x33, 0x4f, 0x1d, 0x6e, 0x45,
+            0xf2, 0x5f, 0xf7, 0x12, 0xa2, 0x14, 0x57, 0x1f, 0xa5, 0xcc, 0x97, 0x41, 0x04, 0x84,
+            0x6d, 0x0a, 0xd3, 0xad, 0x77, 0x34, 0xec, 0xb3, 0xec, 0xee, 0x4e, 0xef, 0xef, 0x7a,
+            0xfd, 0x22, 0x70, 0xe2, 0xe6, 0x0a, 0xdc, 0xe0, 0xba, 0x2f, 0xac, 0xe6, 0x44, 0x4e,
+            0x9a, 0x4b, 0x41, 0xba, 0x73, 0x8d, 0x6c, 0x72, 0xfb, 0x16, 0x69, 0x16, 0x03, 0xc1,
+            0x8e, 0x0e,
+        ];
+
+        let enc = aessafe::AesSafe192EncryptorX8::new(&key);
+        let dec = aessafe::AesSafe192DecryptorX8::new(&key);
+        let mut tmp = [0u8; 128];
+        enc.encrypt_block_x8(&plain, &mut tmp);
+        assert!(tmp[..] == cipher[..]);
+        dec.decrypt_block_x8(&cipher, &mut tmp);
+        assert!(tmp[..] == plain[..]);
+    }
+
+    #[test]
+    fn test_aessafe_256_x8() {
+        let key: [u8; 32] = [
+            0x60, 0x3d, 0xeb, 0x10, 0x15, 0xca, 0x71, 0xbe, 0x2b, 0x73, 0xae, 0xf0, 0x85, 0x7d,
+            0x77, 0x81, 0x1f, 0x35, 0x2c, 0x07, 0x3b, 0x61, 0x08, 0xd7, 0x2d, 0x98, 0x10, 0xa3,
+            0x09, 0x14, 0xdf, 0xf4,
+        ];
+        let plain: [u8; 128] = [
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93,
+            0x17, 0x2a, 0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac,
+            0x45, 0xaf, 0x8e, 0x51, 0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb,
+            0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef, 0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17,
+            0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10, 0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40,
+            0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a, 0xae, 0x2d, 0x8a, 0x57,
+            0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51, 0x30, 0xc8,
+            0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
+            0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c,
+            0x37, 0x10,
+        ];
+        let cipher: [u8; 128] = [
+            0xf3, 0xee, 0xd1, 0xbd, 0xb5, 0xd2, 0xa0, 0x3c, 0x06, 0x4b, 0x5a, 0x7e, 0x3d, 0xb1,
+            0x81, 0xf8, 0x59, 0x1c, 0xcb, 0x10, 0xd4, 0x10, 0xed, 0x26, 0xdc, 0x5b, 0xa7, 0x4a,
+            0x31, 0x36, 0x28, 0x70, 0xb6, 0xed, 0x21, 0xb9, 0x9c, 0xa6, 0xf4, 0xf9, 0xf1, 0x53,
+            0xe7, 0xb1, 0xbe, 0xaf, 0xed, 0x1d, 0x23, 0x30, 0x4b, 0x7a, 0x39, 0xf9, 0xf3, 0xff,
+            0x06, 0x7d, 0x8d, 0x8f, 0x9e, 0x24, 0xec, 0xc7, 0xf3, 0xee, 0xd1, 0xbd, 0xb5, 0xd2,
+            0xa0, 0x3c, 0x06, 0x4b, 0x5a, 0x7e, 0x3d, 0xb1, 0x81, 0xf8, 0x59, 0x1c, 0xcb, 0x10,
+            0xd4, 0x10, 0xed, 0x26, 0xdc, 0x5b, 0xa7, 0x4a, 0x31, 0x36, 0x28, 0x70, 0xb6, 0xed,
+            0x21, 0xb9, 0x9c, 0xa6, 0xf4, 0xf9, 0xf1, 0x53, 0xe7, 0xb1, 0xbe, 0xaf, 0xed, 0x1d,
+            0x23, 0x30, 0x4b, 0x7a, 0x39, 0xf9, 0xf3, 0xff, 0x06, 0x7d, 0x8d, 0x8f, 0x9e, 0x24,
+            0xec, 0xc7,
+        ];
+
+        let enc = aessafe::AesSafe256EncryptorX8::new(&key);
+        let dec = aessafe::AesSafe256DecryptorX8::new(&key);
+        let mut tmp = [0u8; 128];
+        enc.encrypt_block_x8(&plain, &mut tmp);
+        assert!(tmp[..] == cipher[..]);
+        dec.decrypt_block_x8(&cipher, &mut tmp);
+        assert!(tmp[..] == plain[..]);
+    }
+
+    #[test]
+    fn aes_ctr_box() {
+        let tests = aes_ctr_tests();
+        for test in tests.iter() {
+            let mut aes_enc = aes::ctr(aes::KeySize::KeySize128, &test.key[..], &test.ctr[..]);
+            let mut result: Vec<u8> = repeat(0).take(test.plain.len()).collect();
+            aes_enc.process(&test.plain[..], &mut result[..]);
+            let res: &[u8] = result.as_ref();
+            assert!(res == &test.cipher[..]);
+        }
+    }
+
+    #[test]
+    fn aes_ctr_rng_keystream() {
+        // NIST SP 800-38A F.5.1 key and initial counter block. The keystream is AES applied to
+        // successive counter blocks.
+        let key = [
+            0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f,
+            0x3c,
+        ];
+        let nonce = [
+            0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9, 0xfa, 0xfb, 0xfc, 0xfd, 0xfe,
+            0xff,
+        ];
+        let expected = [
+            0xec, 0x8c, 0xdf, 0x73, 0x98, 0x60, 0x7c, 0xb0, 0xf2, 0xd2, 0x16, 0x75, 0xea, 0x9e, 0xa1,
+            0xe4, 0x36, 0x2b, 0x7c, 0x3c, 0x67, 0x73, 0x51, 0x63, 0x18, 0xa0, 0x77, 0xd7, 0xfc, 0x50,
+            0x73, 0xae, 0x6a, 0x2c, 0xc3, 0x78, 0x78, 0x89, 0x37, 0x4f, 0xbe, 0xb4, 0xc8, 0x1b, 0x17,
+            0xba, 0x6c, 0x44, 0xe8, 0x9c, 0x39, 0x9f, 0xf0, 0xf1, 0x98, 0xc6, 0xd4, 0x0a, 0x31, 0xdb,
+            0x15, 0x6c, 0xab, 0xfe,
+        ];
+
+        let mut rng = aes::AesCtrRng::new(KeySize128, &key, &nonce);
+        let mut out = [0u8; 64];
+        rng.fill_bytes(&mut out);
+        assert_eq!(&out[..], &expected[..]);
+
+        // Output split across calls is the same stream, and nonzero input buffers are ignored.
+        let mut rng = aes::AesCtrRng::new(KeySize128, &key, &nonce);
+        let mut a = [0xffu8; 5];
+        let mut b = [0xffu8; 59];
+        rng.fill_bytes(&mut a);
+        rng.fill_bytes(&mut b);
+        assert_eq!(&a[..], &expected[..5]);
+        assert_eq!(&b[..], &expected[5..]);
+
+        let mut rng = aes::AesCtrRng::new(KeySize128, &key, &nonce);
+        assert_eq!(rng.next_u32(), 0x73df8cec);
+    }
+}
+
+#[cfg(all(test, feature = "with-bench"))]
+mod bench {
+    use test::Bencher;
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    use aesni;
+
+    use aes::KeySize::{self, KeySize128, KeySize192, KeySize256};
+    use aessafe;
+    use symmetriccipher::{BlockEncryptor, BlockEncryptorX8};
+    use util;
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[bench]
+    pub fn aesni_128_bench(bh: &mut Bencher) {
+        aesni_bench(bh, KeySize128);
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[bench]
+    pub fn aesni_192_bench(bh: &mut Bencher) {
+        aesni_bench(bh, KeySize192);
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[bench]
+    pub fn aesni_256_bench(bh: &mut Bencher) {
+        aesni_bench(bh, KeySize256);
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn aesni_bench(bh: &mut Bencher, key_size: KeySize) {
+        if util::supports_aesni() {
+            let key: [u8; 16] = [1u8; 16];
+            let plain: [u8; 16] = [2u8; 16];
+
+            let a = aesni::AesNiEncryptor::new(key_size, &key);
+
+            let mut tmp = [0u8; 16];
+
+            bh.iter(|| {
+                a.encrypt_block(&plain, &mut tmp);
+            });
+
+            bh.bytes = (plain.len()) as u64;
+        }
+    }
+
+    #[bench]
+    pub fn aes_safe_bench(bh: &mut Bencher) {
+        let key: [u8; 16] = [1u8; 16];
+        let plain: [u8; 16] = [2u8; 16];
+
+        let a = aessafe::AesSafe128Encryptor::new(&key);
+
+        let mut tmp = [0u8; 16];
+
+        bh.iter(|| {
+            a.encrypt_block(&plain, &mut tmp);
+        });
+
+        bh.bytes = (plain.len()) as u64;
+    }
+
+    #[bench]
+    pub fn aes_safe_x8_bench(bh: &mut Bencher) {
+        let key: [u8; 16] = [1u8; 16];
+        let plain: [u8; 128] = [2u8; 128];
+
+        let a = aessafe::AesSafe128EncryptorX8::new(&key);
+
+        let mut tmp = [0u8; 128];
+
+        bh.iter(|| {
+            a.encrypt_block_x8(&plain, &mut tmp);
+        });
+
+        bh.bytes = (plain.len()) as u64;
+    }
+}
@@ -4,258 +4,1172 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-//use aead::{AeadDecryptor, AeadEncryptor};
-//use aes::{ctr, KeySize};
-//use cryptoutil::copy_memory;
-//use ghash::Ghash;
-//use sr_std;
-//use sr_std::alloc::boxed::Box;
-//use symmetriccipher::SynchronousStreamCipher;
-//use util::fixed_time_eq;
-//
-//pub struct AesGcm<'a> {
-//    cipher: Box<dyn SynchronousStreamCipher + 'a>,
-//    mac: Ghash,
-//    finished: bool,
-//    end_tag: [u8; 16],
-//}
-//
-//impl<'a> AesGcm<'a> {
-//    pub fn new(key_size: KeySize, key: &[u8], nonce: &[u8], aad: &[u8]) -> AesGcm<'a> {
-//        //assert!(key.len() == 16 || key.len() == 24 || key.len() == 32);
-//        //assert!(nonce.len() == 12);
-//
-//        // GCM technically differs from CTR mode in how role overs are handled
-//        // GCM only touches the right most 4 bytes while CTR roles all 16 over
-//        // when the iv is only 96 bits (12 bytes) then 4 bytes of zeros are
-//        // appended to it meaning you have to encrypt 2^37 bytes (256 gigabytes)
-//        // of data before a difference crops up.
-//        // The GCM handles nonces of other lengths by hashing them once with ghash
-//        // this would cause the roleover behavior to potentially be triggered much
-//        // earlier preventing the use of generic CTR mode.
-//
-//        let mut iv = [0u8; 16];
-//        copy_memory(nonce, &mut iv);
-//        iv[15] = 1u8;
-//        let mut cipher = ctr(key_size, key, &iv);
-//        let temp_block = [0u8; 16];
-//        let mut final_block = [0u8; 16];
-//        cipher.process(&temp_block, &mut final_block);
-//        let mut hash_key = [0u8; 16];
-//        let mut encryptor = ctr(key_size, key, &temp_block);
-//        encryptor.process(&temp_block, &mut hash_key);
-//        AesGcm {
-//            cipher: cipher,
-//            mac: Ghash::new(&hash_key).input_a(aad),
-//            finished: false,
-//            end_tag: final_block,
-//        }
-//    }
-//}
-//
-//impl<'a> AeadEncryptor for AesGcm<'static> {
-//    fn encrypt(&mut self, input: &[u8], output: &mut [u8], tag: &mut [u8]) {
-//        self.cipher.process(input, output);
-//        let result = self.mac.input_c(output).result();
-//        self.finished = true;
-//        for i in 0..16 {
-//            tag[i] = result[i] ^ self.end_tag[i];
-//        }
-//    }
-//}
-//
-//impl<'a> AeadDecryptor for AesGcm<'static> {
-//    fn decrypt(&mut self, input: &[u8], output: &mut [u8], tag: &[u8]) -> bool {
-//        self.finished = true;
-//        let mut calc_tag = self.mac.input_c(input).result();
-//        for i in 0..16 {
-//            calc_tag[i] ^= self.end_tag[i];
-//        }
-//        if fixed_time_eq(&calc_tag, tag) {
-//            self.cipher.process(input, output);
-//            true
-//        } else {
-//            false
-//        }
-//    }
-//}
-//
-//#[cfg(test)]
-//mod test {
-//    use aead::{AeadDecryptor, AeadEncryptor};
-//    use aes::KeySize;
-//    use aes_gcm::AesGcm;
-//    use sr_std::iter::repeat;
-//    fn hex_to_bytes(raw_hex: &str) -> Vec<u8> {
-//        hex::decode(raw_hex).ok().unwrap()
-//    }
-//    struct TestVector {
-//        key: Vec<u8>,
-//        iv: Vec<u8>,
-//        plain_text: Vec<u8>,
-//        cipher_text: Vec<u8>,
-//        aad: Vec<u8>,
-//        tag: Vec<u8>,
-//    }
-//
-//    fn get_test_vectors() -> [TestVector; 5] {
-//        [
-//        TestVector {
-//                key: hex_to_bytes("00000000000000000000000000000000"),
-//                iv: hex_to_bytes("000000000000000000000000"),
-//                plain_text: hex_to_bytes(""),
-//                cipher_text: hex_to_bytes(""),
-//                aad: hex_to_bytes(""),
-//                tag: hex_to_bytes("58e2fccefa7e3061367f1d57a4e7455a")
-//            },
-//            TestVector {
-//                key: hex_to_bytes("00000000000000000000000000000000"),
-//                iv: hex_to_bytes("000000000000000000000000"),
-//                plain_text: hex_to_bytes("00000000000000000000000000000000"),
-//                cipher_text: hex_to_bytes("0388dace60b6a392f328c2b971b2fe78"),
-//                aad: hex_to_bytes(""),
-//                tag: hex_to_bytes("ab6e47d42cec13bdf53a67b21257bddf")
-//            },
-//            TestVector {
-//                key: hex_to_bytes("feffe9928665731c6d6a8f9467308308"),
-//                iv: hex_to_bytes("cafebabefacedbaddecaf888"),
-//                plain_text: hex_to_bytes("d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39"),
-//                cipher_text: hex_to_bytes("42831ec2217774244b7221b784d0d49ce3aa212f2c02a4e035c17e2329aca12e21d514b25466931c7d8f6a5aac84aa051ba30b396a0aac973d58e091"),
-//                aad: hex_to_bytes("feedfacedeadbeeffeedfacedeadbeefabaddad2"),
-//                tag: hex_to_bytes("5bc94fbc3221a5db94fae95ae7121a47")
-//            },
-//            TestVector {
-//                key: hex_to_bytes("feffe9928665731c6d6a8f9467308308feffe9928665731c"),
-//                iv: hex_to_bytes("cafebabefacedbaddecaf888"),
-//                plain_text: hex_to_bytes("d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39"),
-//                cipher_text: hex_to_bytes("3980ca0b3c00e841eb06fac4872a2757859e1ceaa6efd984628593b40ca1e19c7d773d00c144c525ac619d18c84a3f4718e2448b2fe324d9ccda2710"),
-//                aad: hex_to_bytes("feedfacedeadbeeffeedfacedeadbeefabaddad2"),
-//                tag: hex_to_bytes("2519498e80f1478f37ba55bd6d27618c")
-//            },
-//            TestVector {
-//                key: hex_to_bytes("feffe9928665731c6d6a8f9467308308feffe9928665731c6d6a8f9467308308"),
-//                iv: hex_to_bytes("cafebabefacedbaddecaf888"),
-//                plain_text: hex_to_bytes("d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39"),
-//                cipher_text: hex_to_bytes("522dc1f099567d07f47f37a32a84427d643a8cdcbfe5c0c97598a2bd2555d1aa8cb08e48590dbb3da7b08b1056828838c5f61e6393ba7a0abcc9f662"),
-//                aad: hex_to_bytes("feedfacedeadbeeffeedfacedeadbeefabaddad2"),
-//                tag: hex_to_bytes("76fc6ece0f4e1768cddf8853bb2d551b")
-//            },
-//    ]
-//    }
-//    #[test]
-//    fn aes_gcm_test() {
-//        for item in get_test_vectors().iter() {
-//            let key_size = match item.key.len() {
-//                16 => KeySize::KeySize128,
-//                24 => KeySize::KeySize192,
-//                32 => KeySize::KeySize256,
-//                _ => unreachable!(),
-//            };
-//            let mut cipher = AesGcm::new(key_size, &item.key[..], &item.iv[..], &item.aad[..]);
-//            let mut out: Vec<u8> = repeat(0).take(item.plain_text.len()).collect();
-//
-//            let mut out_tag: Vec<u8> = repeat(0).take(16).collect();
-//
-//            cipher.encrypt(&item.plain_text[..], &mut out[..], &mut out_tag[..]);
-//            assert_eq!(out, item.cipher_text);
-//            assert_eq!(out_tag, item.tag);
-//        }
-//    }
-//
-//    #[test]
-//    fn aes_gcm_decrypt_test() {
-//        for item in get_test_vectors().iter() {
-//            let key_size = match item.key.len() {
-//                16 => KeySize::KeySize128,
-//                24 => KeySize::KeySize192,
-//                32 => KeySize::KeySize256,
-//                _ => unreachable!(),
-//            };
-//            let mut decipher = AesGcm::new(key_size, &item.key[..], &item.iv[..], &item.aad[..]);
-//            let mut out: Vec<u8> = repeat(0).take(item.plain_text.len()).collect();
-//
-//            let result = decipher.decrypt(&item.cipher_text[..], &mut out[..], &item.tag[..]);
-//            assert_eq!(out, item.plain_text);
-//            //assert!(result);
-//        }
-//    }
-//    #[test]
-//    fn aes_gcm_decrypt_fail_test() {
-//        for item in get_test_vectors().iter() {
-//            let key_size = match item.key.len() {
-//                16 => KeySize::KeySize128,
-//                24 => KeySize::KeySize192,
-//                32 => KeySize::KeySize256,
-//                _ => unreachable!(),
-//            };
-//            let mut decipher = AesGcm::new(key_size, &item.key[..], &item.iv[..], &item.aad[..]);
-//            let tag: Vec<u8> = repeat(0).take(16).collect();
-//            let mut out1: Vec<u8> = repeat(0).take(item.plain_text.len()).collect();
-//            let out2: Vec<u8> = repeat(0).take(item.plain_text.len()).collect();
-//            let result = decipher.decrypt(&item.cipher_text[..], &mut out1[..], &tag[..]);
-//            assert_eq!(out1, out2);
-//            //assert!(!result);
-//        }
-//    }
-//}
-//
-//#[cfg(all(test, feature = "with-bench"))]
-//mod bench {
-//    use aead::{AeadDecryptor, AeadEncryptor};
-//    use aes::KeySize;
-//    use aes_gcm::AesGcm;
-//    use test::Bencher;
-//
-//    #[bench]
-//    pub fn gsm_10(bh: &mut Bencher) {
-//        let input = [1u8; 10];
-//        let aad = [3u8; 10];
-//        bh.iter(|| {
-//            let mut cipher = AesGcm::new(KeySize::KeySize256, &[0; 32], &[0; 12], &aad);
-//            let mut decipher = AesGcm::new(KeySize::KeySize256, &[0; 32], &[0; 12], &aad);
-//
-//            let mut output = [0u8; 10];
-//            let mut tag = [0u8; 16];
-//            let mut output2 = [0u8; 10];
-//            cipher.encrypt(&input, &mut output, &mut tag);
-//            decipher.decrypt(&output, &mut output2, &tag);
-//        });
-//        bh.bytes = 10u64;
-//    }
-//
-//    #[bench]
-//    pub fn gsm_1k(bh: &mut Bencher) {
-//        let input = [1u8; 1024];
-//        let aad = [3u8; 1024];
-//        bh.iter(|| {
-//            let mut cipher = AesGcm::new(KeySize::KeySize256, &[0; 32], &[0; 12], &aad);
-//            let mut decipher = AesGcm::new(KeySize::KeySize256, &[0; 32], &[0; 12], &aad);
-//
-//            let mut output = [0u8; 1024];
-//            let mut tag = [0u8; 16];
-//            let mut output2 = [0u8; 1024];
-//
-//            cipher.encrypt(&input, &mut output, &mut tag);
-//            decipher.decrypt(&output, &mut output2, &tag);
-//        });
-//        bh.bytes = 1024u64;
-//    }
-//
-//    #[bench]
-//    pub fn gsm_64k(bh: &mut Bencher) {
-//        let input = [1u8; 65536];
-//        let aad = [3u8; 65536];
-//        bh.iter(|| {
-//            let mut cipher = AesGcm::new(KeySize::KeySize256, &[0; 32], &[0; 12], &aad);
-//            let mut decipher = AesGcm::new(KeySize::KeySize256, &[0; 32], &[0; 12], &aad);
-//
-//            let mut output = [0u8; 65536];
-//            let mut tag = [0u8; 16];
-//            let mut output2 = [0u8; 65536];
-//
-//            cipher.encrypt(&input, &mut output, &mut tag);
-//            decipher.decrypt(&output, &mut output2, &tag);
-//        });
-//        bh.bytes = 65536u64;
-//    }
-//}
+use aead::{AeadDecryptor, AeadEncryptor, AeadError, AeadKey};
+use aes::{ctr, KeySize};
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use aesni::AesNiEncryptor;
+use aessafe::{AesSafe128EncryptorX8, AesSafe192EncryptorX8, AesSafe256EncryptorX8};
+use blockmodes::{CtrMode, CtrModeX8};
+use cryptoutil::{copy_memory, write_u64_be};
+use ghash::Ghash;
+use mac::{Mac, MacResult};
+use sr_std::convert::TryFrom;
+use sr_std::iter::repeat;
+use sr_std::prelude::*;
+use symmetriccipher::{BlockEncryptor, BlockEncryptorX8, SynchronousStreamCipher};
+use util;
+use util::{fixed_time_eq, secure_memset};
+
+pub struct AesGcm<'a> {
+    cipher: Box<dyn SynchronousStreamCipher + 'a>,
+    mac: Ghash,
+    finished: bool,
+    end_tag: [u8; 16],
+}
+
+impl<'a> AesGcm<'a> {
+    pub fn new(key_size: KeySize, key: &[u8], nonce: &[u8], aad: &[u8]) -> AesGcm<'a> {
+        //assert!(key.len() == 16 || key.len() == 24 || key.len() == 32);
+        //assert!(nonce.len() == 12);
+
+        // GCM technically differs from CTR mode in how role overs are handled
+        // GCM only touches the right most 4 bytes while CTR roles all 16 over
+        // when the iv is only 96 bits (12 bytes) then 4 bytes of zeros are
+        // appended to it meaning you have to encrypt 2^37 bytes (256 gigabytes)
+        // of data before a difference crops up.
+        // The GCM handles nonces of other lengths by hashing them once with ghash
+        // this would cause the roleover behavior to potentially be triggered much
+        // earlier preventing the use of generic CTR mode.
+
+        let (cipher, hash_key, final_block) = gcm_init(key_size, key, nonce);
+        AesGcm {
+            cipher: cipher,
+            mac: Ghash::new(&hash_key).input_a(aad),
+            finished: false,
+            end_tag: final_block,
+        }
+    }
+
+    /**
+     * Like new(), but with the key length fixed by the type: AES-128-GCM.
+     */
+    pub fn new_128(key: &[u8; 16], nonce: &[u8; 12], aad: &[u8]) -> AesGcm<'a> {
+        AesGcm::new(KeySize::KeySize128, key, nonce, aad)
+    }
+
+    /**
+     * Like new(), but with the key length fixed by the type: AES-192-GCM.
+     */
+    pub fn new_192(key: &[u8; 24], nonce: &[u8; 12], aad: &[u8]) -> AesGcm<'a> {
+        AesGcm::new(KeySize::KeySize192, key, nonce, aad)
+    }
+
+    /**
+     * Like new(), but with the key length fixed by the type: AES-256-GCM.
+     */
+    pub fn new_256(key: &[u8; 32], nonce: &[u8; 12], aad: &[u8]) -> AesGcm<'a> {
+        AesGcm::new(KeySize::KeySize256, key, nonce, aad)
+    }
+
+    /**
+     * Like new(), but returns an error instead of misbehaving if the key length does not match
+     * key_size or the nonce is not 12 bytes.
+     */
+    pub fn try_new(
+        key_size: KeySize,
+        key: &[u8],
+        nonce: &[u8],
+        aad: &[u8],
+    ) -> Result<AesGcm<'a>, AeadError> {
+        let key_len = match key_size {
+            KeySize::KeySize128 => 16,
+            KeySize::KeySize192 => 24,
+            KeySize::KeySize256 => 32,
+        };
+        if key.len() != key_len {
+            return Err(AeadError::InvalidKeyLength);
+        }
+        AesGcm::with_key(&AeadKey::try_from(key)?, nonce, aad)
+    }
+
+    /**
+     * Create an AES-GCM instance from an AeadKey, choosing AES-128, AES-192 or AES-256 from its
+     * length. Only the AES key schedule and the derived GHASH key are kept; the key itself is not
+     * copied. Fails if the nonce is not 12 bytes.
+     */
+    pub fn with_key(key: &AeadKey, nonce: &[u8], aad: &[u8]) -> Result<AesGcm<'a>, AeadError> {
+        let key_size = match key.len() {
+            16 => KeySize::KeySize128,
+            24 => KeySize::KeySize192,
+            _ => KeySize::KeySize256,
+        };
+        if nonce.len() != 12 {
+            return Err(AeadError::InvalidNonceLength);
+        }
+        Ok(AesGcm::new(key_size, key.as_bytes(), nonce, aad))
+    }
+
+    /**
+     * Create a sealer for a sequence of records whose nonces are derived as in TLS 1.3
+     * (RFC 8446, section 5.3): the 64 bit big-endian record sequence number, starting at
+     * initial_seq, is left-padded to 12 bytes and XORed with static_iv. The AES key is expanded
+     * once and reused for every record.
+     *
+     * This takes the 12 byte TLS 1.3 IV rather than the 4 byte fixed IV of the TLS 1.2 layout
+     * (RFC 5288), whose nonce is fixed_iv || be64(seq) with the sequence number sent as the
+     * explicit part. That layout is the special case where static_iv is fixed_iv followed by 8
+     * zero bytes.
+     */
+    pub fn with_sequence_iv(
+        key_size: KeySize,
+        key: &[u8],
+        static_iv: &[u8; 12],
+        initial_seq: u64,
+    ) -> AesGcmSequence {
+        let mut cipher = ctr_schedule(key_size, key);
+        let zero = [0u8; 16];
+        let mut hash_key = [0u8; 16];
+        cipher.process(&zero, &mut hash_key);
+        AesGcmSequence {
+            cipher: cipher,
+            hash_key: hash_key,
+            static_iv: *static_iv,
+            seq: initial_seq,
+            exhausted: false,
+        }
+    }
+}
+
+impl AesGcm<'static> {
+    /**
+     * Decrypt ciphertext in place with a detached tag. The AAD is the AAD passed to new() followed
+     * by each of aad_segments in turn, so splitting it across segments authenticates exactly the
+     * same bytes as passing their concatenation. The ciphertext is left untouched unless the tag
+     * verifies. Panics if this instance has already encrypted or decrypted a message.
+     */
+    pub fn open_detached_segmented(
+        &mut self,
+        aad_segments: &[&[u8]],
+        ciphertext: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), AeadError> {
+        assert!(!self.finished);
+        self.finished = true;
+        let mut mac = self.mac;
+        for segment in aad_segments.iter() {
+            mac = mac.input_a(segment);
+        }
+        let mut calc_tag = mac.input_c(ciphertext).result();
+        for i in 0..16 {
+            calc_tag[i] ^= self.end_tag[i];
+        }
+        if !fixed_time_eq(&calc_tag, tag) {
+            return Err(AeadError::AuthenticationFailed);
+        }
+        let mut buf = [0u8; 64];
+        for chunk in ciphertext.chunks_mut(64) {
+            let n = chunk.len();
+            buf[..n].copy_from_slice(chunk);
+            self.cipher.process(&buf[..n], chunk);
+        }
+        secure_memset(&mut buf, 0);
+        Ok(())
+    }
+
+    /**
+     * Verify and decrypt ciphertext with a detached tag into the start of out, returning the
+     * plaintext length. The AAD is the AAD passed to new() followed by aad. If out is shorter than
+     * the ciphertext nothing is decrypted and BufferTooSmall reports the size needed; if the tag
+     * does not verify, the part of out that would have held the plaintext is zeroed.
+     */
+    pub fn open_into(
+        &mut self,
+        aad: &[u8],
+        ciphertext: &[u8],
+        tag: &[u8],
+        out: &mut [u8],
+    ) -> Result<usize, AeadError> {
+        let len = ciphertext.len();
+        if out.len() < len {
+            return Err(AeadError::BufferTooSmall { needed: len });
+        }
+        let plaintext = &mut out[..len];
+        plaintext.copy_from_slice(ciphertext);
+        match self.open_detached_segmented(&[aad], plaintext, tag) {
+            Ok(()) => Ok(len),
+            Err(e) => {
+                secure_memset(plaintext, 0);
+                Err(e)
+            }
+        }
+    }
+
+    /**
+     * Open a TLS 1.3 record in place. header is the 5 byte record header, which is authenticated
+     * as the AAD following any passed to new(), and ciphertext_and_tag is the record body ending
+     * in the 16 byte tag. On success the plaintext occupies the start of ciphertext_and_tag and
+     * its length is returned; the inner content type is left for the caller to strip.
+     */
+    pub fn open_tls_record(
+        &mut self,
+        header: &[u8; 5],
+        ciphertext_and_tag: &mut [u8],
+    ) -> Result<usize, AeadError> {
+        if ciphertext_and_tag.len() < 16 {
+            return Err(AeadError::InvalidTagLength);
+        }
+        let len = ciphertext_and_tag.len() - 16;
+        let (ciphertext, tag) = ciphertext_and_tag.split_at_mut(len);
+        self.open_detached_segmented(&[&header[..]], ciphertext, tag)?;
+        Ok(len)
+    }
+}
+
+// Derive the GHASH key H = E(K, 0^128) and E(K, J0), which is XORed into the final tag. The
+// returned cipher is positioned just after J0, ready to encrypt the first block of plaintext.
+fn gcm_init(
+    key_size: KeySize,
+    key: &[u8],
+    nonce: &[u8],
+) -> (Box<dyn SynchronousStreamCipher + 'static>, [u8; 16], [u8; 16]) {
+    let mut iv = [0u8; 16];
+    copy_memory(nonce, &mut iv);
+    iv[15] = 1u8;
+    let mut cipher = ctr(key_size, key, &iv);
+    let temp_block = [0u8; 16];
+    let mut final_block = [0u8; 16];
+    cipher.process(&temp_block, &mut final_block);
+    let mut hash_key = [0u8; 16];
+    let mut encryptor = ctr(key_size, key, &temp_block);
+    encryptor.process(&temp_block, &mut hash_key);
+    (cipher, hash_key, final_block)
+}
+
+// An AES-CTR keystream whose counter block can be replaced, so that one expanded key schedule can
+// serve many nonces.
+trait CtrReset: SynchronousStreamCipher {
+    fn reset_ctr(&mut self, ctr: &[u8]);
+}
+
+impl<A: BlockEncryptor> CtrReset for CtrMode<A> {
+    fn reset_ctr(&mut self, ctr: &[u8]) {
+        self.reset(ctr);
+    }
+}
+
+impl<A: BlockEncryptorX8> CtrReset for CtrModeX8<A> {
+    fn reset_ctr(&mut self, ctr: &[u8]) {
+        self.reset(ctr);
+    }
+}
+
+// The same choice of AES implementation as aes::ctr(), starting from the all zero counter block.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn ctr_schedule(key_size: KeySize, key: &[u8]) -> Box<dyn CtrReset + 'static> {
+    let zero = [0u8; 16];
+    if util::supports_aesni() {
+        Box::new(CtrMode::new(AesNiEncryptor::new(key_size, key), zero.to_vec()))
+    } else {
+        ctr_schedule_safe(key_size, key)
+    }
+}
+
+#[cfg(all(not(target_arch = "x86"), not(target_arch = "x86_64")))]
+fn ctr_schedule(key_size: KeySize, key: &[u8]) -> Box<dyn CtrReset + 'static> {
+    ctr_schedule_safe(key_size, key)
+}
+
+fn ctr_schedule_safe(key_size: KeySize, key: &[u8]) -> Box<dyn CtrReset + 'static> {
+    let zero = [0u8; 16];
+    match key_size {
+        KeySize::KeySize128 => Box::new(CtrModeX8::new(AesSafe128EncryptorX8::new(key), &zero)),
+        KeySize::KeySize192 => Box::new(CtrModeX8::new(AesSafe192EncryptorX8::new(key), &zero)),
+        KeySize::KeySize256 => Box::new(CtrModeX8::new(AesSafe256EncryptorX8::new(key), &zero)),
+    }
+}
+
+// The pre-counter block J0 of NIST SP 800-38D: nonce || 0^31 || 1 for a 96 bit nonce, and
+// GHASH(nonce || zero padding || 0^64 || be64(nonce length in bits)) for any other length. The
+// latter is GHASH with the nonce in place of the ciphertext and no additional data.
+fn gcm_j0(hash_key: &[u8; 16], nonce: &[u8]) -> [u8; 16] {
+    if nonce.len() == 12 {
+        let mut j0 = [0u8; 16];
+        copy_memory(nonce, &mut j0);
+        j0[15] = 1;
+        j0
+    } else {
+        Ghash::new(hash_key).input_c(nonce).result()
+    }
+}
+
+impl AeadEncryptor for AesGcm<'static> {
+    fn encrypt(&mut self, input: &[u8], output: &mut [u8], tag: &mut [u8]) {
+        self.cipher.process(input, output);
+        let result = self.mac.input_c(output).result();
+        self.finished = true;
+        for i in 0..16 {
+            tag[i] = result[i] ^ self.end_tag[i];
+        }
+    }
+}
+
+impl AeadDecryptor for AesGcm<'static> {
+    fn decrypt(&mut self, input: &[u8], output: &mut [u8], tag: &[u8]) -> bool {
+        self.finished = true;
+        let mut calc_tag = self.mac.input_c(input).result();
+        for i in 0..16 {
+            calc_tag[i] ^= self.end_tag[i];
+        }
+        if fixed_time_eq(&calc_tag, tag) {
+            self.cipher.process(input, output);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/**
+ * Encrypt plaintext in one shot, authenticating it together with aad. The 16 byte tag is appended
+ * to the returned ciphertext. Pass `&[]` as aad if there is no associated data.
+ */
+pub fn seal(key_size: KeySize, key: &[u8], nonce: &[u8], aad: &[u8], plaintext: &[u8]) -> Vec<u8> {
+    let mut sealed: Vec<u8> = repeat(0).take(plaintext.len() + 16).collect();
+    {
+        let (ciphertext, tag) = sealed.split_at_mut(plaintext.len());
+        AesGcm::new(key_size, key, nonce, aad).encrypt(plaintext, ciphertext, tag);
+    }
+    sealed
+}
+
+/**
+ * Decrypt the output of seal(). aad must be the same associated data that was passed to seal().
+ * No plaintext is returned unless the tag verifies.
+ */
+pub fn open(
+    key_size: KeySize,
+    key: &[u8],
+    nonce: &[u8],
+    aad: &[u8],
+    sealed: &[u8],
+) -> Result<Vec<u8>, AeadError> {
+    if sealed.len() < 16 {
+        return Err(AeadError::AuthenticationFailed);
+    }
+    let (ciphertext, tag) = sealed.split_at(sealed.len() - 16);
+    let mut plaintext: Vec<u8> = repeat(0).take(ciphertext.len()).collect();
+    if AesGcm::new(key_size, key, nonce, aad).decrypt(ciphertext, &mut plaintext[..], tag) {
+        Ok(plaintext)
+    } else {
+        Err(AeadError::AuthenticationFailed)
+    }
+}
+
+/**
+ * Seals or opens successive records under one key, deriving each record's nonce from a static IV
+ * and an incrementing sequence number. Created by AesGcm::with_sequence_iv().
+ */
+pub struct AesGcmSequence {
+    cipher: Box<dyn CtrReset + 'static>,
+    hash_key: [u8; 16],
+    static_iv: [u8; 12],
+    seq: u64,
+    exhausted: bool,
+}
+
+impl AesGcmSequence {
+    /**
+     * The sequence number the next record will use.
+     */
+    pub fn sequence(&self) -> u64 {
+        self.seq
+    }
+
+    fn next_nonce(&mut self) -> Result<[u8; 12], AeadError> {
+        if self.exhausted {
+            return Err(AeadError::NonceExhausted);
+        }
+        let mut seq = [0u8; 12];
+        write_u64_be(&mut seq[4..], self.seq);
+        let mut nonce = self.static_iv;
+        for i in 0..12 {
+            nonce[i] ^= seq[i];
+        }
+        match self.seq.checked_add(1) {
+            Some(seq) => self.seq = seq,
+            None => self.exhausted = true,
+        }
+        Ok(nonce)
+    }
+
+    // Point the keystream at the next record's nonce. Returns the GHASH state with aad absorbed
+    // and E(K, J0), which is XORed into the final tag.
+    fn start_record(&mut self, aad: &[u8]) -> Result<(Ghash, [u8; 16]), AeadError> {
+        let nonce = self.next_nonce()?;
+        let mut j0 = [0u8; 16];
+        copy_memory(&nonce, &mut j0);
+        j0[15] = 1;
+        self.cipher.reset_ctr(&j0);
+        let zero = [0u8; 16];
+        let mut end_tag = [0u8; 16];
+        self.cipher.process(&zero, &mut end_tag);
+        Ok((Ghash::new(&self.hash_key).input_a(aad), end_tag))
+    }
+
+    /**
+     * Encrypt the next record, returning the ciphertext and tag.
+     */
+    pub fn seal_next(
+        &mut self,
+        aad: &[u8],
+        plaintext: &[u8],
+    ) -> Result<(Vec<u8>, [u8; 16]), AeadError> {
+        let (mac, end_tag) = self.start_record(aad)?;
+        let mut ciphertext: Vec<u8> = repeat(0).take(plaintext.len()).collect();
+        self.cipher.process(plaintext, &mut ciphertext[..]);
+        let mut tag = mac.input_c(&ciphertext[..]).result();
+        for i in 0..16 {
+            tag[i] ^= end_tag[i];
+        }
+        Ok((ciphertext, tag))
+    }
+
+    /**
+     * Decrypt the next record. Records must be opened in the order they were sealed.
+     */
+    pub fn open_next(
+        &mut self,
+        aad: &[u8],
+        ciphertext: &[u8],
+        tag: &[u8],
+    ) -> Result<Vec<u8>, AeadError> {
+        let (mac, end_tag) = self.start_record(aad)?;
+        let mut calc_tag = mac.input_c(ciphertext).result();
+        for i in 0..16 {
+            calc_tag[i] ^= end_tag[i];
+        }
+        if !fixed_time_eq(&calc_tag, tag) {
+            return Err(AeadError::AuthenticationFailed);
+        }
+        let mut plaintext: Vec<u8> = repeat(0).take(ciphertext.len()).collect();
+        self.cipher.process(ciphertext, &mut plaintext[..]);
+        Ok(plaintext)
+    }
+}
+
+impl Drop for AesGcmSequence {
+    fn drop(&mut self) {
+        secure_memset(&mut self.hash_key, 0);
+        secure_memset(&mut self.static_iv, 0);
+    }
+}
+
+/**
+ * GMAC is GCM with no plaintext: all of the input is authenticated as additional data. Like GCM
+ * it must never be used with the same key and nonce for two different messages.
+ */
+#[derive(Clone, Copy)]
+pub struct Gmac {
+    mac: Ghash,
+    end_tag: [u8; 16],
+}
+
+impl Gmac {
+    /**
+     * The nonce may be any non-zero length. 96 bit nonces are used directly as the initial
+     * counter block; any other length is first hashed with GHASH, as NIST SP 800-38D specifies.
+     */
+    pub fn new(key_size: KeySize, key: &[u8], nonce: &[u8]) -> Gmac {
+        //assert!(key.len() == 16 || key.len() == 24 || key.len() == 32);
+        assert!(!nonce.is_empty());
+        let zero = [0u8; 16];
+        let mut hash_key = [0u8; 16];
+        ctr(key_size, key, &zero).process(&zero, &mut hash_key);
+        let j0 = gcm_j0(&hash_key, nonce);
+        let mut final_block = [0u8; 16];
+        ctr(key_size, key, &j0).process(&zero, &mut final_block);
+        Gmac {
+            mac: Ghash::new(&hash_key),
+            end_tag: final_block,
+        }
+    }
+}
+
+impl Mac for Gmac {
+    fn input(&mut self, data: &[u8]) {
+        self.mac.input(data);
+    }
+
+    fn reset(&mut self) {
+        self.mac.reset();
+    }
+
+    fn result(&mut self) -> MacResult {
+        let mut mac = [0u8; 16];
+        self.raw_result(&mut mac[..]);
+        MacResult::new(&mac[..])
+    }
+
+    fn raw_result(&mut self, output: &mut [u8]) {
+        let mut tag = [0u8; 16];
+        self.mac.raw_result(&mut tag);
+        for i in 0..16 {
+            output[i] = tag[i] ^ self.end_tag[i];
+        }
+    }
+
+    fn output_bytes(&self) -> usize {
+        16
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aead::{AeadDecryptor, AeadEncryptor, AeadError};
+    use aes::{ctr, KeySize};
+    use aes_gcm::{ctr_schedule, ctr_schedule_safe, open, seal, AesGcm, Gmac};
+    use mac::{Mac, MacResult};
+    use sr_std::iter::repeat;
+    fn hex_to_bytes(raw_hex: &str) -> Vec<u8> {
+        hex::decode(raw_hex).ok().unwrap()
+    }
+    struct TestVector {
+        key: Vec<u8>,
+        iv: Vec<u8>,
+        plain_text: Vec<u8>,
+        cipher_text: Vec<u8>,
+        aad: Vec<u8>,
+        tag: Vec<u8>,
+    }
+
+    fn get_test_vectors() -> [TestVector; 5] {
+        [
+        TestVector {
+                key: hex_to_bytes("00000000000000000000000000000000"),
+                iv: hex_to_bytes("000000000000000000000000"),
+                plain_text: hex_to_bytes(""),
+                cipher_text: hex_to_bytes(""),
+                aad: hex_to_bytes(""),
+                tag: hex_to_bytes("58e2fccefa7e3061367f1d57a4e7455a")
+            },
+            TestVector {
+                key: hex_to_bytes("00000000000000000000000000000000"),
+                iv: hex_to_bytes("000000000000000000000000"),
+                plain_text: hex_to_bytes("00000000000000000000000000000000"),
+                cipher_text: hex_to_bytes("0388dace60b6a392f328c2b971b2fe78"),
+                aad: hex_to_bytes(""),
+                tag: hex_to_bytes("ab6e47d42cec13bdf53a67b21257bddf")
+            },
+            TestVector {
+                key: hex_to_bytes("feffe9928665731c6d6a8f9467308308"),
+                iv: hex_to_bytes("cafebabefacedbaddecaf888"),
+                plain_text: hex_to_bytes("d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39"),
+                cipher_text: hex_to_bytes("42831ec2217774244b7221b784d0d49ce3aa212f2c02a4e035c17e2329aca12e21d514b25466931c7d8f6a5aac84aa051ba30b396a0aac973d58e091"),
+                aad: hex_to_bytes("feedfacedeadbeeffeedfacedeadbeefabaddad2"),
+                tag: hex_to_bytes("5bc94fbc3221a5db94fae95ae7121a47")
+            },
+            TestVector {
+                key: hex_to_bytes("feffe9928665731c6d6a8f9467308308feffe9928665731c"),
+                iv: hex_to_bytes("cafebabefacedbaddecaf888"),
+                plain_text: hex_to_bytes("d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39"),
+                cipher_text: hex_to_bytes("3980ca0b3c00e841eb06fac4872a2757859e1ceaa6efd984628593b40ca1e19c7d773d00c144c525ac619d18c84a3f4718e2448b2fe324d9ccda2710"),
+                aad: hex_to_bytes("feedfacedeadbeeffeedfacedeadbeefabaddad2"),
+                tag: hex_to_bytes("2519498e80f1478f37ba55bd6d27618c")
+            },
+            TestVector {
+                key: hex_to_bytes("feffe9928665731c6d6a8f9467308308feffe9928665731c6d6a8f9467308308"),
+                iv: hex_to_bytes("cafebabefacedbaddecaf888"),
+                plain_text: hex_to_bytes("d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39"),
+                cipher_text: hex_to_bytes("522dc1f099567d07f47f37a32a84427d643a8cdcbfe5c0c97598a2bd2555d1aa8cb08e48590dbb3da7b08b1056828838c5f61e6393ba7a0abcc9f662"),
+                aad: hex_to_bytes("feedfacedeadbeeffeedfacedeadbeefabaddad2"),
+                tag: hex_to_bytes("76fc6ece0f4e1768cddf8853bb2d551b")
+            },
+    ]
+    }
+    #[test]
+    fn aes_gcm_test() {
+        for item in get_test_vectors().iter() {
+            let key_size = match item.key.len() {
+                16 => KeySize::KeySize128,
+                24 => KeySize::KeySize192,
+                32 => KeySize::KeySize256,
+                _ => unreachable!(),
+            };
+            let mut cipher = AesGcm::new(key_size, &item.key[..], &item.iv[..], &item.aad[..]);
+            let mut out: Vec<u8> = repeat(0).take(item.plain_text.len()).collect();
+
+            let mut out_tag: Vec<u8> = repeat(0).take(16).collect();
+
+            cipher.encrypt(&item.plain_text[..], &mut out[..], &mut out_tag[..]);
+            assert_eq!(out, item.cipher_text);
+            assert_eq!(out_tag, item.tag);
+        }
+    }
+
+    #[test]
+    fn aes_gcm_decrypt_test() {
+        for item in get_test_vectors().iter() {
+            let key_size = match item.key.len() {
+                16 => KeySize::KeySize128,
+                24 => KeySize::KeySize192,
+                32 => KeySize::KeySize256,
+                _ => unreachable!(),
+            };
+            let mut decipher = AesGcm::new(key_size, &item.key[..], &item.iv[..], &item.aad[..]);
+            let mut out: Vec<u8> = repeat(0).take(item.plain_text.len()).collect();
+
+            let result = decipher.decrypt(&item.cipher_text[..], &mut out[..], &item.tag[..]);
+            assert_eq!(out, item.plain_text);
+            assert!(result);
+        }
+    }
+    #[test]
+    fn aes_gcm_decrypt_fail_test() {
+        for item in get_test_vectors().iter() {
+            let key_size = match item.key.len() {
+                16 => KeySize::KeySize128,
+                24 => KeySize::KeySize192,
+                32 => KeySize::KeySize256,
+                _ => unreachable!(),
+            };
+            let mut decipher = AesGcm::new(key_size, &item.key[..], &item.iv[..], &item.aad[..]);
+            let tag: Vec<u8> = repeat(0).take(16).collect();
+            let mut out1: Vec<u8> = repeat(0).take(item.plain_text.len()).collect();
+            let out2: Vec<u8> = repeat(0).take(item.plain_text.len()).collect();
+            let result = decipher.decrypt(&item.cipher_text[..], &mut out1[..], &tag[..]);
+            assert_eq!(out1, out2);
+            assert!(!result);
+        }
+    }
+
+    fn mac_through_trait<M: Mac>(mac: &mut M, data: &[u8]) -> MacResult {
+        mac.reset();
+        mac.input(data);
+        mac.result()
+    }
+
+    #[test]
+    fn gmac_matches_gcm() {
+        // gcmEncryptExtIV128.rsp, [Keylen = 128] [IVlen = 96] [PTlen = 0] [AADlen = 128]
+        let key = hex_to_bytes("77be63708971c4e240d1cb79e8d77feb");
+        let iv = hex_to_bytes("e0e00f19fed7ba0136a797f3");
+        let aad = hex_to_bytes("7a43ec1d9c0a5a78a0b16533a6213cab");
+        let expected = hex_to_bytes("209fcc8d3675ed938e9c7166709dd946");
+
+        let mut gmac = Gmac::new(KeySize::KeySize128, &key[..], &iv[..]);
+        assert_eq!(gmac.output_bytes(), 16);
+        // Feed some unrelated data first to check that the trait's reset is honoured.
+        gmac.input(b"discarded");
+        let result = mac_through_trait(&mut gmac, &aad[..]);
+        assert!(result == MacResult::new(&expected[..]));
+
+        // The same tag falls out of AES-GCM over an empty plaintext.
+        let mut gcm = AesGcm::new(KeySize::KeySize128, &key[..], &iv[..], &aad[..]);
+        let mut gcm_tag = [0u8; 16];
+        gcm.encrypt(&[], &mut [], &mut gcm_tag);
+        assert!(result == MacResult::new(&gcm_tag[..]));
+
+        let mut other = Gmac::new(KeySize::KeySize128, &key[..], &iv[..]);
+        assert!(mac_through_trait(&mut other, b"tampered data") != result);
+    }
+
+    #[test]
+    fn gmac_nonce_lengths() {
+        // Tags of AES-128-GCM over an empty plaintext, from Python's cryptography. The key, aad
+        // and 60 byte nonce are those of the GCM specification's test case 6.
+        let key = hex_to_bytes("feffe9928665731c6d6a8f9467308308");
+        let aad = hex_to_bytes("feedfacedeadbeeffeedfacedeadbeefabaddad2");
+        let tests = [
+            ("cafebabefacedbaddecaf888", "346434fd51d5cd0c5887ec63e39b907a"),
+            ("cafebabefacedbad", "ef6995e531e81a01f5b2f7762cc60bd2"),
+            (
+                "9313225df88406e555909c5aff5269aa6a7a9538534f7da1e4c303d2a318a728\
+                 c3c0c95156809539fcf0e2429a6b525416aedbf5a0de6a57a637b39b",
+                "7be5178ff2b73c7d6f8b4dfdde8437ec",
+            ),
+        ];
+        for &(nonce, tag) in tests.iter() {
+            let mut gmac = Gmac::new(KeySize::KeySize128, &key[..], &hex_to_bytes(nonce)[..]);
+            gmac.input(&aad[..]);
+            assert!(gmac.result() == MacResult::new(&hex_to_bytes(tag)[..]));
+        }
+
+        // With a 96 bit nonce the tag is also what AesGcm gives for an empty plaintext.
+        let nonce = hex_to_bytes(tests[0].0);
+        let mut gcm = AesGcm::new(KeySize::KeySize128, &key[..], &nonce[..], &aad[..]);
+        let mut gcm_tag = [0u8; 16];
+        gcm.encrypt(&[], &mut [], &mut gcm_tag);
+        assert_eq!(&gcm_tag[..], &hex_to_bytes(tests[0].1)[..]);
+    }
+
+    #[test]
+    fn seal_open_with_aad() {
+        for item in get_test_vectors().iter() {
+            let key_size = match item.key.len() {
+                16 => KeySize::KeySize128,
+                24 => KeySize::KeySize192,
+                32 => KeySize::KeySize256,
+                _ => unreachable!(),
+            };
+            let sealed = seal(
+                key_size,
+                &item.key[..],
+                &item.iv[..],
+                &item.aad[..],
+                &item.plain_text[..],
+            );
+            assert_eq!(&sealed[..item.cipher_text.len()], &item.cipher_text[..]);
+            assert_eq!(&sealed[item.cipher_text.len()..], &item.tag[..]);
+            let opened = open(key_size, &item.key[..], &item.iv[..], &item.aad[..], &sealed[..]);
+            assert_eq!(opened.unwrap(), item.plain_text);
+        }
+
+        let key = [1u8; 16];
+        let nonce = [2u8; 12];
+        let sealed = seal(KeySize::KeySize128, &key, &nonce, b"header v1", b"payload");
+        assert_eq!(
+            open(KeySize::KeySize128, &key, &nonce, b"header v1", &sealed[..]).unwrap(),
+            b"payload".to_vec()
+        );
+        assert_eq!(
+            open(KeySize::KeySize128, &key, &nonce, b"header v2", &sealed[..]),
+            Err(AeadError::AuthenticationFailed)
+        );
+        assert_eq!(
+            open(KeySize::KeySize128, &key, &nonce, b"", &sealed[..]),
+            Err(AeadError::AuthenticationFailed)
+        );
+    }
+
+    #[test]
+    fn empty_plaintext_and_aad() {
+        // Computed with Python's cryptography AESGCM.
+        let key: Vec<u8> = (0..16).collect();
+        let nonce: Vec<u8> = (0..12).collect();
+        let tests: [(&str, &str, &str, &str); 3] = [
+            ("", "", "", "435b9ba12d75a4be8a977ea3cd011890"),
+            ("6b657920636f6e6669726d6174696f6e", "", "", "99308cdfdfa0b2571a8887c862e22a11"),
+            ("", "7061796c6f6164", "e30ddea2097a93", "db2c1eb520b61519cce8667f25f06822"),
+        ];
+
+        for &(aad, plaintext, ciphertext, tag) in tests.iter() {
+            let aad = hex_to_bytes(aad);
+            let plaintext = hex_to_bytes(plaintext);
+            let ciphertext = hex_to_bytes(ciphertext);
+            let tag = hex_to_bytes(tag);
+
+            let mut c = AesGcm::new(KeySize::KeySize128, &key[..], &nonce[..], &aad[..]);
+            let mut output: Vec<u8> = repeat(0).take(plaintext.len()).collect();
+            let mut out_tag = [0u8; 16];
+            c.encrypt(&plaintext[..], &mut output[..], &mut out_tag);
+            assert_eq!(output, ciphertext);
+            assert_eq!(&out_tag[..], &tag[..]);
+
+            let mut d = AesGcm::new(KeySize::KeySize128, &key[..], &nonce[..], &aad[..]);
+            let mut decrypted: Vec<u8> = repeat(0).take(ciphertext.len()).collect();
+            assert!(d.decrypt(&ciphertext[..], &mut decrypted[..], &tag[..]));
+            assert_eq!(decrypted, plaintext);
+
+            let sealed = seal(KeySize::KeySize128, &key[..], &nonce[..], &aad[..], &plaintext[..]);
+            assert_eq!(sealed.len(), plaintext.len() + 16);
+            let opened = open(KeySize::KeySize128, &key[..], &nonce[..], &aad[..], &sealed[..]);
+            assert_eq!(opened.unwrap(), plaintext);
+
+            let mut bad_tag = tag.clone();
+            bad_tag[15] ^= 0x80;
+            let mut d = AesGcm::new(KeySize::KeySize128, &key[..], &nonce[..], &aad[..]);
+            assert!(!d.decrypt(&ciphertext[..], &mut decrypted[..], &bad_tag[..]));
+        }
+    }
+
+    #[test]
+    fn open_detached_segmented() {
+        let key: Vec<u8> = (0..16).collect();
+        let nonce = [0x5c; 12];
+        let aad = b"first segment, second segment that crosses a block, third";
+        let plaintext = b"segmented associated data must authenticate like one buffer";
+        let sealed = seal(KeySize::KeySize128, &key[..], &nonce, aad, plaintext);
+        let (ciphertext, tag) = sealed.split_at(plaintext.len());
+
+        let mut buf = ciphertext.to_vec();
+        let mut c = AesGcm::new(KeySize::KeySize128, &key[..], &nonce, b"");
+        let segments: [&[u8]; 4] = [&aad[..5], &aad[5..29], b"", &aad[29..]];
+        assert_eq!(c.open_detached_segmented(&segments, &mut buf[..], tag), Ok(()));
+        assert_eq!(&buf[..], &plaintext[..]);
+
+        // AAD given to new() comes first, followed by the segments.
+        let mut buf = ciphertext.to_vec();
+        let mut c = AesGcm::new(KeySize::KeySize128, &key[..], &nonce, &aad[..13]);
+        let segments: [&[u8]; 1] = [&aad[13..]];
+        assert_eq!(c.open_detached_segmented(&segments, &mut buf[..], tag), Ok(()));
+        assert_eq!(&buf[..], &plaintext[..]);
+
+        // A missing segment fails and leaves the ciphertext untouched.
+        let mut buf = ciphertext.to_vec();
+        let mut c = AesGcm::new(KeySize::KeySize128, &key[..], &nonce, b"");
+        let segments: [&[u8]; 2] = [&aad[..5], &aad[29..]];
+        assert_eq!(
+            c.open_detached_segmented(&segments, &mut buf[..], tag),
+            Err(AeadError::AuthenticationFailed)
+        );
+        assert_eq!(&buf[..], ciphertext);
+    }
+
+    #[test]
+    #[should_panic]
+    fn open_detached_segmented_reuse() {
+        let key = [7u8; 16];
+        let nonce = [3u8; 12];
+        let sealed = seal(KeySize::KeySize128, &key, &nonce, b"aad", b"plaintext");
+        let (ciphertext, tag) = sealed.split_at(9);
+
+        let mut c = AesGcm::new(KeySize::KeySize128, &key, &nonce, b"");
+        let mut buf = ciphertext.to_vec();
+        assert_eq!(c.open_detached_segmented(&[b"aad"], &mut buf[..], tag), Ok(()));
+        let mut buf = ciphertext.to_vec();
+        let _ = c.open_detached_segmented(&[b"aad"], &mut buf[..], tag);
+    }
+
+    #[test]
+    fn typed_constructors() {
+        let vectors = get_test_vectors();
+        for &(i, key_size) in [(2, KeySize::KeySize128), (4, KeySize::KeySize256)].iter() {
+            let item = &vectors[i];
+            let mut nonce = [0u8; 12];
+            nonce.copy_from_slice(&item.iv[..]);
+            let mut cipher = match key_size {
+                KeySize::KeySize128 => {
+                    let mut key = [0u8; 16];
+                    key.copy_from_slice(&item.key[..]);
+                    AesGcm::new_128(&key, &nonce, &item.aad[..])
+                }
+                _ => {
+                    let mut key = [0u8; 32];
+                    key.copy_from_slice(&item.key[..]);
+                    AesGcm::new_256(&key, &nonce, &item.aad[..])
+                }
+            };
+            let mut out: Vec<u8> = repeat(0).take(item.plain_text.len()).collect();
+            let mut out_tag = [0u8; 16];
+            cipher.encrypt(&item.plain_text[..], &mut out[..], &mut out_tag);
+            assert_eq!(out, item.cipher_text);
+            assert_eq!(&out_tag[..], &item.tag[..]);
+
+            let mut cipher =
+                AesGcm::try_new(key_size, &item.key[..], &item.iv[..], &item.aad[..]).ok().unwrap();
+            cipher.encrypt(&item.plain_text[..], &mut out[..], &mut out_tag);
+            assert_eq!(out, item.cipher_text);
+        }
+
+        let item = &vectors[3];
+        let mut key = [0u8; 24];
+        key.copy_from_slice(&item.key[..]);
+        let mut cipher = AesGcm::new_192(&key, &[0xca; 12], &[]);
+        let mut out = [0u8; 16];
+        let mut out_tag = [0u8; 16];
+        cipher.encrypt(&[0; 16], &mut out, &mut out_tag);
+        let sealed = seal(KeySize::KeySize192, &key, &[0xca; 12], &[], &[0; 16]);
+        assert_eq!(&sealed[..16], &out[..]);
+        assert_eq!(&sealed[16..], &out_tag[..]);
+    }
+
+    #[test]
+    fn try_new_rejects_bad_lengths() {
+        let key = [0u8; 32];
+        let nonce = [0u8; 16];
+        let err = |r: Result<AesGcm, AeadError>| r.err().unwrap();
+        assert_eq!(
+            err(AesGcm::try_new(KeySize::KeySize256, &key[..16], &nonce[..12], &[])),
+            AeadError::InvalidKeyLength
+        );
+        assert_eq!(
+            err(AesGcm::try_new(KeySize::KeySize128, &key[..], &nonce[..12], &[])),
+            AeadError::InvalidKeyLength
+        );
+        assert_eq!(
+            err(AesGcm::try_new(KeySize::KeySize128, &key[..16], &nonce[..8], &[])),
+            AeadError::InvalidNonceLength
+        );
+        assert_eq!(
+            err(AesGcm::try_new(KeySize::KeySize128, &key[..16], &nonce[..], &[])),
+            AeadError::InvalidNonceLength
+        );
+    }
+
+    #[test]
+    fn open_into_bounded_output() {
+        let key = [0u8; 16];
+        let nonce = [0u8; 12];
+        let sealed = seal(KeySize::KeySize128, &key, &nonce, b"aad", &[0x42; 33]);
+        let (ciphertext, tag) = sealed.split_at(33);
+
+        let mut out = [0u8; 32];
+        let mut c = AesGcm::new(KeySize::KeySize128, &key, &nonce, b"a");
+        assert_eq!(
+            c.open_into(b"ad", ciphertext, tag, &mut out),
+            Err(AeadError::BufferTooSmall { needed: 33 })
+        );
+
+        let mut out = [0u8; 33];
+        let mut c = AesGcm::new(KeySize::KeySize128, &key, &nonce, b"a");
+        assert_eq!(c.open_into(b"ad", ciphertext, tag, &mut out), Ok(33));
+        assert_eq!(&out[..], &[0x42; 33][..]);
+    }
+
+    #[test]
+    fn open_tls_record_rfc8448() {
+        // RFC 8448 section 3: the client Finished record, protected with the client handshake
+        // traffic key at sequence number 0.
+        let key = [
+            0xdb, 0xfa, 0xa6, 0x93, 0xd1, 0x76, 0x2c, 0x5b, 0x66, 0x6a, 0xf5, 0xd9, 0x50, 0x25,
+            0x8d, 0x01,
+        ];
+        let iv = [0x5b, 0xd3, 0xc7, 0x1b, 0x83, 0x6e, 0x0b, 0x76, 0xbb, 0x73, 0x26, 0x5f];
+        let header = [0x17, 0x03, 0x03, 0x00, 0x35];
+        let record = [
+            0x75, 0xec, 0x4d, 0xc2, 0x38, 0xcc, 0xe6, 0x0b, 0x29, 0x80, 0x44, 0xa7, 0x1e, 0x21,
+            0x9c, 0x56, 0xcc, 0x77, 0xb0, 0x51, 0x7f, 0xe9, 0xb9, 0x3c, 0x7a, 0x4b, 0xfc, 0x44,
+            0xd8, 0x7f, 0x38, 0xf8, 0x03, 0x38, 0xac, 0x98, 0xfc, 0x46, 0xde, 0xb3, 0x84, 0xbd,
+            0x1c, 0xae, 0xac, 0xab, 0x68, 0x67, 0xd7, 0x26, 0xc4, 0x05, 0x46,
+        ];
+        let plaintext = [
+            0x14, 0x00, 0x00, 0x20, 0xa8, 0xec, 0x43, 0x6d, 0x67, 0x76, 0x34, 0xae, 0x52, 0x5a,
+            0xc1, 0xfc, 0xeb, 0xe1, 0x1a, 0x03, 0x9e, 0xc1, 0x76, 0x94, 0xfa, 0xc6, 0xe9, 0x85,
+            0x27, 0xb6, 0x42, 0xf2, 0xed, 0xd5, 0xce, 0x61, 0x16,
+        ];
+
+        let mut buf = record;
+        let mut c = AesGcm::new_128(&key, &iv, &[]);
+        assert_eq!(c.open_tls_record(&header, &mut buf), Ok(plaintext.len()));
+        assert_eq!(&buf[..plaintext.len()], &plaintext[..]);
+
+        // A header with another content type authenticates different AAD.
+        let mut buf = record;
+        let mut c = AesGcm::new_128(&key, &iv, &[]);
+        let forged = [0x16, 0x03, 0x03, 0x00, 0x35];
+        assert_eq!(c.open_tls_record(&forged, &mut buf), Err(AeadError::AuthenticationFailed));
+        assert_eq!(&buf[..], &record[..]);
+
+        let mut c = AesGcm::new_128(&key, &iv, &[]);
+        assert_eq!(c.open_tls_record(&header, &mut [0u8; 15]), Err(AeadError::InvalidTagLength));
+    }
+
+    #[test]
+    fn seal_open_reject_short_tag() {
+        let key = [0u8; 16];
+        let nonce = [0u8; 12];
+        let mut out = [0u8; 4];
+        let mut short_tag = [0u8; 15];
+        let mut c = AesGcm::new(KeySize::KeySize128, &key, &nonce, &[]);
+        assert_eq!(c.seal(&[1; 4], &mut out, &mut short_tag), Err(AeadError::InvalidTagLength));
+        let mut c = AesGcm::new(KeySize::KeySize128, &key, &nonce, &[]);
+        assert_eq!(c.open(&[1; 4], &mut out, &short_tag), Err(AeadError::InvalidTagLength));
+        let mut c = AesGcm::new(KeySize::KeySize128, &key, &nonce, &[]);
+        assert_eq!(c.open(&[1; 4], &mut out, &[0u8; 17]), Err(AeadError::InvalidTagLength));
+
+        let mut tag = [0u8; 16];
+        let mut ciphertext = [0u8; 4];
+        let mut c = AesGcm::new(KeySize::KeySize128, &key, &nonce, &[]);
+        assert_eq!(c.seal(&[1; 4], &mut ciphertext, &mut tag), Ok(()));
+        let mut c = AesGcm::new(KeySize::KeySize128, &key, &nonce, &[]);
+        assert_eq!(c.open(&ciphertext, &mut out, &tag), Ok(()));
+        assert_eq!(out, [1; 4]);
+        tag[0] ^= 1;
+        let mut c = AesGcm::new(KeySize::KeySize128, &key, &nonce, &[]);
+        assert_eq!(c.open(&ciphertext, &mut out, &tag), Err(AeadError::AuthenticationFailed));
+    }
+
+    // Every open path compares the whole tag with fixed_time_eq and reports any mismatch as the
+    // same AuthenticationFailed, leaving the output untouched, so neither timing nor the error
+    // tells a forger how many leading tag bytes were right.
+    #[test]
+    fn tag_mismatch_in_first_or_last_byte() {
+        let key = [0x42u8; 16];
+        let nonce = [0x24u8; 12];
+        let aad = b"header";
+        let plaintext = b"a message long enough to span two blocks";
+        let sealed = seal(KeySize::KeySize128, &key, &nonce, aad, plaintext);
+        let (ciphertext, tag) = sealed.split_at(plaintext.len());
+
+        for &i in [0, 15].iter() {
+            let mut bad_sealed = sealed.clone();
+            bad_sealed[plaintext.len() + i] ^= 0x01;
+            assert_eq!(
+                open(KeySize::KeySize128, &key, &nonce, aad, &bad_sealed[..]),
+                Err(AeadError::AuthenticationFailed)
+            );
+
+            let mut bad_tag = tag.to_vec();
+            bad_tag[i] ^= 0x80;
+            let mut out = [0u8; 40];
+            let mut c = AesGcm::new(KeySize::KeySize128, &key, &nonce, aad);
+            assert!(!c.decrypt(ciphertext, &mut out[..], &bad_tag[..]));
+            assert_eq!(&out[..], &[0u8; 40][..]);
+
+            let mut buf = ciphertext.to_vec();
+            let mut c = AesGcm::new(KeySize::KeySize128, &key, &nonce, b"");
+            let segments: [&[u8]; 1] = [&aad[..]];
+            assert_eq!(
+                c.open_detached_segmented(&segments, &mut buf[..], &bad_tag[..]),
+                Err(AeadError::AuthenticationFailed)
+            );
+            assert_eq!(&buf[..], ciphertext);
+        }
+
+        let opened = open(KeySize::KeySize128, &key, &nonce, aad, &sealed[..]);
+        assert_eq!(opened.unwrap(), &plaintext[..]);
+    }
+
+    #[test]
+    fn sequence_iv_records() {
+        // RFC 8448, section 3: the client's first application data record, sequence number 0,
+        // under the client application traffic key and IV. The plaintext is 50 bytes of data
+        // followed by the inner content type.
+        let key = hex_to_bytes("17422dda596ed5d9acd890e3c63f5051");
+        let mut static_iv = [0u8; 12];
+        static_iv.copy_from_slice(&hex_to_bytes("5b78923dee08579033e523d9"));
+        let header = hex_to_bytes("1703030043");
+        let mut plaintext: Vec<u8> = (0..50).collect();
+        plaintext.push(0x17);
+        let record = hex_to_bytes(
+            "a23f7054b62c94d0affafe8228ba55cbefacea42f914aa66bcab3f2b9819a8a5b46b395bd54a9a20441e\
+             2b62974e1f5a6292a2977014bd1e3deae63aeebb21694915e4",
+        );
+        let (ciphertext, tag) = record.split_at(plaintext.len());
+
+        let mut sealer = AesGcm::with_sequence_iv(KeySize::KeySize128, &key[..], &static_iv, 0);
+        let (c, t) = sealer.seal_next(&header[..], &plaintext[..]).unwrap();
+        assert_eq!(&c[..], ciphertext);
+        assert_eq!(&t[..], tag);
+        assert_eq!(sealer.sequence(), 1);
+
+        let mut opener = AesGcm::with_sequence_iv(KeySize::KeySize128, &key[..], &static_iv, 0);
+        assert_eq!(opener.open_next(&header[..], ciphertext, tag).unwrap(), plaintext);
+
+        // The sequence number is XORed into the last 8 bytes of the static IV.
+        let seq = 0x0102030405060708;
+        let nonce = hex_to_bytes("5b78923def0a549436e324d1");
+        let mut sealer = AesGcm::with_sequence_iv(KeySize::KeySize128, &key[..], &static_iv, seq);
+        for i in 0..2 {
+            let (c, t) = sealer.seal_next(b"hdr", b"record payload").unwrap();
+            let mut nonce = nonce.clone();
+            nonce[11] ^= i;
+            let expected =
+                seal(KeySize::KeySize128, &key[..], &nonce[..], b"hdr", b"record payload");
+            assert_eq!(&c[..], &expected[..14]);
+            assert_eq!(&t[..], &expected[14..]);
+        }
+
+        // A record opened out of order uses the wrong nonce and fails.
+        let (c, t) = sealer.seal_next(b"hdr", b"record payload").unwrap();
+        let mut skipping =
+            AesGcm::with_sequence_iv(KeySize::KeySize128, &key[..], &static_iv, seq + 3);
+        assert_eq!(
+            skipping.open_next(b"hdr", &c[..], &t),
+            Err(AeadError::AuthenticationFailed)
+        );
+        let mut opener =
+            AesGcm::with_sequence_iv(KeySize::KeySize128, &key[..], &static_iv, seq + 2);
+        assert_eq!(opener.open_next(b"hdr", &c[..], &t).unwrap(), b"record payload".to_vec());
+
+        // The TLS 1.2 layout, fixed_iv || be64(seq), with the fixed IV padded by zeros.
+        let mut tls12_iv = [0u8; 12];
+        tls12_iv[..4].copy_from_slice(&[0xca, 0xfe, 0xba, 0xbe]);
+        let mut sealer = AesGcm::with_sequence_iv(KeySize::KeySize128, &key[..], &tls12_iv, seq);
+        let (c, t) = sealer.seal_next(b"hdr", b"record payload").unwrap();
+        let nonce = hex_to_bytes("cafebabe0102030405060708");
+        let expected = seal(KeySize::KeySize128, &key[..], &nonce[..], b"hdr", b"record payload");
+        assert_eq!(&c[..], &expected[..14]);
+        assert_eq!(&t[..], &expected[14..]);
+    }
+
+    #[test]
+    fn ctr_schedule_reset() {
+        // Both backends must drop keystream buffered for the old counter when it is reset.
+        let key = [7u8; 16];
+        let j0 = [9u8; 16];
+        let zero = [0u8; 200];
+        let mut expected = [0u8; 200];
+        ctr(KeySize::KeySize128, &key, &j0).process(&zero, &mut expected);
+        let schedules = vec![
+            ctr_schedule(KeySize::KeySize128, &key),
+            ctr_schedule_safe(KeySize::KeySize128, &key),
+        ];
+        for mut cipher in schedules {
+            let mut out = [0u8; 200];
+            cipher.process(&zero[..3], &mut out[..3]);
+            cipher.reset_ctr(&j0);
+            cipher.process(&zero, &mut out);
+            assert_eq!(&out[..], &expected[..]);
+        }
+    }
+
+    #[test]
+    fn sequence_iv_exhausted() {
+        let mut sealer =
+            AesGcm::with_sequence_iv(KeySize::KeySize128, &[0; 16], &[0; 12], u64::max_value());
+        assert!(sealer.seal_next(b"", b"last").is_ok());
+        assert_eq!(sealer.seal_next(b"", b"one more"), Err(AeadError::NonceExhausted));
+    }
+}
+
+#[cfg(all(test, feature = "with-bench"))]
+mod bench {
+    use aead::{AeadDecryptor, AeadEncryptor};
+    use aes::KeySize;
+    use aes_gcm::AesGcm;
+    use test::Bencher;
+
+    #[bench]
+    pub fn gsm_10(bh: &mut Bencher) {
+        let input = [1u8; 10];
+        let aad = [3u8; 10];
+        bh.iter(|| {
+            let mut cipher = AesGcm::new(KeySize::KeySize256, &[0; 32], &[0; 12], &aad);
+            let mut decipher = AesGcm::new(KeySize::KeySize256, &[0; 32], &[0; 12], &aad);
+
+            let mut output = [0u8; 10];
+            let mut tag = [0u8; 16];
+            let mut output2 = [0u8; 10];
+            cipher.encrypt(&input, &mut output, &mut tag);
+            decipher.decrypt(&output, &mut output2, &tag);
+        });
+        bh.bytes = 10u64;
+    }
+
+    #[bench]
+    pub fn gsm_1k(bh: &mut Bencher) {
+        let input = [1u8; 1024];
+        let aad = [3u8; 1024];
+        bh.iter(|| {
+            let mut cipher = AesGcm::new(KeySize::KeySize256, &[0; 32], &[0; 12], &aad);
+            let mut decipher = AesGcm::new(KeySize::KeySize256, &[0; 32], &[0; 12], &aad);
+
+            let mut output = [0u8; 1024];
+            let mut tag = [0u8; 16];
+            let mut output2 = [0u8; 1024];
+
+            cipher.encrypt(&input, &mut output, &mut tag);
+            decipher.decrypt(&output, &mut output2, &tag);
+        });
+        bh.bytes = 1024u64;
+    }
+
+    #[bench]
+    pub fn gsm_64k(bh: &mut Bencher) {
+        let input = [1u8; 65536];
+        let aad = [3u8; 65536];
+        bh.iter(|| {
+            let mut cipher = AesGcm::new(KeySize::KeySize256, &[0; 32], &[0; 12], &aad);
+            let mut decipher = AesGcm::new(KeySize::KeySize256, &[0; 32], &[0; 12], &aad);
+
+            let mut output = [0u8; 65536];
+            let mut tag = [0u8; 16];
+            let mut output2 = [0u8; 65536];
+
+            cipher.encrypt(&input, &mut output, &mut tag);
+            decipher.decrypt(&output, &mut output2, &tag);
+        });
+        bh.bytes = 65536u64;
+    }
+}
@@ -157,16 +157,46 @@ macro_rules! define_aes_impl(
     ) => (
         impl $name {
             pub fn new(key: &[u8]) -> $name {
+                let mut tmp = [[0u32; 4]; ($rounds + 1)];
+                create_round_keys(key, KeyType::$mode, &mut tmp);
+                let mut rk = [0u32; 4 * ($rounds + 1)];
+                for (words, round_key) in rk.chunks_mut(4).zip(tmp.iter()) {
+                    words.copy_from_slice(round_key);
+                }
+                $name::from_round_keys(&rk)
+            }
+
+            /// Construct from a key schedule previously obtained from round_keys(), skipping
+            /// the key expansion.
+            pub fn from_round_keys(round_keys: &[u32]) -> $name {
+                assert!(round_keys.len() == 4 * ($rounds + 1));
                 let mut a =  $name {
                     sk: [Bs8State(0, 0, 0, 0, 0, 0, 0, 0); ($rounds + 1)]
                 };
-                let mut tmp = [[0u32; 4]; ($rounds + 1)];
-                create_round_keys(key, KeyType::$mode, &mut tmp);
                 for i in 0..$rounds + 1 {
-                    a.sk[i] = bit_slice_4x4_with_u16(tmp[i][0], tmp[i][1], tmp[i][2], tmp[i][3]);
+                    let w = &round_keys[4 * i..4 * i + 4];
+                    a.sk[i] = bit_slice_4x4_with_u16(w[0], w[1], w[2], w[3]);
                 }
                 a
             }
+
+            /// The expanded key schedule: four words per round key, rounds + 1 round keys. Each
+            /// word holds four bytes of the round key in little-endian order. The decryptors hold
+            /// the schedule of the equivalent inverse cipher, with InvMixColumns already applied
+            /// to the inner round keys.
+            ///
+            /// This is a low level interface for modes that need the AES key schedule; treat the
+            /// result with the same care as the key itself. It is recovered from the bit-sliced
+            /// keys on each call rather than stored, so the cipher holds a single copy of the
+            /// schedule.
+            pub fn round_keys(&self) -> [u32; 4 * ($rounds + 1)] {
+                let mut rk = [0u32; 4 * ($rounds + 1)];
+                for (words, sk) in rk.chunks_mut(4).zip(self.sk.iter()) {
+                    let (a, b, c, d) = un_bit_slice_4x4_with_u16(sk);
+                    words.copy_from_slice(&[a, b, c, d]);
+                }
+                rk
+            }
         }
     )
 );
@@ -8,10 +8,8 @@ use blowfish::Blowfish;
 use cryptoutil::write_u32_be;
 use step_by::RangeExt;
 
-fn setup(cost: u32, salt: &[u8], key: &[u8]) -> Blowfish {
+fn setup(mut state: Blowfish, cost: u32, salt: &[u8], key: &[u8]) -> Blowfish {
     //assert!(cost < 32);
-    let mut state = Blowfish::init_state();
-
     state.salted_expand_key(salt, key);
     for _ in 0..1u32 << cost {
         state.expand_key(key);
@@ -22,11 +20,31 @@ fn setup(cost: u32, salt: &[u8], key: &[u8]) -> Blowfish {
 }
 
 pub fn bcrypt(cost: u32, salt: &[u8], password: &[u8], output: &mut [u8]) {
+    bcrypt_with_state(Blowfish::init_state(), cost, salt, password, output);
+}
+
+/**
+ * Like bcrypt(), but using Blowfish::init_state_constant_time(), so the S-box lookups do not
+ * leak the password through the cache. The output is identical. Each lookup scans a whole S-box,
+ * which makes this about a hundred times slower than bcrypt() at the same cost; lower the cost
+ * only if the extra time is unaffordable, since that weakens resistance to guessing.
+ */
+pub fn bcrypt_constant_time(cost: u32, salt: &[u8], password: &[u8], output: &mut [u8]) {
+    bcrypt_with_state(Blowfish::init_state_constant_time(), cost, salt, password, output);
+}
+
+fn bcrypt_with_state(
+    initial: Blowfish,
+    cost: u32,
+    salt: &[u8],
+    password: &[u8],
+    output: &mut [u8],
+) {
     //assert!(salt.len() == 16);
     //assert!(0 < password.len() && password.len() <= 72);
     //assert!(output.len() == 24);
 
-    let state = setup(cost, salt, password);
+    let state = setup(initial, cost, salt, password);
     // OrpheanBeholderScryDoubt
     let mut ctext = [
         0x4f727068, 0x65616e42, 0x65686f6c, 0x64657253, 0x63727944, 0x6f756274,
@@ -44,7 +62,7 @@ pub fn bcrypt(cost: u32, salt: &[u8], password: &[u8], output: &mut [u8]) {
 
 #[cfg(test)]
 mod test {
-    use bcrypt::bcrypt;
+    use bcrypt::{bcrypt, bcrypt_constant_time};
 
     struct Test {
         cost: u32,
@@ -277,6 +295,19 @@ mod test {
             //assert!(output[0..23] == test.output[..]);
         }
     }
+
+    #[test]
+    fn test_constant_time_matches() {
+        let tests = openwall_test_vectors();
+        let mut output = [0u8; 24];
+        let mut ct_output = [0u8; 24];
+        for test in tests.iter().take(2) {
+            bcrypt(test.cost, &test.salt[..], &test.input[..], &mut output[..]);
+            bcrypt_constant_time(test.cost, &test.salt[..], &test.input[..], &mut ct_output[..]);
+            assert_eq!(output, ct_output);
+            assert_eq!(&ct_output[..23], &test.output[..]);
+        }
+    }
 }
 
 #[cfg(all(test, feature = "with-bench"))]
@@ -4,11 +4,16 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use sr_std::fmt;
+use sr_std::prelude::*;
+
 use blowfish::Blowfish;
+use chacha20poly1305;
 use cryptoutil::{read_u32v_be, write_u32_be, write_u32_le};
 use digest::Digest;
 use sha2::Sha512;
 use step_by::RangeExt;
+use util::secure_memset;
 
 fn bcrypt_hash(hpass: &[u8], hsalt: &[u8], output: &mut [u8; 32]) {
     let mut bf = Blowfish::init_state();
@@ -32,6 +37,7 @@ fn bcrypt_hash(hpass: &[u8], hsalt: &[u8], output: &mut [u8; 32]) {
 
     for i in 0..8 {
         write_u32_le(&mut output[i * 4..(i + 1) * 4], buf[i]);
+        buf[i] = 0;
     }
 }
 
@@ -73,22 +79,101 @@ pub fn bcrypt_pbkdf(password: &[u8], salt: &[u8], rounds: u32, output: &mut [u8]
             for i in 0..out.len() {
                 out[i] ^= tmp[i];
             }
+        }
 
-            for i in 0..out.len() {
-                let idx = i * nblocks + (block - 1);
-                if idx < output.len() {
-                    output[idx] = out[i];
-                }
+        for i in 0..out.len() {
+            let idx = i * nblocks + (block - 1);
+            if idx < output.len() {
+                output[idx] = out[i];
+            }
+        }
+
+        secure_memset(&mut hsalt, 0);
+        secure_memset(&mut out, 0);
+        secure_memset(&mut tmp, 0);
+    }
+
+    secure_memset(&mut hpass, 0);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PasswordBlobError {
+    /// The round count is zero.
+    InvalidRounds,
+    /// The password is wrong, or the blob, salt, nonce or aad were altered.
+    AuthenticationFailed,
+}
+
+impl fmt::Display for PasswordBlobError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PasswordBlobError::InvalidRounds => f.write_str("invalid bcrypt_pbkdf round count"),
+            PasswordBlobError::AuthenticationFailed => {
+                f.write_str("password blob authentication failed")
             }
         }
     }
 }
 
+#[cfg(feature = "std")]
+impl ::std::error::Error for PasswordBlobError {}
+
+/**
+ * Encrypt plaintext with ChaCha20-Poly1305 under a 256 bit key derived from the password by
+ * bcrypt_pbkdf(). The salt should be random and unique to each blob; it and the nonce must be
+ * stored alongside the result for open_with_password(). The nonce may be 8 or 12 bytes.
+ * Fails with InvalidRounds if rounds is zero.
+ */
+pub fn seal_with_password(
+    password: &[u8],
+    salt: &[u8],
+    rounds: u32,
+    nonce: &[u8],
+    aad: &[u8],
+    plaintext: &[u8],
+) -> Result<Vec<u8>, PasswordBlobError> {
+    if rounds < 1 {
+        return Err(PasswordBlobError::InvalidRounds);
+    }
+    let mut key = [0u8; 32];
+    bcrypt_pbkdf(password, salt, rounds, &mut key);
+    let sealed = chacha20poly1305::seal(&key, nonce, aad, plaintext);
+    secure_memset(&mut key, 0);
+    Ok(sealed)
+}
+
+/**
+ * Decrypt the output of seal_with_password(). A wrong password only yields a different key, which
+ * is caught by the same fixed time tag comparison as tampered data, so both fail with
+ * AuthenticationFailed after the same work and cannot be told apart. Fails with InvalidRounds if
+ * rounds is zero.
+ */
+pub fn open_with_password(
+    password: &[u8],
+    salt: &[u8],
+    rounds: u32,
+    nonce: &[u8],
+    aad: &[u8],
+    sealed: &[u8],
+) -> Result<Vec<u8>, PasswordBlobError> {
+    if rounds < 1 {
+        return Err(PasswordBlobError::InvalidRounds);
+    }
+    let mut key = [0u8; 32];
+    bcrypt_pbkdf(password, salt, rounds, &mut key);
+    let result = chacha20poly1305::open(&key, nonce, aad, sealed);
+    secure_memset(&mut key, 0);
+    result.map_err(|_| PasswordBlobError::AuthenticationFailed)
+}
+
 #[cfg(test)]
 mod test {
     use sr_std::iter::repeat;
 
-    use bcrypt_pbkdf::{bcrypt_hash, bcrypt_pbkdf};
+    use bcrypt_pbkdf::{
+        bcrypt_hash, bcrypt_pbkdf, open_with_password, seal_with_password, PasswordBlobError,
+    };
+    use cryptoutil::test::check_display;
 
     #[test]
     fn test_bcrypt_hash() {
@@ -290,6 +375,88 @@ mod test {
             assert_eq!(out, t.out);
         }
     }
+
+    #[test]
+    fn test_repeatable() {
+        // The intermediate buffers are wiped after each block; the output must not change.
+        let expected = [
+            0x5b, 0xbf, 0x0c, 0xc2, 0x93, 0x58, 0x7f, 0x1c, 0x36, 0x35, 0x55, 0x5c, 0x27, 0x79, 0x65,
+            0x98, 0xd4, 0x7e, 0x57, 0x90, 0x71, 0xbf, 0x42, 0x7e, 0x9d, 0x8f, 0xbe, 0x84, 0x2a, 0xba,
+            0x34, 0xd9,
+        ];
+        let mut out = [0u8; 32];
+        for _ in 0..2 {
+            bcrypt_pbkdf(b"password", b"salt", 4, &mut out);
+            assert_eq!(out, expected);
+        }
+    }
+
+    #[test]
+    fn test_password_blob() {
+        let salt = [0x5a; 16];
+        let nonce = [0u8; 12];
+        let sealed =
+            seal_with_password(b"password", &salt, 4, &nonce, b"v1", b"secret data").unwrap();
+        assert_eq!(
+            open_with_password(b"password", &salt, 4, &nonce, b"v1", &sealed),
+            Ok(b"secret data".to_vec())
+        );
+
+        let wrong_password = open_with_password(b"passwore", &salt, 4, &nonce, b"v1", &sealed);
+        let mut tampered = sealed.clone();
+        tampered[0] ^= 1;
+        let tampered = open_with_password(b"password", &salt, 4, &nonce, b"v1", &tampered);
+        assert_eq!(wrong_password, Err(PasswordBlobError::AuthenticationFailed));
+        assert_eq!(tampered, wrong_password);
+
+        assert_eq!(
+            open_with_password(b"password", &salt, 4, &nonce, b"v1", &sealed[..15]),
+            Err(PasswordBlobError::AuthenticationFailed)
+        );
+    }
+
+    #[test]
+    fn test_single_round() {
+        // With one round the key is the first bcrypt_hash output alone; it must still be written.
+        let mut a = [0u8; 32];
+        let mut b = [0u8; 32];
+        bcrypt_pbkdf(b"password", b"salt", 1, &mut a);
+        bcrypt_pbkdf(b"passwore", b"salt", 1, &mut b);
+        assert!(a.iter().any(|&x| x != 0));
+        assert!(a != b);
+
+        let salt = [0x5a; 16];
+        let nonce = [0u8; 12];
+        let sealed = seal_with_password(b"password", &salt, 1, &nonce, b"", b"data").unwrap();
+        assert!(open_with_password(b"passwore", &salt, 1, &nonce, b"", &sealed).is_err());
+        assert_eq!(
+            open_with_password(b"password", &salt, 1, &nonce, b"", &sealed),
+            Ok(b"data".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_zero_rounds_rejected() {
+        let salt = [0x5a; 16];
+        let nonce = [0u8; 12];
+        assert_eq!(
+            seal_with_password(b"password", &salt, 0, &nonce, b"", b"data"),
+            Err(PasswordBlobError::InvalidRounds)
+        );
+        assert_eq!(
+            open_with_password(b"password", &salt, 0, &nonce, b"", &[0u8; 20]),
+            Err(PasswordBlobError::InvalidRounds)
+        );
+    }
+
+    #[test]
+    fn test_error_display() {
+        check_display(&PasswordBlobError::InvalidRounds, "invalid bcrypt_pbkdf round count");
+        check_display(
+            &PasswordBlobError::AuthenticationFailed,
+            "password blob authentication failed",
+        );
+    }
 }
 
 #[cfg(all(test, feature = "with-bench"))]
@@ -10,7 +10,7 @@ use mac::{Mac, MacResult};
 use sr_std::iter::repeat;
 use sr_std::marker::*;
 use sr_std::prelude::*;
-use util::secure_memset;
+use util::{fixed_time_eq, secure_memset};
 
 static IV: [u64; 8] = [
     0x6a09e667f3bcc908,
@@ -350,6 +350,19 @@ impl Blake2b {
         hasher.update(input);
         hasher.finalize(out);
     }
+
+    /**
+     * Finish the (normally keyed) hash and compare it against expected in fixed time. A tag of
+     * the wrong length never verifies.
+     */
+    pub fn verify(&mut self, expected: &[u8]) -> bool {
+        let mut tag = [0u8; BLAKE2B_OUTBYTES];
+        let outlen = self.digest_length as usize;
+        self.finalize(&mut tag[..outlen]);
+        let result = fixed_time_eq(&tag[..outlen], expected);
+        secure_memset(&mut tag, 0);
+        result
+    }
 }
 
 impl Digest for Blake2b {
@@ -366,7 +379,10 @@ impl Digest for Blake2b {
         8 * (self.digest_length as usize)
     }
     fn block_size(&self) -> usize {
-        8 * BLAKE2B_BLOCKBYTES
+        BLAKE2B_BLOCKBYTES
+    }
+    fn internal_state_len(&self) -> usize {
+        64
     }
 }
 
@@ -419,6 +435,7 @@ impl Mac for Blake2b {
 mod digest_tests {
     //use cryptoutil::test::test_digest_1million_random;
     use blake2b::Blake2b;
+    use cryptoutil::test::test_digest_large_single_input;
     use digest::Digest;
 
     struct Test {
@@ -531,12 +548,56 @@ mod digest_tests {
 
         test_hash(&tests[..]);
     }
+
+    #[test]
+    fn test_large_single_input() {
+        test_digest_large_single_input(&mut Blake2b::new(64));
+    }
 }
 
 #[cfg(test)]
 mod mac_tests {
     use blake2b::Blake2b;
+    use hmac::Hmac;
     use mac::Mac;
+    use pbkdf2::pbkdf2;
+
+    #[test]
+    fn test_blake2b_sizes() {
+        let d = Blake2b::new(32);
+        assert_eq!(::digest::Digest::output_bytes(&d), 32);
+        assert_eq!(Mac::output_bytes(&d), 32);
+        assert_eq!(::digest::Digest::block_size(&d), 128);
+    }
+
+    #[test]
+    fn test_blake2b_hmac() {
+        // HMAC needs the real block size for its pads; checked against Python's hmac module.
+        let mut m = Hmac::new(Blake2b::new(64), b"key");
+        m.input(b"The quick brown fox jumps over the lazy dog");
+        let expected = [
+            0x92, 0x29, 0x4f, 0x92, 0xc0, 0xdf, 0xb9, 0xb0, 0x0e, 0xc9, 0xae, 0x8b, 0xd9, 0x4d,
+            0x7e, 0x7d, 0x8a, 0x03, 0x6b, 0x88, 0x5a, 0x49, 0x9f, 0x14, 0x9d, 0xfe, 0x2f, 0xd2,
+            0x19, 0x93, 0x94, 0xaa, 0xaf, 0x6b, 0x88, 0x94, 0xa1, 0x73, 0x0c, 0xcc, 0xb2, 0xcd,
+            0x05, 0x0f, 0x9b, 0xcf, 0x50, 0x62, 0xa3, 0x8b, 0x51, 0xb0, 0xda, 0xb3, 0x32, 0x07,
+            0xf8, 0xef, 0x35, 0xae, 0x2c, 0x9d, 0xf5, 0x1b,
+        ];
+        assert_eq!(m.result().code().to_vec(), expected.to_vec());
+    }
+
+    #[test]
+    fn test_blake2b_pbkdf2_prf() {
+        // Keyed BLAKE2b used directly as the PBKDF2 PRF, checked against Python's hashlib.
+        let mut m = Blake2b::new_keyed(32, b"password");
+        let mut out = [0u8; 40];
+        pbkdf2(&mut m, b"salt", 2, &mut out);
+        let expected = [
+            0x29, 0x7b, 0xa6, 0x71, 0xef, 0xf1, 0x87, 0x3a, 0x5c, 0xdd, 0x35, 0xeb, 0x5d, 0x2e,
+            0xe7, 0xf4, 0x63, 0x22, 0x43, 0x7d, 0xa6, 0x1b, 0xe8, 0x4a, 0xb4, 0xa1, 0xe9, 0x2e,
+            0x3d, 0xc7, 0xbc, 0x8c, 0xf0, 0x99, 0xd3, 0xa6, 0x1a, 0x4e, 0x68, 0xd2,
+        ];
+        assert_eq!(out.to_vec(), expected.to_vec());
+    }
 
     #[test]
     fn test_blake2b_mac() {
@@ -552,6 +613,45 @@ mod mac_tests {
         ];
         assert_eq!(m.result().code().to_vec(), expected.to_vec());
     }
+
+    #[test]
+    fn test_blake2b_verify() {
+        // Keyed vectors from the BLAKE2 reference KAT (blake2b-kat.txt), key 00 01 ... 3f and
+        // input 00 01 ... (n - 1).
+        let key: Vec<u8> = (0..64).collect();
+        let tests = [
+            (
+                0,
+                "10ebb67700b1868efb4417987acf4690ae9d972fb7a590c2f02871799aaa4786\
+                 b5e996e8f0f4eb981fc214b005f42d2ff4233499391653df7aefcbc13fc51568",
+            ),
+            (
+                255,
+                "142709d62e28fcccd0af97fad0f8465b971e82201dc51070faa0372aa43e9248\
+                 4be1c1e73ba10906d5d1853db6a4106e0a7bf9800d373d6dee2d46d62ef2a461",
+            ),
+        ];
+        for &(len, tag) in tests.iter() {
+            let input: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let tag = hex::decode(tag).unwrap();
+
+            let mut m = Blake2b::new_keyed(64, &key[..]);
+            m.input(&input[..]);
+            assert!(m.verify(&tag[..]));
+
+            // Flipping a byte at either end fails, as does a truncated tag.
+            for &i in [0, 63].iter() {
+                let mut bad = tag.clone();
+                bad[i] ^= 1;
+                let mut m = Blake2b::new_keyed(64, &key[..]);
+                m.input(&input[..]);
+                assert!(!m.verify(&bad[..]));
+            }
+            let mut m = Blake2b::new_keyed(64, &key[..]);
+            m.input(&input[..]);
+            assert!(!m.verify(&tag[..32]));
+        }
+    }
 }
 
 #[cfg(all(test, feature = "with-bench"))]
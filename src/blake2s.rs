@@ -181,6 +181,21 @@ impl Blake2s {
         Blake2s::init_param(Blake2s::default_param(outlen as u8), &[])
     }
 
+    /**
+     * BLAKE2s-256, the full 32 byte digest.
+     */
+    pub fn new_256() -> Blake2s {
+        Blake2s::new(32)
+    }
+
+    /**
+     * BLAKE2s-128, a 16 byte digest. The length is part of the parameter block, so this is not a
+     * truncation of BLAKE2s-256.
+     */
+    pub fn new_128() -> Blake2s {
+        Blake2s::new(16)
+    }
+
     fn apply_key(&mut self) {
         let mut block: [u8; BLAKE2S_BLOCKBYTES] = [0; BLAKE2S_BLOCKBYTES];
         copy_memory(&self.key[..self.key_length as usize], &mut block);
@@ -350,7 +365,10 @@ impl Digest for Blake2s {
         8 * (self.digest_length as usize)
     }
     fn block_size(&self) -> usize {
-        8 * BLAKE2S_BLOCKBYTES
+        BLAKE2S_BLOCKBYTES
+    }
+    fn internal_state_len(&self) -> usize {
+        32
     }
 }
 
@@ -486,12 +504,79 @@ mod digest_tests {
 
         test_hash(&tests[..]);
     }
+
+    #[test]
+    fn test_blake2s_presets() {
+        // From Python's hashlib.blake2s.
+        let expected_256 = [
+            0x50, 0x8c, 0x5e, 0x8c, 0x32, 0x7c, 0x14, 0xe2, 0xe1, 0xa7, 0x2b, 0xa3, 0x4e, 0xeb,
+            0x45, 0x2f, 0x37, 0x45, 0x8b, 0x20, 0x9e, 0xd6, 0x3a, 0x29, 0x4d, 0x99, 0x9b, 0x4c,
+            0x86, 0x67, 0x59, 0x82,
+        ];
+        let expected_128 = [
+            0xaa, 0x49, 0x38, 0x11, 0x9b, 0x1d, 0xc7, 0xb8, 0x7c, 0xba, 0xd0, 0xff, 0xd2, 0x00,
+            0xd0, 0xae,
+        ];
+
+        let mut sh = Blake2s::new_256();
+        assert_eq!(sh.output_bytes(), 32);
+        sh.input(b"abc");
+        let mut out_256 = [0u8; 32];
+        sh.result(&mut out_256);
+        assert_eq!(out_256, expected_256);
+
+        let mut sh = Blake2s::new_128();
+        assert_eq!(sh.output_bytes(), 16);
+        sh.input(b"abc");
+        let mut out_128 = [0u8; 16];
+        sh.result(&mut out_128);
+        assert_eq!(out_128, expected_128);
+
+        assert!(out_256[..16] != out_128[..]);
+    }
 }
 
 #[cfg(test)]
 mod mac_tests {
     use blake2s::Blake2s;
+    use hmac::Hmac;
     use mac::Mac;
+    use pbkdf2::pbkdf2;
+
+    #[test]
+    fn test_blake2s_sizes() {
+        let d = Blake2s::new(32);
+        assert_eq!(::digest::Digest::output_bytes(&d), 32);
+        assert_eq!(Mac::output_bytes(&d), 32);
+        assert_eq!(::digest::Digest::block_size(&d), 64);
+    }
+
+    #[test]
+    fn test_blake2s_hmac() {
+        // HMAC needs the real block size for its pads; checked against Python's hmac module.
+        let mut m = Hmac::new(Blake2s::new(32), b"key");
+        m.input(b"The quick brown fox jumps over the lazy dog");
+        let expected = [
+            0xf9, 0x32, 0x15, 0xbb, 0x90, 0xd4, 0xaf, 0x4c, 0x30, 0x61, 0xcd, 0x93, 0x2f, 0xb1,
+            0x69, 0xfb, 0x8b, 0xb8, 0xa9, 0x1d, 0x0b, 0x40, 0x22, 0xba, 0xea, 0x12, 0x71, 0xe1,
+            0x32, 0x3c, 0xd9, 0xa0,
+        ];
+        assert_eq!(m.result().code().to_vec(), expected.to_vec());
+    }
+
+    #[test]
+    fn test_blake2s_pbkdf2_prf() {
+        // Keyed BLAKE2s used directly as the PBKDF2 PRF, checked against Python's hashlib.
+        let mut m = Blake2s::new_keyed(32, b"password");
+        let mut out = [0u8; 40];
+        pbkdf2(&mut m, b"salt", 2, &mut out);
+        let expected = [
+            0x08, 0xc6, 0x6f, 0x2e, 0x76, 0x98, 0xbe, 0xc0, 0x4b, 0xb9, 0xa2, 0xa1, 0x3e, 0xbd,
+            0x3b, 0x11, 0xbb, 0x9e, 0x68, 0x53, 0x9e, 0xc9, 0xe7, 0xa0, 0xde, 0xf9, 0x47, 0x5c,
+            0xaf, 0xee, 0xb6, 0x2d, 0xe1, 0x41, 0x96, 0xb9, 0x31, 0x4b, 0x7f, 0xda,
+        ];
+        assert_eq!(out.to_vec(), expected.to_vec());
+    }
 
     #[test]
     fn test_blake2s_mac() {
@@ -14,6 +14,7 @@ use symmetriccipher::{BlockDecryptor, BlockEncryptor};
 pub struct Blowfish {
     s: [[u32; 256]; 4],
     p: [u32; 18],
+    constant_time: bool,
 }
 
 fn next_u32_wrap(buf: &[u8], offset: &mut usize) -> u32 {
@@ -28,6 +29,18 @@ fn next_u32_wrap(buf: &[u8], offset: &mut usize) -> u32 {
     v
 }
 
+// Read table[index] by scanning the whole table, so that the memory accessed does not depend on
+// index.
+fn ct_lookup(table: &[u32; 256], index: u8) -> u32 {
+    let mut result = 0u32;
+    for (i, &entry) in table.iter().enumerate() {
+        // i ^ index is below 256, so subtracting 1 only sets the top bit when it is zero.
+        let is_index = ((i as u32 ^ index as u32).wrapping_sub(1)) >> 31;
+        result |= entry & 0u32.wrapping_sub(is_index);
+    }
+    result
+}
+
 impl Blowfish {
     pub fn new(key: &[u8]) -> Blowfish {
         //assert!(4 <= key.len() && key.len() <= 56);
@@ -36,9 +49,30 @@ impl Blowfish {
         blowfish
     }
 
+    /**
+     * Like new(), but every S-box lookup reads all 256 entries of the S-box and keeps the wanted
+     * one with a mask, so the memory accessed does not depend on the key or the data. This closes
+     * the cache timing side channel of the table lookups, for example for password hashing on a
+     * shared host, but makes each block about a hundred times slower.
+     */
+    pub fn new_constant_time(key: &[u8]) -> Blowfish {
+        let mut blowfish = Blowfish::init_state_constant_time();
+        blowfish.expand_key(key);
+        blowfish
+    }
+
+    // For bcrypt. Use Blowfish::new_constant_time instead.
+    pub fn init_state_constant_time() -> Blowfish {
+        Blowfish {
+            constant_time: true,
+            ..Blowfish::init_state()
+        }
+    }
+
     // For bcrypt. Use Blowfish::new instead.
     pub fn init_state() -> Blowfish {
         Blowfish {
+            constant_time: false,
             p: [
                 0x243f6a88, 0x85a308d3, 0x13198a2e, 0x03707344, 0xa4093822, 0x299f31d0, 0x082efa98,
                 0xec4e6c89, 0x452821e6, 0x38d01377, 0xbe5466cf, 0x34e90c6c, 0xc0ac29b7, 0xc97c50dd,
@@ -298,9 +332,15 @@ impl Blowfish {
     }
 
     fn round_function(&self, x: u32) -> u32 {
-        ((self.s[0][(x >> 24) as usize].wrapping_add(self.s[1][((x >> 16) & 0xff) as usize]))
-            ^ self.s[2][((x >> 8) & 0xff) as usize])
-            .wrapping_add(self.s[3][(x & 0xff) as usize])
+        let (a, b, c, d) = ((x >> 24) as u8, (x >> 16) as u8, (x >> 8) as u8, x as u8);
+        if self.constant_time {
+            ((ct_lookup(&self.s[0], a).wrapping_add(ct_lookup(&self.s[1], b)))
+                ^ ct_lookup(&self.s[2], c))
+            .wrapping_add(ct_lookup(&self.s[3], d))
+        } else {
+            ((self.s[0][a as usize].wrapping_add(self.s[1][b as usize])) ^ self.s[2][c as usize])
+                .wrapping_add(self.s[3][d as usize])
+        }
     }
 
     // Public for bcrypt.
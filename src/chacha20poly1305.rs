@@ -1,989 +1,1532 @@
-//// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
-//// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
-//// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
-//// option. This file may not be copied, modified, or distributed
-//// except according to those terms.
-//
-//use aead::{AeadDecryptor, AeadEncryptor};
-//use sr_std::marker::*;
-//use sr_std::prelude::*;
-//
-//use chacha20::ChaCha20;
-//use cryptoutil::write_u64_le;
-//use mac::Mac;
-//use poly1305::Poly1305;
-//use symmetriccipher::SynchronousStreamCipher;
-//use util::fixed_time_eq;
-//#[derive(Clone, Copy)]
-//pub struct ChaCha20Poly1305 {
-//    cipher: ChaCha20,
-//    mac: Poly1305,
-//    finished: bool,
-//    data_len: usize,
-//}
-//
-//impl ChaCha20Poly1305 {
-//    pub fn new(key: &[u8], nonce: &[u8], aad: &[u8]) -> ChaCha20Poly1305 {
-//        //assert!(key.len() == 16 || key.len() == 32);
-//        //assert!(nonce.len() == 8);
-//
-//        let mut cipher = ChaCha20::new(key, nonce);
-//        let mut mac_key = [0u8; 64];
-//        let zero_key = [0u8; 64];
-//        cipher.process(&zero_key, &mut mac_key);
-//
-//        let mut mac = Poly1305::new(&mac_key[..32]);
-//        mac.input(aad);
-//        let mut aad_len = [0u8; 8];
-//        let aad_len_uint: u64 = aad.len() as u64;
-//        write_u64_le(&mut aad_len, aad_len_uint);
-//        mac.input(&aad_len);
-//        ChaCha20Poly1305 {
-//            cipher: cipher,
-//            mac: mac,
-//            finished: false,
-//            data_len: 0,
-//        }
-//    }
-//}
-//
-//impl AeadEncryptor for ChaCha20Poly1305 {
-//    fn encrypt(&mut self, input: &[u8], output: &mut [u8], out_tag: &mut [u8]) {
-//        //assert!(input.len() == output.len());
-//        //assert!(self.finished == false);
-//        self.cipher.process(input, output);
-//        self.data_len += input.len();
-//        self.mac.input(output);
-//        self.finished = true;
-//        let mut data_len_buf = [0u8; 8];
-//        write_u64_le(&mut data_len_buf, self.data_len as u64);
-//        self.mac.input(&data_len_buf);
-//        self.mac.raw_result(out_tag);
-//    }
-//}
-//
-//impl AeadDecryptor for ChaCha20Poly1305 {
-//    fn decrypt(&mut self, input: &[u8], output: &mut [u8], tag: &[u8]) -> bool {
-//        //assert!(input.len() == output.len());
-//        //assert!(self.finished == false);
-//
-//        self.finished = true;
-//
-//        self.mac.input(input);
-//
-//        self.data_len += input.len();
-//        let mut data_len_buf = [0u8; 8];
-//
-//        write_u64_le(&mut data_len_buf, self.data_len as u64);
-//        self.mac.input(&data_len_buf);
-//
-//        let mut calc_tag = [0u8; 16];
-//        self.mac.raw_result(&mut calc_tag);
-//        if fixed_time_eq(&calc_tag, tag) {
-//            self.cipher.process(input, output);
-//            true
-//        } else {
-//            false
-//        }
-//    }
-//}
-//#[cfg(test)]
-//mod test {
-//    use sr_std::iter::repeat;
-//
-//    use aead::{AeadDecryptor, AeadEncryptor};
-//    use chacha20poly1305::ChaCha20Poly1305;
-//    struct TestVector {
-//        key: [u8; 32],
-//        nonce: [u8; 8],
-//        plain_text: Vec<u8>,
-//        cipher_text: Vec<u8>,
-//        aad: Vec<u8>,
-//        tag: Vec<u8>,
-//    }
-//
-//    #[test]
-//    fn test_chacha20_256_poly1305_boringssl_vectors_encrypt() {
-//        for tv in get_test_vectors().iter() {
-//            if tv.tag.len() < 16 {
-//                continue;
-//            }
-//            let mut c = ChaCha20Poly1305::new(&tv.key, &tv.nonce, &tv.aad[..]);
-//            let mut output: Vec<u8> = repeat(0).take(tv.plain_text.len()).collect();
-//            let mut tag: Vec<u8> = repeat(0).take(tv.tag.len()).collect();
-//            c.encrypt(&tv.plain_text[..], &mut output[..], &mut tag[..]);
-//            assert_eq!(output, tv.cipher_text);
-//            assert_eq!(tag, tv.tag);
-//        }
-//    }
-//    #[test]
-//    fn test_chacha20_256_poly1305_boringssl_vectors_decrypt() {
-//        for tv in get_test_vectors().iter() {
-//            if tv.tag.len() < 16 {
-//                continue;
-//            }
-//            let mut c = ChaCha20Poly1305::new(&tv.key, &tv.nonce, &tv.aad[..]);
-//            let mut output: Vec<u8> = repeat(0).take(tv.plain_text.len()).collect();
-//            let result = c.decrypt(&tv.cipher_text[..], &mut output[..], &tv.tag[..]);
-//            assert_eq!(output, tv.plain_text);
-//            //assert!(result);
-//        }
-//    }
-//    fn get_test_vectors() -> Vec<TestVector> {
-//        vec![
-//            TestVector {
-//                key: [
-//                    0x9a, 0x97, 0xf6, 0x5b, 0x9b, 0x4c, 0x72, 0x1b, 0x96, 0x0a, 0x67, 0x21, 0x45,
-//                    0xfc, 0xa8, 0xd4, 0xe3, 0x2e, 0x67, 0xf9, 0x11, 0x1e, 0xa9, 0x79, 0xce, 0x9c,
-//                    0x48, 0x26, 0x80, 0x6a, 0xee, 0xe6,
-//                ],
-//                nonce: [0x3d, 0xe9, 0xc0, 0xda, 0x2b, 0xd7, 0xf9, 0x1e],
-//                aad: vec![],
-//                plain_text: vec![],
-//                cipher_text: vec![],
-//                tag: vec![
-//                    0x5a, 0x6e, 0x21, 0xf4, 0xba, 0x6d, 0xbe, 0xe5, 0x73, 0x80, 0xe7, 0x9e, 0x79,
-//                    0xc3, 0x0d, 0xef,
-//                ],
-//            },
-//            TestVector {
-//                key: [
-//                    0xbc, 0xb2, 0x63, 0x9b, 0xf9, 0x89, 0xc6, 0x25, 0x1b, 0x29, 0xbf, 0x38, 0xd3,
-//                    0x9a, 0x9b, 0xdc, 0xe7, 0xc5, 0x5f, 0x4b, 0x2a, 0xc1, 0x2a, 0x39, 0xc8, 0xa3,
-//                    0x7b, 0x5d, 0x0a, 0x5c, 0xc2, 0xb5,
-//                ],
-//                nonce: [0x1e, 0x8b, 0x4c, 0x51, 0x0f, 0x5c, 0xa0, 0x83],
-//                plain_text: vec![0x8c, 0x84, 0x19, 0xbc, 0x27],
-//                aad: vec![0x34, 0xab, 0x88, 0xc2, 0x65],
-//                cipher_text: vec![0x1a, 0x7c, 0x2f, 0x33, 0xf5],
-//                tag: vec![
-//                    0x28, 0x75, 0xc6, 0x59, 0xd0, 0xf2, 0x80, 0x8d, 0xe3, 0xa4, 0x00, 0x27, 0xfe,
-//                    0xff, 0x91, 0xa4,
-//                ],
-//            },
-//            TestVector {
-//                key: [
-//                    0x42, 0x90, 0xbc, 0xb1, 0x54, 0x17, 0x35, 0x31, 0xf3, 0x14, 0xaf, 0x57, 0xf3,
-//                    0xbe, 0x3b, 0x50, 0x06, 0xda, 0x37, 0x1e, 0xce, 0x27, 0x2a, 0xfa, 0x1b, 0x5d,
-//                    0xbd, 0xd1, 0x10, 0x0a, 0x10, 0x07,
-//                ],
-//                nonce: [0xcd, 0x7c, 0xf6, 0x7b, 0xe3, 0x9c, 0x79, 0x4a],
-//                plain_text: vec![0x86, 0xd0, 0x99, 0x74, 0x84, 0x0b, 0xde, 0xd2, 0xa5, 0xca],
-//                aad: vec![0x87, 0xe2, 0x29, 0xd4, 0x50, 0x08, 0x45, 0xa0, 0x79, 0xc0],
-//                cipher_text: vec![0xe3, 0xe4, 0x46, 0xf7, 0xed, 0xe9, 0xa1, 0x9b, 0x62, 0xa4],
-//                tag: vec![
-//                    0x67, 0x7d, 0xab, 0xf4, 0xe3, 0xd2, 0x4b, 0x87, 0x6b, 0xb2, 0x84, 0x75, 0x38,
-//                    0x96, 0xe1, 0xd6,
-//                ],
-//            },
-//            TestVector {
-//                key: [
-//                    0x42, 0x2a, 0x53, 0x55, 0xb5, 0x6d, 0xcf, 0x2b, 0x43, 0x6a, 0xa8, 0x15, 0x28,
-//                    0x58, 0x10, 0x6a, 0x88, 0xd9, 0xba, 0x23, 0xcd, 0xfe, 0x08, 0x7b, 0x5e, 0x74,
-//                    0xe8, 0x17, 0xa5, 0x23, 0x88, 0xb3,
-//                ],
-//                nonce: [0x1d, 0x12, 0xd6, 0xd9, 0x18, 0x48, 0xf2, 0xea],
-//                plain_text: vec![
-//                    0x53, 0x7a, 0x64, 0x53, 0x87, 0xf2, 0x2d, 0x6f, 0x6d, 0xbb, 0xea, 0x56, 0x8d,
-//                    0x3f, 0xeb,
-//                ],
-//                aad: vec![
-//                    0xbe, 0xf2, 0x67, 0xc9, 0x9a, 0xec, 0x8a, 0xf5, 0x6b, 0xc2, 0x38, 0x61, 0x2b,
-//                    0xfe, 0xa6,
-//                ],
-//                cipher_text: vec![
-//                    0x28, 0x1a, 0x36, 0x67, 0x05, 0xc5, 0xa2, 0x4b, 0x94, 0xe5, 0x61, 0x46, 0x68,
-//                    0x1e, 0x44,
-//                ],
-//                tag: vec![
-//                    0x38, 0xf2, 0xb8, 0xee, 0x3b, 0xe4, 0x4a, 0xbb, 0xa3, 0xc0, 0x10, 0xd9, 0xca,
-//                    0xb6, 0xe0, 0x42,
-//                ],
-//            },
-//            TestVector {
-//                key: [
-//                    0xec, 0x7b, 0x86, 0x4a, 0x07, 0x8c, 0x3d, 0x05, 0xd9, 0x70, 0xb6, 0xea, 0x3b,
-//                    0xa6, 0xd3, 0x3d, 0x6b, 0xb7, 0x3d, 0xfa, 0x64, 0xc6, 0x22, 0xa4, 0x72, 0x7a,
-//                    0x96, 0xed, 0xe8, 0x76, 0xf6, 0x85,
-//                ],
-//                nonce: [0x2b, 0xca, 0x0e, 0x59, 0xe3, 0x95, 0x08, 0xd3],
-//                plain_text: vec![
-//                    0xb7, 0x67, 0x33, 0x89, 0x5c, 0x87, 0x1e, 0xdd, 0x72, 0x8a, 0x45, 0xed, 0x1a,
-//                    0x21, 0xf1, 0x5a, 0x95, 0x97, 0xd4, 0x9d,
-//                ],
-//                aad: vec![
-//                    0xcc, 0x12, 0x43, 0xea, 0x54, 0x27, 0x2d, 0xb6, 0x02, 0xfb, 0x08, 0x53, 0xc8,
-//                    0xe7, 0x02, 0x7c, 0x56, 0x33, 0x8b, 0x6c,
-//                ],
-//                cipher_text: vec![
-//                    0x1f, 0xb9, 0xb2, 0x95, 0x8f, 0xce, 0x47, 0xa5, 0xca, 0xda, 0x9d, 0x89, 0x5f,
-//                    0xbb, 0x0c, 0x00, 0xd3, 0x56, 0x98, 0x58,
-//                ],
-//                tag: vec![
-//                    0x04, 0x2a, 0xd5, 0x04, 0x2c, 0x89, 0xeb, 0xc1, 0xaa, 0xd5, 0x7d, 0x3f, 0xb7,
-//                    0x03, 0xd3, 0x14,
-//                ],
-//            },
-//            // TestVector{
-//            //   key: [0x2c, 0x4c, 0x0f, 0xdb, 0x61, 0x1d, 0xf2, 0xd4, 0xd5, 0xe7, 0x89, 0x8c, 0x6a, 0xf0, 0x02, 0x27, 0x95, 0x36, 0x4a, 0xdb, 0x87, 0x49, 0x15, 0x5e, 0x2c, 0x68, 0x77, 0x6a, 0x09, 0x0e, 0x7d, 0x5c],
-//            //   nonce: [0x13, 0xce, 0x73, 0x82, 0x73, 0x4c, 0x4a, 0x71],
-//            //   plain_text: vec!(0x0d, 0xc6, 0xff, 0x21, 0xa3, 0x46, 0xe1, 0x33, 0x7d, 0xd0, 0xdb, 0x81, 0xd8, 0xf7, 0xd9, 0xf6, 0xfd, 0x18, 0x64, 0x41, 0x8b, 0x98, 0xaa, 0xdc, 0xdb),
-//            //   aad: vec!(0x01, 0x15, 0xed, 0xcb, 0x17, 0x6a, 0xb8, 0xbf, 0xa9, 0x47, 0xd1, 0xf7, 0xc3, 0xa8, 0x6a, 0x84, 0x5d, 0x31, 0x0b, 0xf6, 0x70, 0x6c, 0x59, 0xa8, 0xf9),
-//            //   cipher_text: vec!(0xda, 0xd6, 0x5e, 0x42, 0x44, 0xa1, 0xa1, 0x7c, 0xe5, 0x9d, 0x88, 0xb0, 0x0a, 0xf4, 0xf7, 0x43, 0x4b, 0xd7, 0x83, 0x0f, 0xfd, 0xd4, 0xc5, 0x55, 0x8f),
-//            //   tag: vec!(0xac, 0x14, 0x37, 0xb4, 0x5d, 0x8e, 0xac, 0xf9, 0xc0, 0xfe, 0x54, 0x7c, 0x84, 0xfb, 0x82, 0xa2)
-//            // },
-//            // TestVector{
-//            //   key: [0xc6, 0x6e, 0x89, 0xfb, 0xab, 0x01, 0x20, 0x8f, 0x6a, 0x60, 0x84, 0x7f, 0x4f, 0x34, 0xb3, 0x8d, 0x27, 0xb5, 0x54, 0xc1, 0x19, 0xcf, 0x8d, 0x9e, 0x0b, 0x11, 0x8a, 0xa7, 0x26, 0x6a, 0xb8, 0x65],
-//            //   nonce: [0x5d, 0x98, 0x56, 0x06, 0x0c, 0x54, 0xab, 0x06],
-//            //   plain_text: vec!(0xf9, 0xe3, 0xe9, 0xb5, 0xed, 0x07, 0xb2, 0x08, 0x0d, 0xb8, 0xc1, 0xff, 0xc3, 0x7e, 0x4a, 0x6c, 0xb3, 0xcd, 0x54, 0x46, 0x08, 0x92, 0x1e, 0x18, 0x61, 0x0d, 0x00, 0xb1, 0x7c, 0x6e),
-//            //   aad: vec!(0x85, 0xc1, 0x12, 0xa1, 0xef, 0xe0, 0xa2, 0x0e, 0xf3, 0xa5, 0x50, 0x52, 0x6a, 0x7a, 0xfb, 0xc9, 0x8f, 0x63, 0x67, 0xeb, 0xbe, 0xde, 0x4e, 0x70, 0x30, 0x99, 0xab, 0xd7, 0x8f, 0x51),
-//            //   cipher_text: vec!(0xb5, 0xcc, 0x75, 0x4f, 0x6d, 0xd1, 0x9e, 0xf2, 0xd6, 0x6f, 0x90, 0xe6, 0xbc, 0x9a, 0x32, 0x2d, 0xdf, 0x21, 0x6e, 0xf2, 0x48, 0xcb, 0xe7, 0x6b, 0x5a, 0xb6, 0xdd, 0x53, 0xbc, 0x36),
-//            //   tag: vec!(0x6d, 0xd9, 0x87, 0x10, 0xd8, 0xa8, 0x89, 0xdc, 0xee, 0xa0, 0xd0, 0xa9, 0x36, 0xf9, 0x86, 0x17)
-//            // },
-//            // TestVector{
-//            //   key: [0xa8, 0xb9, 0x76, 0x6f, 0x40, 0x4d, 0xea, 0x8c, 0xf7, 0xd7, 0xdf, 0xaf, 0x58, 0x22, 0xf5, 0x3d, 0xf9, 0xcc, 0xd0, 0x92, 0xe3, 0x32, 0xa5, 0x7f, 0x00, 0x7b, 0x30, 0x1b, 0x50, 0x7d, 0x5e, 0x14],
-//            //   nonce: [0xc7, 0xf2, 0xf7, 0xa2, 0x33, 0x10, 0x4a, 0x2d],
-//            //   plain_text: vec!(0x4d, 0x6f, 0xae, 0xae, 0xe3, 0x91, 0x79, 0xa7, 0xc8, 0x92, 0xfa, 0xae, 0x37, 0x19, 0x65, 0x6c, 0xc6, 0x14, 0xc7, 0xe6, 0xec, 0xd8, 0xfc, 0xb5, 0x70, 0xa3, 0xb8, 0x2c, 0x4d, 0xac, 0xe9, 0x69, 0x09, 0x03, 0x38),
-//            //   aad: vec!(0xc6, 0xd8, 0x3b, 0x6a, 0x56, 0x40, 0x8a, 0x35, 0x6e, 0x68, 0xd0, 0x49, 0x4d, 0x4e, 0xff, 0x15, 0x05, 0x30, 0xb0, 0x95, 0x51, 0xd0, 0x08, 0x37, 0x3d, 0x6d, 0xee, 0x2b, 0x8d, 0x6b, 0x56, 0x19, 0xd6, 0x7f, 0xdb),
-//            //   cipher_text: vec!(0xa1, 0x54, 0x43, 0xf0, 0x83, 0x31, 0x6e, 0xef, 0x62, 0x7a, 0x37, 0x1f, 0x4c, 0x9a, 0xc6, 0x54, 0xd0, 0xdd, 0x75, 0x25, 0x5d, 0x8a, 0x30, 0x31, 0x25, 0xe9, 0xf5, 0x1a, 0xf4, 0x23, 0x3f, 0xf4, 0xce, 0xb7, 0xfe),
-//            //   tag: vec!(0x52, 0x50, 0x4e, 0x88, 0x0f, 0x67, 0x92, 0xa6, 0x07, 0x08, 0xcc, 0x6d, 0xb7, 0x2e, 0xae, 0x42)
-//            // },
-//            // TestVector{
-//            //   key: [0x5e, 0x8d, 0x0e, 0x5f, 0x14, 0x67, 0xf7, 0xa7, 0x50, 0xc5, 0x51, 0x44, 0xd0, 0xc6, 0x70, 0xf7, 0xd9, 0x10, 0x75, 0xf3, 0x86, 0x79, 0x5b, 0x23, 0x0c, 0x9b, 0xf1, 0xc0, 0x4b, 0xa2, 0x50, 0xbc],
-//            //   nonce: [0x88, 0x04, 0x9f, 0x44, 0xba, 0x61, 0xb8, 0x8f],
-//            //   plain_text: vec!(0x51, 0xa1, 0xee, 0xbc, 0xc3, 0x48, 0xe0, 0x58, 0x21, 0x96, 0xa0, 0xbc, 0xe1, 0x6e, 0xd1, 0xf8, 0xac, 0x2e, 0x91, 0xc3, 0xe8, 0xa6, 0x90, 0xe0, 0x4a, 0x9f, 0x4b, 0x5c, 0xf6, 0x33, 0x13, 0xd7, 0xad, 0x08, 0xd1, 0xef, 0xbf, 0xf8, 0x5c, 0x89),
-//            //   aad: vec!(0x5d, 0x09, 0xbf, 0x0b, 0xe9, 0x00, 0x26, 0xf9, 0xfc, 0x51, 0xf7, 0x34, 0x18, 0xd6, 0xd8, 0x64, 0xb6, 0xd1, 0x97, 0xea, 0x03, 0x0b, 0x3d, 0xe0, 0x72, 0xbd, 0x2c, 0x2f, 0x5c, 0xab, 0x58, 0x60, 0xa3, 0x42, 0xab, 0xbd, 0x29, 0xdb, 0xa9, 0xdc),
-//            //   cipher_text: vec!(0x35, 0xaa, 0x4b, 0xd4, 0x53, 0x7a, 0xa6, 0x11, 0xfd, 0x75, 0x78, 0xfc, 0x22, 0x7d, 0xf5, 0x0e, 0xbc, 0xb0, 0x0c, 0x69, 0x2a, 0x1c, 0xf6, 0xf0, 0x2e, 0x50, 0xed, 0x92, 0x70, 0xbd, 0x93, 0xaf, 0x3b, 0xc6, 0x8f, 0x4c, 0x75, 0xb9, 0x66, 0x38),
-//            //   tag: vec!(0xcc, 0xea, 0x1c, 0xbb, 0xc8, 0x39, 0x44, 0xcc, 0x66, 0xdf, 0x4d, 0xbf, 0x6f, 0xb7, 0xfc, 0x46)
-//            // },
-//            // TestVector{
-//            //   key: [0x21, 0xa9, 0xf0, 0x7e, 0xc8, 0x91, 0xd4, 0x88, 0x80, 0x5e, 0x9b, 0x92, 0xbb, 0x1b, 0x22, 0x86, 0xf3, 0xf0, 0x41, 0x0c, 0x32, 0x3b, 0x07, 0xfe, 0xe1, 0xdc, 0x6f, 0x73, 0x79, 0xe2, 0x2e, 0x48],
-//            //   nonce: [0x06, 0x62, 0x15, 0xbe, 0x65, 0x67, 0x37, 0x7a],
-//            //   plain_text: vec!(0xc1, 0xb0, 0xaf, 0xfa, 0xf2, 0xb8, 0xd7, 0xef, 0x51, 0xcc, 0xa9, 0xaa, 0xcf, 0x79, 0x69, 0xf9, 0x2f, 0x92, 0x8c, 0x2e, 0x3c, 0xc7, 0xdb, 0x2e, 0x15, 0xf4, 0x7e, 0xe1, 0xf6, 0x50, 0x23, 0x91, 0x0d, 0x09, 0xf2, 0x09, 0xd0, 0x07, 0xb7, 0x43, 0x6e, 0xe8, 0x98, 0x13, 0x3d),
-//            //   aad: vec!(0xdf, 0xdf, 0xdf, 0x4d, 0x3a, 0x68, 0xb4, 0x7a, 0xd0, 0xd4, 0x88, 0x28, 0xdc, 0x17, 0xb2, 0x58, 0x5d, 0xa9, 0xc8, 0x1c, 0x3a, 0x8d, 0x71, 0xd8, 0x26, 0xb5, 0xfa, 0x80, 0x20, 0xfe, 0xe0, 0x02, 0x39, 0x7e, 0x91, 0xfc, 0x96, 0x58, 0xe9, 0xd6, 0x1d, 0x72, 0x8b, 0x93, 0xeb),
-//            //   cipher_text: vec!(0x8f, 0xf4, 0xce, 0xb6, 0x00, 0xe7, 0xd4, 0x56, 0x96, 0xd0, 0x24, 0x67, 0xf8, 0xe3, 0x0d, 0xf0, 0xd3, 0x38, 0x64, 0xa0, 0x40, 0xa4, 0x1f, 0xfb, 0x9e, 0x4c, 0x2d, 0xa0, 0x9b, 0x92, 0xe8, 0x8b, 0x6f, 0x6b, 0x85, 0x0e, 0x9f, 0x72, 0x58, 0xd8, 0x27, 0xb9, 0xaa, 0xf3, 0x46),
-//            //   tag: vec!(0x4e, 0xed, 0xdc, 0x99, 0x78, 0x40, 0x11, 0xf0, 0x75, 0x8b, 0xa5, 0xeb, 0xfb, 0xa6, 0x18, 0x27)
-//            // },
-//            // TestVector{
-//            //   key: [0x54, 0xc9, 0x3d, 0xb9, 0xaa, 0x0e, 0x00, 0xd1, 0x0b, 0x45, 0x04, 0x1c, 0x7a, 0x7e, 0x41, 0xee, 0x9f, 0x90, 0xab, 0x78, 0xae, 0x4c, 0x1b, 0xba, 0x18, 0xd6, 0x73, 0xc3, 0xb3, 0x70, 0xab, 0xde],
-//            //   nonce: [0x3f, 0x2d, 0x44, 0xe7, 0xb3, 0x52, 0x36, 0x0f],
-//            //   plain_text: vec!(0x12, 0x41, 0xe7, 0xd6, 0xfb, 0xe5, 0xee, 0xf5, 0xd8, 0xaf, 0x9c, 0x2f, 0xb8, 0xb5, 0x16, 0xe0, 0xf1, 0xdd, 0x49, 0xaa, 0x4e, 0xbe, 0x54, 0x91, 0x20, 0x51, 0x94, 0xfe, 0x5a, 0xea, 0x37, 0x04, 0xef, 0xaf, 0x30, 0xd3, 0x92, 0xf4, 0x4c, 0xc9, 0x9e, 0x09, 0x25, 0xb8, 0x44, 0x60, 0xd4, 0x87, 0x33, 0x44),
-//            //   aad: vec!(0xf1, 0xd1, 0xb0, 0x8d, 0xd6, 0xfe, 0x96, 0xc4, 0x65, 0x78, 0xc1, 0xd1, 0xad, 0x38, 0x88, 0x18, 0x40, 0xb1, 0x0c, 0xb5, 0xea, 0xe4, 0x1e, 0x5f, 0x05, 0xfe, 0x52, 0x87, 0x22, 0x3f, 0xa7, 0x22, 0x42, 0xae, 0xa4, 0x8c, 0xb3, 0x74, 0xa8, 0x0b, 0xe9, 0x37, 0xb5, 0x41, 0xf9, 0x38, 0x1e, 0xfa, 0x66, 0xbb),
-//            //   cipher_text: vec!(0x02, 0x7b, 0x86, 0x86, 0x5b, 0x80, 0xb4, 0xc4, 0xda, 0x82, 0x3a, 0x7d, 0x3d, 0xbc, 0xf5, 0x84, 0x5b, 0xf5, 0x7d, 0x58, 0xee, 0x33, 0x4e, 0xb3, 0x57, 0xe8, 0x23, 0x69, 0xcc, 0x62, 0x89, 0x79, 0xe2, 0x94, 0x78, 0x30, 0xd9, 0xd4, 0x81, 0x7e, 0xfd, 0x3d, 0x0b, 0xc4, 0x77, 0x9f, 0x0b, 0x38, 0x89, 0x43),
-//            //   tag: vec!(0x43, 0x03, 0xfa, 0x01, 0x74, 0xac, 0x2b, 0x99, 0x16, 0xbf, 0x89, 0xc5, 0x93, 0xba, 0xee, 0x37)
-//            // },
-//            // TestVector{
-//            //   key: [0x80, 0x8e, 0x0e, 0x73, 0xe9, 0xbc, 0xd2, 0x74, 0xd4, 0xc6, 0xf6, 0x5d, 0xf2, 0xfe, 0x95, 0x78, 0x22, 0xa6, 0x02, 0xf0, 0x39, 0xd4, 0x75, 0x26, 0x16, 0xba, 0x29, 0xa2, 0x89, 0x26, 0xef, 0x4a],
-//            //   nonce: [0x1b, 0x9c, 0xd7, 0x3d, 0x2f, 0xc3, 0xcb, 0x8e],
-//            //   plain_text: vec!(0x34, 0x36, 0xc7, 0xb5, 0xbe, 0x23, 0x94, 0xaf, 0x7e, 0x88, 0x32, 0x0c, 0x82, 0x32, 0x6a, 0x6d, 0xb3, 0x78, 0x87, 0xff, 0x9d, 0xe4, 0x19, 0x61, 0xc7, 0xd6, 0x54, 0xdd, 0x22, 0xdd, 0x1f, 0x7d, 0x40, 0x44, 0x4d, 0x48, 0xf5, 0xc6, 0x63, 0xb8, 0x6f, 0xf4, 0x1f, 0x3e, 0x15, 0xb5, 0xc8, 0xca, 0x13, 0x37, 0xf9, 0x76, 0x35, 0x85, 0x8f),
-//            //   aad: vec!(0xd5, 0x7c, 0xfb, 0xe5, 0xf2, 0x53, 0x80, 0x44, 0x28, 0x2e, 0x53, 0xb2, 0xf0, 0xbb, 0x4e, 0x86, 0xea, 0x22, 0x33, 0x04, 0x1f, 0xb3, 0x6a, 0xdb, 0x83, 0x38, 0xde, 0xd0, 0x92, 0x14, 0x8f, 0x8c, 0x2e, 0x89, 0x4e, 0xf8, 0x76, 0x6a, 0x7e, 0xc2, 0xdd, 0x02, 0xc6, 0xac, 0x5d, 0xba, 0xb0, 0xc3, 0x70, 0x3c, 0x5e, 0x91, 0x19, 0xe3, 0x7c),
-//            //   cipher_text: vec!(0x9b, 0x95, 0x0b, 0x3c, 0xaf, 0x7d, 0x25, 0xea, 0xf5, 0xfc, 0xa6, 0xfa, 0x3f, 0xe1, 0x2e, 0xd0, 0x77, 0xd8, 0x0d, 0xcd, 0x55, 0x79, 0x85, 0x12, 0x33, 0xc7, 0x66, 0xbb, 0x8b, 0xb6, 0x13, 0xec, 0x91, 0xd9, 0x25, 0xa9, 0x39, 0xbb, 0x52, 0xfb, 0x88, 0xd5, 0xed, 0xa8, 0x03, 0xcf, 0xe2, 0xa8, 0xcd, 0xa2, 0xe0, 0x55, 0xb9, 0x62, 0xfd),
-//            //   tag: vec!(0x6b, 0xf5, 0xb7, 0x18, 0xf5, 0xbb, 0xe1, 0x39, 0x5a, 0x5f, 0xdf, 0xcb, 0xbe, 0xf7, 0x52, 0xf5)
-//            // },
-//            // TestVector{
-//            //   key: [0x4a, 0xdf, 0xe1, 0xa2, 0x6c, 0x56, 0x36, 0x53, 0x6c, 0xd7, 0xcb, 0x72, 0xaa, 0x5b, 0xde, 0xd0, 0xb1, 0xaa, 0x64, 0x48, 0x7a, 0xd0, 0xe4, 0x07, 0x8f, 0x31, 0x1e, 0x87, 0x82, 0x76, 0x8e, 0x97],
-//            //   nonce: [0xd6, 0x9e, 0x54, 0xba, 0xde, 0xc1, 0x15, 0x60],
-//            //   plain_text: vec!(0x19, 0xb3, 0xf9, 0x41, 0x1c, 0xe8, 0x75, 0xfc, 0xb6, 0x84, 0xcb, 0xdc, 0x07, 0x93, 0x8c, 0x4c, 0x13, 0x47, 0xe1, 0x64, 0xf9, 0x64, 0x0d, 0x37, 0xb2, 0x2f, 0x97, 0x5b, 0x4b, 0x9a, 0x37, 0x3c, 0x43, 0x02, 0xae, 0x0e, 0x7d, 0xfd, 0xeb, 0xa1, 0xe0, 0xd0, 0x0c, 0xed, 0x44, 0x6e, 0x33, 0x8f, 0x4c, 0x5b, 0xc0, 0x1b, 0x4b, 0xec, 0xef, 0x51, 0x15, 0x82, 0x52, 0x76),
-//            //   aad: vec!(0xbd, 0xa1, 0xb0, 0xf6, 0xc2, 0xf4, 0xeb, 0x81, 0x21, 0xdc, 0xbd, 0x2e, 0xeb, 0xd9, 0x1a, 0x03, 0xae, 0x1d, 0x6e, 0x05, 0x23, 0xb9, 0xb6, 0xf3, 0x4b, 0x6f, 0x16, 0xce, 0xca, 0x0d, 0x08, 0x66, 0x54, 0xfb, 0x05, 0x52, 0xbf, 0xd5, 0xc8, 0xe1, 0x88, 0x77, 0x30, 0xe1, 0x44, 0x9e, 0xa0, 0x2d, 0x7f, 0x64, 0x7a, 0xe8, 0x35, 0xbc, 0x2d, 0xab, 0x4b, 0xbc, 0x65, 0xb9),
-//            //   cipher_text: vec!(0xea, 0x76, 0x5a, 0x82, 0x9d, 0x96, 0x1e, 0x08, 0xba, 0xca, 0xed, 0x80, 0x12, 0x37, 0xef, 0x40, 0x67, 0xdf, 0x38, 0xad, 0x37, 0x37, 0xb7, 0xc6, 0xde, 0x4d, 0xb5, 0x87, 0xa1, 0x02, 0xa8, 0x6f, 0xc4, 0xab, 0xba, 0xab, 0xea, 0x0e, 0xe9, 0x7c, 0x95, 0xca, 0x7f, 0x57, 0x1c, 0x7b, 0xab, 0x6f, 0x38, 0xcb, 0xae, 0x60, 0xcd, 0x6e, 0x6a, 0x4c, 0xe3, 0xc7, 0xa3, 0x20),
-//            //   tag: vec!(0xb4, 0x25, 0xcd, 0xf1, 0x0c, 0xd0, 0x12, 0x3a, 0x7e, 0x64, 0xb3, 0x47, 0xc6, 0xb4, 0xb1, 0xf0)
-//            // },
-//            // TestVector{
-//            //   key: [0xeb, 0x3d, 0xb8, 0x6c, 0x14, 0xb7, 0xcc, 0x2e, 0x49, 0x43, 0x45, 0xd0, 0xdf, 0xb4, 0x84, 0x1b, 0xbd, 0x3a, 0xa1, 0xe2, 0xbc, 0x64, 0x0c, 0xca, 0x0c, 0x6c, 0x40, 0x55, 0x20, 0x68, 0x56, 0x39],
-//            //   nonce: [0x88, 0xb5, 0x4b, 0x28, 0xd6, 0xda, 0x8c, 0x81],
-//            //   plain_text: vec!(0xf7, 0x5c, 0x0a, 0x35, 0x72, 0x71, 0x43, 0x0b, 0x1e, 0xcf, 0xf0, 0x7a, 0x30, 0x7b, 0x6c, 0x29, 0x32, 0x5c, 0x6e, 0x66, 0x93, 0x50, 0x46, 0x70, 0x4a, 0x19, 0x84, 0x5e, 0x62, 0x9f, 0x87, 0xa9, 0xe3, 0xb8, 0xaa, 0x6c, 0x1d, 0xf5, 0x5d, 0xd4, 0x26, 0xa4, 0x87, 0xd5, 0x33, 0xbb, 0x33, 0x3e, 0x46, 0xf0, 0xd3, 0x41, 0x84, 0x64, 0xac, 0x1b, 0xef, 0x05, 0x92, 0x31, 0xf8, 0xe8, 0x7e, 0x62, 0x84),
-//            //   aad: vec!(0x34, 0xb0, 0x8b, 0xb0, 0xdf, 0x82, 0x1c, 0x57, 0x3d, 0xcb, 0x56, 0xf5, 0xb8, 0xb4, 0xa9, 0x92, 0x04, 0x65, 0x06, 0x7f, 0x3b, 0x5b, 0xf3, 0xe3, 0x25, 0x4e, 0xa1, 0xda, 0x1a, 0x7f, 0xc9, 0x84, 0x7f, 0xd3, 0x8b, 0xdf, 0xe6, 0xb3, 0x09, 0x27, 0x94, 0x52, 0x63, 0xa9, 0x1f, 0xa2, 0x88, 0xc7, 0xcf, 0x1b, 0xee, 0x0f, 0xdd, 0xb0, 0xfa, 0xdf, 0x59, 0x48, 0xc5, 0xd8, 0x3e, 0xb4, 0x62, 0x35, 0x75),
-//            //   cipher_text: vec!(0x14, 0x6e, 0xc8, 0x4f, 0x5d, 0xc1, 0xc9, 0xfe, 0x9d, 0xe3, 0x30, 0x7a, 0x91, 0x82, 0xdb, 0xaa, 0x75, 0x96, 0x5b, 0xf8, 0x5f, 0x5e, 0x64, 0x56, 0x3e, 0x68, 0xd0, 0x39, 0xa5, 0xb6, 0x59, 0xaa, 0x88, 0x63, 0xb8, 0x92, 0x28, 0xed, 0xb9, 0x3f, 0xf3, 0xd8, 0xc3, 0x32, 0x3a, 0xb0, 0xd0, 0x33, 0x00, 0x47, 0x6a, 0xa4, 0xac, 0xa2, 0x06, 0xd4, 0x62, 0x6a, 0x6b, 0x26, 0x9b, 0x20, 0x78, 0x91, 0x2d),
-//            //   tag: vec!(0x00, 0x58, 0xa8, 0xdf, 0xf3, 0x2c, 0x29, 0x93, 0x5c, 0x62, 0x21, 0x0c, 0x35, 0x9b, 0xd2, 0x81)
-//            // },
-//            // TestVector{
-//            //   key: [0xdd, 0x5b, 0x49, 0xb5, 0x95, 0x3e, 0x04, 0xd9, 0x26, 0xd6, 0x64, 0xda, 0x3b, 0x65, 0xeb, 0xcf, 0xfb, 0xbf, 0x06, 0xab, 0xbe, 0x93, 0xa3, 0x81, 0x9d, 0xfc, 0x1a, 0xbb, 0xec, 0xba, 0xab, 0x13],
-//            //   nonce: [0xc5, 0xc8, 0x00, 0x94, 0x59, 0xb9, 0xe3, 0x1a],
-//            //   plain_text: vec!(0xf2, 0x1f, 0x67, 0x06, 0xa4, 0xdc, 0x33, 0xa3, 0x61, 0x36, 0x2c, 0x21, 0x4d, 0xef, 0xd5, 0x6d, 0x35, 0x3b, 0xcb, 0x29, 0x81, 0x1e, 0x58, 0x19, 0xab, 0x3c, 0x5c, 0x2c, 0x13, 0x95, 0x0c, 0x7a, 0xa0, 0x00, 0x0b, 0x9d, 0x1f, 0xe6, 0x9b, 0xb4, 0x64, 0x54, 0x51, 0x4d, 0xcc, 0xe8, 0x8a, 0x4a, 0x5e, 0xda, 0x09, 0x7c, 0x28, 0x1b, 0x81, 0xe5, 0x1d, 0x6a, 0x4d, 0xba, 0x47, 0xc8, 0x03, 0x26, 0xba, 0x6c, 0xea, 0x8e, 0x2b, 0xab),
-//            //   aad: vec!(0xfe, 0x6f, 0x4c, 0xbb, 0x00, 0x79, 0x4a, 0xde, 0xa5, 0x9e, 0x9d, 0xe8, 0xb0, 0x3c, 0x7f, 0xdf, 0x48, 0x2e, 0x46, 0xf6, 0xc4, 0x7a, 0x35, 0xf9, 0x69, 0x97, 0x66, 0x9c, 0x73, 0x5e, 0xd5, 0xe7, 0x29, 0xa4, 0x94, 0x16, 0xb4, 0x24, 0x68, 0x77, 0x7e, 0x6a, 0x8d, 0x7a, 0xa1, 0x73, 0xc1, 0x8b, 0x81, 0x77, 0x41, 0x8d, 0xed, 0x60, 0x01, 0x24, 0xa9, 0x8c, 0xbb, 0x65, 0x48, 0x9f, 0x9c, 0x24, 0xa0, 0x4f, 0x1e, 0x71, 0x27, 0xce),
-//            //   cipher_text: vec!(0x91, 0x1e, 0xad, 0x61, 0xb2, 0xaa, 0x81, 0xd0, 0x0c, 0x5e, 0xff, 0x53, 0xae, 0xea, 0x3a, 0xb7, 0x13, 0x70, 0x9e, 0xd5, 0x71, 0x76, 0x58, 0x90, 0xd5, 0x58, 0xfb, 0x59, 0xd3, 0x99, 0x3b, 0x45, 0xf5, 0x98, 0xa3, 0x9e, 0x5e, 0xff, 0x4b, 0xe8, 0x44, 0xc4, 0xd4, 0xbd, 0x1e, 0xf9, 0x62, 0x2e, 0x60, 0x41, 0x2b, 0x21, 0x14, 0x00, 0x07, 0xd5, 0x4d, 0xcf, 0x31, 0xb2, 0xc0, 0xe3, 0xe9, 0x8c, 0xf3, 0x3a, 0x00, 0xfd, 0x27, 0xf0),
-//            //   tag: vec!(0xd3, 0x8d, 0x67, 0x26, 0x65, 0xe2, 0xc8, 0xc4, 0xa0, 0x79, 0x54, 0xb1, 0x0e, 0xcf, 0xf7, 0xd9)
-//            // },
-//            // TestVector{
-//            //   key: [0x3b, 0x31, 0x9e, 0x40, 0x14, 0x8a, 0x67, 0xdc, 0x0b, 0xb1, 0x92, 0x71, 0xd9, 0x27, 0x2b, 0x32, 0x7b, 0xc5, 0xee, 0xe0, 0x87, 0x17, 0x3d, 0x3d, 0x13, 0x4a, 0xd5, 0x6c, 0x8c, 0x7d, 0xc0, 0x20],
-//            //   nonce: [0xce, 0x5c, 0xf6, 0xfe, 0xf8, 0x4d, 0x00, 0x10],
-//            //   plain_text: vec!(0x27, 0xb5, 0x62, 0x7b, 0x17, 0xa2, 0xde, 0x31, 0xad, 0x00, 0xfc, 0x2e, 0xcb, 0x34, 0x7d, 0xa0, 0xa3, 0x99, 0xbb, 0x75, 0xcc, 0x6e, 0xad, 0xd4, 0xd6, 0xee, 0x02, 0xde, 0x8f, 0xbd, 0x6a, 0x21, 0x68, 0xd4, 0x76, 0x3b, 0xa9, 0x36, 0x8b, 0xa9, 0x82, 0xe9, 0x7a, 0x2d, 0xb8, 0x12, 0x6d, 0xf0, 0x34, 0x3c, 0xda, 0xd0, 0x6d, 0x2b, 0xc7, 0xd7, 0xe1, 0x2e, 0xec, 0x73, 0x1d, 0x13, 0x0f, 0x8b, 0x87, 0x45, 0xc1, 0x95, 0x4b, 0xfd, 0x1d, 0x71, 0x7b, 0x4e, 0xa2),
-//            //   aad: vec!(0xa0, 0x26, 0xb6, 0x63, 0x8f, 0x29, 0x39, 0xec, 0x9c, 0xc2, 0x8d, 0x93, 0x5f, 0xb7, 0x11, 0x31, 0x57, 0xf3, 0xb5, 0xb7, 0xe2, 0x6c, 0x12, 0xf8, 0xf2, 0x5b, 0x36, 0x41, 0x2b, 0x0c, 0xd5, 0x60, 0xb7, 0xf1, 0x1b, 0x62, 0x78, 0x8a, 0x76, 0xbd, 0x17, 0x13, 0x42, 0xe2, 0xae, 0x85, 0x8b, 0xce, 0xcb, 0x82, 0x66, 0xff, 0x84, 0x82, 0xbb, 0xae, 0xd5, 0x93, 0xaf, 0xe8, 0x18, 0xb9, 0x82, 0x9e, 0x05, 0xe8, 0xe2, 0xb2, 0x81, 0xae, 0x77, 0x99, 0x58, 0x01, 0x42),
-//            //   cipher_text: vec!(0x36, 0x8f, 0xb6, 0x98, 0x92, 0x44, 0x7b, 0x75, 0x77, 0x8f, 0x1c, 0x52, 0x36, 0xe1, 0xe9, 0xd5, 0xd8, 0x92, 0x55, 0xc3, 0xd6, 0x8d, 0x56, 0x5a, 0x5b, 0xba, 0x4f, 0x52, 0x4d, 0x6a, 0xd2, 0x7d, 0xe1, 0x30, 0x87, 0xf3, 0x01, 0xe2, 0xef, 0x4c, 0x08, 0xf5, 0xe2, 0xc6, 0x12, 0x8b, 0x1d, 0x3e, 0x26, 0xde, 0x84, 0x5c, 0x4a, 0xc4, 0x86, 0x9e, 0x4c, 0x8b, 0xd8, 0x85, 0x8a, 0xd0, 0xd2, 0x6d, 0xec, 0x3b, 0x5d, 0x61, 0xa9, 0xe3, 0x66, 0x6a, 0x39, 0x11, 0xba),
-//            //   tag: vec!(0x2e, 0x70, 0x56, 0x4c, 0x39, 0x99, 0xc4, 0x48, 0xd9, 0x2c, 0xc6, 0xdf, 0x29, 0xd0, 0x95, 0xc4)
-//            // },
-//            // TestVector{
-//            //   key: [0x43, 0xbf, 0x97, 0x40, 0x7a, 0x82, 0xd0, 0xf6, 0x84, 0xbb, 0x85, 0x34, 0x23, 0x80, 0xd6, 0x6b, 0x85, 0xfc, 0xc8, 0x1c, 0x3e, 0x22, 0xf1, 0xc0, 0xd9, 0x72, 0xcd, 0x5b, 0xfd, 0xf4, 0x07, 0xf4],
-//            //   nonce: [0x8b, 0x6b, 0xa4, 0x94, 0xc5, 0x40, 0xfb, 0xa4],
-//            //   plain_text: vec!(0x4b, 0x4c, 0x7e, 0x29, 0x2a, 0x35, 0x7f, 0x56, 0xfd, 0xf5, 0x67, 0xc3, 0x2f, 0xc0, 0xf3, 0x36, 0x08, 0x11, 0x0d, 0x7c, 0xe5, 0xc6, 0x91, 0x12, 0x98, 0x7d, 0x7b, 0x5a, 0x0b, 0xd4, 0x6d, 0x86, 0x27, 0xa7, 0x21, 0xb0, 0xae, 0xd0, 0x70, 0xb5, 0x4e, 0xa9, 0x72, 0x60, 0x84, 0x18, 0x8c, 0x51, 0x8c, 0xba, 0x82, 0x9f, 0x39, 0x20, 0x36, 0x5a, 0xfc, 0x93, 0x82, 0xc6, 0xa5, 0xeb, 0x0d, 0xd3, 0x32, 0xb8, 0x46, 0x12, 0x36, 0x67, 0x35, 0xbe, 0x24, 0x79, 0xb6, 0x3c, 0x9e, 0xfc, 0x7f, 0xf5),
-//            //   aad: vec!(0x1e, 0x0a, 0xcf, 0x40, 0x70, 0xe8, 0xd6, 0x75, 0x8b, 0x60, 0xd8, 0x1b, 0x6d, 0x28, 0x9a, 0x4e, 0xcd, 0xc3, 0x0e, 0x3d, 0xe4, 0xf9, 0x09, 0x0c, 0x13, 0x69, 0x1d, 0x5b, 0x93, 0xd5, 0xbb, 0xce, 0xf9, 0x84, 0xf9, 0x09, 0x56, 0xde, 0x53, 0xc5, 0xcf, 0x44, 0xbe, 0x6c, 0x70, 0x44, 0x06, 0x61, 0xfa, 0x58, 0xe6, 0x5d, 0xec, 0x27, 0x34, 0xff, 0x51, 0xd6, 0xd0, 0x3f, 0x57, 0xbd, 0xdd, 0xa1, 0xf4, 0x78, 0x07, 0x24, 0x7e, 0x31, 0x94, 0xe2, 0xf7, 0xdd, 0xd5, 0xf3, 0xca, 0xfd, 0x25, 0x0f),
-//            //   cipher_text: vec!(0xd0, 0x07, 0x6c, 0x88, 0xad, 0x4b, 0xc1, 0x2d, 0x77, 0xeb, 0x8a, 0xe8, 0xd9, 0xb5, 0xbf, 0x3a, 0x2c, 0x58, 0x88, 0xa8, 0xd4, 0xc1, 0x52, 0x97, 0xb3, 0x8e, 0xce, 0x5d, 0x64, 0xf6, 0x73, 0x19, 0x1d, 0xc8, 0x15, 0x47, 0x24, 0x0a, 0x0c, 0xbe, 0x06, 0x6c, 0x9c, 0x56, 0x3f, 0x5c, 0x34, 0x24, 0x80, 0x99, 0x71, 0xb5, 0xa0, 0x7d, 0xcc, 0x70, 0xb1, 0x07, 0x30, 0x55, 0x61, 0xce, 0x85, 0xae, 0xcb, 0x0b, 0x0e, 0xa0, 0xe8, 0xb4, 0xff, 0x4d, 0x1e, 0x4f, 0x84, 0x83, 0x69, 0x55, 0xa9, 0x45),
-//            //   tag: vec!(0x75, 0xc9, 0x34, 0x74, 0x25, 0xb4, 0x59, 0xaf, 0x6d, 0x99, 0xb1, 0x73, 0x45, 0xc6, 0x1f, 0xf7)
-//            // },
-//            // TestVector{
-//            //   key: [0x12, 0xfc, 0x0b, 0xc9, 0x41, 0x04, 0xed, 0x81, 0x50, 0xbd, 0xe1, 0xe5, 0x68, 0x56, 0xce, 0x3c, 0x57, 0xcd, 0x1c, 0xf6, 0x33, 0x95, 0x4d, 0x22, 0x55, 0x21, 0x40, 0xe1, 0xf4, 0xe7, 0xc6, 0x5d],
-//            //   nonce: [0xd3, 0x87, 0x5d, 0x1b, 0x6c, 0x80, 0x83, 0x53],
-//            //   plain_text: vec!(0x24, 0x59, 0x20, 0x82, 0xd6, 0xe7, 0x3e, 0xb6, 0x5c, 0x40, 0x9b, 0x26, 0xce, 0xae, 0x03, 0x2e, 0x57, 0xf6, 0x87, 0x75, 0x14, 0x94, 0x7f, 0xc4, 0x5e, 0xb0, 0x07, 0xb8, 0xa6, 0x03, 0x44, 0x94, 0xdd, 0xe5, 0x56, 0x3a, 0xc5, 0x86, 0xea, 0x08, 0x1d, 0xc1, 0x2f, 0xa6, 0xcd, 0xa3, 0x22, 0x66, 0xbe, 0x85, 0x8e, 0x47, 0x48, 0xbe, 0x40, 0xbb, 0x20, 0xf7, 0x13, 0x20, 0x71, 0x1b, 0xf8, 0x4c, 0x3f, 0x0e, 0x27, 0x83, 0xa6, 0x3a, 0xd6, 0xe2, 0x5a, 0x63, 0xb4, 0x4c, 0x37, 0x3a, 0x99, 0xaf, 0x84, 0x5c, 0xdf, 0x45, 0x2c),
-//            //   aad: vec!(0xb8, 0xbe, 0x08, 0x46, 0x3e, 0x84, 0xa9, 0x09, 0xd0, 0x71, 0xf5, 0xff, 0x87, 0x21, 0x33, 0x91, 0xb7, 0xda, 0x88, 0x9d, 0xc5, 0x6f, 0xd2, 0xf1, 0xe3, 0xcf, 0x86, 0xa0, 0xa0, 0x3e, 0x2c, 0x8e, 0xaa, 0x2f, 0x53, 0x9b, 0xf7, 0x3f, 0x90, 0xf5, 0x29, 0x8c, 0x26, 0xf2, 0x7e, 0xf4, 0xa6, 0x73, 0xa1, 0x27, 0x84, 0x83, 0x3a, 0xcb, 0x4d, 0x08, 0x61, 0x56, 0x21, 0x42, 0xc9, 0x74, 0xee, 0x37, 0xb0, 0x9a, 0xe7, 0x70, 0x8a, 0x19, 0xf1, 0x4d, 0x1a, 0xd8, 0xc4, 0x02, 0xbd, 0x1e, 0xcf, 0x5e, 0xa2, 0x80, 0xfa, 0xb2, 0x80),
-//            //   cipher_text: vec!(0x9d, 0x9a, 0xe6, 0x32, 0x87, 0x11, 0xfb, 0x89, 0x7a, 0x88, 0x46, 0x2d, 0x20, 0xb8, 0xaa, 0x1b, 0x27, 0x81, 0x34, 0xcd, 0xf7, 0xb2, 0x3e, 0x1f, 0x1c, 0x80, 0x9f, 0xa4, 0x08, 0xb6, 0x8a, 0x7b, 0xfc, 0x2b, 0xe6, 0x1a, 0x79, 0x00, 0x08, 0xed, 0xaa, 0x98, 0x82, 0x33, 0x81, 0xf4, 0x5a, 0xe6, 0x5f, 0x71, 0x04, 0x26, 0x89, 0xd8, 0x8a, 0xcf, 0xa5, 0xf6, 0x33, 0x32, 0xf0, 0xfb, 0xa7, 0x37, 0xc4, 0x77, 0x2c, 0x97, 0x2e, 0xba, 0x26, 0x66, 0x40, 0x05, 0x64, 0x52, 0x90, 0x3d, 0x65, 0x22, 0xce, 0xfd, 0x3f, 0x26, 0x4e),
-//            //   tag: vec!(0xe9, 0xc9, 0x82, 0xd4, 0xad, 0xe7, 0x39, 0x7b, 0xcf, 0xaa, 0x1e, 0x4c, 0x5a, 0x6c, 0xd5, 0x78)
-//            // },
-//            // TestVector{
-//            //   key: [0x7b, 0x63, 0x00, 0xf7, 0xdc, 0x21, 0xc9, 0xfd, 0xde, 0xaa, 0x71, 0xf4, 0x39, 0xd5, 0x3b, 0x55, 0x3a, 0x7b, 0xf3, 0xe6, 0x9f, 0xf5, 0x15, 0xb5, 0xcb, 0x64, 0x95, 0xd6, 0x52, 0xa0, 0xf9, 0x9c],
-//            //   nonce: [0x40, 0xb3, 0x2e, 0x3f, 0xdc, 0x64, 0x64, 0x53],
-//            //   plain_text: vec!(0x57, 0x2f, 0x60, 0xd9, 0x8c, 0x8b, 0xec, 0xc8, 0xba, 0x80, 0xdd, 0x6b, 0x8d, 0x2d, 0x0f, 0x7b, 0x7b, 0xbf, 0xd7, 0xe4, 0xab, 0xc2, 0x35, 0xf3, 0x74, 0xab, 0xd4, 0x4d, 0x90, 0x35, 0xc7, 0x65, 0x0a, 0x79, 0xd1, 0xdd, 0x54, 0x5f, 0xa2, 0xf6, 0xfb, 0x0b, 0x5e, 0xba, 0x27, 0x17, 0x79, 0x91, 0x3e, 0x5c, 0x5e, 0xb4, 0x50, 0x52, 0x8e, 0x41, 0x28, 0x90, 0x9a, 0x96, 0xd1, 0x1a, 0x65, 0x2b, 0xf3, 0xf7, 0xae, 0x9d, 0x0d, 0x17, 0xad, 0xbf, 0x61, 0x2e, 0xc9, 0xca, 0x32, 0xe7, 0x3e, 0xf6, 0xe8, 0x7d, 0x7f, 0x4e, 0x21, 0xfe, 0x34, 0x12, 0xce, 0x14),
-//            //   aad: vec!(0x9f, 0xf3, 0x77, 0x54, 0x5a, 0x35, 0xcf, 0x1b, 0xfb, 0x77, 0xc7, 0x34, 0xad, 0x90, 0x0c, 0x70, 0x3a, 0xee, 0x6c, 0x31, 0x74, 0xfd, 0xb3, 0x73, 0x66, 0x64, 0x86, 0x30, 0x36, 0xa3, 0xa9, 0xd0, 0x91, 0x63, 0xc2, 0x99, 0x2f, 0x09, 0x3e, 0x24, 0x08, 0x91, 0x1b, 0x87, 0x51, 0xf0, 0x01, 0xe4, 0x93, 0xde, 0xcc, 0x41, 0xe4, 0xee, 0xee, 0xd0, 0x4f, 0x69, 0x8b, 0x6d, 0xae, 0xd4, 0x84, 0x52, 0xa7, 0xe1, 0xa7, 0x4e, 0xc3, 0xb4, 0xf3, 0xdc, 0xf2, 0x15, 0x1c, 0xa2, 0x49, 0xfa, 0x56, 0x8a, 0xa0, 0x84, 0xc8, 0x42, 0x8a, 0x41, 0xf2, 0x0b, 0xe5, 0xfd),
-//            //   cipher_text: vec!(0x22, 0x9d, 0xa7, 0x68, 0x44, 0x42, 0x66, 0x39, 0xe2, 0xfd, 0x3e, 0xf2, 0x53, 0xa1, 0x95, 0xe0, 0xa9, 0x3f, 0x08, 0x45, 0x2b, 0xa3, 0x72, 0x19, 0xb6, 0x77, 0x3f, 0x10, 0x31, 0x34, 0xf3, 0xf8, 0x7b, 0x13, 0x45, 0xf9, 0xb4, 0xbf, 0x8c, 0xfc, 0x11, 0x27, 0x7c, 0x31, 0x17, 0x80, 0xa2, 0xb6, 0xe1, 0x9a, 0x36, 0x3b, 0x6a, 0xc2, 0xef, 0xe6, 0xc4, 0xcc, 0x54, 0xa3, 0x9b, 0x14, 0x4e, 0x29, 0xc9, 0x4b, 0x9e, 0xbb, 0xde, 0x6f, 0xd0, 0x94, 0xc3, 0x0f, 0x59, 0xd1, 0xb7, 0x70, 0xeb, 0xf9, 0xfc, 0xad, 0x2a, 0x5c, 0x69, 0x5d, 0xc0, 0x03, 0xbf, 0x51),
-//            //   tag: vec!(0xb7, 0x2a, 0xca, 0xb5, 0x01, 0x31, 0xa2, 0x95, 0x58, 0xd5, 0x6a, 0xe7, 0xb9, 0xd4, 0x8e, 0x4e)
-//            // },
-//            // TestVector{
-//            //   key: [0x4a, 0xeb, 0x62, 0xf0, 0x24, 0xe1, 0x87, 0x60, 0x6e, 0xe7, 0xcc, 0x9f, 0x58, 0x65, 0xc3, 0x91, 0xc4, 0x3d, 0xf1, 0x96, 0x3f, 0x45, 0x9c, 0x87, 0xba, 0x00, 0xe4, 0x4b, 0xb1, 0x63, 0xa8, 0x66],
-//            //   nonce: [0x95, 0x59, 0xbd, 0x08, 0x71, 0x8b, 0x75, 0xaf],
-//            //   plain_text: vec!(0xc5, 0xd5, 0x86, 0xce, 0xec, 0xe6, 0xf4, 0x18, 0x12, 0xc9, 0x69, 0xbc, 0xf1, 0xe7, 0x27, 0xfe, 0x6f, 0xf8, 0xd1, 0xae, 0x8c, 0x8c, 0x52, 0x36, 0x7c, 0x61, 0x2c, 0xaa, 0x7c, 0xdf, 0x50, 0xe0, 0x66, 0x2f, 0x5d, 0xff, 0xc5, 0xea, 0x7d, 0x3c, 0xc3, 0x94, 0x00, 0xdf, 0xe3, 0xdc, 0x18, 0x97, 0x90, 0x5f, 0x64, 0x90, 0xfd, 0x77, 0x47, 0xb5, 0xf5, 0xf9, 0x84, 0x27, 0x39, 0xc6, 0x7d, 0x07, 0xce, 0x7c, 0x33, 0x9a, 0x5b, 0x39, 0x97, 0xa7, 0xfb, 0x4c, 0xd0, 0xd8, 0xe4, 0x81, 0x7f, 0xf8, 0x91, 0x6b, 0x25, 0x1c, 0x11, 0xef, 0x91, 0x91, 0x67, 0xf8, 0x58, 0xe4, 0x15, 0x04, 0xb9),
-//            //   aad: vec!(0x51, 0xf5, 0xb5, 0x03, 0xb7, 0x3a, 0x5d, 0xe8, 0xb9, 0x65, 0x34, 0xc2, 0xa3, 0xf2, 0xd8, 0x59, 0xec, 0xe0, 0xbd, 0x06, 0x3e, 0xa6, 0xdf, 0xa4, 0x86, 0xa7, 0xee, 0xc9, 0x9f, 0x6c, 0x02, 0x09, 0x83, 0xf7, 0x14, 0x8c, 0xcc, 0xb8, 0x62, 0x02, 0xcf, 0x96, 0x85, 0xcc, 0x1c, 0xc2, 0x66, 0x93, 0x0f, 0x04, 0xe5, 0x36, 0xad, 0x8b, 0xc2, 0x60, 0x94, 0x25, 0x2b, 0xaa, 0x46, 0x06, 0xd8, 0x83, 0xbd, 0x2a, 0xee, 0xd6, 0xb4, 0x30, 0x15, 0x22, 0x02, 0xe9, 0xb6, 0xcc, 0x79, 0x7f, 0xf2, 0x4f, 0xc3, 0x65, 0x31, 0x5e, 0xd6, 0x73, 0x91, 0x37, 0x4c, 0x13, 0x57, 0xc9, 0xa8, 0x45, 0xf2),
-//            //   cipher_text: vec!(0x25, 0x2e, 0xa4, 0x2b, 0x6e, 0x57, 0x40, 0x30, 0x68, 0x16, 0x97, 0x4a, 0x4f, 0xe6, 0x7b, 0x66, 0xe7, 0x93, 0xeb, 0xe0, 0x91, 0x47, 0x78, 0xef, 0x48, 0x5d, 0x55, 0x28, 0x8e, 0xb6, 0xc9, 0xc4, 0x5f, 0xa3, 0x4a, 0xc8, 0x53, 0xdc, 0x7a, 0x39, 0x25, 0x25, 0x20, 0x51, 0x4c, 0x3c, 0xb3, 0x4c, 0x72, 0xb9, 0x73, 0xb1, 0x4b, 0x32, 0xbc, 0x25, 0x76, 0x87, 0xd3, 0x98, 0xf3, 0x6f, 0x64, 0xcc, 0x2a, 0x66, 0x8f, 0xaf, 0xfa, 0x73, 0x05, 0xab, 0x24, 0x01, 0x71, 0x34, 0x3b, 0x5f, 0x9f, 0x49, 0xb6, 0xc2, 0x19, 0x7e, 0x4f, 0xbe, 0x18, 0x7b, 0x10, 0x54, 0x0d, 0x7c, 0xdc, 0xfa, 0x37),
-//            //   tag: vec!(0x71, 0x1f, 0xf3, 0x3e, 0xf8, 0xd2, 0xb0, 0x67, 0xa1, 0xb8, 0x5c, 0x64, 0xf3, 0x2f, 0x18, 0x14)
-//            // },
-//            // TestVector{
-//            //   key: [0x9a, 0x19, 0xe7, 0x2f, 0x00, 0x5c, 0xae, 0x1a, 0xe7, 0x8b, 0x8e, 0x35, 0x0d, 0x7a, 0xab, 0xe5, 0x9f, 0xc8, 0x84, 0x59, 0x99, 0xe8, 0xc5, 0x2f, 0xad, 0x54, 0x5b, 0x94, 0x2c, 0x22, 0x5e, 0xaf],
-//            //   nonce: [0xd9, 0xda, 0xe2, 0xea, 0x8d, 0x2f, 0xfc, 0x31],
-//            //   plain_text: vec!(0x21, 0x10, 0x37, 0x8d, 0x85, 0x6d, 0xed, 0x07, 0xeb, 0x2b, 0xe8, 0xe8, 0xf4, 0x33, 0x08, 0xe0, 0xc7, 0x5b, 0xc8, 0xa3, 0xfc, 0xc7, 0xb1, 0x77, 0x3b, 0x07, 0x25, 0xb7, 0xde, 0x49, 0xf6, 0xa1, 0x66, 0xc4, 0x52, 0x8e, 0x64, 0x12, 0x0b, 0xdf, 0x7c, 0x97, 0x76, 0x61, 0x5d, 0x3c, 0xe6, 0xfe, 0xeb, 0x03, 0xde, 0x96, 0x4a, 0x7b, 0x91, 0x92, 0x06, 0xa7, 0x73, 0x92, 0xf8, 0x04, 0x37, 0xfa, 0xce, 0xb6, 0x74, 0x58, 0x45, 0xca, 0xfc, 0x16, 0x6e, 0x1c, 0x13, 0xb6, 0x8e, 0x70, 0xca, 0x2a, 0x1d, 0x00, 0xc7, 0x17, 0x37, 0xb8, 0xfc, 0xbb, 0xbd, 0x50, 0x90, 0x25, 0x65, 0xc3, 0x21, 0x59, 0xe0, 0x5f, 0xcd, 0x23),
-//            //   aad: vec!(0x1c, 0xd7, 0x3b, 0x72, 0xc4, 0xe1, 0x03, 0xaf, 0xbe, 0xfd, 0x7c, 0x77, 0x7e, 0x04, 0x80, 0xf3, 0xf5, 0xe6, 0x8c, 0x60, 0xb8, 0x5b, 0xd2, 0xe7, 0x1e, 0xf5, 0xca, 0xeb, 0xb1, 0x75, 0xd7, 0xfc, 0x65, 0x35, 0xd3, 0x9f, 0x38, 0xf9, 0x2c, 0x24, 0xf2, 0xeb, 0x0f, 0xe9, 0x7d, 0x87, 0x8e, 0xd3, 0xd5, 0x96, 0x7c, 0x0b, 0xb4, 0x39, 0x4a, 0x5d, 0x41, 0xf7, 0xd3, 0x4c, 0xda, 0x6e, 0x15, 0x23, 0xd3, 0x84, 0x8f, 0x04, 0x9c, 0xde, 0x55, 0x4a, 0x7d, 0x31, 0xe1, 0xaf, 0xea, 0xb5, 0xd3, 0xe6, 0x15, 0x0f, 0x85, 0x85, 0x83, 0x35, 0xcb, 0xd2, 0x8c, 0x8a, 0x7f, 0x87, 0xd5, 0x28, 0x05, 0x8d, 0xf5, 0x0e, 0xea, 0x06),
-//            //   cipher_text: vec!(0x5f, 0x00, 0x9f, 0xbc, 0xe4, 0xec, 0x8e, 0x4c, 0xa9, 0xd8, 0xd4, 0x22, 0x58, 0xb1, 0xa3, 0xe4, 0xe9, 0x20, 0xb2, 0xfb, 0xad, 0x33, 0xd5, 0xe9, 0xf0, 0x75, 0x57, 0xd9, 0x59, 0x5e, 0x84, 0x10, 0x25, 0x19, 0x3b, 0x52, 0x1b, 0xa4, 0x40, 0x11, 0x0d, 0xd8, 0x39, 0x58, 0xe8, 0xee, 0x30, 0x21, 0x9d, 0x95, 0x2b, 0x41, 0x8e, 0x98, 0xa6, 0xc6, 0x24, 0x89, 0x4a, 0xa2, 0x48, 0xae, 0xdc, 0x06, 0x78, 0xf2, 0xd2, 0x63, 0xe7, 0xbf, 0xaf, 0x54, 0xca, 0x37, 0x9f, 0xef, 0x6c, 0x5d, 0x2f, 0x7a, 0xc4, 0x22, 0xea, 0x4b, 0x43, 0x69, 0x40, 0x8b, 0x82, 0xd6, 0x22, 0x5a, 0x7a, 0x2c, 0xf9, 0xa9, 0xf4, 0x6f, 0xd4, 0xef),
-//            //   tag: vec!(0xaa, 0x0a, 0x5f, 0xa7, 0xd3, 0xcf, 0x71, 0x7a, 0x47, 0x04, 0xa5, 0x99, 0x73, 0xb1, 0xcd, 0x15)
-//            // },
-//            // TestVector{
-//            //   key: [0xba, 0x1d, 0x0b, 0x33, 0x29, 0xec, 0xc0, 0x09, 0xf1, 0xda, 0x0f, 0xab, 0x4c, 0x85, 0x4b, 0x00, 0xad, 0x94, 0x48, 0x70, 0xfd, 0xca, 0x56, 0x18, 0x38, 0xe3, 0x8b, 0xad, 0x36, 0x4d, 0xa5, 0x07],
-//            //   nonce: [0x8a, 0x81, 0xc9, 0x2b, 0x37, 0x22, 0x1f, 0x2f],
-//            //   plain_text: vec!(0x62, 0x89, 0x94, 0x4f, 0xfa, 0x3c, 0xce, 0xa4, 0xbf, 0x25, 0xcd, 0x60, 0x1b, 0x27, 0x1f, 0x64, 0xe6, 0xde, 0xb0, 0xeb, 0xa7, 0x7d, 0x65, 0xef, 0xb4, 0xd6, 0x9c, 0xa9, 0x3e, 0x01, 0x99, 0x6e, 0x47, 0x27, 0x16, 0x8b, 0x6f, 0x74, 0xf3, 0xcc, 0xf1, 0x7b, 0xd4, 0x47, 0x15, 0xf2, 0x3c, 0xeb, 0x8f, 0xc0, 0x30, 0xc0, 0xe0, 0x35, 0xe7, 0x7f, 0x53, 0x26, 0x3d, 0xb0, 0x25, 0x02, 0x1f, 0xd2, 0xd0, 0x4b, 0x87, 0xa1, 0xb5, 0x4b, 0x12, 0x22, 0x9c, 0x5e, 0x86, 0x04, 0x81, 0x45, 0x2a, 0x80, 0xa1, 0x25, 0xcb, 0x06, 0x93, 0xa2, 0xba, 0x1b, 0x47, 0xe2, 0x8e, 0xe7, 0xcb, 0xaf, 0x9e, 0x68, 0x3c, 0x17, 0x82, 0x32, 0xc7, 0xf6, 0xd3, 0x4f, 0x97),
-//            //   aad: vec!(0xe5, 0x78, 0x83, 0x96, 0x1b, 0x8d, 0x04, 0x1d, 0x9b, 0x9e, 0xea, 0xdd, 0xcf, 0xd6, 0x1f, 0xa9, 0xf5, 0x92, 0x13, 0xf6, 0x65, 0x71, 0xfa, 0xdf, 0xff, 0xfd, 0xd1, 0x49, 0x8b, 0x9b, 0x01, 0x4f, 0x1e, 0xf2, 0xe7, 0xe5, 0x6c, 0x30, 0x44, 0xd7, 0xf9, 0xfa, 0x7a, 0x14, 0x03, 0xa1, 0x16, 0x9e, 0x86, 0x43, 0x0a, 0x2a, 0x78, 0x21, 0x37, 0x09, 0x3f, 0x54, 0x56, 0xe1, 0x42, 0xaa, 0xd0, 0x3a, 0x5f, 0x7a, 0x66, 0xd3, 0x80, 0x09, 0xdd, 0x01, 0xb7, 0xfc, 0x02, 0xc9, 0xcf, 0x61, 0x64, 0x2d, 0xed, 0xaf, 0x7c, 0xc8, 0xd4, 0x60, 0x66, 0xc2, 0x81, 0xee, 0x17, 0x78, 0x06, 0x74, 0xc3, 0xa3, 0x6e, 0xae, 0x66, 0xc5, 0x8d, 0x2d, 0x76, 0x50, 0x75),
-//            //   cipher_text: vec!(0x9c, 0x44, 0xd9, 0x13, 0x5d, 0xb0, 0xdb, 0xf8, 0x1c, 0x86, 0x2c, 0x1f, 0x69, 0xbe, 0xc5, 0x5a, 0x27, 0x97, 0x94, 0xcd, 0xd2, 0x9a, 0x58, 0xe6, 0x19, 0x09, 0xaa, 0x29, 0xec, 0x4c, 0x12, 0x0c, 0x9c, 0x5a, 0x50, 0x8d, 0x85, 0x6b, 0x9e, 0x56, 0x13, 0x80, 0x95, 0x71, 0x4a, 0x4b, 0xb5, 0x84, 0x02, 0xa1, 0xad, 0x06, 0x77, 0x4c, 0xf4, 0xec, 0xdf, 0x22, 0x73, 0x83, 0x9c, 0x00, 0x07, 0xcb, 0x88, 0xb5, 0x44, 0x4b, 0x25, 0xc7, 0x6f, 0x6d, 0x24, 0x24, 0x28, 0x11, 0x01, 0xd0, 0x43, 0xfc, 0x63, 0x69, 0xeb, 0xb3, 0xb2, 0xff, 0x63, 0xcd, 0xb0, 0xf1, 0x1a, 0x6e, 0xa1, 0xb8, 0xa7, 0xda, 0xfc, 0x80, 0xcd, 0xae, 0xf2, 0x81, 0x3f, 0xa6, 0x61),
-//            //   tag: vec!(0x65, 0xc7, 0x46, 0xf6, 0x59, 0xbc, 0xbd, 0xcd, 0x05, 0x4e, 0x76, 0x8c, 0x57, 0xc8, 0x48, 0xc9)
-//            // },
-//            // TestVector{
-//            //   key: [0x0c, 0xf8, 0xc7, 0x3a, 0x6c, 0xff, 0xc1, 0xb8, 0xb2, 0xf5, 0xd3, 0x20, 0xda, 0x1d, 0x85, 0x9d, 0x31, 0x43, 0x74, 0xe4, 0xa9, 0x46, 0x8d, 0xb7, 0xfd, 0x42, 0xc8, 0xd2, 0x70, 0xb7, 0x61, 0x3a],
-//            //   nonce: [0x3c, 0x4c, 0x6f, 0x02, 0x81, 0x84, 0x1a, 0xff],
-//            //   plain_text: vec!(0x44, 0x34, 0x72, 0x8d, 0x23, 0x46, 0x03, 0xc9, 0x16, 0xe2, 0xfa, 0xa0, 0x6b, 0x25, 0xd8, 0x3b, 0xad, 0x33, 0x48, 0x99, 0x0e, 0xcd, 0xe2, 0x34, 0x43, 0x68, 0xd1, 0xa7, 0xaf, 0x13, 0x09, 0xbd, 0x04, 0x25, 0x1b, 0xb2, 0xe0, 0xb7, 0x20, 0x44, 0x94, 0x8f, 0x8d, 0xea, 0x33, 0xcc, 0xe2, 0x61, 0x82, 0x83, 0xb6, 0xaf, 0x74, 0x20, 0x73, 0xa9, 0x58, 0x6b, 0x26, 0xc1, 0x08, 0x93, 0x35, 0xfe, 0x73, 0x51, 0x41, 0xe0, 0x99, 0x78, 0x5a, 0x12, 0x35, 0x81, 0x0a, 0x3a, 0x67, 0xff, 0x30, 0x9e, 0x2f, 0x0c, 0xe6, 0x82, 0x20, 0xba, 0x00, 0x77, 0xad, 0x1a, 0x5d, 0xc1, 0xa4, 0xae, 0xf8, 0x98, 0xa3, 0xb9, 0xff, 0x8f, 0x5a, 0xd7, 0xfe, 0x60, 0x14, 0x9b, 0xd0, 0xbd, 0x6d, 0x83),
-//            //   aad: vec!(0xa3, 0x8d, 0x09, 0xa4, 0xf1, 0xc9, 0x24, 0x16, 0x23, 0xc6, 0x39, 0xb7, 0x68, 0x8d, 0x8d, 0x35, 0x34, 0x5e, 0xa5, 0x82, 0x40, 0x80, 0xc9, 0xd7, 0x4e, 0x43, 0x52, 0x91, 0x9d, 0xb6, 0x3c, 0x74, 0xd3, 0x18, 0xf1, 0x9e, 0x1c, 0xbb, 0x9b, 0x14, 0xee, 0xbd, 0x7c, 0x74, 0xb0, 0xad, 0x01, 0x19, 0x24, 0x76, 0x51, 0x91, 0x1f, 0x35, 0x51, 0x58, 0x3e, 0x74, 0x9e, 0xa5, 0x0f, 0xf6, 0x48, 0x85, 0x8d, 0xca, 0xaa, 0x78, 0x9b, 0x74, 0x19, 0xd9, 0xe9, 0x3a, 0x5b, 0xf6, 0xc8, 0x16, 0x71, 0x88, 0xdb, 0xac, 0x2f, 0x36, 0x80, 0x43, 0x80, 0xdb, 0x32, 0x52, 0x01, 0x98, 0x2b, 0x8b, 0x06, 0x59, 0x7e, 0xfe, 0xb7, 0x68, 0x45, 0x46, 0xb2, 0x72, 0x64, 0x29, 0x41, 0x59, 0x1e, 0x92),
-//            //   cipher_text: vec!(0xbd, 0xfb, 0xfe, 0xa2, 0x61, 0xb1, 0xf4, 0xc1, 0x34, 0x44, 0x53, 0x21, 0xdb, 0x9e, 0x6e, 0x40, 0x47, 0x6e, 0x2d, 0xd2, 0xf4, 0xe4, 0xdb, 0xe8, 0x6e, 0x31, 0xd6, 0xa1, 0x16, 0xd2, 0x58, 0x30, 0x76, 0x2e, 0x06, 0x5b, 0x07, 0xb1, 0x1a, 0x37, 0x99, 0xaa, 0xb9, 0x3a, 0x94, 0xb4, 0xf9, 0x8c, 0x31, 0xc0, 0xfa, 0xeb, 0x77, 0xec, 0x52, 0xc0, 0x20, 0x48, 0xe9, 0x57, 0x92, 0x57, 0xe6, 0x7f, 0x5a, 0x6b, 0xae, 0x9b, 0xc6, 0x52, 0x10, 0xc2, 0x5b, 0x37, 0xfc, 0x16, 0xee, 0x93, 0xbd, 0xa8, 0x8f, 0xd5, 0xf3, 0x0a, 0x53, 0x3e, 0x47, 0x0b, 0x61, 0x88, 0xc6, 0xce, 0x57, 0x39, 0xfa, 0x3e, 0x90, 0xf7, 0x71, 0x20, 0xb4, 0x90, 0xfc, 0x10, 0x27, 0x96, 0x4f, 0x27, 0x7f, 0x40),
-//            //   tag: vec!(0x49, 0x93, 0xee, 0x95, 0x82, 0xf5, 0x8e, 0xab, 0xdb, 0x26, 0xb9, 0x8c, 0x4d, 0x56, 0xa2, 0x44)
-//            // },
-//            // TestVector{
-//            //   key: [0x69, 0xf4, 0xe5, 0x78, 0x8d, 0x48, 0x6a, 0x75, 0xad, 0xf9, 0x20, 0x7d, 0xf1, 0xbd, 0x26, 0x2d, 0xd2, 0xfe, 0x3d, 0xd3, 0xa0, 0x23, 0x64, 0x20, 0x39, 0x0d, 0x16, 0xe2, 0xa3, 0x04, 0x04, 0x66],
-//            //   nonce: [0x62, 0x55, 0xbf, 0x5c, 0x71, 0xbb, 0x27, 0xd1],
-//            //   plain_text: vec!(0xc1, 0x50, 0x48, 0xca, 0x29, 0x41, 0xef, 0x96, 0x00, 0xe7, 0x67, 0xa5, 0x04, 0x5a, 0xa9, 0x8a, 0xc6, 0x15, 0x22, 0x5b, 0x80, 0x5a, 0x9f, 0xbd, 0xa3, 0xac, 0x63, 0x01, 0xcd, 0x5a, 0x66, 0xae, 0xf6, 0x11, 0x40, 0x0f, 0xa3, 0xbc, 0x04, 0x83, 0x8e, 0xad, 0x99, 0x24, 0xd3, 0x82, 0xbe, 0xf8, 0x25, 0x1a, 0x47, 0xf1, 0xe4, 0x87, 0xd2, 0xf3, 0xca, 0x4b, 0xcc, 0xd3, 0x47, 0x6a, 0x6c, 0xa7, 0xf1, 0x3e, 0x94, 0xfd, 0x63, 0x9a, 0x25, 0x9e, 0xf2, 0x3c, 0xc2, 0xf8, 0xb8, 0xd2, 0x48, 0xa4, 0x71, 0xd3, 0x0a, 0xc9, 0x21, 0x96, 0x31, 0xc3, 0xe6, 0x98, 0x51, 0x00, 0xdc, 0x45, 0xe0, 0xb5, 0x9b, 0x8f, 0xc6, 0x20, 0x46, 0x30, 0x91, 0x65, 0xdd, 0xb6, 0xf0, 0x92, 0xda, 0x3a, 0x4f, 0x06, 0x7c, 0x8a, 0x44),
-//            //   aad: vec!(0x0c, 0x83, 0x03, 0x95, 0x04, 0xc8, 0x46, 0x4b, 0x49, 0xd6, 0x3b, 0x7f, 0x94, 0x48, 0x02, 0xf0, 0xd3, 0x9c, 0x85, 0xe9, 0xf3, 0x74, 0x5e, 0x25, 0x0f, 0x10, 0x11, 0x9f, 0xa2, 0xc9, 0x60, 0x49, 0x0f, 0x75, 0xae, 0x4d, 0xce, 0xd8, 0x50, 0x3b, 0x15, 0x6d, 0x07, 0x2a, 0x69, 0xf2, 0x04, 0x00, 0xe9, 0x49, 0x4a, 0xb2, 0xfa, 0x58, 0x44, 0x6c, 0x25, 0x5d, 0x82, 0xff, 0x0b, 0xe4, 0xb7, 0xe4, 0x30, 0x46, 0x58, 0x0b, 0xc1, 0xcf, 0x34, 0x06, 0x0c, 0x6f, 0x07, 0x6c, 0x72, 0xea, 0x45, 0x5c, 0x36, 0x87, 0x38, 0x1a, 0x3b, 0x90, 0x8e, 0x15, 0x2b, 0x10, 0xc9, 0x5c, 0x7b, 0x94, 0x15, 0x5b, 0x0b, 0x4b, 0x30, 0x3b, 0x77, 0x64, 0xa8, 0xa2, 0x7d, 0x1d, 0xb0, 0xa8, 0x85, 0xf1, 0x04, 0x0d, 0x5d, 0xbc, 0xc3),
-//            //   cipher_text: vec!(0xf0, 0xbb, 0x2b, 0x73, 0xd9, 0x4f, 0x2a, 0x7c, 0xef, 0x70, 0xfe, 0x77, 0xe0, 0x54, 0xf2, 0x06, 0x99, 0x8e, 0xac, 0xf2, 0xb8, 0x6c, 0x05, 0xc4, 0xfa, 0x3f, 0x40, 0xf2, 0xb8, 0xce, 0xbf, 0x03, 0x4f, 0xe1, 0x7b, 0xcb, 0xee, 0x4d, 0xea, 0x82, 0x1f, 0x51, 0xc1, 0x8c, 0x0a, 0xa8, 0x5b, 0x16, 0x0f, 0x85, 0x08, 0xbd, 0x1d, 0xc4, 0x55, 0xcc, 0x7f, 0x49, 0x66, 0x8b, 0x1f, 0xb2, 0x55, 0x57, 0xcd, 0xae, 0x14, 0x7b, 0xf2, 0x39, 0x9e, 0x07, 0xfc, 0xac, 0xac, 0xa1, 0x8e, 0xcc, 0xde, 0xd7, 0x41, 0xe0, 0x26, 0xef, 0x25, 0x36, 0x5a, 0x6b, 0x0f, 0x44, 0xa6, 0xb3, 0xdd, 0x97, 0x5e, 0xe6, 0xbb, 0x58, 0x0f, 0x5f, 0xcc, 0xd0, 0x40, 0xb7, 0x3c, 0x18, 0xb0, 0xfb, 0xf8, 0xf6, 0x31, 0x99, 0xba, 0x10, 0xfe),
-//            //   tag: vec!(0x42, 0x36, 0xa8, 0x75, 0x0f, 0x0c, 0xaf, 0xee, 0x3c, 0x4a, 0x06, 0xa5, 0x77, 0xa8, 0x5c, 0xb3)
-//            // },
-//            // TestVector{
-//            //   key: [0xad, 0x7b, 0x94, 0x09, 0x14, 0x7a, 0x89, 0x66, 0x48, 0xa2, 0xa2, 0xfe, 0x21, 0x28, 0xf7, 0x90, 0x22, 0xa7, 0x0d, 0x96, 0xdc, 0x48, 0x27, 0x30, 0xcd, 0x85, 0xc7, 0x0d, 0xb4, 0x92, 0xb6, 0x38],
-//            //   nonce: [0xa2, 0x8a, 0x6d, 0xed, 0xf3, 0xf2, 0xb0, 0x1a],
-//            //   plain_text: vec!(0x79, 0x1d, 0x29, 0x3f, 0xf0, 0xa3, 0xb8, 0x51, 0x0b, 0x4d, 0x49, 0x4b, 0x30, 0xf5, 0x0b, 0x38, 0xa0, 0x16, 0x38, 0xbf, 0x13, 0x0e, 0x58, 0xc7, 0x60, 0x19, 0x04, 0xf1, 0x2c, 0xb8, 0x90, 0x08, 0x71, 0xe8, 0xcf, 0x3d, 0x50, 0xab, 0xd4, 0xd3, 0x4f, 0xda, 0x12, 0x2c, 0x76, 0xdf, 0xee, 0x5b, 0x7f, 0x82, 0xcd, 0x6e, 0x85, 0x90, 0x64, 0x75, 0x35, 0xc9, 0x15, 0xae, 0x08, 0x71, 0x4e, 0x42, 0x7d, 0xa5, 0x2f, 0x80, 0xae, 0xf0, 0x9f, 0x40, 0x04, 0x00, 0x36, 0x03, 0x4c, 0xa5, 0x27, 0x18, 0xea, 0x68, 0x31, 0x3c, 0x53, 0x4e, 0x7a, 0x04, 0x5c, 0xd5, 0x17, 0x45, 0xec, 0x52, 0xf2, 0xe1, 0xb5, 0x94, 0x63, 0xdb, 0x07, 0xde, 0x7c, 0xa4, 0x01, 0xc6, 0xf6, 0x45, 0x38, 0x41, 0xd2, 0x47, 0xf3, 0x70, 0x34, 0x1b, 0x2d, 0xbc, 0x12, 0x12),
-//            //   aad: vec!(0x9a, 0x6d, 0xef, 0xdd, 0xb9, 0xb8, 0xd5, 0xc2, 0x4a, 0x26, 0xdd, 0x80, 0x96, 0xf5, 0xb8, 0xc3, 0xaf, 0x7a, 0x89, 0xe1, 0xf7, 0xd8, 0x86, 0xf5, 0x60, 0xfa, 0xbb, 0xe6, 0x4f, 0x14, 0xdb, 0x83, 0x8d, 0x6e, 0xb9, 0xd6, 0x87, 0x9f, 0x4f, 0x0b, 0x76, 0x9f, 0xe1, 0xf9, 0xee, 0xbf, 0x67, 0xfc, 0xd4, 0x7b, 0x6f, 0x9c, 0xeb, 0x48, 0x40, 0xb2, 0xdb, 0xa7, 0x58, 0x7e, 0x98, 0xdc, 0x5c, 0xae, 0x18, 0x6e, 0xf2, 0xa0, 0xf8, 0x60, 0x10, 0x60, 0xe8, 0x05, 0x8d, 0x9d, 0xda, 0x81, 0x2d, 0x91, 0x38, 0x7c, 0x58, 0x3d, 0xa7, 0x01, 0xd2, 0xba, 0x33, 0x47, 0xf2, 0x85, 0xc5, 0xd4, 0x43, 0x85, 0xa2, 0xb0, 0xbf, 0x07, 0x15, 0x0c, 0xbc, 0x95, 0xe7, 0xfc, 0xfa, 0x8a, 0xe0, 0x71, 0x32, 0x84, 0x9a, 0x02, 0x3c, 0x98, 0x81, 0x7c, 0x03, 0xd2),
-//            //   cipher_text: vec!(0xc2, 0xf1, 0x09, 0xd6, 0xd9, 0x4f, 0x77, 0xa7, 0x28, 0x9c, 0x8a, 0x2a, 0xb3, 0x3b, 0xc6, 0xa9, 0x8d, 0x97, 0x65, 0x54, 0x72, 0x1b, 0x0c, 0x72, 0x6c, 0xbf, 0x41, 0x21, 0x06, 0x94, 0x73, 0xe6, 0x2b, 0xa3, 0x6e, 0x70, 0x90, 0xe0, 0x24, 0x14, 0xf3, 0xed, 0xc2, 0x5c, 0x5d, 0x83, 0xac, 0x80, 0xb4, 0x9a, 0xd5, 0x28, 0xcd, 0xa1, 0xe3, 0xad, 0x81, 0x5b, 0x5a, 0x8c, 0x8a, 0xe9, 0xad, 0x07, 0x53, 0xde, 0x72, 0x53, 0x19, 0xdf, 0x23, 0x69, 0x83, 0xab, 0xd3, 0xf6, 0x9a, 0xb4, 0x46, 0x5d, 0x9b, 0x80, 0x6c, 0x07, 0x5b, 0x18, 0x96, 0xd4, 0x0b, 0xdb, 0xa7, 0x2d, 0x73, 0xba, 0x84, 0xc4, 0xa5, 0x30, 0x89, 0x6e, 0xb9, 0x4f, 0xfc, 0xcf, 0x5f, 0xb6, 0x7e, 0xb5, 0x91, 0x19, 0xe6, 0x6a, 0x18, 0x61, 0x87, 0x22, 0x18, 0xf9, 0x28, 0xcf),
-//            //   tag: vec!(0xe4, 0x8d, 0xc0, 0x15, 0x3d, 0x5b, 0x0f, 0x7e, 0xdb, 0x76, 0xfc, 0x97, 0xa0, 0x22, 0x49, 0x87)
-//            // },
-//            // TestVector{
-//            //   key: [0x48, 0x47, 0x0d, 0xa9, 0x82, 0x28, 0xc9, 0xb5, 0x3f, 0x58, 0x74, 0x76, 0x73, 0x50, 0x4f, 0x74, 0xca, 0x17, 0x37, 0xd7, 0xd4, 0xbb, 0x6d, 0xbf, 0x7c, 0x0c, 0xba, 0x6c, 0xa4, 0x2f, 0x80, 0xb9],
-//            //   nonce: [0x56, 0xfb, 0x49, 0x23, 0xa9, 0x7e, 0x93, 0x20],
-//            //   plain_text: vec!(0xbc, 0x66, 0x26, 0xd6, 0x51, 0xe2, 0xb2, 0x37, 0xf2, 0x2e, 0xe5, 0x16, 0x08, 0xdd, 0xcf, 0xfe, 0xba, 0x5f, 0x31, 0xc2, 0x6d, 0xf7, 0x2f, 0x44, 0x3f, 0x70, 0x1f, 0x2b, 0x08, 0x5d, 0x6f, 0x34, 0xf8, 0x06, 0xe2, 0x96, 0x73, 0x58, 0x4c, 0xb2, 0x15, 0x22, 0x17, 0x9e, 0xdb, 0x62, 0xa8, 0x24, 0x27, 0xd9, 0x46, 0xac, 0xab, 0xce, 0x06, 0x5b, 0x88, 0xb2, 0x87, 0x8e, 0x9e, 0xb8, 0x7e, 0xd1, 0x00, 0x4e, 0x55, 0xef, 0x58, 0xf5, 0x1e, 0xc4, 0x63, 0x75, 0xac, 0x54, 0x2c, 0x57, 0x82, 0x72, 0x5f, 0xf0, 0x13, 0x13, 0x6c, 0xb5, 0x06, 0xfc, 0xf9, 0x94, 0x96, 0xe1, 0x3f, 0xcd, 0x22, 0x4b, 0x8a, 0x74, 0xa9, 0x71, 0xcc, 0x8d, 0xdb, 0x8b, 0x39, 0x3c, 0xcc, 0x6a, 0xc9, 0x10, 0xbd, 0x19, 0x06, 0xea, 0x9f, 0x2e, 0xd8, 0xa5, 0xd0, 0x66, 0xdc, 0x63, 0x9c, 0x20, 0xcd),
-//            //   aad: vec!(0xdf, 0x8a, 0xb6, 0x34, 0xd3, 0xdc, 0xa1, 0x4e, 0x2e, 0x09, 0x1b, 0x15, 0xec, 0xc7, 0x8f, 0x91, 0xe2, 0x29, 0xa1, 0xa1, 0x3c, 0xba, 0x5e, 0xdd, 0x65, 0x26, 0xd1, 0x82, 0x52, 0x5e, 0xc5, 0x75, 0xaa, 0x45, 0xbc, 0x70, 0xfb, 0x61, 0x93, 0xff, 0xcd, 0x59, 0xba, 0xd3, 0xc3, 0x47, 0x15, 0x90, 0x99, 0xc4, 0xf1, 0x39, 0xc3, 0x23, 0xc3, 0x0a, 0x23, 0x07, 0x53, 0xd0, 0x70, 0x01, 0x87, 0x86, 0xb2, 0xe5, 0x9b, 0x75, 0x8d, 0xd4, 0xa9, 0x7d, 0x1a, 0x88, 0xe8, 0xf6, 0x72, 0x09, 0x2b, 0xef, 0x78, 0x0b, 0x45, 0x1f, 0xd6, 0x6b, 0xa7, 0x43, 0x1c, 0xbb, 0x56, 0x60, 0xea, 0x78, 0x16, 0xcd, 0xf2, 0x6e, 0x19, 0xa6, 0xeb, 0xb9, 0xaa, 0xdc, 0x30, 0x88, 0xe6, 0x92, 0x3f, 0x29, 0xf5, 0x3f, 0x87, 0x7a, 0x67, 0x58, 0x06, 0x8f, 0x79, 0xa6, 0xf2, 0xa1, 0x82, 0xb4, 0xbf),
-//            //   cipher_text: vec!(0xa6, 0x2e, 0x31, 0x3e, 0xcf, 0x25, 0x8c, 0xc9, 0x08, 0x7c, 0xbb, 0x94, 0xfc, 0xc1, 0x26, 0x43, 0xeb, 0x72, 0x2d, 0x25, 0x5c, 0x3f, 0x98, 0xc3, 0x9f, 0x13, 0x0e, 0x10, 0x05, 0x8a, 0x37, 0x5f, 0x08, 0x09, 0x66, 0x24, 0x42, 0xc7, 0xb1, 0x80, 0x44, 0xfe, 0xb1, 0x60, 0x2d, 0x89, 0xbe, 0x40, 0xfa, 0xca, 0xe8, 0xe8, 0x9c, 0xa9, 0x67, 0x01, 0x5f, 0x0b, 0x7f, 0x8c, 0x2e, 0x4e, 0x4a, 0x38, 0x55, 0xdb, 0xb4, 0x6a, 0x06, 0x6e, 0x49, 0xab, 0xf9, 0xce, 0xf6, 0x7e, 0x60, 0x36, 0x40, 0x0c, 0x8f, 0xf4, 0x6b, 0x24, 0x1f, 0xc9, 0x9b, 0xa1, 0x97, 0x4b, 0xa3, 0xba, 0x6e, 0xa2, 0x0d, 0xc5, 0x2e, 0xc6, 0x75, 0x3f, 0x6f, 0xc7, 0x69, 0x7a, 0xdb, 0xcc, 0xd0, 0x2b, 0x0b, 0xbe, 0xa1, 0xdf, 0x83, 0x52, 0x62, 0x9b, 0x03, 0xb4, 0x3c, 0xc3, 0xd6, 0x32, 0x57, 0x67, 0x87),
-//            //   tag: vec!(0x67, 0x52, 0x87, 0xf8, 0x14, 0x3b, 0x9b, 0x97, 0x6e, 0x50, 0xa8, 0x0f, 0x85, 0x31, 0xbd, 0x39)
-//            // },
-//            // TestVector{
-//            //   key: [0xb6, 0x2f, 0xb8, 0x5c, 0x1d, 0xec, 0xd0, 0xfa, 0xf2, 0x42, 0xce, 0x66, 0x21, 0x40, 0xad, 0x1b, 0x82, 0x97, 0x5e, 0x99, 0xa3, 0xfa, 0x01, 0x66, 0x6c, 0xac, 0x23, 0x85, 0xab, 0x91, 0xda, 0x54],
-//            //   nonce: [0x2f, 0x4a, 0x5c, 0xa0, 0x96, 0xa4, 0xfa, 0xf8],
-//            //   plain_text: vec!(0x03, 0xb1, 0x4f, 0x13, 0xc0, 0x06, 0x5e, 0x4a, 0x44, 0x21, 0xde, 0x62, 0xab, 0x1d, 0x84, 0x2b, 0xff, 0xb8, 0x0f, 0x3d, 0xa3, 0x0b, 0xf4, 0x7d, 0x11, 0x5c, 0x09, 0x85, 0x7f, 0x5b, 0xdd, 0x57, 0x56, 0xfd, 0x7c, 0x9a, 0xc3, 0xd9, 0xaf, 0x1c, 0x9f, 0xb9, 0x4f, 0x26, 0x40, 0xf7, 0xf4, 0x38, 0x6c, 0xfb, 0xa7, 0x4d, 0xb4, 0x68, 0xe5, 0x28, 0x8d, 0xbe, 0x4d, 0xd7, 0x8b, 0xfe, 0x4f, 0x69, 0xe4, 0x14, 0x80, 0xca, 0x61, 0x38, 0xe8, 0xbe, 0xac, 0xc6, 0xea, 0xa3, 0x37, 0x41, 0x57, 0xc7, 0x13, 0xcf, 0xa9, 0x00, 0xc0, 0x7d, 0xd8, 0x36, 0xea, 0xec, 0xc8, 0x82, 0x7f, 0xa3, 0xe7, 0x0e, 0x05, 0x2a, 0xe0, 0x9e, 0x84, 0x73, 0xe2, 0xae, 0x1a, 0x10, 0xb1, 0xbb, 0x66, 0x9e, 0xf6, 0x0a, 0x8d, 0xd9, 0x57, 0xf6, 0x55, 0x3d, 0xaa, 0x81, 0x14, 0x91, 0x8e, 0x17, 0x37, 0x1f, 0x2a, 0xc3, 0x27, 0xbd),
-//            //   aad: vec!(0xcf, 0xe3, 0xb7, 0xab, 0x75, 0x50, 0xb0, 0xe8, 0xe2, 0xe8, 0x23, 0x5f, 0xa0, 0xdc, 0xef, 0x95, 0x64, 0x7c, 0xe6, 0x81, 0x4a, 0xbd, 0x3d, 0xc3, 0xf5, 0xa3, 0xbd, 0x7d, 0x6d, 0x28, 0x25, 0x04, 0x66, 0x0c, 0x34, 0xad, 0x83, 0x41, 0xe4, 0xd1, 0x14, 0x02, 0xc7, 0xd4, 0x6c, 0x83, 0xa4, 0x94, 0xd7, 0xdd, 0xb1, 0x05, 0xe1, 0x00, 0x29, 0x79, 0x02, 0x3e, 0x0e, 0x3d, 0xc2, 0x97, 0x8c, 0x9a, 0xe5, 0x3e, 0x10, 0xeb, 0x85, 0x67, 0xe7, 0xa0, 0x2b, 0x60, 0xe5, 0x1e, 0x94, 0x5c, 0x70, 0x40, 0xd8, 0x32, 0xca, 0x90, 0x0d, 0x13, 0x2b, 0x42, 0x05, 0xa3, 0x50, 0x34, 0xfe, 0xd9, 0x39, 0xa1, 0xb7, 0x96, 0x51, 0x83, 0xc2, 0x56, 0x54, 0x93, 0x1a, 0x9b, 0x74, 0x44, 0x01, 0xc4, 0x64, 0x9c, 0x94, 0x57, 0x10, 0xb0, 0xd9, 0x73, 0x3b, 0x87, 0x45, 0x13, 0x48, 0xb3, 0x2b, 0xa8, 0x1d, 0xe3, 0x0e, 0xa7),
-//            //   cipher_text: vec!(0x89, 0x65, 0xdb, 0x3d, 0x3a, 0xe4, 0xfb, 0x48, 0x32, 0x08, 0xf1, 0x47, 0x27, 0x6e, 0x7d, 0x81, 0xb7, 0x1a, 0x86, 0xe7, 0x20, 0x2f, 0xfc, 0x9b, 0x1e, 0xaa, 0xde, 0x00, 0x9b, 0xc0, 0x16, 0x83, 0x8d, 0xc0, 0x9c, 0xa4, 0xbc, 0xf3, 0x08, 0x87, 0xb2, 0xf4, 0x24, 0x3f, 0xbd, 0x65, 0x2c, 0xd9, 0x0e, 0xbe, 0xd1, 0xce, 0xef, 0x81, 0x51, 0xff, 0x17, 0xea, 0x70, 0x51, 0x8d, 0x03, 0xb0, 0xf2, 0xa2, 0x49, 0x60, 0xaa, 0x7d, 0xe9, 0xb3, 0x0f, 0xa6, 0x5c, 0x2e, 0x2d, 0x57, 0x36, 0x00, 0x61, 0xaa, 0xe6, 0xd9, 0x37, 0x6e, 0x98, 0x4e, 0x9f, 0xcd, 0x5e, 0x5d, 0xd0, 0x91, 0x1a, 0x4b, 0xc8, 0xde, 0xca, 0x83, 0x2f, 0xfb, 0x76, 0xf2, 0x52, 0xbd, 0x7d, 0xa5, 0x23, 0x07, 0x65, 0x93, 0xba, 0x6b, 0x17, 0x4f, 0x7d, 0x9f, 0xb0, 0x37, 0x7e, 0x06, 0x6e, 0xcb, 0xb6, 0x63, 0x80, 0x36, 0x24, 0x1e, 0x86),
-//            //   tag: vec!(0x3d, 0x0f, 0xc5, 0x3e, 0x90, 0x58, 0xc2, 0xbe, 0x32, 0xaa, 0x08, 0x50, 0xe0, 0xfa, 0xb5, 0xa6)
-//            // },
-//            // TestVector{
-//            //   key: [0xde, 0x9c, 0x65, 0x72, 0x58, 0x77, 0x4d, 0x4e, 0xbc, 0x09, 0xd1, 0x09, 0xa0, 0xfc, 0x79, 0xd6, 0x64, 0x93, 0xae, 0x57, 0x87, 0x97, 0xca, 0xc4, 0xeb, 0x88, 0x30, 0xa6, 0xa4, 0xb5, 0x47, 0xe0],
-//            //   nonce: [0xb5, 0xe3, 0x5f, 0xe3, 0x39, 0x8e, 0xfa, 0x34],
-//            //   plain_text: vec!(0x4d, 0x68, 0xfb, 0x68, 0x3a, 0xa4, 0xf4, 0xc7, 0xa1, 0x6b, 0xa1, 0x11, 0x4f, 0xc0, 0xb1, 0xb8, 0xd8, 0x89, 0x86, 0x10, 0xfa, 0x27, 0x63, 0xe4, 0x35, 0xde, 0xd8, 0x77, 0x1b, 0x36, 0x51, 0x07, 0x8b, 0xef, 0x73, 0xd4, 0xdf, 0xd1, 0x4e, 0x76, 0xa3, 0x4c, 0xd5, 0xeb, 0x9e, 0xf4, 0xdb, 0x4e, 0xad, 0x4d, 0xa9, 0xe8, 0x3f, 0x4c, 0xe5, 0x0f, 0xe0, 0x59, 0x97, 0x7b, 0x2d, 0x17, 0xd6, 0x87, 0xc2, 0x93, 0x35, 0xa0, 0x4d, 0x87, 0x38, 0x9d, 0x21, 0x1f, 0x82, 0x15, 0x44, 0x97, 0x49, 0x96, 0x9f, 0x76, 0x52, 0xdc, 0x19, 0x35, 0xa0, 0xf9, 0xa9, 0x45, 0x38, 0xdc, 0x81, 0xdc, 0x9a, 0x39, 0xaf, 0x63, 0x44, 0x6a, 0x65, 0x17, 0x60, 0x90, 0x76, 0x98, 0x79, 0x20, 0x54, 0x7d, 0x00, 0x98, 0xa9, 0xc6, 0x76, 0x6c, 0xf5, 0xe7, 0x04, 0x88, 0x3e, 0xa3, 0x2f, 0xea, 0xea, 0x18, 0x89, 0xb1, 0x55, 0x4b, 0x5e, 0xb0, 0xce, 0x5e, 0xcc),
-//            //   aad: vec!(0x43, 0x6e, 0xa5, 0xa5, 0xfe, 0xe8, 0x29, 0x3b, 0x93, 0xe4, 0xe8, 0x48, 0x81, 0x16, 0xc9, 0x4d, 0x32, 0x69, 0xc1, 0x9f, 0x1d, 0x50, 0x50, 0xde, 0xf2, 0x3d, 0x28, 0x05, 0x15, 0x45, 0x7b, 0x93, 0x1b, 0xbe, 0xd6, 0x4a, 0x54, 0x2b, 0x31, 0x7c, 0xc5, 0x02, 0x3d, 0x64, 0x83, 0x30, 0xa4, 0xb7, 0xad, 0xca, 0x14, 0xdd, 0x6f, 0x37, 0x83, 0x20, 0x7b, 0x94, 0xf8, 0x6c, 0xca, 0xa0, 0xa0, 0xac, 0x39, 0xb7, 0xdb, 0x00, 0xac, 0x87, 0xa9, 0x9e, 0x3c, 0xd8, 0xa7, 0x64, 0xed, 0x9c, 0x75, 0xda, 0x84, 0x54, 0x47, 0x96, 0x36, 0xab, 0x2b, 0x29, 0xe7, 0x70, 0xb1, 0x66, 0xa5, 0xb7, 0x5c, 0xac, 0xc4, 0x25, 0xc9, 0x19, 0xbf, 0x1c, 0xe9, 0xac, 0x34, 0xaf, 0xe6, 0xb4, 0x42, 0x5c, 0x3d, 0x9f, 0xd2, 0xe4, 0x8b, 0xc8, 0x1e, 0x7d, 0x15, 0x51, 0x6d, 0x60, 0xe5, 0x92, 0xbf, 0xcc, 0x2e, 0xbe, 0xfb, 0x66, 0x0f, 0x09, 0x95, 0xf2, 0xb5),
-//            //   cipher_text: vec!(0x97, 0xa9, 0x7b, 0x8f, 0x0f, 0x54, 0x20, 0x84, 0x5a, 0xe8, 0xd5, 0x75, 0x67, 0xf9, 0xbb, 0xa6, 0x93, 0xd3, 0x0e, 0x6d, 0xb9, 0x16, 0xfa, 0xd0, 0xb9, 0x71, 0xf5, 0x53, 0xad, 0x7d, 0x99, 0x3f, 0x80, 0x6f, 0x27, 0xab, 0x8b, 0x45, 0x8d, 0x80, 0x46, 0x06, 0x2c, 0xed, 0x47, 0x78, 0xc0, 0x04, 0xb4, 0xf9, 0x58, 0xa4, 0x43, 0x61, 0x41, 0x63, 0x7c, 0x60, 0x39, 0x96, 0x33, 0x08, 0xde, 0xa2, 0xf5, 0x40, 0x08, 0xb7, 0xfe, 0xab, 0x79, 0x65, 0x02, 0x95, 0xed, 0x41, 0xbf, 0x9e, 0x65, 0xe1, 0xa2, 0xd7, 0x5a, 0xb1, 0xc7, 0xb2, 0xa7, 0x0e, 0xbb, 0x9e, 0x9f, 0x38, 0xd0, 0x7a, 0x9a, 0x67, 0x2d, 0x3e, 0x95, 0xea, 0x78, 0xaf, 0xe9, 0xac, 0x02, 0xf2, 0x56, 0x6b, 0x48, 0xb0, 0x25, 0x1a, 0xef, 0x6e, 0xee, 0xca, 0x8b, 0xd1, 0x5b, 0xd8, 0xd4, 0x3b, 0x55, 0x94, 0x26, 0xaa, 0x9d, 0x15, 0xd9, 0x60, 0xee, 0x35, 0xcb, 0x3e, 0xdf),
-//            //   tag: vec!(0xe5, 0x5d, 0xbb, 0x21, 0x85, 0x1e, 0x8a, 0x5b, 0x36, 0x5f, 0x86, 0xd0, 0x25, 0x18, 0x33, 0x1c)
-//            // },
-//            // TestVector{
-//            //   key: [0x68, 0x85, 0xbd, 0x33, 0x3c, 0x33, 0x6c, 0x76, 0x72, 0xdb, 0x8e, 0xbd, 0xf2, 0x4c, 0x1a, 0x1b, 0x60, 0x5c, 0x5a, 0x4a, 0xe2, 0x79, 0xf0, 0xf6, 0x98, 0x16, 0x2f, 0x47, 0xe6, 0xc7, 0x34, 0x01],
-//            //   nonce: [0xf0, 0xc4, 0xa2, 0x13, 0xa6, 0x16, 0x8a, 0xab],
-//            //   plain_text: vec!(0xfa, 0x90, 0x5a, 0x2b, 0xfa, 0x5b, 0x5b, 0xad, 0x76, 0x72, 0x39, 0xfb, 0x07, 0x0a, 0x7b, 0xc0, 0xb3, 0x03, 0xd1, 0x50, 0x3e, 0xcd, 0x2b, 0x42, 0x94, 0x18, 0xcc, 0x8f, 0xeb, 0xa8, 0x43, 0xe5, 0x44, 0x4e, 0xd8, 0x90, 0x22, 0xfd, 0xb3, 0x79, 0xc3, 0xb1, 0x55, 0xa0, 0xf9, 0xce, 0xab, 0x29, 0x79, 0x00, 0x0a, 0x0f, 0x60, 0x29, 0x2a, 0x63, 0x17, 0x71, 0xf2, 0xfd, 0xe4, 0xef, 0x06, 0x5a, 0xa7, 0x46, 0x42, 0x66, 0x09, 0x08, 0x29, 0x69, 0x53, 0x0a, 0x9c, 0x70, 0xad, 0x14, 0x53, 0x08, 0xc3, 0x0b, 0xa3, 0x89, 0xea, 0x12, 0x2f, 0xd7, 0x66, 0x08, 0x15, 0x11, 0xa0, 0x31, 0xce, 0x3a, 0x0b, 0xd9, 0xf9, 0xf5, 0x83, 0xc7, 0x00, 0x0b, 0x33, 0x3b, 0x79, 0xac, 0x00, 0x4f, 0xbd, 0xe6, 0xec, 0x3e, 0xb2, 0xd9, 0x05, 0x97, 0x7f, 0xf9, 0x5d, 0xcf, 0xf7, 0x78, 0x58, 0xe3, 0xc4, 0x24, 0xfe, 0x89, 0x32, 0xa6, 0xa1, 0x21, 0x39, 0xe6, 0xec, 0x8d, 0x5e, 0x98),
-//            //   aad: vec!(0x8d, 0xed, 0x36, 0x8f, 0x91, 0x9e, 0xfb, 0x52, 0x2b, 0xb6, 0xa9, 0xad, 0x00, 0x9e, 0x02, 0xff, 0xbc, 0x6a, 0x16, 0x53, 0x6e, 0x34, 0xd9, 0x5c, 0xdb, 0x34, 0xf1, 0x15, 0x3d, 0x7c, 0xb7, 0xb0, 0xf3, 0xc2, 0xb1, 0x3d, 0xd0, 0x5c, 0xed, 0xae, 0x27, 0xcf, 0xe6, 0x8e, 0xc3, 0xac, 0xa8, 0x04, 0x7e, 0x09, 0x30, 0xa2, 0x9c, 0x9d, 0x07, 0x70, 0xc1, 0xb8, 0x3c, 0x23, 0x4d, 0xcb, 0x03, 0x85, 0xde, 0xae, 0x7a, 0xe8, 0x5d, 0xa7, 0x3a, 0x5f, 0x8d, 0xe3, 0xdf, 0xb2, 0x86, 0x12, 0xa0, 0x01, 0xf4, 0xe5, 0x52, 0xc4, 0xf6, 0x7a, 0xe0, 0xe2, 0xec, 0x53, 0x85, 0x32, 0x89, 0xb7, 0x01, 0x7a, 0x58, 0x59, 0x1f, 0xd6, 0xf7, 0x0b, 0x0e, 0x95, 0x48, 0x76, 0xbb, 0x2f, 0x7e, 0xc3, 0x30, 0x01, 0xe2, 0x98, 0x85, 0x6a, 0x64, 0xbb, 0x16, 0x18, 0x10, 0x17, 0xba, 0x92, 0x46, 0x48, 0xc0, 0x9f, 0xc6, 0x3c, 0x62, 0xef, 0xf2, 0x62, 0xc8, 0x0d, 0x61, 0x46, 0x79, 0xbd),
-//            //   cipher_text: vec!(0x0c, 0xb3, 0xd6, 0xc3, 0x1e, 0x0f, 0x40, 0x29, 0xec, 0xa5, 0x52, 0x4f, 0x95, 0x12, 0x44, 0xdf, 0x04, 0x2f, 0xc6, 0x37, 0xc4, 0x16, 0x25, 0x11, 0xfe, 0xa5, 0x12, 0xa5, 0x2d, 0x3f, 0x75, 0x81, 0xaf, 0x09, 0x7e, 0xb6, 0x42, 0xe7, 0x9e, 0x48, 0x66, 0x6c, 0xb1, 0x08, 0x6e, 0xdb, 0xd3, 0x8c, 0x47, 0x77, 0xc5, 0x35, 0xa2, 0x09, 0x45, 0xfa, 0xbc, 0x23, 0xe7, 0xc9, 0x27, 0x7e, 0x2b, 0x96, 0x0a, 0xac, 0x46, 0x86, 0x5f, 0x10, 0x26, 0xeb, 0x6d, 0xa8, 0x27, 0x59, 0x10, 0x8b, 0x9b, 0xae, 0xce, 0x5d, 0xa9, 0x30, 0xcc, 0xfc, 0x10, 0x52, 0xb1, 0x65, 0x6b, 0x0e, 0xad, 0xaa, 0x12, 0x0e, 0xd0, 0xc4, 0x5a, 0xd0, 0x4b, 0x24, 0xae, 0x8c, 0xdb, 0x22, 0xce, 0xab, 0x76, 0xc5, 0xf1, 0x80, 0xb4, 0x6a, 0x39, 0x2a, 0xb4, 0x5b, 0x1b, 0x99, 0xc6, 0x12, 0x54, 0x6e, 0x6b, 0x94, 0x7f, 0x4d, 0x5c, 0x06, 0xad, 0x5a, 0xbe, 0xe9, 0x2f, 0xf9, 0x63, 0x45, 0xad, 0x43),
-//            //   tag: vec!(0xd3, 0xb5, 0x41, 0xac, 0x44, 0x6c, 0x84, 0x62, 0x6d, 0xaf, 0x80, 0x0c, 0x01, 0x72, 0xee, 0xc6)
-//            // },
-//            // TestVector{
-//            //   key: [0xfb, 0xc9, 0x78, 0xab, 0xb1, 0x24, 0x0a, 0x69, 0x37, 0xcc, 0xc1, 0x67, 0x35, 0xb8, 0xd6, 0xed, 0x54, 0x11, 0xcd, 0xbc, 0x18, 0x97, 0x21, 0x41, 0x65, 0xa1, 0x74, 0xe1, 0x6f, 0x4e, 0x69, 0x9b],
-//            //   nonce: [0x79, 0x68, 0x37, 0x9a, 0x8c, 0xe8, 0x81, 0x17],
-//            //   plain_text: vec!(0x1a, 0x81, 0x96, 0xcd, 0x4a, 0x13, 0x89, 0xec, 0x91, 0x6e, 0xf8, 0xb7, 0xda, 0x50, 0x78, 0xa2, 0xaf, 0xa8, 0xe9, 0xf1, 0x08, 0x12, 0x23, 0xfa, 0x72, 0xf6, 0x52, 0x4a, 0xc0, 0xa1, 0xa8, 0x01, 0x9e, 0x44, 0xa0, 0x95, 0x63, 0xa9, 0x53, 0x61, 0x55, 0x87, 0x42, 0x92, 0x95, 0x05, 0x2c, 0xc9, 0x04, 0xb8, 0x9f, 0x77, 0x8e, 0xf4, 0x46, 0xed, 0x34, 0x14, 0x30, 0xd7, 0xd8, 0xf7, 0x47, 0xcf, 0x2d, 0xb4, 0x30, 0x84, 0x78, 0x52, 0x46, 0x39, 0xf4, 0x44, 0x57, 0x25, 0x3a, 0xe5, 0xa4, 0x45, 0x1c, 0x7e, 0xfc, 0xa8, 0xae, 0x0b, 0x6c, 0x5c, 0x05, 0x1a, 0xaa, 0x78, 0x1e, 0x9c, 0x50, 0x54, 0x89, 0xb3, 0x81, 0xa6, 0xdc, 0xba, 0x87, 0xb1, 0x57, 0xed, 0xc7, 0xf8, 0x20, 0xa8, 0xfb, 0xaf, 0x2a, 0x52, 0xe4, 0x84, 0xdc, 0x12, 0x1f, 0x33, 0xd9, 0xd8, 0xb9, 0xac, 0x59, 0xd4, 0x90, 0x1d, 0x6e, 0xd8, 0x99, 0x6e, 0xd4, 0xf6, 0x2d, 0x9d, 0x4d, 0x82, 0x27, 0x4c, 0x44, 0x9c, 0xd7, 0x4e, 0xfa),
-//            //   aad: vec!(0x39, 0x13, 0xcd, 0x01, 0x29, 0x9b, 0x8a, 0x4e, 0x50, 0x7f, 0x06, 0x7d, 0x88, 0x7d, 0x7e, 0x9a, 0x6d, 0xed, 0x16, 0xdd, 0x9f, 0x9b, 0xb3, 0x11, 0x5c, 0x57, 0x79, 0xaa, 0x14, 0x23, 0x9f, 0xd3, 0x3e, 0xe9, 0xf2, 0x57, 0x56, 0xd4, 0x52, 0x62, 0xdc, 0x30, 0x11, 0x06, 0x93, 0x56, 0x42, 0x5b, 0x5c, 0x81, 0xa4, 0x72, 0x95, 0x94, 0xe1, 0x7c, 0x97, 0x47, 0x11, 0x9f, 0x81, 0x46, 0x3e, 0x85, 0x62, 0x5d, 0x56, 0x03, 0xd0, 0x5e, 0x00, 0xf5, 0x68, 0xb0, 0xc8, 0x00, 0xbb, 0x18, 0x1e, 0xb7, 0x17, 0xbe, 0x8d, 0x7a, 0x93, 0x16, 0x6a, 0x50, 0x4c, 0xe1, 0xbc, 0x81, 0x7e, 0x15, 0x53, 0x0c, 0x5b, 0xd2, 0xb3, 0xdf, 0x1d, 0x42, 0x22, 0x24, 0x5e, 0xa7, 0x8a, 0x38, 0xbc, 0x10, 0xf6, 0x6c, 0x5c, 0xf6, 0x8d, 0x66, 0x15, 0x03, 0x13, 0x1f, 0x11, 0xaf, 0x88, 0x5c, 0x8a, 0x91, 0x0b, 0x6d, 0xce, 0x70, 0xbc, 0x3a, 0x74, 0x48, 0xdf, 0xae, 0x00, 0x59, 0x5b, 0xeb, 0x70, 0x7f, 0xe0, 0x54, 0xd3),
-//            //   cipher_text: vec!(0xd1, 0x52, 0xbc, 0xb4, 0xc2, 0x4c, 0x37, 0x11, 0xb0, 0xfa, 0xd2, 0x85, 0x48, 0xdc, 0x4d, 0xb6, 0x05, 0xbb, 0xc8, 0x92, 0x37, 0xcd, 0xbe, 0xa7, 0xdb, 0xf9, 0x56, 0xb8, 0x85, 0x5d, 0x11, 0x61, 0xa0, 0x78, 0x1f, 0x27, 0xbd, 0x56, 0xd7, 0x98, 0x14, 0x1e, 0x2a, 0xce, 0x33, 0x99, 0x55, 0xef, 0xb9, 0x8f, 0xe0, 0x5d, 0x9b, 0x44, 0xcd, 0x01, 0x1e, 0x64, 0x51, 0x06, 0xbf, 0x47, 0x72, 0x61, 0x83, 0x95, 0x8c, 0xb6, 0xdf, 0x34, 0xce, 0x57, 0x66, 0x69, 0x5f, 0x60, 0xbc, 0x70, 0xb6, 0xfe, 0x0f, 0xab, 0xb9, 0xaf, 0xa0, 0x09, 0xa8, 0xef, 0x04, 0x3d, 0xbf, 0x75, 0xf8, 0x61, 0x88, 0x13, 0x68, 0xfa, 0x07, 0x72, 0x66, 0x25, 0x44, 0x8f, 0xe6, 0x08, 0xd5, 0x78, 0xcd, 0xc4, 0x82, 0x77, 0xf2, 0xdc, 0x53, 0xea, 0xaf, 0x1b, 0xdc, 0x07, 0x52, 0x69, 0xa4, 0x2f, 0x93, 0x02, 0xa5, 0x7c, 0xad, 0x38, 0x7a, 0x82, 0xc6, 0x96, 0x96, 0x08, 0xac, 0xac, 0xda, 0x20, 0xe1, 0xca, 0xc4, 0x59, 0x6c),
-//            //   tag: vec!(0x94, 0x5d, 0xca, 0x73, 0xcf, 0x2f, 0x00, 0x7a, 0xe2, 0x43, 0x99, 0x1c, 0x4f, 0xbe, 0x04, 0x79)
-//            // },
-//            // TestVector{
-//            //   key: [0x77, 0xd1, 0xa8, 0x57, 0xfb, 0xad, 0xfe, 0x01, 0xab, 0xa7, 0x97, 0x4e, 0xea, 0x2d, 0xfb, 0x3d, 0xc7, 0xbf, 0x41, 0xde, 0x73, 0x68, 0x6a, 0xec, 0xe4, 0x03, 0x99, 0x3e, 0x50, 0x16, 0xc7, 0x14],
-//            //   nonce: [0xfd, 0xd9, 0x13, 0xa3, 0x21, 0xc4, 0x0e, 0xb0],
-//            //   plain_text: vec!(0xdb, 0x89, 0x15, 0xbf, 0xe6, 0x51, 0xe2, 0xec, 0xb3, 0xce, 0x0b, 0x27, 0xd9, 0x9a, 0x6b, 0xfa, 0x7a, 0x7c, 0x50, 0x7c, 0xfc, 0xb2, 0x98, 0x72, 0x93, 0x01, 0x86, 0x36, 0xc3, 0x65, 0xa4, 0x59, 0xc6, 0xa1, 0x38, 0xb4, 0x42, 0x8b, 0xe5, 0x38, 0x41, 0x3d, 0xb1, 0x5b, 0xda, 0x69, 0xe6, 0x97, 0xcb, 0xb9, 0x2b, 0x15, 0x4b, 0x7f, 0x4d, 0x2c, 0xbb, 0x07, 0x96, 0x52, 0x25, 0xaa, 0x68, 0x65, 0xd7, 0xdc, 0xd1, 0xba, 0x2c, 0x17, 0xc4, 0x84, 0xb0, 0x0b, 0x19, 0x86, 0xfe, 0xd6, 0x3e, 0x88, 0x9f, 0x25, 0xa4, 0x96, 0x6d, 0xc3, 0xed, 0x42, 0x73, 0xf1, 0x57, 0x77, 0x68, 0xf6, 0x65, 0x36, 0x2d, 0x7d, 0x3e, 0x82, 0x44, 0x84, 0xf0, 0xdd, 0xed, 0x7f, 0x82, 0xb8, 0xbe, 0x87, 0x97, 0xad, 0x95, 0x17, 0x19, 0x71, 0x93, 0x65, 0xe4, 0x5a, 0xbb, 0xf7, 0x63, 0x24, 0xbc, 0x7d, 0x65, 0x77, 0x99, 0xd4, 0xd4, 0xf4, 0xbb, 0x1d, 0xba, 0x67, 0xd9, 0x6a, 0xb1, 0xc8, 0x85, 0x19, 0xa5, 0xbe, 0xe7, 0x04, 0xf7, 0x21, 0x48, 0x14),
-//            //   aad: vec!(0x3c, 0xb2, 0xc0, 0x6c, 0x20, 0xcb, 0x08, 0x32, 0xbb, 0xac, 0xeb, 0xfc, 0x20, 0x5d, 0x77, 0x39, 0x3c, 0xa1, 0x81, 0x63, 0x46, 0xea, 0x26, 0x81, 0xde, 0x4d, 0x3a, 0xb1, 0xfa, 0xdb, 0x77, 0x4a, 0xd2, 0x73, 0xe4, 0x71, 0x32, 0x90, 0x45, 0x44, 0x96, 0xf5, 0x28, 0x1e, 0xbc, 0x65, 0xe0, 0x4c, 0xfe, 0x84, 0xed, 0x37, 0xcd, 0x0a, 0xed, 0xc4, 0xbb, 0xe3, 0xde, 0xcb, 0xd8, 0xd7, 0x9d, 0x04, 0xa4, 0xe4, 0x34, 0x87, 0x66, 0x50, 0xe0, 0xd6, 0x43, 0x09, 0xe3, 0x36, 0xbf, 0xb1, 0x0e, 0x92, 0x40, 0x66, 0xa6, 0x4a, 0xcb, 0x92, 0x26, 0x0b, 0x2d, 0xbd, 0x96, 0x73, 0x5d, 0x03, 0xaf, 0x03, 0x90, 0x9a, 0xa6, 0xa8, 0x0a, 0x6e, 0x89, 0xfd, 0xa8, 0x10, 0x37, 0x25, 0x7a, 0xec, 0x21, 0xfe, 0x9b, 0xe7, 0xe9, 0x1a, 0x64, 0xe8, 0x8e, 0x0a, 0x58, 0xfa, 0x38, 0xec, 0xba, 0x4c, 0x4c, 0x4c, 0xff, 0xb6, 0x19, 0x58, 0xf3, 0xc4, 0x86, 0xcb, 0xb0, 0xb1, 0xd0, 0xb0, 0x01, 0x4a, 0x2d, 0x1d, 0x3d, 0xf2, 0x48, 0xee, 0xc1, 0xca),
-//            //   cipher_text: vec!(0xac, 0xb8, 0x25, 0xe6, 0x02, 0x3b, 0x44, 0xb0, 0x3b, 0x2e, 0xfc, 0x26, 0x56, 0x03, 0xe8, 0x87, 0x95, 0x4e, 0x86, 0x12, 0xb2, 0xee, 0x13, 0x4b, 0xdc, 0xb6, 0x15, 0x01, 0xcf, 0xb9, 0x49, 0x29, 0x52, 0xbf, 0x67, 0xbe, 0x59, 0x7c, 0x3a, 0x00, 0x5b, 0x09, 0xaf, 0x74, 0xd9, 0xe4, 0x21, 0xa5, 0x76, 0xd2, 0xc6, 0x5e, 0x98, 0x10, 0x47, 0x80, 0xfe, 0xab, 0x83, 0x8d, 0x8c, 0xb1, 0xbd, 0x13, 0x54, 0x52, 0xea, 0x39, 0xdc, 0x89, 0x07, 0xa4, 0xc1, 0xa6, 0xa9, 0x16, 0x18, 0x05, 0xe4, 0xfa, 0x3e, 0x16, 0x98, 0x9e, 0x6a, 0x41, 0x8a, 0x7e, 0xea, 0x25, 0x82, 0xbf, 0x89, 0x5d, 0xa9, 0x67, 0x02, 0x8e, 0xab, 0x7c, 0x95, 0xd8, 0x46, 0xa6, 0xde, 0x4b, 0x99, 0x80, 0x78, 0x58, 0x14, 0xcf, 0x00, 0x48, 0x4b, 0xaa, 0x2f, 0x6d, 0xe6, 0x09, 0x91, 0x2f, 0xff, 0x68, 0x9b, 0xce, 0x6e, 0x85, 0x42, 0x61, 0xff, 0xe8, 0x66, 0xbd, 0x8e, 0x63, 0x27, 0x46, 0x05, 0xc7, 0xc5, 0xad, 0x67, 0x7b, 0xd7, 0x89, 0x7a, 0xde, 0x54, 0x3e),
-//            //   tag: vec!(0x93, 0x84, 0x78, 0xa4, 0x1a, 0x32, 0x23, 0xa2, 0x19, 0x9f, 0x92, 0x76, 0xd1, 0x16, 0x21, 0x0f)
-//            // },
-//            // TestVector{
-//            //   key: [0xb7, 0xe9, 0xb9, 0x0d, 0xc0, 0x2b, 0x5c, 0xd6, 0xdf, 0x5d, 0xf7, 0x28, 0x3e, 0xf2, 0x93, 0xed, 0x4d, 0xc0, 0x75, 0x13, 0xd9, 0xe6, 0x73, 0x31, 0xb6, 0x06, 0xf4, 0xd4, 0x2d, 0xec, 0x7d, 0x29],
-//            //   nonce: [0xa6, 0xc1, 0x91, 0xf6, 0xd1, 0x81, 0x8f, 0x8e],
-//            //   plain_text: vec!(0x2a, 0xda, 0x0e, 0x3c, 0x7c, 0xa6, 0xdb, 0x1f, 0x78, 0x0c, 0xe8, 0xc7, 0x94, 0x72, 0xaf, 0x4e, 0x8e, 0x95, 0x1d, 0xdc, 0x82, 0x8e, 0x0d, 0x6e, 0x8a, 0x67, 0xdf, 0x52, 0x06, 0x38, 0xff, 0x5f, 0x14, 0xa2, 0xf9, 0x5a, 0x5e, 0x59, 0x31, 0x74, 0x9a, 0xe2, 0xc4, 0xe9, 0x94, 0x6a, 0xe4, 0xd5, 0xeb, 0x5d, 0xe4, 0x2f, 0xb5, 0xb7, 0x7d, 0x22, 0x36, 0xe2, 0xe2, 0xbd, 0x81, 0x7d, 0xf5, 0x1b, 0xe4, 0x0b, 0x1b, 0x8a, 0x6c, 0x21, 0x01, 0x5a, 0x7c, 0x79, 0xfe, 0x06, 0xdb, 0xa4, 0xa0, 0x8b, 0x34, 0x01, 0x3d, 0xfa, 0x02, 0x74, 0x7b, 0x5f, 0x03, 0x93, 0x02, 0x68, 0x40, 0x4c, 0x45, 0x5d, 0xc5, 0x4a, 0x74, 0xd9, 0xc6, 0xe3, 0x54, 0x85, 0xe1, 0x00, 0x26, 0xda, 0x57, 0x3c, 0xb4, 0x1c, 0xd5, 0x0b, 0x64, 0xcf, 0xaf, 0xe4, 0xcf, 0xcd, 0xf3, 0xc9, 0x68, 0x4e, 0xf8, 0x77, 0xe4, 0x5d, 0x84, 0xe2, 0x2b, 0xd5, 0xe1, 0x5f, 0xa6, 0xc8, 0xfd, 0x5b, 0xe9, 0x21, 0x36, 0x6f, 0xf0, 0xdc, 0x6f, 0xe2, 0xdf, 0x45, 0xf7, 0x25, 0x29, 0x72, 0xc9, 0xb3, 0x03),
-//            //   aad: vec!(0x0f, 0x42, 0x69, 0xed, 0x5e, 0xf0, 0xbf, 0xff, 0x7b, 0xe3, 0x99, 0x46, 0xa4, 0xe8, 0x6e, 0x8b, 0xf7, 0x9f, 0x84, 0xb7, 0x0c, 0xd0, 0xb1, 0x4f, 0xec, 0xb7, 0xbe, 0x3c, 0x07, 0x13, 0x16, 0xce, 0x86, 0xde, 0x3d, 0x99, 0xd6, 0x87, 0x1e, 0x0b, 0xa5, 0x66, 0x7d, 0x9d, 0x7b, 0xba, 0x7d, 0xca, 0xba, 0x10, 0xcb, 0x2a, 0x36, 0x66, 0x8b, 0x6c, 0x3e, 0x2f, 0xb6, 0xc1, 0x02, 0x93, 0x8b, 0x75, 0x00, 0x8b, 0xb9, 0xc2, 0x13, 0xeb, 0xf9, 0xb8, 0x5b, 0x5e, 0x91, 0xa8, 0x02, 0xdf, 0x0d, 0x31, 0xd7, 0xf1, 0x1d, 0x76, 0x4b, 0x22, 0x89, 0xf6, 0x22, 0x52, 0x12, 0x69, 0x4a, 0xb6, 0xb7, 0xc0, 0xe3, 0xff, 0x36, 0xe8, 0x42, 0x45, 0xd9, 0xf4, 0xf4, 0x3f, 0xc5, 0xf9, 0x8e, 0x65, 0x4d, 0xea, 0x7b, 0xa9, 0xbd, 0x91, 0x86, 0x58, 0x87, 0x9c, 0x5b, 0xb4, 0xa1, 0x64, 0x2a, 0xf0, 0xd8, 0x31, 0x13, 0xe3, 0xcf, 0x93, 0x5d, 0x3c, 0x0d, 0x52, 0x08, 0x31, 0x8f, 0x66, 0xf6, 0x54, 0xeb, 0x17, 0xd8, 0xc2, 0x8a, 0x60, 0x25, 0x43, 0xe7, 0x7a, 0xd3, 0xe8, 0x15),
-//            //   cipher_text: vec!(0x22, 0x58, 0x6f, 0xe7, 0x33, 0x8e, 0x99, 0xcd, 0xaa, 0xd9, 0xf8, 0x5b, 0xd7, 0x24, 0xba, 0x4c, 0xfe, 0x62, 0x49, 0xb8, 0xa7, 0x13, 0x99, 0xf9, 0xa3, 0x70, 0x7b, 0x5c, 0x43, 0x23, 0xb8, 0xd9, 0x66, 0x79, 0x56, 0x8d, 0xfc, 0x8d, 0x23, 0x0a, 0xef, 0xb4, 0x53, 0xdf, 0x59, 0x6e, 0x13, 0xeb, 0x3e, 0x8a, 0x43, 0x92, 0x49, 0xbd, 0x64, 0xbc, 0x93, 0xa5, 0x8f, 0x95, 0x08, 0x9a, 0x62, 0xb9, 0x4f, 0x65, 0x62, 0xb8, 0x21, 0xc8, 0x3d, 0x91, 0xf5, 0x6c, 0x55, 0x14, 0x73, 0x81, 0xe9, 0xde, 0x4b, 0xeb, 0x4a, 0xe8, 0x1b, 0xd6, 0xfe, 0x7c, 0xae, 0xf7, 0xe7, 0xe9, 0xa2, 0x07, 0x8f, 0x2f, 0xba, 0x8f, 0x3e, 0x70, 0xd4, 0x91, 0x0d, 0xa9, 0xac, 0xcc, 0x92, 0xb8, 0xe8, 0x1a, 0x61, 0xb0, 0xfe, 0xfb, 0xec, 0xe4, 0xbd, 0x89, 0x44, 0x3e, 0x66, 0xe8, 0xdd, 0xda, 0x8e, 0x47, 0xa6, 0x6a, 0x62, 0xf1, 0x7f, 0xd0, 0xe7, 0xd0, 0xa4, 0x85, 0x2c, 0xe1, 0xa4, 0xd4, 0x3d, 0x72, 0xa0, 0xb5, 0xe8, 0x91, 0x4b, 0xbe, 0xc6, 0x98, 0xf0, 0x60, 0xf2, 0xb0, 0x92),
-//            //   tag: vec!(0xc0, 0x82, 0x47, 0x02, 0x97, 0xda, 0x8c, 0x5f, 0x68, 0x2a, 0x16, 0x9d, 0x28, 0xbc, 0x02, 0x39)
-//            // },
-//            // TestVector{
-//            //   key: [0x6b, 0x2c, 0xb2, 0x67, 0x8d, 0x11, 0x02, 0xf2, 0xfb, 0xbd, 0x02, 0x87, 0x94, 0xa7, 0x9f, 0x14, 0x58, 0x5c, 0x22, 0x3d, 0x40, 0x5e, 0x1a, 0xe9, 0x04, 0xc0, 0x36, 0x1e, 0x9b, 0x24, 0x1e, 0x99],
-//            //   nonce: [0x7b, 0x3a, 0xe3, 0x1f, 0x8f, 0x93, 0x82, 0x51],
-//            //   plain_text: vec!(0xb3, 0xcb, 0x74, 0x59, 0x30, 0xe0, 0x5f, 0x3a, 0xb8, 0xc9, 0x26, 0xc0, 0xa3, 0x43, 0xa6, 0xeb, 0x14, 0x80, 0x9f, 0xd2, 0x1b, 0x83, 0x90, 0xa6, 0xfc, 0xc5, 0x8a, 0xdb, 0x55, 0x79, 0xe5, 0x43, 0x20, 0x21, 0x76, 0x5b, 0x2d, 0x24, 0x9a, 0x0e, 0xcf, 0x6b, 0xa6, 0x78, 0x63, 0x4c, 0x4f, 0x53, 0xf7, 0x14, 0x95, 0x86, 0x5f, 0x03, 0x1e, 0xe9, 0x7a, 0xa1, 0x59, 0xf9, 0xea, 0xd3, 0xa3, 0xfc, 0xb8, 0x23, 0xee, 0x52, 0x38, 0xbd, 0xf1, 0x27, 0x06, 0xa9, 0xc6, 0x13, 0x7d, 0x23, 0x6e, 0x2e, 0x71, 0x10, 0xce, 0x65, 0x0c, 0x32, 0x1e, 0x41, 0xda, 0xf0, 0xaf, 0xd6, 0x2b, 0xab, 0x2a, 0x8f, 0xe5, 0x5d, 0x70, 0x18, 0xde, 0x49, 0xa1, 0x4e, 0xfe, 0x6d, 0x83, 0xa1, 0x5b, 0x2f, 0x25, 0x6d, 0x59, 0x5e, 0x99, 0x8d, 0x25, 0x30, 0x9f, 0x23, 0x63, 0x33, 0x60, 0xf5, 0x74, 0x5c, 0x50, 0xc4, 0xe5, 0xaf, 0x8c, 0xcc, 0x9a, 0x8a, 0x2c, 0xb4, 0x70, 0x64, 0x10, 0x5a, 0x02, 0x3e, 0x91, 0x9c, 0x77, 0x95, 0xd2, 0xdc, 0x33, 0x1d, 0x3f, 0x2a, 0xfb, 0x8c, 0x42, 0xe5, 0xc0, 0xbc, 0xc2, 0x6d),
-//            //   aad: vec!(0x1c, 0x32, 0xfd, 0x3d, 0xf2, 0x2b, 0x3e, 0x44, 0x0e, 0x2a, 0x3c, 0x7a, 0x76, 0x24, 0x99, 0x01, 0x94, 0xcb, 0x16, 0xa5, 0xf7, 0x4a, 0xf3, 0x6f, 0x87, 0xfd, 0x6c, 0xa7, 0xd4, 0x10, 0xce, 0x90, 0x64, 0x31, 0x6a, 0x2d, 0x09, 0x19, 0x45, 0xde, 0xef, 0x7d, 0x9b, 0x35, 0xce, 0xec, 0x83, 0x96, 0x06, 0x93, 0x07, 0xca, 0xce, 0xd2, 0xb8, 0x0a, 0xfd, 0x7d, 0x53, 0xec, 0x47, 0x9c, 0x35, 0xce, 0xdf, 0x2d, 0xfd, 0x4c, 0x95, 0xc3, 0xdd, 0x84, 0x00, 0xf7, 0x1a, 0xd3, 0x40, 0x28, 0xc6, 0xe4, 0xf8, 0x68, 0x1d, 0x93, 0xd0, 0x77, 0x40, 0x64, 0xba, 0x38, 0xf3, 0xfb, 0x9b, 0x0c, 0x1d, 0xfa, 0x1f, 0x5f, 0x0c, 0x7d, 0x20, 0x67, 0x6a, 0x59, 0x11, 0xd9, 0x99, 0xfb, 0x6a, 0x1d, 0x41, 0x36, 0x7a, 0x8e, 0x99, 0xd8, 0x52, 0xbf, 0x3d, 0x3b, 0x7b, 0x3f, 0x4c, 0x23, 0x32, 0x49, 0xed, 0x1c, 0xa1, 0x35, 0x38, 0x9a, 0x67, 0x4f, 0xf4, 0x82, 0x32, 0xde, 0xd3, 0xf6, 0x80, 0x0a, 0x97, 0xb6, 0xd4, 0x09, 0xc4, 0x0e, 0x6c, 0xd7, 0x0d, 0x09, 0xbf, 0x9d, 0x2a, 0xd2, 0x5d, 0x9b, 0x94, 0x85),
-//            //   cipher_text: vec!(0xef, 0x70, 0xc7, 0xde, 0x98, 0xab, 0x1d, 0x4a, 0xd8, 0x17, 0x02, 0x4a, 0x97, 0x0b, 0xe4, 0x63, 0x44, 0x36, 0x40, 0xeb, 0x0c, 0xd7, 0xff, 0x23, 0x4b, 0xdd, 0x00, 0xe6, 0x53, 0x07, 0x4a, 0x77, 0xa1, 0xd5, 0x74, 0x9e, 0x69, 0x8b, 0xd5, 0x26, 0xdc, 0x70, 0x9f, 0x82, 0xdf, 0x06, 0xf4, 0xc0, 0xe6, 0x40, 0x46, 0xb3, 0xdc, 0x5f, 0x3c, 0x70, 0x44, 0xae, 0xf5, 0x3a, 0xeb, 0xb8, 0x07, 0xd3, 0x22, 0x39, 0xd0, 0x65, 0x2d, 0xd9, 0x90, 0x36, 0x2c, 0x44, 0xec, 0x25, 0xbf, 0x5a, 0xea, 0xe6, 0x41, 0xe2, 0x7b, 0xf7, 0x16, 0xe0, 0xc4, 0xa1, 0xc9, 0xfb, 0xd3, 0x7b, 0xbf, 0x60, 0x2b, 0xb0, 0xd0, 0xc3, 0x5b, 0x06, 0x38, 0xbe, 0x20, 0xdd, 0x5d, 0x58, 0x91, 0xd4, 0x46, 0x13, 0x7e, 0x84, 0x2f, 0x92, 0xc0, 0xee, 0x07, 0x5c, 0x68, 0x22, 0x5e, 0x4d, 0xba, 0xcb, 0x63, 0xcc, 0x6f, 0xb3, 0x24, 0x42, 0xb4, 0xbc, 0xda, 0x5e, 0x62, 0xcb, 0x50, 0x0a, 0x4d, 0xf2, 0x74, 0x1a, 0x40, 0x59, 0x03, 0x4d, 0x2c, 0xcb, 0x71, 0xb0, 0xb8, 0xb0, 0x11, 0x2b, 0xf1, 0xc4, 0xca, 0x6e, 0xec, 0x74),
-//            //   tag: vec!(0x39, 0x3a, 0xe2, 0x33, 0x84, 0x80, 0x34, 0x24, 0x8c, 0x19, 0x1a, 0xc0, 0xe3, 0x6b, 0x61, 0x23)
-//            // },
-//            // TestVector{
-//            //   key: [0x4d, 0xbc, 0x80, 0xa4, 0x02, 0xc9, 0xfc, 0xea, 0xa7, 0x55, 0xe1, 0x10, 0x5d, 0xc4, 0x9e, 0xf6, 0x48, 0x90, 0x16, 0x77, 0x68, 0x83, 0xe0, 0x6f, 0xcf, 0x3a, 0xed, 0x93, 0xbf, 0x7f, 0x6a, 0xf7],
-//            //   nonce: [0x23, 0x58, 0xae, 0x0c, 0xe3, 0xfb, 0x8e, 0x9f],
-//            //   plain_text: vec!(0x19, 0x7c, 0x06, 0x40, 0x3e, 0xb8, 0x96, 0xd2, 0xfa, 0x64, 0x65, 0xe4, 0xd6, 0x44, 0x26, 0xd2, 0x4c, 0xc7, 0x47, 0x6a, 0xa1, 0xae, 0x41, 0x27, 0xcd, 0x2b, 0xd8, 0xa4, 0x8c, 0xe2, 0xc9, 0x9c, 0x16, 0xb1, 0xcb, 0xf3, 0x06, 0x48, 0x56, 0xe8, 0x40, 0x73, 0xb6, 0xcf, 0x12, 0xe7, 0x40, 0x66, 0x98, 0xef, 0x3d, 0xd1, 0x24, 0x0c, 0x02, 0x6c, 0xbd, 0x1a, 0xb0, 0x4e, 0xe6, 0x03, 0xe1, 0xe6, 0xe7, 0x35, 0xc9, 0xb7, 0x55, 0x1f, 0xd0, 0xd3, 0x55, 0x20, 0x2b, 0x4f, 0x64, 0xb4, 0x82, 0xdd, 0x4a, 0x7c, 0x7d, 0x82, 0xc4, 0xfe, 0x2e, 0xb4, 0x94, 0xd0, 0xd5, 0xe1, 0x77, 0x88, 0x98, 0x2d, 0x70, 0x4c, 0x13, 0x56, 0xc4, 0x1a, 0x94, 0x65, 0x55, 0x30, 0xde, 0xda, 0x23, 0x11, 0x8c, 0xba, 0x28, 0x1d, 0x0f, 0x71, 0x7e, 0x14, 0x9f, 0xbe, 0xb2, 0xc5, 0x9b, 0x22, 0xd0, 0xc0, 0x57, 0x4c, 0x1a, 0x2e, 0x64, 0x0a, 0xfa, 0xd1, 0xa6, 0xce, 0xb9, 0x2e, 0x1b, 0xf1, 0xdd, 0xe7, 0x17, 0x52, 0xa1, 0xc9, 0x91, 0xe9, 0xa5, 0x51, 0x7f, 0xe9, 0x86, 0x88, 0xa1, 0x6b, 0x07, 0x3d, 0xbf, 0x68, 0x84, 0xcf, 0xde, 0x61, 0xac),
-//            //   aad: vec!(0xcf, 0x6c, 0xe7, 0xb8, 0x99, 0xfb, 0x70, 0x0a, 0x90, 0xd2, 0xa5, 0x46, 0x6d, 0x54, 0xd3, 0x13, 0x58, 0xec, 0xf0, 0x56, 0x2e, 0x02, 0xb3, 0x30, 0xa2, 0x7b, 0xa0, 0x13, 0x80, 0x06, 0xb3, 0x42, 0xb7, 0xed, 0x63, 0x49, 0xd7, 0x3c, 0x4c, 0x5c, 0x6d, 0x29, 0xbd, 0xe7, 0x5a, 0x25, 0x08, 0x9b, 0x11, 0xda, 0xc5, 0xb2, 0x7a, 0xde, 0xa7, 0xe7, 0x64, 0x0c, 0xa1, 0xa7, 0xce, 0xb0, 0x50, 0xe3, 0xaa, 0xe8, 0x4a, 0x47, 0xe1, 0x16, 0x40, 0xa6, 0xe4, 0x85, 0xbd, 0x54, 0xae, 0x9f, 0xdb, 0x54, 0x7e, 0xdc, 0x73, 0x13, 0xd2, 0x4a, 0x03, 0x28, 0x42, 0x9f, 0xcf, 0xfd, 0x8b, 0x18, 0xf3, 0x98, 0x80, 0xed, 0xd6, 0x16, 0x44, 0x73, 0x44, 0xeb, 0xee, 0xc9, 0xea, 0xdb, 0x2d, 0xcb, 0x1f, 0xa7, 0xe6, 0x71, 0x79, 0xe7, 0xf9, 0x13, 0xc1, 0x94, 0xeb, 0xd8, 0xf5, 0xa5, 0x8a, 0xea, 0x73, 0xb0, 0xc5, 0xd1, 0x13, 0x35, 0x61, 0x24, 0x5b, 0x6d, 0x9c, 0x5c, 0xfd, 0x8b, 0xb0, 0xc2, 0x5b, 0x38, 0xff, 0xb3, 0x7d, 0xb5, 0xe2, 0xde, 0x5c, 0xdd, 0xed, 0x6b, 0x57, 0x35, 0x5e, 0x9d, 0x21, 0x5c, 0xb0, 0x95, 0xb8, 0x73, 0x1f),
-//            //   cipher_text: vec!(0xaa, 0x87, 0xf9, 0xa8, 0x30, 0x48, 0xb6, 0x91, 0x9c, 0x8f, 0x2b, 0x05, 0x03, 0x15, 0xdb, 0x4e, 0x2a, 0xda, 0xe4, 0xa9, 0xc2, 0xca, 0x01, 0x09, 0xb8, 0x19, 0x61, 0xb5, 0x20, 0xe6, 0x32, 0x99, 0xdc, 0xb0, 0x28, 0xce, 0xc0, 0xb9, 0xd3, 0x24, 0x9a, 0x94, 0x5e, 0xe6, 0x7d, 0xd0, 0x29, 0xb4, 0x0f, 0x36, 0x12, 0x45, 0xc7, 0x40, 0xf0, 0x04, 0xf8, 0xcf, 0x0d, 0x22, 0x14, 0xfc, 0xfa, 0x65, 0xe6, 0x12, 0x4a, 0x3e, 0x74, 0xb7, 0x8a, 0xa9, 0x43, 0x45, 0xc4, 0x6f, 0xdc, 0x15, 0x8d, 0x34, 0x82, 0x3e, 0xd2, 0x49, 0xee, 0x55, 0x04, 0x31, 0xea, 0xae, 0x92, 0x18, 0x36, 0x73, 0x21, 0xcd, 0xd6, 0xe6, 0xa4, 0x77, 0x65, 0x04, 0x69, 0xbb, 0x3c, 0xc1, 0x37, 0xa8, 0xf4, 0x8d, 0x9c, 0xf2, 0x79, 0x34, 0xb1, 0x67, 0x03, 0x60, 0x8b, 0x38, 0x3d, 0x21, 0x45, 0x65, 0x99, 0x22, 0xfb, 0x83, 0xbb, 0x2e, 0x7e, 0xe2, 0xef, 0x93, 0x8a, 0x90, 0xf2, 0xff, 0x84, 0x6a, 0x4a, 0x94, 0x91, 0x29, 0xb1, 0xfb, 0x74, 0xdd, 0xe5, 0x5c, 0x5a, 0xe0, 0x13, 0xc2, 0xf2, 0x85, 0xde, 0x84, 0xf7, 0xda, 0xc7, 0xd1, 0x66, 0x2f, 0x23),
-//            //   tag: vec!(0x06, 0xb4, 0x31, 0x8a, 0xc7, 0xf6, 0x5d, 0x55, 0x6f, 0x78, 0x14, 0x28, 0xa0, 0x51, 0x4f, 0xfe)
-//            // },
-//            // TestVector{
-//            //   key: [0x9e, 0x4a, 0x62, 0x01, 0x6d, 0xae, 0x4b, 0x32, 0x23, 0xfe, 0xd1, 0xd0, 0x1d, 0x07, 0x87, 0xe3, 0x1d, 0x30, 0x69, 0x4f, 0x79, 0xe8, 0x14, 0x22, 0x24, 0xfe, 0x4c, 0x47, 0x35, 0x24, 0x8a, 0x83],
-//            //   nonce: [0x26, 0x3a, 0x2f, 0xc0, 0x6a, 0x28, 0x72, 0xe7],
-//            //   plain_text: vec!(0x5a, 0x46, 0x94, 0x66, 0x01, 0xf9, 0x3a, 0x0c, 0xee, 0x59, 0x93, 0xc6, 0x95, 0x75, 0xe5, 0x99, 0xcc, 0x24, 0xf5, 0x1a, 0xaf, 0xa2, 0xd7, 0xc2, 0x8d, 0x81, 0x6a, 0x5b, 0x9b, 0x4d, 0xec, 0xda, 0x2e, 0x59, 0xc1, 0x11, 0x07, 0x5f, 0xb6, 0x0a, 0x90, 0x3d, 0x70, 0x1a, 0xd2, 0x68, 0x0b, 0xb1, 0x4a, 0xed, 0xa1, 0x4a, 0xf2, 0xae, 0x9c, 0x07, 0xa7, 0x59, 0xd8, 0x38, 0x8b, 0x30, 0x44, 0x6f, 0x28, 0xb8, 0x5f, 0x0a, 0x05, 0xcd, 0x15, 0x00, 0x50, 0xbd, 0x2e, 0x71, 0x5f, 0xf5, 0x50, 0xeb, 0xbd, 0x24, 0xda, 0x3e, 0xbb, 0x1e, 0xac, 0x15, 0xab, 0xa2, 0x3d, 0x44, 0x86, 0x59, 0xde, 0x34, 0xbe, 0x96, 0x2a, 0xb3, 0xab, 0x31, 0xcb, 0x17, 0x58, 0xdb, 0x76, 0xc4, 0x68, 0xb5, 0xbb, 0x8c, 0xe4, 0x4b, 0x06, 0xc4, 0xe4, 0xdb, 0x9b, 0xd2, 0xf0, 0x61, 0x5b, 0x1e, 0x72, 0x7f, 0x05, 0x3f, 0x6b, 0x4f, 0xfb, 0x63, 0x58, 0xd2, 0x48, 0xf0, 0x22, 0xbc, 0xad, 0x6c, 0xa9, 0x73, 0x04, 0x4b, 0xed, 0x23, 0xd3, 0x92, 0x09, 0x06, 0xa8, 0x9a, 0x9a, 0x9c, 0x5d, 0x80, 0x24, 0xec, 0x67, 0xd7, 0xf0, 0x61, 0xf6, 0x45, 0x29, 0xa9, 0x55, 0xce, 0x16, 0xb3),
-//            //   aad: vec!(0x4c, 0xd6, 0x5f, 0x68, 0xf9, 0xf8, 0x8c, 0x05, 0x16, 0x23, 0x1f, 0x2a, 0x42, 0x5c, 0x8f, 0x8a, 0x28, 0x7d, 0xe4, 0x7d, 0x40, 0x9d, 0x5e, 0xcd, 0xe3, 0xad, 0x15, 0x1e, 0x90, 0x6b, 0x38, 0x39, 0xfb, 0x01, 0xbb, 0x91, 0xa4, 0x56, 0xf2, 0x0e, 0xa9, 0xd3, 0x94, 0xd4, 0xb0, 0x66, 0x04, 0xab, 0x1f, 0x90, 0x09, 0xef, 0x29, 0x01, 0x9a, 0xf7, 0x96, 0x8d, 0x96, 0x5d, 0x16, 0x43, 0x16, 0x1a, 0xb3, 0x3a, 0x53, 0x54, 0xcd, 0xa2, 0xfd, 0xc9, 0xf1, 0xd2, 0x1e, 0xc9, 0xcb, 0x71, 0xc3, 0x25, 0xc6, 0x59, 0x64, 0xa1, 0x4f, 0x9b, 0x26, 0xeb, 0x16, 0x56, 0x0b, 0xeb, 0x97, 0x92, 0x07, 0x5a, 0x15, 0x97, 0x39, 0x40, 0x00, 0xfd, 0x5f, 0x33, 0x1b, 0xd8, 0xb7, 0xd2, 0x0d, 0x88, 0xe5, 0xf8, 0x9c, 0xf8, 0xd0, 0xb3, 0x3e, 0x4e, 0x78, 0xe4, 0x90, 0x4b, 0xb5, 0x9c, 0x9c, 0x8d, 0x5d, 0x31, 0xac, 0x86, 0xb8, 0x93, 0xe4, 0xa0, 0x66, 0x7a, 0xf1, 0xbe, 0x85, 0xfd, 0xb7, 0x7f, 0x7e, 0xc3, 0xe2, 0x59, 0x4a, 0x68, 0x04, 0x8d, 0x20, 0xc2, 0xfb, 0x94, 0x22, 0xf5, 0x87, 0x90, 0x78, 0x77, 0x2e, 0xe2, 0x6a, 0x1c, 0x56, 0x0c, 0xbc, 0xbb, 0x21, 0x13),
-//            //   cipher_text: vec!(0xe9, 0x44, 0xbb, 0x2a, 0xb0, 0x6d, 0x13, 0x8a, 0xd6, 0x33, 0xc1, 0x6c, 0xe8, 0x27, 0x06, 0xec, 0xf0, 0xef, 0x5d, 0x11, 0x9b, 0xe1, 0xf3, 0x46, 0x0c, 0x9c, 0xe1, 0x01, 0xd9, 0xc4, 0xe0, 0x4e, 0xf1, 0x67, 0x77, 0x07, 0xfc, 0xa4, 0x0d, 0x1f, 0x8c, 0xa1, 0x81, 0xe0, 0x72, 0x73, 0x70, 0x7b, 0x06, 0x62, 0x4d, 0x6d, 0x70, 0x63, 0xc3, 0xb7, 0xb0, 0xbb, 0x01, 0x51, 0xb7, 0x57, 0xb3, 0xe5, 0x23, 0x7f, 0xb8, 0x00, 0x4c, 0x16, 0x12, 0x33, 0xd8, 0xbc, 0x7e, 0x5f, 0x28, 0xea, 0x1c, 0x18, 0xda, 0x18, 0x74, 0xb3, 0xd5, 0x4c, 0x5a, 0xd6, 0xff, 0x08, 0x35, 0xee, 0xd3, 0x5c, 0x88, 0x53, 0x70, 0x45, 0x85, 0xcf, 0x83, 0x99, 0x6e, 0x5e, 0x7c, 0xec, 0x68, 0x18, 0x0a, 0xf4, 0x14, 0xe0, 0x4f, 0x08, 0x13, 0x4d, 0x3b, 0x03, 0x84, 0xeb, 0xdf, 0x03, 0x93, 0xc9, 0x31, 0x0b, 0x55, 0xd8, 0x69, 0x8f, 0xe1, 0x0c, 0xb3, 0x62, 0xde, 0xfc, 0x09, 0x95, 0xe9, 0xa1, 0x3b, 0x48, 0xb4, 0x2c, 0xff, 0x61, 0xff, 0xd9, 0xfe, 0x4c, 0x3c, 0x8c, 0x6d, 0xab, 0x35, 0x57, 0x13, 0xb8, 0x8f, 0x6e, 0x98, 0xa0, 0x2e, 0x72, 0x31, 0xa0, 0xc6, 0x64, 0x4e, 0xc4),
-//            //   tag: vec!(0x27, 0xde, 0x0d, 0x4c, 0xa7, 0x64, 0x8f, 0x63, 0x96, 0xd5, 0x41, 0x9a, 0x7b, 0x12, 0x43, 0xb7)
-//            // },
-//            // TestVector{
-//            //   key: [0x18, 0xca, 0x3e, 0xa3, 0xe8, 0xba, 0xee, 0xd1, 0xb3, 0x41, 0x18, 0x92, 0x97, 0xd3, 0x3c, 0xef, 0x7f, 0x4e, 0x0a, 0x2f, 0xab, 0x40, 0xec, 0x3b, 0x6b, 0xb6, 0x73, 0x85, 0xd0, 0x96, 0x9c, 0xfe],
-//            //   nonce: [0xb6, 0xae, 0xf3, 0x4c, 0x75, 0x81, 0x8e, 0x7c],
-//            //   plain_text: vec!(0xef, 0x6d, 0x1b, 0xb4, 0x09, 0x47, 0x82, 0xf6, 0x02, 0xfc, 0xf4, 0x15, 0x61, 0xcb, 0xa4, 0x97, 0x06, 0x79, 0x66, 0x1c, 0x63, 0xbe, 0xfe, 0x35, 0xff, 0x2c, 0xa7, 0xad, 0x1a, 0x28, 0x0b, 0xf6, 0xb1, 0xe7, 0xf1, 0x53, 0xfa, 0x84, 0x8e, 0xdf, 0xef, 0xfe, 0x25, 0x15, 0x3f, 0x54, 0x0b, 0x71, 0x25, 0x3e, 0x8b, 0xab, 0xa9, 0xae, 0xb7, 0x19, 0xa0, 0x27, 0x52, 0xcd, 0xa6, 0x0e, 0xa5, 0x93, 0x8a, 0xab, 0x33, 0x9e, 0xea, 0xd5, 0xaa, 0xbf, 0x81, 0xb1, 0x9b, 0x0f, 0xc5, 0xc1, 0xed, 0x55, 0x6b, 0xe6, 0xad, 0x89, 0x70, 0xea, 0x43, 0xc3, 0x03, 0xd3, 0x04, 0x62, 0x05, 0xb1, 0x2c, 0x41, 0x9d, 0xea, 0x71, 0xc4, 0x24, 0x5c, 0xfe, 0xdd, 0x0a, 0x31, 0xb0, 0xf4, 0x15, 0x0b, 0x5a, 0x9f, 0xe8, 0x00, 0x52, 0x79, 0x01, 0x88, 0x52, 0x9a, 0xb3, 0x2f, 0x5e, 0x61, 0xd8, 0xcc, 0xde, 0x59, 0x73, 0xed, 0x30, 0xbd, 0xf2, 0x90, 0xcb, 0xfb, 0xd5, 0xf0, 0x73, 0xc0, 0xc6, 0xa0, 0x20, 0xea, 0xc0, 0x33, 0x2f, 0xce, 0xd1, 0x7a, 0x9a, 0x08, 0xce, 0xf6, 0xf9, 0x21, 0x7b, 0xd6, 0xbe, 0xf6, 0x8c, 0x15, 0x05, 0xd6, 0xee, 0xd4, 0x09, 0x53, 0xe1, 0x55, 0x08, 0xd8, 0x7f, 0x08, 0xfc),
-//            //   aad: vec!(0xf4, 0x0f, 0x03, 0xbe, 0xaa, 0x02, 0x3d, 0xb6, 0x31, 0x1b, 0xad, 0x9b, 0x4d, 0x5d, 0x0d, 0x66, 0xa5, 0x8d, 0x97, 0x8e, 0x0b, 0xcb, 0xbf, 0x78, 0xac, 0xeb, 0xde, 0x1f, 0x4e, 0xb9, 0xa2, 0x84, 0x09, 0x56, 0x28, 0x95, 0x5a, 0x0b, 0x15, 0xaf, 0xc4, 0x54, 0x15, 0x2f, 0x96, 0x2e, 0xc3, 0xea, 0x2b, 0x9a, 0x3b, 0x08, 0x9b, 0x99, 0x65, 0x8e, 0x68, 0xed, 0xe4, 0xde, 0xe5, 0xac, 0xd5, 0x66, 0x72, 0x02, 0x5e, 0xb7, 0x32, 0x3b, 0xcb, 0xc6, 0xba, 0x5d, 0x91, 0xc9, 0x43, 0x10, 0xf1, 0x8c, 0x91, 0x8e, 0x39, 0x14, 0xbb, 0xbf, 0x86, 0x9e, 0x1b, 0x87, 0x21, 0x47, 0x6f, 0x9d, 0xef, 0x31, 0xb9, 0xd3, 0x2c, 0x47, 0x1a, 0x54, 0x13, 0x24, 0x81, 0xaa, 0x89, 0xf6, 0xc7, 0x35, 0xab, 0x19, 0x33, 0x69, 0x49, 0x6d, 0x8d, 0xbe, 0xb4, 0x9b, 0x13, 0x0d, 0x85, 0xfb, 0xff, 0x3f, 0x9c, 0xb7, 0xdc, 0xce, 0xa4, 0xc1, 0xda, 0x7a, 0x28, 0x46, 0xee, 0xf5, 0xe6, 0x92, 0x9d, 0x90, 0x09, 0xa9, 0x14, 0x9e, 0x39, 0xc6, 0xc8, 0xec, 0x15, 0x0c, 0x9a, 0xb4, 0x9a, 0x09, 0xc1, 0x8c, 0x47, 0x49, 0xa0, 0xa9, 0xfc, 0xba, 0x77, 0x05, 0x7c, 0xde, 0xa6, 0xef, 0xd4, 0xd1, 0x42, 0x25, 0x6c),
-//            //   cipher_text: vec!(0xc5, 0x31, 0x63, 0x3c, 0x0c, 0x98, 0x23, 0x0d, 0xcf, 0x05, 0x9c, 0x10, 0x81, 0xd1, 0xd6, 0x9c, 0x96, 0xba, 0xb7, 0x1c, 0x31, 0x43, 0xae, 0x60, 0xf9, 0xfc, 0x2b, 0x9c, 0xd1, 0x87, 0x62, 0x31, 0x44, 0x96, 0xab, 0x6e, 0x90, 0xbf, 0x67, 0x96, 0x25, 0x2c, 0xb9, 0xf6, 0x67, 0xa1, 0xf0, 0x8d, 0xa4, 0x7f, 0xc2, 0xb0, 0xee, 0xcd, 0xa8, 0x13, 0x22, 0x8c, 0xae, 0x00, 0xd4, 0xc0, 0xd7, 0x1f, 0x5e, 0x01, 0xb6, 0xce, 0x76, 0x2f, 0xa6, 0x36, 0xef, 0xff, 0xe5, 0x5d, 0x0e, 0x89, 0xfd, 0xc8, 0x9b, 0xa4, 0x25, 0x21, 0xcc, 0x01, 0x9a, 0xb9, 0xd4, 0x08, 0xfc, 0xd7, 0x9c, 0x14, 0x91, 0x4e, 0x8b, 0xbf, 0x0e, 0xa4, 0x4d, 0x8a, 0x1d, 0x35, 0x74, 0x3a, 0xd6, 0x28, 0x32, 0x7e, 0x43, 0x2f, 0xdc, 0xfe, 0xb0, 0xb6, 0x67, 0x9d, 0xdc, 0xa8, 0xc9, 0x2b, 0x99, 0x84, 0x73, 0x73, 0x2a, 0xbd, 0x55, 0xdb, 0xa5, 0x4e, 0xef, 0xff, 0x83, 0xc7, 0x84, 0x88, 0xee, 0xe5, 0xf9, 0x2b, 0x14, 0x5a, 0x74, 0xb6, 0x86, 0x65, 0x31, 0x47, 0x6f, 0xc4, 0x62, 0x79, 0xd4, 0xfd, 0xe2, 0x4d, 0x04, 0x9c, 0x1c, 0xe2, 0xb4, 0x23, 0x58, 0xff, 0x3a, 0xb2, 0xba, 0x3a, 0x88, 0x66, 0xe5, 0x47, 0xaf),
-//            //   tag: vec!(0xa0, 0xa5, 0x24, 0x27, 0x59, 0xa6, 0xd9, 0xb1, 0xaa, 0x5b, 0xaf, 0x9a, 0x4e, 0xf8, 0x95, 0xa2)
-//            // },
-//            // TestVector{
-//            //   key: [0x95, 0xfd, 0xd2, 0xd3, 0xd4, 0x29, 0x60, 0x69, 0x05, 0x5b, 0x6b, 0x79, 0xe5, 0xd1, 0x38, 0x76, 0x28, 0x25, 0x4a, 0x7b, 0xe6, 0x47, 0xba, 0xaf, 0xdf, 0x99, 0xdd, 0x8a, 0xf3, 0x54, 0xd8, 0x17],
-//            //   nonce: [0xcd, 0x7e, 0xd9, 0xe7, 0x0f, 0x60, 0x86, 0x13],
-//            //   plain_text: vec!(0x02, 0x48, 0x28, 0x4a, 0xcf, 0xfa, 0x4b, 0x2c, 0x46, 0x63, 0x6b, 0xdf, 0x8c, 0xc7, 0x00, 0x28, 0xdd, 0x15, 0x1a, 0x6d, 0x8e, 0x7a, 0x5a, 0x5b, 0xc2, 0xd3, 0x9a, 0xcc, 0x10, 0x20, 0xe7, 0x36, 0x88, 0x50, 0x31, 0xb2, 0x52, 0xbf, 0xe9, 0xf9, 0x64, 0x90, 0x92, 0x1f, 0x41, 0xd1, 0xe1, 0x74, 0xbf, 0x1a, 0xc0, 0x37, 0x07, 0xbc, 0x2a, 0xe5, 0x08, 0x8a, 0x12, 0x08, 0xa7, 0xc6, 0x64, 0x58, 0x38, 0x35, 0xe8, 0xbb, 0x93, 0xc7, 0x87, 0xb9, 0x6d, 0xea, 0x9f, 0xc4, 0xb8, 0x84, 0x93, 0x0c, 0x57, 0x79, 0x9e, 0x7b, 0x7a, 0x66, 0x49, 0xc6, 0x13, 0x40, 0x37, 0x6d, 0x04, 0x2b, 0x9f, 0x5f, 0xae, 0xe8, 0x95, 0x6c, 0x70, 0xa6, 0x3c, 0xf1, 0xcf, 0xf4, 0xfc, 0x2c, 0x7c, 0xb8, 0x53, 0x5c, 0x10, 0x21, 0x4e, 0x73, 0xce, 0xc6, 0xb7, 0x96, 0x69, 0xd8, 0x24, 0xf2, 0x3f, 0xf8, 0xc8, 0xa2, 0xca, 0x1c, 0x05, 0x97, 0x4d, 0xd6, 0x18, 0x9c, 0xfe, 0xe4, 0x84, 0xd0, 0x90, 0x6d, 0xf4, 0x87, 0xb6, 0xbd, 0x85, 0x67, 0x1c, 0xe2, 0xb2, 0x38, 0x25, 0x05, 0x2e, 0x44, 0xb8, 0x48, 0x03, 0xe2, 0x83, 0x9a, 0x96, 0x39, 0x1a, 0xbc, 0x25, 0x94, 0x5c, 0xb8, 0x67, 0xb5, 0x27, 0xcd, 0xd9, 0xb3, 0x73, 0xfb, 0xfb, 0x83),
-//            //   aad: vec!(0x24, 0xa4, 0x5a, 0x3a, 0x00, 0x76, 0xa5, 0xbc, 0xfd, 0x5a, 0xfe, 0x1c, 0x54, 0xf7, 0xb7, 0x74, 0x96, 0x11, 0x7d, 0x29, 0xf4, 0xc0, 0x90, 0x9f, 0x1e, 0x69, 0x40, 0xb8, 0x1d, 0xde, 0x3a, 0xba, 0xcb, 0x71, 0xec, 0x71, 0xf0, 0xf4, 0xdb, 0x8a, 0x7e, 0x54, 0x0b, 0xd4, 0xc2, 0xc6, 0x0f, 0xae, 0xe2, 0x1d, 0xd3, 0xce, 0x72, 0x96, 0x38, 0x55, 0xbe, 0x1b, 0x0c, 0xe5, 0x4f, 0xb2, 0x0a, 0xd8, 0x2d, 0xbc, 0x45, 0xbe, 0x20, 0xcd, 0x6c, 0x17, 0x1e, 0x2b, 0xeb, 0xb7, 0x9e, 0x65, 0xe7, 0xd0, 0x15, 0x67, 0xad, 0x0e, 0xeb, 0x86, 0x98, 0x83, 0xe4, 0xe8, 0x14, 0xc9, 0x36, 0x88, 0x60, 0x7a, 0x12, 0xb3, 0xb7, 0x32, 0xc1, 0x70, 0x3b, 0x09, 0x56, 0x6c, 0x30, 0x8d, 0x29, 0xce, 0x67, 0x6a, 0x5c, 0x76, 0x2a, 0x85, 0x70, 0x06, 0x39, 0xb7, 0x0d, 0x82, 0xaa, 0xef, 0x40, 0x8c, 0xf9, 0x88, 0x21, 0xa3, 0x72, 0xc6, 0xa0, 0x61, 0x4a, 0x73, 0xba, 0x99, 0x18, 0xa7, 0x95, 0x1e, 0xa8, 0xb2, 0xbb, 0x77, 0xcd, 0x98, 0x96, 0xd2, 0x69, 0x88, 0x08, 0x6d, 0x85, 0x86, 0xd7, 0x2e, 0xdc, 0x92, 0xaf, 0x20, 0x42, 0xff, 0x5e, 0x5f, 0x14, 0x29, 0xa2, 0x2f, 0x61, 0x06, 0x5e, 0x03, 0xcf, 0xcd, 0x7e, 0xdc, 0x2a, 0x93),
-//            //   cipher_text: vec!(0x40, 0xc6, 0x31, 0x8d, 0x9e, 0x38, 0x3e, 0x10, 0x7c, 0xdd, 0x3e, 0x1c, 0x89, 0x51, 0x56, 0x21, 0x93, 0xc3, 0xef, 0x64, 0xee, 0x44, 0x24, 0x32, 0xa6, 0x3e, 0x2e, 0xde, 0xfc, 0x78, 0xf3, 0x2a, 0xb0, 0x77, 0x72, 0xae, 0xac, 0x17, 0x2c, 0xb6, 0x7e, 0xcf, 0x4d, 0x21, 0xf8, 0xb4, 0x48, 0x42, 0x35, 0x27, 0xbb, 0xeb, 0x9d, 0x8d, 0xdd, 0x0b, 0x46, 0xbd, 0xb2, 0x7f, 0x74, 0x09, 0x6c, 0xeb, 0x24, 0xe4, 0x19, 0x63, 0xb4, 0xcd, 0xca, 0x17, 0x66, 0x76, 0xa7, 0x5b, 0xdb, 0xe3, 0xab, 0xc2, 0x70, 0xb3, 0x49, 0xac, 0x0c, 0x6c, 0xbd, 0x9c, 0x3a, 0x5c, 0xd5, 0xbc, 0xe2, 0x02, 0x02, 0xfc, 0x5c, 0xc0, 0xc1, 0xbd, 0xd4, 0xfd, 0x25, 0xe1, 0x21, 0xe0, 0xa2, 0x4b, 0xd7, 0xbb, 0xeb, 0x9b, 0x19, 0xb1, 0x91, 0x24, 0x67, 0xbf, 0x53, 0x38, 0xee, 0x2c, 0xe8, 0x8a, 0xa3, 0x83, 0xc0, 0x82, 0xb4, 0x2c, 0xc3, 0x99, 0xc9, 0x65, 0x4c, 0xa3, 0x25, 0xf3, 0x55, 0x23, 0xe8, 0x14, 0x38, 0xbe, 0xb3, 0xf8, 0x92, 0x6b, 0xe7, 0x9c, 0x37, 0x88, 0x22, 0xd7, 0xc8, 0xf7, 0x85, 0x61, 0x44, 0x08, 0xa5, 0xf7, 0xca, 0xc4, 0x9e, 0x45, 0x43, 0x18, 0x87, 0x25, 0x64, 0x3e, 0x6c, 0x1a, 0x70, 0xb4, 0x6d, 0x0e, 0xc4, 0x00),
-//            //   tag: vec!(0x58, 0x01, 0xe8, 0x41, 0x92, 0xc7, 0x26, 0x7f, 0x66, 0xb0, 0xe0, 0x46, 0x07, 0xa3, 0x9a, 0x3e)
-//            // },
-//            // TestVector{
-//            //   key: [0x6a, 0xe1, 0x10, 0x2f, 0x84, 0xed, 0x4d, 0xc1, 0x14, 0xbb, 0x9d, 0x63, 0xf4, 0xdc, 0x78, 0xd7, 0xdb, 0xb1, 0xab, 0x63, 0xf1, 0x65, 0x9d, 0xd9, 0x5f, 0x47, 0x94, 0x0a, 0x7b, 0x7a, 0x81, 0x1f],
-//            //   nonce: [0xc9, 0x65, 0xd5, 0x78, 0xba, 0x91, 0xd2, 0x27],
-//            //   plain_text: vec!(0xb8, 0x2a, 0x8a, 0x92, 0x09, 0x61, 0x8f, 0x1f, 0x5b, 0xe9, 0xc2, 0xc3, 0x2a, 0xba, 0x3d, 0xc4, 0x5b, 0x49, 0x47, 0x00, 0x7b, 0x14, 0xc8, 0x51, 0xcd, 0x69, 0x44, 0x56, 0xb3, 0x03, 0xad, 0x59, 0xa4, 0x65, 0x66, 0x28, 0x03, 0x00, 0x67, 0x05, 0x67, 0x3d, 0x6c, 0x3e, 0x29, 0xf1, 0xd3, 0x51, 0x0d, 0xfc, 0x04, 0x05, 0x46, 0x3c, 0x03, 0x41, 0x4e, 0x0e, 0x07, 0xe3, 0x59, 0xf1, 0xf1, 0x81, 0x6c, 0x68, 0xb2, 0x43, 0x4a, 0x19, 0xd3, 0xee, 0xe0, 0x46, 0x48, 0x73, 0xe2, 0x3c, 0x43, 0xf3, 0xab, 0x60, 0xa3, 0xf6, 0x06, 0xa0, 0xe5, 0xbe, 0x81, 0xe3, 0xab, 0x4a, 0xa2, 0x7f, 0xb7, 0x70, 0x7a, 0x57, 0xb9, 0x49, 0xf0, 0x0d, 0x6c, 0xd3, 0xa1, 0x1a, 0xe4, 0x82, 0x7d, 0x48, 0x89, 0xdd, 0x45, 0x5a, 0x0b, 0x6d, 0x39, 0xe9, 0x90, 0x12, 0xfd, 0x40, 0xdb, 0x23, 0xfb, 0x50, 0xe7, 0x9e, 0x11, 0xf8, 0xa6, 0x45, 0x16, 0x69, 0xbe, 0xb2, 0xfb, 0xd9, 0x13, 0xef, 0xfd, 0x49, 0xad, 0x1b, 0x43, 0x92, 0x63, 0x11, 0xf6, 0xe1, 0x3a, 0x6e, 0x7a, 0x09, 0xcf, 0x4b, 0xeb, 0xb1, 0xc0, 0xbf, 0x63, 0xce, 0x59, 0xcd, 0x5a, 0x08, 0xe4, 0xb8, 0xd8, 0xdb, 0xf9, 0xd0, 0x02, 0xe8, 0xa3, 0xd9, 0xe8, 0x0c, 0x79, 0x95, 0xbb, 0x0b, 0x48, 0x52, 0x80),
-//            //   aad: vec!(0xdf, 0xd4, 0xac, 0x3e, 0x80, 0xb2, 0x90, 0x46, 0x23, 0xff, 0x79, 0xea, 0x8e, 0xe8, 0x78, 0x62, 0x26, 0x89, 0x39, 0xde, 0xcf, 0x53, 0x06, 0xc0, 0x7a, 0x17, 0x5b, 0x6b, 0x9d, 0xa0, 0xeb, 0x13, 0xac, 0x20, 0x9b, 0x4d, 0x16, 0x47, 0x55, 0x92, 0x9e, 0x03, 0x24, 0x0a, 0x0f, 0xe2, 0x65, 0x99, 0xf1, 0x36, 0xfb, 0x2a, 0xfd, 0xff, 0xd1, 0x2b, 0xb2, 0x03, 0x54, 0xaa, 0x1d, 0x20, 0xe5, 0x79, 0x98, 0x39, 0xab, 0xb6, 0x8a, 0xe4, 0x6d, 0x50, 0xc8, 0x97, 0x4e, 0x13, 0xe3, 0x61, 0xd8, 0x7e, 0xf5, 0x50, 0xfe, 0x6d, 0x82, 0xe8, 0xb5, 0xb1, 0x72, 0xcf, 0x5c, 0xd0, 0x84, 0x82, 0xef, 0xde, 0xf7, 0x93, 0xed, 0xe3, 0x53, 0x0d, 0x24, 0x66, 0x7f, 0xaf, 0x3a, 0x1e, 0x96, 0x34, 0x88, 0x67, 0xc2, 0x94, 0x26, 0x41, 0xf4, 0xc0, 0x36, 0x98, 0x1b, 0x83, 0xf5, 0x02, 0x36, 0xb8, 0xe8, 0xa1, 0x0b, 0x83, 0xeb, 0xf6, 0x90, 0x9a, 0xad, 0x00, 0x76, 0x30, 0x2f, 0x10, 0x83, 0xf7, 0x2d, 0xe4, 0xcf, 0x4a, 0x1a, 0x31, 0x83, 0xfe, 0x6e, 0xc6, 0xbf, 0xe2, 0xe7, 0x3e, 0x2a, 0xf8, 0xe1, 0xe8, 0xc9, 0xd8, 0x50, 0x79, 0x08, 0x3f, 0xd1, 0x79, 0xcc, 0xc2, 0xee, 0x9f, 0xf0, 0x02, 0xf2, 0x13, 0xdb, 0xd7, 0x33, 0x30, 0x53, 0xa4, 0x6c, 0x5e, 0x43),
-//            //   cipher_text: vec!(0xa9, 0xae, 0xb8, 0xf0, 0xa2, 0xb3, 0xca, 0x14, 0x1a, 0xc7, 0x1a, 0x80, 0x8d, 0xcc, 0x0c, 0x97, 0x98, 0xac, 0x11, 0x7c, 0x5d, 0x2b, 0xd0, 0x9b, 0x3c, 0xfe, 0x62, 0x26, 0x93, 0xa9, 0xf8, 0xca, 0x62, 0xe8, 0x41, 0xb5, 0x8b, 0xdd, 0xb2, 0x04, 0x2f, 0x88, 0x8e, 0x30, 0x99, 0xb5, 0x36, 0x38, 0xb8, 0x8d, 0xfc, 0x93, 0x0b, 0x7a, 0x6e, 0xe4, 0x27, 0x2d, 0x77, 0xe4, 0xb1, 0xd7, 0xe4, 0x42, 0xba, 0xb6, 0xaf, 0xbd, 0xe9, 0x6a, 0xb0, 0xb4, 0x32, 0xf0, 0x09, 0x2d, 0x9c, 0xa5, 0x0e, 0xef, 0x42, 0xf6, 0x3c, 0x60, 0xc0, 0x9e, 0x7b, 0x8d, 0xe0, 0x19, 0xb3, 0x2e, 0xbe, 0x40, 0x30, 0xc3, 0x7b, 0x81, 0x83, 0xcc, 0x1e, 0x3b, 0x91, 0x3b, 0x0c, 0xe4, 0xee, 0x4d, 0x74, 0x43, 0x98, 0xfa, 0x03, 0xf9, 0xaf, 0x1c, 0x07, 0x0b, 0xed, 0x8c, 0xda, 0xfd, 0x65, 0xb3, 0xa8, 0x41, 0x40, 0xcb, 0x4d, 0xea, 0xdc, 0x70, 0x18, 0x4d, 0xe7, 0x57, 0x33, 0x2c, 0xe3, 0x78, 0x0a, 0xf8, 0x43, 0x53, 0xf5, 0x40, 0x75, 0x52, 0x27, 0xe8, 0x86, 0xa8, 0xd7, 0xad, 0x98, 0x0f, 0x3d, 0xd6, 0xfd, 0x68, 0x26, 0x3d, 0x82, 0xe9, 0x3f, 0x88, 0x33, 0x81, 0xde, 0xc8, 0x88, 0xbc, 0x9f, 0x4f, 0x48, 0x34, 0x9a, 0xa2, 0xb4, 0xc3, 0x42, 0xcb, 0x9f, 0x48, 0xc6),
-//            //   tag: vec!(0xf2, 0x6b, 0x3a, 0xf8, 0xa4, 0x5c, 0x41, 0x62, 0x91, 0xce, 0x66, 0x33, 0x07, 0x33, 0xb2, 0xf8)
-//            // },
-//            // TestVector{
-//            //   key: [0x40, 0x5b, 0xb7, 0xb9, 0x47, 0x15, 0xb8, 0x75, 0xdf, 0x06, 0x86, 0x55, 0xf0, 0x05, 0x13, 0xcb, 0x1a, 0xe2, 0x3f, 0xfa, 0xac, 0x97, 0x7c, 0xe2, 0x73, 0xe5, 0x7d, 0x3f, 0x83, 0xb4, 0x36, 0x63],
-//            //   nonce: [0x5c, 0x6d, 0xa1, 0x25, 0x94, 0x51, 0x11, 0x9a],
-//            //   plain_text: vec!(0xf9, 0xf1, 0x43, 0xc0, 0xc5, 0x2c, 0x94, 0xb4, 0xba, 0x7b, 0x06, 0x08, 0xb1, 0x44, 0x15, 0x6a, 0x49, 0xe7, 0xb5, 0xd2, 0x7c, 0x97, 0x31, 0x57, 0x43, 0xd1, 0x71, 0x91, 0x1e, 0x36, 0x45, 0xab, 0x79, 0x57, 0xc8, 0x09, 0x24, 0xe3, 0xc6, 0xb9, 0xc2, 0x2a, 0xb7, 0xa1, 0xca, 0xc4, 0xb7, 0xe9, 0xc0, 0xde, 0x84, 0xe4, 0x9f, 0xd5, 0xe4, 0xa2, 0xd1, 0xab, 0x51, 0xd7, 0x64, 0xfc, 0x56, 0x70, 0x31, 0x86, 0x88, 0xec, 0x94, 0x2f, 0x7a, 0xb3, 0x4c, 0x33, 0x1d, 0xce, 0x8f, 0x90, 0xfe, 0xa6, 0x97, 0x2e, 0x07, 0xf0, 0xda, 0xde, 0xc2, 0x9d, 0x8e, 0xb3, 0xb7, 0xb6, 0x52, 0x1d, 0xdd, 0x67, 0x8a, 0x65, 0x27, 0xa9, 0x62, 0xf4, 0xd8, 0xaf, 0x78, 0xc0, 0x77, 0xe2, 0x7f, 0x7a, 0x0b, 0x2e, 0xf7, 0xea, 0xbd, 0x19, 0xe9, 0x2b, 0x7f, 0x8c, 0x1e, 0x8f, 0xb1, 0x66, 0xd4, 0x76, 0x3c, 0xe9, 0xc4, 0x0c, 0x88, 0x8c, 0xf4, 0x9a, 0xa9, 0xcd, 0xfc, 0x3e, 0x99, 0x7c, 0x8f, 0xe1, 0xcc, 0xe3, 0xfe, 0x80, 0x24, 0x41, 0xbb, 0xd6, 0x98, 0xde, 0x26, 0x9f, 0xf3, 0x16, 0xf3, 0x1c, 0x19, 0x6e, 0x62, 0xd1, 0x2c, 0x6b, 0xb5, 0xcd, 0x93, 0xfb, 0x3c, 0x79, 0xca, 0x63, 0x69, 0xf8, 0xc1, 0xac, 0x91, 0x02, 0xda, 0xf8, 0x18, 0x97, 0x5e, 0xa7, 0xf5, 0x13, 0xbb, 0x38, 0x57, 0x6a),
-//            //   aad: vec!(0x6f, 0xe6, 0x44, 0x65, 0x05, 0x67, 0x7b, 0xf0, 0x8b, 0x38, 0x5e, 0x2f, 0x6d, 0x83, 0xef, 0x70, 0xe1, 0x54, 0x77, 0x12, 0x20, 0x8d, 0x9c, 0xeb, 0xc0, 0x10, 0xcb, 0xa8, 0xc1, 0x6e, 0xa4, 0xec, 0xe0, 0x58, 0xd7, 0x3c, 0x72, 0x27, 0x3e, 0xed, 0x65, 0x0a, 0xfd, 0xc9, 0xf9, 0x54, 0xf3, 0x5a, 0xa1, 0xbd, 0xf9, 0x0f, 0x11, 0x18, 0xb1, 0x17, 0x33, 0x68, 0xac, 0xbc, 0x8d, 0x38, 0xd9, 0x3e, 0xbf, 0x85, 0xbd, 0x30, 0xd6, 0xdc, 0x6d, 0x1b, 0x90, 0x91, 0x37, 0x90, 0xc3, 0xef, 0xa5, 0x5f, 0x34, 0xd3, 0x15, 0x31, 0xf7, 0x0c, 0x95, 0x87, 0x59, 0xb2, 0xba, 0x6f, 0x95, 0x6c, 0x6f, 0xcd, 0xd2, 0x89, 0xb5, 0x8c, 0xb4, 0xc2, 0x6e, 0x95, 0x15, 0xbf, 0x55, 0x0f, 0x0f, 0xd7, 0x1a, 0xb8, 0x52, 0x7f, 0x06, 0x2c, 0x95, 0x05, 0xcb, 0xb1, 0x6e, 0x8e, 0x03, 0x7d, 0x34, 0xde, 0x17, 0x56, 0xbe, 0xf0, 0x2a, 0x13, 0x3d, 0xbf, 0x4a, 0x9c, 0x00, 0xac, 0x03, 0xbe, 0xfc, 0x3f, 0xb7, 0xf1, 0x37, 0xaf, 0x04, 0xe1, 0x25, 0x95, 0xce, 0x95, 0x60, 0xf9, 0x8b, 0x61, 0x24, 0x80, 0xfc, 0xdb, 0xa3, 0xb8, 0xbe, 0x01, 0xdb, 0x56, 0xeb, 0xec, 0x40, 0xf9, 0xde, 0xae, 0x53, 0x2c, 0x3b, 0x03, 0x70, 0xb5, 0xc2, 0x3a, 0x2a, 0x6b, 0x02, 0xa4, 0xde, 0x69, 0xef, 0xa8, 0x90, 0x0c),
-//            //   cipher_text: vec!(0x1a, 0x4b, 0x07, 0x38, 0x81, 0x92, 0x2c, 0x63, 0x66, 0x68, 0x0c, 0xc9, 0xc2, 0xa1, 0x27, 0xb2, 0x6f, 0x26, 0x41, 0x48, 0x65, 0x1b, 0x29, 0xab, 0xb0, 0xc3, 0x88, 0xcf, 0x6c, 0x9b, 0x18, 0x65, 0xdb, 0xa5, 0xa9, 0x91, 0xe1, 0xf8, 0x30, 0x9e, 0xfb, 0xdb, 0x91, 0xbc, 0xe4, 0x4b, 0x27, 0x87, 0x72, 0xc5, 0x8f, 0xd4, 0x12, 0x73, 0x52, 0x6c, 0x33, 0xfe, 0xc8, 0x4b, 0xeb, 0x53, 0xd1, 0x68, 0x9b, 0x9d, 0xa8, 0x48, 0x3f, 0x71, 0xbe, 0x6d, 0xb7, 0x3a, 0x73, 0x41, 0x70, 0x69, 0xbb, 0x4c, 0xd3, 0xf1, 0x95, 0x23, 0x6e, 0x8d, 0x0a, 0x00, 0xd1, 0x24, 0xee, 0xd3, 0xa6, 0xb6, 0xf8, 0x94, 0x15, 0xb1, 0x9a, 0x27, 0xfb, 0xe3, 0x57, 0x74, 0xf6, 0xa1, 0xa6, 0xee, 0x4b, 0xd4, 0x35, 0x0b, 0x25, 0x2b, 0x97, 0x5f, 0x0d, 0xb2, 0xd2, 0xee, 0xa8, 0x2f, 0x48, 0x36, 0x35, 0x08, 0x50, 0xd6, 0x29, 0x09, 0x01, 0xe7, 0x26, 0xe8, 0xaf, 0x13, 0x64, 0x4e, 0x2d, 0x98, 0xbc, 0x1d, 0x56, 0x9c, 0x20, 0x80, 0x05, 0x21, 0xe6, 0xaf, 0xfe, 0x97, 0x6b, 0xd4, 0x07, 0x04, 0x9a, 0x2e, 0x6d, 0x9d, 0xd2, 0x3f, 0x88, 0xd5, 0x2e, 0x65, 0x13, 0x91, 0xec, 0xd2, 0xfc, 0x45, 0xb8, 0x64, 0x31, 0x08, 0x24, 0xaa, 0xad, 0xfa, 0x20, 0x37, 0x62, 0xa7, 0x7c, 0x1d, 0x64, 0x56, 0x2d, 0xae),
-//            //   tag: vec!(0x00, 0x60, 0x02, 0x6d, 0x3e, 0xfc, 0x12, 0x0f, 0x11, 0xc0, 0x73, 0x99, 0x59, 0xae, 0x00, 0x66)
-//            // },
-//            // TestVector{
-//            //   key: [0x8c, 0x60, 0x2b, 0xd9, 0x4c, 0x63, 0x0c, 0xd0, 0x0c, 0x7a, 0x9c, 0x50, 0x80, 0x67, 0xa5, 0xa9, 0xf1, 0x33, 0xd1, 0x2f, 0x06, 0xd9, 0xf6, 0xfe, 0x2a, 0x7b, 0x68, 0xdc, 0xe4, 0x78, 0x6d, 0x8a],
-//            //   nonce: [0x76, 0x0d, 0xe0, 0xf7, 0xb7, 0xcb, 0x67, 0xe2],
-//            //   plain_text: vec!(0xc3, 0xff, 0x55, 0x9c, 0xf1, 0xd6, 0xba, 0x6c, 0x0c, 0xc7, 0x93, 0xca, 0x09, 0xa0, 0xba, 0x57, 0x3a, 0x28, 0x35, 0x93, 0x86, 0xa6, 0xec, 0x93, 0xe1, 0xba, 0xcd, 0x8e, 0x63, 0x02, 0x09, 0xe0, 0xb4, 0x77, 0xa2, 0x0a, 0xed, 0xec, 0x3c, 0x9c, 0xbf, 0x51, 0x3e, 0xe6, 0xa1, 0xe3, 0x88, 0x71, 0x12, 0x21, 0x8d, 0x61, 0x55, 0xb9, 0x87, 0x5f, 0x7e, 0x6c, 0x4b, 0xbb, 0xa2, 0xc3, 0x19, 0x72, 0xe9, 0x05, 0xd1, 0x9f, 0x52, 0x9f, 0x4f, 0x0f, 0x95, 0x02, 0x99, 0x61, 0x99, 0xf9, 0x4f, 0x87, 0x28, 0xba, 0x8d, 0x64, 0x24, 0xbb, 0x15, 0xf8, 0x7f, 0xca, 0xcd, 0x88, 0xbb, 0x42, 0xc6, 0x3f, 0xcc, 0x51, 0x37, 0x59, 0x71, 0x2b, 0xd0, 0x17, 0x2b, 0x1e, 0x87, 0xc9, 0xda, 0x12, 0x2f, 0x19, 0x93, 0xff, 0xb7, 0xef, 0xd3, 0xa5, 0xc3, 0x4b, 0x24, 0x0d, 0xd3, 0xdb, 0x89, 0xdd, 0xde, 0xa3, 0x6d, 0xbe, 0xb2, 0x83, 0x6d, 0x9f, 0x86, 0x48, 0xf8, 0xe7, 0xcd, 0x42, 0x8c, 0x0f, 0x94, 0x80, 0x97, 0xaf, 0x75, 0x3b, 0x35, 0xf9, 0x87, 0x60, 0x59, 0xe7, 0x70, 0x20, 0x27, 0xbb, 0x00, 0xdc, 0x69, 0x07, 0x12, 0x06, 0xe7, 0x85, 0xf4, 0x8f, 0xcb, 0xf8, 0x1b, 0x39, 0xcc, 0x03, 0x43, 0x97, 0x4a, 0xc7, 0x07, 0x84, 0xa2, 0xe6, 0x0c, 0x0d, 0xf9, 0x3b, 0x40, 0x37, 0x9b, 0xea, 0x4a, 0xd8, 0xca, 0xc6, 0x25),
-//            //   aad: vec!(0x9e, 0x14, 0x90, 0x7c, 0x3a, 0x8e, 0x96, 0xc2, 0x63, 0x6d, 0xb1, 0xf3, 0xd7, 0x8e, 0xb1, 0xf6, 0x73, 0xd6, 0xef, 0x04, 0x3c, 0xbb, 0xb3, 0x49, 0x46, 0x7f, 0x1f, 0xe2, 0x9b, 0xf6, 0x0f, 0x23, 0xd5, 0xd5, 0xd1, 0xc3, 0xb1, 0x33, 0xa8, 0xad, 0x72, 0x06, 0x5d, 0x82, 0x23, 0x47, 0x54, 0x1c, 0x13, 0xd1, 0x57, 0x4b, 0xaf, 0x73, 0x7e, 0xb3, 0xcc, 0x33, 0x82, 0xfb, 0x47, 0x9e, 0x6d, 0x51, 0x93, 0xb9, 0xc8, 0xe7, 0xd2, 0x44, 0x4c, 0x66, 0x97, 0x1e, 0xf0, 0x99, 0xdc, 0x7f, 0x37, 0xf6, 0xcd, 0x97, 0xb9, 0xf7, 0x95, 0x9d, 0x46, 0xe2, 0xcf, 0x25, 0xe8, 0xa5, 0xb3, 0x11, 0x1b, 0x4d, 0x9e, 0x2e, 0xf9, 0x06, 0xd9, 0x05, 0xf0, 0xee, 0x2d, 0x17, 0x58, 0x7f, 0x70, 0x82, 0xd7, 0xc8, 0xe9, 0xa5, 0x15, 0x09, 0xbd, 0xe0, 0x3d, 0x3d, 0x64, 0x33, 0x8e, 0x18, 0x38, 0xd7, 0x17, 0x00, 0xf1, 0xb4, 0xfc, 0xb1, 0x00, 0xb5, 0xe0, 0x40, 0x29, 0x69, 0xda, 0x46, 0x2f, 0x26, 0xf9, 0x74, 0xb4, 0xf9, 0xe7, 0x66, 0x12, 0x1f, 0x8f, 0xd5, 0x4b, 0xe9, 0x9f, 0xc1, 0x0b, 0xeb, 0x9a, 0x60, 0x6e, 0x13, 0xfb, 0xb1, 0xf9, 0x60, 0x06, 0x28, 0x15, 0xd1, 0x9e, 0x67, 0xf8, 0x00, 0x93, 0x36, 0x03, 0x24, 0x01, 0x30, 0x95, 0x71, 0x92, 0x73, 0xc6, 0x55, 0x42, 0xb0, 0xe3, 0x1b, 0x1a, 0x2a, 0x3d, 0x92, 0x8f),
-//            //   cipher_text: vec!(0x27, 0x94, 0xe6, 0xe1, 0x33, 0xf6, 0x89, 0x2f, 0x23, 0x83, 0x7f, 0xff, 0x60, 0xcf, 0x7c, 0x28, 0xee, 0x99, 0x42, 0xf8, 0x98, 0x2e, 0xf8, 0x08, 0x9d, 0xb1, 0x17, 0x90, 0x3d, 0x01, 0x43, 0x29, 0x3f, 0xdf, 0x12, 0xea, 0x1c, 0xc0, 0x14, 0xbc, 0xd8, 0x80, 0x6f, 0xb8, 0x3c, 0x19, 0x57, 0x0e, 0xed, 0x7a, 0xf5, 0x22, 0xdb, 0x0d, 0xe4, 0x89, 0xbb, 0xc8, 0x71, 0x33, 0xa1, 0x34, 0x34, 0x51, 0x8b, 0xcf, 0xb9, 0xcd, 0xa4, 0xd9, 0xf6, 0xd8, 0x32, 0xa6, 0x92, 0x09, 0x65, 0x7a, 0x44, 0x7a, 0xbf, 0x8a, 0xfd, 0x81, 0x6a, 0xe1, 0x5f, 0x31, 0x3c, 0x7e, 0xa9, 0x5e, 0xc4, 0xbc, 0x69, 0x4e, 0xfc, 0x23, 0x86, 0xcd, 0xd8, 0xd9, 0x15, 0xdc, 0x47, 0x5e, 0x8f, 0xad, 0xf3, 0x42, 0x1f, 0xbb, 0x03, 0x19, 0xa3, 0xc0, 0xb3, 0xb6, 0xdf, 0xa8, 0x0c, 0xa3, 0xbb, 0x22, 0xc7, 0xaa, 0xb0, 0x7f, 0xe1, 0x4a, 0x3f, 0xea, 0x5f, 0x0a, 0xee, 0x17, 0xab, 0x13, 0x02, 0x33, 0x8e, 0xea, 0xc0, 0x10, 0xa0, 0x4e, 0x50, 0x5e, 0x20, 0x09, 0x6a, 0x95, 0xf3, 0x34, 0x7d, 0xc2, 0xb4, 0x51, 0x0f, 0x62, 0xd6, 0xa4, 0xc1, 0xfa, 0xe6, 0xb3, 0x69, 0x39, 0x50, 0x3a, 0x6a, 0xc2, 0x27, 0x80, 0xa6, 0x2d, 0x72, 0xf2, 0xfc, 0x38, 0x49, 0xd4, 0xef, 0x21, 0x26, 0x7f, 0xff, 0xde, 0xf2, 0x31, 0x96, 0xd8, 0x8f, 0xbb, 0x9b),
-//            //   tag: vec!(0x45, 0x7c, 0xce, 0x6e, 0x07, 0x5f, 0xfd, 0xb1, 0x80, 0x76, 0x5a, 0xb2, 0xe1, 0x05, 0xc7, 0x07)
-//            // },
-//            // TestVector{
-//            //   key: [0xbd, 0x68, 0xff, 0x5e, 0xb2, 0x96, 0xc7, 0x1c, 0xfe, 0x6b, 0xc9, 0x03, 0xc1, 0x49, 0x07, 0xf7, 0x72, 0x6b, 0xcb, 0x13, 0x31, 0xf0, 0xc7, 0x5f, 0x78, 0x01, 0xcd, 0x1b, 0x79, 0x48, 0xf3, 0xa1],
-//            //   nonce: [0x65, 0xa7, 0x48, 0x00, 0x4b, 0x35, 0x2b, 0xa6],
-//            //   plain_text: vec!(0x52, 0xbf, 0x78, 0xc0, 0x0f, 0x6e, 0x5d, 0xca, 0x2f, 0xc6, 0x0e, 0x2e, 0x9a, 0x52, 0xe8, 0x27, 0xdf, 0x97, 0x80, 0x8e, 0x9c, 0xf7, 0x27, 0x77, 0x38, 0x60, 0xca, 0xfc, 0x89, 0xf4, 0xb6, 0x41, 0x78, 0xa1, 0x9b, 0x30, 0xb4, 0x6e, 0xd8, 0x13, 0xfe, 0x00, 0xc8, 0xf0, 0x9b, 0x25, 0xa6, 0xa1, 0xb6, 0xe3, 0x50, 0xd5, 0xb0, 0x05, 0x12, 0x29, 0x34, 0xa5, 0x9b, 0xfb, 0xd5, 0xe6, 0xe0, 0xc6, 0x35, 0xc8, 0x4a, 0x52, 0x26, 0xc3, 0xf2, 0xf7, 0xdc, 0xf9, 0x51, 0x56, 0x0f, 0x18, 0xac, 0x22, 0x04, 0x53, 0xd5, 0x83, 0x01, 0x5f, 0xdb, 0x2e, 0x44, 0x6c, 0x69, 0xc6, 0xe6, 0xfd, 0xec, 0xf2, 0xe5, 0x95, 0xe0, 0x4f, 0xab, 0x1b, 0x0c, 0x50, 0x6e, 0x3c, 0x6b, 0xd5, 0xe4, 0x41, 0x4a, 0x35, 0xf1, 0x50, 0x21, 0xe9, 0x7f, 0x44, 0x7a, 0xa3, 0x34, 0xf5, 0x4a, 0x8f, 0x1e, 0xf9, 0x42, 0xde, 0xc6, 0x27, 0x35, 0x11, 0xb5, 0x66, 0x8b, 0x69, 0x6f, 0xca, 0x97, 0x18, 0x8f, 0xf1, 0x5e, 0xd8, 0x4b, 0x2f, 0x46, 0x14, 0x5c, 0xce, 0x03, 0x1c, 0x1a, 0x7f, 0x00, 0xbd, 0x88, 0xbb, 0x83, 0xd9, 0x07, 0x97, 0xed, 0xc4, 0x61, 0x61, 0xb3, 0xfd, 0xa7, 0xa2, 0x29, 0x91, 0x73, 0x49, 0x6d, 0x73, 0xb8, 0x12, 0x13, 0x95, 0x56, 0xe8, 0xb4, 0xeb, 0x31, 0x80, 0x78, 0xb9, 0xeb, 0x2a, 0xe5, 0x04, 0x6e, 0x83, 0xb7, 0x9d, 0xd3, 0xd4, 0x59, 0x50),
-//            //   aad: vec!(0x55, 0x57, 0xb0, 0x8a, 0x50, 0x10, 0xcb, 0xc9, 0xf4, 0x6b, 0xb1, 0x40, 0xc2, 0x50, 0x5f, 0x68, 0x68, 0x4e, 0xb2, 0x48, 0x89, 0x32, 0x4b, 0xff, 0x44, 0xb2, 0x72, 0x34, 0xfd, 0x7a, 0x95, 0xa9, 0x9c, 0xfb, 0x4f, 0xf9, 0x0a, 0x8f, 0x99, 0x82, 0x08, 0x5b, 0x72, 0x5f, 0x78, 0xac, 0x42, 0xec, 0xa6, 0xce, 0x7f, 0x33, 0x14, 0xe4, 0x57, 0xdc, 0x41, 0xf4, 0x04, 0x00, 0x86, 0x81, 0xa9, 0xd2, 0x9b, 0xa7, 0x65, 0x66, 0x0d, 0xe2, 0xe0, 0x5b, 0xb6, 0x79, 0xd6, 0x5b, 0x81, 0xf5, 0xe7, 0x97, 0xd8, 0x41, 0x7b, 0x94, 0xeb, 0x9a, 0xab, 0xbd, 0x05, 0x76, 0xb5, 0xc5, 0x7f, 0x86, 0xea, 0xe2, 0x5f, 0x60, 0x50, 0xa7, 0x91, 0x8e, 0x4c, 0x80, 0x21, 0xa8, 0x5b, 0x47, 0xf7, 0xa8, 0x3b, 0x4c, 0x84, 0x46, 0x89, 0x84, 0x41, 0xc5, 0xcc, 0x4e, 0x02, 0x29, 0x77, 0x6e, 0xf3, 0xe8, 0x09, 0xcb, 0x08, 0x5d, 0x71, 0xf3, 0xc7, 0x5e, 0xc0, 0x33, 0x78, 0x73, 0x0c, 0xb0, 0x66, 0x15, 0x0f, 0x07, 0xe6, 0x0f, 0x96, 0xae, 0xc9, 0x83, 0xc0, 0xe7, 0xe7, 0x2b, 0xf6, 0xbf, 0x87, 0xae, 0x42, 0x22, 0x8d, 0xfd, 0xa1, 0x95, 0xf9, 0x78, 0x55, 0xfc, 0xdf, 0x4e, 0x6d, 0x1c, 0x44, 0x79, 0xd9, 0x78, 0xab, 0xcf, 0xa2, 0x76, 0xd1, 0x6e, 0xd6, 0x0e, 0xcb, 0xfb, 0xfc, 0x66, 0x40, 0x41, 0x33, 0x5c, 0xe6, 0x5a, 0x40, 0xa2, 0xca, 0x34, 0x24, 0xdf),
-//            //   cipher_text: vec!(0xa5, 0xc8, 0xcf, 0x42, 0x28, 0x7d, 0x47, 0x60, 0xfc, 0xa7, 0x55, 0xe2, 0x11, 0x18, 0x17, 0xb9, 0x81, 0xc4, 0x7e, 0x85, 0xb0, 0x04, 0x7d, 0xe2, 0x70, 0xec, 0x30, 0x1c, 0xa5, 0xf7, 0xb3, 0x67, 0x9f, 0x47, 0x49, 0x21, 0x08, 0x92, 0xb6, 0xea, 0x65, 0x68, 0xf3, 0xa6, 0xa4, 0x34, 0x47, 0x34, 0xa0, 0xef, 0xc0, 0x12, 0x0f, 0xfe, 0xde, 0xcf, 0x21, 0x2d, 0x55, 0xcb, 0xcb, 0xb6, 0x78, 0x15, 0xac, 0x96, 0x48, 0x75, 0xaf, 0x45, 0xf7, 0x35, 0xb7, 0x00, 0x92, 0xa8, 0xf8, 0x43, 0x5f, 0x52, 0xfc, 0x01, 0xb9, 0x81, 0xae, 0x97, 0x1d, 0x48, 0x60, 0x26, 0xfb, 0x69, 0xa9, 0xc3, 0x92, 0x7a, 0xcf, 0xe1, 0xf2, 0xea, 0xb0, 0x34, 0x0a, 0xe9, 0x5f, 0x8d, 0xbe, 0xe4, 0x1b, 0x25, 0x48, 0xe4, 0x00, 0x80, 0x5e, 0xce, 0x19, 0x1d, 0xb5, 0xfd, 0x1f, 0x08, 0x04, 0x05, 0x3f, 0x1d, 0xbf, 0xaf, 0x7f, 0x8d, 0x6f, 0xde, 0xd3, 0x87, 0x4c, 0xb9, 0x2d, 0x99, 0xa2, 0x72, 0x9d, 0x3f, 0xaa, 0xa6, 0x05, 0x22, 0x06, 0x0c, 0xf0, 0xb8, 0x10, 0x1b, 0x46, 0x3b, 0x3e, 0xb3, 0x5b, 0x38, 0x0f, 0xcd, 0xdb, 0x64, 0x06, 0xc0, 0x27, 0xd7, 0x3f, 0xe7, 0x01, 0xa5, 0x09, 0x0c, 0x8d, 0xd5, 0x31, 0xc2, 0x03, 0xce, 0x97, 0x9e, 0x26, 0xb9, 0xce, 0xd3, 0x43, 0x1e, 0x2b, 0x72, 0x6a, 0x72, 0x44, 0xa2, 0x0d, 0x93, 0x77, 0xbd, 0x62, 0x95, 0x1b, 0xf5),
-//            //   tag: vec!(0x45, 0x79, 0xfa, 0x1f, 0xdb, 0x4c, 0x67, 0x4c, 0xc3, 0xcd, 0x23, 0x2b, 0x8d, 0xa5, 0x2a, 0x97)
-//            // },
-//            // TestVector{
-//            //   key: [0x93, 0x4f, 0xd0, 0x43, 0xc3, 0x2d, 0x16, 0xa8, 0x8f, 0xad, 0x01, 0xc3, 0x50, 0x64, 0x69, 0xb0, 0x77, 0xcb, 0x79, 0xd2, 0x58, 0xb5, 0x66, 0x4f, 0xa5, 0x5a, 0xd8, 0x52, 0x1a, 0xfd, 0xca, 0xa2],
-//            //   nonce: [0xc7, 0x09, 0x1f, 0x6a, 0xfb, 0xbe, 0xb3, 0x60],
-//            //   plain_text: vec!(0x2b, 0xdd, 0x1f, 0xc4, 0xf0, 0x11, 0xef, 0x97, 0xea, 0x52, 0xec, 0x64, 0x38, 0x19, 0x94, 0x1c, 0x7e, 0x0f, 0xb3, 0x90, 0x23, 0xc2, 0xf3, 0xc7, 0x68, 0x38, 0x04, 0xa0, 0xdd, 0xee, 0x14, 0xa5, 0xd1, 0x78, 0x4a, 0x52, 0x46, 0x96, 0x6d, 0x53, 0x3b, 0x35, 0x38, 0xed, 0xc7, 0xd8, 0x74, 0x2d, 0x27, 0x06, 0x1c, 0x3c, 0xab, 0x88, 0xdf, 0x03, 0x18, 0xab, 0x24, 0x21, 0x02, 0xde, 0x3a, 0x54, 0xd0, 0x36, 0x32, 0xee, 0xb8, 0x71, 0xb7, 0x2c, 0x7e, 0x8f, 0x80, 0x65, 0xb4, 0x9f, 0x4a, 0x91, 0xe9, 0x5e, 0x15, 0xf3, 0xf4, 0x6b, 0x29, 0xfd, 0x76, 0xb8, 0xfc, 0xea, 0x0d, 0x23, 0x57, 0x0c, 0x55, 0x30, 0xe3, 0xbb, 0xb8, 0xa6, 0xaa, 0xfa, 0x9a, 0xe3, 0x2c, 0x1b, 0x3e, 0xac, 0x65, 0x3c, 0x5e, 0xd5, 0xfd, 0xb2, 0xda, 0x5a, 0x98, 0x60, 0x75, 0x80, 0x8f, 0x63, 0x85, 0x87, 0x0c, 0x85, 0xb1, 0x91, 0x3e, 0x26, 0x04, 0x2a, 0x9d, 0x8e, 0x78, 0xf5, 0xbc, 0x2e, 0xa6, 0xde, 0x5a, 0x64, 0xf8, 0xae, 0xaf, 0xa2, 0x2a, 0xdc, 0xff, 0xc7, 0xf6, 0x93, 0x2d, 0x54, 0x3c, 0x29, 0xbb, 0x3a, 0x04, 0x61, 0x47, 0x83, 0xf9, 0x48, 0x68, 0x0e, 0x43, 0x3a, 0x71, 0x57, 0x35, 0x68, 0xd2, 0xce, 0x98, 0x4d, 0x24, 0x9f, 0xb4, 0xfc, 0x06, 0xa9, 0xf3, 0x58, 0xc7, 0x6a, 0xa3, 0xe6, 0x4a, 0x35, 0x7f, 0x4e, 0xae, 0x92, 0x4c, 0x13, 0x56, 0xbd, 0x5b, 0xac, 0xcf, 0x7e, 0x0f),
-//            //   aad: vec!(0xf7, 0x37, 0xdd, 0x85, 0x63, 0x8e, 0xb3, 0x24, 0xdd, 0x38, 0x91, 0x21, 0x9c, 0x5e, 0xef, 0x7c, 0x2d, 0xd0, 0x53, 0xcf, 0xd0, 0x55, 0xd4, 0x47, 0xa4, 0x11, 0xeb, 0xa3, 0x04, 0xa4, 0xb2, 0x7d, 0xce, 0x98, 0x1d, 0x11, 0x2c, 0x45, 0x40, 0x59, 0x09, 0x33, 0xc1, 0x53, 0xd6, 0x03, 0x02, 0x2c, 0x91, 0xeb, 0xd2, 0xb4, 0xa5, 0x80, 0x69, 0xd2, 0x7e, 0x6c, 0xa1, 0x7a, 0x46, 0x2e, 0xf8, 0x22, 0xca, 0x41, 0xbf, 0xfa, 0x80, 0xb4, 0x3a, 0x68, 0xb1, 0xb5, 0x64, 0x64, 0x4c, 0xb3, 0xc5, 0xa7, 0xf0, 0xfd, 0xdf, 0x7a, 0x13, 0xa3, 0x0f, 0xf2, 0x44, 0x37, 0xfd, 0xdd, 0x8e, 0xf9, 0x3c, 0x6f, 0x6f, 0x20, 0x5d, 0x05, 0x4f, 0x81, 0x89, 0x0d, 0x98, 0x2b, 0xd4, 0xd4, 0xec, 0xe0, 0xb1, 0x56, 0x36, 0x77, 0xe8, 0x43, 0xfe, 0x48, 0xc1, 0xf5, 0x4e, 0x9a, 0x57, 0xed, 0x4d, 0xa6, 0x60, 0x61, 0x48, 0x27, 0x12, 0xe7, 0x10, 0xa4, 0x01, 0x07, 0x3b, 0xe5, 0x08, 0x0d, 0x5b, 0x8b, 0x96, 0x52, 0x5b, 0xff, 0xa6, 0x7d, 0xe5, 0xaf, 0x31, 0xd5, 0x03, 0x85, 0xfb, 0xbf, 0x1a, 0x87, 0xc2, 0x1b, 0xf0, 0xe0, 0xa1, 0xfd, 0xff, 0x69, 0xec, 0x32, 0xc7, 0xb7, 0x10, 0x3e, 0x0b, 0x8e, 0xe6, 0xc8, 0x44, 0x24, 0x5e, 0x0f, 0xc8, 0x4b, 0x9f, 0x89, 0xfc, 0xce, 0x62, 0x96, 0x6c, 0xea, 0x68, 0xe2, 0x87, 0x1d, 0x3b, 0x82, 0xe8, 0xdf, 0x42, 0x4c, 0x76, 0x30, 0x9f, 0xc8, 0x8d),
-//            //   cipher_text: vec!(0xdd, 0x13, 0xfb, 0xf2, 0x2c, 0x8d, 0x18, 0x35, 0x4d, 0x77, 0x4b, 0xcd, 0x18, 0xf7, 0xeb, 0x81, 0x4e, 0x9b, 0x52, 0x8e, 0x9e, 0x42, 0x4a, 0xbc, 0x4e, 0x3f, 0x24, 0x63, 0x19, 0x5e, 0x80, 0x18, 0x57, 0x65, 0x65, 0xd1, 0x6a, 0xb4, 0x88, 0x45, 0xd1, 0x1c, 0x92, 0x77, 0xf2, 0x86, 0x5e, 0xbb, 0x4d, 0xc4, 0x12, 0xfd, 0x5b, 0x27, 0x07, 0x8f, 0x83, 0x25, 0xea, 0xdf, 0x97, 0x1e, 0x69, 0x44, 0xc6, 0x65, 0x42, 0xe3, 0x4d, 0x9d, 0xda, 0x97, 0x1e, 0x2a, 0xba, 0x70, 0xdb, 0xd3, 0xe9, 0x4a, 0x1e, 0x63, 0x8d, 0x52, 0x14, 0x77, 0xa0, 0x27, 0x77, 0x6b, 0x52, 0xac, 0xf9, 0x05, 0x20, 0xca, 0x22, 0x9e, 0xbc, 0x76, 0x0b, 0x73, 0x12, 0x88, 0x79, 0x47, 0x5d, 0x1c, 0xbe, 0x1f, 0x70, 0xfc, 0x59, 0x8b, 0x54, 0x9c, 0xd9, 0x2d, 0x8a, 0x9a, 0xc6, 0x83, 0x3e, 0x50, 0x0c, 0x13, 0x8c, 0x56, 0x47, 0x4d, 0xb8, 0x4c, 0xb3, 0xd7, 0x0b, 0x7a, 0xa4, 0xf2, 0x93, 0xa4, 0xc2, 0xb4, 0xd8, 0x18, 0xb0, 0xff, 0x9f, 0xd8, 0x59, 0x18, 0xdc, 0x59, 0x0a, 0x12, 0xa8, 0xc0, 0xe3, 0x75, 0xc4, 0xd9, 0x8b, 0x7f, 0xc8, 0x75, 0x96, 0x54, 0x7e, 0xb9, 0x60, 0x67, 0x6a, 0xad, 0x55, 0x59, 0x83, 0x45, 0x88, 0xf0, 0x0f, 0x25, 0x1a, 0x9d, 0x53, 0xf9, 0x5c, 0x47, 0xaf, 0x4d, 0xf3, 0xc4, 0x29, 0x91, 0x75, 0xd5, 0x21, 0x17, 0x79, 0xc1, 0x48, 0xcf, 0xc9, 0x88, 0xa5, 0xe9, 0xd9),
-//            //   tag: vec!(0x47, 0x66, 0x16, 0xea, 0x15, 0x19, 0x0c, 0x10, 0x93, 0xfd, 0xc4, 0xa0, 0x87, 0x64, 0x3c, 0xae)
-//            // },
-//            // TestVector{
-//            //   key: [0xf9, 0xf6, 0xeb, 0x9a, 0xd7, 0x36, 0xa8, 0xf6, 0x6e, 0x74, 0x59, 0xfe, 0xf5, 0xec, 0x28, 0x90, 0x18, 0x8d, 0xc2, 0x6b, 0xaf, 0x34, 0xa9, 0x5f, 0x6f, 0x03, 0x84, 0xe7, 0x9f, 0x5c, 0x65, 0x59],
-//            //   nonce: [0x78, 0x58, 0xdf, 0xc0, 0x84, 0xfe, 0x4b, 0x0f],
-//            //   plain_text: vec!(0xa6, 0x44, 0xca, 0x6e, 0x7c, 0xc0, 0x76, 0xe8, 0x7e, 0xb2, 0x92, 0x9f, 0xd2, 0x57, 0x69, 0x3f, 0xce, 0x0f, 0x6f, 0xb6, 0x4f, 0xd6, 0x32, 0xf7, 0xf0, 0x7c, 0x64, 0x8e, 0xbd, 0x03, 0x69, 0x6c, 0x8e, 0x26, 0x2e, 0x6a, 0x81, 0x0d, 0x7b, 0x7c, 0x4e, 0x5e, 0xef, 0x8c, 0x65, 0xb5, 0x32, 0x3c, 0x99, 0xdb, 0xba, 0x50, 0xa7, 0x0b, 0x4a, 0x9e, 0x5c, 0x2a, 0x9e, 0x73, 0x15, 0x97, 0x3c, 0xd6, 0x7f, 0x35, 0xd8, 0x05, 0x2c, 0xe9, 0xa8, 0x5a, 0x20, 0x64, 0x16, 0xdd, 0x30, 0x31, 0x92, 0x9f, 0x4f, 0x92, 0x9b, 0x13, 0xd0, 0xa5, 0xfb, 0x10, 0xcb, 0x73, 0xc6, 0x5f, 0x6c, 0x0a, 0xce, 0x01, 0x9d, 0xa1, 0x46, 0xb5, 0x1c, 0x52, 0x74, 0xa0, 0x99, 0xf4, 0x4e, 0x36, 0x69, 0xd2, 0x6a, 0xdd, 0x6f, 0x2f, 0xf0, 0x81, 0xe8, 0x86, 0xf3, 0xcf, 0x95, 0x2f, 0xe0, 0xdb, 0xbe, 0x6b, 0x05, 0x34, 0xc2, 0x3e, 0x30, 0x75, 0x74, 0xbd, 0x35, 0xfb, 0xd6, 0x57, 0xf5, 0xfc, 0xbd, 0x5d, 0xc1, 0x9f, 0xb3, 0x82, 0xa1, 0xdc, 0x0a, 0x2d, 0xc8, 0x28, 0x5a, 0x03, 0x50, 0xf7, 0x15, 0x54, 0xe4, 0xc6, 0x01, 0x49, 0x77, 0x49, 0xe3, 0x55, 0x67, 0xdd, 0x4a, 0x27, 0x3c, 0xdd, 0xc9, 0xa4, 0x8c, 0xe5, 0x3a, 0x5f, 0x1d, 0x29, 0x7f, 0xd8, 0xba, 0xf8, 0xd1, 0xb9, 0xfe, 0xb3, 0x5d, 0x91, 0x51, 0x11, 0x43, 0x45, 0xab, 0xad, 0xa4, 0xd9, 0x0d, 0xb9, 0x47, 0xbb, 0x9a, 0x74, 0x3c, 0x17, 0x5f, 0x56, 0x53, 0xd1),
-//            //   aad: vec!(0x20, 0x48, 0xd1, 0xc2, 0xdd, 0xfb, 0x5e, 0xc3, 0x85, 0xb2, 0x01, 0x83, 0x2c, 0x7a, 0x99, 0x3f, 0x22, 0x9b, 0xa7, 0x2e, 0xc1, 0x6d, 0x6e, 0xbf, 0x72, 0x3e, 0xf0, 0xc5, 0x03, 0x2b, 0x99, 0x66, 0x20, 0x9a, 0x9e, 0x8a, 0x63, 0x15, 0x1b, 0x40, 0x41, 0x2e, 0x96, 0xb8, 0x2f, 0x86, 0x72, 0x8e, 0xa6, 0x58, 0x8c, 0x7e, 0x8e, 0x11, 0xac, 0x71, 0xcc, 0x8e, 0xab, 0xab, 0x8c, 0x4b, 0x54, 0xde, 0x86, 0x66, 0x58, 0xd9, 0xc5, 0x01, 0x1d, 0xef, 0x61, 0xfb, 0x3d, 0xbe, 0x4e, 0x63, 0x01, 0x58, 0xa4, 0x5e, 0xa4, 0x1a, 0x2e, 0xd5, 0x5e, 0xbd, 0x1e, 0xfb, 0x1a, 0xbe, 0xda, 0x76, 0x37, 0xde, 0x6f, 0xa5, 0xfd, 0x2f, 0x15, 0x1c, 0x6d, 0x2f, 0x38, 0x5b, 0xf6, 0xcd, 0x00, 0x2c, 0xa8, 0xb4, 0xa2, 0x89, 0x6e, 0x0d, 0x65, 0x94, 0x4e, 0xe9, 0x13, 0xe3, 0xc7, 0x84, 0x66, 0x9d, 0xd2, 0x01, 0xb1, 0x98, 0x5e, 0xf3, 0x57, 0x7f, 0x7f, 0x12, 0x3a, 0x5f, 0x9b, 0xcf, 0xfa, 0x17, 0x6c, 0x8f, 0x55, 0x7c, 0x4f, 0x72, 0x91, 0x33, 0xca, 0xc5, 0x18, 0x64, 0x2f, 0x27, 0xd9, 0xb2, 0x2c, 0xa9, 0xb9, 0x7f, 0xaa, 0xaf, 0xe5, 0xb6, 0x69, 0xa1, 0x0b, 0x79, 0xac, 0xe4, 0xa7, 0xd5, 0x72, 0x7d, 0xf1, 0x46, 0xc7, 0x7c, 0xe6, 0x81, 0x35, 0x7d, 0x69, 0xf9, 0xc2, 0xd6, 0x5b, 0x44, 0x01, 0xbd, 0x73, 0xcd, 0x11, 0x33, 0x87, 0xe3, 0xb3, 0xa0, 0x5d, 0x89, 0x7a, 0xda, 0xd7, 0xa2, 0x4c, 0x48, 0x5e, 0x7b),
-//            //   cipher_text: vec!(0x41, 0x46, 0xfa, 0xff, 0xd7, 0x31, 0x3f, 0x5d, 0x9f, 0x62, 0x53, 0x70, 0xd2, 0x04, 0x13, 0xcc, 0x62, 0xab, 0x65, 0xf4, 0xac, 0xfa, 0x3c, 0x7e, 0xe1, 0x12, 0x5b, 0x93, 0x7d, 0xd7, 0xa3, 0x9f, 0x63, 0x8f, 0xc4, 0x6c, 0x8e, 0xd0, 0x04, 0xfb, 0x52, 0x56, 0x98, 0xde, 0x5d, 0x86, 0x20, 0xec, 0x15, 0x34, 0x35, 0x57, 0x18, 0x17, 0xc3, 0xde, 0x25, 0x7b, 0x0d, 0x0e, 0x64, 0x8e, 0xbb, 0x92, 0x94, 0x0c, 0x86, 0xa9, 0x82, 0x62, 0xd5, 0x4e, 0x76, 0x4f, 0x28, 0xcb, 0xdd, 0x4f, 0x7d, 0x9b, 0xea, 0x97, 0x02, 0x91, 0xf2, 0x11, 0x04, 0x14, 0xf6, 0x20, 0x64, 0xd7, 0x22, 0x9c, 0x63, 0x32, 0x23, 0x6c, 0x50, 0x7b, 0x3d, 0xac, 0x74, 0x2e, 0x65, 0x1d, 0x85, 0xa2, 0xa2, 0x2f, 0xb2, 0x43, 0xc0, 0xcc, 0x7c, 0xc2, 0xd0, 0x16, 0xe5, 0xbe, 0xa3, 0x8f, 0x33, 0xf9, 0xa9, 0xce, 0x04, 0x89, 0x44, 0xa5, 0xfe, 0x8b, 0x07, 0x8d, 0x71, 0xd2, 0x31, 0x68, 0xe1, 0x2d, 0xfe, 0x5a, 0x0f, 0x0b, 0x82, 0x97, 0x71, 0xed, 0xc7, 0x07, 0x3f, 0xb9, 0x60, 0x32, 0xb7, 0xbe, 0x47, 0x13, 0x37, 0xa3, 0x7a, 0xca, 0x0c, 0xf7, 0xc0, 0xcd, 0xd5, 0x43, 0xee, 0xd6, 0x86, 0xcd, 0x34, 0x93, 0x47, 0x17, 0xfd, 0x79, 0xa3, 0xf1, 0x84, 0x92, 0xee, 0xf7, 0x2f, 0x9f, 0x45, 0x0b, 0x88, 0x0a, 0xa7, 0xe2, 0xe1, 0xb6, 0x5e, 0x3b, 0x04, 0xc2, 0x2e, 0x72, 0x30, 0x13, 0x38, 0xb4, 0x3a, 0xa3, 0x2c, 0xee, 0xc2, 0xe6),
-//            //   tag: vec!(0x10, 0xff, 0xaf, 0x2b, 0xe3, 0x16, 0x67, 0x6d, 0xa0, 0x2d, 0x74, 0x73, 0xa9, 0xdf, 0x87, 0xb9)
-//            // },
-//            // TestVector{
-//            //   key: [0x29, 0xb1, 0x96, 0x36, 0xcd, 0xd3, 0x25, 0x07, 0xfd, 0x98, 0xec, 0x4e, 0xe2, 0x6c, 0xaa, 0xb1, 0xa9, 0x17, 0x64, 0x6f, 0xb8, 0xf0, 0x5b, 0x0d, 0xc0, 0x17, 0x28, 0xa9, 0xf4, 0xa1, 0x27, 0xf0],
-//            //   nonce: [0x06, 0x69, 0x9d, 0x24, 0x59, 0x16, 0x68, 0x6d],
-//            //   plain_text: vec!(0x5f, 0xdf, 0x91, 0x3a, 0xce, 0xab, 0x1d, 0x6d, 0xba, 0xf7, 0xd9, 0xa2, 0x93, 0x52, 0xfa, 0x8a, 0x3e, 0xb2, 0x27, 0x18, 0x04, 0x3a, 0x79, 0xcf, 0xfa, 0x2f, 0xe8, 0xc3, 0x5c, 0x82, 0x0a, 0xec, 0x7c, 0x07, 0x64, 0x4b, 0x87, 0x85, 0xdc, 0xf7, 0xa4, 0x33, 0xb4, 0x18, 0x9a, 0xbb, 0x25, 0x7f, 0xb1, 0x2b, 0x06, 0xfa, 0xe0, 0x66, 0x26, 0x41, 0x01, 0x1a, 0x06, 0x98, 0x73, 0xc3, 0xe3, 0xc5, 0xcc, 0xc7, 0x8e, 0x73, 0x58, 0x18, 0x4a, 0x62, 0xc2, 0x00, 0x5c, 0x44, 0xb8, 0xa9, 0x22, 0x54, 0x95, 0x8e, 0xb5, 0xff, 0x46, 0x0d, 0x73, 0xcd, 0x80, 0x28, 0x4d, 0x6d, 0xab, 0xa2, 0x2c, 0x3f, 0xab, 0xa0, 0x46, 0xc5, 0x42, 0x6f, 0xe8, 0xb7, 0xca, 0xce, 0xc6, 0x4b, 0x23, 0x5a, 0x8f, 0x8d, 0x3e, 0x26, 0x41, 0xe5, 0xbc, 0x37, 0x88, 0x30, 0x59, 0x4b, 0xcf, 0xb2, 0x7c, 0x17, 0x7a, 0xea, 0x74, 0x59, 0x51, 0xee, 0x57, 0x80, 0xa6, 0x37, 0x05, 0x72, 0x7e, 0xf4, 0x2c, 0x4a, 0xd3, 0xab, 0xf5, 0x56, 0xd8, 0x8e, 0x38, 0x30, 0xf3, 0xdb, 0x6b, 0x09, 0xe9, 0x3e, 0xdd, 0x09, 0x48, 0x5c, 0xbf, 0x90, 0x7f, 0x79, 0xde, 0x61, 0xf8, 0xdc, 0x5c, 0xb5, 0xfb, 0x76, 0x65, 0xff, 0xa0, 0xef, 0x53, 0xcb, 0x48, 0x70, 0x2f, 0x6a, 0x81, 0xd8, 0xad, 0x42, 0x1c, 0xef, 0x20, 0xc1, 0xdb, 0xdf, 0x40, 0x2b, 0x8f, 0xaf, 0xed, 0x56, 0xa5, 0x36, 0x1b, 0x2f, 0x93, 0xf9, 0x14, 0xa2, 0x38, 0x0f, 0xdd, 0x05, 0x57, 0xfa, 0xf1, 0xf4, 0xde),
-//            //   aad: vec!(0x39, 0x11, 0x6c, 0x49, 0xcc, 0x13, 0xad, 0xb0, 0x65, 0xb9, 0x2c, 0xb7, 0x63, 0x5f, 0x73, 0xd5, 0xf6, 0xbf, 0x6b, 0x5c, 0xcb, 0xf7, 0x2a, 0x3f, 0x65, 0xa5, 0xdf, 0x6b, 0xd4, 0xa6, 0x61, 0x10, 0x50, 0x15, 0x35, 0x8d, 0x9e, 0x69, 0xf4, 0x2e, 0x98, 0xae, 0xd7, 0x95, 0xe8, 0x16, 0x12, 0x82, 0xbc, 0x11, 0x30, 0x58, 0xb7, 0xef, 0x3b, 0x9e, 0x23, 0xfc, 0xd8, 0xee, 0xab, 0x34, 0xa3, 0x92, 0xe0, 0x3f, 0x4d, 0x63, 0x29, 0xc1, 0x12, 0xcb, 0x96, 0x83, 0x85, 0xec, 0x52, 0xa7, 0xaf, 0xc9, 0x8b, 0xb8, 0x69, 0x57, 0x85, 0xaf, 0x6b, 0x27, 0xb7, 0x00, 0x97, 0x3c, 0xc9, 0x52, 0x63, 0x0b, 0x72, 0x47, 0xce, 0x22, 0x6b, 0x4f, 0xbb, 0x99, 0xb8, 0xa4, 0x86, 0x37, 0x0b, 0xf6, 0x34, 0x5d, 0x45, 0x16, 0xc5, 0x2c, 0x64, 0xe3, 0x3f, 0x40, 0x7c, 0x4f, 0x2d, 0x1b, 0xa9, 0x05, 0x45, 0xc8, 0x87, 0x32, 0xd9, 0x8b, 0xbd, 0x97, 0x97, 0x2a, 0xc5, 0xe9, 0x4c, 0x69, 0x46, 0x24, 0xa9, 0xb3, 0x78, 0x2b, 0x00, 0x99, 0x82, 0x46, 0x51, 0xcb, 0x75, 0x67, 0x91, 0x4d, 0x25, 0xb3, 0xe1, 0x31, 0x81, 0xa7, 0x91, 0xdb, 0xcd, 0x40, 0xe7, 0x6e, 0x83, 0x6b, 0x33, 0x50, 0xd3, 0x10, 0xa5, 0x21, 0x51, 0xbf, 0x83, 0x5d, 0x3c, 0x35, 0x7c, 0x98, 0x71, 0x48, 0x2c, 0x29, 0x28, 0xe8, 0x40, 0x4c, 0x6e, 0x53, 0x34, 0x06, 0xd4, 0xd6, 0xfa, 0x8f, 0x63, 0x36, 0x6f, 0x2c, 0x4e, 0xd8, 0x28, 0x14, 0x1f, 0x1f, 0xf0, 0x0f, 0x01, 0xa5, 0x36),
-//            //   cipher_text: vec!(0x01, 0xe2, 0x37, 0x22, 0x0b, 0x61, 0x90, 0x54, 0xa1, 0xf3, 0x67, 0x09, 0x28, 0xfe, 0x67, 0xd4, 0x04, 0x84, 0xb5, 0xaf, 0x40, 0xfb, 0xd0, 0x4d, 0x03, 0x25, 0x00, 0xaa, 0xc5, 0xac, 0xaa, 0x3b, 0x45, 0x84, 0xdd, 0x99, 0xa5, 0x8c, 0x39, 0x06, 0x27, 0x63, 0x6a, 0x50, 0xde, 0x5d, 0x74, 0x4f, 0x76, 0xa5, 0x6a, 0x33, 0x20, 0x5f, 0x9e, 0x3b, 0x00, 0xe1, 0x61, 0x62, 0xeb, 0x47, 0xff, 0x33, 0x33, 0xe1, 0xe2, 0x08, 0xca, 0x20, 0x0f, 0x1a, 0x53, 0x38, 0xa8, 0x6e, 0x17, 0xbd, 0x92, 0xdd, 0x2d, 0x16, 0xaf, 0x8b, 0xb0, 0x22, 0xa7, 0xdc, 0x05, 0xb9, 0x23, 0xd0, 0x19, 0xe0, 0x52, 0x47, 0xf1, 0xa0, 0xd0, 0xb4, 0xbf, 0xcf, 0xce, 0x58, 0xdd, 0x6d, 0x83, 0x83, 0x07, 0x05, 0x70, 0x76, 0x76, 0xd5, 0x57, 0x39, 0xab, 0xee, 0x89, 0xfc, 0xd5, 0xcb, 0x94, 0xb8, 0xfd, 0xe0, 0x06, 0xa5, 0xda, 0x02, 0xdf, 0x64, 0xb0, 0x0a, 0x46, 0x7f, 0x45, 0x97, 0x0b, 0x5c, 0xa4, 0x40, 0xf2, 0x23, 0x19, 0xb9, 0x73, 0x5a, 0x55, 0xd4, 0x54, 0xb9, 0xfb, 0xa0, 0x58, 0x8f, 0xef, 0x0c, 0x59, 0xd3, 0xd8, 0x38, 0x23, 0xeb, 0xa6, 0xe0, 0x60, 0x1a, 0x96, 0xe1, 0x02, 0x33, 0x82, 0x6c, 0x5a, 0xde, 0xea, 0x6b, 0x2a, 0x51, 0xd3, 0x86, 0xa0, 0x7a, 0x9e, 0x04, 0x7a, 0xd4, 0x05, 0xb2, 0x3d, 0x4c, 0x3d, 0x89, 0xf3, 0x0c, 0x31, 0xe3, 0x19, 0x9f, 0x0c, 0x8f, 0x92, 0x7b, 0xfa, 0xc4, 0x3c, 0xee, 0xa1, 0xf9, 0x69, 0xde, 0x0a, 0x8c, 0x0f),
-//            //   tag: vec!(0x09, 0x2f, 0x9f, 0x3c, 0x5d, 0x4f, 0x25, 0x70, 0xc9, 0x94, 0x6c, 0x87, 0x96, 0x7f, 0x45, 0x79)
-//            // },
-//            // TestVector{
-//            //   key: [0xba, 0xe0, 0x6b, 0x9b, 0x54, 0x56, 0x70, 0x75, 0x51, 0xc7, 0xb0, 0xe2, 0x07, 0xaa, 0xe0, 0x2a, 0x19, 0xb4, 0x84, 0x8a, 0xd8, 0xca, 0x4c, 0xe4, 0x07, 0x05, 0xbf, 0x8c, 0x85, 0x6a, 0x6e, 0x52],
-//            //   nonce: [0x9c, 0x27, 0x06, 0x5c, 0x3e, 0xf2, 0xd5, 0x22],
-//            //   plain_text: vec!(0x50, 0xcd, 0xd8, 0x81, 0x37, 0xff, 0x42, 0x8a, 0x88, 0xe8, 0x7b, 0x58, 0x45, 0xbe, 0x49, 0x24, 0xf6, 0x38, 0x75, 0x37, 0xbb, 0x5c, 0x0b, 0x65, 0x4c, 0x80, 0x10, 0x7a, 0xb5, 0x69, 0x8d, 0xb7, 0x5b, 0x2e, 0x13, 0x18, 0x48, 0xe7, 0xae, 0xc1, 0x56, 0xd3, 0x1a, 0xed, 0x07, 0x66, 0xd3, 0x1c, 0x37, 0x9f, 0xec, 0xe4, 0x09, 0x5d, 0x38, 0x26, 0x4c, 0x6d, 0x59, 0x45, 0x97, 0x4d, 0x25, 0xf7, 0x29, 0xc3, 0xb0, 0xba, 0x11, 0xea, 0x85, 0x3e, 0x9c, 0xeb, 0xdb, 0x6f, 0x03, 0xbb, 0x67, 0x0f, 0xce, 0x08, 0xad, 0xff, 0x74, 0xd0, 0xa8, 0xf0, 0x2d, 0x63, 0x3f, 0xb3, 0x4e, 0x0f, 0xb7, 0x33, 0x7a, 0x8e, 0x66, 0xe1, 0xc1, 0x20, 0x84, 0xd9, 0x14, 0xfb, 0x61, 0x73, 0xb8, 0x10, 0x56, 0x84, 0xdb, 0x82, 0x27, 0x52, 0xc6, 0x75, 0x1a, 0x37, 0x2b, 0xb1, 0x66, 0x90, 0x28, 0x4d, 0x66, 0x1b, 0x8b, 0x8b, 0xc6, 0xa6, 0xdf, 0xbd, 0xdf, 0x45, 0xeb, 0xc2, 0x21, 0x95, 0x96, 0xf9, 0xf2, 0xf8, 0x78, 0xc1, 0x18, 0xdf, 0x69, 0x03, 0x0d, 0xe3, 0x8b, 0x4d, 0x99, 0xdd, 0xe4, 0x3b, 0x9b, 0x9e, 0x20, 0xa3, 0xda, 0xb6, 0x91, 0x64, 0x5d, 0xd5, 0x18, 0x34, 0x2f, 0x49, 0xb0, 0x6a, 0x0f, 0xe0, 0xa3, 0x97, 0xad, 0xf2, 0x61, 0xe9, 0x9f, 0x07, 0xaf, 0x5b, 0x0b, 0x37, 0x98, 0xb1, 0x02, 0x2b, 0xa0, 0x93, 0x9c, 0x42, 0xa5, 0x4d, 0x3b, 0x93, 0x64, 0x1c, 0xff, 0xa3, 0xc2, 0xe1, 0x74, 0xbc, 0xe9, 0xab, 0x7a, 0xd7, 0xe7, 0xc7, 0x92, 0x43, 0x08, 0xd1, 0xa7, 0x7a),
-//            //   aad: vec!(0x5d, 0x55, 0x90, 0xdb, 0x1b, 0xd3, 0x16, 0xeb, 0x7a, 0x0e, 0x30, 0xe4, 0xc7, 0xa6, 0xdf, 0xdb, 0xef, 0x9d, 0x32, 0x87, 0xfd, 0xb8, 0xd8, 0x24, 0x38, 0x95, 0x99, 0xc3, 0xc2, 0xee, 0x26, 0x2b, 0x21, 0x92, 0xeb, 0x5b, 0x97, 0x08, 0xe6, 0x6e, 0x22, 0xdb, 0xc7, 0xec, 0xa8, 0x3f, 0xa1, 0xa9, 0x95, 0xda, 0x3c, 0xe6, 0x4c, 0x86, 0xfe, 0x5a, 0xa0, 0x8b, 0x82, 0x6d, 0x47, 0x6d, 0xc4, 0x39, 0x49, 0x7e, 0x2d, 0x12, 0xe2, 0x70, 0x2c, 0x63, 0xc8, 0xd2, 0x7a, 0xa7, 0xf0, 0x9f, 0xed, 0xee, 0x81, 0x6d, 0xc8, 0xbf, 0xfe, 0x13, 0x51, 0xd5, 0x32, 0x71, 0xa3, 0x4d, 0x42, 0x92, 0xb6, 0x13, 0xb7, 0xef, 0xce, 0xdb, 0x7e, 0x3c, 0xf3, 0xe6, 0xad, 0x38, 0x9e, 0xef, 0x12, 0x47, 0x1e, 0x9e, 0x20, 0xe3, 0x8e, 0x7a, 0xe2, 0x2a, 0x32, 0x3a, 0xbb, 0xad, 0xfe, 0x8f, 0x2e, 0x84, 0x27, 0x1b, 0xff, 0xb1, 0x81, 0x9f, 0xeb, 0x4f, 0x77, 0xb8, 0x28, 0x43, 0xcb, 0x87, 0x57, 0xcf, 0xae, 0x29, 0x36, 0x31, 0xbc, 0x6d, 0x39, 0x66, 0x91, 0x07, 0xe7, 0x01, 0x5c, 0x85, 0xd7, 0x34, 0x3f, 0xfa, 0x6f, 0xc1, 0xbb, 0xe6, 0xf5, 0xab, 0x4d, 0xe3, 0x0c, 0xd7, 0x52, 0xa2, 0x81, 0xe0, 0x30, 0x61, 0xea, 0x89, 0xde, 0x2a, 0x3f, 0x5e, 0x90, 0xe2, 0x0d, 0xa2, 0x2f, 0xd6, 0xe8, 0x52, 0x5c, 0x10, 0x07, 0x38, 0x66, 0x7f, 0x42, 0x21, 0x2b, 0x2c, 0xf4, 0x5f, 0xcb, 0x23, 0xbb, 0xb5, 0x4b, 0x21, 0xc1, 0x17, 0x48, 0x4b, 0x22, 0xc6, 0xe5, 0x14, 0x68, 0x53, 0x14, 0xdf),
-//            //   cipher_text: vec!(0x66, 0xb7, 0xf6, 0x9a, 0xc4, 0x9f, 0xab, 0x4e, 0x59, 0x75, 0xae, 0xb6, 0xfa, 0x92, 0x87, 0xd8, 0xea, 0xc0, 0x2a, 0xc3, 0x12, 0xc4, 0xde, 0x78, 0xf7, 0x7f, 0x59, 0xda, 0x16, 0xcb, 0xcf, 0x87, 0x27, 0x4e, 0x66, 0x80, 0x1c, 0x4b, 0x86, 0x2c, 0x33, 0xea, 0x79, 0xcd, 0xc7, 0x65, 0x28, 0x86, 0x2b, 0xb2, 0x95, 0x6c, 0x06, 0xdb, 0x8b, 0x8a, 0xcf, 0xac, 0x47, 0x94, 0xeb, 0xf3, 0x9e, 0x35, 0xac, 0x03, 0xcc, 0x73, 0xa4, 0x35, 0x1a, 0x4f, 0xf7, 0x62, 0xf6, 0x81, 0xa4, 0x8d, 0x6f, 0x25, 0xca, 0xd3, 0x6e, 0x28, 0x14, 0xc9, 0xb5, 0xc4, 0x0b, 0x9a, 0xe9, 0x25, 0x09, 0xe5, 0x84, 0x29, 0x10, 0x68, 0x47, 0x78, 0x94, 0x54, 0xd3, 0x76, 0x83, 0x69, 0x36, 0xbe, 0xbc, 0x7a, 0x80, 0xe6, 0xc6, 0x6e, 0x7a, 0xa5, 0x29, 0x36, 0xd6, 0xb3, 0x61, 0x37, 0x8a, 0x41, 0xf8, 0x49, 0xad, 0x4e, 0x48, 0xf9, 0xee, 0x2d, 0x3e, 0x92, 0x21, 0x7a, 0x90, 0x8f, 0xa8, 0xeb, 0x35, 0x73, 0x6a, 0xc8, 0xad, 0xa7, 0xd3, 0x2a, 0xe0, 0x53, 0x91, 0xf2, 0xd8, 0x07, 0xbe, 0x35, 0x12, 0x54, 0x3c, 0x36, 0x13, 0x8a, 0x5f, 0xe6, 0x60, 0xdd, 0x4c, 0xd4, 0xcd, 0x18, 0x4b, 0xb4, 0x3b, 0x6b, 0xa6, 0xbc, 0x0b, 0xae, 0x63, 0x4e, 0x2f, 0xa9, 0x66, 0x93, 0x04, 0xcd, 0x51, 0x0e, 0xd5, 0x10, 0x3f, 0x63, 0x00, 0x68, 0xff, 0x76, 0xd3, 0x37, 0x57, 0x38, 0xde, 0x60, 0xa3, 0x81, 0x84, 0x2b, 0x42, 0x14, 0x77, 0xe2, 0x5a, 0x49, 0x0c, 0xdd, 0x68, 0x94, 0xb2, 0x70, 0x41, 0x25),
-//            //   tag: vec!(0xc9, 0x99, 0x8a, 0x67, 0x7d, 0xfb, 0x0e, 0x91, 0x92, 0x4a, 0xec, 0x9d, 0xe0, 0xaf, 0xd5, 0x85)
-//            // },
-//            // TestVector{
-//            //   key: [0x2c, 0xb3, 0x74, 0xcb, 0x04, 0x8c, 0x16, 0x8f, 0x2e, 0x43, 0x59, 0x7f, 0x02, 0x8d, 0x9e, 0x73, 0xca, 0xde, 0x1b, 0x45, 0x82, 0x84, 0xff, 0xc2, 0x60, 0xd4, 0xfc, 0x6b, 0x90, 0x11, 0xc4, 0x14],
-//            //   nonce: [0x9f, 0xb9, 0x09, 0x16, 0x9b, 0xc9, 0xf4, 0xe9],
-//            //   plain_text: vec!(0x39, 0xeb, 0x92, 0x94, 0x82, 0x78, 0x4b, 0x46, 0x35, 0x46, 0xf5, 0xd8, 0x4f, 0x80, 0x51, 0x0f, 0x20, 0x19, 0x92, 0x3d, 0x46, 0x5b, 0x99, 0xd1, 0x94, 0x24, 0x6d, 0x68, 0xc7, 0xae, 0x34, 0x3f, 0x91, 0x97, 0x1d, 0x8f, 0x70, 0x59, 0xce, 0xbb, 0x86, 0xaa, 0x5d, 0xd0, 0x99, 0x28, 0x9a, 0xa6, 0x48, 0x24, 0x8b, 0x8c, 0x5c, 0xa0, 0x4e, 0x66, 0xac, 0x5e, 0x9b, 0xf0, 0x67, 0x76, 0xe3, 0x88, 0x34, 0x95, 0x39, 0x76, 0x18, 0xa0, 0x22, 0x7f, 0x03, 0x56, 0x66, 0x80, 0x6e, 0x63, 0x68, 0x36, 0xb4, 0x7d, 0x3d, 0x2d, 0x25, 0x5a, 0x49, 0xdb, 0x79, 0x86, 0x6c, 0xf0, 0x0d, 0x9d, 0xda, 0xbd, 0xa2, 0x59, 0xc4, 0xf9, 0x68, 0xa1, 0xe0, 0x1e, 0x65, 0x1c, 0x78, 0x11, 0xce, 0xbb, 0xee, 0x2e, 0xe7, 0x18, 0x03, 0xea, 0x1d, 0x9d, 0x23, 0x48, 0x7e, 0xb2, 0x21, 0xf2, 0xd9, 0x55, 0x57, 0x56, 0x80, 0x0a, 0xba, 0x5e, 0x6a, 0xbb, 0xef, 0xd6, 0xfb, 0x72, 0xb3, 0x15, 0x1c, 0xc9, 0x9c, 0xed, 0x59, 0x9c, 0xd8, 0x6d, 0xf2, 0xa9, 0xb1, 0xce, 0x94, 0xf8, 0x9f, 0x34, 0x7e, 0xeb, 0x12, 0x4d, 0x9e, 0x7f, 0x0d, 0x9c, 0xc4, 0x8d, 0x3d, 0xed, 0xd8, 0x19, 0xe6, 0xd3, 0xdb, 0xac, 0x57, 0xec, 0xee, 0x19, 0x95, 0x47, 0xb2, 0x66, 0x11, 0x6a, 0x20, 0x35, 0xc9, 0xac, 0xc4, 0xc8, 0xca, 0x32, 0x71, 0xac, 0x74, 0x95, 0x23, 0x72, 0x89, 0x7c, 0x4a, 0x5f, 0x2c, 0xb8, 0x4e, 0x2d, 0x81, 0x81, 0x7f, 0xec, 0x9d, 0x67, 0x74, 0xf6, 0xd8, 0xa5, 0xb2, 0x02, 0x16, 0x84, 0x13, 0x2d, 0xb4, 0xfc, 0xa3),
-//            //   aad: vec!(0x0c, 0x7b, 0xd4, 0xf3, 0xa3, 0x0e, 0xe9, 0x44, 0xcc, 0xf9, 0x48, 0x91, 0x81, 0xe6, 0x91, 0x16, 0x84, 0xdc, 0xff, 0xad, 0x45, 0x93, 0xa9, 0xb6, 0x5a, 0x67, 0xdf, 0xc8, 0x07, 0x18, 0xc6, 0x9b, 0x35, 0x89, 0x7d, 0x01, 0x28, 0x10, 0x16, 0xb7, 0x73, 0x1e, 0x12, 0xc1, 0x5c, 0xad, 0x84, 0x82, 0xe7, 0x94, 0x58, 0xe0, 0x8a, 0x75, 0x56, 0x22, 0xe3, 0xf3, 0xf2, 0x2a, 0x23, 0xef, 0x6c, 0x84, 0x87, 0xa3, 0x6a, 0xd1, 0x77, 0x1b, 0xa0, 0x6c, 0x64, 0x1f, 0x06, 0xf8, 0x5d, 0xe0, 0xdb, 0x37, 0x76, 0xcc, 0x6d, 0xf0, 0x6a, 0xd8, 0xfe, 0x3b, 0x4d, 0x60, 0xd5, 0x85, 0x08, 0xde, 0x94, 0x30, 0x83, 0xf1, 0x7c, 0xbb, 0x9d, 0xc0, 0xd3, 0x90, 0xac, 0x94, 0xd8, 0x42, 0x9e, 0x8c, 0x6f, 0xcf, 0xe0, 0x63, 0xf4, 0x24, 0xfb, 0xde, 0x0f, 0x62, 0xf6, 0xa7, 0xf9, 0x1a, 0x62, 0x6d, 0x19, 0x5d, 0xc4, 0x98, 0xa6, 0xe6, 0x9b, 0xd9, 0x31, 0x09, 0xc4, 0xe9, 0xba, 0x13, 0xe7, 0x33, 0x0a, 0xba, 0x45, 0x6d, 0x71, 0x0a, 0x4b, 0x0c, 0xc2, 0x79, 0xd4, 0x04, 0x56, 0x60, 0x40, 0x6e, 0x26, 0xd6, 0x1d, 0xff, 0x70, 0xd4, 0xa3, 0x3c, 0x4f, 0x10, 0x52, 0x86, 0x9f, 0x92, 0x48, 0x02, 0x4e, 0x7a, 0x0f, 0x85, 0xf1, 0xef, 0xfb, 0x32, 0xf6, 0xf7, 0xcc, 0xb1, 0xf8, 0x60, 0xf3, 0xef, 0x04, 0xe8, 0xf7, 0xb2, 0x90, 0x96, 0xe6, 0xbc, 0xf9, 0xd4, 0xb3, 0xe0, 0xce, 0x70, 0x3e, 0x9b, 0xf2, 0x28, 0xfd, 0xf5, 0x15, 0xc2, 0xff, 0x9c, 0xba, 0xbd, 0x16, 0x98, 0x7b, 0xe0, 0xf9, 0xba, 0xbd, 0x3d, 0x8a),
-//            //   cipher_text: vec!(0x91, 0xdd, 0xad, 0xb8, 0x6b, 0x7e, 0xbe, 0xf7, 0x98, 0xdd, 0xaa, 0x59, 0xda, 0x51, 0xd7, 0x13, 0x16, 0xfc, 0xf6, 0xc9, 0x67, 0x81, 0x43, 0x17, 0x82, 0x27, 0xd7, 0x78, 0x75, 0x0d, 0xc9, 0x82, 0x7f, 0xc6, 0xcc, 0x21, 0xe6, 0x05, 0xc5, 0x05, 0x02, 0x3e, 0x6d, 0xb2, 0x58, 0x49, 0xdf, 0x7f, 0xb6, 0xfc, 0x1c, 0xa4, 0xd2, 0x23, 0xaa, 0x21, 0x5f, 0x8c, 0x85, 0xb7, 0x24, 0x64, 0x3c, 0x83, 0xbf, 0x82, 0x18, 0x81, 0x5a, 0x9f, 0x9e, 0x29, 0x52, 0x38, 0x4e, 0x0c, 0xa6, 0xa8, 0x0a, 0x37, 0x60, 0xb3, 0x9d, 0xaf, 0x91, 0xa3, 0xc6, 0x15, 0x4c, 0x47, 0x28, 0xc2, 0x37, 0x1f, 0xd1, 0x81, 0xfa, 0x37, 0x64, 0x75, 0x3d, 0x0b, 0x0c, 0x23, 0x80, 0x8a, 0x82, 0xcd, 0x8f, 0x04, 0x97, 0x24, 0x6e, 0x3a, 0x0f, 0x17, 0xf8, 0x90, 0x6a, 0x07, 0xc7, 0x25, 0xd2, 0x89, 0x1c, 0xe9, 0x68, 0xa9, 0xd4, 0x32, 0xc2, 0xb1, 0x02, 0xd8, 0x5c, 0x05, 0x51, 0x0b, 0x28, 0xe7, 0x15, 0xbb, 0x60, 0xd0, 0x40, 0x3a, 0x77, 0x49, 0x0e, 0x7f, 0x18, 0xbe, 0x81, 0x21, 0x8b, 0xc4, 0xf3, 0x92, 0x87, 0xb9, 0xbb, 0x09, 0xf5, 0x02, 0x27, 0xdd, 0x2f, 0x55, 0xe4, 0xfb, 0x70, 0xc4, 0x43, 0x8d, 0xa8, 0xba, 0x3c, 0x8f, 0xfb, 0xce, 0xd8, 0x7d, 0x90, 0x15, 0x59, 0x13, 0xfa, 0xa9, 0x97, 0x9f, 0xc5, 0x7e, 0x6c, 0xbe, 0xdd, 0xfa, 0xba, 0x3d, 0x3a, 0xb4, 0x16, 0x3c, 0x0e, 0xeb, 0xc7, 0xd9, 0x42, 0x79, 0xc2, 0x7d, 0x3e, 0xd5, 0x63, 0x38, 0x89, 0x3d, 0xba, 0x54, 0x2e, 0xae, 0xfb, 0xa3, 0x0f, 0x8c, 0x3b),
-//            //   tag: vec!(0x72, 0x8e, 0x60, 0xf8, 0x12, 0x4e, 0xff, 0xba, 0xc2, 0x34, 0xf7, 0x0d, 0xa9, 0x25, 0x88, 0x1c)
-//            // },
-//            // TestVector{
-//            //   key: [0xf0, 0xf1, 0x6b, 0x6f, 0x12, 0xb3, 0x84, 0x0b, 0xbd, 0x1c, 0x4a, 0x6a, 0x08, 0x11, 0xee, 0xf2, 0x37, 0xf1, 0x52, 0x1b, 0x45, 0xde, 0x99, 0x86, 0xda, 0xec, 0x9f, 0x28, 0xfc, 0xa6, 0x48, 0x5c],
-//            //   nonce: [0x7a, 0xc9, 0x3e, 0x75, 0x4e, 0x29, 0x03, 0x23],
-//            //   plain_text: vec!(0x05, 0x30, 0x55, 0x64, 0x24, 0xd8, 0x23, 0xf9, 0x0a, 0x7f, 0x1c, 0x52, 0x4c, 0x4b, 0xaa, 0x70, 0x6a, 0xad, 0x28, 0x07, 0xe2, 0x89, 0xe9, 0x47, 0x93, 0x01, 0xe3, 0xe7, 0xa7, 0x1f, 0x2a, 0x5e, 0x14, 0xe6, 0x23, 0x2e, 0xa7, 0x85, 0xf3, 0x39, 0xc6, 0x69, 0xaf, 0x2e, 0x6d, 0x25, 0xf1, 0xd5, 0xa2, 0x61, 0x09, 0x6a, 0x54, 0x8d, 0x23, 0x86, 0x49, 0x45, 0xc3, 0xa5, 0x89, 0xb6, 0x7b, 0x09, 0xb0, 0x30, 0x4a, 0x78, 0x4d, 0x61, 0xb4, 0x2b, 0x24, 0x19, 0x13, 0x94, 0x85, 0x24, 0x2e, 0x0d, 0x51, 0xfc, 0xbe, 0x9e, 0x8f, 0xed, 0x99, 0x6d, 0x21, 0x4d, 0xe8, 0x71, 0x7e, 0x6a, 0x71, 0xf8, 0x98, 0x7c, 0xca, 0xd6, 0x5e, 0xb9, 0x2e, 0x66, 0x70, 0x70, 0x34, 0xa5, 0xae, 0x38, 0xe6, 0x48, 0x6e, 0x26, 0xeb, 0x43, 0x74, 0xc5, 0x65, 0xaa, 0xd5, 0xdf, 0x94, 0x9d, 0xab, 0x20, 0x9f, 0x7f, 0x7b, 0xcd, 0x8e, 0xb6, 0xfc, 0x52, 0x76, 0x1a, 0x26, 0xcf, 0xe5, 0xd0, 0x1f, 0xd3, 0x49, 0xe5, 0x9f, 0x40, 0x42, 0xe6, 0xdb, 0xe6, 0xb2, 0x32, 0xf9, 0x30, 0x1b, 0x97, 0x1d, 0xee, 0x12, 0x1d, 0x8a, 0xa1, 0xe6, 0x2d, 0x40, 0xf0, 0x43, 0xa4, 0x2f, 0x3a, 0xa8, 0x59, 0xd8, 0x67, 0xeb, 0x80, 0x9b, 0x1c, 0xed, 0x5a, 0xe1, 0xec, 0x62, 0xca, 0xcf, 0x94, 0xa6, 0x9f, 0xaf, 0xd0, 0x63, 0x1a, 0x8b, 0x5d, 0xfd, 0x66, 0xd8, 0x55, 0x90, 0x0f, 0xb2, 0x95, 0xee, 0xc9, 0x0a, 0xe5, 0xfc, 0xbf, 0x77, 0xbe, 0xae, 0x26, 0x7a, 0x79, 0xd2, 0x40, 0x81, 0xbb, 0x32, 0x2d, 0x8c, 0x4e, 0x06, 0x30, 0xfe, 0xd2, 0x52, 0x54, 0x1b, 0x36),
-//            //   aad: vec!(0x13, 0xbf, 0xcc, 0x17, 0xb8, 0x10, 0x09, 0x9c, 0xda, 0x31, 0xca, 0x53, 0xa1, 0x32, 0x3d, 0xb9, 0xb0, 0x76, 0x33, 0xce, 0xb2, 0x08, 0x8a, 0x42, 0x26, 0x3a, 0x4c, 0xbd, 0x6a, 0x4d, 0x47, 0x97, 0x87, 0x76, 0x00, 0x5c, 0x9a, 0x20, 0x20, 0x33, 0x19, 0xc3, 0xa3, 0xae, 0x43, 0x4e, 0x9a, 0x26, 0xfb, 0x54, 0x10, 0x47, 0xdc, 0x9d, 0xf3, 0x8d, 0xc3, 0x6c, 0x09, 0x52, 0x67, 0x27, 0x2e, 0x20, 0x3d, 0x0b, 0x24, 0xd1, 0x19, 0xa7, 0x0a, 0x7e, 0x96, 0x04, 0x1b, 0x6d, 0x82, 0xb7, 0xc4, 0xd5, 0x57, 0x0e, 0x1e, 0x4a, 0x1c, 0xf2, 0xf6, 0xe4, 0x4a, 0xe6, 0x3f, 0xe0, 0x05, 0xa1, 0xf5, 0xb9, 0x00, 0x77, 0x8c, 0x48, 0x2f, 0x7b, 0xd8, 0x9e, 0x2e, 0x02, 0x30, 0x5e, 0x35, 0xb8, 0xf6, 0x1b, 0x7b, 0xb2, 0xc7, 0x8a, 0x13, 0xae, 0xbf, 0xce, 0x01, 0x45, 0xd1, 0xc5, 0xaa, 0x0b, 0xf1, 0xd1, 0x0d, 0x23, 0x61, 0x6d, 0x5a, 0x3a, 0x44, 0x6d, 0xe5, 0x50, 0x30, 0x2f, 0x56, 0xf8, 0x1d, 0xc5, 0x6f, 0xe4, 0xf3, 0x70, 0x0f, 0x14, 0x24, 0x26, 0x88, 0xd9, 0xb9, 0x2d, 0x8a, 0x42, 0x79, 0x79, 0xb4, 0x03, 0xc8, 0xde, 0x8c, 0x49, 0x3a, 0x2c, 0xde, 0x51, 0x0e, 0xaf, 0x6b, 0x28, 0x5e, 0x66, 0x75, 0xb1, 0x73, 0xaa, 0x03, 0x14, 0xa3, 0x86, 0xb6, 0x35, 0xc7, 0x57, 0x7d, 0x5a, 0xff, 0x0d, 0x86, 0x8a, 0x0c, 0xb3, 0xf7, 0x3c, 0x8d, 0x20, 0x05, 0xf8, 0xc7, 0xc9, 0xda, 0xb5, 0xa0, 0x60, 0xef, 0x80, 0x10, 0x2c, 0x9d, 0x4a, 0x4a, 0xf9, 0x88, 0x83, 0x8a, 0xfe, 0x87, 0xaf, 0xf0, 0x4c, 0x06, 0x89, 0xe8, 0xc3, 0xc7, 0xf9),
-//            //   cipher_text: vec!(0x2c, 0x14, 0xc3, 0x93, 0x1e, 0x98, 0xe8, 0x45, 0x07, 0xc4, 0xc1, 0x65, 0xc2, 0xed, 0x47, 0xad, 0x4a, 0x17, 0x8f, 0x0e, 0x21, 0x6c, 0xd7, 0xac, 0x24, 0x53, 0xbb, 0xbf, 0x9f, 0x85, 0xdd, 0x06, 0xbd, 0x8e, 0xf5, 0x4a, 0x9f, 0xf1, 0xfd, 0x3d, 0xd8, 0xe0, 0xca, 0xfb, 0x63, 0x5d, 0x8f, 0x2d, 0xe8, 0x61, 0xa0, 0xdb, 0x5b, 0x14, 0xd0, 0x3f, 0x17, 0xaa, 0xea, 0x8c, 0x89, 0xb3, 0x01, 0x07, 0x97, 0xc7, 0x1c, 0x13, 0xa0, 0xe6, 0x66, 0x89, 0x9d, 0x7f, 0xf6, 0xe5, 0x3c, 0x4f, 0x08, 0xbe, 0x8d, 0xdb, 0x3e, 0x37, 0x68, 0x8b, 0x5a, 0xfa, 0x08, 0x80, 0x79, 0xb6, 0xc7, 0x51, 0x9b, 0x83, 0x3e, 0x16, 0x56, 0x00, 0x73, 0xe6, 0x99, 0x53, 0x03, 0x02, 0x02, 0x8a, 0x34, 0x96, 0xe0, 0x5e, 0xdd, 0xde, 0xc0, 0x1a, 0x23, 0xa4, 0xc7, 0x98, 0x39, 0x56, 0x25, 0x0e, 0x8d, 0x9e, 0x61, 0x6f, 0x7b, 0x94, 0x08, 0x56, 0x95, 0x5c, 0xde, 0x81, 0xc1, 0xef, 0xab, 0xf6, 0xb7, 0xb9, 0x2f, 0x15, 0x3d, 0x03, 0xf4, 0xcd, 0x17, 0xe7, 0xf7, 0xd2, 0x90, 0x76, 0x70, 0xcf, 0xc8, 0x4d, 0x45, 0xc1, 0xd7, 0x93, 0x67, 0x75, 0xa3, 0xfc, 0xe4, 0x79, 0x68, 0x50, 0x42, 0x78, 0xff, 0xae, 0xca, 0xce, 0xa0, 0x87, 0x1b, 0x22, 0x7f, 0x25, 0x0e, 0x29, 0x79, 0x51, 0x6f, 0x6f, 0xa3, 0x10, 0xfe, 0xc0, 0xd8, 0xdf, 0x1a, 0xf7, 0x87, 0x2e, 0x5a, 0x53, 0x4e, 0x82, 0x87, 0x0a, 0xa0, 0x5f, 0x43, 0xef, 0x0a, 0x45, 0x58, 0x46, 0xb9, 0x3c, 0xe9, 0x38, 0x06, 0x4f, 0xa3, 0x3e, 0x92, 0xde, 0x26, 0x2e, 0x41, 0x56, 0xda, 0xe5, 0x67, 0x75),
-//            //   tag: vec!(0xd9, 0x5d, 0x73, 0xbf, 0x9a, 0xeb, 0x71, 0xeb, 0xa9, 0x04, 0x23, 0x96, 0xf3, 0x72, 0x54, 0x24)
-//            // },
-//            // TestVector{
-//            //   key: [0x37, 0x92, 0x94, 0x3c, 0x03, 0x96, 0xf1, 0x84, 0x04, 0x96, 0x91, 0x7c, 0xe8, 0xad, 0x89, 0x60, 0x83, 0x85, 0x00, 0x7e, 0x79, 0x6f, 0xeb, 0xee, 0xa3, 0x80, 0x5f, 0x3f, 0x4c, 0xbe, 0xcc, 0xf7],
-//            //   nonce: [0x23, 0xb2, 0xf9, 0x06, 0x8b, 0x2c, 0x4c, 0x85],
-//            //   plain_text: vec!(0xbe, 0x6b, 0x67, 0xeb, 0x94, 0x3e, 0xe7, 0xb5, 0xc7, 0x85, 0xcd, 0x88, 0x2f, 0x65, 0x3e, 0x73, 0xa8, 0xf7, 0x5b, 0x4a, 0x41, 0xa2, 0xa7, 0xc5, 0x6a, 0xe5, 0xa1, 0x0f, 0x72, 0x9c, 0xaf, 0x39, 0x94, 0x8f, 0xe4, 0x8a, 0xd0, 0xe5, 0x12, 0x40, 0xe2, 0xe7, 0xaa, 0x43, 0x19, 0x3c, 0x7e, 0xc6, 0xce, 0x7f, 0x49, 0x09, 0xfc, 0x94, 0xc9, 0xf9, 0x9e, 0x38, 0xe6, 0xa0, 0xad, 0x7e, 0x98, 0xeb, 0x29, 0xc5, 0xc2, 0xe6, 0x1c, 0x99, 0xe9, 0xcb, 0xe8, 0x90, 0xf1, 0x54, 0x18, 0x5c, 0xec, 0x21, 0x3a, 0x74, 0x72, 0x5d, 0x23, 0xc1, 0xa4, 0xe4, 0xd0, 0xcb, 0x9b, 0x1a, 0x36, 0xb7, 0x8c, 0x87, 0xe5, 0xee, 0xe2, 0x0d, 0x2a, 0xa2, 0x9a, 0xae, 0x80, 0xd4, 0x75, 0x9e, 0xb0, 0xc5, 0x1c, 0x5d, 0xc3, 0xa9, 0x5b, 0xdb, 0xbf, 0x7e, 0x14, 0xeb, 0x43, 0x44, 0x19, 0xa6, 0xc8, 0x8a, 0x95, 0x4a, 0xc0, 0x3d, 0x0c, 0x98, 0x73, 0x9f, 0x42, 0x11, 0xb8, 0x73, 0x2a, 0xcd, 0x71, 0xc2, 0x97, 0xf5, 0x78, 0xb8, 0xcb, 0x64, 0xcc, 0xac, 0x45, 0xf7, 0x23, 0x5d, 0xdc, 0x7f, 0x2a, 0x3f, 0x5f, 0x99, 0x75, 0x25, 0xc1, 0xed, 0x39, 0xdc, 0x55, 0x01, 0x26, 0xcd, 0xf9, 0xce, 0xda, 0xf5, 0x54, 0x25, 0x48, 0x90, 0x85, 0xe9, 0x1b, 0x17, 0x0b, 0xe6, 0x20, 0x5a, 0x5a, 0x39, 0x5f, 0x2d, 0xd4, 0x08, 0x4a, 0x3e, 0x8d, 0xbc, 0x4f, 0xd8, 0xb1, 0x32, 0x52, 0xf7, 0xef, 0xfa, 0xe0, 0x67, 0xb5, 0x71, 0xcb, 0x94, 0xa1, 0xe5, 0x4a, 0xba, 0x45, 0xb1, 0xb9, 0x84, 0x13, 0x08, 0xdb, 0x0c, 0xc7, 0x5b, 0x03, 0xcf, 0xce, 0x4d, 0xda, 0xfe, 0x89, 0xce, 0x20, 0xf2, 0xd1),
-//            //   aad: vec!(0x7e, 0xb6, 0xd7, 0xb7, 0xbb, 0xaa, 0xa3, 0xc2, 0x02, 0xa4, 0xf0, 0xf1, 0xde, 0x22, 0x63, 0x76, 0x71, 0x69, 0xeb, 0x4a, 0x64, 0x85, 0x32, 0x40, 0xd4, 0x8c, 0x0f, 0x8d, 0x5d, 0x31, 0xb0, 0x8d, 0x5b, 0xaf, 0x42, 0x97, 0x76, 0x14, 0xa5, 0x7a, 0xad, 0x99, 0x42, 0x6c, 0xde, 0x76, 0xd2, 0x42, 0xcb, 0x37, 0xd2, 0x95, 0x6d, 0x8c, 0x77, 0xdc, 0x4f, 0xd6, 0x2a, 0x3a, 0xbf, 0x30, 0xe8, 0xac, 0x6c, 0xd5, 0x8c, 0x8e, 0xf3, 0x5e, 0x67, 0x49, 0x70, 0x22, 0x96, 0x01, 0x38, 0xc5, 0x77, 0x87, 0x81, 0x88, 0x92, 0x46, 0x0f, 0x3b, 0xfc, 0x16, 0xe3, 0x7f, 0xf3, 0x88, 0xb1, 0xed, 0xc6, 0xce, 0x2b, 0xc5, 0x3c, 0x22, 0x71, 0x7e, 0xdc, 0x7a, 0x03, 0xd4, 0xc7, 0x8b, 0x0d, 0xbb, 0xe9, 0x12, 0x1c, 0x7f, 0xd8, 0xa3, 0xe3, 0x99, 0x3b, 0x87, 0xa4, 0xfe, 0x38, 0x9b, 0xff, 0x13, 0xbd, 0xae, 0x3b, 0x34, 0x9d, 0xe0, 0xb6, 0xdb, 0x56, 0x16, 0x02, 0xc5, 0x3f, 0x74, 0x60, 0x22, 0xae, 0xb4, 0x48, 0x3c, 0x72, 0x3b, 0x67, 0x82, 0x50, 0x42, 0xf4, 0xaf, 0x20, 0xb7, 0xdd, 0x1e, 0x60, 0x31, 0xcf, 0x54, 0x21, 0x52, 0x66, 0x29, 0x5c, 0x52, 0x4a, 0xc8, 0xe1, 0x37, 0x04, 0x24, 0xc5, 0xc5, 0xe6, 0x07, 0xfb, 0x3e, 0x23, 0xe9, 0x7c, 0x8e, 0xeb, 0xe6, 0x46, 0x56, 0x77, 0x5e, 0xdf, 0x61, 0x64, 0x22, 0xa8, 0xb9, 0x74, 0xe1, 0xac, 0xf1, 0x3a, 0xb4, 0x5c, 0x9a, 0x36, 0x7a, 0x7d, 0xd9, 0xb2, 0xd6, 0x2f, 0x48, 0xbb, 0xc0, 0x58, 0x19, 0xb6, 0x5e, 0xcc, 0xb8, 0x13, 0xca, 0x81, 0x3f, 0x57, 0xb2, 0x2e, 0xe4, 0xc2, 0x80, 0xdb, 0xb5, 0xa9, 0xd8, 0xd5),
-//            //   cipher_text: vec!(0x0b, 0x31, 0x6a, 0xb2, 0xbc, 0xf5, 0x35, 0x99, 0x00, 0xfa, 0x40, 0x82, 0xd5, 0xd2, 0x53, 0xb4, 0x9a, 0xd9, 0x4b, 0x70, 0xe3, 0xfa, 0xb5, 0x44, 0xf9, 0x8b, 0xd1, 0x11, 0xcb, 0xce, 0xf6, 0x76, 0x6c, 0xf9, 0x53, 0xde, 0xec, 0x08, 0xca, 0xe1, 0xf4, 0x89, 0xfe, 0x12, 0xf7, 0xac, 0xc0, 0x03, 0x2d, 0xb8, 0xa6, 0xb0, 0xc0, 0xee, 0xe0, 0xc2, 0x06, 0xea, 0x5f, 0xb9, 0x73, 0xfe, 0xae, 0xbf, 0x90, 0xf6, 0x90, 0xe8, 0x40, 0x09, 0x4d, 0xb5, 0xe1, 0x3f, 0xdd, 0x71, 0x57, 0xba, 0x12, 0x73, 0x68, 0xc9, 0x95, 0xb4, 0x26, 0x52, 0x94, 0x35, 0xa1, 0xbc, 0xdd, 0x1f, 0x14, 0xce, 0x91, 0x25, 0xb8, 0xa0, 0xe4, 0xc9, 0x6b, 0x6e, 0xc0, 0x9e, 0x3c, 0x36, 0xa1, 0x80, 0xad, 0xf8, 0x19, 0x41, 0xc0, 0x02, 0xd1, 0x9c, 0x19, 0xd5, 0x3c, 0x20, 0x09, 0xbe, 0x80, 0x3b, 0x98, 0x75, 0x04, 0x60, 0x6b, 0x7d, 0x43, 0xbd, 0xee, 0x5e, 0x0b, 0x32, 0xff, 0x23, 0xc4, 0x66, 0xb6, 0xcc, 0xcf, 0xcd, 0x0d, 0x4e, 0x88, 0xfd, 0x13, 0x32, 0xe7, 0x37, 0x12, 0xb5, 0xab, 0x72, 0x5c, 0x1a, 0x38, 0x3e, 0x58, 0x4f, 0x34, 0xf8, 0x0d, 0xaf, 0xf2, 0x9d, 0x28, 0x5a, 0xe5, 0xe4, 0x3c, 0xf1, 0xd0, 0xcc, 0x7a, 0x82, 0x8e, 0x75, 0xc2, 0x5d, 0xac, 0xed, 0x3a, 0x58, 0x1a, 0x93, 0xd7, 0xa5, 0x0f, 0x31, 0x3b, 0x33, 0xf3, 0x8d, 0xdd, 0xfa, 0xa2, 0x3c, 0xd5, 0xb9, 0x91, 0x47, 0x97, 0xdb, 0x82, 0x0e, 0xe2, 0x40, 0x0d, 0x52, 0xbf, 0x5f, 0xa9, 0x82, 0x27, 0x7f, 0xe9, 0xb5, 0x88, 0x1a, 0xc4, 0x29, 0x81, 0x63, 0x3b, 0x39, 0x57, 0xb0, 0xe9, 0x35, 0x05, 0x18, 0x28),
-//            //   tag: vec!(0x01, 0x97, 0x3e, 0xe2, 0xe8, 0x1c, 0xef, 0x22, 0x75, 0x1a, 0x6a, 0x88, 0x31, 0xd7, 0x52, 0xef)
-//            // },
-//            // TestVector{
-//            //   key: [0xfe, 0x4b, 0xe6, 0x05, 0x47, 0x73, 0xf6, 0x34, 0x35, 0x6a, 0xc3, 0x28, 0x59, 0x1f, 0xbc, 0x6f, 0x83, 0x3b, 0x0d, 0x1b, 0xee, 0xb3, 0x8d, 0xd5, 0xb6, 0xfe, 0xb7, 0x48, 0x1b, 0x44, 0x89, 0xd4],
-//            //   nonce: [0x0b, 0x3f, 0x16, 0xf8, 0x98, 0xa5, 0xa7, 0xd5],
-//            //   plain_text: vec!(0x76, 0xce, 0xd1, 0xad, 0xe6, 0xd1, 0xef, 0x40, 0x69, 0xaf, 0xdd, 0xb3, 0x2e, 0x74, 0x32, 0xd4, 0xff, 0x2f, 0xd0, 0x66, 0x85, 0x12, 0x1f, 0x7b, 0x16, 0x46, 0x4e, 0x7a, 0x72, 0xd3, 0x65, 0x74, 0x4f, 0x54, 0x7d, 0x2c, 0xcf, 0x53, 0x48, 0x63, 0x10, 0xe3, 0x8b, 0x42, 0xd8, 0xba, 0xca, 0xf7, 0x11, 0xe5, 0x4c, 0x54, 0x58, 0xd2, 0xd6, 0x8c, 0x4d, 0xbc, 0xc8, 0xde, 0x31, 0xab, 0x67, 0x32, 0xf4, 0x43, 0x0e, 0x88, 0xa6, 0x45, 0x65, 0xf5, 0xb2, 0x87, 0x64, 0x07, 0x75, 0xaa, 0xa2, 0xaf, 0x1c, 0xc4, 0x61, 0xd3, 0xe4, 0x15, 0xbb, 0x27, 0x5c, 0x62, 0x46, 0xb1, 0xb5, 0x85, 0x17, 0xaa, 0x72, 0x66, 0x7e, 0xae, 0x29, 0x1a, 0x29, 0x82, 0xed, 0xa1, 0x75, 0xd1, 0xb2, 0x2c, 0x5a, 0x58, 0xe6, 0xfe, 0xc2, 0xb3, 0x74, 0x3d, 0x55, 0x71, 0x2f, 0x20, 0x1c, 0xa2, 0x4b, 0xa5, 0xc0, 0xae, 0x8c, 0x25, 0x72, 0x48, 0x71, 0xb2, 0xec, 0x2f, 0xb9, 0x14, 0xa8, 0xda, 0x5a, 0x52, 0x67, 0x0a, 0xb9, 0xb4, 0x3a, 0x83, 0xb8, 0x56, 0x8c, 0xe7, 0x4d, 0xb5, 0xc6, 0x34, 0x06, 0x1c, 0xb8, 0x05, 0x30, 0xc8, 0x07, 0x0c, 0x38, 0xb8, 0xf4, 0x8c, 0x33, 0xba, 0x13, 0x6c, 0xb9, 0xf2, 0x15, 0x8e, 0xe7, 0xed, 0xa8, 0xb6, 0x5f, 0x21, 0x92, 0xfc, 0x94, 0xd1, 0x29, 0x1f, 0x18, 0x2f, 0x10, 0x17, 0x95, 0xb7, 0x19, 0x0c, 0x74, 0xb3, 0x19, 0xd2, 0xd3, 0xe0, 0x2a, 0x97, 0xc8, 0x24, 0xd9, 0xc9, 0x47, 0x1a, 0x83, 0x79, 0x7e, 0x49, 0x36, 0x31, 0x0b, 0x20, 0x7e, 0x3a, 0x1e, 0x0b, 0xcf, 0x75, 0xf7, 0xc3, 0xe3, 0xee, 0x48, 0xa7, 0x47, 0x64, 0x1c, 0xdc, 0x43, 0x77, 0xf2, 0xd5, 0x50, 0x82),
-//            //   aad: vec!(0x83, 0x4c, 0xd7, 0x75, 0xcb, 0xef, 0xe4, 0xb3, 0x3a, 0x3c, 0xa5, 0x3a, 0x00, 0xc0, 0x6a, 0x3c, 0x4a, 0x66, 0x69, 0x83, 0xe4, 0x11, 0x5a, 0x02, 0x9f, 0x15, 0x72, 0x94, 0x60, 0xda, 0xa4, 0x5d, 0x15, 0x05, 0xe9, 0x51, 0x72, 0xd3, 0x69, 0x56, 0x25, 0xa1, 0x86, 0xb2, 0x8b, 0x8b, 0xe1, 0x73, 0xa9, 0x25, 0xaf, 0x04, 0x66, 0x5f, 0x20, 0x92, 0x67, 0xb3, 0xc5, 0x12, 0x3e, 0x8b, 0xe1, 0x3d, 0xa4, 0x47, 0xee, 0x1a, 0xe8, 0x56, 0xbb, 0x09, 0x25, 0xf3, 0x5a, 0xaa, 0x76, 0xe0, 0x4a, 0x7b, 0xca, 0x84, 0x60, 0xf7, 0x6c, 0x20, 0x24, 0xde, 0x21, 0x49, 0xf3, 0x8a, 0x8c, 0xfb, 0xa8, 0x16, 0x94, 0xb8, 0x54, 0x88, 0x5d, 0x72, 0x56, 0x81, 0x05, 0x57, 0x1b, 0x6b, 0x21, 0x3a, 0x0b, 0xc1, 0x88, 0xa4, 0x4c, 0xc7, 0xfe, 0x13, 0x15, 0x3c, 0xbf, 0x26, 0x14, 0x01, 0xb2, 0x38, 0xcf, 0x12, 0xa9, 0x5e, 0x23, 0xcb, 0x56, 0xf2, 0x40, 0x11, 0x4f, 0x16, 0xe2, 0xf1, 0xe3, 0xa5, 0x14, 0x61, 0x5a, 0xab, 0x44, 0x49, 0xc0, 0xc4, 0x9e, 0x4d, 0x90, 0x0b, 0x0e, 0x17, 0xd1, 0xa8, 0xda, 0xbb, 0x53, 0xd4, 0x3d, 0xca, 0x32, 0xfa, 0x05, 0x2d, 0x57, 0x6b, 0x73, 0xdd, 0x9b, 0x40, 0x85, 0x6b, 0x51, 0x5d, 0x6d, 0x7e, 0xfc, 0x2a, 0x5c, 0x17, 0xe0, 0xeb, 0xcb, 0x17, 0xbd, 0x59, 0xdc, 0x86, 0xf2, 0x2c, 0xe9, 0x09, 0x30, 0x1a, 0x26, 0x52, 0xf1, 0x34, 0xe8, 0x2e, 0xf0, 0xe4, 0x51, 0x94, 0x87, 0xed, 0x12, 0xd5, 0x15, 0x36, 0x02, 0x4f, 0x2a, 0xe8, 0xf7, 0x5d, 0x93, 0x7c, 0x42, 0xd0, 0x03, 0x07, 0x6e, 0x5d, 0xea, 0x8d, 0xe0, 0xc6, 0x84, 0xcd, 0xa1, 0xf3, 0x42, 0x53, 0xd8, 0xfc),
-//            //   cipher_text: vec!(0xf8, 0xde, 0xfb, 0x6f, 0xe9, 0x5d, 0xfe, 0xc4, 0x99, 0xb9, 0x09, 0x99, 0x6a, 0x1f, 0x75, 0xa1, 0x98, 0xa9, 0x0e, 0x4d, 0x6c, 0x64, 0x64, 0xd0, 0x0a, 0x35, 0x7a, 0x55, 0x53, 0x11, 0xc4, 0x2f, 0xe9, 0x2d, 0xbb, 0xc4, 0xb7, 0x9c, 0x93, 0x5e, 0x4f, 0x0b, 0x1a, 0x95, 0xe4, 0x4f, 0xdb, 0xc1, 0x38, 0x0b, 0xeb, 0xab, 0xca, 0x28, 0xdb, 0x4d, 0xd0, 0xd2, 0x87, 0x0d, 0xaa, 0xaf, 0xc3, 0x8e, 0xf2, 0x79, 0x08, 0xc3, 0x50, 0x9e, 0x94, 0x57, 0x14, 0x80, 0x1c, 0xc5, 0x1f, 0x1a, 0x07, 0xb2, 0x43, 0x0c, 0x74, 0xfa, 0x64, 0xf2, 0xa7, 0xc2, 0xf7, 0xfd, 0x15, 0x51, 0xd2, 0x58, 0xc9, 0xc3, 0xbe, 0x02, 0x08, 0x73, 0xfc, 0x1b, 0xf1, 0x9f, 0x33, 0xab, 0x6c, 0x66, 0x09, 0x11, 0xdc, 0xf2, 0x31, 0x71, 0x95, 0xd0, 0xef, 0xee, 0x82, 0xd2, 0x0e, 0xc2, 0x6d, 0x22, 0x61, 0x1f, 0x9c, 0xf8, 0x6c, 0x51, 0xa6, 0x4e, 0x28, 0xb3, 0xa1, 0xf3, 0x44, 0x50, 0x00, 0x18, 0xe0, 0x85, 0x5c, 0x88, 0xda, 0xe3, 0xc0, 0x7a, 0xca, 0xea, 0xa1, 0x0b, 0x60, 0x38, 0x84, 0x84, 0xdc, 0xe9, 0x3e, 0x16, 0xe6, 0xe1, 0xa6, 0xe6, 0x9e, 0x89, 0x98, 0x06, 0x64, 0x8a, 0x92, 0x56, 0x8c, 0x87, 0x80, 0xe9, 0xf4, 0xba, 0xac, 0xd9, 0x8c, 0xbb, 0x35, 0x3a, 0xc2, 0xf9, 0x08, 0xe7, 0x75, 0xd9, 0x23, 0x03, 0xcf, 0xab, 0x84, 0x3f, 0x15, 0xbe, 0x0e, 0x0c, 0x32, 0x2a, 0x95, 0x88, 0x02, 0xfb, 0x1a, 0x60, 0xfc, 0xc7, 0x63, 0x1f, 0x15, 0x1f, 0x4c, 0x2b, 0x8c, 0xb9, 0x65, 0xd2, 0xd2, 0x96, 0xac, 0xef, 0x25, 0x02, 0x75, 0xa2, 0xfe, 0xcc, 0x0c, 0xea, 0x80, 0x3c, 0xe7, 0xc0, 0x58, 0xb1, 0x2d, 0xd2),
-//            //   tag: vec!(0xad, 0xe5, 0x15, 0x09, 0x19, 0x30, 0xdd, 0x78, 0x61, 0xb2, 0x7f, 0x78, 0xa8, 0x7e, 0xf6, 0x0c)
-//            // },
-//            // TestVector{
-//            //   key: [0xa2, 0x88, 0xb1, 0x1c, 0xe5, 0x38, 0x2e, 0xc7, 0x24, 0xce, 0x4a, 0xb2, 0xd7, 0xef, 0xa8, 0xe7, 0x77, 0xe9, 0x1e, 0xbd, 0x04, 0x36, 0x79, 0x35, 0xe1, 0x5f, 0x9d, 0xac, 0x48, 0x3e, 0x95, 0x96],
-//            //   nonce: [0x87, 0x41, 0x44, 0xdb, 0xf6, 0x48, 0xb3, 0x25],
-//            //   plain_text: vec!(0x4c, 0x91, 0x95, 0x28, 0x0a, 0x79, 0xa5, 0x09, 0x91, 0x9a, 0xf4, 0x94, 0x7e, 0x9e, 0x07, 0x23, 0x16, 0x95, 0xfd, 0x7c, 0x50, 0x88, 0x53, 0x9f, 0x23, 0x93, 0x6c, 0xe8, 0x87, 0x70, 0xce, 0x07, 0xd9, 0xad, 0x3a, 0xe4, 0xa4, 0x63, 0xb3, 0xa5, 0x7d, 0x06, 0x34, 0xd3, 0xa7, 0x7c, 0xea, 0xad, 0xf3, 0x47, 0xa3, 0x34, 0x68, 0x2b, 0x04, 0xbe, 0x8e, 0x58, 0xb8, 0xe8, 0x6f, 0xb9, 0x4a, 0x1f, 0x93, 0x25, 0x51, 0x32, 0xb8, 0xcd, 0xb0, 0xdf, 0x86, 0xf5, 0xbe, 0xa3, 0x54, 0xee, 0xa4, 0xe8, 0x31, 0x5f, 0xea, 0x83, 0xe3, 0xfd, 0xf6, 0xe5, 0x8a, 0xa9, 0xf2, 0x6e, 0x93, 0xca, 0xa0, 0x8e, 0x5e, 0x25, 0x51, 0xa9, 0x4b, 0xd9, 0x16, 0xa5, 0x1f, 0xed, 0x29, 0xec, 0x16, 0xf6, 0x68, 0x00, 0xcd, 0xa6, 0xa0, 0xaa, 0x24, 0xec, 0x30, 0x8b, 0xf5, 0xfb, 0x88, 0x5a, 0xfb, 0xa2, 0x72, 0x68, 0x5d, 0xe2, 0x7c, 0x1e, 0xdc, 0xdd, 0x36, 0x68, 0x04, 0x8e, 0xf0, 0x7b, 0x06, 0xe9, 0x0d, 0x46, 0x4a, 0x8a, 0xa2, 0x86, 0x64, 0x90, 0x3c, 0xac, 0x45, 0xe1, 0x54, 0xe8, 0xe1, 0xe3, 0x9c, 0x25, 0x7e, 0x1f, 0xf5, 0x06, 0xb9, 0xd9, 0x5c, 0xef, 0x4f, 0x30, 0x0b, 0xb7, 0x3b, 0x89, 0x9e, 0x78, 0x28, 0x60, 0x2c, 0x3c, 0x1d, 0x29, 0x0b, 0x8c, 0xf5, 0x5e, 0xe5, 0xfd, 0x72, 0xec, 0xce, 0x9e, 0x6e, 0xfc, 0x92, 0x93, 0xae, 0xbf, 0x67, 0x4a, 0x70, 0xe2, 0xa7, 0x67, 0x3e, 0x75, 0x62, 0x9c, 0x12, 0x95, 0x06, 0x22, 0xdf, 0xf7, 0x1d, 0x3e, 0xc0, 0x99, 0x2e, 0x57, 0x77, 0x6c, 0x78, 0x8c, 0x69, 0x27, 0xd3, 0x0b, 0x4e, 0x24, 0xb7, 0x49, 0x19, 0x1c, 0x3c, 0xe8, 0x01, 0x7f, 0x0a, 0xda, 0x62, 0x76, 0xe4, 0x37, 0x20),
-//            //   aad: vec!(0x04, 0xab, 0xe8, 0x58, 0x8c, 0x8c, 0x8c, 0x39, 0xa1, 0x82, 0x09, 0x2e, 0x5e, 0x78, 0x40, 0x44, 0x2b, 0xd1, 0xc1, 0x14, 0x9d, 0xa1, 0x02, 0xc4, 0xee, 0x41, 0x2b, 0xd8, 0xb8, 0x2b, 0xaa, 0x50, 0x87, 0xef, 0x72, 0x91, 0xb5, 0xcd, 0x07, 0x7c, 0x17, 0x7c, 0x42, 0x77, 0x0b, 0x00, 0x23, 0xe0, 0xe4, 0x62, 0xb0, 0x6e, 0x75, 0x53, 0xf1, 0x91, 0xbc, 0xb0, 0x31, 0x5a, 0x34, 0x91, 0x8d, 0xcd, 0xbf, 0xfe, 0x2b, 0x99, 0xc3, 0xe0, 0x11, 0xb4, 0x22, 0x0c, 0xc1, 0x77, 0x5d, 0xeb, 0xcc, 0x0d, 0xb5, 0x5f, 0xa6, 0x0d, 0xf9, 0xb5, 0x22, 0x34, 0xf3, 0xd3, 0xfa, 0x96, 0x06, 0x50, 0x8b, 0xad, 0xc2, 0x6f, 0x30, 0xb4, 0x7c, 0xdb, 0x4f, 0x1c, 0x0f, 0x47, 0x08, 0xd4, 0x17, 0xb6, 0x85, 0x3e, 0x66, 0xc2, 0xf1, 0xf6, 0x7f, 0x62, 0x00, 0xda, 0xf7, 0x60, 0xce, 0xb6, 0x4f, 0xfc, 0x43, 0xdb, 0x27, 0xf0, 0x57, 0xad, 0x3e, 0xe9, 0x73, 0xe3, 0x1d, 0x7e, 0x5d, 0x5d, 0xeb, 0x05, 0x03, 0x15, 0xc1, 0xc6, 0x87, 0x98, 0x0c, 0x0c, 0x14, 0x8e, 0xe1, 0xa4, 0x92, 0xd4, 0x7a, 0xcf, 0xcd, 0x61, 0x32, 0x33, 0x41, 0x76, 0xc1, 0x12, 0x58, 0xc8, 0x9b, 0x19, 0xba, 0x02, 0xe6, 0xac, 0xc5, 0x5d, 0x85, 0x2f, 0x87, 0xb6, 0xa2, 0x16, 0x9e, 0xd3, 0x4a, 0x61, 0x47, 0xca, 0xa6, 0x09, 0x06, 0xac, 0x8c, 0x08, 0x13, 0xc0, 0xf0, 0x55, 0x22, 0xaf, 0x7b, 0x7f, 0x0f, 0xad, 0xdb, 0x4b, 0xc2, 0x97, 0x40, 0x5e, 0x28, 0xec, 0xf5, 0xa0, 0xf6, 0xaa, 0xc6, 0x25, 0x84, 0x22, 0xd2, 0x9c, 0xfe, 0x25, 0x0d, 0x61, 0x40, 0x28, 0x40, 0xf3, 0xc2, 0x7d, 0x0c, 0xe3, 0x9b, 0x3e, 0x2d, 0x5f, 0x1e, 0x52, 0x05, 0x41, 0xd2, 0x96, 0x5e),
-//            //   cipher_text: vec!(0x0a, 0xfc, 0xe7, 0x70, 0xa1, 0x2f, 0x15, 0xd6, 0x7a, 0xc1, 0x04, 0xba, 0x06, 0x40, 0xaa, 0xb9, 0x59, 0x22, 0x39, 0x06, 0x07, 0x47, 0x3c, 0xbd, 0xa7, 0x13, 0x21, 0x15, 0x6a, 0x55, 0x59, 0x90, 0x6b, 0xe9, 0x33, 0xfb, 0x09, 0x80, 0xda, 0x56, 0xf2, 0x7e, 0x89, 0x79, 0x6e, 0xaa, 0x10, 0x54, 0xf5, 0xaa, 0xcf, 0x16, 0x68, 0xd9, 0xf2, 0x73, 0xcc, 0x69, 0x07, 0x1b, 0x9e, 0x8e, 0x22, 0xaf, 0x6a, 0x20, 0x5a, 0x6a, 0x88, 0xf7, 0xad, 0x91, 0x8e, 0x22, 0xf6, 0x16, 0xbd, 0xdb, 0xb0, 0x7c, 0x78, 0x91, 0x3c, 0x7e, 0x05, 0x6e, 0x76, 0x9e, 0x6f, 0xcf, 0x91, 0xc7, 0x60, 0x0c, 0x27, 0x40, 0x21, 0x2e, 0x3a, 0x17, 0x6e, 0x41, 0x10, 0xca, 0xc9, 0xe3, 0x61, 0xa5, 0x9a, 0x77, 0x34, 0x57, 0x06, 0x4d, 0x2d, 0xc6, 0x52, 0xdd, 0x11, 0x5d, 0x04, 0xf1, 0xc3, 0x75, 0x6c, 0x0e, 0x1d, 0x39, 0xf6, 0x73, 0x7a, 0x16, 0xb4, 0x50, 0x86, 0x63, 0xe3, 0x10, 0x93, 0x4c, 0x49, 0xc5, 0x80, 0x58, 0xb3, 0xc7, 0xb9, 0xaf, 0x7b, 0xb2, 0x33, 0x4c, 0x8a, 0x16, 0x36, 0x08, 0xc4, 0x24, 0x99, 0x65, 0x89, 0x86, 0x92, 0x7c, 0xda, 0x36, 0x5e, 0x2a, 0xea, 0xd3, 0xac, 0x29, 0xde, 0x16, 0xe4, 0x7e, 0x95, 0x43, 0x83, 0xea, 0x56, 0x6f, 0x8f, 0xb2, 0x45, 0xa4, 0xe5, 0xa9, 0x34, 0xc7, 0x67, 0xbb, 0x3b, 0xf7, 0xe0, 0xeb, 0x8a, 0x47, 0x7f, 0xd0, 0xe1, 0xf6, 0x1b, 0xcb, 0x23, 0x84, 0x62, 0xa0, 0xd1, 0x9c, 0x5c, 0xea, 0x92, 0x93, 0xca, 0x58, 0xad, 0xe7, 0x68, 0x29, 0x41, 0x32, 0x16, 0xa7, 0x88, 0x2c, 0xd2, 0x84, 0x63, 0x23, 0x04, 0x66, 0x94, 0xf7, 0x8c, 0xd8, 0xb0, 0x34, 0x77, 0x92, 0xeb, 0xb7, 0x5a, 0xbd, 0xc1),
-//            //   tag: vec!(0x97, 0x3e, 0x58, 0xb1, 0xb8, 0xad, 0xb1, 0x76, 0xa6, 0xf1, 0xe5, 0xc9, 0x63, 0xbf, 0xdc, 0x5c)
-//            // },
-//            // TestVector{
-//            //   key: [0x65, 0xb6, 0x3e, 0xd5, 0x37, 0x50, 0xc8, 0x8c, 0x50, 0x8c, 0x44, 0x88, 0x1a, 0xe5, 0x9e, 0x6f, 0xff, 0x69, 0xc6, 0x62, 0x88, 0xf3, 0xc1, 0x4c, 0xfe, 0xc5, 0x03, 0x39, 0x12, 0x62, 0xca, 0xfc],
-//            //   nonce: [0x7f, 0x5e, 0x56, 0x0a, 0x1d, 0xe4, 0x34, 0xba],
-//            //   plain_text: vec!(0x84, 0x5e, 0xf2, 0x7b, 0x66, 0x15, 0xfb, 0x69, 0x9d, 0x37, 0x97, 0x1d, 0xb6, 0xb5, 0x97, 0x93, 0x0a, 0x7e, 0xf1, 0xe6, 0xf9, 0x00, 0x54, 0x79, 0x1e, 0xb0, 0x4d, 0xdf, 0xe7, 0x25, 0x2b, 0x5f, 0x88, 0xfd, 0x60, 0xeb, 0xa5, 0xaf, 0x46, 0x9b, 0xc0, 0x96, 0x61, 0xc0, 0x98, 0x7a, 0x49, 0x6f, 0xa5, 0x40, 0x62, 0x1a, 0xfe, 0xec, 0x51, 0xbe, 0xbd, 0xa7, 0x86, 0x82, 0x68, 0x00, 0x94, 0x3d, 0x97, 0x70, 0x39, 0xde, 0xe7, 0x62, 0x35, 0x24, 0x81, 0x12, 0xff, 0x8b, 0x74, 0x3f, 0x25, 0xed, 0x5f, 0x3c, 0xb0, 0xd3, 0x30, 0x7f, 0x5e, 0x11, 0x8d, 0x84, 0xfd, 0xbb, 0x9c, 0x3f, 0x55, 0x31, 0xbc, 0x17, 0x7f, 0xb8, 0x45, 0x49, 0xc9, 0x94, 0xea, 0x44, 0x96, 0xc6, 0x5e, 0x52, 0x49, 0xda, 0x98, 0x7d, 0xd7, 0x55, 0xd4, 0x6d, 0xc1, 0x78, 0x8f, 0x58, 0x24, 0x10, 0x26, 0x6a, 0x10, 0xf2, 0x91, 0xc1, 0x47, 0x4f, 0x73, 0x21, 0x83, 0xa2, 0xa3, 0x9a, 0xfe, 0x60, 0x37, 0x71, 0xbb, 0x9c, 0x42, 0x3f, 0xe3, 0xe8, 0x90, 0x6f, 0x2b, 0xe4, 0x4a, 0x0c, 0x9a, 0x7c, 0x3f, 0x0c, 0xeb, 0x09, 0xd1, 0xd0, 0xf9, 0x2d, 0x94, 0x23, 0x83, 0xa8, 0x75, 0xc0, 0x56, 0x7c, 0x78, 0x69, 0xf0, 0x45, 0xe5, 0x6d, 0xd1, 0xa4, 0xd6, 0xe9, 0x0c, 0x58, 0xd4, 0x4f, 0xe0, 0xc5, 0x76, 0x0b, 0xb4, 0xfd, 0x01, 0xde, 0x55, 0x43, 0x9d, 0xb5, 0x2b, 0x56, 0x83, 0x1e, 0x5a, 0x26, 0xa4, 0x7d, 0xe1, 0x42, 0x49, 0x45, 0x3a, 0x4f, 0x8e, 0x7d, 0xa3, 0xcb, 0x32, 0x82, 0xc6, 0x62, 0x29, 0x16, 0x19, 0x7e, 0xbf, 0xaa, 0xd8, 0x5d, 0xd6, 0x5c, 0x61, 0xe7, 0xd2, 0xd3, 0xba, 0x62, 0x62, 0x76, 0x36, 0x67, 0x46, 0xf3, 0x96, 0x39, 0x4c, 0x1b, 0xf7, 0x5f, 0x51, 0xce),
-//            //   aad: vec!(0x51, 0xa3, 0x58, 0x83, 0x98, 0x80, 0x8e, 0x1d, 0x6a, 0x98, 0x50, 0x5c, 0x6e, 0x56, 0x01, 0xae, 0x2a, 0x27, 0x66, 0xf1, 0xf2, 0x8f, 0x8f, 0x69, 0xd1, 0xcc, 0xbc, 0xad, 0x18, 0x03, 0x8c, 0x15, 0x7b, 0x41, 0x52, 0x5b, 0xe5, 0x8a, 0xe4, 0x52, 0x7a, 0x07, 0x37, 0x48, 0xb7, 0xa0, 0x48, 0x09, 0xe5, 0x2a, 0x5d, 0xf0, 0xc7, 0x98, 0x84, 0x17, 0x60, 0x77, 0x38, 0xe6, 0x3d, 0x7e, 0xad, 0x47, 0xdb, 0x79, 0x5a, 0x34, 0x6b, 0x04, 0xe7, 0x40, 0x18, 0x6e, 0x73, 0xcc, 0xad, 0x79, 0xf7, 0x25, 0xb5, 0x8e, 0xe2, 0x2d, 0xc6, 0xe3, 0x0d, 0x1f, 0x0a, 0x21, 0x8e, 0xda, 0x17, 0x91, 0xe2, 0x22, 0x9b, 0x25, 0x3d, 0x4a, 0xb2, 0xb9, 0x63, 0xa4, 0x3e, 0x12, 0x31, 0x8c, 0x8b, 0x07, 0x85, 0xc2, 0x0f, 0xca, 0x3a, 0xbc, 0xf2, 0x20, 0xc0, 0x87, 0x45, 0xd9, 0xf9, 0x60, 0x2f, 0x0e, 0xce, 0x54, 0x4a, 0x05, 0x73, 0x6d, 0x76, 0xb1, 0x2d, 0x24, 0x96, 0x99, 0xc9, 0xe3, 0xe9, 0x9f, 0x3f, 0x13, 0xcf, 0x4e, 0x5d, 0xc1, 0x3a, 0x04, 0x12, 0x5c, 0x94, 0x9a, 0x5b, 0x30, 0xd0, 0x34, 0xb2, 0x3c, 0xb3, 0x64, 0xc8, 0x78, 0x19, 0x64, 0xbc, 0x6c, 0x30, 0xe5, 0xe5, 0xca, 0x96, 0x73, 0xd5, 0x17, 0xef, 0x5f, 0x35, 0x96, 0x5d, 0x8a, 0x8c, 0xf1, 0xbe, 0x01, 0x7e, 0x34, 0x3d, 0xf9, 0x7b, 0x6b, 0xee, 0x37, 0xb3, 0x06, 0x38, 0xb1, 0x54, 0x28, 0x6d, 0x1f, 0x36, 0xd2, 0xf9, 0xa0, 0xea, 0xa2, 0x3c, 0xc4, 0x84, 0xea, 0xc5, 0xa0, 0x5b, 0x15, 0xd9, 0xef, 0xc5, 0x37, 0xd9, 0x89, 0xdb, 0xc8, 0xb3, 0x10, 0x6c, 0x0d, 0xc1, 0xa5, 0x6e, 0x97, 0xe6, 0xae, 0xc2, 0xef, 0xf5, 0x4a, 0x82, 0xcf, 0x7a, 0xe9, 0xdf, 0x2a, 0xf4, 0x6b, 0x4c, 0x86, 0x0f, 0x83),
-//            //   cipher_text: vec!(0x02, 0x7b, 0x14, 0x19, 0x7b, 0x40, 0x12, 0x25, 0x6b, 0x13, 0x3b, 0x78, 0xdd, 0xc9, 0x4e, 0x72, 0xfb, 0x4d, 0x72, 0x4f, 0xef, 0xa4, 0xae, 0x32, 0x9f, 0x5a, 0x5f, 0xa3, 0xfa, 0x78, 0x4f, 0xe6, 0xd7, 0xe1, 0xe8, 0x05, 0xe3, 0xf7, 0xa7, 0x55, 0x57, 0xde, 0x64, 0xde, 0x50, 0x6d, 0x38, 0x23, 0x7b, 0x46, 0x7f, 0xa5, 0x77, 0xef, 0xb5, 0x9e, 0x7c, 0xfe, 0x23, 0x56, 0xbe, 0xd6, 0x65, 0x5c, 0x5a, 0xa4, 0xe2, 0x38, 0xdc, 0xfe, 0xb7, 0x5c, 0x16, 0x54, 0x9a, 0x09, 0x17, 0x26, 0x87, 0x68, 0xa9, 0x6a, 0xcb, 0x5e, 0x20, 0x54, 0x6a, 0x1f, 0xb7, 0xe3, 0xa7, 0xcf, 0xf8, 0x87, 0xf4, 0x9f, 0x2c, 0xd7, 0xa1, 0x35, 0xf7, 0x2a, 0x98, 0xa7, 0x79, 0x15, 0x0f, 0x32, 0x07, 0xbf, 0x73, 0x3e, 0x88, 0x86, 0x1f, 0xd7, 0x9e, 0xad, 0xbf, 0x77, 0xfa, 0x3b, 0xfe, 0x97, 0xbf, 0xe8, 0xb6, 0xa9, 0x91, 0xcb, 0x3b, 0xcc, 0x2c, 0xde, 0x82, 0x87, 0xf7, 0xe8, 0x93, 0x84, 0x84, 0x65, 0x61, 0x93, 0x4b, 0x0f, 0x3e, 0x05, 0xe0, 0x64, 0x6e, 0x0e, 0x19, 0x07, 0x77, 0x0d, 0xf6, 0x7a, 0x75, 0x94, 0x16, 0x1a, 0x4d, 0x07, 0x63, 0xfa, 0xa6, 0xfa, 0x84, 0x40, 0x80, 0x93, 0x21, 0x59, 0x99, 0x9d, 0x52, 0x8e, 0xe0, 0x55, 0x87, 0x10, 0x05, 0x8c, 0xe1, 0x6f, 0x97, 0xd1, 0x3a, 0xc9, 0xfd, 0x9b, 0xf5, 0x04, 0x41, 0x91, 0x18, 0x8b, 0xbf, 0xb5, 0x98, 0xd0, 0xfa, 0xfb, 0xdf, 0x79, 0x0b, 0x61, 0xce, 0x07, 0x81, 0xec, 0xc0, 0x42, 0x18, 0xa3, 0x0d, 0xed, 0x45, 0xef, 0xd4, 0x98, 0xcc, 0x9b, 0xa0, 0x35, 0x62, 0xed, 0x2b, 0x4a, 0x99, 0x3e, 0xe9, 0x88, 0x76, 0xb3, 0xab, 0x7a, 0x9b, 0xc0, 0x78, 0x29, 0xf1, 0xc4, 0xca, 0x6e, 0xad, 0x98, 0xc0, 0x6b),
-//            //   tag: vec!(0xe4, 0xd1, 0x8a, 0x70, 0x1b, 0x83, 0x08, 0x69, 0x7b, 0x5e, 0x79, 0x14, 0x1e, 0xd7, 0x83, 0xc1)
-//            // },
-//            // TestVector{
-//            //   key: [0x49, 0x86, 0xfd, 0x62, 0xd6, 0xcb, 0x86, 0xb2, 0xea, 0xf2, 0x19, 0x17, 0x4b, 0xec, 0x68, 0x1b, 0xeb, 0xcd, 0xef, 0x86, 0xc8, 0xbe, 0x29, 0x1f, 0x27, 0xd3, 0xe5, 0xdc, 0x69, 0xe2, 0xfe, 0xba],
-//            //   nonce: [0xd0, 0x8d, 0x48, 0x66, 0x20, 0xed, 0x2e, 0x84],
-//            //   plain_text: vec!(0x3a, 0x22, 0xad, 0x5d, 0xe3, 0x87, 0xdb, 0x4f, 0xdd, 0x5d, 0x62, 0xa1, 0xb7, 0x28, 0xc2, 0x3a, 0x8d, 0xdd, 0xc5, 0x0b, 0x1e, 0x89, 0xf5, 0x4f, 0x61, 0x98, 0xb9, 0x04, 0x99, 0xf9, 0xda, 0x31, 0x22, 0xeb, 0xeb, 0x38, 0xeb, 0xf5, 0xfd, 0xfe, 0x30, 0x30, 0x97, 0x34, 0xf7, 0x9a, 0xff, 0x01, 0xe3, 0xde, 0x1e, 0x19, 0x6b, 0x35, 0xbf, 0xfa, 0x33, 0xba, 0xe4, 0x51, 0xf3, 0x1f, 0x74, 0xb8, 0xae, 0xc0, 0x37, 0x63, 0xf9, 0xe0, 0x86, 0x1a, 0x34, 0xfe, 0x5d, 0xb0, 0xb4, 0x0c, 0x76, 0xe5, 0x7c, 0x7f, 0xc5, 0x82, 0xbf, 0xa1, 0x9c, 0x94, 0xee, 0x25, 0xb5, 0xe1, 0x68, 0x27, 0x0f, 0x37, 0x9b, 0xf9, 0xf8, 0xa0, 0xa1, 0x8b, 0xed, 0x05, 0xde, 0x25, 0x6f, 0x8f, 0x0d, 0xd7, 0xc2, 0x3b, 0xa2, 0xff, 0x1c, 0x7f, 0x72, 0x14, 0x09, 0x46, 0x2f, 0x04, 0xcc, 0x61, 0x1a, 0xd9, 0xbd, 0x4c, 0x3c, 0x9a, 0xcf, 0x30, 0x74, 0x2a, 0xcf, 0xb9, 0x51, 0x8a, 0x63, 0x75, 0xcb, 0xb1, 0x5d, 0x65, 0xa1, 0xbc, 0x69, 0x93, 0xea, 0x43, 0x48, 0x94, 0xf9, 0x3d, 0x4f, 0x6e, 0x05, 0x99, 0x6e, 0xbc, 0x1b, 0xd5, 0x65, 0x79, 0x29, 0x63, 0x09, 0xa2, 0xc6, 0xb8, 0xfd, 0xe9, 0x50, 0x72, 0x16, 0x8b, 0x5f, 0xd3, 0x19, 0x27, 0xc4, 0xc0, 0xab, 0xaa, 0x05, 0x6b, 0xcd, 0x16, 0x22, 0x1d, 0x5f, 0x22, 0x0b, 0xe4, 0x75, 0x91, 0xf4, 0x32, 0x55, 0x01, 0x3a, 0x26, 0x2d, 0xce, 0x43, 0x98, 0x17, 0xf5, 0x34, 0x83, 0x0b, 0xa8, 0x21, 0x55, 0x34, 0x7e, 0x5f, 0xe3, 0x10, 0x1f, 0x80, 0x11, 0xb8, 0x93, 0x65, 0xa6, 0x56, 0x82, 0x14, 0xed, 0x06, 0x61, 0x91, 0x4e, 0x8c, 0xb3, 0x43, 0x1d, 0x6c, 0x8f, 0x23, 0x47, 0xdf, 0xc1, 0x20, 0x9a, 0x3e, 0xca, 0x4a, 0xaf, 0x0a, 0x11, 0x1f, 0x47, 0xfe),
-//            //   aad: vec!(0x7d, 0xd3, 0xf6, 0x56, 0xa0, 0x3c, 0x00, 0x1b, 0x45, 0xca, 0x06, 0x80, 0xbc, 0x3a, 0xc9, 0xd6, 0x8c, 0x6e, 0x96, 0xb5, 0x91, 0xd3, 0xc6, 0x9e, 0xb8, 0xc6, 0x5e, 0x48, 0x90, 0x09, 0xd8, 0x45, 0xcb, 0x33, 0x1c, 0x98, 0xb8, 0x2e, 0x62, 0x7e, 0x06, 0xd5, 0xbf, 0x01, 0xe7, 0x4c, 0x57, 0x3d, 0xf2, 0x68, 0xc2, 0x38, 0x6f, 0x12, 0x62, 0x8c, 0x01, 0x99, 0x51, 0xd4, 0x2f, 0x55, 0x99, 0x1f, 0xf2, 0x0d, 0x72, 0xa7, 0xb2, 0xc4, 0x5f, 0x41, 0xd0, 0xbe, 0x7a, 0xf4, 0x28, 0xc9, 0x2f, 0x32, 0x4a, 0xaa, 0xb8, 0xdf, 0x70, 0xd9, 0x00, 0x30, 0x1c, 0xdf, 0x09, 0xa3, 0xd9, 0x3e, 0xb7, 0x11, 0xc9, 0x19, 0xd3, 0x4a, 0x86, 0xff, 0xf9, 0xcb, 0x07, 0x83, 0x22, 0xee, 0x2e, 0x0a, 0xd4, 0x8d, 0xbd, 0xf3, 0xb7, 0x88, 0x4f, 0x0f, 0x2d, 0xc5, 0xc3, 0x62, 0x62, 0xc5, 0x9b, 0xcf, 0xd7, 0x5a, 0xc6, 0x20, 0x0f, 0x59, 0xc6, 0xfc, 0xd0, 0xce, 0x10, 0xff, 0x50, 0x05, 0xfe, 0xf5, 0xdf, 0x8f, 0x04, 0x32, 0x37, 0x7d, 0xfb, 0xfc, 0x1d, 0xb8, 0xf5, 0x59, 0xe2, 0x7e, 0x1a, 0xee, 0xf3, 0x38, 0x0e, 0xa3, 0x86, 0x48, 0x67, 0xd3, 0x6a, 0x25, 0xa1, 0x86, 0x54, 0x77, 0x9a, 0x75, 0x15, 0x86, 0xca, 0xd3, 0xb8, 0xa4, 0x6b, 0x90, 0x86, 0x4e, 0xe6, 0x97, 0xb0, 0x86, 0x05, 0x67, 0x3b, 0x8d, 0x21, 0x23, 0x43, 0x3c, 0x02, 0x0a, 0x21, 0xc4, 0xdb, 0x24, 0x3d, 0xde, 0x24, 0x20, 0xc1, 0x2f, 0xd4, 0xd5, 0x4a, 0x27, 0x04, 0xa0, 0xc8, 0xc3, 0x76, 0x45, 0x4a, 0x1b, 0x5e, 0x80, 0xfd, 0x6d, 0xb8, 0x9a, 0xab, 0xd5, 0x6d, 0x9b, 0x42, 0x1f, 0x29, 0x64, 0x9e, 0x47, 0x48, 0x24, 0xdf, 0xa5, 0x6c, 0xb5, 0xc6, 0x73, 0xc5, 0x04, 0xd1, 0x0b, 0xe5, 0x2b, 0x53, 0x75, 0x17, 0x09, 0xfe),
-//            //   cipher_text: vec!(0xc4, 0x01, 0x80, 0xaf, 0xd5, 0x30, 0x01, 0x66, 0x3f, 0xf4, 0x83, 0x41, 0x10, 0xf5, 0x6e, 0x6b, 0x0f, 0x17, 0x8c, 0xd3, 0xc0, 0xe7, 0xf7, 0xde, 0x5d, 0x00, 0x89, 0xee, 0x41, 0xd8, 0x40, 0x3f, 0xfb, 0x98, 0xe8, 0x49, 0x22, 0x70, 0x65, 0x44, 0xa3, 0x44, 0xd7, 0xe2, 0x62, 0x5b, 0x12, 0xcf, 0x66, 0xb9, 0xc9, 0x66, 0xf9, 0xf5, 0x7d, 0x7b, 0x94, 0xe3, 0xe4, 0xb3, 0x4e, 0x6f, 0x0a, 0xae, 0xd1, 0x76, 0x3c, 0xe0, 0x12, 0x78, 0x2e, 0x2f, 0x5e, 0x16, 0x82, 0xe6, 0xc3, 0x43, 0xfc, 0x79, 0x61, 0xfe, 0xdd, 0xdd, 0x09, 0x19, 0xd0, 0xb9, 0x10, 0xe9, 0x92, 0x3c, 0x17, 0xe3, 0x64, 0x06, 0x97, 0x9b, 0x25, 0x6b, 0x85, 0xae, 0xc2, 0x4e, 0xe3, 0x52, 0xf0, 0x3b, 0x48, 0xc1, 0x30, 0x2e, 0xab, 0x41, 0x9c, 0x83, 0xdc, 0xcc, 0x53, 0x72, 0xcc, 0x05, 0x9e, 0x9d, 0xe5, 0x96, 0x22, 0x4f, 0xa7, 0x00, 0x98, 0xeb, 0x32, 0xfc, 0x95, 0x79, 0xe9, 0x79, 0x17, 0xb9, 0x23, 0x91, 0x4f, 0xa2, 0xef, 0xc3, 0x0a, 0xb2, 0x9b, 0x45, 0x7b, 0xf1, 0x4e, 0x45, 0x58, 0x3b, 0x37, 0x71, 0x48, 0x6b, 0xdc, 0x08, 0x76, 0xf3, 0xea, 0x6e, 0x1a, 0x64, 0x67, 0x46, 0xc4, 0xf8, 0xc5, 0xcb, 0x26, 0x41, 0xa1, 0x55, 0x7c, 0x84, 0x73, 0xe6, 0xea, 0x67, 0xd4, 0x81, 0x1a, 0x67, 0x48, 0x5a, 0xe9, 0xa6, 0x78, 0xff, 0x3a, 0x24, 0x08, 0xca, 0x84, 0x5c, 0x3b, 0x51, 0x95, 0x7e, 0x18, 0x9e, 0xef, 0x47, 0xdf, 0xc1, 0xd4, 0x6b, 0xde, 0x4b, 0x9d, 0x75, 0x4d, 0x7d, 0xf1, 0x3f, 0x82, 0x8d, 0xda, 0xdb, 0x06, 0xe4, 0xeb, 0xdd, 0xb5, 0xf0, 0xda, 0xfb, 0xdb, 0x28, 0xde, 0x4c, 0x5e, 0x60, 0x78, 0x92, 0x6f, 0x20, 0xcd, 0xf9, 0xe9, 0x7e, 0xcd, 0x58, 0xe3, 0x09, 0xe6, 0x40, 0xf7, 0x4f, 0x06),
-//            //   tag: vec!(0xfd, 0x5e, 0x29, 0x33, 0x28, 0x32, 0xa1, 0x4a, 0x31, 0xa9, 0xce, 0x2c, 0xa8, 0x56, 0x84, 0x98)
-//            // },
-//            // TestVector{
-//            //   key: [0x7d, 0x28, 0xa6, 0x08, 0x10, 0xe4, 0x3d, 0x3d, 0xfa, 0x32, 0xe9, 0x7c, 0x07, 0x95, 0x7e, 0xc0, 0x69, 0xfc, 0x80, 0xcc, 0x6a, 0x50, 0x06, 0x18, 0x30, 0xaa, 0x29, 0xb3, 0xaa, 0x77, 0x7d, 0xfc],
-//            //   nonce: [0x47, 0x73, 0x8a, 0xc8, 0xf1, 0x0f, 0x2c, 0x3a],
-//            //   plain_text: vec!(0xb5, 0x02, 0x78, 0xae, 0x0f, 0x0f, 0xa2, 0xf9, 0x18, 0xbb, 0x9a, 0x5e, 0xd3, 0xa0, 0x79, 0x7c, 0x32, 0x8e, 0x45, 0x29, 0x74, 0xd3, 0x3c, 0xbf, 0x26, 0xa1, 0xe2, 0x13, 0xaa, 0x20, 0xc0, 0x3d, 0x0d, 0x89, 0x49, 0x08, 0x69, 0x75, 0x4a, 0xbf, 0x84, 0xdb, 0xbe, 0x23, 0x1d, 0x7b, 0xcc, 0xdc, 0xed, 0x77, 0xd5, 0x3f, 0xd4, 0x52, 0x73, 0x56, 0xd8, 0xe0, 0x2b, 0x68, 0x1f, 0xc8, 0x9a, 0x53, 0x5a, 0xe8, 0x73, 0x08, 0xbf, 0x7f, 0xbc, 0x26, 0x19, 0x7a, 0x5e, 0xa8, 0x5b, 0xdb, 0x3a, 0xa0, 0x33, 0xb8, 0xda, 0x5c, 0xd1, 0x97, 0xea, 0x6d, 0x72, 0xf9, 0x6f, 0x63, 0xb0, 0x3f, 0x4e, 0xcc, 0x7a, 0xde, 0xdf, 0x39, 0x9a, 0x50, 0x43, 0x77, 0x6c, 0xdb, 0x32, 0xc0, 0x8f, 0x30, 0xb7, 0x7f, 0x34, 0xdf, 0x85, 0xf8, 0xad, 0xb8, 0xe0, 0x26, 0x49, 0xa0, 0x4b, 0x02, 0x0b, 0x03, 0xe1, 0x7d, 0x44, 0x5c, 0xa6, 0x3e, 0x4e, 0xd7, 0x3a, 0xe4, 0x32, 0xc4, 0x81, 0x39, 0x2e, 0x03, 0x1e, 0xba, 0x2f, 0x9d, 0x2f, 0x7f, 0x98, 0x1d, 0x1e, 0x50, 0x91, 0x78, 0x22, 0xbd, 0x6f, 0xf7, 0x1c, 0x23, 0x9d, 0x33, 0x44, 0x4a, 0xda, 0x35, 0x23, 0xa5, 0x9d, 0xfb, 0xce, 0x54, 0x57, 0xea, 0xde, 0xc1, 0xab, 0x92, 0x6c, 0x9e, 0x6c, 0x52, 0x99, 0xc7, 0x52, 0x1e, 0x3f, 0x20, 0x4b, 0x96, 0x90, 0x1a, 0x71, 0x25, 0x04, 0xfc, 0xc7, 0x82, 0xe8, 0xce, 0xa8, 0x0b, 0xa1, 0x2a, 0x7f, 0x7e, 0x71, 0xce, 0xc3, 0xd0, 0x87, 0x18, 0x99, 0xb6, 0xca, 0x05, 0x90, 0x61, 0xda, 0x03, 0x77, 0x15, 0xf7, 0xd1, 0x3f, 0xed, 0x01, 0xc9, 0xca, 0xde, 0x1e, 0x68, 0x7b, 0x4f, 0xbb, 0x1f, 0x4a, 0xc4, 0xb0, 0x40, 0xdb, 0x3b, 0x43, 0x80, 0x0f, 0x11, 0x2f, 0xb9, 0x00, 0xe4, 0xf7, 0x72, 0xd6, 0x1b, 0x92, 0x1c, 0xbc, 0xe4, 0xda, 0x6f),
-//            //   aad: vec!(0x32, 0x42, 0x92, 0x81, 0x3b, 0x7d, 0xf1, 0x5b, 0xc0, 0x70, 0xcc, 0x5d, 0x8a, 0x4b, 0xf7, 0x4e, 0xad, 0x03, 0x64, 0x30, 0xbe, 0x63, 0xab, 0xc4, 0x33, 0x04, 0xcf, 0x65, 0x39, 0x59, 0xa2, 0x4a, 0x91, 0xc7, 0xde, 0x5a, 0x67, 0x1c, 0x50, 0xfa, 0x8a, 0x87, 0xe2, 0x1b, 0xb8, 0x2b, 0x06, 0x99, 0x99, 0xaa, 0xdf, 0xb6, 0x89, 0x5d, 0x8b, 0xda, 0x4c, 0x30, 0x83, 0xd1, 0x7b, 0x8c, 0xa5, 0x5b, 0x9a, 0xb1, 0x51, 0x1e, 0xd8, 0xc4, 0xb3, 0x9d, 0x8c, 0x28, 0xc1, 0x1a, 0x22, 0xef, 0x90, 0xc0, 0x8a, 0x98, 0x3e, 0x3f, 0xe2, 0xd9, 0x88, 0xdf, 0x9e, 0x02, 0xb1, 0x6a, 0x20, 0xb2, 0x4f, 0x39, 0xdd, 0xb2, 0x84, 0x29, 0x62, 0x5f, 0x51, 0x1d, 0xb0, 0x82, 0x98, 0xc4, 0xdc, 0x32, 0x1f, 0x6c, 0x26, 0x8f, 0xc8, 0x36, 0xa6, 0x19, 0x1d, 0xf6, 0x23, 0x2f, 0x51, 0xc4, 0x63, 0xa3, 0x97, 0xa8, 0xd8, 0xb3, 0x33, 0x74, 0xab, 0xe9, 0x4e, 0x62, 0xc0, 0xf5, 0xc3, 0x22, 0x38, 0x7e, 0x1f, 0xc4, 0xa1, 0xc1, 0x98, 0x0a, 0x04, 0xa1, 0xa3, 0xc2, 0xc3, 0x1b, 0x32, 0xf1, 0x83, 0xa1, 0x1c, 0x32, 0x68, 0xc6, 0xdc, 0xa5, 0x21, 0x14, 0x9d, 0xc1, 0x6a, 0xf1, 0x20, 0xa7, 0x8b, 0xe6, 0x62, 0x72, 0x10, 0xe8, 0xdd, 0xbc, 0x44, 0x47, 0x2b, 0xc2, 0x4d, 0x66, 0xce, 0x36, 0x81, 0xc7, 0x57, 0x9b, 0x3d, 0x9a, 0x42, 0x52, 0x12, 0xa7, 0x04, 0xa4, 0xf5, 0x10, 0x5c, 0xb8, 0x0f, 0x0d, 0x18, 0xee, 0x86, 0x09, 0x53, 0xd1, 0x0b, 0x59, 0xc1, 0x14, 0x82, 0x67, 0x79, 0xbb, 0xc3, 0x68, 0xd7, 0xa0, 0xee, 0xce, 0x9f, 0x22, 0x3e, 0x47, 0xcd, 0x8e, 0x5f, 0xd4, 0x53, 0x60, 0x7d, 0x10, 0x1d, 0x9d, 0x9c, 0x2b, 0xd9, 0xa6, 0x58, 0xd6, 0x52, 0x0b, 0x87, 0xd7, 0xb4, 0x26, 0x3f, 0x6d, 0x84, 0x5a, 0x52, 0x4a, 0x36, 0xe4),
-//            //   cipher_text: vec!(0x2c, 0x21, 0x7e, 0x96, 0x9c, 0x04, 0x74, 0x0a, 0x1a, 0xcf, 0xa3, 0x01, 0x17, 0xeb, 0x5b, 0x32, 0xdc, 0x57, 0x3d, 0xf3, 0x35, 0x4f, 0x4c, 0xc3, 0xbf, 0x8f, 0x69, 0x6f, 0xf9, 0x05, 0xf1, 0xe6, 0x40, 0xf3, 0xb2, 0xc2, 0x50, 0x47, 0x3b, 0x37, 0x66, 0x22, 0xe0, 0xc9, 0xbd, 0xa1, 0x3b, 0x94, 0x64, 0x05, 0x21, 0xbe, 0x1e, 0xf0, 0xfc, 0x66, 0x0b, 0x4c, 0x10, 0xdb, 0xe2, 0xbf, 0xc0, 0x93, 0x03, 0x07, 0x53, 0xe0, 0x4f, 0x6a, 0xae, 0xcf, 0x81, 0x3b, 0x43, 0xb6, 0x1f, 0x96, 0x04, 0x55, 0x97, 0x4b, 0x8b, 0xb8, 0xa9, 0xb4, 0x61, 0xd1, 0xe8, 0xfd, 0x38, 0x02, 0x31, 0x5e, 0x86, 0x3c, 0x00, 0x44, 0x8f, 0x24, 0xdd, 0x38, 0xde, 0xb9, 0x0e, 0x13, 0x54, 0x93, 0x27, 0x4e, 0xb1, 0x4c, 0xcb, 0xde, 0x15, 0xc5, 0x0d, 0xca, 0xd7, 0x34, 0xed, 0x81, 0x5a, 0x80, 0x6b, 0xe6, 0x62, 0x24, 0x92, 0xa8, 0x4c, 0xd0, 0x62, 0xe3, 0xba, 0x56, 0x7b, 0x90, 0x9a, 0x20, 0x5a, 0x1d, 0x0d, 0x2b, 0xed, 0xd4, 0x01, 0x69, 0x69, 0x7d, 0x26, 0x1c, 0x7b, 0x6c, 0x2e, 0x0b, 0x1f, 0x06, 0x98, 0x53, 0xfd, 0x47, 0x0e, 0x8f, 0x36, 0x4a, 0x14, 0x2c, 0x38, 0x6c, 0x43, 0x9a, 0x6d, 0xbe, 0x19, 0x2d, 0xed, 0x5a, 0x3d, 0x0f, 0xbf, 0x73, 0x79, 0x9f, 0x58, 0x8c, 0x59, 0xe5, 0x8c, 0x60, 0x24, 0x9d, 0x98, 0x0d, 0xdc, 0xf0, 0xd9, 0x69, 0x36, 0x31, 0xcd, 0x9b, 0x3f, 0x97, 0x25, 0x09, 0xc3, 0xa7, 0x71, 0x23, 0xd3, 0x8d, 0x9e, 0x26, 0x7e, 0xca, 0xd0, 0x6e, 0x12, 0x08, 0xe3, 0xf1, 0xc0, 0xa6, 0x9f, 0xbc, 0xa7, 0xc3, 0xbb, 0x1a, 0x48, 0xfd, 0xa1, 0x94, 0x93, 0xd0, 0xf8, 0xf4, 0x83, 0x98, 0x82, 0x00, 0x57, 0xb9, 0x41, 0x20, 0xf3, 0xef, 0x97, 0xd8, 0x7e, 0x9e, 0x8a, 0x1b, 0x30, 0x1a, 0x25, 0x34, 0xc6, 0x8f),
-//            //   tag: vec!(0x1f, 0xdd, 0x2d, 0xcd, 0x93, 0x5f, 0x55, 0x82, 0x2b, 0xf7, 0x23, 0x1a, 0x51, 0x6c, 0xa8, 0x41)
-//            // },
-//            // TestVector{
-//            //   key: [0xa7, 0x6e, 0x9b, 0x91, 0x6f, 0x5a, 0x67, 0xb7, 0x8a, 0x59, 0x49, 0x65, 0x1c, 0x8c, 0x3a, 0x97, 0x41, 0xa1, 0xbc, 0x3c, 0x41, 0xcd, 0xf8, 0x5f, 0xd2, 0xc8, 0xf3, 0xe9, 0xa0, 0x61, 0x60, 0x98],
-//            //   nonce: [0x08, 0x08, 0xda, 0x82, 0x92, 0xdc, 0x14, 0xe0],
-//            //   plain_text: vec!(0x9c, 0x14, 0x9e, 0xeb, 0x09, 0x34, 0x5c, 0x3c, 0x22, 0x46, 0x2b, 0x03, 0xe4, 0x9e, 0xb4, 0xdb, 0xa6, 0xbc, 0x98, 0xb2, 0x69, 0xb1, 0x08, 0x6d, 0x75, 0x2b, 0xcd, 0x8e, 0xea, 0x53, 0xb8, 0x97, 0x7b, 0x23, 0x8a, 0x04, 0xa9, 0x94, 0xba, 0xf9, 0x15, 0x59, 0x16, 0x86, 0xba, 0xab, 0x90, 0xb7, 0x9a, 0x3b, 0xf7, 0xd9, 0xad, 0xb2, 0xc6, 0xc2, 0xe3, 0x1a, 0xcd, 0x3e, 0x72, 0xf0, 0x81, 0x3f, 0xb7, 0x45, 0xaa, 0x5f, 0xb2, 0xe3, 0xda, 0x40, 0x8f, 0x78, 0x00, 0x1c, 0x9c, 0x09, 0xbd, 0x26, 0xa1, 0xa2, 0x64, 0x60, 0x11, 0xb6, 0x12, 0x0a, 0xaa, 0x2b, 0xba, 0xcc, 0x4a, 0x16, 0xc3, 0x9f, 0xb5, 0x25, 0x7b, 0x9b, 0x2e, 0xa2, 0xad, 0x8b, 0xf7, 0x0b, 0xcc, 0x98, 0x55, 0xcf, 0x11, 0x84, 0x11, 0x16, 0xc2, 0x76, 0x73, 0x10, 0xcf, 0x3c, 0xd4, 0x9d, 0x1a, 0xa4, 0x4c, 0xd5, 0x05, 0xf0, 0x79, 0x76, 0x1e, 0x06, 0x4d, 0x5b, 0xc7, 0xce, 0xa4, 0xa7, 0x17, 0x3b, 0x08, 0x68, 0x82, 0xa7, 0x7d, 0x3f, 0xc1, 0x79, 0xef, 0xc8, 0x6f, 0xc4, 0xdb, 0x8a, 0x37, 0x34, 0x91, 0xd2, 0xed, 0x81, 0xea, 0xbc, 0x63, 0xc9, 0x50, 0xe8, 0x32, 0xdb, 0x17, 0xd0, 0x9f, 0x47, 0x4d, 0x4e, 0xc4, 0x6b, 0xde, 0x47, 0x83, 0x0c, 0xaf, 0x26, 0xfa, 0xba, 0xa0, 0x37, 0x2b, 0x81, 0xfc, 0xcc, 0x44, 0x9c, 0x0e, 0x19, 0xcc, 0xd6, 0x30, 0xca, 0xf6, 0x93, 0xa7, 0xb4, 0x3b, 0xb1, 0xc4, 0x08, 0xa5, 0x4e, 0x03, 0xf5, 0x0c, 0x44, 0x28, 0x0a, 0x05, 0xad, 0x89, 0xfb, 0x6e, 0x8f, 0x01, 0xd8, 0xac, 0x27, 0x8e, 0xdf, 0x55, 0x6e, 0x5d, 0x86, 0xce, 0xb4, 0xb6, 0x14, 0xfb, 0x2e, 0xf1, 0x33, 0x81, 0x9c, 0x6e, 0x1f, 0xf6, 0xab, 0xb8, 0x6c, 0x54, 0xa1, 0x35, 0x25, 0x62, 0x04, 0xb5, 0xcd, 0x40, 0x0b, 0x93, 0x62, 0x4d, 0x39, 0x32, 0xe7, 0xc2, 0xb0, 0x46),
-//            //   aad: vec!(0x6a, 0xeb, 0x70, 0x31, 0xe4, 0xa2, 0xe2, 0x3e, 0xea, 0x93, 0xf0, 0x5f, 0xdc, 0x56, 0x2a, 0xa2, 0xbf, 0x43, 0xb8, 0x99, 0x8b, 0xea, 0x73, 0x44, 0x37, 0x7a, 0xad, 0xdc, 0x60, 0xfb, 0xdb, 0x7b, 0xcb, 0x14, 0x91, 0xd3, 0x79, 0xed, 0x0c, 0xb6, 0x13, 0xee, 0x75, 0x7c, 0xfb, 0x66, 0x49, 0x0d, 0xb6, 0x1b, 0xb4, 0x31, 0xd2, 0xfa, 0xd3, 0x4b, 0x38, 0xdd, 0xd5, 0x5b, 0xc5, 0xb2, 0x2a, 0xa6, 0xc4, 0x77, 0x3b, 0x99, 0x92, 0xf3, 0x4b, 0x87, 0x8c, 0x56, 0x63, 0xf6, 0xe8, 0xcd, 0xb5, 0xf8, 0x0a, 0x17, 0xf4, 0xd3, 0x12, 0xbf, 0x34, 0x24, 0x92, 0xe4, 0x8d, 0x1c, 0xe4, 0xc6, 0xd7, 0x54, 0x07, 0x6a, 0x63, 0x4f, 0xec, 0xe6, 0x15, 0x00, 0xac, 0xf8, 0x16, 0x8d, 0x47, 0x38, 0x1a, 0xf4, 0xfa, 0xf9, 0x80, 0xc6, 0xca, 0xc2, 0xbf, 0xd5, 0xda, 0x8c, 0x09, 0xb6, 0xed, 0xb0, 0xf5, 0x43, 0xbf, 0x0f, 0xe0, 0x26, 0x43, 0xe3, 0x8d, 0x73, 0xfa, 0x37, 0xd8, 0xae, 0x87, 0xfb, 0x66, 0x19, 0x3f, 0x22, 0xe5, 0x7f, 0xaf, 0x43, 0x93, 0xc0, 0x07, 0xd4, 0x8c, 0x86, 0x31, 0xa6, 0x85, 0xd5, 0x20, 0x57, 0x8f, 0x8f, 0x89, 0xdb, 0x68, 0x4f, 0xb3, 0x71, 0xea, 0x02, 0xf3, 0xa5, 0x8b, 0x1e, 0x21, 0x68, 0xf0, 0x21, 0x63, 0x21, 0x13, 0x94, 0x72, 0xe0, 0xd0, 0x3b, 0x6d, 0x90, 0xba, 0x8a, 0xab, 0x65, 0x40, 0x2e, 0x1c, 0x1a, 0xc4, 0xf9, 0x17, 0x2a, 0x60, 0xe2, 0x7e, 0x3d, 0x99, 0x7b, 0x9b, 0x05, 0xe2, 0xf6, 0x72, 0x12, 0x0d, 0x6c, 0x87, 0xbc, 0xaf, 0xa6, 0xd4, 0xc9, 0xb4, 0xcf, 0x8b, 0xa8, 0xa8, 0x29, 0x32, 0xd9, 0x28, 0x40, 0x36, 0x8f, 0xc5, 0x3d, 0xc5, 0xb4, 0x85, 0x26, 0x10, 0x3d, 0xca, 0xb5, 0xf1, 0x53, 0x10, 0x38, 0xaa, 0xbe, 0x89, 0x17, 0x13, 0x27, 0xac, 0x55, 0x9b, 0x98, 0xa3, 0xcf, 0x4e, 0xa7, 0x0b, 0xf0, 0x51),
-//            //   cipher_text: vec!(0x9c, 0x3f, 0xaa, 0xb9, 0x26, 0x1a, 0x63, 0xce, 0xa9, 0x47, 0x7b, 0x32, 0x69, 0x00, 0x72, 0x83, 0x99, 0x5b, 0x06, 0xba, 0x77, 0xef, 0x83, 0xd9, 0xe6, 0x93, 0xf7, 0xe4, 0xee, 0x98, 0x55, 0x55, 0x0e, 0xef, 0x94, 0x85, 0x5b, 0xe3, 0x9a, 0x7a, 0x43, 0x5b, 0x6a, 0x35, 0x84, 0xb2, 0x02, 0x97, 0x37, 0x77, 0xc7, 0xb2, 0x48, 0x23, 0x76, 0xba, 0x47, 0xb4, 0x93, 0x11, 0x94, 0x7a, 0x64, 0x98, 0x3b, 0x60, 0x23, 0x67, 0x56, 0xee, 0x44, 0x55, 0xd4, 0xcf, 0xad, 0xa8, 0xc3, 0x6a, 0xf8, 0xeb, 0x06, 0xb0, 0x6b, 0xa2, 0xf6, 0xb7, 0x9f, 0xfb, 0x11, 0x85, 0xc8, 0x9f, 0x2b, 0x2a, 0x83, 0x1c, 0xfa, 0xa3, 0x85, 0x5f, 0xc1, 0x84, 0x1d, 0x89, 0x10, 0x90, 0x8b, 0xe5, 0x07, 0x83, 0x52, 0x01, 0x11, 0x68, 0xa6, 0x7d, 0x36, 0x37, 0x2d, 0x85, 0x1a, 0x32, 0x17, 0xca, 0xbf, 0x59, 0x3e, 0xa4, 0x62, 0xdc, 0xd3, 0x25, 0xcf, 0x9a, 0x4f, 0x67, 0xe8, 0x54, 0x18, 0xfd, 0x5c, 0x92, 0x4e, 0x9b, 0x92, 0xab, 0x02, 0x6c, 0xbe, 0xe4, 0xe7, 0xab, 0x10, 0x67, 0x06, 0x6c, 0xb5, 0x94, 0x9d, 0xfc, 0x69, 0x9a, 0x68, 0xfe, 0x53, 0x9e, 0x1a, 0xbb, 0x13, 0xce, 0xc3, 0x39, 0x04, 0xe5, 0x20, 0x7e, 0x69, 0x63, 0xd2, 0x4f, 0x5a, 0x0b, 0x77, 0x06, 0x13, 0xb8, 0xb0, 0x00, 0x14, 0xe7, 0x91, 0xbf, 0xff, 0x88, 0xf9, 0xc2, 0x5c, 0xa1, 0x26, 0x12, 0x7a, 0x2f, 0x8d, 0x1d, 0x1e, 0x97, 0x94, 0xef, 0xd2, 0x8d, 0xce, 0x98, 0xb5, 0x3e, 0x22, 0x80, 0x73, 0xfa, 0xae, 0x8d, 0x50, 0x47, 0x53, 0x0d, 0x50, 0x21, 0x84, 0xfc, 0x34, 0x13, 0x21, 0xc3, 0xf5, 0x5f, 0xcb, 0xf4, 0x11, 0x87, 0xfc, 0x31, 0x26, 0x2c, 0x32, 0x5b, 0x97, 0xf5, 0x19, 0x95, 0x9b, 0x6a, 0x29, 0xb3, 0x6c, 0x71, 0xf7, 0x6f, 0x60, 0x19, 0x6b, 0xb1, 0x45, 0x7b, 0x77, 0xc8, 0xbb),
-//            //   tag: vec!(0xb4, 0x5d, 0xf1, 0x19, 0x04, 0x3d, 0x29, 0x00, 0x8f, 0xce, 0xf3, 0x6a, 0x16, 0x9e, 0xf8, 0x86)
-//            // },
-//            // TestVector{
-//            //   key: [0x98, 0xcd, 0x24, 0x77, 0xa7, 0xa0, 0x72, 0xc6, 0x9f, 0x37, 0x5b, 0x88, 0xd0, 0x9e, 0xd9, 0xd7, 0xb9, 0xc3, 0xdf, 0x3f, 0x87, 0xe3, 0x6c, 0xe6, 0x21, 0x72, 0x6f, 0x76, 0xe3, 0xb4, 0x1a, 0x1d],
-//            //   nonce: [0x77, 0xd1, 0x85, 0xaa, 0xf7, 0x15, 0xaa, 0x48],
-//            //   plain_text: vec!(0x42, 0xb3, 0x1e, 0xef, 0xda, 0xca, 0xb0, 0xf0, 0x3e, 0xf6, 0x06, 0x01, 0x56, 0x00, 0x0c, 0x81, 0x95, 0xad, 0xb0, 0x97, 0x6c, 0xab, 0xbe, 0x1a, 0x42, 0xbf, 0xcc, 0x09, 0xf8, 0x56, 0x59, 0xc6, 0x0b, 0x98, 0x63, 0x84, 0x01, 0xf2, 0xd2, 0xe2, 0xfa, 0xcf, 0xb9, 0xa9, 0x7a, 0x62, 0x92, 0x6b, 0xb0, 0xce, 0xca, 0xf3, 0xaf, 0x01, 0x80, 0xa0, 0x1b, 0xfb, 0x6e, 0x57, 0x6b, 0xab, 0xf7, 0xfc, 0x43, 0x33, 0x19, 0x37, 0xa9, 0x2a, 0xbd, 0x30, 0xcd, 0xdf, 0xa3, 0xe4, 0x50, 0xf8, 0x95, 0xe9, 0xdd, 0x91, 0x4d, 0xea, 0x3f, 0xaf, 0xd7, 0x59, 0xc1, 0x36, 0xd6, 0x85, 0x31, 0x0e, 0xbc, 0xe2, 0x8a, 0xc0, 0x61, 0x3c, 0xcd, 0xbf, 0x30, 0x11, 0x59, 0x46, 0xc9, 0x63, 0x4b, 0x67, 0x51, 0x0b, 0x77, 0xd0, 0xe3, 0x7f, 0x07, 0x71, 0x4b, 0x2d, 0xda, 0xc9, 0xd7, 0x09, 0x5b, 0x8d, 0x4b, 0xd8, 0x87, 0xc1, 0x32, 0xc4, 0xa9, 0x12, 0x7e, 0xb0, 0x1c, 0x8d, 0xed, 0xb4, 0xc3, 0x9c, 0x87, 0xb9, 0x8a, 0x74, 0x13, 0x16, 0x65, 0x6f, 0x9a, 0x8d, 0x5a, 0x5b, 0x0c, 0x0a, 0xc8, 0x47, 0x89, 0xaa, 0x23, 0x47, 0xa5, 0xf9, 0x9c, 0xa5, 0xad, 0x55, 0xcd, 0x1b, 0xcf, 0x98, 0xf7, 0x03, 0xeb, 0x4b, 0x00, 0xba, 0xdb, 0x8a, 0x85, 0x55, 0xf3, 0x8b, 0x3b, 0x36, 0x8d, 0xb8, 0xba, 0x7c, 0xee, 0xa9, 0x4e, 0x8b, 0x21, 0x9f, 0x51, 0xed, 0xce, 0x75, 0xd8, 0x41, 0x66, 0xb5, 0x60, 0x21, 0x56, 0xed, 0x59, 0x62, 0xa9, 0x3a, 0x51, 0xdb, 0x73, 0xc5, 0x9d, 0x87, 0xe9, 0x06, 0x17, 0x9d, 0x7a, 0x74, 0xa2, 0xa2, 0xa6, 0x9d, 0x8a, 0xd9, 0x9f, 0x32, 0x32, 0x25, 0xc8, 0x7e, 0x47, 0x5d, 0x3f, 0x77, 0x1b, 0x4a, 0x20, 0x3a, 0x2e, 0x2b, 0x03, 0xb4, 0x58, 0x40, 0x10, 0x44, 0x64, 0x9f, 0xa6, 0x53, 0x6d, 0xfa, 0xb2, 0x4d, 0x70, 0x37, 0x80, 0x7d, 0xcb, 0xf6, 0x51, 0x8e, 0x65, 0x78),
-//            //   aad: vec!(0xf5, 0xbb, 0x14, 0x96, 0x05, 0x2a, 0x43, 0x61, 0xdd, 0xdf, 0x72, 0xa2, 0x88, 0xe3, 0x69, 0x53, 0xa3, 0xd8, 0x15, 0xd6, 0x87, 0x6c, 0x01, 0x3f, 0x1d, 0x6b, 0xa8, 0x39, 0xe1, 0x27, 0xf7, 0x21, 0xb0, 0x52, 0xb1, 0xf7, 0xd8, 0xca, 0x20, 0xc7, 0xdc, 0x03, 0x86, 0xa7, 0xd4, 0x59, 0xeb, 0xd7, 0xeb, 0x9f, 0xc8, 0xcb, 0x08, 0x94, 0x1e, 0x6c, 0xa9, 0xdd, 0xb9, 0x80, 0xf3, 0x11, 0x5f, 0x65, 0xbc, 0x19, 0x28, 0xa4, 0x14, 0xd4, 0x41, 0xae, 0x71, 0xdc, 0xb8, 0x79, 0xd5, 0xbf, 0xe0, 0xcd, 0xe0, 0x56, 0x2b, 0xc3, 0x7f, 0x8f, 0xde, 0x0d, 0x52, 0x91, 0xad, 0x40, 0x5c, 0x92, 0xfc, 0xbb, 0x86, 0x0c, 0x43, 0xb5, 0x5a, 0xc0, 0xfe, 0x66, 0x3b, 0x54, 0xb3, 0xd0, 0x61, 0x6a, 0xca, 0x13, 0xa5, 0xc8, 0x2b, 0x7b, 0x5d, 0x34, 0x12, 0x5a, 0x05, 0xc2, 0xac, 0xb5, 0x53, 0x01, 0x41, 0x03, 0x0e, 0x6f, 0x2a, 0xa0, 0xc8, 0x32, 0x2b, 0x2c, 0x8f, 0xa3, 0x07, 0xe7, 0x51, 0x89, 0x18, 0xe5, 0x50, 0xe9, 0xf4, 0x89, 0x21, 0xc6, 0x16, 0x8f, 0x09, 0x4d, 0x87, 0x58, 0xe1, 0x6b, 0x9f, 0x81, 0x5f, 0xd0, 0x45, 0x80, 0x95, 0xc4, 0x14, 0x3f, 0x09, 0x22, 0xad, 0xb1, 0x84, 0x0d, 0x0e, 0x68, 0x56, 0x36, 0x82, 0x5a, 0x9c, 0x90, 0xee, 0x90, 0xee, 0x53, 0x7f, 0x4b, 0x8d, 0xce, 0xec, 0xbc, 0x42, 0x87, 0xc8, 0x2d, 0xc9, 0xa0, 0x0d, 0x7e, 0x51, 0x67, 0x1e, 0x37, 0xea, 0x28, 0x4e, 0xe3, 0xca, 0x50, 0x1b, 0x1b, 0x25, 0x96, 0x45, 0x9d, 0x3f, 0x59, 0x2f, 0x70, 0x18, 0x6f, 0x41, 0x12, 0x57, 0x39, 0xe3, 0x42, 0xc9, 0xf6, 0xbe, 0x92, 0x41, 0x97, 0x3b, 0x14, 0x14, 0xdf, 0xe5, 0xfb, 0x8c, 0xba, 0x1a, 0xf8, 0x2e, 0x67, 0x92, 0x78, 0xcf, 0xcf, 0x95, 0x42, 0x0d, 0xf0, 0xc5, 0x36, 0x4a, 0xf4, 0xd7, 0xe7, 0x2a, 0xd5, 0x7d, 0x5c, 0x87, 0x1f, 0xcb, 0xc3, 0x54, 0x62),
-//            //   cipher_text: vec!(0x7a, 0x3b, 0xf3, 0xe3, 0xad, 0x5a, 0xe3, 0xab, 0x71, 0xfb, 0x1f, 0x71, 0x21, 0xc3, 0xd8, 0xfb, 0x51, 0x10, 0x99, 0x48, 0x4b, 0x50, 0xaf, 0x7c, 0xa1, 0x28, 0xee, 0x03, 0x37, 0xed, 0x4b, 0x82, 0x8d, 0xc4, 0xcd, 0xe0, 0xb8, 0x8d, 0xc1, 0xe8, 0x08, 0x91, 0x01, 0xfa, 0x82, 0xc9, 0xbe, 0xb3, 0xeb, 0x48, 0xfd, 0xcf, 0x0f, 0x5b, 0x16, 0xda, 0x44, 0x1f, 0x5a, 0x3f, 0xce, 0x9a, 0x59, 0x00, 0x22, 0xaf, 0x95, 0xa9, 0x4a, 0xed, 0x6a, 0x3e, 0x71, 0xe5, 0x05, 0xf6, 0x0f, 0x30, 0x3c, 0x78, 0xc3, 0x56, 0xf2, 0x74, 0xea, 0x85, 0xa5, 0x53, 0x54, 0x07, 0x85, 0x30, 0x66, 0x4e, 0xcd, 0xa3, 0x2c, 0x80, 0xe7, 0x7d, 0xc2, 0x09, 0x74, 0xb3, 0xb3, 0x8f, 0x48, 0x25, 0xb8, 0xfb, 0xee, 0x8c, 0x39, 0x70, 0x76, 0x9a, 0x2f, 0x42, 0xc5, 0x18, 0x16, 0x08, 0xa8, 0xd7, 0xd7, 0x6e, 0xf4, 0xd0, 0x93, 0x96, 0x1b, 0x66, 0x5e, 0xe4, 0x2b, 0x97, 0x08, 0xfc, 0xaf, 0xe2, 0xc8, 0x2d, 0x3a, 0x30, 0x71, 0x73, 0xe2, 0xa2, 0x5a, 0xd2, 0x52, 0x8c, 0x3b, 0xf8, 0x33, 0x52, 0xb9, 0x26, 0x5e, 0x45, 0xb7, 0x07, 0x22, 0xd7, 0xcf, 0x8c, 0x9b, 0x80, 0x82, 0x6d, 0x21, 0x33, 0x52, 0x34, 0xee, 0x3d, 0xb6, 0x9d, 0x0d, 0x37, 0x87, 0x1c, 0x83, 0x22, 0x23, 0x65, 0x90, 0x0c, 0x96, 0xc1, 0x7a, 0x7e, 0x9f, 0x57, 0x42, 0xd0, 0xbf, 0xe3, 0x83, 0xbe, 0x24, 0xd0, 0xd4, 0x45, 0x90, 0xd4, 0xb0, 0xf2, 0x9f, 0x7a, 0xbe, 0x0c, 0x65, 0xda, 0xaf, 0xfb, 0x96, 0x8b, 0x3f, 0x26, 0x57, 0xb1, 0xeb, 0x30, 0x05, 0x34, 0xea, 0xcb, 0x52, 0xec, 0x7a, 0x6b, 0x6f, 0x9f, 0x57, 0xa5, 0x0a, 0x91, 0xb1, 0x79, 0x9f, 0x49, 0x13, 0x61, 0xcf, 0x61, 0x3c, 0x93, 0x4b, 0x7f, 0x52, 0x0d, 0xc4, 0xee, 0xeb, 0x40, 0xff, 0xc4, 0x5e, 0x10, 0xbe, 0x0a, 0x95, 0xe7, 0x6f, 0x36, 0x6d, 0x4e, 0xac, 0x14),
-//            //   tag: vec!(0xf6, 0x13, 0xb6, 0x52, 0x26, 0xaf, 0xb6, 0x4c, 0x61, 0x4f, 0xe6, 0x0d, 0x9c, 0x71, 0xed, 0x74)
-//            // },
-//            // TestVector{
-//            //   key: [0x2f, 0x0f, 0x46, 0x31, 0xab, 0x1c, 0x1b, 0xcf, 0x8f, 0x3a, 0xd0, 0x55, 0x9c, 0x81, 0x8d, 0x50, 0xe0, 0xaf, 0x7d, 0x8c, 0xd6, 0x3f, 0xaa, 0x35, 0x7f, 0x20, 0x69, 0xf3, 0x08, 0x81, 0xd9, 0xcb],
-//            //   nonce: [0x7d, 0x0c, 0xed, 0x2f, 0xdb, 0x1c, 0x91, 0x73],
-//            //   plain_text: vec!(0x65, 0x16, 0xba, 0x1d, 0x29, 0x35, 0x71, 0x44, 0xee, 0xbf, 0xa4, 0x86, 0xd2, 0x1d, 0xec, 0xf2, 0x23, 0xda, 0x3a, 0xa7, 0x6e, 0xc2, 0x9b, 0xbf, 0xcb, 0xe7, 0xf1, 0xee, 0xaf, 0x4a, 0x84, 0x77, 0x10, 0xe5, 0x08, 0x01, 0x77, 0xf7, 0xe5, 0xa7, 0xc8, 0xb4, 0x75, 0x2c, 0x21, 0x9b, 0x1c, 0xc7, 0x0a, 0xef, 0x4d, 0xb8, 0x61, 0xba, 0x67, 0xd0, 0xfa, 0x62, 0x22, 0xd9, 0xf4, 0xa1, 0xdc, 0x75, 0x6a, 0x0b, 0xa4, 0x4e, 0x62, 0x90, 0x6f, 0x93, 0x74, 0xa9, 0x60, 0xc1, 0x61, 0x98, 0x86, 0x6d, 0x86, 0x78, 0x54, 0xd8, 0x8f, 0x52, 0x8a, 0x60, 0xe2, 0x12, 0xeb, 0x91, 0x64, 0x57, 0x87, 0xe7, 0x56, 0x85, 0xb2, 0xe2, 0x15, 0xc0, 0xa4, 0x19, 0x90, 0xab, 0xc3, 0x44, 0xa7, 0x72, 0x36, 0xec, 0x01, 0x86, 0xba, 0x63, 0xa6, 0x64, 0x59, 0x29, 0x38, 0xcc, 0x5a, 0x8a, 0xc1, 0xd3, 0xeb, 0x99, 0xc9, 0x5c, 0xe0, 0x0e, 0x19, 0xfb, 0xe2, 0x49, 0x26, 0x30, 0x83, 0xd8, 0x5b, 0x05, 0x2d, 0x48, 0xbf, 0xdf, 0xfc, 0x01, 0x58, 0x5d, 0xc5, 0x7b, 0xb2, 0xa2, 0xc6, 0xc4, 0xa8, 0x19, 0x60, 0x4c, 0x1e, 0xc0, 0x54, 0x8c, 0x6f, 0x0f, 0x78, 0xdc, 0x05, 0xe4, 0x41, 0x8b, 0x36, 0x27, 0x7d, 0xc0, 0x72, 0x33, 0xc7, 0x53, 0x2f, 0x9c, 0x28, 0x9d, 0x6a, 0xed, 0x0c, 0xc6, 0xbc, 0x7d, 0xf4, 0xfd, 0x0a, 0x53, 0x6c, 0x49, 0x7b, 0x98, 0x2e, 0x2d, 0xad, 0x2c, 0x30, 0xd2, 0xdb, 0x1c, 0x65, 0x45, 0xa8, 0x45, 0xc5, 0xdf, 0xa8, 0x3a, 0x4a, 0xc4, 0x9e, 0xf0, 0x6f, 0xc9, 0xc9, 0x19, 0x07, 0x9d, 0x3e, 0x29, 0x9e, 0x31, 0xb5, 0xc3, 0xbe, 0x37, 0x08, 0x14, 0xae, 0x50, 0x22, 0xae, 0x46, 0x9d, 0x3e, 0xe5, 0x52, 0x46, 0xa4, 0x1b, 0xd0, 0xdc, 0x4e, 0x64, 0x35, 0x1c, 0xc3, 0x8c, 0x3c, 0x09, 0xaf, 0x0a, 0x1a, 0xee, 0x3b, 0x38, 0x8a, 0x68, 0x92, 0xde, 0xff, 0x0d, 0xf3, 0xf9, 0x3c, 0xd9, 0x2d, 0x72, 0x2b),
-//            //   aad: vec!(0x1c, 0xcf, 0xa1, 0xec, 0xec, 0xc8, 0xde, 0x1e, 0x20, 0x0d, 0x0e, 0xcc, 0x19, 0xdc, 0xf6, 0x7b, 0x7c, 0x96, 0xbe, 0xa3, 0xa2, 0x82, 0xc2, 0xbc, 0xcb, 0xa6, 0x10, 0x35, 0xdb, 0x5c, 0x14, 0x77, 0x63, 0x87, 0xb8, 0xb8, 0xf5, 0x8e, 0x57, 0x57, 0xde, 0xb0, 0x12, 0x9d, 0x4e, 0x5e, 0x31, 0x5f, 0x64, 0xdf, 0x35, 0x4a, 0x59, 0x85, 0xd2, 0xe4, 0x7e, 0xbb, 0xbe, 0xaf, 0xe0, 0xc9, 0x14, 0xf7, 0xcf, 0x1d, 0x63, 0xdd, 0x03, 0x11, 0xac, 0xe1, 0x9e, 0x69, 0xa8, 0xb6, 0xff, 0x0a, 0xb2, 0x5c, 0xc8, 0xdf, 0x04, 0x08, 0xd2, 0x21, 0x32, 0x20, 0x5e, 0x89, 0xe5, 0xeb, 0x67, 0x92, 0x68, 0xd8, 0x2b, 0x29, 0x13, 0xe6, 0x4e, 0x3f, 0x88, 0x5b, 0xbf, 0x4a, 0x6d, 0x37, 0x9b, 0x76, 0x0b, 0x94, 0x59, 0x0e, 0x31, 0x40, 0xdd, 0x72, 0x75, 0xab, 0x47, 0x13, 0xcb, 0x56, 0xd0, 0xb7, 0x16, 0xe2, 0x71, 0x8f, 0x11, 0x31, 0x66, 0x40, 0xcb, 0x39, 0x48, 0x02, 0x86, 0x2d, 0x39, 0xe7, 0x7a, 0x46, 0xd0, 0xc0, 0x65, 0xaf, 0x3c, 0xaf, 0x7d, 0xec, 0x14, 0xe8, 0x87, 0x03, 0x9d, 0x8a, 0xa8, 0xc3, 0xd3, 0xa8, 0xac, 0x1e, 0xe0, 0x60, 0x26, 0xf4, 0x9d, 0x00, 0xb2, 0xf5, 0x9d, 0x97, 0x1b, 0x54, 0x73, 0x5e, 0x95, 0xa5, 0x1f, 0x19, 0x93, 0x89, 0xa9, 0x3a, 0x4f, 0xc2, 0x4e, 0xba, 0xba, 0x1f, 0x7a, 0x2e, 0xef, 0x74, 0x12, 0xf6, 0x1f, 0xeb, 0xf7, 0x90, 0x84, 0xfb, 0xf4, 0x81, 0xaf, 0xc6, 0xfb, 0x6b, 0x20, 0x40, 0x84, 0xe5, 0xef, 0x5d, 0xf7, 0x1f, 0x30, 0x50, 0x64, 0x59, 0xde, 0xa0, 0x74, 0xf1, 0x1f, 0xc0, 0x55, 0xcd, 0x2a, 0x8c, 0x0f, 0xc9, 0x22, 0xc4, 0x81, 0x1a, 0x84, 0x99, 0x84, 0x35, 0x2a, 0x56, 0xa1, 0x56, 0x59, 0xb7, 0xd0, 0x7a, 0x4c, 0xc9, 0x0b, 0x88, 0x62, 0x36, 0x38, 0xea, 0x00, 0xc4, 0xc8, 0xbc, 0x13, 0x88, 0x4d, 0xf2, 0x23, 0x7b, 0x35, 0x9f, 0x28, 0x77, 0xaa, 0x41, 0xd6),
-//            //   cipher_text: vec!(0xe5, 0x80, 0x09, 0x37, 0x89, 0xba, 0x17, 0xff, 0xb4, 0x66, 0x72, 0xdc, 0x32, 0x6f, 0x09, 0x27, 0x8a, 0xca, 0x08, 0x59, 0x8d, 0x3e, 0x54, 0x58, 0xea, 0xa5, 0x3e, 0x6e, 0xd4, 0x5d, 0x5c, 0x71, 0xa3, 0x96, 0xe3, 0x5b, 0x5e, 0xa3, 0xfe, 0x7b, 0x7c, 0x04, 0x96, 0xa7, 0x34, 0xd2, 0x4f, 0x1c, 0x75, 0x42, 0x06, 0x94, 0xbe, 0x2f, 0xf0, 0x95, 0xd5, 0x17, 0x2f, 0xd3, 0x40, 0x77, 0x94, 0xe4, 0xb9, 0x9f, 0xd7, 0xc3, 0x74, 0xfb, 0xe8, 0xd1, 0x56, 0x4a, 0x04, 0x86, 0x14, 0xd3, 0xf3, 0x55, 0xbf, 0xb5, 0x86, 0x6d, 0xe1, 0xa5, 0x3e, 0x1a, 0x51, 0xf9, 0xf5, 0xe8, 0x31, 0x22, 0x53, 0xcf, 0xd8, 0x2f, 0x36, 0xef, 0xaa, 0x18, 0x98, 0xc8, 0x50, 0xca, 0x0d, 0x97, 0x5a, 0xd1, 0xe8, 0xb0, 0xd9, 0x59, 0x7a, 0x5a, 0x9e, 0x65, 0x16, 0xfe, 0x2a, 0x3c, 0x92, 0xef, 0xb7, 0x49, 0x55, 0x57, 0xa8, 0xaf, 0xc3, 0xda, 0x15, 0xb0, 0xd3, 0xe2, 0xba, 0x58, 0xf6, 0x12, 0x51, 0x98, 0x36, 0x94, 0x6c, 0xf2, 0xd1, 0x5b, 0x89, 0x83, 0x20, 0xd1, 0x6a, 0x02, 0x6c, 0x8c, 0x00, 0xa1, 0xbe, 0x2e, 0x35, 0xf0, 0xeb, 0xe6, 0x8f, 0x28, 0xd9, 0x1c, 0x6c, 0x45, 0xd2, 0x4c, 0x3f, 0x3c, 0x15, 0x7c, 0xb1, 0x32, 0xfa, 0x65, 0x9b, 0x77, 0x94, 0xdf, 0x88, 0x3d, 0x90, 0x74, 0x1f, 0xa2, 0xd2, 0xaf, 0xcc, 0x4f, 0x27, 0x85, 0x8e, 0x13, 0xec, 0xd4, 0x1b, 0x15, 0x4a, 0x35, 0xd2, 0x49, 0x47, 0xae, 0x73, 0x61, 0x17, 0x00, 0x60, 0xc1, 0x07, 0xd8, 0xec, 0xac, 0xb3, 0x93, 0xea, 0x67, 0x10, 0x4b, 0x60, 0x45, 0x72, 0x78, 0xa3, 0x92, 0xfd, 0xf1, 0x79, 0x4b, 0xab, 0x97, 0xd3, 0xb0, 0x2b, 0x71, 0xa4, 0xeb, 0x01, 0x5e, 0xaa, 0x38, 0xa4, 0xb4, 0xc9, 0x44, 0xc2, 0xbc, 0x7c, 0xd5, 0xe3, 0x29, 0xda, 0x4a, 0x1a, 0xb2, 0x93, 0x7a, 0x6a, 0xf8, 0x1a, 0x6c, 0xaa, 0x5f, 0xce, 0x75, 0x23, 0x31, 0xfd, 0xef, 0xd4),
-//            //   tag: vec!(0x0f, 0xd7, 0x41, 0x9c, 0x54, 0xbc, 0x84, 0x26, 0x5e, 0xd3, 0x10, 0xa3, 0x41, 0x1a, 0x3f, 0x2e)
-//            // },
-//            // TestVector{
-//            //   key: [0xa4, 0x8b, 0x9b, 0x6d, 0xf4, 0x75, 0xe5, 0x66, 0xab, 0xa7, 0x67, 0x1f, 0xbd, 0x76, 0x77, 0x2c, 0xb0, 0xef, 0xf0, 0xb1, 0x24, 0x99, 0x96, 0x79, 0x78, 0xce, 0x3e, 0x25, 0xfa, 0xc9, 0x2f, 0xeb],
-//            //   nonce: [0x2c, 0xcb, 0xf0, 0xd6, 0xc4, 0x0c, 0xb3, 0x02],
-//            //   plain_text: vec!(0x09, 0xda, 0x1c, 0xac, 0xd0, 0x01, 0xdc, 0xe4, 0xf7, 0x57, 0x3a, 0x06, 0x5a, 0x44, 0x06, 0xfe, 0x0d, 0xa0, 0x4a, 0xb3, 0x67, 0xa2, 0xd8, 0x77, 0x80, 0xa2, 0x76, 0x2e, 0x16, 0x89, 0x57, 0xa8, 0x8d, 0x3f, 0xa7, 0x8f, 0x0a, 0x4b, 0x69, 0x78, 0xd4, 0x49, 0x02, 0x6e, 0x5a, 0x80, 0x1d, 0x32, 0x88, 0x4b, 0x6e, 0x14, 0xfd, 0xaa, 0xaf, 0x86, 0x42, 0x14, 0xf9, 0x28, 0xeb, 0xc0, 0x3d, 0xea, 0xd0, 0x81, 0xfe, 0xe9, 0x66, 0x83, 0xeb, 0xb0, 0x32, 0x36, 0x2d, 0x50, 0x88, 0xc4, 0xc2, 0xa3, 0xb1, 0xe2, 0x42, 0xf0, 0x55, 0xf2, 0x60, 0x49, 0x19, 0xf4, 0xdd, 0x55, 0x1d, 0xb7, 0x77, 0xa2, 0x58, 0xcf, 0x9d, 0xa6, 0xd9, 0x5a, 0x2b, 0xde, 0x24, 0x92, 0x47, 0x81, 0x2b, 0x9e, 0xfc, 0x79, 0x85, 0xcf, 0x08, 0x70, 0x76, 0x20, 0x80, 0x85, 0x24, 0xd6, 0xdd, 0x30, 0x79, 0xb0, 0xb6, 0x3b, 0xf0, 0xf7, 0x1e, 0xa5, 0xde, 0x83, 0x4c, 0xcb, 0x8b, 0x7c, 0x6a, 0x97, 0x12, 0x5f, 0xd6, 0xca, 0x49, 0x14, 0x8e, 0x86, 0x6d, 0x31, 0x34, 0xbb, 0xf1, 0xd8, 0xa6, 0xb7, 0x14, 0xe9, 0xa8, 0x0f, 0xe5, 0x49, 0xc8, 0xbf, 0xef, 0xe3, 0x42, 0xf4, 0x1b, 0xe2, 0xba, 0x23, 0x00, 0xe0, 0x02, 0x8f, 0x78, 0xce, 0xfa, 0xb6, 0x52, 0x74, 0x63, 0x2d, 0xfd, 0xbe, 0x70, 0xbf, 0x7d, 0x65, 0x5e, 0xc4, 0x03, 0x6d, 0xf5, 0x61, 0xf2, 0xd4, 0xfc, 0x4d, 0x56, 0xa4, 0x82, 0xbb, 0xe2, 0xf9, 0xf2, 0xae, 0x27, 0x9b, 0x3a, 0xa2, 0x16, 0xb3, 0x9a, 0xfe, 0xe7, 0x5e, 0x53, 0x60, 0x2d, 0xe3, 0x19, 0x48, 0x4d, 0xb8, 0x9a, 0x51, 0xe8, 0x44, 0xf3, 0x8c, 0x36, 0x16, 0x34, 0xe4, 0x74, 0xf8, 0xf1, 0xf0, 0x1c, 0x34, 0x0f, 0x3f, 0x35, 0x94, 0x86, 0x0d, 0x67, 0x13, 0x46, 0x44, 0x9c, 0x6d, 0x08, 0xee, 0x38, 0xde, 0x22, 0xd2, 0x46, 0x30, 0x9b, 0xc7, 0xe4, 0xa2, 0x52, 0xa2, 0x9c, 0x86, 0xaa, 0x6d, 0x94, 0xb5, 0xb4, 0xfa, 0x58, 0x90, 0x4c, 0x70),
-//            //   aad: vec!(0x1c, 0x25, 0x03, 0xd5, 0xaa, 0x1a, 0xad, 0x19, 0x3f, 0x0d, 0xa1, 0x28, 0x74, 0x07, 0x4e, 0xa0, 0x43, 0x2b, 0xb7, 0x6a, 0x61, 0xcd, 0x43, 0xa3, 0x01, 0x70, 0x61, 0x51, 0x4d, 0xa0, 0x75, 0x98, 0x46, 0xa0, 0xf3, 0xae, 0x3a, 0x49, 0xfd, 0xb0, 0xb6, 0xd2, 0x9f, 0x71, 0x3d, 0xe6, 0x65, 0xbe, 0xac, 0xb6, 0x56, 0x8f, 0x26, 0x94, 0x11, 0x2c, 0xa3, 0x80, 0xd1, 0x3f, 0x3c, 0x16, 0x98, 0x31, 0x68, 0x66, 0xa7, 0xa7, 0xf8, 0x7f, 0x1d, 0x75, 0x03, 0xa9, 0x21, 0x76, 0xab, 0x84, 0xfc, 0x08, 0x97, 0x7b, 0x46, 0xba, 0x66, 0x45, 0x08, 0xa8, 0x58, 0xe7, 0x52, 0x57, 0x53, 0xc4, 0x55, 0x11, 0xb3, 0xd2, 0xf4, 0x07, 0xd5, 0xe9, 0x93, 0xc6, 0xed, 0xe7, 0x7f, 0x13, 0xd1, 0x29, 0x75, 0x70, 0x7e, 0x51, 0x95, 0x70, 0x49, 0x70, 0xa8, 0x9f, 0x71, 0xfc, 0x30, 0x82, 0x80, 0x49, 0xf9, 0x2f, 0x94, 0x4f, 0x3a, 0xa9, 0x3d, 0x6a, 0x52, 0x97, 0xe6, 0x78, 0xe0, 0x89, 0x52, 0x91, 0x9b, 0xeb, 0x7e, 0xac, 0x59, 0x19, 0xdf, 0x19, 0x19, 0xca, 0xb3, 0xc3, 0xda, 0x6a, 0xa6, 0x96, 0xa1, 0xee, 0xab, 0x63, 0x71, 0xf3, 0x10, 0xf7, 0xe8, 0x11, 0x43, 0xe7, 0xd2, 0x40, 0xb0, 0x21, 0x3a, 0xe5, 0x54, 0x52, 0x4b, 0x52, 0x00, 0x03, 0x06, 0x16, 0x0d, 0xd4, 0x87, 0x7b, 0xf1, 0x3b, 0xa0, 0xf1, 0x3b, 0xbe, 0x86, 0x7d, 0xa7, 0xc7, 0xd7, 0x07, 0xf3, 0x13, 0x35, 0xee, 0xf4, 0xcd, 0x94, 0x29, 0x38, 0xac, 0x89, 0x0a, 0x08, 0x29, 0xec, 0x66, 0xbd, 0x30, 0xae, 0x01, 0xa2, 0x18, 0x8a, 0x6e, 0x5e, 0xa0, 0xf1, 0x7c, 0xd7, 0xdc, 0x87, 0x5e, 0x17, 0xf0, 0x3c, 0x0a, 0xb5, 0xdd, 0x18, 0xe3, 0x6d, 0xb8, 0xa1, 0xfc, 0x1f, 0x72, 0x85, 0x9e, 0xe0, 0x46, 0xb6, 0x23, 0x68, 0xf1, 0x68, 0xb3, 0xbe, 0xa2, 0x23, 0x4e, 0x04, 0x32, 0xc0, 0x7b, 0x7d, 0x8e, 0x1b, 0x92, 0x77, 0xf2, 0x1e, 0x69, 0x2c, 0x51, 0x3b, 0x9e, 0x81, 0x6e, 0x68, 0x60),
-//            //   cipher_text: vec!(0x7d, 0x35, 0xcf, 0xe4, 0xbe, 0x56, 0xbd, 0x6e, 0x0e, 0x09, 0xde, 0xdc, 0xd0, 0x17, 0x35, 0xb9, 0x15, 0xbc, 0x18, 0x91, 0xa4, 0xd1, 0xf6, 0xa5, 0x41, 0xab, 0xc4, 0xbc, 0xd0, 0xeb, 0xe8, 0x9d, 0xcb, 0x8e, 0x36, 0x5e, 0x58, 0x13, 0x74, 0x2e, 0x8e, 0xc6, 0x57, 0x77, 0xb6, 0x15, 0x94, 0x22, 0xfa, 0xda, 0x74, 0x7d, 0xa9, 0x93, 0x94, 0x25, 0x2b, 0xaf, 0x8a, 0x04, 0x6f, 0xc1, 0xb6, 0x0a, 0xd7, 0x97, 0x55, 0xf5, 0x45, 0xf4, 0x44, 0x86, 0x27, 0xb7, 0xac, 0xaf, 0x40, 0x30, 0x00, 0x89, 0x4f, 0x56, 0x41, 0xe7, 0x8d, 0x3f, 0x94, 0x6d, 0xfc, 0xa2, 0x9e, 0xc6, 0x17, 0xf0, 0x66, 0x0d, 0xcd, 0x6e, 0x8d, 0x88, 0x27, 0xe6, 0x7e, 0x10, 0x22, 0xa2, 0x45, 0xc5, 0x95, 0xd8, 0x6e, 0x60, 0xfb, 0xd1, 0x76, 0xbf, 0x72, 0x1b, 0x17, 0x1b, 0xbe, 0x5e, 0xca, 0xf4, 0xae, 0x67, 0x1b, 0x9f, 0x3d, 0xd3, 0x92, 0x01, 0x46, 0xe6, 0xad, 0x43, 0x1b, 0xd8, 0xfc, 0x43, 0x18, 0x20, 0xe1, 0x94, 0x54, 0xb6, 0xca, 0x20, 0x97, 0x23, 0xd8, 0x0f, 0xdb, 0xee, 0x18, 0x7f, 0xca, 0x9c, 0x93, 0x7c, 0x97, 0x92, 0x06, 0xae, 0x97, 0xbe, 0x55, 0xf6, 0xba, 0x73, 0x66, 0xa5, 0x60, 0x87, 0x70, 0xa1, 0x1d, 0x53, 0x73, 0x96, 0x48, 0x5e, 0xb0, 0xa6, 0x65, 0x86, 0x38, 0x5f, 0x4d, 0x4c, 0xf3, 0x90, 0x5d, 0x1f, 0xc9, 0x08, 0x31, 0xc3, 0xe1, 0x36, 0xd5, 0xd5, 0x13, 0xfa, 0x22, 0xbe, 0x28, 0x51, 0x93, 0x14, 0x29, 0x94, 0xa3, 0xed, 0x47, 0x71, 0x45, 0xba, 0xcd, 0xcb, 0xdd, 0x79, 0x1e, 0x8b, 0x3b, 0x88, 0xb0, 0xd4, 0xf1, 0xd1, 0x8b, 0x27, 0x38, 0x25, 0x50, 0xa8, 0x18, 0xc4, 0xfd, 0x88, 0x84, 0xbf, 0x36, 0xf6, 0x77, 0xc6, 0xc3, 0xff, 0x56, 0x77, 0x40, 0x6e, 0x51, 0x09, 0x11, 0xe6, 0x96, 0xaf, 0x75, 0xe5, 0xb3, 0xf8, 0x59, 0xbe, 0xf6, 0x99, 0xbd, 0xd1, 0x6e, 0x62, 0x15, 0xfd, 0xb9, 0x8d, 0x87, 0x40, 0x25, 0xea, 0xda, 0x50),
-//            //   tag: vec!(0x2a, 0xab, 0xff, 0x35, 0x61, 0x1b, 0x3e, 0x00, 0x13, 0xf6, 0xae, 0x0d, 0xf1, 0x30, 0x79, 0x9b)
-//            // },
-//            // TestVector{
-//            //   key: [0x92, 0x3d, 0x4b, 0x08, 0x6b, 0x9e, 0x43, 0xb9, 0x86, 0xf7, 0xb6, 0x5e, 0x4c, 0xea, 0x61, 0x13, 0xa3, 0xd8, 0xaa, 0xbe, 0xfa, 0x89, 0x32, 0x3c, 0x5e, 0x4d, 0x5b, 0x6f, 0x15, 0x8b, 0xb7, 0xe0],
-//            //   nonce: [0xa0, 0xf7, 0x32, 0x97, 0xb8, 0x7f, 0x5d, 0xeb],
-//            //   plain_text: vec!(0x21, 0x43, 0x5e, 0x8d, 0x5c, 0x8e, 0xdf, 0x06, 0x84, 0xf5, 0x8c, 0x2c, 0xba, 0x40, 0x70, 0xc1, 0x0b, 0x48, 0x01, 0xad, 0xf4, 0x6b, 0x6c, 0x4d, 0x32, 0x2e, 0xb3, 0x99, 0x0a, 0x38, 0xa9, 0xad, 0x33, 0x8a, 0xd7, 0x04, 0xb9, 0xdf, 0x65, 0x97, 0xf3, 0xe6, 0x8d, 0x66, 0xcd, 0x5b, 0x56, 0x29, 0x0c, 0x84, 0x66, 0xdb, 0x22, 0x31, 0xe5, 0x6d, 0x6b, 0xcb, 0x9c, 0x44, 0xe1, 0xbd, 0x08, 0x1f, 0x42, 0xca, 0x2a, 0x89, 0x4d, 0xad, 0x36, 0x9d, 0xf2, 0xbd, 0x0d, 0x2c, 0x63, 0xd6, 0xc8, 0x81, 0x73, 0x2d, 0x6e, 0xa2, 0x2b, 0xb2, 0x2b, 0x5b, 0xc9, 0xa6, 0x2e, 0xaf, 0xfa, 0x1b, 0x09, 0x4d, 0x08, 0x45, 0xf6, 0xb9, 0x66, 0xd2, 0xcb, 0x09, 0x5e, 0x7b, 0x3b, 0x8b, 0xcb, 0xc1, 0x5e, 0x70, 0x74, 0x49, 0xd3, 0x5c, 0x8d, 0xf4, 0xae, 0xa3, 0x0c, 0x3b, 0x72, 0x43, 0xe9, 0x77, 0xff, 0xfd, 0x59, 0xc8, 0x0f, 0x1c, 0x5c, 0x9a, 0xf4, 0xbb, 0x5a, 0x54, 0xb9, 0xc7, 0x86, 0xfb, 0xbe, 0x8d, 0x21, 0xb2, 0xb9, 0x06, 0xa8, 0x7a, 0x78, 0x6c, 0xae, 0xd8, 0x41, 0xa3, 0x4a, 0x3e, 0x0c, 0xc0, 0xac, 0x32, 0x09, 0xd8, 0x3c, 0x58, 0xaf, 0xba, 0x19, 0xed, 0xd6, 0x36, 0x22, 0xdd, 0x26, 0x15, 0x32, 0xd2, 0xcf, 0xb0, 0xb4, 0x9d, 0x52, 0x7d, 0x8e, 0xaa, 0x08, 0x87, 0xa0, 0x87, 0xf5, 0x12, 0x9d, 0x89, 0x7f, 0x66, 0x52, 0x64, 0xb2, 0x29, 0xf8, 0x60, 0x36, 0x3d, 0x71, 0xa8, 0x8b, 0x7d, 0x49, 0xc8, 0xdc, 0x63, 0x60, 0x18, 0x2b, 0x35, 0x7b, 0x06, 0x62, 0x39, 0x1b, 0xb4, 0x13, 0x37, 0xf4, 0x60, 0x10, 0xac, 0x32, 0xb9, 0xfa, 0xda, 0x2d, 0x60, 0xa2, 0xef, 0xcb, 0x99, 0x36, 0x5d, 0x3b, 0x27, 0xb7, 0xac, 0x39, 0x69, 0x00, 0xd1, 0xc8, 0x21, 0xd0, 0xdf, 0x8b, 0x86, 0xcc, 0x9c, 0xc1, 0xf2, 0x67, 0x32, 0x59, 0xa3, 0x3e, 0xfe, 0xa6, 0x10, 0xbf, 0x8e, 0x1d, 0x00, 0xd7, 0xe9, 0xdb, 0x2a, 0xfe, 0xa2, 0x1d, 0xa8, 0xf5, 0x8c, 0x55, 0xf7, 0x99, 0x99, 0x9d),
-//            //   aad: vec!(0xc8, 0x53, 0xa8, 0xb3, 0x9c, 0x0d, 0xc5, 0x97, 0xd5, 0x62, 0xf1, 0x23, 0xcd, 0x22, 0x1e, 0x41, 0x04, 0xb6, 0x54, 0x23, 0xa0, 0x62, 0xa4, 0xf4, 0xba, 0x89, 0x0b, 0xa3, 0x44, 0xfe, 0xb8, 0x42, 0x90, 0xf6, 0x18, 0x17, 0xe2, 0x33, 0x30, 0xc3, 0x65, 0xf5, 0x8c, 0x35, 0x83, 0xce, 0x08, 0x36, 0x0d, 0x3c, 0x11, 0x71, 0x98, 0x2e, 0xad, 0x54, 0x96, 0xd5, 0x25, 0xac, 0x87, 0x8f, 0x23, 0xa5, 0x74, 0x80, 0xa6, 0xee, 0x39, 0xd4, 0xe6, 0x5a, 0xfd, 0x62, 0x68, 0x24, 0x5b, 0xb9, 0x82, 0xa2, 0x54, 0x5f, 0xa1, 0x19, 0x54, 0x27, 0xcd, 0xbb, 0xcd, 0x40, 0x4c, 0xda, 0xd5, 0x19, 0x8f, 0x55, 0xcc, 0xe2, 0xa5, 0xa0, 0x28, 0xfa, 0xe4, 0x35, 0xf7, 0x1b, 0x15, 0x92, 0x1d, 0x06, 0x6e, 0x8d, 0x43, 0x76, 0x6c, 0x32, 0xb2, 0xf2, 0xc3, 0xf5, 0x7c, 0x06, 0x74, 0xe1, 0x29, 0x60, 0x7d, 0xcd, 0x37, 0x03, 0xec, 0xa5, 0x29, 0x41, 0x4a, 0xda, 0xee, 0x79, 0xd8, 0x1f, 0xed, 0x43, 0x21, 0x53, 0xcc, 0xeb, 0x6f, 0x3f, 0xc5, 0x34, 0x04, 0x81, 0x0d, 0x8e, 0xc8, 0x78, 0xf7, 0xd9, 0x4b, 0xe5, 0xd3, 0x79, 0xd0, 0xe0, 0xe1, 0xaa, 0x9b, 0xc4, 0x04, 0xb4, 0xb5, 0xd3, 0x96, 0x03, 0x8a, 0x9d, 0x76, 0xa5, 0xce, 0x53, 0xc9, 0xf3, 0x75, 0x9b, 0x8e, 0x50, 0xfb, 0x33, 0x18, 0x58, 0xca, 0x58, 0xce, 0xe8, 0x1b, 0xfc, 0x3e, 0xe5, 0x8b, 0xae, 0xf5, 0xd1, 0x9c, 0x40, 0x2a, 0x3d, 0xc8, 0xb3, 0x63, 0x70, 0xec, 0x1a, 0xce, 0x5a, 0x4a, 0xa2, 0x52, 0x7f, 0xb9, 0x4b, 0x4f, 0x93, 0x3a, 0x4a, 0xb8, 0xcc, 0xaa, 0xf6, 0xa5, 0xaf, 0x5a, 0x77, 0x9e, 0xae, 0x56, 0x67, 0xc2, 0xa2, 0x4a, 0xb0, 0x27, 0xe7, 0x81, 0xc8, 0xd4, 0xf3, 0x0c, 0x37, 0x7a, 0xa5, 0x88, 0x5a, 0x2f, 0xda, 0xf6, 0x50, 0x7d, 0x18, 0xcd, 0x82, 0x4a, 0x84, 0x7c, 0x35, 0x36, 0x8b, 0x4e, 0xa9, 0x84, 0xd2, 0xc3, 0xc3, 0x82, 0x4a, 0x5b, 0x8b, 0xa3, 0x04, 0x2e, 0x18, 0x52, 0x50, 0x4a, 0x21, 0xa3),
-//            //   cipher_text: vec!(0xf2, 0xe2, 0x10, 0x52, 0xee, 0xbb, 0xb8, 0x6a, 0x4f, 0x5e, 0x80, 0x33, 0x60, 0x85, 0x5d, 0x86, 0x32, 0xaa, 0x72, 0x7d, 0xca, 0x6f, 0x5e, 0x79, 0xdd, 0x74, 0xd7, 0xaf, 0xf1, 0x06, 0xe4, 0x42, 0x00, 0x19, 0x28, 0xd1, 0x13, 0x00, 0x5b, 0x03, 0x0f, 0x84, 0x46, 0xf8, 0xef, 0xf2, 0xee, 0x95, 0x1d, 0xb6, 0x63, 0x97, 0x8a, 0xbe, 0x43, 0x09, 0x0d, 0xd5, 0xad, 0x2c, 0x51, 0xba, 0x97, 0xa0, 0xec, 0xf9, 0x88, 0xc6, 0x07, 0xd9, 0x5e, 0x48, 0x6d, 0x02, 0x52, 0x4f, 0x69, 0x0f, 0xa3, 0xc2, 0x8d, 0x5c, 0x48, 0xc1, 0xf7, 0x5c, 0x1f, 0x55, 0x5e, 0x7b, 0x43, 0xfe, 0x7e, 0x46, 0xf2, 0xca, 0x2b, 0x9f, 0xdb, 0x40, 0x8e, 0xc4, 0xba, 0x18, 0xb6, 0xcd, 0xde, 0x2a, 0xf6, 0x73, 0x18, 0x3c, 0xb7, 0xb1, 0xa3, 0xc2, 0x3a, 0xe7, 0x7e, 0xdd, 0xd4, 0xca, 0xc7, 0x5e, 0x1e, 0xa1, 0x47, 0x43, 0xfc, 0x57, 0x1f, 0x8d, 0x31, 0xce, 0x2e, 0x96, 0x78, 0x75, 0x24, 0xcd, 0x48, 0xaa, 0xda, 0xa4, 0x74, 0x18, 0x1c, 0x09, 0x6a, 0x03, 0x21, 0x84, 0x57, 0x4d, 0xdc, 0x25, 0xa6, 0xe0, 0xac, 0x84, 0x41, 0xc2, 0x12, 0xbc, 0x36, 0x29, 0x87, 0x08, 0xe3, 0x3c, 0x96, 0x3a, 0xe9, 0x31, 0xe6, 0xc6, 0x24, 0x1d, 0x1a, 0xff, 0xee, 0xf7, 0xb6, 0xef, 0x75, 0x94, 0x95, 0xdf, 0x44, 0xb6, 0xab, 0x64, 0x74, 0x47, 0x69, 0x3c, 0xf7, 0x03, 0x56, 0x9e, 0x69, 0xaa, 0x72, 0xf1, 0xde, 0xf9, 0xa3, 0x42, 0xb8, 0x97, 0x8c, 0x1e, 0xde, 0xa9, 0x70, 0x3a, 0x42, 0x1c, 0xa7, 0x5b, 0x92, 0xca, 0xc4, 0xde, 0x14, 0xb8, 0x8c, 0x69, 0x32, 0x00, 0x02, 0x2b, 0x8a, 0x2e, 0xd2, 0x2b, 0x1c, 0x46, 0x78, 0xb9, 0x9f, 0x4d, 0x69, 0x5e, 0x08, 0x0d, 0xd1, 0x19, 0x6d, 0x71, 0x68, 0xe1, 0x4f, 0x0d, 0x0f, 0x8f, 0xf8, 0x80, 0xd7, 0x42, 0xe9, 0x7b, 0x9f, 0x6d, 0x00, 0xaf, 0x1f, 0x71, 0x18, 0xe1, 0x0b, 0x77, 0xc5, 0xef, 0x3e, 0xa6, 0xc5, 0x2f, 0x84, 0xa2, 0x0f, 0xd6, 0xea, 0x46, 0xdc),
-//            //   tag: vec!(0xfa, 0x8e, 0xe1, 0x34, 0x00, 0xfb, 0x3f, 0x63, 0xb8, 0x99, 0xdf, 0x58, 0x2f, 0x2f, 0xec, 0x45)
-//            // },
-//            // TestVector{
-//            //   key: [0xdf, 0x73, 0xad, 0xab, 0x27, 0x68, 0x55, 0x9e, 0xa9, 0x83, 0xcc, 0xe8, 0x54, 0x53, 0xfe, 0x81, 0xd7, 0x9b, 0xe3, 0xb3, 0xc5, 0x7f, 0x20, 0x2b, 0x31, 0xb9, 0x4d, 0x66, 0x35, 0xcf, 0x2e, 0x4b],
-//            //   nonce: [0xe7, 0xa8, 0x7e, 0x6b, 0xf6, 0xb5, 0xa3, 0x54],
-//            //   plain_text: vec!(0x00, 0x32, 0xa3, 0x7a, 0xbf, 0x66, 0x1f, 0xaa, 0x18, 0xc5, 0x87, 0xfd, 0x2a, 0xa8, 0x88, 0x85, 0xc0, 0x61, 0xde, 0xeb, 0xa8, 0x11, 0x05, 0xdd, 0x22, 0x19, 0x69, 0xbe, 0xd5, 0xd5, 0x9c, 0x72, 0x04, 0xb0, 0x9b, 0x1a, 0x8c, 0x4c, 0x8d, 0xe3, 0xb9, 0xf7, 0x48, 0xc7, 0xfc, 0x70, 0x62, 0x6e, 0xbe, 0xac, 0xa0, 0x60, 0x23, 0x3a, 0x57, 0xb1, 0x02, 0x22, 0x1b, 0x1b, 0xf0, 0xf3, 0xd9, 0xfd, 0xaa, 0xad, 0x3d, 0x2b, 0x14, 0x39, 0xc2, 0x4d, 0x08, 0xf9, 0xc6, 0x7f, 0x49, 0xf3, 0xc4, 0x71, 0x28, 0xf9, 0x2e, 0xe5, 0x30, 0xab, 0xf4, 0xc4, 0xf4, 0x57, 0x3b, 0xc6, 0x0a, 0xe4, 0xb3, 0x81, 0x09, 0xf5, 0x5b, 0xca, 0x3c, 0xa9, 0xe1, 0xba, 0x9f, 0x9f, 0xd6, 0xe3, 0x4b, 0xa0, 0xd1, 0x74, 0x89, 0x29, 0x77, 0xa5, 0x33, 0x56, 0xe1, 0xf5, 0xc8, 0x8c, 0x61, 0x4f, 0xe3, 0xff, 0x3b, 0x3d, 0xd0, 0x81, 0x8e, 0x7a, 0x22, 0x85, 0x41, 0x2e, 0x3b, 0x37, 0x44, 0x4b, 0xbe, 0x8a, 0x80, 0x94, 0x2e, 0xfc, 0xfd, 0x03, 0x95, 0x88, 0x09, 0xa6, 0x96, 0x6c, 0xda, 0x94, 0x30, 0xb2, 0xf0, 0xc9, 0xe5, 0x52, 0xf4, 0xbc, 0xed, 0x6e, 0x19, 0xeb, 0x3e, 0x85, 0xfc, 0x57, 0x58, 0xbd, 0x7b, 0x58, 0x82, 0x97, 0xcc, 0xbe, 0xd3, 0x7e, 0xd9, 0x4c, 0x3a, 0xdc, 0x8c, 0x08, 0xea, 0x8b, 0x05, 0x84, 0x62, 0xaa, 0xc9, 0xd5, 0x7a, 0x93, 0x9e, 0xc7, 0x11, 0xbc, 0x4e, 0xcf, 0xec, 0x94, 0x4d, 0x2b, 0x65, 0x3b, 0x7c, 0xfc, 0x7b, 0x02, 0xa6, 0x5d, 0x70, 0x57, 0xc9, 0xfd, 0xad, 0xd5, 0x1b, 0x9d, 0xa8, 0xcc, 0x4a, 0x3c, 0x68, 0xda, 0xe9, 0xda, 0x8b, 0x9c, 0x53, 0x19, 0xc1, 0xa2, 0xba, 0xa3, 0xd6, 0xc8, 0x91, 0xc5, 0xac, 0x4a, 0x39, 0x46, 0x14, 0x84, 0xb5, 0xa0, 0x1a, 0xbc, 0x64, 0xdf, 0x44, 0x7a, 0xda, 0x24, 0xc0, 0x4a, 0x43, 0x63, 0xe6, 0x05, 0xea, 0xcc, 0xf3, 0x39, 0xa9, 0xaa, 0x51, 0x5e, 0x72, 0x42, 0x06, 0x20, 0x6d, 0xa6, 0xd2, 0x2b, 0xbd, 0x2f, 0x52, 0xe6, 0x4c, 0xd7, 0xc8, 0x95),
-//            //   aad: vec!(0xf8, 0x33, 0xe5, 0xab, 0x4f, 0x8b, 0xc8, 0x91, 0x67, 0xf8, 0x0f, 0x57, 0x6b, 0x1d, 0x6b, 0x22, 0xcd, 0xd0, 0xe3, 0x07, 0x21, 0xf5, 0xf7, 0x35, 0x79, 0x97, 0x46, 0xcf, 0x64, 0x5b, 0x6e, 0xff, 0x53, 0x1d, 0x4c, 0x7b, 0x03, 0x58, 0x4f, 0x3d, 0xfc, 0xb7, 0x3c, 0xbd, 0x35, 0xac, 0x42, 0x73, 0x62, 0x16, 0xdc, 0x7f, 0x0d, 0xe0, 0x98, 0xa4, 0xf4, 0x2c, 0x61, 0xce, 0xb4, 0xb2, 0x27, 0xee, 0x28, 0x8e, 0x47, 0xd6, 0x97, 0xa0, 0xa7, 0x6a, 0xfc, 0x76, 0x2f, 0x08, 0x4e, 0x8f, 0xdb, 0xf9, 0x35, 0x1c, 0x28, 0x34, 0x0c, 0x32, 0x47, 0x71, 0xc1, 0x09, 0xa4, 0x69, 0x34, 0x1a, 0xb1, 0x0c, 0xa1, 0x04, 0x83, 0xed, 0x2a, 0xf5, 0xe8, 0x78, 0xd7, 0xd3, 0xdc, 0x2b, 0xce, 0xd2, 0xf7, 0x2d, 0xa3, 0xd1, 0xa2, 0x58, 0x52, 0xb1, 0x03, 0xee, 0x98, 0x78, 0xe8, 0x15, 0x8e, 0xb4, 0x30, 0x9c, 0x1c, 0xe5, 0x28, 0xf3, 0xa1, 0x78, 0xac, 0xe1, 0x53, 0xb6, 0xd3, 0xae, 0x0a, 0xf0, 0xd5, 0x77, 0xcb, 0x3c, 0xb1, 0x54, 0x04, 0x89, 0xe8, 0x04, 0x27, 0xf7, 0x92, 0x21, 0x7a, 0xd8, 0xa0, 0x9b, 0x84, 0xf0, 0x27, 0xfc, 0xa7, 0xce, 0xb6, 0x51, 0xb4, 0x26, 0x4e, 0x98, 0xe9, 0x4b, 0x4c, 0xb8, 0xa3, 0x7b, 0x13, 0x33, 0x90, 0x89, 0x72, 0x33, 0xe8, 0xba, 0x91, 0x03, 0x62, 0x8d, 0x05, 0xb9, 0x60, 0x9e, 0x85, 0x52, 0xc4, 0xa4, 0xb1, 0x1e, 0x3f, 0x2f, 0xa8, 0xd5, 0x6a, 0xf3, 0x69, 0x57, 0x39, 0x0e, 0x88, 0xcb, 0xa4, 0x46, 0x56, 0xbe, 0x3e, 0xda, 0xce, 0x79, 0x8c, 0xf8, 0xcd, 0xf7, 0x77, 0x1b, 0xac, 0x33, 0x8a, 0x25, 0x6b, 0xc3, 0xcb, 0xa6, 0xdf, 0x97, 0x72, 0x8f, 0x22, 0x2f, 0x42, 0x3c, 0xa7, 0xc6, 0xd1, 0x49, 0xc9, 0x37, 0x2d, 0x66, 0x16, 0x3a, 0x98, 0xf7, 0x9a, 0x23, 0x4b, 0x00, 0xd4, 0xb7, 0x5f, 0xb2, 0xec, 0x86, 0x0d, 0xcc, 0x2d, 0x19, 0x98, 0x10, 0x5e, 0x4b, 0x9c, 0x01, 0xd6, 0x8f, 0x07, 0x9f, 0x3e, 0x0a, 0xa2, 0x1c, 0xc5, 0x34, 0x04, 0x7f, 0xc7, 0xb8, 0x58, 0xf8),
-//            //   cipher_text: vec!(0xb8, 0x42, 0xea, 0xdf, 0xdf, 0x43, 0x1c, 0x13, 0x5b, 0xd6, 0x58, 0x1d, 0x3e, 0xcc, 0xae, 0x54, 0xe2, 0x26, 0x7d, 0x88, 0x90, 0x03, 0x6a, 0xa3, 0x3d, 0xfe, 0x2d, 0x2d, 0x97, 0x15, 0xc4, 0x46, 0x25, 0x44, 0x12, 0x10, 0xa3, 0xa0, 0xd6, 0x66, 0xd7, 0x08, 0xd3, 0x05, 0x88, 0xfe, 0x85, 0x1e, 0xc3, 0x6e, 0x10, 0xd8, 0xfa, 0x35, 0x84, 0xed, 0x77, 0xb0, 0x95, 0x14, 0x94, 0x94, 0xb7, 0xc5, 0x43, 0x79, 0xd6, 0x2c, 0x89, 0x35, 0xe1, 0xd2, 0xb9, 0xa8, 0xf4, 0x7e, 0x47, 0x59, 0xad, 0x0b, 0x34, 0x37, 0xfd, 0xf2, 0xcc, 0x2f, 0xb6, 0xc5, 0xea, 0x25, 0xad, 0x10, 0xe0, 0xbd, 0xc9, 0xdc, 0x5b, 0x05, 0x17, 0xfc, 0x23, 0x7e, 0xb7, 0x83, 0xcc, 0x46, 0x1c, 0x46, 0x66, 0x5e, 0x2b, 0x1d, 0x1a, 0x5b, 0x80, 0x08, 0xdb, 0xf4, 0x09, 0xea, 0x2a, 0x63, 0xfe, 0xa0, 0x27, 0x6d, 0xe2, 0x3a, 0x32, 0xc9, 0x9d, 0x92, 0xa4, 0x98, 0x80, 0x7a, 0x0f, 0x95, 0xe2, 0x08, 0xfc, 0x62, 0x62, 0x32, 0x1a, 0x78, 0xaa, 0xfa, 0xf0, 0xcc, 0x3f, 0x83, 0x3f, 0xff, 0x37, 0xbd, 0x4e, 0xfa, 0x66, 0xf6, 0x02, 0x3a, 0x25, 0xcd, 0xc6, 0x70, 0x2c, 0xee, 0x39, 0x12, 0x79, 0x95, 0x63, 0xd9, 0x08, 0xa5, 0x18, 0x3c, 0x99, 0x56, 0xa0, 0x6a, 0xa7, 0x10, 0x85, 0xd8, 0x55, 0xdc, 0x7c, 0x80, 0x9e, 0xd6, 0xe2, 0x88, 0x95, 0x92, 0xb3, 0x61, 0xab, 0x3a, 0xb3, 0x90, 0x60, 0xf8, 0xe4, 0x19, 0x15, 0x21, 0x87, 0xa7, 0x94, 0xa1, 0x9c, 0x2a, 0x11, 0x28, 0x88, 0x22, 0x01, 0x90, 0x0e, 0xa2, 0xcd, 0x59, 0x78, 0x60, 0x67, 0x4b, 0xf7, 0x8d, 0x97, 0x20, 0x64, 0x3d, 0xf8, 0x70, 0x16, 0x76, 0x71, 0x8f, 0xd2, 0x01, 0xba, 0xed, 0x49, 0x35, 0xa8, 0x8e, 0x50, 0x55, 0x8d, 0xaf, 0x86, 0xed, 0xd0, 0x8a, 0x9a, 0xb2, 0x27, 0xac, 0x7a, 0xfa, 0xe5, 0x5c, 0x97, 0x4b, 0x68, 0xde, 0x8d, 0xac, 0xad, 0x4a, 0x4d, 0x79, 0xb1, 0x3e, 0xd6, 0xdf, 0xe7, 0x40, 0x17, 0xa4, 0xcb, 0x91, 0x48, 0xe0, 0x33, 0x43, 0x6f, 0xb6),
-//            //   tag: vec!(0x18, 0x40, 0x95, 0xb7, 0xa8, 0x19, 0x0a, 0xbe, 0xc0, 0x8b, 0xb7, 0x2d, 0x19, 0xee, 0xb1, 0x03)
-//            // },
-//            // TestVector{
-//            //   key: [0x55, 0xa4, 0xbe, 0x24, 0x48, 0xb4, 0x64, 0xc2, 0xea, 0x52, 0xa2, 0xf2, 0x66, 0x4e, 0xd6, 0xab, 0xa8, 0x65, 0xc1, 0x4e, 0xa1, 0xfe, 0xa7, 0x7f, 0x46, 0x89, 0x33, 0x1f, 0xd1, 0x05, 0xc8, 0xd4],
-//            //   nonce: [0xdb, 0x37, 0xc0, 0xa4, 0x05, 0xb4, 0x62, 0x6d],
-//            //   plain_text: vec!(0xd2, 0x66, 0xe6, 0x62, 0x72, 0xe5, 0xd3, 0x46, 0x20, 0x81, 0xb0, 0x04, 0xcb, 0x42, 0x42, 0x9c, 0x8b, 0x97, 0x41, 0xe9, 0xf6, 0x78, 0x15, 0x37, 0x54, 0xd7, 0x26, 0xf6, 0xf9, 0xaa, 0x51, 0x34, 0x64, 0x76, 0x3c, 0x5e, 0x79, 0x3b, 0x48, 0x2f, 0xe5, 0x12, 0xfe, 0xce, 0x97, 0x58, 0x5f, 0x14, 0x26, 0x12, 0x0d, 0x4c, 0xef, 0xb3, 0xd0, 0xa8, 0xcc, 0x0a, 0x8d, 0xb4, 0xbd, 0xe9, 0x3f, 0xc7, 0x2c, 0x78, 0xf4, 0x4d, 0x4f, 0xec, 0xca, 0x14, 0x65, 0x0c, 0x66, 0x0d, 0x3e, 0x28, 0x5b, 0x32, 0x7e, 0x7c, 0xdd, 0x81, 0x30, 0x63, 0xe7, 0xe8, 0x67, 0xb8, 0xa2, 0xd0, 0x59, 0xa4, 0x1b, 0xab, 0x70, 0x43, 0x2b, 0x7f, 0x85, 0x71, 0x99, 0x89, 0x4d, 0xa9, 0x0d, 0xca, 0x3f, 0xe5, 0x27, 0x2b, 0xae, 0x1e, 0xc6, 0x94, 0xa1, 0xa0, 0x7b, 0x60, 0xb0, 0x5d, 0xf2, 0x75, 0x78, 0x4d, 0x49, 0x75, 0x63, 0x7e, 0x46, 0x73, 0x10, 0x9f, 0x3b, 0xa8, 0x46, 0xdf, 0xd1, 0xa0, 0x48, 0xb2, 0x02, 0xed, 0x8e, 0x89, 0x97, 0x3b, 0xe6, 0x08, 0xb9, 0x1e, 0xe4, 0x74, 0x3b, 0x1e, 0x75, 0x99, 0x00, 0xf1, 0x44, 0x30, 0x38, 0x95, 0x1f, 0xe6, 0x18, 0x9e, 0x80, 0x66, 0x38, 0x98, 0x5f, 0x3c, 0x16, 0x33, 0x8c, 0x3c, 0x60, 0x69, 0x5d, 0xf5, 0x8e, 0x62, 0x11, 0x54, 0xd7, 0x9b, 0xb9, 0x73, 0x85, 0x9c, 0x45, 0x58, 0xe9, 0xdc, 0xa9, 0x04, 0x70, 0xf7, 0x7c, 0x73, 0xf0, 0x04, 0x44, 0x3a, 0xd5, 0xdb, 0x07, 0x17, 0xab, 0xbe, 0x43, 0x26, 0x6f, 0x90, 0xe5, 0x73, 0x97, 0xb8, 0x3a, 0xc3, 0x4d, 0x1f, 0xef, 0x2e, 0x89, 0x7e, 0x24, 0x83, 0xd5, 0xbc, 0xdc, 0xb6, 0x27, 0xab, 0xd6, 0x4b, 0x0d, 0x1a, 0xef, 0x52, 0x58, 0x35, 0xf2, 0x5e, 0x76, 0xd6, 0xe9, 0x15, 0x82, 0x32, 0xcd, 0xde, 0x6d, 0xce, 0x97, 0x0b, 0x59, 0xf5, 0x8d, 0xe8, 0xa9, 0x8e, 0x65, 0x3b, 0xe3, 0x2f, 0xb5, 0x8e, 0xda, 0xbb, 0xce, 0xfa, 0x50, 0x65, 0xd7, 0x3a, 0xfd, 0xf1, 0xc9, 0xc4, 0xfb, 0xf5, 0x0c, 0x10, 0x22, 0xbd, 0x22, 0xbf, 0xcb, 0x98, 0xe4, 0xb4, 0x22),
-//            //   aad: vec!(0xfd, 0x6a, 0x3f, 0xdd, 0x87, 0x9f, 0x88, 0x80, 0x84, 0x3e, 0xac, 0x20, 0xae, 0x01, 0xc1, 0xb9, 0xdc, 0x34, 0x87, 0xd2, 0x70, 0xa8, 0x06, 0x57, 0x20, 0x88, 0xef, 0x2d, 0xdc, 0x1f, 0x1e, 0x0d, 0xe4, 0x95, 0xe7, 0x1d, 0x48, 0x13, 0xbf, 0x5c, 0x50, 0x1a, 0xd3, 0x1e, 0x5d, 0x79, 0x1c, 0x4b, 0x5b, 0x3a, 0x0a, 0x71, 0xb6, 0x3f, 0xdd, 0xdc, 0xc8, 0xde, 0x4b, 0x05, 0x60, 0x64, 0xef, 0x46, 0x79, 0x89, 0xec, 0xcc, 0xc5, 0xd0, 0x16, 0x0d, 0x40, 0x3b, 0xf3, 0xa0, 0x25, 0xd4, 0x89, 0x2b, 0x3b, 0x1d, 0xe3, 0xe0, 0x62, 0xbc, 0x35, 0x81, 0xd4, 0x41, 0x0f, 0x27, 0x33, 0x38, 0x31, 0x1e, 0xb4, 0x63, 0x75, 0x29, 0xe4, 0xa6, 0x80, 0xa6, 0xe4, 0xa5, 0xe2, 0x6e, 0x30, 0x86, 0x30, 0xa5, 0xb6, 0xd4, 0x9e, 0xad, 0x6d, 0x54, 0x3f, 0x8f, 0x2b, 0xf9, 0x05, 0x0a, 0xa9, 0x4c, 0xe0, 0x91, 0x31, 0x87, 0x21, 0xe1, 0xd8, 0xb9, 0x6e, 0x27, 0x9f, 0x34, 0xb9, 0x75, 0x9b, 0x65, 0x03, 0x7b, 0xec, 0x4b, 0xf6, 0xcc, 0xda, 0x69, 0x29, 0x70, 0x5a, 0xee, 0xee, 0xbe, 0x49, 0xe3, 0x27, 0xe4, 0xd7, 0xa9, 0x16, 0x62, 0x0c, 0x9f, 0xaf, 0x37, 0x65, 0x12, 0x06, 0x58, 0xaf, 0x34, 0xc5, 0x3f, 0xbb, 0x97, 0xec, 0x07, 0x65, 0x7b, 0x3f, 0x08, 0x8f, 0xcb, 0xdc, 0x40, 0x1a, 0xa7, 0x94, 0x9d, 0xde, 0xda, 0x34, 0xd8, 0x85, 0x01, 0x8c, 0x2c, 0x23, 0xf4, 0xf0, 0xbb, 0x82, 0x18, 0xbf, 0x0d, 0x4f, 0xc9, 0x06, 0x43, 0x65, 0x8b, 0x4d, 0x88, 0x34, 0xf4, 0xa8, 0xc0, 0x8e, 0x59, 0x0c, 0x2a, 0x79, 0x09, 0x95, 0xba, 0xa9, 0xe7, 0x76, 0x27, 0xc3, 0x42, 0xd2, 0x83, 0xe4, 0x54, 0xf8, 0x4f, 0xcc, 0x05, 0xbe, 0x15, 0xe9, 0x62, 0x7a, 0x2d, 0x9b, 0xe3, 0x40, 0xc9, 0xd7, 0x2f, 0x22, 0x2b, 0xbd, 0xfc, 0x47, 0x90, 0x5f, 0x56, 0x61, 0x6c, 0xd9, 0xf9, 0x36, 0xd4, 0x9e, 0x47, 0x32, 0xf3, 0x19, 0xf0, 0x20, 0x51, 0x33, 0x40, 0xfb, 0x8b, 0x22, 0x82, 0x8d, 0xb2, 0x51, 0xb1, 0x02, 0xb6, 0xb1, 0x37, 0xc9, 0x53, 0x39, 0x36, 0xd6),
-//            //   cipher_text: vec!(0xbd, 0x11, 0xed, 0x07, 0xb7, 0xb4, 0xb3, 0x0e, 0xea, 0xf2, 0x5d, 0x6a, 0x41, 0xa5, 0x49, 0xcc, 0xa0, 0xa5, 0xae, 0xe7, 0x1f, 0x99, 0x0a, 0xc5, 0x66, 0xa3, 0x72, 0x65, 0xd7, 0xaf, 0x2c, 0xe3, 0xc0, 0x37, 0x03, 0x42, 0x7e, 0xe0, 0xb2, 0x75, 0x5c, 0x2b, 0xdf, 0xc2, 0x9f, 0x9d, 0x82, 0x6a, 0xec, 0x6e, 0xe4, 0xad, 0x28, 0xaf, 0x48, 0x07, 0x9a, 0xc2, 0x3d, 0xb1, 0x65, 0x80, 0xb9, 0x74, 0x24, 0xf3, 0xa4, 0xe3, 0x5c, 0xc2, 0x36, 0x25, 0xd3, 0x9f, 0x95, 0x69, 0x9d, 0x9f, 0xf5, 0x14, 0x3e, 0x9a, 0x2b, 0xc2, 0x6f, 0xcf, 0xee, 0x4f, 0x12, 0x5f, 0x5a, 0xa2, 0xd9, 0x68, 0xcc, 0xfc, 0x2f, 0xaa, 0xf9, 0xdb, 0x3c, 0x28, 0x85, 0x0f, 0x67, 0x57, 0xf7, 0x35, 0xcb, 0xc5, 0x0c, 0x94, 0xc4, 0x98, 0xbc, 0xde, 0x4f, 0x23, 0xbf, 0xfa, 0xfa, 0x8d, 0xd5, 0xf7, 0x0d, 0x1a, 0x01, 0x1e, 0x35, 0xeb, 0x26, 0xe9, 0x05, 0xd4, 0xe6, 0x88, 0x48, 0xfe, 0xde, 0xbe, 0xb1, 0x97, 0xbe, 0x59, 0x5c, 0x08, 0x5b, 0xa3, 0x3f, 0x11, 0xba, 0x83, 0x98, 0x25, 0x84, 0x45, 0x05, 0x17, 0x51, 0x88, 0x8e, 0x9b, 0xba, 0x11, 0x1f, 0x80, 0x0f, 0x31, 0xb3, 0x7c, 0x44, 0x70, 0x74, 0xca, 0x6d, 0xce, 0x6d, 0x54, 0xb4, 0xdf, 0xad, 0x6c, 0xee, 0x51, 0x38, 0x64, 0x3d, 0x4f, 0x6a, 0xc0, 0x45, 0xe8, 0x04, 0x72, 0x48, 0x92, 0x4e, 0x88, 0xea, 0x42, 0x94, 0xc7, 0x87, 0x8b, 0xc2, 0x2c, 0x9b, 0x41, 0x92, 0x4c, 0xe3, 0x01, 0xf2, 0x26, 0x93, 0xc3, 0x37, 0x33, 0x10, 0x7b, 0xf1, 0xba, 0x85, 0xe3, 0x48, 0x06, 0xc5, 0xe4, 0x36, 0x6e, 0xa6, 0x6f, 0xc5, 0x2a, 0x5f, 0x89, 0xdd, 0x9b, 0xf2, 0x13, 0x23, 0x91, 0x58, 0xb3, 0xd4, 0xd2, 0x60, 0x0d, 0xde, 0x69, 0x6c, 0x61, 0xd7, 0x6c, 0x39, 0x8b, 0x9b, 0xf1, 0x0d, 0xe9, 0x11, 0x8e, 0x81, 0x2e, 0x89, 0x1c, 0x8f, 0x33, 0x55, 0xc0, 0xec, 0xc6, 0x40, 0x5f, 0x79, 0xbc, 0x32, 0xa5, 0x89, 0x05, 0xe3, 0x78, 0x88, 0xa1, 0xd8, 0x39, 0x5f, 0xbe, 0xdc, 0x3a, 0xc5, 0x4e, 0xca, 0x56, 0x9f),
-//            //   tag: vec!(0xf7, 0xd3, 0xb5, 0x8a, 0x34, 0xa8, 0x6e, 0x99, 0x26, 0x7e, 0x5d, 0xb2, 0x06, 0xf1, 0x7b, 0xbe)
-//            // },
-//            // TestVector{
-//            //   key: [0x33, 0x04, 0xe4, 0x91, 0x7a, 0xd7, 0x77, 0x7b, 0x86, 0xc2, 0x6a, 0x63, 0x62, 0x92, 0xc9, 0xcc, 0x4c, 0x10, 0xd3, 0x20, 0x03, 0xc4, 0x9e, 0x07, 0x20, 0x9e, 0xb0, 0xef, 0x85, 0x05, 0x03, 0x1a],
-//            //   nonce: [0x4d, 0x57, 0x2d, 0x11, 0x6f, 0xbd, 0x8c, 0x4d],
-//            //   plain_text: vec!(0x2f, 0x24, 0x2c, 0x2b, 0xa3, 0x37, 0x90, 0xec, 0xef, 0x86, 0x2b, 0x0e, 0x07, 0x7f, 0xf8, 0xb1, 0x5e, 0xb9, 0xd1, 0x0c, 0xf2, 0xff, 0x62, 0x1e, 0xd6, 0x59, 0x02, 0x49, 0x44, 0x31, 0xdc, 0xbd),
-//            //   aad: vec!(0xe6, 0x99, 0xbb, 0xf2, 0x50, 0xcd, 0xd9, 0x3d, 0x22, 0x9d, 0x07, 0x40, 0xe4, 0x33, 0x89, 0x7e, 0x2d, 0x19, 0x13, 0x2e, 0x2b, 0x72, 0x2d, 0xf8, 0xb6, 0x9b, 0xb6, 0xa7, 0xc2, 0xcf, 0x3b, 0x93),
-//            //   cipher_text: vec!(0xfb, 0x81, 0xe3, 0x04, 0x36, 0xe4, 0x37, 0xc7, 0xf6, 0x86, 0xf8, 0x6b, 0x1b, 0x65, 0xc7, 0x35, 0x49, 0xa9, 0xd0, 0x9d, 0xb8, 0x10, 0xd3, 0x20, 0x78, 0x5c, 0x36, 0x34, 0x93, 0x41, 0x50, 0xb3),
-//            //   tag: vec!(0x8b)
-//            // },
-//            // TestVector{
-//            //   key: [0xed, 0x60, 0x57, 0xbb, 0x16, 0x3f, 0x16, 0x09, 0xff, 0x28, 0xb9, 0x38, 0x12, 0x2f, 0x49, 0x5e, 0x3d, 0x5a, 0xe4, 0xec, 0x3d, 0xbd, 0x74, 0x56, 0xc9, 0xb5, 0xc8, 0x2e, 0x28, 0xe9, 0x52, 0xdc],
-//            //   nonce: [0xe6, 0xff, 0x68, 0x52, 0xf3, 0xa3, 0xaf, 0xde],
-//            //   plain_text: vec!(0x3c, 0x50, 0xed, 0xc9, 0x67, 0xeb, 0x0b, 0x3b, 0x23, 0x55, 0xf6, 0x40, 0x0e, 0x0a, 0x03, 0x6e, 0x79, 0x6c, 0x8b, 0x7d, 0x72, 0xc5, 0xe5, 0x83, 0xa8, 0x6e, 0x82, 0x0d, 0x53, 0xe7, 0x6c, 0x43),
-//            //   aad: vec!(0x24, 0x41, 0xdb, 0x55, 0x14, 0x8e, 0x14, 0xe9, 0xe2, 0x41, 0xd6, 0x82, 0x96, 0xeb, 0x60, 0xd5, 0x29, 0x40, 0x8f, 0x05, 0x34, 0x14, 0x30, 0x89, 0x67, 0x1b, 0xce, 0x54, 0x6d, 0xb9, 0x6d, 0x88),
-//            //   cipher_text: vec!(0x6e, 0xca, 0xbc, 0xce, 0xe3, 0x15, 0x19, 0x37, 0x4d, 0x4b, 0xed, 0x11, 0x29, 0x6e, 0x74, 0x83, 0xd1, 0xcb, 0x75, 0x9b, 0xea, 0x3f, 0x44, 0x46, 0xa9, 0x6b, 0xda, 0x8b, 0x4c, 0xa6, 0xd7, 0xac),
-//            //   tag: vec!(0x35, 0x5f)
-//            // },
-//            // TestVector{
-//            //   key: [0x73, 0x56, 0x81, 0x83, 0xc1, 0xf9, 0x72, 0x5a, 0xf3, 0x0e, 0x0f, 0x20, 0x67, 0x60, 0x6c, 0xe8, 0x02, 0xc3, 0xfe, 0x3a, 0xb5, 0xcf, 0xf8, 0xd0, 0x2b, 0x3d, 0xb8, 0xc3, 0x51, 0x76, 0xee, 0x0d],
-//            //   nonce: [0x0b, 0xc9, 0xe1, 0x93, 0x21, 0xb3, 0xd0, 0x0a],
-//            //   plain_text: vec!(0xec, 0x25, 0x90, 0xaf, 0x5c, 0xcd, 0x22, 0x6a, 0x32, 0xff, 0x75, 0x0c, 0x1b, 0x02, 0x9c, 0x11, 0xe3, 0xdd, 0x76, 0xc4, 0x69, 0xa5, 0x57, 0x9d, 0xa9, 0x41, 0x8e, 0x4c, 0x3f, 0xdc, 0x0d, 0x41),
-//            //   aad: vec!(0xdf, 0x30, 0x16, 0x0a, 0xe0, 0xcb, 0xf2, 0xcf, 0x89, 0x92, 0x22, 0x1b, 0xd6, 0x2d, 0xff, 0xe6, 0x91, 0xdd, 0x60, 0x2a, 0xfa, 0x78, 0x4c, 0xa6, 0x91, 0x47, 0x9e, 0x95, 0x7a, 0xf3, 0xac, 0xf1),
-//            //   cipher_text: vec!(0x9e, 0x8d, 0x8a, 0xc3, 0x06, 0x26, 0xf8, 0xb8, 0x31, 0x44, 0x8d, 0x69, 0x76, 0x93, 0x3a, 0xa5, 0xbb, 0x8c, 0x6d, 0xbc, 0x79, 0x4e, 0x1f, 0x4b, 0x7e, 0xeb, 0x0e, 0x4a, 0x59, 0x34, 0x2c, 0x07),
-//            //   tag: vec!(0x9f, 0xd3, 0x6a)
-//            // },
-//            // TestVector{
-//            //   key: [0x27, 0x3b, 0xcb, 0x3f, 0x8c, 0x06, 0x7d, 0xa4, 0xec, 0x34, 0x18, 0x79, 0x9a, 0xd4, 0x0e, 0x7e, 0x4a, 0xee, 0x74, 0xad, 0x7e, 0x62, 0x94, 0x99, 0xd6, 0x46, 0xdf, 0x4a, 0x7e, 0x58, 0x50, 0x25],
-//            //   nonce: [0xf6, 0x0b, 0xe3, 0xeb, 0x89, 0x4b, 0x40, 0x30],
-//            //   plain_text: vec!(0x69, 0x74, 0x98, 0xba, 0x96, 0x4d, 0x5e, 0xf4, 0x01, 0xda, 0x4d, 0x94, 0x84, 0x4f, 0xab, 0x1e, 0xfc, 0x63, 0x5e, 0x71, 0x57, 0xd0, 0x83, 0x1a, 0x32, 0x5b, 0xb5, 0xa4, 0xcf, 0x1f, 0xbd, 0x34),
-//            //   aad: vec!(0x91, 0x29, 0x71, 0x5d, 0xea, 0xb1, 0x4f, 0x02, 0xc7, 0x6b, 0xa8, 0x17, 0x25, 0x71, 0xb1, 0xfa, 0x9d, 0x50, 0x36, 0x5c, 0xd7, 0x95, 0xbf, 0xcc, 0xdf, 0xc2, 0x8e, 0x7e, 0x7b, 0x4f, 0x66, 0xfc),
-//            //   cipher_text: vec!(0xbd, 0x4c, 0xd5, 0xaf, 0x83, 0xbe, 0x1c, 0x13, 0x93, 0x33, 0x02, 0x67, 0x5d, 0x9f, 0xca, 0xf1, 0xc4, 0xca, 0xcd, 0xf2, 0x69, 0xf6, 0xff, 0x44, 0x1d, 0x1e, 0xa2, 0x21, 0x1c, 0x54, 0xe7, 0xed),
-//            //   tag: vec!(0x7a, 0xb1, 0x2a, 0x37)
-//            // },
-//            // TestVector{
-//            //   key: [0xad, 0x39, 0x61, 0x0c, 0x2e, 0x6a, 0x6d, 0x09, 0x61, 0x20, 0x73, 0x90, 0xe0, 0x76, 0xe9, 0x72, 0xc2, 0xed, 0xad, 0xca, 0x88, 0x5c, 0x92, 0x96, 0x5f, 0xa6, 0x48, 0xb2, 0xce, 0x34, 0xfd, 0xbf],
-//            //   nonce: [0xa9, 0x0d, 0xb6, 0x90, 0xbb, 0xa8, 0x3b, 0x78],
-//            //   plain_text: vec!(0x31, 0xc4, 0x9e, 0x3c, 0xd3, 0xd8, 0x0a, 0x82, 0xe6, 0xb9, 0x03, 0x16, 0xdf, 0xb9, 0x4b, 0x38, 0xb8, 0xa2, 0x30, 0x42, 0x51, 0x9b, 0xf4, 0x0c, 0x81, 0x81, 0xfe, 0xc8, 0x73, 0xc9, 0x90, 0x02),
-//            //   aad: vec!(0xdd, 0xbd, 0x7d, 0x82, 0x1d, 0x18, 0xd4, 0x4c, 0x66, 0x29, 0x5a, 0xbf, 0x24, 0x5b, 0x22, 0x7b, 0x5c, 0xf4, 0x36, 0x68, 0x11, 0xb7, 0xb3, 0x4c, 0x07, 0x67, 0x96, 0x00, 0xab, 0xdb, 0xfc, 0x29),
-//            //   cipher_text: vec!(0x94, 0x62, 0x8f, 0xc3, 0x03, 0xa0, 0x54, 0x6e, 0xdd, 0x51, 0xe9, 0x66, 0xf2, 0xbd, 0x87, 0x96, 0x8f, 0x37, 0x80, 0x0c, 0x60, 0x7d, 0x5e, 0x5a, 0x91, 0xf7, 0x27, 0xfc, 0x1f, 0xec, 0x40, 0x6f),
-//            //   tag: vec!(0xc2, 0x2e, 0xc4, 0xe4, 0xc8)
-//            // },
-//            // TestVector{
-//            //   key: [0x29, 0x98, 0x49, 0x54, 0x06, 0x0b, 0xa0, 0x6e, 0xce, 0x1b, 0xcf, 0xc0, 0xe5, 0x01, 0x95, 0xf4, 0x63, 0x2c, 0x6d, 0xf4, 0x8d, 0xa1, 0xe0, 0x2a, 0xe6, 0xc1, 0x4f, 0x70, 0x65, 0x66, 0x89, 0x71],
-//            //   nonce: [0xcc, 0xe5, 0x3a, 0x25, 0xae, 0xea, 0xf7, 0x47],
-//            //   plain_text: vec!(0xb9, 0xb8, 0x74, 0x33, 0xa9, 0x89, 0x4f, 0x3c, 0x9c, 0xa8, 0x21, 0x26, 0x23, 0xd6, 0x23, 0x69, 0xa5, 0x65, 0xa2, 0xed, 0xcd, 0xdd, 0x27, 0x6e, 0x07, 0xd6, 0x11, 0xed, 0xa3, 0x59, 0x74, 0x26),
-//            //   aad: vec!(0x19, 0xfa, 0x9a, 0xa5, 0x96, 0x97, 0x55, 0x9d, 0x8b, 0x46, 0xd9, 0xcd, 0x49, 0xc3, 0xb7, 0x63, 0xc0, 0xb7, 0x3b, 0x26, 0xb9, 0xe3, 0x34, 0xa3, 0xee, 0xac, 0x2c, 0x86, 0xfd, 0xba, 0xca, 0x8d),
-//            //   cipher_text: vec!(0xb6, 0x8c, 0x83, 0x39, 0x77, 0x70, 0xc3, 0x6f, 0x07, 0x37, 0x10, 0x88, 0x2f, 0xa8, 0x6d, 0x43, 0xb0, 0xe5, 0x4e, 0x8e, 0xfe, 0xf0, 0xff, 0x75, 0x07, 0x56, 0x04, 0xd0, 0xd7, 0xec, 0x4e, 0x1b),
-//            //   tag: vec!(0x40, 0xd4, 0xab, 0x75, 0x2f, 0x3d)
-//            // },
-//            // TestVector{
-//            //   key: [0x5c, 0x3b, 0x83, 0x8b, 0x84, 0x10, 0x0b, 0x2a, 0x81, 0x8c, 0x08, 0x42, 0xe9, 0xfe, 0x19, 0xa7, 0xc5, 0x0c, 0xf5, 0xf3, 0xea, 0x73, 0x36, 0x4c, 0x81, 0x6e, 0xf5, 0x88, 0xe5, 0x00, 0xff, 0x3f],
-//            //   nonce: [0xfd, 0xf6, 0xb0, 0x22, 0x9e, 0x4b, 0xcc, 0x2a],
-//            //   plain_text: vec!(0x2b, 0xa9, 0x19, 0x04, 0xc1, 0x43, 0xbe, 0x99, 0x29, 0x7b, 0x39, 0xf5, 0x28, 0x56, 0x90, 0x4a, 0xf4, 0x17, 0x05, 0xc1, 0x76, 0xc8, 0xc6, 0x55, 0x4b, 0x6b, 0xc8, 0x9b, 0xdd, 0xff, 0xbc, 0xc1),
-//            //   aad: vec!(0x35, 0x39, 0xd9, 0xdd, 0x82, 0x1f, 0x00, 0x4f, 0x4c, 0xed, 0x16, 0x37, 0x07, 0x1f, 0x4b, 0xe6, 0xab, 0xd7, 0xfe, 0x98, 0xf0, 0x17, 0xf0, 0xa8, 0xce, 0x3f, 0x49, 0xdc, 0x8d, 0x49, 0x6f, 0x46),
-//            //   cipher_text: vec!(0xff, 0x9d, 0x6d, 0x92, 0x4e, 0x73, 0x7a, 0x1d, 0xf8, 0xc2, 0xbd, 0x30, 0x47, 0xe4, 0x0a, 0xb4, 0x01, 0xf9, 0x03, 0xaa, 0x0e, 0x5b, 0x51, 0xac, 0xb9, 0x91, 0xba, 0xc3, 0x8a, 0xc2, 0xcc, 0x4d),
-//            //   tag: vec!(0x1b, 0xca, 0xa4, 0x15, 0xa6, 0xa3, 0xc7)
-//            // },
-//            TestVector {
-//                key: [
-//                    0x6d, 0x65, 0xe6, 0x27, 0xca, 0xb6, 0xd5, 0xeb, 0x1a, 0x08, 0x8b, 0x25, 0xbd,
-//                    0x6c, 0x3a, 0x8a, 0x00, 0x4a, 0x7a, 0x19, 0xcc, 0xca, 0xe9, 0x09, 0xd6, 0x2f,
-//                    0xed, 0x35, 0x59, 0xc8, 0x12, 0xf7,
-//                ],
-//                nonce: [0x7f, 0xf0, 0x0a, 0x87, 0x98, 0xb7, 0x92, 0xde],
-//                plain_text: vec![
-//                    0x68, 0x48, 0xee, 0x4a, 0xc8, 0x20, 0x29, 0x1a, 0x2e, 0x1d, 0xc3, 0xba, 0xad,
-//                    0x97, 0xf1, 0xad, 0x8b, 0x71, 0x60, 0xdf, 0xea, 0xa1, 0xbc, 0x83, 0xb2, 0x70,
-//                    0x0a, 0xe4, 0x2b, 0x5a, 0x36, 0x6b,
-//                ],
-//                aad: vec![
-//                    0xd2, 0x43, 0x7b, 0x13, 0x06, 0xbf, 0x0e, 0xa2, 0x11, 0x44, 0x9f, 0xac, 0x86,
-//                    0x3c, 0xa0, 0xd1, 0x07, 0x4d, 0x84, 0xca, 0xee, 0x90, 0x09, 0xc5, 0xd5, 0x4b,
-//                    0x9e, 0x9b, 0xdc, 0x8d, 0xe6, 0xb1,
-//                ],
-//                cipher_text: vec![
-//                    0x2d, 0xa0, 0xab, 0xe2, 0xa7, 0x1e, 0x1c, 0x0b, 0x1a, 0xb3, 0x09, 0xc1, 0x60,
-//                    0xa8, 0xce, 0xbe, 0x45, 0xc6, 0xe1, 0x61, 0x70, 0xaa, 0x55, 0x61, 0x80, 0x64,
-//                    0x84, 0xba, 0x2b, 0x5b, 0x9a, 0x9a,
-//                ],
-//                tag: vec![0x56, 0x60, 0x03, 0xe1, 0xf7, 0x8d, 0x2a, 0x90],
-//            },
-//            TestVector {
-//                key: [
-//                    0x63, 0x40, 0x10, 0x46, 0xa9, 0x6e, 0xfb, 0xc8, 0xc6, 0x48, 0x3a, 0x2c, 0x39,
-//                    0x6b, 0x2a, 0x59, 0x3d, 0x3f, 0xae, 0x0d, 0xb5, 0x65, 0x52, 0x5b, 0x85, 0x99,
-//                    0x9f, 0xae, 0x13, 0xa4, 0x6b, 0x6a,
-//                ],
-//                nonce: [0x05, 0x13, 0x93, 0xd7, 0x75, 0xe6, 0x35, 0xee],
-//                plain_text: vec![
-//                    0x2b, 0x4b, 0x64, 0x77, 0x58, 0x03, 0x82, 0xaa, 0xe7, 0x82, 0xf8, 0xb5, 0x77,
-//                    0x2c, 0x09, 0x48, 0xa4, 0x44, 0xd8, 0xd9, 0x5c, 0xaa, 0xcd, 0x85, 0xc0, 0x85,
-//                    0x6c, 0x7e, 0x43, 0x93, 0xfe, 0x09,
-//                ],
-//                aad: vec![
-//                    0x3d, 0x84, 0xd2, 0xe7, 0x0e, 0x9c, 0x06, 0x2d, 0x1f, 0x51, 0x1e, 0xb6, 0x85,
-//                    0xa9, 0xa9, 0x0c, 0x8d, 0x5f, 0xa5, 0x0e, 0xad, 0xf8, 0x45, 0x5c, 0x71, 0x48,
-//                    0x66, 0x6b, 0x3e, 0x71, 0x55, 0xe0,
-//                ],
-//                cipher_text: vec![
-//                    0x88, 0x0c, 0x11, 0x23, 0xe5, 0x4f, 0xd8, 0xff, 0xb3, 0xc2, 0x93, 0x72, 0x0d,
-//                    0xd1, 0x74, 0x91, 0x35, 0x72, 0xe6, 0x19, 0xef, 0x46, 0x50, 0x4c, 0xda, 0xa6,
-//                    0x4f, 0xc4, 0x51, 0xb0, 0xec, 0x1c,
-//                ],
-//                tag: vec![0x33, 0x92, 0x74, 0x33, 0x9c, 0x88, 0xd5, 0x0a, 0xc0],
-//            },
-//            TestVector {
-//                key: [
-//                    0x29, 0x1f, 0xcc, 0xfc, 0xe0, 0x78, 0x2f, 0x17, 0x87, 0xd6, 0x2d, 0x4b, 0x92,
-//                    0x93, 0xd2, 0xad, 0xa4, 0xc0, 0x4d, 0x37, 0xa8, 0x28, 0x8b, 0xa9, 0xba, 0x9a,
-//                    0xae, 0x0d, 0x31, 0xaa, 0xd2, 0x04,
-//                ],
-//                nonce: [0x74, 0x50, 0xbb, 0xd6, 0x2e, 0x4a, 0xba, 0x7b],
-//                plain_text: vec![
-//                    0xad, 0xc2, 0x51, 0xe7, 0x93, 0x18, 0x1e, 0x5d, 0x4c, 0x4b, 0xd9, 0x83, 0xb8,
-//                    0x53, 0xeb, 0x13, 0xf2, 0x09, 0x6c, 0xcb, 0x34, 0x09, 0x96, 0xb6, 0xec, 0xa4,
-//                    0xcd, 0x21, 0x57, 0xef, 0xce, 0xc7,
-//                ],
-//                aad: vec![
-//                    0x4c, 0x59, 0x8f, 0x6d, 0xee, 0xdc, 0x8c, 0x1d, 0x97, 0xda, 0x33, 0x65, 0x47,
-//                    0x63, 0x49, 0x5c, 0xca, 0x35, 0x17, 0x43, 0x0e, 0xec, 0x4e, 0xdb, 0x00, 0x6b,
-//                    0x10, 0xc9, 0x5e, 0x03, 0x1a, 0xe6,
-//                ],
-//                cipher_text: vec![
-//                    0x28, 0xbd, 0xa2, 0x2e, 0x49, 0x22, 0xcd, 0x8f, 0xf6, 0x73, 0x9c, 0xd8, 0xa6,
-//                    0xbd, 0xaf, 0xce, 0x03, 0x6d, 0x9c, 0x61, 0xa1, 0x45, 0xa6, 0x5c, 0xa1, 0xb8,
-//                    0x6f, 0x6d, 0x4d, 0x32, 0x06, 0xa1,
-//                ],
-//                tag: vec![0xd9, 0x8f, 0xd4, 0x3f, 0xe7, 0xac, 0x74, 0xd4, 0xb0, 0x16],
-//            },
-//            TestVector {
-//                key: [
-//                    0xfa, 0x3a, 0x96, 0x74, 0xd4, 0xa0, 0xeb, 0x36, 0xb2, 0xf7, 0x54, 0x7c, 0x95,
-//                    0x64, 0x43, 0xd0, 0x9e, 0x6b, 0x4e, 0x4a, 0xcf, 0xc9, 0xde, 0xda, 0x83, 0x8e,
-//                    0xb7, 0xeb, 0xdb, 0x99, 0x9a, 0x8d,
-//                ],
-//                nonce: [0x0a, 0x25, 0x72, 0x59, 0x2c, 0x3b, 0xbb, 0xf6],
-//                plain_text: vec![
-//                    0xae, 0x27, 0xf7, 0x0f, 0xda, 0x9f, 0x5a, 0x5b, 0xe0, 0xf7, 0x04, 0xa2, 0x7f,
-//                    0x0b, 0x8a, 0x9c, 0x04, 0xce, 0x83, 0xd3, 0xc2, 0xe0, 0xd7, 0xec, 0x15, 0x2d,
-//                    0xa2, 0x5f, 0x47, 0x3b, 0x0c, 0x8a,
-//                ],
-//                aad: vec![
-//                    0x6e, 0xe8, 0x70, 0x5a, 0x9a, 0x36, 0x55, 0xd1, 0x98, 0x49, 0x7a, 0xd4, 0x10,
-//                    0xda, 0x02, 0x00, 0x58, 0x72, 0xec, 0xbe, 0x39, 0x78, 0x24, 0x85, 0x1b, 0x80,
-//                    0xf4, 0x05, 0x0b, 0xfd, 0xd3, 0x11,
-//                ],
-//                cipher_text: vec![
-//                    0xf3, 0x56, 0xcb, 0xd8, 0x8e, 0x4e, 0x2a, 0xff, 0x62, 0xd9, 0x1e, 0x3f, 0x91,
-//                    0x40, 0x32, 0x08, 0x53, 0x88, 0x95, 0x5b, 0xbb, 0xa9, 0x95, 0xfd, 0xe0, 0x13,
-//                    0x75, 0x8b, 0x87, 0x02, 0xe3, 0x8f,
-//                ],
-//                tag: vec![
-//                    0x00, 0x32, 0x4c, 0x76, 0xfe, 0xcd, 0x3f, 0x50, 0xe1, 0xe3, 0xb8,
-//                ],
-//            },
-//            TestVector {
-//                key: [
-//                    0x47, 0x1e, 0xc8, 0x7b, 0x99, 0x2b, 0x10, 0x4d, 0x36, 0x97, 0x48, 0xd9, 0x68,
-//                    0x56, 0xb5, 0xf6, 0x61, 0x49, 0xcb, 0x45, 0xca, 0x05, 0xc1, 0x7f, 0x29, 0xd2,
-//                    0x4e, 0xb9, 0x52, 0x6f, 0xe6, 0xdb,
-//                ],
-//                nonce: [0x23, 0xa2, 0xdf, 0x9e, 0xd0, 0xb4, 0x74, 0x39],
-//                plain_text: vec![
-//                    0x2b, 0x94, 0x52, 0xbc, 0xa0, 0xf4, 0x8e, 0x55, 0x19, 0xec, 0x3d, 0x07, 0x36,
-//                    0x59, 0x76, 0x08, 0xdf, 0x6a, 0xd9, 0xce, 0x79, 0x9e, 0xba, 0x91, 0x3c, 0xff,
-//                    0x71, 0x57, 0x3d, 0x79, 0xc0, 0x92,
-//                ],
-//                aad: vec![
-//                    0xa5, 0x67, 0x22, 0xdd, 0xfa, 0xee, 0x5f, 0x1b, 0x64, 0x39, 0x8c, 0x22, 0x5e,
-//                    0xe8, 0xbc, 0xdc, 0xfd, 0xe5, 0xc2, 0x12, 0x71, 0x01, 0xc3, 0x63, 0xbf, 0xac,
-//                    0x52, 0xbc, 0x40, 0x9c, 0x10, 0x82,
-//                ],
-//                cipher_text: vec![
-//                    0x7b, 0xbc, 0x46, 0x4a, 0xac, 0x5d, 0xd2, 0x9c, 0x25, 0x26, 0x2f, 0xe0, 0xb1,
-//                    0x16, 0xc1, 0x76, 0xd8, 0x27, 0xc2, 0xcc, 0x8d, 0xd6, 0x34, 0x28, 0x39, 0x3b,
-//                    0x0a, 0x91, 0x10, 0xf3, 0xc1, 0x94,
-//                ],
-//                tag: vec![
-//                    0x2e, 0x87, 0xf4, 0xa6, 0x66, 0x3a, 0x62, 0xe4, 0x7c, 0x7e, 0x19, 0x7f,
-//                ],
-//            },
-//            TestVector {
-//                key: [
-//                    0xa2, 0x9d, 0x1c, 0xfd, 0x4c, 0xcd, 0xc1, 0x88, 0x03, 0xfb, 0xca, 0x95, 0x00,
-//                    0xf4, 0xbb, 0x29, 0xce, 0x99, 0xcf, 0xcb, 0xf8, 0xac, 0xc4, 0x1b, 0x82, 0x08,
-//                    0xda, 0xe4, 0xb7, 0xee, 0x5d, 0x64,
-//                ],
-//                nonce: [0x63, 0x4f, 0x99, 0xe8, 0x8e, 0x23, 0x7e, 0xf0],
-//                plain_text: vec![
-//                    0x09, 0xee, 0x59, 0x82, 0xc5, 0x74, 0x3f, 0x39, 0x6d, 0x0c, 0x29, 0xc1, 0x3e,
-//                    0x3f, 0xbb, 0x8f, 0xb8, 0x9f, 0x61, 0x70, 0x5d, 0xa0, 0x54, 0x66, 0x29, 0x1e,
-//                    0x01, 0x0e, 0xff, 0xd5, 0x1a, 0x5c,
-//                ],
-//                aad: vec![
-//                    0x56, 0x4d, 0xdd, 0xfc, 0xc3, 0x22, 0x7b, 0x41, 0x32, 0x44, 0xf1, 0x10, 0x5b,
-//                    0x61, 0x0f, 0x19, 0x2d, 0xec, 0xf1, 0x5c, 0x4c, 0xfa, 0x06, 0x7f, 0x4d, 0x7f,
-//                    0xcd, 0x6b, 0xd7, 0xaf, 0x11, 0xb8,
-//                ],
-//                cipher_text: vec![
-//                    0x32, 0x91, 0x6b, 0x67, 0xa6, 0xf3, 0x27, 0x33, 0x62, 0x33, 0x44, 0xc9, 0x8c,
-//                    0x49, 0x77, 0x3f, 0x3e, 0x72, 0x1d, 0xc2, 0xde, 0xd1, 0x05, 0xfb, 0x24, 0x57,
-//                    0x99, 0x52, 0x5b, 0xc9, 0xc8, 0x4c,
-//                ],
-//                tag: vec![
-//                    0xff, 0x46, 0x3c, 0x07, 0xe7, 0xef, 0x83, 0x13, 0x21, 0xd3, 0xfd, 0x77, 0x5f,
-//                ],
-//            },
-//            TestVector {
-//                key: [
-//                    0x08, 0xba, 0x23, 0x61, 0x6d, 0x91, 0x11, 0x88, 0xf9, 0x1d, 0xa0, 0x63, 0x27,
-//                    0x8b, 0xef, 0x12, 0x37, 0xdc, 0xbf, 0x17, 0xf5, 0x25, 0x85, 0xe5, 0x3c, 0x2c,
-//                    0x4b, 0x6c, 0xf3, 0xac, 0x9f, 0x0d,
-//                ],
-//                nonce: [0x98, 0x9a, 0xe5, 0x93, 0xed, 0xdd, 0x38, 0x74],
-//                plain_text: vec![
-//                    0x74, 0x91, 0x52, 0xc9, 0x47, 0x89, 0x44, 0xc8, 0x27, 0x1c, 0x0c, 0x11, 0xe0,
-//                    0x7b, 0xc1, 0xc5, 0x69, 0xee, 0xc0, 0x14, 0x93, 0xe6, 0x5b, 0x3b, 0x94, 0x84,
-//                    0x2a, 0x1b, 0xf5, 0xd7, 0x21, 0xf8,
-//                ],
-//                aad: vec![
-//                    0xa1, 0x2d, 0x1a, 0x45, 0xb7, 0xc9, 0xb9, 0x1a, 0xb0, 0x87, 0x51, 0xa7, 0x0b,
-//                    0x75, 0x37, 0x14, 0x05, 0x2a, 0xd2, 0x4e, 0x0b, 0x26, 0x19, 0xfe, 0x8c, 0x3b,
-//                    0xe3, 0x03, 0xc6, 0x5f, 0x2d, 0xbc,
-//                ],
-//                cipher_text: vec![
-//                    0x34, 0xc4, 0x05, 0x38, 0xee, 0x1d, 0x22, 0xdd, 0xf8, 0xac, 0x29, 0x0d, 0xd7,
-//                    0xd4, 0x23, 0xdf, 0xc6, 0x22, 0xb5, 0xcf, 0x8f, 0x34, 0x12, 0xa5, 0x34, 0x3e,
-//                    0x27, 0x78, 0x22, 0xae, 0xa7, 0x13,
-//                ],
-//                tag: vec![
-//                    0x01, 0x4c, 0x7c, 0x67, 0x8e, 0x09, 0x49, 0xe8, 0x80, 0x71, 0xd1, 0xfe, 0x35,
-//                    0x31,
-//                ],
-//            },
-//            TestVector {
-//                key: [
-//                    0xc2, 0xba, 0x8b, 0xed, 0x86, 0x34, 0x15, 0x6a, 0xfc, 0x6b, 0xfe, 0x37, 0x54,
-//                    0xc9, 0x17, 0x44, 0xd4, 0x13, 0x1d, 0xe3, 0x9d, 0x05, 0x9f, 0x3a, 0x86, 0x63,
-//                    0x99, 0xf9, 0x16, 0x55, 0x3b, 0x5c,
-//                ],
-//                nonce: [0x80, 0xfb, 0xf7, 0xb4, 0x33, 0xa4, 0xcd, 0x9c],
-//                plain_text: vec![
-//                    0x41, 0x9b, 0xe6, 0x62, 0x3e, 0x79, 0x64, 0xf9, 0xf2, 0x60, 0x68, 0xdd, 0x96,
-//                    0x9e, 0x4a, 0x13, 0x96, 0x17, 0xe6, 0x7c, 0x5f, 0xfb, 0x26, 0x9b, 0x30, 0x13,
-//                    0xc4, 0x33, 0xfe, 0x77, 0x1c, 0x77,
-//                ],
-//                aad: vec![
-//                    0x39, 0x37, 0x59, 0x2d, 0xb7, 0x8a, 0x61, 0xff, 0x46, 0x96, 0x91, 0xb6, 0x80,
-//                    0x07, 0x92, 0x01, 0x9b, 0xc2, 0xb3, 0xd4, 0x25, 0x12, 0xf2, 0x3c, 0x1b, 0x1a,
-//                    0x66, 0xa8, 0x27, 0x44, 0x95, 0xcb,
-//                ],
-//                cipher_text: vec![
-//                    0x9d, 0x5b, 0xd1, 0xc7, 0xe7, 0x66, 0x76, 0x3e, 0xb0, 0x06, 0x84, 0xc0, 0x38,
-//                    0x04, 0x31, 0x11, 0xd8, 0xc6, 0x39, 0x0a, 0x8d, 0x6e, 0x17, 0xa1, 0x5e, 0xf9,
-//                    0x7c, 0x02, 0xab, 0x16, 0xf0, 0x9c,
-//                ],
-//                tag: vec![
-//                    0xa6, 0x4d, 0x0e, 0xeb, 0x4a, 0x01, 0x48, 0x1e, 0xc0, 0xce, 0xe8, 0xc1, 0xc3,
-//                    0x57, 0xe3,
-//                ],
-//            },
-//        ]
-//    }
-//}
-//
-//#[cfg(all(test, feature = "with-bench"))]
-//mod bench {
-//    use aead::{AeadDecryptor, AeadEncryptor};
-//    use chacha20poly1305::ChaCha20Poly1305;
-//    use test::Bencher;
-//
-//    #[bench]
-//    pub fn chacha20poly1305_10(bh: &mut Bencher) {
-//        let input = [1u8; 10];
-//        let aad = [3u8; 10];
-//        bh.iter(|| {
-//            let mut cipher = ChaCha20Poly1305::new(&[0; 32], &[0; 8], &aad);
-//            let mut decipher = ChaCha20Poly1305::new(&[0; 32], &[0; 8], &aad);
-//
-//            let mut output = [0u8; 10];
-//            let mut tag = [0u8; 16];
-//            let mut output2 = [0u8; 10];
-//            cipher.encrypt(&input, &mut output, &mut tag);
-//            decipher.decrypt(&output, &mut output2, &tag);
-//        });
-//        bh.bytes = 10u64;
-//    }
-//
-//    #[bench]
-//    pub fn chacha20poly1305_1k(bh: &mut Bencher) {
-//        let input = [1u8; 1024];
-//        let aad = [3u8; 1024];
-//        bh.iter(|| {
-//            let mut cipher = ChaCha20Poly1305::new(&[0; 32], &[0; 8], &aad);
-//            let mut decipher = ChaCha20Poly1305::new(&[0; 32], &[0; 8], &aad);
-//
-//            let mut output = [0u8; 1024];
-//            let mut tag = [0u8; 16];
-//            let mut output2 = [0u8; 1024];
-//
-//            cipher.encrypt(&input, &mut output, &mut tag);
-//            decipher.decrypt(&output, &mut output2, &tag);
-//        });
-//        bh.bytes = 1024u64;
-//    }
-//
-//    #[bench]
-//    pub fn chacha20poly1305_64k(bh: &mut Bencher) {
-//        let input = [1u8; 65536];
-//        let aad = [3u8; 65536];
-//        bh.iter(|| {
-//            let mut cipher = ChaCha20Poly1305::new(&[0; 32], &[0; 8], &aad);
-//            let mut decipher = ChaCha20Poly1305::new(&[0; 32], &[0; 8], &aad);
-//
-//            let mut output = [0u8; 65536];
-//            let mut tag = [0u8; 16];
-//            let mut output2 = [0u8; 65536];
-//
-//            cipher.encrypt(&input, &mut output, &mut tag);
-//            decipher.decrypt(&output, &mut output2, &tag);
-//        });
-//        bh.bytes = 65536u64;
-//    }
-//}
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use aead::{AeadDecryptor, AeadEncryptor, AeadError, AeadKey};
+use sr_std::convert::TryFrom;
+use sr_std::iter::repeat;
+use sr_std::marker::*;
+use sr_std::prelude::*;
+
+use chacha20::ChaCha20;
+use cryptoutil::write_u64_le;
+use mac::Mac;
+use poly1305::Poly1305;
+use symmetriccipher::SynchronousStreamCipher;
+use util::{fixed_time_eq, secure_memset};
+/**
+ * ChaCha20-Poly1305 as described in draft-agl-tls-chacha20poly1305: the Poly1305 tag covers the
+ * AAD, its little endian 64 bit length, the ciphertext and its little endian 64 bit length, with no
+ * padding in between. This is not the RFC 8439 AEAD, even when a 12 byte nonce is used.
+ */
+#[derive(Clone, Copy)]
+pub struct ChaCha20Poly1305 {
+    cipher: ChaCha20,
+    mac: Poly1305,
+    finished: bool,
+    aad_len: u64,
+    aad_finished: bool,
+    data_len: u64,
+}
+
+// Add n bytes to a length that is authenticated as a 64 bit integer.
+fn add_len(len: u64, n: usize) -> u64 {
+    match len.checked_add(n as u64) {
+        Some(len) => len,
+        None => panic!("ChaCha20Poly1305 input length overflows its 64 bit length field"),
+    }
+}
+
+impl ChaCha20Poly1305 {
+    pub fn new(key: &[u8], nonce: &[u8], aad: &[u8]) -> ChaCha20Poly1305 {
+        //assert!(key.len() == 16 || key.len() == 32);
+        //assert!(nonce.len() == 8);
+
+        let mut cipher = ChaCha20::new(key, nonce);
+        let mut mac_key = [0u8; 64];
+        let zero_key = [0u8; 64];
+        cipher.process(&zero_key, &mut mac_key);
+
+        let mut mac = Poly1305::new(&mac_key[..32]);
+        secure_memset(&mut mac_key, 0);
+        mac.input(aad);
+        ChaCha20Poly1305 {
+            cipher: cipher,
+            mac: mac,
+            finished: false,
+            aad_len: aad.len() as u64,
+            aad_finished: false,
+            data_len: 0,
+        }
+    }
+
+    /**
+     * Like new(), but with a 256 bit key whose length is checked by the type.
+     */
+    pub fn new_256(key: &[u8; 32], nonce: &[u8], aad: &[u8]) -> ChaCha20Poly1305 {
+        ChaCha20Poly1305::new(key, nonce, aad)
+    }
+
+    /**
+     * Like new(), but returns an error instead of misbehaving if the key is not 16 or 32 bytes or
+     * the nonce is not 8 or 12 bytes.
+     */
+    pub fn try_new(key: &[u8], nonce: &[u8], aad: &[u8]) -> Result<ChaCha20Poly1305, AeadError> {
+        ChaCha20Poly1305::with_key(&AeadKey::try_from(key)?, nonce, aad)
+    }
+
+    /**
+     * Like try_new(), but with the key taken from an AeadKey, which must be 16 or 32 bytes.
+     */
+    pub fn with_key(
+        key: &AeadKey,
+        nonce: &[u8],
+        aad: &[u8],
+    ) -> Result<ChaCha20Poly1305, AeadError> {
+        if key.len() != 16 && key.len() != 32 {
+            return Err(AeadError::InvalidKeyLength);
+        }
+        if nonce.len() != 8 && nonce.len() != 12 {
+            return Err(AeadError::InvalidNonceLength);
+        }
+        Ok(ChaCha20Poly1305::new(key.as_bytes(), nonce, aad))
+    }
+
+    /**
+     * Decrypt ciphertext in place with a detached tag. The AAD is the AAD passed to new() followed
+     * by each of aad_segments in turn, so splitting it across segments authenticates exactly the
+     * same bytes as passing their concatenation. The ciphertext is left untouched unless the tag
+     * verifies. Panics if this instance has already encrypted or decrypted a message.
+     */
+    pub fn open_detached_segmented(
+        &mut self,
+        aad_segments: &[&[u8]],
+        ciphertext: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), AeadError> {
+        assert!(!self.finished);
+        assert!(!self.aad_finished);
+        for segment in aad_segments.iter() {
+            self.mac.input(segment);
+            self.aad_len = add_len(self.aad_len, segment.len());
+        }
+        self.finish_aad();
+        self.finished = true;
+
+        self.mac.input(ciphertext);
+        self.data_len = add_len(self.data_len, ciphertext.len());
+        let mut data_len_buf = [0u8; 8];
+        write_u64_le(&mut data_len_buf, self.data_len);
+        self.mac.input(&data_len_buf);
+
+        let mut calc_tag = [0u8; 16];
+        self.mac.raw_result(&mut calc_tag);
+        if !fixed_time_eq(&calc_tag, tag) {
+            return Err(AeadError::AuthenticationFailed);
+        }
+        let mut buf = [0u8; 64];
+        for chunk in ciphertext.chunks_mut(64) {
+            let n = chunk.len();
+            buf[..n].copy_from_slice(chunk);
+            self.cipher.process(&buf[..n], chunk);
+        }
+        secure_memset(&mut buf, 0);
+        Ok(())
+    }
+
+    /**
+     * Verify and decrypt ciphertext with a detached tag into the start of out, returning the
+     * plaintext length. The AAD is the AAD passed to new() followed by aad. If out is shorter than
+     * the ciphertext nothing is decrypted and BufferTooSmall reports the size needed; if the tag
+     * does not verify, the part of out that would have held the plaintext is zeroed.
+     */
+    pub fn open_into(
+        &mut self,
+        aad: &[u8],
+        ciphertext: &[u8],
+        tag: &[u8],
+        out: &mut [u8],
+    ) -> Result<usize, AeadError> {
+        let len = ciphertext.len();
+        if out.len() < len {
+            return Err(AeadError::BufferTooSmall { needed: len });
+        }
+        let plaintext = &mut out[..len];
+        plaintext.copy_from_slice(ciphertext);
+        match self.open_detached_segmented(&[aad], plaintext, tag) {
+            Ok(()) => Ok(len),
+            Err(e) => {
+                secure_memset(plaintext, 0);
+                Err(e)
+            }
+        }
+    }
+
+    /**
+     * Authenticate a digest of a header as AAD, after any AAD passed to new(). Must be called
+     * before the first encrypt() or decrypt().
+     *
+     * Poly1305 is keyed afresh for every nonce, so nothing computed over a raw header can be
+     * reused between messages. What can be reused is a hash of the header: compute it once with
+     * any Digest and pass it here for each message, so a large header is hashed once instead of
+     * once per message. The digest bytes themselves are the AAD, so this gives the same tag as
+     * passing the digest to new(), and a different tag from passing the raw header. Both sides
+     * must therefore agree to use this representation; a peer that authenticates the raw header
+     * will reject every message.
+     */
+    pub fn with_aad_hash(&mut self, header_digest: &[u8]) {
+        assert!(!self.aad_finished);
+        self.mac.input(header_digest);
+        self.aad_len = add_len(self.aad_len, header_digest.len());
+    }
+
+    // The AAD length is authenticated after the last byte of AAD, which is only known once the
+    // first ciphertext is processed.
+    fn finish_aad(&mut self) {
+        if !self.aad_finished {
+            let mut aad_len = [0u8; 8];
+            write_u64_le(&mut aad_len, self.aad_len);
+            self.mac.input(&aad_len);
+            self.aad_finished = true;
+        }
+    }
+}
+
+impl AeadEncryptor for ChaCha20Poly1305 {
+    fn encrypt(&mut self, input: &[u8], output: &mut [u8], out_tag: &mut [u8]) {
+        //assert!(input.len() == output.len());
+        //assert!(self.finished == false);
+        self.finish_aad();
+        self.cipher.process(input, output);
+        self.data_len = add_len(self.data_len, input.len());
+        self.mac.input(output);
+        self.finished = true;
+        let mut data_len_buf = [0u8; 8];
+        write_u64_le(&mut data_len_buf, self.data_len);
+        self.mac.input(&data_len_buf);
+        self.mac.raw_result(out_tag);
+    }
+}
+
+impl AeadDecryptor for ChaCha20Poly1305 {
+    fn decrypt(&mut self, input: &[u8], output: &mut [u8], tag: &[u8]) -> bool {
+        //assert!(input.len() == output.len());
+        //assert!(self.finished == false);
+
+        self.finish_aad();
+        self.finished = true;
+
+        self.mac.input(input);
+
+        self.data_len = add_len(self.data_len, input.len());
+        let mut data_len_buf = [0u8; 8];
+
+        write_u64_le(&mut data_len_buf, self.data_len);
+        self.mac.input(&data_len_buf);
+
+        let mut calc_tag = [0u8; 16];
+        self.mac.raw_result(&mut calc_tag);
+        if fixed_time_eq(&calc_tag, tag) {
+            self.cipher.process(input, output);
+            true
+        } else {
+            false
+        }
+    }
+}
+/**
+ * The original ChaCha20-Poly1305 AEAD, as shipped in early TLS and OpenSSL/BoringSSL under the name
+ * "chacha20-poly1305-old". It is ChaCha20Poly1305 restricted to an 8 byte nonce, which the
+ * constructors take as an array, so that code which needs this construction rather than the IETF
+ * one can say so. It differs from the IETF construction of RFC 8439 in three ways:
+ *
+ * - the nonce is 8 bytes and the ChaCha20 block counter is 64 bits, instead of a 12 byte nonce and
+ *   a 32 bit counter, so a single message is not limited to 256 GiB;
+ * - the AAD and ciphertext are not padded to a multiple of 16 bytes before being fed to Poly1305;
+ * - each length is authenticated directly after the data it describes, rather than both lengths
+ *   together at the end.
+ *
+ * Ciphertexts are therefore not interchangeable with RFC 8439 ones. Use this only to interoperate
+ * with peers that speak the legacy construction. Note that this is not OpenSSH's
+ * chacha20-poly1305@openssh.com either, which uses two keys and a different MAC layout.
+ */
+pub struct ChaCha20Poly1305Legacy(ChaCha20Poly1305);
+
+impl ChaCha20Poly1305Legacy {
+    pub fn new(key: &[u8], nonce: &[u8; 8], aad: &[u8]) -> ChaCha20Poly1305Legacy {
+        ChaCha20Poly1305Legacy(ChaCha20Poly1305::new(key, nonce, aad))
+    }
+
+    /**
+     * Like new(), but with the key taken from an AeadKey, which must be 16 or 32 bytes.
+     */
+    pub fn with_key(
+        key: &AeadKey,
+        nonce: &[u8; 8],
+        aad: &[u8],
+    ) -> Result<ChaCha20Poly1305Legacy, AeadError> {
+        ChaCha20Poly1305::with_key(key, nonce, aad).map(ChaCha20Poly1305Legacy)
+    }
+}
+
+impl AeadEncryptor for ChaCha20Poly1305Legacy {
+    fn encrypt(&mut self, input: &[u8], output: &mut [u8], out_tag: &mut [u8]) {
+        self.0.encrypt(input, output, out_tag)
+    }
+}
+
+impl AeadDecryptor for ChaCha20Poly1305Legacy {
+    fn decrypt(&mut self, input: &[u8], output: &mut [u8], tag: &[u8]) -> bool {
+        self.0.decrypt(input, output, tag)
+    }
+}
+
+/**
+ * Derive the one-time Poly1305 key for a ChaCha20 key and nonce: the first 32 bytes of keystream
+ * block 0. The nonce may be 8 or 12 bytes, as for ChaCha20::new(). Each nonce must be used at most
+ * once per key.
+ */
+pub fn poly1305_key(chacha_key: &[u8; 32], nonce: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    ChaCha20::new(chacha_key, nonce).keystream(&mut key);
+    key
+}
+
+/**
+ * Encrypt plaintext in one shot, authenticating it together with aad. The 16 byte tag is appended
+ * to the returned ciphertext. Pass `&[]` as aad if there is no associated data.
+ */
+pub fn seal(key: &[u8], nonce: &[u8], aad: &[u8], plaintext: &[u8]) -> Vec<u8> {
+    let mut sealed: Vec<u8> = repeat(0).take(plaintext.len() + 16).collect();
+    let (ciphertext, tag) = sealed.split_at_mut(plaintext.len());
+    ChaCha20Poly1305::new(key, nonce, aad).encrypt(plaintext, ciphertext, tag);
+    sealed
+}
+
+/**
+ * Decrypt the output of seal(). aad must be the same associated data that was passed to seal().
+ * No plaintext is returned unless the tag verifies.
+ */
+pub fn open(key: &[u8], nonce: &[u8], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>, AeadError> {
+    if sealed.len() < 16 {
+        return Err(AeadError::AuthenticationFailed);
+    }
+    let (ciphertext, tag) = sealed.split_at(sealed.len() - 16);
+    let mut plaintext: Vec<u8> = repeat(0).take(ciphertext.len()).collect();
+    if ChaCha20Poly1305::new(key, nonce, aad).decrypt(ciphertext, &mut plaintext[..], tag) {
+        Ok(plaintext)
+    } else {
+        Err(AeadError::AuthenticationFailed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use sr_std::iter::repeat;
+
+    use aead::{AeadDecryptor, AeadEncryptor, AeadError};
+    use chacha20poly1305::{open, poly1305_key, seal, ChaCha20Poly1305, ChaCha20Poly1305Legacy};
+    use digest::Digest;
+    use sha2::Sha256;
+    struct TestVector {
+        key: [u8; 32],
+        nonce: [u8; 8],
+        plain_text: Vec<u8>,
+        cipher_text: Vec<u8>,
+        aad: Vec<u8>,
+        tag: Vec<u8>,
+    }
+
+    #[test]
+    fn test_chacha20_256_poly1305_boringssl_vectors_encrypt() {
+        for tv in get_test_vectors().iter() {
+            if tv.tag.len() < 16 {
+                continue;
+            }
+            let mut c = ChaCha20Poly1305::new(&tv.key, &tv.nonce, &tv.aad[..]);
+            let mut output: Vec<u8> = repeat(0).take(tv.plain_text.len()).collect();
+            let mut tag: Vec<u8> = repeat(0).take(tv.tag.len()).collect();
+            c.encrypt(&tv.plain_text[..], &mut output[..], &mut tag[..]);
+            assert_eq!(output, tv.cipher_text);
+            assert_eq!(tag, tv.tag);
+        }
+    }
+    #[test]
+    fn test_chacha20_256_poly1305_boringssl_vectors_decrypt() {
+        for tv in get_test_vectors().iter() {
+            if tv.tag.len() < 16 {
+                continue;
+            }
+            let mut c = ChaCha20Poly1305::new(&tv.key, &tv.nonce, &tv.aad[..]);
+            let mut output: Vec<u8> = repeat(0).take(tv.plain_text.len()).collect();
+            let result = c.decrypt(&tv.cipher_text[..], &mut output[..], &tv.tag[..]);
+            assert_eq!(output, tv.plain_text);
+            assert!(result);
+        }
+    }
+    #[test]
+    fn test_seal_open_with_aad() {
+        for tv in get_test_vectors().iter() {
+            if tv.tag.len() < 16 {
+                continue;
+            }
+            let sealed = seal(&tv.key, &tv.nonce, &tv.aad[..], &tv.plain_text[..]);
+            assert_eq!(&sealed[..tv.cipher_text.len()], &tv.cipher_text[..]);
+            assert_eq!(&sealed[tv.cipher_text.len()..], &tv.tag[..]);
+            assert_eq!(
+                open(&tv.key, &tv.nonce, &tv.aad[..], &sealed[..]).unwrap(),
+                tv.plain_text
+            );
+        }
+
+        let key = [1u8; 32];
+        let nonce = [2u8; 12];
+        let sealed = seal(&key, &nonce, b"header v1", b"payload");
+        assert_eq!(open(&key, &nonce, b"header v1", &sealed[..]).unwrap(), b"payload".to_vec());
+        assert_eq!(
+            open(&key, &nonce, b"header v2", &sealed[..]),
+            Err(AeadError::AuthenticationFailed)
+        );
+        assert_eq!(open(&key, &nonce, b"", &sealed[..]), Err(AeadError::AuthenticationFailed));
+        assert_eq!(
+            open(&key, &nonce, b"header v1", &sealed[..15]),
+            Err(AeadError::AuthenticationFailed)
+        );
+    }
+
+    #[test]
+    fn test_length_near_limit() {
+        // Simulate a stream that has already authenticated almost 2^64 bytes.
+        let mut c = ChaCha20Poly1305::new(&[1; 32], &[2; 8], b"");
+        c.data_len = u64::max_value() - 4;
+        let mut tag = [0u8; 16];
+        c.encrypt(&[0; 4], &mut [0; 4], &mut tag);
+        assert_eq!(c.data_len, u64::max_value());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_length_overflow() {
+        let mut c = ChaCha20Poly1305::new(&[1; 32], &[2; 8], b"");
+        c.data_len = u64::max_value() - 4;
+        c.decrypt(&[0; 5], &mut [0; 5], &[0; 16]);
+    }
+
+    #[test]
+    fn test_empty_plaintext_and_aad() {
+        // Tags computed with Python's cryptography package over
+        // aad || le64(aad.len()) || ciphertext || le64(ciphertext.len()).
+        let key: Vec<u8> = (0x80..0xa0).collect();
+        let nonce = [0x07, 0x00, 0x00, 0x00, 0x40, 0x41, 0x42, 0x43];
+        let tests: [(&[u8], &[u8], &[u8], [u8; 16]); 3] = [
+            (b"", b"", b"", [
+                0x34, 0x02, 0xb5, 0x4e, 0x24, 0x08, 0x9a, 0x6e, 0x01, 0x5a, 0xfd, 0xb1, 0xb5, 0x5c,
+                0xcf, 0x1a,
+            ]),
+            (b"key confirmation", b"", b"", [
+                0xa7, 0xd0, 0xca, 0x51, 0x60, 0x57, 0xda, 0x89, 0x95, 0x4e, 0x08, 0x4f, 0xde, 0x69,
+                0x60, 0x78,
+            ]),
+            (b"", b"payload", &[0x68, 0x19, 0x6b, 0xe3, 0x51, 0x7c, 0x38], [
+                0x40, 0x41, 0x9e, 0x5d, 0x83, 0x61, 0xc4, 0xca, 0xee, 0xfc, 0xc7, 0xff, 0xcf, 0x28,
+                0xdd, 0x4a,
+            ]),
+        ];
+
+        for &(aad, plaintext, ciphertext, ref tag) in tests.iter() {
+            let mut c = ChaCha20Poly1305::new(&key[..], &nonce, aad);
+            let mut output: Vec<u8> = repeat(0).take(plaintext.len()).collect();
+            let mut out_tag = [0u8; 16];
+            c.encrypt(plaintext, &mut output[..], &mut out_tag);
+            assert_eq!(&output[..], ciphertext);
+            assert_eq!(&out_tag, tag);
+
+            let mut d = ChaCha20Poly1305::new(&key[..], &nonce, aad);
+            let mut decrypted: Vec<u8> = repeat(0).take(ciphertext.len()).collect();
+            assert!(d.decrypt(ciphertext, &mut decrypted[..], tag));
+            assert_eq!(&decrypted[..], plaintext);
+
+            let sealed = seal(&key[..], &nonce, aad, plaintext);
+            assert_eq!(sealed.len(), plaintext.len() + 16);
+            assert_eq!(open(&key[..], &nonce, aad, &sealed[..]).unwrap(), plaintext.to_vec());
+
+            let mut bad_tag = *tag;
+            bad_tag[15] ^= 0x80;
+            let mut d = ChaCha20Poly1305::new(&key[..], &nonce, aad);
+            assert!(!d.decrypt(ciphertext, &mut decrypted[..], &bad_tag));
+        }
+    }
+
+    #[test]
+    fn test_typed_constructors() {
+        for tv in get_test_vectors().iter() {
+            let mut c = ChaCha20Poly1305::new_256(&tv.key, &tv.nonce, &tv.aad[..]);
+            let mut output: Vec<u8> = repeat(0).take(tv.plain_text.len()).collect();
+            let mut out_tag = [0u8; 16];
+            c.encrypt(&tv.plain_text[..], &mut output[..], &mut out_tag);
+            assert_eq!(output, tv.cipher_text);
+            assert_eq!(&out_tag[..tv.tag.len()], &tv.tag[..]);
+
+            let mut c = ChaCha20Poly1305::try_new(&tv.key[..], &tv.nonce[..], &tv.aad[..]).unwrap();
+            c.encrypt(&tv.plain_text[..], &mut output[..], &mut out_tag);
+            assert_eq!(output, tv.cipher_text);
+        }
+
+        let err = |r: Result<ChaCha20Poly1305, AeadError>| r.err().unwrap();
+        assert_eq!(
+            err(ChaCha20Poly1305::try_new(&[0; 24], &[0; 8], b"")),
+            AeadError::InvalidKeyLength
+        );
+        assert_eq!(
+            err(ChaCha20Poly1305::try_new(&[0; 32], &[0; 16], b"")),
+            AeadError::InvalidNonceLength
+        );
+        assert!(ChaCha20Poly1305::try_new(&[0; 16], &[0; 12], b"").is_ok());
+    }
+
+    #[test]
+    fn test_with_aad_hash() {
+        let key = [7u8; 32];
+        let header: Vec<u8> = (0..1000).map(|i| i as u8).collect();
+        let mut header_digest = [0u8; 32];
+        let mut sha = Sha256::new();
+        sha.input(&header[..]);
+        sha.result(&mut header_digest);
+
+        for counter in 0..3u8 {
+            let nonce = [counter; 8];
+            let plaintext = [counter; 20];
+            let mut ciphertext = [0u8; 20];
+            let mut tag = [0u8; 16];
+            let mut c = ChaCha20Poly1305::new(&key, &nonce, &[]);
+            c.with_aad_hash(&header_digest);
+            c.encrypt(&plaintext, &mut ciphertext, &mut tag);
+
+            // The same as passing the digest to new(), and not the same as the raw header.
+            let sealed = seal(&key, &nonce, &header_digest, &plaintext);
+            assert_eq!(&sealed[..20], &ciphertext[..]);
+            assert_eq!(&sealed[20..], &tag[..]);
+            assert!(&seal(&key, &nonce, &header[..], &plaintext)[20..] != &tag[..]);
+
+            // AAD given to new() comes first.
+            let mut aad = b"v1".to_vec();
+            aad.extend_from_slice(&header_digest);
+            let sealed = seal(&key, &nonce, &aad, &plaintext);
+            let mut c = ChaCha20Poly1305::new(&key, &nonce, b"v1");
+            c.with_aad_hash(&header_digest);
+            let mut decrypted = [0u8; 20];
+            assert!(c.decrypt(&sealed[..20], &mut decrypted, &sealed[20..]));
+            assert_eq!(decrypted, plaintext);
+        }
+    }
+
+    #[test]
+    fn test_open_into() {
+        let key = [3u8; 32];
+        let nonce = [4u8; 12];
+        let mut aad = b"fixed".to_vec();
+        aad.extend_from_slice(b" header");
+        let sealed = seal(&key, &nonce, &aad, b"twenty byte message.");
+        let (ciphertext, tag) = sealed.split_at(20);
+
+        let mut out = [0xffu8; 19];
+        let mut c = ChaCha20Poly1305::new(&key, &nonce, b"fixed");
+        assert_eq!(
+            c.open_into(b" header", ciphertext, tag, &mut out),
+            Err(AeadError::BufferTooSmall { needed: 20 })
+        );
+        assert_eq!(out, [0xff; 19]);
+
+        let mut out = [0xffu8; 24];
+        let mut c = ChaCha20Poly1305::new(&key, &nonce, b"fixed");
+        assert_eq!(c.open_into(b" header", ciphertext, tag, &mut out), Ok(20));
+        assert_eq!(&out[..20], b"twenty byte message.");
+        assert_eq!(&out[20..], &[0xff; 4]);
+
+        let mut c = ChaCha20Poly1305::new(&key, &nonce, b"fixed");
+        assert_eq!(
+            c.open_into(b" Header", ciphertext, tag, &mut out),
+            Err(AeadError::AuthenticationFailed)
+        );
+        assert_eq!(&out[..20], &[0; 20]);
+    }
+
+    #[test]
+    fn test_seal_open_reject_short_tag() {
+        let key = [0u8; 32];
+        let nonce = [0u8; 12];
+        let mut out = [0u8; 4];
+        let mut short_tag = [0u8; 15];
+        let mut c = ChaCha20Poly1305::new(&key, &nonce, b"");
+        assert_eq!(c.seal(&[1; 4], &mut out, &mut short_tag), Err(AeadError::InvalidTagLength));
+        let mut c = ChaCha20Poly1305::new(&key, &nonce, b"");
+        assert_eq!(c.open(&[1; 4], &mut out, &short_tag), Err(AeadError::InvalidTagLength));
+        let mut c = ChaCha20Poly1305::new(&key, &nonce, b"");
+        assert_eq!(c.open(&[1; 4], &mut out, &[0u8; 17]), Err(AeadError::InvalidTagLength));
+
+        let mut tag = [0u8; 16];
+        let mut ciphertext = [0u8; 4];
+        let mut c = ChaCha20Poly1305::new(&key, &nonce, b"");
+        assert_eq!(c.seal(&[1; 4], &mut ciphertext, &mut tag), Ok(()));
+        let mut c = ChaCha20Poly1305::new(&key, &nonce, b"");
+        assert_eq!(c.open(&ciphertext, &mut out, &tag), Ok(()));
+        assert_eq!(out, [1; 4]);
+        tag[0] ^= 1;
+        let mut c = ChaCha20Poly1305::new(&key, &nonce, b"");
+        assert_eq!(c.open(&ciphertext, &mut out, &tag), Err(AeadError::AuthenticationFailed));
+    }
+
+    #[test]
+    fn test_poly1305_key_rfc8439() {
+        // RFC 8439, section 2.6.2.
+        let mut key = [0u8; 32];
+        for (i, b) in key.iter_mut().enumerate() {
+            *b = 0x80 + i as u8;
+        }
+        let nonce = [0, 0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7];
+        let expected = [
+            0x8a, 0xd5, 0xa0, 0x8b, 0x90, 0x5f, 0x81, 0xcc, 0x81, 0x50, 0x40, 0x27, 0x4a, 0xb2,
+            0x94, 0x71, 0xa8, 0x33, 0xb6, 0x37, 0xe3, 0xfd, 0x0d, 0xa5, 0x08, 0xdb, 0xb8, 0xe2,
+            0xfd, 0xd1, 0xa6, 0x46,
+        ];
+        assert_eq!(poly1305_key(&key, &nonce), expected);
+    }
+
+    #[test]
+    fn test_legacy() {
+        // The AEAD test vector of draft-agl-tls-chacha20poly1305-04, section 7.
+        let key = hex::decode("4290bcb154173531f314af57f3be3b5006da371ece272afa1b5dbdd1100a1007")
+            .unwrap();
+        let nonce = [0xcd, 0x7c, 0xf6, 0x7b, 0xe3, 0x9c, 0x79, 0x4a];
+        let aad = hex::decode("87e229d4500845a079c0").unwrap();
+        let plain_text = hex::decode("86d09974840bded2a5ca").unwrap();
+        let cipher_text = hex::decode("e3e446f7ede9a19b62a4").unwrap();
+        let tag = hex::decode("677dabf4e3d24b876bb284753896e1d6").unwrap();
+
+        let mut output = [0u8; 10];
+        let mut out_tag = [0u8; 16];
+        ChaCha20Poly1305Legacy::new(&key, &nonce, &aad).encrypt(
+            &plain_text,
+            &mut output,
+            &mut out_tag,
+        );
+        assert_eq!(&output[..], &cipher_text[..]);
+        assert_eq!(&out_tag[..], &tag[..]);
+
+        let mut decrypted = [0u8; 10];
+        let mut c = ChaCha20Poly1305Legacy::new(&key, &nonce, &aad);
+        assert!(c.decrypt(&cipher_text, &mut decrypted, &tag));
+        assert_eq!(&decrypted[..], &plain_text[..]);
+
+        let mut c = ChaCha20Poly1305Legacy::new(&key, &nonce, b"other aad");
+        assert!(!c.decrypt(&cipher_text, &mut decrypted, &tag));
+    }
+
+    #[test]
+    fn test_open_detached_segmented() {
+        let key: Vec<u8> = (0..32).collect();
+        let nonce = [0x4a; 8];
+        let aad = b"header one|header two|trailer";
+        let plaintext = b"segmented associated data must authenticate like one buffer";
+        let sealed = seal(&key[..], &nonce, aad, plaintext);
+        let (ciphertext, tag) = sealed.split_at(plaintext.len());
+
+        let mut buf = ciphertext.to_vec();
+        let mut c = ChaCha20Poly1305::new(&key[..], &nonce, b"");
+        let segments: [&[u8]; 3] = [&aad[..11], &aad[11..22], &aad[22..]];
+        assert_eq!(c.open_detached_segmented(&segments, &mut buf[..], tag), Ok(()));
+        assert_eq!(&buf[..], &plaintext[..]);
+
+        // AAD given to new() comes first, followed by the segments.
+        let mut buf = ciphertext.to_vec();
+        let mut c = ChaCha20Poly1305::new(&key[..], &nonce, &aad[..5]);
+        let segments: [&[u8]; 2] = [&aad[5..], b""];
+        assert_eq!(c.open_detached_segmented(&segments, &mut buf[..], tag), Ok(()));
+        assert_eq!(&buf[..], &plaintext[..]);
+
+        // Reordered segments fail and leave the ciphertext untouched.
+        let mut buf = ciphertext.to_vec();
+        let mut c = ChaCha20Poly1305::new(&key[..], &nonce, b"");
+        let segments: [&[u8]; 3] = [&aad[11..22], &aad[..11], &aad[22..]];
+        assert_eq!(
+            c.open_detached_segmented(&segments, &mut buf[..], tag),
+            Err(AeadError::AuthenticationFailed)
+        );
+        assert_eq!(&buf[..], ciphertext);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_open_detached_segmented_reuse() {
+        let key = [7u8; 32];
+        let nonce = [3u8; 8];
+        let sealed = seal(&key, &nonce, b"aad", b"plaintext");
+        let (ciphertext, tag) = sealed.split_at(9);
+
+        let mut c = ChaCha20Poly1305::new(&key, &nonce, b"");
+        let mut buf = ciphertext.to_vec();
+        assert_eq!(c.open_detached_segmented(&[b"aad"], &mut buf[..], tag), Ok(()));
+        let mut buf = ciphertext.to_vec();
+        let _ = c.open_detached_segmented(&[b"aad"], &mut buf[..], tag);
+    }
+
+    fn get_test_vectors() -> Vec<TestVector> {
+        vec![
+            TestVector {
+                key: [
+                    0x9a, 0x97, 0xf6, 0x5b, 0x9b, 0x4c, 0x72, 0x1b, 0x96, 0x0a, 0x67, 0x21, 0x45,
+                    0xfc, 0xa8, 0xd4, 0xe3, 0x2e, 0x67, 0xf9, 0x11, 0x1e, 0xa9, 0x79, 0xce, 0x9c,
+                    0x48, 0x26, 0x80, 0x6a, 0xee, 0xe6,
+                ],
+                nonce: [0x3d, 0xe9, 0xc0, 0xda, 0x2b, 0xd7, 0xf9, 0x1e],
+                aad: vec![],
+                plain_text: vec![],
+                cipher_text: vec![],
+                tag: vec![
+                    0x5a, 0x6e, 0x21, 0xf4, 0xba, 0x6d, 0xbe, 0xe5, 0x73, 0x80, 0xe7, 0x9e, 0x79,
+                    0xc3, 0x0d, 0xef,
+                ],
+            },
+            TestVector {
+                key: [
+                    0xbc, 0xb2, 0x63, 0x9b, 0xf9, 0x89, 0xc6, 0x25, 0x1b, 0x29, 0xbf, 0x38, 0xd3,
+                    0x9a, 0x9b, 0xdc, 0xe7, 0xc5, 0x5f, 0x4b, 0x2a, 0xc1, 0x2a, 0x39, 0xc8, 0xa3,
+                    0x7b, 0x5d, 0x0a, 0x5c, 0xc2, 0xb5,
+                ],
+                nonce: [0x1e, 0x8b, 0x4c, 0x51, 0x0f, 0x5c, 0xa0, 0x83],
+                plain_text: vec![0x8c, 0x84, 0x19, 0xbc, 0x27],
+                aad: vec![0x34, 0xab, 0x88, 0xc2, 0x65],
+                cipher_text: vec![0x1a, 0x7c, 0x2f, 0x33, 0xf5],
+                tag: vec![
+                    0x28, 0x75, 0xc6, 0x59, 0xd0, 0xf2, 0x80, 0x8d, 0xe3, 0xa4, 0x00, 0x27, 0xfe,
+                    0xff, 0x91, 0xa4,
+                ],
+            },
+            TestVector {
+                key: [
+                    0x42, 0x90, 0xbc, 0xb1, 0x54, 0x17, 0x35, 0x31, 0xf3, 0x14, 0xaf, 0x57, 0xf3,
+                    0xbe, 0x3b, 0x50, 0x06, 0xda, 0x37, 0x1e, 0xce, 0x27, 0x2a, 0xfa, 0x1b, 0x5d,
+                    0xbd, 0xd1, 0x10, 0x0a, 0x10, 0x07,
+                ],
+                nonce: [0xcd, 0x7c, 0xf6, 0x7b, 0xe3, 0x9c, 0x79, 0x4a],
+                plain_text: vec![0x86, 0xd0, 0x99, 0x74, 0x84, 0x0b, 0xde, 0xd2, 0xa5, 0xca],
+                aad: vec![0x87, 0xe2, 0x29, 0xd4, 0x50, 0x08, 0x45, 0xa0, 0x79, 0xc0],
+                cipher_text: vec![0xe3, 0xe4, 0x46, 0xf7, 0xed, 0xe9, 0xa1, 0x9b, 0x62, 0xa4],
+                tag: vec![
+                    0x67, 0x7d, 0xab, 0xf4, 0xe3, 0xd2, 0x4b, 0x87, 0x6b, 0xb2, 0x84, 0x75, 0x38,
+                    0x96, 0xe1, 0xd6,
+                ],
+            },
+            TestVector {
+                key: [
+                    0x42, 0x2a, 0x53, 0x55, 0xb5, 0x6d, 0xcf, 0x2b, 0x43, 0x6a, 0xa8, 0x15, 0x28,
+                    0x58, 0x10, 0x6a, 0x88, 0xd9, 0xba, 0x23, 0xcd, 0xfe, 0x08, 0x7b, 0x5e, 0x74,
+                    0xe8, 0x17, 0xa5, 0x23, 0x88, 0xb3,
+                ],
+                nonce: [0x1d, 0x12, 0xd6, 0xd9, 0x18, 0x48, 0xf2, 0xea],
+                plain_text: vec![
+                    0x53, 0x7a, 0x64, 0x53, 0x87, 0xf2, 0x2d, 0x6f, 0x6d, 0xbb, 0xea, 0x56, 0x8d,
+                    0x3f, 0xeb,
+                ],
+                aad: vec![
+                    0xbe, 0xf2, 0x67, 0xc9, 0x9a, 0xec, 0x8a, 0xf5, 0x6b, 0xc2, 0x38, 0x61, 0x2b,
+                    0xfe, 0xa6,
+                ],
+                cipher_text: vec![
+                    0x28, 0x1a, 0x36, 0x67, 0x05, 0xc5, 0xa2, 0x4b, 0x94, 0xe5, 0x61, 0x46, 0x68,
+                    0x1e, 0x44,
+                ],
+                tag: vec![
+                    0x38, 0xf2, 0xb8, 0xee, 0x3b, 0xe4, 0x4a, 0xbb, 0xa3, 0xc0, 0x10, 0xd9, 0xca,
+                    0xb6, 0xe0, 0x42,
+                ],
+            },
+            TestVector {
+                key: [
+                    0xec, 0x7b, 0x86, 0x4a, 0x07, 0x8c, 0x3d, 0x05, 0xd9, 0x70, 0xb6, 0xea, 0x3b,
+                    0xa6, 0xd3, 0x3d, 0x6b, 0xb7, 0x3d, 0xfa, 0x64, 0xc6, 0x22, 0xa4, 0x72, 0x7a,
+                    0x96, 0xed, 0xe8, 0x76, 0xf6, 0x85,
+                ],
+                nonce: [0x2b, 0xca, 0x0e, 0x59, 0xe3, 0x95, 0x08, 0xd3],
+                plain_text: vec![
+                    0xb7, 0x67, 0x33, 0x89, 0x5c, 0x87, 0x1e, 0xdd, 0x72, 0x8a, 0x45, 0xed, 0x1a,
+                    0x21, 0xf1, 0x5a, 0x95, 0x97, 0xd4, 0x9d,
+                ],
+                aad: vec![
+                    0xcc, 0x12, 0x43, 0xea, 0x54, 0x27, 0x2d, 0xb6, 0x02, 0xfb, 0x08, 0x53, 0xc8,
+                    0xe7, 0x02, 0x7c, 0x56, 0x33, 0x8b, 0x6c,
+                ],
+                cipher_text: vec![
+                    0x1f, 0xb9, 0xb2, 0x95, 0x8f, 0xce, 0x47, 0xa5, 0xca, 0xda, 0x9d, 0x89, 0x5f,
+                    0xbb, 0x0c, 0x00, 0xd3, 0x56, 0x98, 0x58,
+                ],
+                tag: vec![
+                    0x04, 0x2a, 0xd5, 0x04, 0x2c, 0x89, 0xeb, 0xc1, 0xaa, 0xd5, 0x7d, 0x3f, 0xb7,
+                    0x03, 0xd3, 0x14,
+                ],
+            },
+            // TestVector{
+            //   key: [0x2c, 0x4c, 0x0f, 0xdb, 0x61, 0x1d, 0xf2, 0xd4, 0xd5, 0xe7, 0x89, 0x8c, 0x6a, 0xf0, 0x02, 0x27, 0x95, 0x36, 0x4a, 0xdb, 0x87, 0x49, 0x15, 0x5e, 0x2c, 0x68, 0x77, 0x6a, 0x09, 0x0e, 0x7d, 0x5c],
+            //   nonce: [0x13, 0xce, 0x73, 0x82, 0x73, 0x4c, 0x4a, 0x71],
+            //   plain_text: vec!(0x0d, 0xc6, 0xff, 0x21, 0xa3, 0x46, 0xe1, 0x33, 0x7d, 0xd0, 0xdb, 0x81, 0xd8, 0xf7, 0xd9, 0xf6, 0xfd, 0x18, 0x64, 0x41, 0x8b, 0x98, 0xaa, 0xdc, 0xdb),
+            //   aad: vec!(0x01, 0x15, 0xed, 0xcb, 0x17, 0x6a, 0xb8, 0xbf, 0xa9, 0x47, 0xd1, 0xf7, 0xc3, 0xa8, 0x6a, 0x84, 0x5d, 0x31, 0x0b, 0xf6, 0x70, 0x6c, 0x59, 0xa8, 0xf9),
+            //   cipher_text: vec!(0xda, 0xd6, 0x5e, 0x42, 0x44, 0xa1, 0xa1, 0x7c, 0xe5, 0x9d, 0x88, 0xb0, 0x0a, 0xf4, 0xf7, 0x43, 0x4b, 0xd7, 0x83, 0x0f, 0xfd, 0xd4, 0xc5, 0x55, 0x8f),
+            //   tag: vec!(0xac, 0x14, 0x37, 0xb4, 0x5d, 0x8e, 0xac, 0xf9, 0xc0, 0xfe, 0x54, 0x7c, 0x84, 0xfb, 0x82, 0xa2)
+            // },
+            // TestVector{
+            //   key: [0xc6, 0x6e, 0x89, 0xfb, 0xab, 0x01, 0x20, 0x8f, 0x6a, 0x60, 0x84, 0x7f, 0x4f, 0x34, 0xb3, 0x8d, 0x27, 0xb5, 0x54, 0xc1, 0x19, 0xcf, 0x8d, 0x9e, 0x0b, 0x11, 0x8a, 0xa7, 0x26, 0x6a, 0xb8, 0x65],
+            //   nonce: [0x5d, 0x98, 0x56, 0x06, 0x0c, 0x54, 0xab, 0x06],
+            //   plain_text: vec!(0xf9, 0xe3, 0xe9, 0xb5, 0xed, 0x07, 0xb2, 0x08, 0x0d, 0xb8, 0xc1, 0xff, 0xc3, 0x7e, 0x4a, 0x6c, 0xb3, 0xcd, 0x54, 0x46, 0x08, 0x92, 0x1e, 0x18, 0x61, 0x0d, 0x00, 0xb1, 0x7c, 0x6e),
+            //   aad: vec!(0x85, 0xc1, 0x12, 0xa1, 0xef, 0xe0, 0xa2, 0x0e, 0xf3, 0xa5, 0x50, 0x52, 0x6a, 0x7a, 0xfb, 0xc9, 0x8f, 0x63, 0x67, 0xeb, 0xbe, 0xde, 0x4e, 0x70, 0x30, 0x99, 0xab, 0xd7, 0x8f, 0x51),
+            //   cipher_text: vec!(0xb5, 0xcc, 0x75, 0x4f, 0x6d, 0xd1, 0x9e, 0xf2, 0xd6, 0x6f, 0x90, 0xe6, 0xbc, 0x9a, 0x32, 0x2d, 0xdf, 0x21, 0x6e, 0xf2, 0x48, 0xcb, 0xe7, 0x6b, 0x5a, 0xb6, 0xdd, 0x53, 0xbc, 0x36),
+            //   tag: vec!(0x6d, 0xd9, 0x87, 0x10, 0xd8, 0xa8, 0x89, 0xdc, 0xee, 0xa0, 0xd0, 0xa9, 0x36, 0xf9, 0x86, 0x17)
+            // },
+            // TestVector{
+            //   key: [0xa8, 0xb9, 0x76, 0x6f, 0x40, 0x4d, 0xea, 0x8c, 0xf7, 0xd7, 0xdf, 0xaf, 0x58, 0x22, 0xf5, 0x3d, 0xf9, 0xcc, 0xd0, 0x92, 0xe3, 0x32, 0xa5, 0x7f, 0x00, 0x7b, 0x30, 0x1b, 0x50, 0x7d, 0x5e, 0x14],
+            //   nonce: [0xc7, 0xf2, 0xf7, 0xa2, 0x33, 0x10, 0x4a, 0x2d],
+            //   plain_text: vec!(0x4d, 0x6f, 0xae, 0xae, 0xe3, 0x91, 0x79, 0xa7, 0xc8, 0x92, 0xfa, 0xae, 0x37, 0x19, 0x65, 0x6c, 0xc6, 0x14, 0xc7, 0xe6, 0xec, 0xd8, 0xfc, 0xb5, 0x70, 0xa3, 0xb8, 0x2c, 0x4d, 0xac, 0xe9, 0x69, 0x09, 0x03, 0x38),
+            //   aad: vec!(0xc6, 0xd8, 0x3b, 0x6a, 0x56, 0x40, 0x8a, 0x35, 0x6e, 0x68, 0xd0, 0x49, 0x4d, 0x4e, 0xff, 0x15, 0x05, 0x30, 0xb0, 0x95, 0x51, 0xd0, 0x08, 0x37, 0x3d, 0x6d, 0xee, 0x2b, 0x8d, 0x6b, 0x56, 0x19, 0xd6, 0x7f, 0xdb),
+            //   cipher_text: vec!(0xa1, 0x54, 0x43, 0xf0, 0x83, 0x31, 0x6e, 0xef, 0x62, 0x7a, 0x37, 0x1f, 0x4c, 0x9a, 0xc6, 0x54, 0xd0, 0xdd, 0x75, 0x25, 0x5d, 0x8a, 0x30, 0x31, 0x25, 0xe9, 0xf5, 0x1a, 0xf4, 0x23, 0x3f, 0xf4, 0xce, 0xb7, 0xfe),
+            //   tag: vec!(0x52, 0x50, 0x4e, 0x88, 0x0f, 0x67, 0x92, 0xa6, 0x07, 0x08, 0xcc, 0x6d, 0xb7, 0x2e, 0xae, 0x42)
+            // },
+            // TestVector{
+            //   key: [0x5e, 0x8d, 0x0e, 0x5f, 0x14, 0x67, 0xf7, 0xa7, 0x50, 0xc5, 0x51, 0x44, 0xd0, 0xc6, 0x70, 0xf7, 0xd9, 0x10, 0x75, 0xf3, 0x86, 0x79, 0x5b, 0x23, 0x0c, 0x9b, 0xf1, 0xc0, 0x4b, 0xa2, 0x50, 0xbc],
+            //   nonce: [0x88, 0x04, 0x9f, 0x44, 0xba, 0x61, 0xb8, 0x8f],
+            //   plain_text: vec!(0x51, 0xa1, 0xee, 0xbc, 0xc3, 0x48, 0xe0, 0x58, 0x21, 0x96, 0xa0, 0xbc, 0xe1, 0x6e, 0xd1, 0xf8, 0xac, 0x2e, 0x91, 0xc3, 0xe8, 0xa6, 0x90, 0xe0, 0x4a, 0x9f, 0x4b, 0x5c, 0xf6, 0x33, 0x13, 0xd7, 0xad, 0x08, 0xd1, 0xef, 0xbf, 0xf8, 0x5c, 0x89),
+            //   aad: vec!(0x5d, 0x09, 0xbf, 0x0b, 0xe9, 0x00, 0x26, 0xf9, 0xfc, 0x51, 0xf7, 0x34, 0x18, 0xd6, 0xd8, 0x64, 0xb6, 0xd1, 0x97, 0xea, 0x03, 0x0b, 0x3d, 0xe0, 0x72, 0xbd, 0x2c, 0x2f, 0x5c, 0xab, 0x58, 0x60, 0xa3, 0x42, 0xab, 0xbd, 0x29, 0xdb, 0xa9, 0xdc),
+            //   cipher_text: vec!(0x35, 0xaa, 0x4b, 0xd4, 0x53, 0x7a, 0xa6, 0x11, 0xfd, 0x75, 0x78, 0xfc, 0x22, 0x7d, 0xf5, 0x0e, 0xbc, 0xb0, 0x0c, 0x69, 0x2a, 0x1c, 0xf6, 0xf0, 0x2e, 0x50, 0xed, 0x92, 0x70, 0xbd, 0x93, 0xaf, 0x3b, 0xc6, 0x8f, 0x4c, 0x75, 0xb9, 0x66, 0x38),
+            //   tag: vec!(0xcc, 0xea, 0x1c, 0xbb, 0xc8, 0x39, 0x44, 0xcc, 0x66, 0xdf, 0x4d, 0xbf, 0x6f, 0xb7, 0xfc, 0x46)
+            // },
+            // TestVector{
+            //   key: [0x21, 0xa9, 0xf0, 0x7e, 0xc8, 0x91, 0xd4, 0x88, 0x80, 0x5e, 0x9b, 0x92, 0xbb, 0x1b, 0x22, 0x86, 0xf3, 0xf0, 0x41, 0x0c, 0x32, 0x3b, 0x07, 0xfe, 0xe1, 0xdc, 0x6f, 0x73, 0x79, 0xe2, 0x2e, 0x48],
+            //   nonce: [0x06, 0x62, 0x15, 0xbe, 0x65, 0x67, 0x37, 0x7a],
+            //   plain_text: vec!(0xc1, 0xb0, 0xaf, 0xfa, 0xf2, 0xb8, 0xd7, 0xef, 0x51, 0xcc, 0xa9, 0xaa, 0xcf, 0x79, 0x69, 0xf9, 0x2f, 0x92, 0x8c, 0x2e, 0x3c, 0xc7, 0xdb, 0x2e, 0x15, 0xf4, 0x7e, 0xe1, 0xf6, 0x50, 0x23, 0x91, 0x0d, 0x09, 0xf2, 0x09, 0xd0, 0x07, 0xb7, 0x43, 0x6e, 0xe8, 0x98, 0x13, 0x3d),
+            //   aad: vec!(0xdf, 0xdf, 0xdf, 0x4d, 0x3a, 0x68, 0xb4, 0x7a, 0xd0, 0xd4, 0x88, 0x28, 0xdc, 0x17, 0xb2, 0x58, 0x5d, 0xa9, 0xc8, 0x1c, 0x3a, 0x8d, 0x71, 0xd8, 0x26, 0xb5, 0xfa, 0x80, 0x20, 0xfe, 0xe0, 0x02, 0x39, 0x7e, 0x91, 0xfc, 0x96, 0x58, 0xe9, 0xd6, 0x1d, 0x72, 0x8b, 0x93, 0xeb),
+            //   cipher_text: vec!(0x8f, 0xf4, 0xce, 0xb6, 0x00, 0xe7, 0xd4, 0x56, 0x96, 0xd0, 0x24, 0x67, 0xf8, 0xe3, 0x0d, 0xf0, 0xd3, 0x38, 0x64, 0xa0, 0x40, 0xa4, 0x1f, 0xfb, 0x9e, 0x4c, 0x2d, 0xa0, 0x9b, 0x92, 0xe8, 0x8b, 0x6f, 0x6b, 0x85, 0x0e, 0x9f, 0x72, 0x58, 0xd8, 0x27, 0xb9, 0xaa, 0xf3, 0x46),
+            //   tag: vec!(0x4e, 0xed, 0xdc, 0x99, 0x78, 0x40, 0x11, 0xf0, 0x75, 0x8b, 0xa5, 0xeb, 0xfb, 0xa6, 0x18, 0x27)
+            // },
+            // TestVector{
+            //   key: [0x54, 0xc9, 0x3d, 0xb9, 0xaa, 0x0e, 0x00, 0xd1, 0x0b, 0x45, 0x04, 0x1c, 0x7a, 0x7e, 0x41, 0xee, 0x9f, 0x90, 0xab, 0x78, 0xae, 0x4c, 0x1b, 0xba, 0x18, 0xd6, 0x73, 0xc3, 0xb3, 0x70, 0xab, 0xde],
+            //   nonce: [0x3f, 0x2d, 0x44, 0xe7, 0xb3, 0x52, 0x36, 0x0f],
+            //   plain_text: vec!(0x12, 0x41, 0xe7, 0xd6, 0xfb, 0xe5, 0xee, 0xf5, 0xd8, 0xaf, 0x9c, 0x2f, 0xb8, 0xb5, 0x16, 0xe0, 0xf1, 0xdd, 0x49, 0xaa, 0x4e, 0xbe, 0x54, 0x91, 0x20, 0x51, 0x94, 0xfe, 0x5a, 0xea, 0x37, 0x04, 0xef, 0xaf, 0x30, 0xd3, 0x92, 0xf4, 0x4c, 0xc9, 0x9e, 0x09, 0x25, 0xb8, 0x44, 0x60, 0xd4, 0x87, 0x33, 0x44),
+            //   aad: vec!(0xf1, 0xd1, 0xb0, 0x8d, 0xd6, 0xfe, 0x96, 0xc4, 0x65, 0x78, 0xc1, 0xd1, 0xad, 0x38, 0x88, 0x18, 0x40, 0xb1, 0x0c, 0xb5, 0xea, 0xe4, 0x1e, 0x5f, 0x05, 0xfe, 0x52, 0x87, 0x22, 0x3f, 0xa7, 0x22, 0x42, 0xae, 0xa4, 0x8c, 0xb3, 0x74, 0xa8, 0x0b, 0xe9, 0x37, 0xb5, 0x41, 0xf9, 0x38, 0x1e, 0xfa, 0x66, 0xbb),
+            //   cipher_text: vec!(0x02, 0x7b, 0x86, 0x86, 0x5b, 0x80, 0xb4, 0xc4, 0xda, 0x82, 0x3a, 0x7d, 0x3d, 0xbc, 0xf5, 0x84, 0x5b, 0xf5, 0x7d, 0x58, 0xee, 0x33, 0x4e, 0xb3, 0x57, 0xe8, 0x23, 0x69, 0xcc, 0x62, 0x89, 0x79, 0xe2, 0x94, 0x78, 0x30, 0xd9, 0xd4, 0x81, 0x7e, 0xfd, 0x3d, 0x0b, 0xc4, 0x77, 0x9f, 0x0b, 0x38, 0x89, 0x43),
+            //   tag: vec!(0x43, 0x03, 0xfa, 0x01, 0x74, 0xac, 0x2b, 0x99, 0x16, 0xbf, 0x89, 0xc5, 0x93, 0xba, 0xee, 0x37)
+            // },
+            // TestVector{
+            //   key: [0x80, 0x8e, 0x0e, 0x73, 0xe9, 0xbc, 0xd2, 0x74, 0xd4, 0xc6, 0xf6, 0x5d, 0xf2, 0xfe, 0x95, 0x78, 0x22, 0xa6, 0x02, 0xf0, 0x39, 0xd4, 0x75, 0x26, 0x16, 0xba, 0x29, 0xa2, 0x89, 0x26, 0xef, 0x4a],
+            //   nonce: [0x1b, 0x9c, 0xd7, 0x3d, 0x2f, 0xc3, 0xcb, 0x8e],
+            //   plain_text: vec!(0x34, 0x36, 0xc7, 0xb5, 0xbe, 0x23, 0x94, 0xaf, 0x7e, 0x88, 0x32, 0x0c, 0x82, 0x32, 0x6a, 0x6d, 0xb3, 0x78, 0x87, 0xff, 0x9d, 0xe4, 0x19, 0x61, 0xc7, 0xd6, 0x54, 0xdd, 0x22, 0xdd, 0x1f, 0x7d, 0x40, 0x44, 0x4d, 0x48, 0xf5, 0xc6, 0x63, 0xb8, 0x6f, 0xf4, 0x1f, 0x3e, 0x15, 0xb5, 0xc8, 0xca, 0x13, 0x37, 0xf9, 0x76, 0x35, 0x85, 0x8f),
+            //   aad: vec!(0xd5, 0x7c, 0xfb, 0xe5, 0xf2, 0x53, 0x80, 0x44, 0x28, 0x2e, 0x53, 0xb2, 0xf0, 0xbb, 0x4e, 0x86, 0xea, 0x22, 0x33, 0x04, 0x1f, 0xb3, 0x6a, 0xdb, 0x83, 0x38, 0xde, 0xd0, 0x92, 0x14, 0x8f, 0x8c, 0x2e, 0x89, 0x4e, 0xf8, 0x76, 0x6a, 0x7e, 0xc2, 0xdd, 0x02, 0xc6, 0xac, 0x5d, 0xba, 0xb0, 0xc3, 0x70, 0x3c, 0x5e, 0x91, 0x19, 0xe3, 0x7c),
+            //   cipher_text: vec!(0x9b, 0x95, 0x0b, 0x3c, 0xaf, 0x7d, 0x25, 0xea, 0xf5, 0xfc, 0xa6, 0xfa, 0x3f, 0xe1, 0x2e, 0xd0, 0x77, 0xd8, 0x0d, 0xcd, 0x55, 0x79, 0x85, 0x12, 0x33, 0xc7, 0x66, 0xbb, 0x8b, 0xb6, 0x13, 0xec, 0x91, 0xd9, 0x25, 0xa9, 0x39, 0xbb, 0x52, 0xfb, 0x88, 0xd5, 0xed, 0xa8, 0x03, 0xcf, 0xe2, 0xa8, 0xcd, 0xa2, 0xe0, 0x55, 0xb9, 0x62, 0xfd),
+            //   tag: vec!(0x6b, 0xf5, 0xb7, 0x18, 0xf5, 0xbb, 0xe1, 0x39, 0x5a, 0x5f, 0xdf, 0xcb, 0xbe, 0xf7, 0x52, 0xf5)
+            // },
+            // TestVector{
+            //   key: [0x4a, 0xdf, 0xe1, 0xa2, 0x6c, 0x56, 0x36, 0x53, 0x6c, 0xd7, 0xcb, 0x72, 0xaa, 0x5b, 0xde, 0xd0, 0xb1, 0xaa, 0x64, 0x48, 0x7a, 0xd0, 0xe4, 0x07, 0x8f, 0x31, 0x1e, 0x87, 0x82, 0x76, 0x8e, 0x97],
+            //   nonce: [0xd6, 0x9e, 0x54, 0xba, 0xde, 0xc1, 0x15, 0x60],
+            //   plain_text: vec!(0x19, 0xb3, 0xf9, 0x41, 0x1c, 0xe8, 0x75, 0xfc, 0xb6, 0x84, 0xcb, 0xdc, 0x07, 0x93, 0x8c, 0x4c, 0x13, 0x47, 0xe1, 0x64, 0xf9, 0x64, 0x0d, 0x37, 0xb2, 0x2f, 0x97, 0x5b, 0x4b, 0x9a, 0x37, 0x3c, 0x43, 0x02, 0xae, 0x0e, 0x7d, 0xfd, 0xeb, 0xa1, 0xe0, 0xd0, 0x0c, 0xed, 0x44, 0x6e, 0x33, 0x8f, 0x4c, 0x5b, 0xc0, 0x1b, 0x4b, 0xec, 0xef, 0x51, 0x15, 0x82, 0x52, 0x76),
+            //   aad: vec!(0xbd, 0xa1, 0xb0, 0xf6, 0xc2, 0xf4, 0xeb, 0x81, 0x21, 0xdc, 0xbd, 0x2e, 0xeb, 0xd9, 0x1a, 0x03, 0xae, 0x1d, 0x6e, 0x05, 0x23, 0xb9, 0xb6, 0xf3, 0x4b, 0x6f, 0x16, 0xce, 0xca, 0x0d, 0x08, 0x66, 0x54, 0xfb, 0x05, 0x52, 0xbf, 0xd5, 0xc8, 0xe1, 0x88, 0x77, 0x30, 0xe1, 0x44, 0x9e, 0xa0, 0x2d, 0x7f, 0x64, 0x7a, 0xe8, 0x35, 0xbc, 0x2d, 0xab, 0x4b, 0xbc, 0x65, 0xb9),
+            //   cipher_text: vec!(0xea, 0x76, 0x5a, 0x82, 0x9d, 0x96, 0x1e, 0x08, 0xba, 0xca, 0xed, 0x80, 0x12, 0x37, 0xef, 0x40, 0x67, 0xdf, 0x38, 0xad, 0x37, 0x37, 0xb7, 0xc6, 0xde, 0x4d, 0xb5, 0x87, 0xa1, 0x02, 0xa8, 0x6f, 0xc4, 0xab, 0xba, 0xab, 0xea, 0x0e, 0xe9, 0x7c, 0x95, 0xca, 0x7f, 0x57, 0x1c, 0x7b, 0xab, 0x6f, 0x38, 0xcb, 0xae, 0x60, 0xcd, 0x6e, 0x6a, 0x4c, 0xe3, 0xc7, 0xa3, 0x20),
+            //   tag: vec!(0xb4, 0x25, 0xcd, 0xf1, 0x0c, 0xd0, 0x12, 0x3a, 0x7e, 0x64, 0xb3, 0x47, 0xc6, 0xb4, 0xb1, 0xf0)
+            // },
+            // TestVector{
+            //   key: [0xeb, 0x3d, 0xb8, 0x6c, 0x14, 0xb7, 0xcc, 0x2e, 0x49, 0x43, 0x45, 0xd0, 0xdf, 0xb4, 0x84, 0x1b, 0xbd, 0x3a, 0xa1, 0xe2, 0xbc, 0x64, 0x0c, 0xca, 0x0c, 0x6c, 0x40, 0x55, 0x20, 0x68, 0x56, 0x39],
+            //   nonce: [0x88, 0xb5, 0x4b, 0x28, 0xd6, 0xda, 0x8c, 0x81],
+            //   plain_text: vec!(0xf7, 0x5c, 0x0a, 0x35, 0x72, 0x71, 0x43, 0x0b, 0x1e, 0xcf, 0xf0, 0x7a, 0x30, 0x7b, 0x6c, 0x29, 0x32, 0x5c, 0x6e, 0x66, 0x93, 0x50, 0x46, 0x70, 0x4a, 0x19, 0x84, 0x5e, 0x62, 0x9f, 0x87, 0xa9, 0xe3, 0xb8, 0xaa, 0x6c, 0x1d, 0xf5, 0x5d, 0xd4, 0x26, 0xa4, 0x87, 0xd5, 0x33, 0xbb, 0x33, 0x3e, 0x46, 0xf0, 0xd3, 0x41, 0x84, 0x64, 0xac, 0x1b, 0xef, 0x05, 0x92, 0x31, 0xf8, 0xe8, 0x7e, 0x62, 0x84),
+            //   aad: vec!(0x34, 0xb0, 0x8b, 0xb0, 0xdf, 0x82, 0x1c, 0x57, 0x3d, 0xcb, 0x56, 0xf5, 0xb8, 0xb4, 0xa9, 0x92, 0x04, 0x65, 0x06, 0x7f, 0x3b, 0x5b, 0xf3, 0xe3, 0x25, 0x4e, 0xa1, 0xda, 0x1a, 0x7f, 0xc9, 0x84, 0x7f, 0xd3, 0x8b, 0xdf, 0xe6, 0xb3, 0x09, 0x27, 0x94, 0x52, 0x63, 0xa9, 0x1f, 0xa2, 0x88, 0xc7, 0xcf, 0x1b, 0xee, 0x0f, 0xdd, 0xb0, 0xfa, 0xdf, 0x59, 0x48, 0xc5, 0xd8, 0x3e, 0xb4, 0x62, 0x35, 0x75),
+            //   cipher_text: vec!(0x14, 0x6e, 0xc8, 0x4f, 0x5d, 0xc1, 0xc9, 0xfe, 0x9d, 0xe3, 0x30, 0x7a, 0x91, 0x82, 0xdb, 0xaa, 0x75, 0x96, 0x5b, 0xf8, 0x5f, 0x5e, 0x64, 0x56, 0x3e, 0x68, 0xd0, 0x39, 0xa5, 0xb6, 0x59, 0xaa, 0x88, 0x63, 0xb8, 0x92, 0x28, 0xed, 0xb9, 0x3f, 0xf3, 0xd8, 0xc3, 0x32, 0x3a, 0xb0, 0xd0, 0x33, 0x00, 0x47, 0x6a, 0xa4, 0xac, 0xa2, 0x06, 0xd4, 0x62, 0x6a, 0x6b, 0x26, 0x9b, 0x20, 0x78, 0x91, 0x2d),
+            //   tag: vec!(0x00, 0x58, 0xa8, 0xdf, 0xf3, 0x2c, 0x29, 0x93, 0x5c, 0x62, 0x21, 0x0c, 0x35, 0x9b, 0xd2, 0x81)
+            // },
+            // TestVector{
+            //   key: [0xdd, 0x5b, 0x49, 0xb5, 0x95, 0x3e, 0x04, 0xd9, 0x26, 0xd6, 0x64, 0xda, 0x3b, 0x65, 0xeb, 0xcf, 0xfb, 0xbf, 0x06, 0xab, 0xbe, 0x93, 0xa3, 0x81, 0x9d, 0xfc, 0x1a, 0xbb, 0xec, 0xba, 0xab, 0x13],
+            //   nonce: [0xc5, 0xc8, 0x00, 0x94, 0x59, 0xb9, 0xe3, 0x1a],
+            //   plain_text: vec!(0xf2, 0x1f, 0x67, 0x06, 0xa4, 0xdc, 0x33, 0xa3, 0x61, 0x36, 0x2c, 0x21, 0x4d, 0xef, 0xd5, 0x6d, 0x35, 0x3b, 0xcb, 0x29, 0x81, 0x1e, 0x58, 0x19, 0xab, 0x3c, 0x5c, 0x2c, 0x13, 0x95, 0x0c, 0x7a, 0xa0, 0x00, 0x0b, 0x9d, 0x1f, 0xe6, 0x9b, 0xb4, 0x64, 0x54, 0x51, 0x4d, 0xcc, 0xe8, 0x8a, 0x4a, 0x5e, 0xda, 0x09, 0x7c, 0x28, 0x1b, 0x81, 0xe5, 0x1d, 0x6a, 0x4d, 0xba, 0x47, 0xc8, 0x03, 0x26, 0xba, 0x6c, 0xea, 0x8e, 0x2b, 0xab),
+            //   aad: vec!(0xfe, 0x6f, 0x4c, 0xbb, 0x00, 0x79, 0x4a, 0xde, 0xa5, 0x9e, 0x9d, 0xe8, 0xb0, 0x3c, 0x7f, 0xdf, 0x48, 0x2e, 0x46, 0xf6, 0xc4, 0x7a, 0x35, 0xf9, 0x69, 0x97, 0x66, 0x9c, 0x73, 0x5e, 0xd5, 0xe7, 0x29, 0xa4, 0x94, 0x16, 0xb4, 0x24, 0x68, 0x77, 0x7e, 0x6a, 0x8d, 0x7a, 0xa1, 0x73, 0xc1, 0x8b, 0x81, 0x77, 0x41, 0x8d, 0xed, 0x60, 0x01, 0x24, 0xa9, 0x8c, 0xbb, 0x65, 0x48, 0x9f, 0x9c, 0x24, 0xa0, 0x4f, 0x1e, 0x71, 0x27, 0xce),
+            //   cipher_text: vec!(0x91, 0x1e, 0xad, 0x61, 0xb2, 0xaa, 0x81, 0xd0, 0x0c, 0x5e, 0xff, 0x53, 0xae, 0xea, 0x3a, 0xb7, 0x13, 0x70, 0x9e, 0xd5, 0x71, 0x76, 0x58, 0x90, 0xd5, 0x58, 0xfb, 0x59, 0xd3, 0x99, 0x3b, 0x45, 0xf5, 0x98, 0xa3, 0x9e, 0x5e, 0xff, 0x4b, 0xe8, 0x44, 0xc4, 0xd4, 0xbd, 0x1e, 0xf9, 0x62, 0x2e, 0x60, 0x41, 0x2b, 0x21, 0x14, 0x00, 0x07, 0xd5, 0x4d, 0xcf, 0x31, 0xb2, 0xc0, 0xe3, 0xe9, 0x8c, 0xf3, 0x3a, 0x00, 0xfd, 0x27, 0xf0),
+            //   tag: vec!(0xd3, 0x8d, 0x67, 0x26, 0x65, 0xe2, 0xc8, 0xc4, 0xa0, 0x79, 0x54, 0xb1, 0x0e, 0xcf, 0xf7, 0xd9)
+            // },
+            // TestVector{
+            //   key: [0x3b, 0x31, 0x9e, 0x40, 0x14, 0x8a, 0x67, 0xdc, 0x0b, 0xb1, 0x92, 0x71, 0xd9, 0x27, 0x2b, 0x32, 0x7b, 0xc5, 0xee, 0xe0, 0x87, 0x17, 0x3d, 0x3d, 0x13, 0x4a, 0xd5, 0x6c, 0x8c, 0x7d, 0xc0, 0x20],
+            //   nonce: [0xce, 0x5c, 0xf6, 0xfe, 0xf8, 0x4d, 0x00, 0x10],
+            //   plain_text: vec!(0x27, 0xb5, 0x62, 0x7b, 0x17, 0xa2, 0xde, 0x31, 0xad, 0x00, 0xfc, 0x2e, 0xcb, 0x34, 0x7d, 0xa0, 0xa3, 0x99, 0xbb, 0x75, 0xcc, 0x6e, 0xad, 0xd4, 0xd6, 0xee, 0x02, 0xde, 0x8f, 0xbd, 0x6a, 0x21, 0x68, 0xd4, 0x76, 0x3b, 0xa9, 0x36, 0x8b, 0xa9, 0x82, 0xe9, 0x7a, 0x2d, 0xb8, 0x12, 0x6d, 0xf0, 0x34, 0x3c, 0xda, 0xd0, 0x6d, 0x2b, 0xc7, 0xd7, 0xe1, 0x2e, 0xec, 0x73, 0x1d, 0x13, 0x0f, 0x8b, 0x87, 0x45, 0xc1, 0x95, 0x4b, 0xfd, 0x1d, 0x71, 0x7b, 0x4e, 0xa2),
+            //   aad: vec!(0xa0, 0x26, 0xb6, 0x63, 0x8f, 0x29, 0x39, 0xec, 0x9c, 0xc2, 0x8d, 0x93, 0x5f, 0xb7, 0x11, 0x31, 0x57, 0xf3, 0xb5, 0xb7, 0xe2, 0x6c, 0x12, 0xf8, 0xf2, 0x5b, 0x36, 0x41, 0x2b, 0x0c, 0xd5, 0x60, 0xb7, 0xf1, 0x1b, 0x62, 0x78, 0x8a, 0x76, 0xbd, 0x17, 0x13, 0x42, 0xe2, 0xae, 0x85, 0x8b, 0xce, 0xcb, 0x82, 0x66, 0xff, 0x84, 0x82, 0xbb, 0xae, 0xd5, 0x93, 0xaf, 0xe8, 0x18, 0xb9, 0x82, 0x9e, 0x05, 0xe8, 0xe2, 0xb2, 0x81, 0xae, 0x77, 0x99, 0x58, 0x01, 0x42),
+            //   cipher_text: vec!(0x36, 0x8f, 0xb6, 0x98, 0x92, 0x44, 0x7b, 0x75, 0x77, 0x8f, 0x1c, 0x52, 0x36, 0xe1, 0xe9, 0xd5, 0xd8, 0x92, 0x55, 0xc3, 0xd6, 0x8d, 0x56, 0x5a, 0x5b, 0xba, 0x4f, 0x52, 0x4d, 0x6a, 0xd2, 0x7d, 0xe1, 0x30, 0x87, 0xf3, 0x01, 0xe2, 0xef, 0x4c, 0x08, 0xf5, 0xe2, 0xc6, 0x12, 0x8b, 0x1d, 0x3e, 0x26, 0xde, 0x84, 0x5c, 0x4a, 0xc4, 0x86, 0x9e, 0x4c, 0x8b, 0xd8, 0x85, 0x8a, 0xd0, 0xd2, 0x6d, 0xec, 0x3b, 0x5d, 0x61, 0xa9, 0xe3, 0x66, 0x6a, 0x39, 0x11, 0xba),
+            //   tag: vec!(0x2e, 0x70, 0x56, 0x4c, 0x39, 0x99, 0xc4, 0x48, 0xd9, 0x2c, 0xc6, 0xdf, 0x29, 0xd0, 0x95, 0xc4)
+            // },
+            // TestVector{
+            //   key: [0x43, 0xbf, 0x97, 0x40, 0x7a, 0x82, 0xd0, 0xf6, 0x84, 0xbb, 0x85, 0x34, 0x23, 0x80, 0xd6, 0x6b, 0x85, 0xfc, 0xc8, 0x1c, 0x3e, 0x22, 0xf1, 0xc0, 0xd9, 0x72, 0xcd, 0x5b, 0xfd, 0xf4, 0x07, 0xf4],
+            //   nonce: [0x8b, 0x6b, 0xa4, 0x94, 0xc5, 0x40, 0xfb, 0xa4],
+            //   plain_text: vec!(0x4b, 0x4c, 0x7e, 0x29, 0x2a, 0x35, 0x7f, 0x56, 0xfd, 0xf5, 0x67, 0xc3, 0x2f, 0xc0, 0xf3, 0x36, 0x08, 0x11, 0x0d, 0x7c, 0xe5, 0xc6, 0x91, 0x12, 0x98, 0x7d, 0x7b, 0x5a, 0x0b, 0xd4, 0x6d, 0x86, 0x27, 0xa7, 0x21, 0xb0, 0xae, 0xd0, 0x70, 0xb5, 0x4e, 0xa9, 0x72, 0x60, 0x84, 0x18, 0x8c, 0x51, 0x8c, 0xba, 0x82, 0x9f, 0x39, 0x20, 0x36, 0x5a, 0xfc, 0x93, 0x82, 0xc6, 0xa5, 0xeb, 0x0d, 0xd3, 0x32, 0xb8, 0x46, 0x12, 0x36, 0x67, 0x35, 0xbe, 0x24, 0x79, 0xb6, 0x3c, 0x9e, 0xfc, 0x7f, 0xf5),
+            //   aad: vec!(0x1e, 0x0a, 0xcf, 0x40, 0x70, 0xe8, 0xd6, 0x75, 0x8b, 0x60, 0xd8, 0x1b, 0x6d, 0x28, 0x9a, 0x4e, 0xcd, 0xc3, 0x0e, 0x3d, 0xe4, 0xf9, 0x09, 0x0c, 0x13, 0x69, 0x1d, 0x5b, 0x93, 0xd5, 0xbb, 0xce, 0xf9, 0x84, 0xf9, 0x09, 0x56, 0xde, 0x53, 0xc5, 0xcf, 0x44, 0xbe, 0x6c, 0x70, 0x44, 0x06, 0x61, 0xfa, 0x58, 0xe6, 0x5d, 0xec, 0x27, 0x34, 0xff, 0x51, 0xd6, 0xd0, 0x3f, 0x57, 0xbd, 0xdd, 0xa1, 0xf4, 0x78, 0x07, 0x24, 0x7e, 0x31, 0x94, 0xe2, 0xf7, 0xdd, 0xd5, 0xf3, 0xca, 0xfd, 0x25, 0x0f),
+            //   cipher_text: vec!(0xd0, 0x07, 0x6c, 0x88, 0xad, 0x4b, 0xc1, 0x2d, 0x77, 0xeb, 0x8a, 0xe8, 0xd9, 0xb5, 0xbf, 0x3a, 0x2c, 0x58, 0x88, 0xa8, 0xd4, 0xc1, 0x52, 0x97, 0xb3, 0x8e, 0xce, 0x5d, 0x64, 0xf6, 0x73, 0x19, 0x1d, 0xc8, 0x15, 0x47, 0x24, 0x0a, 0x0c, 0xbe, 0x06, 0x6c, 0x9c, 0x56, 0x3f, 0x5c, 0x34, 0x24, 0x80, 0x99, 0x71, 0xb5, 0xa0, 0x7d, 0xcc, 0x70, 0xb1, 0x07, 0x30, 0x55, 0x61, 0xce, 0x85, 0xae, 0xcb, 0x0b, 0x0e, 0xa0, 0xe8, 0xb4, 0xff, 0x4d, 0x1e, 0x4f, 0x84, 0x83, 0x69, 0x55, 0xa9, 0x45),
+            //   tag: vec!(0x75, 0xc9, 0x34, 0x74, 0x25, 0xb4, 0x59, 0xaf, 0x6d, 0x99, 0xb1, 0x73, 0x45, 0xc6, 0x1f, 0xf7)
+            // },
+            // TestVector{
+            //   key: [0x12, 0xfc, 0x0b, 0xc9, 0x41, 0x04, 0xed, 0x81, 0x50, 0xbd, 0xe1, 0xe5, 0x68, 0x56, 0xce, 0x3c, 0x57, 0xcd, 0x1c, 0xf6, 0x33, 0x95, 0x4d, 0x22, 0x55, 0x21, 0x40, 0xe1, 0xf4, 0xe7, 0xc6, 0x5d],
+            //   nonce: [0xd3, 0x87, 0x5d, 0x1b, 0x6c, 0x80, 0x83, 0x53],
+            //   plain_text: vec!(0x24, 0x59, 0x20, 0x82, 0xd6, 0xe7, 0x3e, 0xb6, 0x5c, 0x40, 0x9b, 0x26, 0xce, 0xae, 0x03, 0x2e, 0x57, 0xf6, 0x87, 0x75, 0x14, 0x94, 0x7f, 0xc4, 0x5e, 0xb0, 0x07, 0xb8, 0xa6, 0x03, 0x44, 0x94, 0xdd, 0xe5, 0x56, 0x3a, 0xc5, 0x86, 0xea, 0x08, 0x1d, 0xc1, 0x2f, 0xa6, 0xcd, 0xa3, 0x22, 0x66, 0xbe, 0x85, 0x8e, 0x47, 0x48, 0xbe, 0x40, 0xbb, 0x20, 0xf7, 0x13, 0x20, 0x71, 0x1b, 0xf8, 0x4c, 0x3f, 0x0e, 0x27, 0x83, 0xa6, 0x3a, 0xd6, 0xe2, 0x5a, 0x63, 0xb4, 0x4c, 0x37, 0x3a, 0x99, 0xaf, 0x84, 0x5c, 0xdf, 0x45, 0x2c),
+            //   aad: vec!(0xb8, 0xbe, 0x08, 0x46, 0x3e, 0x84, 0xa9, 0x09, 0xd0, 0x71, 0xf5, 0xff, 0x87, 0x21, 0x33, 0x91, 0xb7, 0xda, 0x88, 0x9d, 0xc5, 0x6f, 0xd2, 0xf1, 0xe3, 0xcf, 0x86, 0xa0, 0xa0, 0x3e, 0x2c, 0x8e, 0xaa, 0x2f, 0x53, 0x9b, 0xf7, 0x3f, 0x90, 0xf5, 0x29, 0x8c, 0x26, 0xf2, 0x7e, 0xf4, 0xa6, 0x73, 0xa1, 0x27, 0x84, 0x83, 0x3a, 0xcb, 0x4d, 0x08, 0x61, 0x56, 0x21, 0x42, 0xc9, 0x74, 0xee, 0x37, 0xb0, 0x9a, 0xe7, 0x70, 0x8a, 0x19, 0xf1, 0x4d, 0x1a, 0xd8, 0xc4, 0x02, 0xbd, 0x1e, 0xcf, 0x5e, 0xa2, 0x80, 0xfa, 0xb2, 0x80),
+            //   cipher_text: vec!(0x9d, 0x9a, 0xe6, 0x32, 0x87, 0x11, 0xfb, 0x89, 0x7a, 0x88, 0x46, 0x2d, 0x20, 0xb8, 0xaa, 0x1b, 0x27, 0x81, 0x34, 0xcd, 0xf7, 0xb2, 0x3e, 0x1f, 0x1c, 0x80, 0x9f, 0xa4, 0x08, 0xb6, 0x8a, 0x7b, 0xfc, 0x2b, 0xe6, 0x1a, 0x79, 0x00, 0x08, 0xed, 0xaa, 0x98, 0x82, 0x33, 0x81, 0xf4, 0x5a, 0xe6, 0x5f, 0x71, 0x04, 0x26, 0x89, 0xd8, 0x8a, 0xcf, 0xa5, 0xf6, 0x33, 0x32, 0xf0, 0xfb, 0xa7, 0x37, 0xc4, 0x77, 0x2c, 0x97, 0x2e, 0xba, 0x26, 0x66, 0x40, 0x05, 0x64, 0x52, 0x90, 0x3d, 0x65, 0x22, 0xce, 0xfd, 0x3f, 0x26, 0x4e),
+            //   tag: vec!(0xe9, 0xc9, 0x82, 0xd4, 0xad, 0xe7, 0x39, 0x7b, 0xcf, 0xaa, 0x1e, 0x4c, 0x5a, 0x6c, 0xd5, 0x78)
+            // },
+            // TestVector{
+            //   key: [0x7b, 0x63, 0x00, 0xf7, 0xdc, 0x21, 0xc9, 0xfd, 0xde, 0xaa, 0x71, 0xf4, 0x39, 0xd5, 0x3b, 0x55, 0x3a, 0x7b, 0xf3, 0xe6, 0x9f, 0xf5, 0x15, 0xb5, 0xcb, 0x64, 0x95, 0xd6, 0x52, 0xa0, 0xf9, 0x9c],
+            //   nonce: [0x40, 0xb3, 0x2e, 0x3f, 0xdc, 0x64, 0x64, 0x53],
+            //   plain_text: vec!(0x57, 0x2f, 0x60, 0xd9, 0x8c, 0x8b, 0xec, 0xc8, 0xba, 0x80, 0xdd, 0x6b, 0x8d, 0x2d, 0x0f, 0x7b, 0x7b, 0xbf, 0xd7, 0xe4, 0xab, 0xc2, 0x35, 0xf3, 0x74, 0xab, 0xd4, 0x4d, 0x90, 0x35, 0xc7, 0x65, 0x0a, 0x79, 0xd1, 0xdd, 0x54, 0x5f, 0xa2, 0xf6, 0xfb, 0x0b, 0x5e, 0xba, 0x27, 0x17, 0x79, 0x91, 0x3e, 0x5c, 0x5e, 0xb4, 0x50, 0x52, 0x8e, 0x41, 0x28, 0x90, 0x9a, 0x96, 0xd1, 0x1a, 0x65, 0x2b, 0xf3, 0xf7, 0xae, 0x9d, 0x0d, 0x17, 0xad, 0xbf, 0x61, 0x2e, 0xc9, 0xca, 0x32, 0xe7, 0x3e, 0xf6, 0xe8, 0x7d, 0x7f, 0x4e, 0x21, 0xfe, 0x34, 0x12, 0xce, 0x14),
+            //   aad: vec!(0x9f, 0xf3, 0x77, 0x54, 0x5a, 0x35, 0xcf, 0x1b, 0xfb, 0x77, 0xc7, 0x34, 0xad, 0x90, 0x0c, 0x70, 0x3a, 0xee, 0x6c, 0x31, 0x74, 0xfd, 0xb3, 0x73, 0x66, 0x64, 0x86, 0x30, 0x36, 0xa3, 0xa9, 0xd0, 0x91, 0x63, 0xc2, 0x99, 0x2f, 0x09, 0x3e, 0x24, 0x08, 0x91, 0x1b, 0x87, 0x51, 0xf0, 0x01, 0xe4, 0x93, 0xde, 0xcc, 0x41, 0xe4, 0xee, 0xee, 0xd0, 0x4f, 0x69, 0x8b, 0x6d, 0xae, 0xd4, 0x84, 0x52, 0xa7, 0xe1, 0xa7, 0x4e, 0xc3, 0xb4, 0xf3, 0xdc, 0xf2, 0x15, 0x1c, 0xa2, 0x49, 0xfa, 0x56, 0x8a, 0xa0, 0x84, 0xc8, 0x42, 0x8a, 0x41, 0xf2, 0x0b, 0xe5, 0xfd),
+            //   cipher_text: vec!(0x22, 0x9d, 0xa7, 0x68, 0x44, 0x42, 0x66, 0x39, 0xe2, 0xfd, 0x3e, 0xf2, 0x53, 0xa1, 0x95, 0xe0, 0xa9, 0x3f, 0x08, 0x45, 0x2b, 0xa3, 0x72, 0x19, 0xb6, 0x77, 0x3f, 0x10, 0x31, 0x34, 0xf3, 0xf8, 0x7b, 0x13, 0x45, 0xf9, 0xb4, 0xbf, 0x8c, 0xfc, 0x11, 0x27, 0x7c, 0x31, 0x17, 0x80, 0xa2, 0xb6, 0xe1, 0x9a, 0x36, 0x3b, 0x6a, 0xc2, 0xef, 0xe6, 0xc4, 0xcc, 0x54, 0xa3, 0x9b, 0x14, 0x4e, 0x29, 0xc9, 0x4b, 0x9e, 0xbb, 0xde, 0x6f, 0xd0, 0x94, 0xc3, 0x0f, 0x59, 0xd1, 0xb7, 0x70, 0xeb, 0xf9, 0xfc, 0xad, 0x2a, 0x5c, 0x69, 0x5d, 0xc0, 0x03, 0xbf, 0x51),
+            //   tag: vec!(0xb7, 0x2a, 0xca, 0xb5, 0x01, 0x31, 0xa2, 0x95, 0x58, 0xd5, 0x6a, 0xe7, 0xb9, 0xd4, 0x8e, 0x4e)
+            // },
+            // TestVector{
+            //   key: [0x4a, 0xeb, 0x62, 0xf0, 0x24, 0xe1, 0x87, 0x60, 0x6e, 0xe7, 0xcc, 0x9f, 0x58, 0x65, 0xc3, 0x91, 0xc4, 0x3d, 0xf1, 0x96, 0x3f, 0x45, 0x9c, 0x87, 0xba, 0x00, 0xe4, 0x4b, 0xb1, 0x63, 0xa8, 0x66],
+            //   nonce: [0x95, 0x59, 0xbd, 0x08, 0x71, 0x8b, 0x75, 0xaf],
+            //   plain_text: vec!(0xc5, 0xd5, 0x86, 0xce, 0xec, 0xe6, 0xf4, 0x18, 0x12, 0xc9, 0x69, 0xbc, 0xf1, 0xe7, 0x27, 0xfe, 0x6f, 0xf8, 0xd1, 0xae, 0x8c, 0x8c, 0x52, 0x36, 0x7c, 0x61, 0x2c, 0xaa, 0x7c, 0xdf, 0x50, 0xe0, 0x66, 0x2f, 0x5d, 0xff, 0xc5, 0xea, 0x7d, 0x3c, 0xc3, 0x94, 0x00, 0xdf, 0xe3, 0xdc, 0x18, 0x97, 0x90, 0x5f, 0x64, 0x90, 0xfd, 0x77, 0x47, 0xb5, 0xf5, 0xf9, 0x84, 0x27, 0x39, 0xc6, 0x7d, 0x07, 0xce, 0x7c, 0x33, 0x9a, 0x5b, 0x39, 0x97, 0xa7, 0xfb, 0x4c, 0xd0, 0xd8, 0xe4, 0x81, 0x7f, 0xf8, 0x91, 0x6b, 0x25, 0x1c, 0x11, 0xef, 0x91, 0x91, 0x67, 0xf8, 0x58, 0xe4, 0x15, 0x04, 0xb9),
+            //   aad: vec!(0x51, 0xf5, 0xb5, 0x03, 0xb7, 0x3a, 0x5d, 0xe8, 0xb9, 0x65, 0x34, 0xc2, 0xa3, 0xf2, 0xd8, 0x59, 0xec, 0xe0, 0xbd, 0x06, 0x3e, 0xa6, 0xdf, 0xa4, 0x86, 0xa7, 0xee, 0xc9, 0x9f, 0x6c, 0x02, 0x09, 0x83, 0xf7, 0x14, 0x8c, 0xcc, 0xb8, 0x62, 0x02, 0xcf, 0x96, 0x85, 0xcc, 0x1c, 0xc2, 0x66, 0x93, 0x0f, 0x04, 0xe5, 0x36, 0xad, 0x8b, 0xc2, 0x60, 0x94, 0x25, 0x2b, 0xaa, 0x46, 0x06, 0xd8, 0x83, 0xbd, 0x2a, 0xee, 0xd6, 0xb4, 0x30, 0x15, 0x22, 0x02, 0xe9, 0xb6, 0xcc, 0x79, 0x7f, 0xf2, 0x4f, 0xc3, 0x65, 0x31, 0x5e, 0xd6, 0x73, 0x91, 0x37, 0x4c, 0x13, 0x57, 0xc9, 0xa8, 0x45, 0xf2),
+            //   cipher_text: vec!(0x25, 0x2e, 0xa4, 0x2b, 0x6e, 0x57, 0x40, 0x30, 0x68, 0x16, 0x97, 0x4a, 0x4f, 0xe6, 0x7b, 0x66, 0xe7, 0x93, 0xeb, 0xe0, 0x91, 0x47, 0x78, 0xef, 0x48, 0x5d, 0x55, 0x28, 0x8e, 0xb6, 0xc9, 0xc4, 0x5f, 0xa3, 0x4a, 0xc8, 0x53, 0xdc, 0x7a, 0x39, 0x25, 0x25, 0x20, 0x51, 0x4c, 0x3c, 0xb3, 0x4c, 0x72, 0xb9, 0x73, 0xb1, 0x4b, 0x32, 0xbc, 0x25, 0x76, 0x87, 0xd3, 0x98, 0xf3, 0x6f, 0x64, 0xcc, 0x2a, 0x66, 0x8f, 0xaf, 0xfa, 0x73, 0x05, 0xab, 0x24, 0x01, 0x71, 0x34, 0x3b, 0x5f, 0x9f, 0x49, 0xb6, 0xc2, 0x19, 0x7e, 0x4f, 0xbe, 0x18, 0x7b, 0x10, 0x54, 0x0d, 0x7c, 0xdc, 0xfa, 0x37),
+            //   tag: vec!(0x71, 0x1f, 0xf3, 0x3e, 0xf8, 0xd2, 0xb0, 0x67, 0xa1, 0xb8, 0x5c, 0x64, 0xf3, 0x2f, 0x18, 0x14)
+            // },
+            // TestVector{
+            //   key: [0x9a, 0x19, 0xe7, 0x2f, 0x00, 0x5c, 0xae, 0x1a, 0xe7, 0x8b, 0x8e, 0x35, 0x0d, 0x7a, 0xab, 0xe5, 0x9f, 0xc8, 0x84, 0x59, 0x99, 0xe8, 0xc5, 0x2f, 0xad, 0x54, 0x5b, 0x94, 0x2c, 0x22, 0x5e, 0xaf],
+            //   nonce: [0xd9, 0xda, 0xe2, 0xea, 0x8d, 0x2f, 0xfc, 0x31],
+            //   plain_text: vec!(0x21, 0x10, 0x37, 0x8d, 0x85, 0x6d, 0xed, 0x07, 0xeb, 0x2b, 0xe8, 0xe8, 0xf4, 0x33, 0x08, 0xe0, 0xc7, 0x5b, 0xc8, 0xa3, 0xfc, 0xc7, 0xb1, 0x77, 0x3b, 0x07, 0x25, 0xb7, 0xde, 0x49, 0xf6, 0xa1, 0x66, 0xc4, 0x52, 0x8e, 0x64, 0x12, 0x0b, 0xdf, 0x7c, 0x97, 0x76, 0x61, 0x5d, 0x3c, 0xe6, 0xfe, 0xeb, 0x03, 0xde, 0x96, 0x4a, 0x7b, 0x91, 0x92, 0x06, 0xa7, 0x73, 0x92, 0xf8, 0x04, 0x37, 0xfa, 0xce, 0xb6, 0x74, 0x58, 0x45, 0xca, 0xfc, 0x16, 0x6e, 0x1c, 0x13, 0xb6, 0x8e, 0x70, 0xca, 0x2a, 0x1d, 0x00, 0xc7, 0x17, 0x37, 0xb8, 0xfc, 0xbb, 0xbd, 0x50, 0x90, 0x25, 0x65, 0xc3, 0x21, 0x59, 0xe0, 0x5f, 0xcd, 0x23),
+            //   aad: vec!(0x1c, 0xd7, 0x3b, 0x72, 0xc4, 0xe1, 0x03, 0xaf, 0xbe, 0xfd, 0x7c, 0x77, 0x7e, 0x04, 0x80, 0xf3, 0xf5, 0xe6, 0x8c, 0x60, 0xb8, 0x5b, 0xd2, 0xe7, 0x1e, 0xf5, 0xca, 0xeb, 0xb1, 0x75, 0xd7, 0xfc, 0x65, 0x35, 0xd3, 0x9f, 0x38, 0xf9, 0x2c, 0x24, 0xf2, 0xeb, 0x0f, 0xe9, 0x7d, 0x87, 0x8e, 0xd3, 0xd5, 0x96, 0x7c, 0x0b, 0xb4, 0x39, 0x4a, 0x5d, 0x41, 0xf7, 0xd3, 0x4c, 0xda, 0x6e, 0x15, 0x23, 0xd3, 0x84, 0x8f, 0x04, 0x9c, 0xde, 0x55, 0x4a, 0x7d, 0x31, 0xe1, 0xaf, 0xea, 0xb5, 0xd3, 0xe6, 0x15, 0x0f, 0x85, 0x85, 0x83, 0x35, 0xcb, 0xd2, 0x8c, 0x8a, 0x7f, 0x87, 0xd5, 0x28, 0x05, 0x8d, 0xf5, 0x0e, 0xea, 0x06),
+            //   cipher_text: vec!(0x5f, 0x00, 0x9f, 0xbc, 0xe4, 0xec, 0x8e, 0x4c, 0xa9, 0xd8, 0xd4, 0x22, 0x58, 0xb1, 0xa3, 0xe4, 0xe9, 0x20, 0xb2, 0xfb, 0xad, 0x33, 0xd5, 0xe9, 0xf0, 0x75, 0x57, 0xd9, 0x59, 0x5e, 0x84, 0x10, 0x25, 0x19, 0x3b, 0x52, 0x1b, 0xa4, 0x40, 0x11, 0x0d, 0xd8, 0x39, 0x58, 0xe8, 0xee, 0x30, 0x21, 0x9d, 0x95, 0x2b, 0x41, 0x8e, 0x98, 0xa6, 0xc6, 0x24, 0x89, 0x4a, 0xa2, 0x48, 0xae, 0xdc, 0x06, 0x78, 0xf2, 0xd2, 0x63, 0xe7, 0xbf, 0xaf, 0x54, 0xca, 0x37, 0x9f, 0xef, 0x6c, 0x5d, 0x2f, 0x7a, 0xc4, 0x22, 0xea, 0x4b, 0x43, 0x69, 0x40, 0x8b, 0x82, 0xd6, 0x22, 0x5a, 0x7a, 0x2c, 0xf9, 0xa9, 0xf4, 0x6f, 0xd4, 0xef),
+            //   tag: vec!(0xaa, 0x0a, 0x5f, 0xa7, 0xd3, 0xcf, 0x71, 0x7a, 0x47, 0x04, 0xa5, 0x99, 0x73, 0xb1, 0xcd, 0x15)
+            // },
+            // TestVector{
+            //   key: [0xba, 0x1d, 0x0b, 0x33, 0x29, 0xec, 0xc0, 0x09, 0xf1, 0xda, 0x0f, 0xab, 0x4c, 0x85, 0x4b, 0x00, 0xad, 0x94, 0x48, 0x70, 0xfd, 0xca, 0x56, 0x18, 0x38, 0xe3, 0x8b, 0xad, 0x36, 0x4d, 0xa5, 0x07],
+            //   nonce: [0x8a, 0x81, 0xc9, 0x2b, 0x37, 0x22, 0x1f, 0x2f],
+            //   plain_text: vec!(0x62, 0x89, 0x94, 0x4f, 0xfa, 0x3c, 0xce, 0xa4, 0xbf, 0x25, 0xcd, 0x60, 0x1b, 0x27, 0x1f, 0x64, 0xe6, 0xde, 0xb0, 0xeb, 0xa7, 0x7d, 0x65, 0xef, 0xb4, 0xd6, 0x9c, 0xa9, 0x3e, 0x01, 0x99, 0x6e, 0x47, 0x27, 0x16, 0x8b, 0x6f, 0x74, 0xf3, 0xcc, 0xf1, 0x7b, 0xd4, 0x47, 0x15, 0xf2, 0x3c, 0xeb, 0x8f, 0xc0, 0x30, 0xc0, 0xe0, 0x35, 0xe7, 0x7f, 0x53, 0x26, 0x3d, 0xb0, 0x25, 0x02, 0x1f, 0xd2, 0xd0, 0x4b, 0x87, 0xa1, 0xb5, 0x4b, 0x12, 0x22, 0x9c, 0x5e, 0x86, 0x04, 0x81, 0x45, 0x2a, 0x80, 0xa1, 0x25, 0xcb, 0x06, 0x93, 0xa2, 0xba, 0x1b, 0x47, 0xe2, 0x8e, 0xe7, 0xcb, 0xaf, 0x9e, 0x68, 0x3c, 0x17, 0x82, 0x32, 0xc7, 0xf6, 0xd3, 0x4f, 0x97),
+            //   aad: vec!(0xe5, 0x78, 0x83, 0x96, 0x1b, 0x8d, 0x04, 0x1d, 0x9b, 0x9e, 0xea, 0xdd, 0xcf, 0xd6, 0x1f, 0xa9, 0xf5, 0x92, 0x13, 0xf6, 0x65, 0x71, 0xfa, 0xdf, 0xff, 0xfd, 0xd1, 0x49, 0x8b, 0x9b, 0x01, 0x4f, 0x1e, 0xf2, 0xe7, 0xe5, 0x6c, 0x30, 0x44, 0xd7, 0xf9, 0xfa, 0x7a, 0x14, 0x03, 0xa1, 0x16, 0x9e, 0x86, 0x43, 0x0a, 0x2a, 0x78, 0x21, 0x37, 0x09, 0x3f, 0x54, 0x56, 0xe1, 0x42, 0xaa, 0xd0, 0x3a, 0x5f, 0x7a, 0x66, 0xd3, 0x80, 0x09, 0xdd, 0x01, 0xb7, 0xfc, 0x02, 0xc9, 0xcf, 0x61, 0x64, 0x2d, 0xed, 0xaf, 0x7c, 0xc8, 0xd4, 0x60, 0x66, 0xc2, 0x81, 0xee, 0x17, 0x78, 0x06, 0x74, 0xc3, 0xa3, 0x6e, 0xae, 0x66, 0xc5, 0x8d, 0x2d, 0x76, 0x50, 0x75),
+            //   cipher_text: vec!(0x9c, 0x44, 0xd9, 0x13, 0x5d, 0xb0, 0xdb, 0xf8, 0x1c, 0x86, 0x2c, 0x1f, 0x69, 0xbe, 0xc5, 0x5a, 0x27, 0x97, 0x94, 0xcd, 0xd2, 0x9a, 0x58, 0xe6, 0x19, 0x09, 0xaa, 0x29, 0xec, 0x4c, 0x12, 0x0c, 0x9c, 0x5a, 0x50, 0x8d, 0x85, 0x6b, 0x9e, 0x56, 0x13, 0x80, 0x95, 0x71, 0x4a, 0x4b, 0xb5, 0x84, 0x02, 0xa1, 0xad, 0x06, 0x77, 0x4c, 0xf4, 0xec, 0xdf, 0x22, 0x73, 0x83, 0x9c, 0x00, 0x07, 0xcb, 0x88, 0xb5, 0x44, 0x4b, 0x25, 0xc7, 0x6f, 0x6d, 0x24, 0x24, 0x28, 0x11, 0x01, 0xd0, 0x43, 0xfc, 0x63, 0x69, 0xeb, 0xb3, 0xb2, 0xff, 0x63, 0xcd, 0xb0, 0xf1, 0x1a, 0x6e, 0xa1, 0xb8, 0xa7, 0xda, 0xfc, 0x80, 0xcd, 0xae, 0xf2, 0x81, 0x3f, 0xa6, 0x61),
+            //   tag: vec!(0x65, 0xc7, 0x46, 0xf6, 0x59, 0xbc, 0xbd, 0xcd, 0x05, 0x4e, 0x76, 0x8c, 0x57, 0xc8, 0x48, 0xc9)
+            // },
+            // TestVector{
+            //   key: [0x0c, 0xf8, 0xc7, 0x3a, 0x6c, 0xff, 0xc1, 0xb8, 0xb2, 0xf5, 0xd3, 0x20, 0xda, 0x1d, 0x85, 0x9d, 0x31, 0x43, 0x74, 0xe4, 0xa9, 0x46, 0x8d, 0xb7, 0xfd, 0x42, 0xc8, 0xd2, 0x70, 0xb7, 0x61, 0x3a],
+            //   nonce: [0x3c, 0x4c, 0x6f, 0x02, 0x81, 0x84, 0x1a, 0xff],
+            //   plain_text: vec!(0x44, 0x34, 0x72, 0x8d, 0x23, 0x46, 0x03, 0xc9, 0x16, 0xe2, 0xfa, 0xa0, 0x6b, 0x25, 0xd8, 0x3b, 0xad, 0x33, 0x48, 0x99, 0x0e, 0xcd, 0xe2, 0x34, 0x43, 0x68, 0xd1, 0xa7, 0xaf, 0x13, 0x09, 0xbd, 0x04, 0x25, 0x1b, 0xb2, 0xe0, 0xb7, 0x20, 0x44, 0x94, 0x8f, 0x8d, 0xea, 0x33, 0xcc, 0xe2, 0x61, 0x82, 0x83, 0xb6, 0xaf, 0x74, 0x20, 0x73, 0xa9, 0x58, 0x6b, 0x26, 0xc1, 0x08, 0x93, 0x35, 0xfe, 0x73, 0x51, 0x41, 0xe0, 0x99, 0x78, 0x5a, 0x12, 0x35, 0x81, 0x0a, 0x3a, 0x67, 0xff, 0x30, 0x9e, 0x2f, 0x0c, 0xe6, 0x82, 0x20, 0xba, 0x00, 0x77, 0xad, 0x1a, 0x5d, 0xc1, 0xa4, 0xae, 0xf8, 0x98, 0xa3, 0xb9, 0xff, 0x8f, 0x5a, 0xd7, 0xfe, 0x60, 0x14, 0x9b, 0xd0, 0xbd, 0x6d, 0x83),
+            //   aad: vec!(0xa3, 0x8d, 0x09, 0xa4, 0xf1, 0xc9, 0x24, 0x16, 0x23, 0xc6, 0x39, 0xb7, 0x68, 0x8d, 0x8d, 0x35, 0x34, 0x5e, 0xa5, 0x82, 0x40, 0x80, 0xc9, 0xd7, 0x4e, 0x43, 0x52, 0x91, 0x9d, 0xb6, 0x3c, 0x74, 0xd3, 0x18, 0xf1, 0x9e, 0x1c, 0xbb, 0x9b, 0x14, 0xee, 0xbd, 0x7c, 0x74, 0xb0, 0xad, 0x01, 0x19, 0x24, 0x76, 0x51, 0x91, 0x1f, 0x35, 0x51, 0x58, 0x3e, 0x74, 0x9e, 0xa5, 0x0f, 0xf6, 0x48, 0x85, 0x8d, 0xca, 0xaa, 0x78, 0x9b, 0x74, 0x19, 0xd9, 0xe9, 0x3a, 0x5b, 0xf6, 0xc8, 0x16, 0x71, 0x88, 0xdb, 0xac, 0x2f, 0x36, 0x80, 0x43, 0x80, 0xdb, 0x32, 0x52, 0x01, 0x98, 0x2b, 0x8b, 0x06, 0x59, 0x7e, 0xfe, 0xb7, 0x68, 0x45, 0x46, 0xb2, 0x72, 0x64, 0x29, 0x41, 0x59, 0x1e, 0x92),
+            //   cipher_text: vec!(0xbd, 0xfb, 0xfe, 0xa2, 0x61, 0xb1, 0xf4, 0xc1, 0x34, 0x44, 0x53, 0x21, 0xdb, 0x9e, 0x6e, 0x40, 0x47, 0x6e, 0x2d, 0xd2, 0xf4, 0xe4, 0xdb, 0xe8, 0x6e, 0x31, 0xd6, 0xa1, 0x16, 0xd2, 0x58, 0x30, 0x76, 0x2e, 0x06, 0x5b, 0x07, 0xb1, 0x1a, 0x37, 0x99, 0xaa, 0xb9, 0x3a, 0x94, 0xb4, 0xf9, 0x8c, 0x31, 0xc0, 0xfa, 0xeb, 0x77, 0xec, 0x52, 0xc0, 0x20, 0x48, 0xe9, 0x57, 0x92, 0x57, 0xe6, 0x7f, 0x5a, 0x6b, 0xae, 0x9b, 0xc6, 0x52, 0x10, 0xc2, 0x5b, 0x37, 0xfc, 0x16, 0xee, 0x93, 0xbd, 0xa8, 0x8f, 0xd5, 0xf3, 0x0a, 0x53, 0x3e, 0x47, 0x0b, 0x61, 0x88, 0xc6, 0xce, 0x57, 0x39, 0xfa, 0x3e, 0x90, 0xf7, 0x71, 0x20, 0xb4, 0x90, 0xfc, 0x10, 0x27, 0x96, 0x4f, 0x27, 0x7f, 0x40),
+            //   tag: vec!(0x49, 0x93, 0xee, 0x95, 0x82, 0xf5, 0x8e, 0xab, 0xdb, 0x26, 0xb9, 0x8c, 0x4d, 0x56, 0xa2, 0x44)
+            // },
+            // TestVector{
+            //   key: [0x69, 0xf4, 0xe5, 0x78, 0x8d, 0x48, 0x6a, 0x75, 0xad, 0xf9, 0x20, 0x7d, 0xf1, 0xbd, 0x26, 0x2d, 0xd2, 0xfe, 0x3d, 0xd3, 0xa0, 0x23, 0x64, 0x20, 0x39, 0x0d, 0x16, 0xe2, 0xa3, 0x04, 0x04, 0x66],
+            //   nonce: [0x62, 0x55, 0xbf, 0x5c, 0x71, 0xbb, 0x27, 0xd1],
+            //   plain_text: vec!(0xc1, 0x50, 0x48, 0xca, 0x29, 0x41, 0xef, 0x96, 0x00, 0xe7, 0x67, 0xa5, 0x04, 0x5a, 0xa9, 0x8a, 0xc6, 0x15, 0x22, 0x5b, 0x80, 0x5a, 0x9f, 0xbd, 0xa3, 0xac, 0x63, 0x01, 0xcd, 0x5a, 0x66, 0xae, 0xf6, 0x11, 0x40, 0x0f, 0xa3, 0xbc, 0x04, 0x83, 0x8e, 0xad, 0x99, 0x24, 0xd3, 0x82, 0xbe, 0xf8, 0x25, 0x1a, 0x47, 0xf1, 0xe4, 0x87, 0xd2, 0xf3, 0xca, 0x4b, 0xcc, 0xd3, 0x47, 0x6a, 0x6c, 0xa7, 0xf1, 0x3e, 0x94, 0xfd, 0x63, 0x9a, 0x25, 0x9e, 0xf2, 0x3c, 0xc2, 0xf8, 0xb8, 0xd2, 0x48, 0xa4, 0x71, 0xd3, 0x0a, 0xc9, 0x21, 0x96, 0x31, 0xc3, 0xe6, 0x98, 0x51, 0x00, 0xdc, 0x45, 0xe0, 0xb5, 0x9b, 0x8f, 0xc6, 0x20, 0x46, 0x30, 0x91, 0x65, 0xdd, 0xb6, 0xf0, 0x92, 0xda, 0x3a, 0x4f, 0x06, 0x7c, 0x8a, 0x44),
+            //   aad: vec!(0x0c, 0x83, 0x03, 0x95, 0x04, 0xc8, 0x46, 0x4b, 0x49, 0xd6, 0x3b, 0x7f, 0x94, 0x48, 0x02, 0xf0, 0xd3, 0x9c, 0x85, 0xe9, 0xf3, 0x74, 0x5e, 0x25, 0x0f, 0x10, 0x11, 0x9f, 0xa2, 0xc9, 0x60, 0x49, 0x0f, 0x75, 0xae, 0x4d, 0xce, 0xd8, 0x50, 0x3b, 0x15, 0x6d, 0x07, 0x2a, 0x69, 0xf2, 0x04, 0x00, 0xe9, 0x49, 0x4a, 0xb2, 0xfa, 0x58, 0x44, 0x6c, 0x25, 0x5d, 0x82, 0xff, 0x0b, 0xe4, 0xb7, 0xe4, 0x30, 0x46, 0x58, 0x0b, 0xc1, 0xcf, 0x34, 0x06, 0x0c, 0x6f, 0x07, 0x6c, 0x72, 0xea, 0x45, 0x5c, 0x36, 0x87, 0x38, 0x1a, 0x3b, 0x90, 0x8e, 0x15, 0x2b, 0x10, 0xc9, 0x5c, 0x7b, 0x94, 0x15, 0x5b, 0x0b, 0x4b, 0x30, 0x3b, 0x77, 0x64, 0xa8, 0xa2, 0x7d, 0x1d, 0xb0, 0xa8, 0x85, 0xf1, 0x04, 0x0d, 0x5d, 0xbc, 0xc3),
+            //   cipher_text: vec!(0xf0, 0xbb, 0x2b, 0x73, 0xd9, 0x4f, 0x2a, 0x7c, 0xef, 0x70, 0xfe, 0x77, 0xe0, 0x54, 0xf2, 0x06, 0x99, 0x8e, 0xac, 0xf2, 0xb8, 0x6c, 0x05, 0xc4, 0xfa, 0x3f, 0x40, 0xf2, 0xb8, 0xce, 0xbf, 0x03, 0x4f, 0xe1, 0x7b, 0xcb, 0xee, 0x4d, 0xea, 0x82, 0x1f, 0x51, 0xc1, 0x8c, 0x0a, 0xa8, 0x5b, 0x16, 0x0f, 0x85, 0x08, 0xbd, 0x1d, 0xc4, 0x55, 0xcc, 0x7f, 0x49, 0x66, 0x8b, 0x1f, 0xb2, 0x55, 0x57, 0xcd, 0xae, 0x14, 0x7b, 0xf2, 0x39, 0x9e, 0x07, 0xfc, 0xac, 0xac, 0xa1, 0x8e, 0xcc, 0xde, 0xd7, 0x41, 0xe0, 0x26, 0xef, 0x25, 0x36, 0x5a, 0x6b, 0x0f, 0x44, 0xa6, 0xb3, 0xdd, 0x97, 0x5e, 0xe6, 0xbb, 0x58, 0x0f, 0x5f, 0xcc, 0xd0, 0x40, 0xb7, 0x3c, 0x18, 0xb0, 0xfb, 0xf8, 0xf6, 0x31, 0x99, 0xba, 0x10, 0xfe),
+            //   tag: vec!(0x42, 0x36, 0xa8, 0x75, 0x0f, 0x0c, 0xaf, 0xee, 0x3c, 0x4a, 0x06, 0xa5, 0x77, 0xa8, 0x5c, 0xb3)
+            // },
+            // TestVector{
+            //   key: [0xad, 0x7b, 0x94, 0x09, 0x14, 0x7a, 0x89, 0x66, 0x48, 0xa2, 0xa2, 0xfe, 0x21, 0x28, 0xf7, 0x90, 0x22, 0xa7, 0x0d, 0x96, 0xdc, 0x48, 0x27, 0x30, 0xcd, 0x85, 0xc7, 0x0d, 0xb4, 0x92, 0xb6, 0x38],
+            //   nonce: [0xa2, 0x8a, 0x6d, 0xed, 0xf3, 0xf2, 0xb0, 0x1a],
+            //   plain_text: vec!(0x79, 0x1d, 0x29, 0x3f, 0xf0, 0xa3, 0xb8, 0x51, 0x0b, 0x4d, 0x49, 0x4b, 0x30, 0xf5, 0x0b, 0x38, 0xa0, 0x16, 0x38, 0xbf, 0x13, 0x0e, 0x58, 0xc7, 0x60, 0x19, 0x04, 0xf1, 0x2c, 0xb8, 0x90, 0x08, 0x71, 0xe8, 0xcf, 0x3d, 0x50, 0xab, 0xd4, 0xd3, 0x4f, 0xda, 0x12, 0x2c, 0x76, 0xdf, 0xee, 0x5b, 0x7f, 0x82, 0xcd, 0x6e, 0x85, 0x90, 0x64, 0x75, 0x35, 0xc9, 0x15, 0xae, 0x08, 0x71, 0x4e, 0x42, 0x7d, 0xa5, 0x2f, 0x80, 0xae, 0xf0, 0x9f, 0x40, 0x04, 0x00, 0x36, 0x03, 0x4c, 0xa5, 0x27, 0x18, 0xea, 0x68, 0x31, 0x3c, 0x53, 0x4e, 0x7a, 0x04, 0x5c, 0xd5, 0x17, 0x45, 0xec, 0x52, 0xf2, 0xe1, 0xb5, 0x94, 0x63, 0xdb, 0x07, 0xde, 0x7c, 0xa4, 0x01, 0xc6, 0xf6, 0x45, 0x38, 0x41, 0xd2, 0x47, 0xf3, 0x70, 0x34, 0x1b, 0x2d, 0xbc, 0x12, 0x12),
+            //   aad: vec!(0x9a, 0x6d, 0xef, 0xdd, 0xb9, 0xb8, 0xd5, 0xc2, 0x4a, 0x26, 0xdd, 0x80, 0x96, 0xf5, 0xb8, 0xc3, 0xaf, 0x7a, 0x89, 0xe1, 0xf7, 0xd8, 0x86, 0xf5, 0x60, 0xfa, 0xbb, 0xe6, 0x4f, 0x14, 0xdb, 0x83, 0x8d, 0x6e, 0xb9, 0xd6, 0x87, 0x9f, 0x4f, 0x0b, 0x76, 0x9f, 0xe1, 0xf9, 0xee, 0xbf, 0x67, 0xfc, 0xd4, 0x7b, 0x6f, 0x9c, 0xeb, 0x48, 0x40, 0xb2, 0xdb, 0xa7, 0x58, 0x7e, 0x98, 0xdc, 0x5c, 0xae, 0x18, 0x6e, 0xf2, 0xa0, 0xf8, 0x60, 0x10, 0x60, 0xe8, 0x05, 0x8d, 0x9d, 0xda, 0x81, 0x2d, 0x91, 0x38, 0x7c, 0x58, 0x3d, 0xa7, 0x01, 0xd2, 0xba, 0x33, 0x47, 0xf2, 0x85, 0xc5, 0xd4, 0x43, 0x85, 0xa2, 0xb0, 0xbf, 0x07, 0x15, 0x0c, 0xbc, 0x95, 0xe7, 0xfc, 0xfa, 0x8a, 0xe0, 0x71, 0x32, 0x84, 0x9a, 0x02, 0x3c, 0x98, 0x81, 0x7c, 0x03, 0xd2),
+            //   cipher_text: vec!(0xc2, 0xf1, 0x09, 0xd6, 0xd9, 0x4f, 0x77, 0xa7, 0x28, 0x9c, 0x8a, 0x2a, 0xb3, 0x3b, 0xc6, 0xa9, 0x8d, 0x97, 0x65, 0x54, 0x72, 0x1b, 0x0c, 0x72, 0x6c, 0xbf, 0x41, 0x21, 0x06, 0x94, 0x73, 0xe6, 0x2b, 0xa3, 0x6e, 0x70, 0x90, 0xe0, 0x24, 0x14, 0xf3, 0xed, 0xc2, 0x5c, 0x5d, 0x83, 0xac, 0x80, 0xb4, 0x9a, 0xd5, 0x28, 0xcd, 0xa1, 0xe3, 0xad, 0x81, 0x5b, 0x5a, 0x8c, 0x8a, 0xe9, 0xad, 0x07, 0x53, 0xde, 0x72, 0x53, 0x19, 0xdf, 0x23, 0x69, 0x83, 0xab, 0xd3, 0xf6, 0x9a, 0xb4, 0x46, 0x5d, 0x9b, 0x80, 0x6c, 0x07, 0x5b, 0x18, 0x96, 0xd4, 0x0b, 0xdb, 0xa7, 0x2d, 0x73, 0xba, 0x84, 0xc4, 0xa5, 0x30, 0x89, 0x6e, 0xb9, 0x4f, 0xfc, 0xcf, 0x5f, 0xb6, 0x7e, 0xb5, 0x91, 0x19, 0xe6, 0x6a, 0x18, 0x61, 0x87, 0x22, 0x18, 0xf9, 0x28, 0xcf),
+            //   tag: vec!(0xe4, 0x8d, 0xc0, 0x15, 0x3d, 0x5b, 0x0f, 0x7e, 0xdb, 0x76, 0xfc, 0x97, 0xa0, 0x22, 0x49, 0x87)
+            // },
+            // TestVector{
+            //   key: [0x48, 0x47, 0x0d, 0xa9, 0x82, 0x28, 0xc9, 0xb5, 0x3f, 0x58, 0x74, 0x76, 0x73, 0x50, 0x4f, 0x74, 0xca, 0x17, 0x37, 0xd7, 0xd4, 0xbb, 0x6d, 0xbf, 0x7c, 0x0c, 0xba, 0x6c, 0xa4, 0x2f, 0x80, 0xb9],
+            //   nonce: [0x56, 0xfb, 0x49, 0x23, 0xa9, 0x7e, 0x93, 0x20],
+            //   plain_text: vec!(0xbc, 0x66, 0x26, 0xd6, 0x51, 0xe2, 0xb2, 0x37, 0xf2, 0x2e, 0xe5, 0x16, 0x08, 0xdd, 0xcf, 0xfe, 0xba, 0x5f, 0x31, 0xc2, 0x6d, 0xf7, 0x2f, 0x44, 0x3f, 0x70, 0x1f, 0x2b, 0x08, 0x5d, 0x6f, 0x34, 0xf8, 0x06, 0xe2, 0x96, 0x73, 0x58, 0x4c, 0xb2, 0x15, 0x22, 0x17, 0x9e, 0xdb, 0x62, 0xa8, 0x24, 0x27, 0xd9, 0x46, 0xac, 0xab, 0xce, 0x06, 0x5b, 0x88, 0xb2, 0x87, 0x8e, 0x9e, 0xb8, 0x7e, 0xd1, 0x00, 0x4e, 0x55, 0xef, 0x58, 0xf5, 0x1e, 0xc4, 0x63, 0x75, 0xac, 0x54, 0x2c, 0x57, 0x82, 0x72, 0x5f, 0xf0, 0x13, 0x13, 0x6c, 0xb5, 0x06, 0xfc, 0xf9, 0x94, 0x96, 0xe1, 0x3f, 0xcd, 0x22, 0x4b, 0x8a, 0x74, 0xa9, 0x71, 0xcc, 0x8d, 0xdb, 0x8b, 0x39, 0x3c, 0xcc, 0x6a, 0xc9, 0x10, 0xbd, 0x19, 0x06, 0xea, 0x9f, 0x2e, 0xd8, 0xa5, 0xd0, 0x66, 0xdc, 0x63, 0x9c, 0x20, 0xcd),
+            //   aad: vec!(0xdf, 0x8a, 0xb6, 0x34, 0xd3, 0xdc, 0xa1, 0x4e, 0x2e, 0x09, 0x1b, 0x15, 0xec, 0xc7, 0x8f, 0x91, 0xe2, 0x29, 0xa1, 0xa1, 0x3c, 0xba, 0x5e, 0xdd, 0x65, 0x26, 0xd1, 0x82, 0x52, 0x5e, 0xc5, 0x75, 0xaa, 0x45, 0xbc, 0x70, 0xfb, 0x61, 0x93, 0xff, 0xcd, 0x59, 0xba, 0xd3, 0xc3, 0x47, 0x15, 0x90, 0x99, 0xc4, 0xf1, 0x39, 0xc3, 0x23, 0xc3, 0x0a, 0x23, 0x07, 0x53, 0xd0, 0x70, 0x01, 0x87, 0x86, 0xb2, 0xe5, 0x9b, 0x75, 0x8d, 0xd4, 0xa9, 0x7d, 0x1a, 0x88, 0xe8, 0xf6, 0x72, 0x09, 0x2b, 0xef, 0x78, 0x0b, 0x45, 0x1f, 0xd6, 0x6b, 0xa7, 0x43, 0x1c, 0xbb, 0x56, 0x60, 0xea, 0x78, 0x16, 0xcd, 0xf2, 0x6e, 0x19, 0xa6, 0xeb, 0xb9, 0xaa, 0xdc, 0x30, 0x88, 0xe6, 0x92, 0x3f, 0x29, 0xf5, 0x3f, 0x87, 0x7a, 0x67, 0x58, 0x06, 0x8f, 0x79, 0xa6, 0xf2, 0xa1, 0x82, 0xb4, 0xbf),
+            //   cipher_text: vec!(0xa6, 0x2e, 0x31, 0x3e, 0xcf, 0x25, 0x8c, 0xc9, 0x08, 0x7c, 0xbb, 0x94, 0xfc, 0xc1, 0x26, 0x43, 0xeb, 0x72, 0x2d, 0x25, 0x5c, 0x3f, 0x98, 0xc3, 0x9f, 0x13, 0x0e, 0x10, 0x05, 0x8a, 0x37, 0x5f, 0x08, 0x09, 0x66, 0x24, 0x42, 0xc7, 0xb1, 0x80, 0x44, 0xfe, 0xb1, 0x60, 0x2d, 0x89, 0xbe, 0x40, 0xfa, 0xca, 0xe8, 0xe8, 0x9c, 0xa9, 0x67, 0x01, 0x5f, 0x0b, 0x7f, 0x8c, 0x2e, 0x4e, 0x4a, 0x38, 0x55, 0xdb, 0xb4, 0x6a, 0x06, 0x6e, 0x49, 0xab, 0xf9, 0xce, 0xf6, 0x7e, 0x60, 0x36, 0x40, 0x0c, 0x8f, 0xf4, 0x6b, 0x24, 0x1f, 0xc9, 0x9b, 0xa1, 0x97, 0x4b, 0xa3, 0xba, 0x6e, 0xa2, 0x0d, 0xc5, 0x2e, 0xc6, 0x75, 0x3f, 0x6f, 0xc7, 0x69, 0x7a, 0xdb, 0xcc, 0xd0, 0x2b, 0x0b, 0xbe, 0xa1, 0xdf, 0x83, 0x52, 0x62, 0x9b, 0x03, 0xb4, 0x3c, 0xc3, 0xd6, 0x32, 0x57, 0x67, 0x87),
+            //   tag: vec!(0x67, 0x52, 0x87, 0xf8, 0x14, 0x3b, 0x9b, 0x97, 0x6e, 0x50, 0xa8, 0x0f, 0x85, 0x31, 0xbd, 0x39)
+            // },
+            // TestVector{
+            //   key: [0xb6, 0x2f, 0xb8, 0x5c, 0x1d, 0xec, 0xd0, 0xfa, 0xf2, 0x42, 0xce, 0x66, 0x21, 0x40, 0xad, 0x1b, 0x82, 0x97, 0x5e, 0x99, 0xa3, 0xfa, 0x01, 0x66, 0x6c, 0xac, 0x23, 0x85, 0xab, 0x91, 0xda, 0x54],
+            //   nonce: [0x2f, 0x4a, 0x5c, 0xa0, 0x96, 0xa4, 0xfa, 0xf8],
+            //   plain_text: vec!(0x03, 0xb1, 0x4f, 0x13, 0xc0, 0x06, 0x5e, 0x4a, 0x44, 0x21, 0xde, 0x62, 0xab, 0x1d, 0x84, 0x2b, 0xff, 0xb8, 0x0f, 0x3d, 0xa3, 0x0b, 0xf4, 0x7d, 0x11, 0x5c, 0x09, 0x85, 0x7f, 0x5b, 0xdd, 0x57, 0x56, 0xfd, 0x7c, 0x9a, 0xc3, 0xd9, 0xaf, 0x1c, 0x9f, 0xb9, 0x4f, 0x26, 0x40, 0xf7, 0xf4, 0x38, 0x6c, 0xfb, 0xa7, 0x4d, 0xb4, 0x68, 0xe5, 0x28, 0x8d, 0xbe, 0x4d, 0xd7, 0x8b, 0xfe, 0x4f, 0x69, 0xe4, 0x14, 0x80, 0xca, 0x61, 0x38, 0xe8, 0xbe, 0xac, 0xc6, 0xea, 0xa3, 0x37, 0x41, 0x57, 0xc7, 0x13, 0xcf, 0xa9, 0x00, 0xc0, 0x7d, 0xd8, 0x36, 0xea, 0xec, 0xc8, 0x82, 0x7f, 0xa3, 0xe7, 0x0e, 0x05, 0x2a, 0xe0, 0x9e, 0x84, 0x73, 0xe2, 0xae, 0x1a, 0x10, 0xb1, 0xbb, 0x66, 0x9e, 0xf6, 0x0a, 0x8d, 0xd9, 0x57, 0xf6, 0x55, 0x3d, 0xaa, 0x81, 0x14, 0x91, 0x8e, 0x17, 0x37, 0x1f, 0x2a, 0xc3, 0x27, 0xbd),
+            //   aad: vec!(0xcf, 0xe3, 0xb7, 0xab, 0x75, 0x50, 0xb0, 0xe8, 0xe2, 0xe8, 0x23, 0x5f, 0xa0, 0xdc, 0xef, 0x95, 0x64, 0x7c, 0xe6, 0x81, 0x4a, 0xbd, 0x3d, 0xc3, 0xf5, 0xa3, 0xbd, 0x7d, 0x6d, 0x28, 0x25, 0x04, 0x66, 0x0c, 0x34, 0xad, 0x83, 0x41, 0xe4, 0xd1, 0x14, 0x02, 0xc7, 0xd4, 0x6c, 0x83, 0xa4, 0x94, 0xd7, 0xdd, 0xb1, 0x05, 0xe1, 0x00, 0x29, 0x79, 0x02, 0x3e, 0x0e, 0x3d, 0xc2, 0x97, 0x8c, 0x9a, 0xe5, 0x3e, 0x10, 0xeb, 0x85, 0x67, 0xe7, 0xa0, 0x2b, 0x60, 0xe5, 0x1e, 0x94, 0x5c, 0x70, 0x40, 0xd8, 0x32, 0xca, 0x90, 0x0d, 0x13, 0x2b, 0x42, 0x05, 0xa3, 0x50, 0x34, 0xfe, 0xd9, 0x39, 0xa1, 0xb7, 0x96, 0x51, 0x83, 0xc2, 0x56, 0x54, 0x93, 0x1a, 0x9b, 0x74, 0x44, 0x01, 0xc4, 0x64, 0x9c, 0x94, 0x57, 0x10, 0xb0, 0xd9, 0x73, 0x3b, 0x87, 0x45, 0x13, 0x48, 0xb3, 0x2b, 0xa8, 0x1d, 0xe3, 0x0e, 0xa7),
+            //   cipher_text: vec!(0x89, 0x65, 0xdb, 0x3d, 0x3a, 0xe4, 0xfb, 0x48, 0x32, 0x08, 0xf1, 0x47, 0x27, 0x6e, 0x7d, 0x81, 0xb7, 0x1a, 0x86, 0xe7, 0x20, 0x2f, 0xfc, 0x9b, 0x1e, 0xaa, 0xde, 0x00, 0x9b, 0xc0, 0x16, 0x83, 0x8d, 0xc0, 0x9c, 0xa4, 0xbc, 0xf3, 0x08, 0x87, 0xb2, 0xf4, 0x24, 0x3f, 0xbd, 0x65, 0x2c, 0xd9, 0x0e, 0xbe, 0xd1, 0xce, 0xef, 0x81, 0x51, 0xff, 0x17, 0xea, 0x70, 0x51, 0x8d, 0x03, 0xb0, 0xf2, 0xa2, 0x49, 0x60, 0xaa, 0x7d, 0xe9, 0xb3, 0x0f, 0xa6, 0x5c, 0x2e, 0x2d, 0x57, 0x36, 0x00, 0x61, 0xaa, 0xe6, 0xd9, 0x37, 0x6e, 0x98, 0x4e, 0x9f, 0xcd, 0x5e, 0x5d, 0xd0, 0x91, 0x1a, 0x4b, 0xc8, 0xde, 0xca, 0x83, 0x2f, 0xfb, 0x76, 0xf2, 0x52, 0xbd, 0x7d, 0xa5, 0x23, 0x07, 0x65, 0x93, 0xba, 0x6b, 0x17, 0x4f, 0x7d, 0x9f, 0xb0, 0x37, 0x7e, 0x06, 0x6e, 0xcb, 0xb6, 0x63, 0x80, 0x36, 0x24, 0x1e, 0x86),
+            //   tag: vec!(0x3d, 0x0f, 0xc5, 0x3e, 0x90, 0x58, 0xc2, 0xbe, 0x32, 0xaa, 0x08, 0x50, 0xe0, 0xfa, 0xb5, 0xa6)
+            // },
+            // TestVector{
+            //   key: [0xde, 0x9c, 0x65, 0x72, 0x58, 0x77, 0x4d, 0x4e, 0xbc, 0x09, 0xd1, 0x09, 0xa0, 0xfc, 0x79, 0xd6, 0x64, 0x93, 0xae, 0x57, 0x87, 0x97, 0xca, 0xc4, 0xeb, 0x88, 0x30, 0xa6, 0xa4, 0xb5, 0x47, 0xe0],
+            //   nonce: [0xb5, 0xe3, 0x5f, 0xe3, 0x39, 0x8e, 0xfa, 0x34],
+            //   plain_text: vec!(0x4d, 0x68, 0xfb, 0x68, 0x3a, 0xa4, 0xf4, 0xc7, 0xa1, 0x6b, 0xa1, 0x11, 0x4f, 0xc0, 0xb1, 0xb8, 0xd8, 0x89, 0x86, 0x10, 0xfa, 0x27, 0x63, 0xe4, 0x35, 0xde, 0xd8, 0x77, 0x1b, 0x36, 0x51, 0x07, 0x8b, 0xef, 0x73, 0xd4, 0xdf, 0xd1, 0x4e, 0x76, 0xa3, 0x4c, 0xd5, 0xeb, 0x9e, 0xf4, 0xdb, 0x4e, 0xad, 0x4d, 0xa9, 0xe8, 0x3f, 0x4c, 0xe5, 0x0f, 0xe0, 0x59, 0x97, 0x7b, 0x2d, 0x17, 0xd6, 0x87, 0xc2, 0x93, 0x35, 0xa0, 0x4d, 0x87, 0x38, 0x9d, 0x21, 0x1f, 0x82, 0x15, 0x44, 0x97, 0x49, 0x96, 0x9f, 0x76, 0x52, 0xdc, 0x19, 0x35, 0xa0, 0xf9, 0xa9, 0x45, 0x38, 0xdc, 0x81, 0xdc, 0x9a, 0x39, 0xaf, 0x63, 0x44, 0x6a, 0x65, 0x17, 0x60, 0x90, 0x76, 0x98, 0x79, 0x20, 0x54, 0x7d, 0x00, 0x98, 0xa9, 0xc6, 0x76, 0x6c, 0xf5, 0xe7, 0x04, 0x88, 0x3e, 0xa3, 0x2f, 0xea, 0xea, 0x18, 0x89, 0xb1, 0x55, 0x4b, 0x5e, 0xb0, 0xce, 0x5e, 0xcc),
+            //   aad: vec!(0x43, 0x6e, 0xa5, 0xa5, 0xfe, 0xe8, 0x29, 0x3b, 0x93, 0xe4, 0xe8, 0x48, 0x81, 0x16, 0xc9, 0x4d, 0x32, 0x69, 0xc1, 0x9f, 0x1d, 0x50, 0x50, 0xde, 0xf2, 0x3d, 0x28, 0x05, 0x15, 0x45, 0x7b, 0x93, 0x1b, 0xbe, 0xd6, 0x4a, 0x54, 0x2b, 0x31, 0x7c, 0xc5, 0x02, 0x3d, 0x64, 0x83, 0x30, 0xa4, 0xb7, 0xad, 0xca, 0x14, 0xdd, 0x6f, 0x37, 0x83, 0x20, 0x7b, 0x94, 0xf8, 0x6c, 0xca, 0xa0, 0xa0, 0xac, 0x39, 0xb7, 0xdb, 0x00, 0xac, 0x87, 0xa9, 0x9e, 0x3c, 0xd8, 0xa7, 0x64, 0xed, 0x9c, 0x75, 0xda, 0x84, 0x54, 0x47, 0x96, 0x36, 0xab, 0x2b, 0x29, 0xe7, 0x70, 0xb1, 0x66, 0xa5, 0xb7, 0x5c, 0xac, 0xc4, 0x25, 0xc9, 0x19, 0xbf, 0x1c, 0xe9, 0xac, 0x34, 0xaf, 0xe6, 0xb4, 0x42, 0x5c, 0x3d, 0x9f, 0xd2, 0xe4, 0x8b, 0xc8, 0x1e, 0x7d, 0x15, 0x51, 0x6d, 0x60, 0xe5, 0x92, 0xbf, 0xcc, 0x2e, 0xbe, 0xfb, 0x66, 0x0f, 0x09, 0x95, 0xf2, 0xb5),
+            //   cipher_text: vec!(0x97, 0xa9, 0x7b, 0x8f, 0x0f, 0x54, 0x20, 0x84, 0x5a, 0xe8, 0xd5, 0x75, 0x67, 0xf9, 0xbb, 0xa6, 0x93, 0xd3, 0x0e, 0x6d, 0xb9, 0x16, 0xfa, 0xd0, 0xb9, 0x71, 0xf5, 0x53, 0xad, 0x7d, 0x99, 0x3f, 0x80, 0x6f, 0x27, 0xab, 0x8b, 0x45, 0x8d, 0x80, 0x46, 0x06, 0x2c, 0xed, 0x47, 0x78, 0xc0, 0x04, 0xb4, 0xf9, 0x58, 0xa4, 0x43, 0x61, 0x41, 0x63, 0x7c, 0x60, 0x39, 0x96, 0x33, 0x08, 0xde, 0xa2, 0xf5, 0x40, 0x08, 0xb7, 0xfe, 0xab, 0x79, 0x65, 0x02, 0x95, 0xed, 0x41, 0xbf, 0x9e, 0x65, 0xe1, 0xa2, 0xd7, 0x5a, 0xb1, 0xc7, 0xb2, 0xa7, 0x0e, 0xbb, 0x9e, 0x9f, 0x38, 0xd0, 0x7a, 0x9a, 0x67, 0x2d, 0x3e, 0x95, 0xea, 0x78, 0xaf, 0xe9, 0xac, 0x02, 0xf2, 0x56, 0x6b, 0x48, 0xb0, 0x25, 0x1a, 0xef, 0x6e, 0xee, 0xca, 0x8b, 0xd1, 0x5b, 0xd8, 0xd4, 0x3b, 0x55, 0x94, 0x26, 0xaa, 0x9d, 0x15, 0xd9, 0x60, 0xee, 0x35, 0xcb, 0x3e, 0xdf),
+            //   tag: vec!(0xe5, 0x5d, 0xbb, 0x21, 0x85, 0x1e, 0x8a, 0x5b, 0x36, 0x5f, 0x86, 0xd0, 0x25, 0x18, 0x33, 0x1c)
+            // },
+            // TestVector{
+            //   key: [0x68, 0x85, 0xbd, 0x33, 0x3c, 0x33, 0x6c, 0x76, 0x72, 0xdb, 0x8e, 0xbd, 0xf2, 0x4c, 0x1a, 0x1b, 0x60, 0x5c, 0x5a, 0x4a, 0xe2, 0x79, 0xf0, 0xf6, 0x98, 0x16, 0x2f, 0x47, 0xe6, 0xc7, 0x34, 0x01],
+            //   nonce: [0xf0, 0xc4, 0xa2, 0x13, 0xa6, 0x16, 0x8a, 0xab],
+            //   plain_text: vec!(0xfa, 0x90, 0x5a, 0x2b, 0xfa, 0x5b, 0x5b, 0xad, 0x76, 0x72, 0x39, 0xfb, 0x07, 0x0a, 0x7b, 0xc0, 0xb3, 0x03, 0xd1, 0x50, 0x3e, 0xcd, 0x2b, 0x42, 0x94, 0x18, 0xcc, 0x8f, 0xeb, 0xa8, 0x43, 0xe5, 0x44, 0x4e, 0xd8, 0x90, 0x22, 0xfd, 0xb3, 0x79, 0xc3, 0xb1, 0x55, 0xa0, 0xf9, 0xce, 0xab, 0x29, 0x79, 0x00, 0x0a, 0x0f, 0x60, 0x29, 0x2a, 0x63, 0x17, 0x71, 0xf2, 0xfd, 0xe4, 0xef, 0x06, 0x5a, 0xa7, 0x46, 0x42, 0x66, 0x09, 0x08, 0x29, 0x69, 0x53, 0x0a, 0x9c, 0x70, 0xad, 0x14, 0x53, 0x08, 0xc3, 0x0b, 0xa3, 0x89, 0xea, 0x12, 0x2f, 0xd7, 0x66, 0x08, 0x15, 0x11, 0xa0, 0x31, 0xce, 0x3a, 0x0b, 0xd9, 0xf9, 0xf5, 0x83, 0xc7, 0x00, 0x0b, 0x33, 0x3b, 0x79, 0xac, 0x00, 0x4f, 0xbd, 0xe6, 0xec, 0x3e, 0xb2, 0xd9, 0x05, 0x97, 0x7f, 0xf9, 0x5d, 0xcf, 0xf7, 0x78, 0x58, 0xe3, 0xc4, 0x24, 0xfe, 0x89, 0x32, 0xa6, 0xa1, 0x21, 0x39, 0xe6, 0xec, 0x8d, 0x5e, 0x98),
+            //   aad: vec!(0x8d, 0xed, 0x36, 0x8f, 0x91, 0x9e, 0xfb, 0x52, 0x2b, 0xb6, 0xa9, 0xad, 0x00, 0x9e, 0x02, 0xff, 0xbc, 0x6a, 0x16, 0x53, 0x6e, 0x34, 0xd9, 0x5c, 0xdb, 0x34, 0xf1, 0x15, 0x3d, 0x7c, 0xb7, 0xb0, 0xf3, 0xc2, 0xb1, 0x3d, 0xd0, 0x5c, 0xed, 0xae, 0x27, 0xcf, 0xe6, 0x8e, 0xc3, 0xac, 0xa8, 0x04, 0x7e, 0x09, 0x30, 0xa2, 0x9c, 0x9d, 0x07, 0x70, 0xc1, 0xb8, 0x3c, 0x23, 0x4d, 0xcb, 0x03, 0x85, 0xde, 0xae, 0x7a, 0xe8, 0x5d, 0xa7, 0x3a, 0x5f, 0x8d, 0xe3, 0xdf, 0xb2, 0x86, 0x12, 0xa0, 0x01, 0xf4, 0xe5, 0x52, 0xc4, 0xf6, 0x7a, 0xe0, 0xe2, 0xec, 0x53, 0x85, 0x32, 0x89, 0xb7, 0x01, 0x7a, 0x58, 0x59, 0x1f, 0xd6, 0xf7, 0x0b, 0x0e, 0x95, 0x48, 0x76, 0xbb, 0x2f, 0x7e, 0xc3, 0x30, 0x01, 0xe2, 0x98, 0x85, 0x6a, 0x64, 0xbb, 0x16, 0x18, 0x10, 0x17, 0xba, 0x92, 0x46, 0x48, 0xc0, 0x9f, 0xc6, 0x3c, 0x62, 0xef, 0xf2, 0x62, 0xc8, 0x0d, 0x61, 0x46, 0x79, 0xbd),
+            //   cipher_text: vec!(0x0c, 0xb3, 0xd6, 0xc3, 0x1e, 0x0f, 0x40, 0x29, 0xec, 0xa5, 0x52, 0x4f, 0x95, 0x12, 0x44, 0xdf, 0x04, 0x2f, 0xc6, 0x37, 0xc4, 0x16, 0x25, 0x11, 0xfe, 0xa5, 0x12, 0xa5, 0x2d, 0x3f, 0x75, 0x81, 0xaf, 0x09, 0x7e, 0xb6, 0x42, 0xe7, 0x9e, 0x48, 0x66, 0x6c, 0xb1, 0x08, 0x6e, 0xdb, 0xd3, 0x8c, 0x47, 0x77, 0xc5, 0x35, 0xa2, 0x09, 0x45, 0xfa, 0xbc, 0x23, 0xe7, 0xc9, 0x27, 0x7e, 0x2b, 0x96, 0x0a, 0xac, 0x46, 0x86, 0x5f, 0x10, 0x26, 0xeb, 0x6d, 0xa8, 0x27, 0x59, 0x10, 0x8b, 0x9b, 0xae, 0xce, 0x5d, 0xa9, 0x30, 0xcc, 0xfc, 0x10, 0x52, 0xb1, 0x65, 0x6b, 0x0e, 0xad, 0xaa, 0x12, 0x0e, 0xd0, 0xc4, 0x5a, 0xd0, 0x4b, 0x24, 0xae, 0x8c, 0xdb, 0x22, 0xce, 0xab, 0x76, 0xc5, 0xf1, 0x80, 0xb4, 0x6a, 0x39, 0x2a, 0xb4, 0x5b, 0x1b, 0x99, 0xc6, 0x12, 0x54, 0x6e, 0x6b, 0x94, 0x7f, 0x4d, 0x5c, 0x06, 0xad, 0x5a, 0xbe, 0xe9, 0x2f, 0xf9, 0x63, 0x45, 0xad, 0x43),
+            //   tag: vec!(0xd3, 0xb5, 0x41, 0xac, 0x44, 0x6c, 0x84, 0x62, 0x6d, 0xaf, 0x80, 0x0c, 0x01, 0x72, 0xee, 0xc6)
+            // },
+            // TestVector{
+            //   key: [0xfb, 0xc9, 0x78, 0xab, 0xb1, 0x24, 0x0a, 0x69, 0x37, 0xcc, 0xc1, 0x67, 0x35, 0xb8, 0xd6, 0xed, 0x54, 0x11, 0xcd, 0xbc, 0x18, 0x97, 0x21, 0x41, 0x65, 0xa1, 0x74, 0xe1, 0x6f, 0x4e, 0x69, 0x9b],
+            //   nonce: [0x79, 0x68, 0x37, 0x9a, 0x8c, 0xe8, 0x81, 0x17],
+            //   plain_text: vec!(0x1a, 0x81, 0x96, 0xcd, 0x4a, 0x13, 0x89, 0xec, 0x91, 0x6e, 0xf8, 0xb7, 0xda, 0x50, 0x78, 0xa2, 0xaf, 0xa8, 0xe9, 0xf1, 0x08, 0x12, 0x23, 0xfa, 0x72, 0xf6, 0x52, 0x4a, 0xc0, 0xa1, 0xa8, 0x01, 0x9e, 0x44, 0xa0, 0x95, 0x63, 0xa9, 0x53, 0x61, 0x55, 0x87, 0x42, 0x92, 0x95, 0x05, 0x2c, 0xc9, 0x04, 0xb8, 0x9f, 0x77, 0x8e, 0xf4, 0x46, 0xed, 0x34, 0x14, 0x30, 0xd7, 0xd8, 0xf7, 0x47, 0xcf, 0x2d, 0xb4, 0x30, 0x84, 0x78, 0x52, 0x46, 0x39, 0xf4, 0x44, 0x57, 0x25, 0x3a, 0xe5, 0xa4, 0x45, 0x1c, 0x7e, 0xfc, 0xa8, 0xae, 0x0b, 0x6c, 0x5c, 0x05, 0x1a, 0xaa, 0x78, 0x1e, 0x9c, 0x50, 0x54, 0x89, 0xb3, 0x81, 0xa6, 0xdc, 0xba, 0x87, 0xb1, 0x57, 0xed, 0xc7, 0xf8, 0x20, 0xa8, 0xfb, 0xaf, 0x2a, 0x52, 0xe4, 0x84, 0xdc, 0x12, 0x1f, 0x33, 0xd9, 0xd8, 0xb9, 0xac, 0x59, 0xd4, 0x90, 0x1d, 0x6e, 0xd8, 0x99, 0x6e, 0xd4, 0xf6, 0x2d, 0x9d, 0x4d, 0x82, 0x27, 0x4c, 0x44, 0x9c, 0xd7, 0x4e, 0xfa),
+            //   aad: vec!(0x39, 0x13, 0xcd, 0x01, 0x29, 0x9b, 0x8a, 0x4e, 0x50, 0x7f, 0x06, 0x7d, 0x88, 0x7d, 0x7e, 0x9a, 0x6d, 0xed, 0x16, 0xdd, 0x9f, 0x9b, 0xb3, 0x11, 0x5c, 0x57, 0x79, 0xaa, 0x14, 0x23, 0x9f, 0xd3, 0x3e, 0xe9, 0xf2, 0x57, 0x56, 0xd4, 0x52, 0x62, 0xdc, 0x30, 0x11, 0x06, 0x93, 0x56, 0x42, 0x5b, 0x5c, 0x81, 0xa4, 0x72, 0x95, 0x94, 0xe1, 0x7c, 0x97, 0x47, 0x11, 0x9f, 0x81, 0x46, 0x3e, 0x85, 0x62, 0x5d, 0x56, 0x03, 0xd0, 0x5e, 0x00, 0xf5, 0x68, 0xb0, 0xc8, 0x00, 0xbb, 0x18, 0x1e, 0xb7, 0x17, 0xbe, 0x8d, 0x7a, 0x93, 0x16, 0x6a, 0x50, 0x4c, 0xe1, 0xbc, 0x81, 0x7e, 0x15, 0x53, 0x0c, 0x5b, 0xd2, 0xb3, 0xdf, 0x1d, 0x42, 0x22, 0x24, 0x5e, 0xa7, 0x8a, 0x38, 0xbc, 0x10, 0xf6, 0x6c, 0x5c, 0xf6, 0x8d, 0x66, 0x15, 0x03, 0x13, 0x1f, 0x11, 0xaf, 0x88, 0x5c, 0x8a, 0x91, 0x0b, 0x6d, 0xce, 0x70, 0xbc, 0x3a, 0x74, 0x48, 0xdf, 0xae, 0x00, 0x59, 0x5b, 0xeb, 0x70, 0x7f, 0xe0, 0x54, 0xd3),
+            //   cipher_text: vec!(0xd1, 0x52, 0xbc, 0xb4, 0xc2, 0x4c, 0x37, 0x11, 0xb0, 0xfa, 0xd2, 0x85, 0x48, 0xdc, 0x4d, 0xb6, 0x05, 0xbb, 0xc8, 0x92, 0x37, 0xcd, 0xbe, 0xa7, 0xdb, 0xf9, 0x56, 0xb8, 0x85, 0x5d, 0x11, 0x61, 0xa0, 0x78, 0x1f, 0x27, 0xbd, 0x56, 0xd7, 0x98, 0x14, 0x1e, 0x2a, 0xce, 0x33, 0x99, 0x55, 0xef, 0xb9, 0x8f, 0xe0, 0x5d, 0x9b, 0x44, 0xcd, 0x01, 0x1e, 0x64, 0x51, 0x06, 0xbf, 0x47, 0x72, 0x61, 0x83, 0x95, 0x8c, 0xb6, 0xdf, 0x34, 0xce, 0x57, 0x66, 0x69, 0x5f, 0x60, 0xbc, 0x70, 0xb6, 0xfe, 0x0f, 0xab, 0xb9, 0xaf, 0xa0, 0x09, 0xa8, 0xef, 0x04, 0x3d, 0xbf, 0x75, 0xf8, 0x61, 0x88, 0x13, 0x68, 0xfa, 0x07, 0x72, 0x66, 0x25, 0x44, 0x8f, 0xe6, 0x08, 0xd5, 0x78, 0xcd, 0xc4, 0x82, 0x77, 0xf2, 0xdc, 0x53, 0xea, 0xaf, 0x1b, 0xdc, 0x07, 0x52, 0x69, 0xa4, 0x2f, 0x93, 0x02, 0xa5, 0x7c, 0xad, 0x38, 0x7a, 0x82, 0xc6, 0x96, 0x96, 0x08, 0xac, 0xac, 0xda, 0x20, 0xe1, 0xca, 0xc4, 0x59, 0x6c),
+            //   tag: vec!(0x94, 0x5d, 0xca, 0x73, 0xcf, 0x2f, 0x00, 0x7a, 0xe2, 0x43, 0x99, 0x1c, 0x4f, 0xbe, 0x04, 0x79)
+            // },
+            // TestVector{
+            //   key: [0x77, 0xd1, 0xa8, 0x57, 0xfb, 0xad, 0xfe, 0x01, 0xab, 0xa7, 0x97, 0x4e, 0xea, 0x2d, 0xfb, 0x3d, 0xc7, 0xbf, 0x41, 0xde, 0x73, 0x68, 0x6a, 0xec, 0xe4, 0x03, 0x99, 0x3e, 0x50, 0x16, 0xc7, 0x14],
+            //   nonce: [0xfd, 0xd9, 0x13, 0xa3, 0x21, 0xc4, 0x0e, 0xb0],
+            //   plain_text: vec!(0xdb, 0x89, 0x15, 0xbf, 0xe6, 0x51, 0xe2, 0xec, 0xb3, 0xce, 0x0b, 0x27, 0xd9, 0x9a, 0x6b, 0xfa, 0x7a, 0x7c, 0x50, 0x7c, 0xfc, 0xb2, 0x98, 0x72, 0x93, 0x01, 0x86, 0x36, 0xc3, 0x65, 0xa4, 0x59, 0xc6, 0xa1, 0x38, 0xb4, 0x42, 0x8b, 0xe5, 0x38, 0x41, 0x3d, 0xb1, 0x5b, 0xda, 0x69, 0xe6, 0x97, 0xcb, 0xb9, 0x2b, 0x15, 0x4b, 0x7f, 0x4d, 0x2c, 0xbb, 0x07, 0x96, 0x52, 0x25, 0xaa, 0x68, 0x65, 0xd7, 0xdc, 0xd1, 0xba, 0x2c, 0x17, 0xc4, 0x84, 0xb0, 0x0b, 0x19, 0x86, 0xfe, 0xd6, 0x3e, 0x88, 0x9f, 0x25, 0xa4, 0x96, 0x6d, 0xc3, 0xed, 0x42, 0x73, 0xf1, 0x57, 0x77, 0x68, 0xf6, 0x65, 0x36, 0x2d, 0x7d, 0x3e, 0x82, 0x44, 0x84, 0xf0, 0xdd, 0xed, 0x7f, 0x82, 0xb8, 0xbe, 0x87, 0x97, 0xad, 0x95, 0x17, 0x19, 0x71, 0x93, 0x65, 0xe4, 0x5a, 0xbb, 0xf7, 0x63, 0x24, 0xbc, 0x7d, 0x65, 0x77, 0x99, 0xd4, 0xd4, 0xf4, 0xbb, 0x1d, 0xba, 0x67, 0xd9, 0x6a, 0xb1, 0xc8, 0x85, 0x19, 0xa5, 0xbe, 0xe7, 0x04, 0xf7, 0x21, 0x48, 0x14),
+            //   aad: vec!(0x3c, 0xb2, 0xc0, 0x6c, 0x20, 0xcb, 0x08, 0x32, 0xbb, 0xac, 0xeb, 0xfc, 0x20, 0x5d, 0x77, 0x39, 0x3c, 0xa1, 0x81, 0x63, 0x46, 0xea, 0x26, 0x81, 0xde, 0x4d, 0x3a, 0xb1, 0xfa, 0xdb, 0x77, 0x4a, 0xd2, 0x73, 0xe4, 0x71, 0x32, 0x90, 0x45, 0x44, 0x96, 0xf5, 0x28, 0x1e, 0xbc, 0x65, 0xe0, 0x4c, 0xfe, 0x84, 0xed, 0x37, 0xcd, 0x0a, 0xed, 0xc4, 0xbb, 0xe3, 0xde, 0xcb, 0xd8, 0xd7, 0x9d, 0x04, 0xa4, 0xe4, 0x34, 0x87, 0x66, 0x50, 0xe0, 0xd6, 0x43, 0x09, 0xe3, 0x36, 0xbf, 0xb1, 0x0e, 0x92, 0x40, 0x66, 0xa6, 0x4a, 0xcb, 0x92, 0x26, 0x0b, 0x2d, 0xbd, 0x96, 0x73, 0x5d, 0x03, 0xaf, 0x03, 0x90, 0x9a, 0xa6, 0xa8, 0x0a, 0x6e, 0x89, 0xfd, 0xa8, 0x10, 0x37, 0x25, 0x7a, 0xec, 0x21, 0xfe, 0x9b, 0xe7, 0xe9, 0x1a, 0x64, 0xe8, 0x8e, 0x0a, 0x58, 0xfa, 0x38, 0xec, 0xba, 0x4c, 0x4c, 0x4c, 0xff, 0xb6, 0x19, 0x58, 0xf3, 0xc4, 0x86, 0xcb, 0xb0, 0xb1, 0xd0, 0xb0, 0x01, 0x4a, 0x2d, 0x1d, 0x3d, 0xf2, 0x48, 0xee, 0xc1, 0xca),
+            //   cipher_text: vec!(0xac, 0xb8, 0x25, 0xe6, 0x02, 0x3b, 0x44, 0xb0, 0x3b, 0x2e, 0xfc, 0x26, 0x56, 0x03, 0xe8, 0x87, 0x95, 0x4e, 0x86, 0x12, 0xb2, 0xee, 0x13, 0x4b, 0xdc, 0xb6, 0x15, 0x01, 0xcf, 0xb9, 0x49, 0x29, 0x52, 0xbf, 0x67, 0xbe, 0x59, 0x7c, 0x3a, 0x00, 0x5b, 0x09, 0xaf, 0x74, 0xd9, 0xe4, 0x21, 0xa5, 0x76, 0xd2, 0xc6, 0x5e, 0x98, 0x10, 0x47, 0x80, 0xfe, 0xab, 0x83, 0x8d, 0x8c, 0xb1, 0xbd, 0x13, 0x54, 0x52, 0xea, 0x39, 0xdc, 0x89, 0x07, 0xa4, 0xc1, 0xa6, 0xa9, 0x16, 0x18, 0x05, 0xe4, 0xfa, 0x3e, 0x16, 0x98, 0x9e, 0x6a, 0x41, 0x8a, 0x7e, 0xea, 0x25, 0x82, 0xbf, 0x89, 0x5d, 0xa9, 0x67, 0x02, 0x8e, 0xab, 0x7c, 0x95, 0xd8, 0x46, 0xa6, 0xde, 0x4b, 0x99, 0x80, 0x78, 0x58, 0x14, 0xcf, 0x00, 0x48, 0x4b, 0xaa, 0x2f, 0x6d, 0xe6, 0x09, 0x91, 0x2f, 0xff, 0x68, 0x9b, 0xce, 0x6e, 0x85, 0x42, 0x61, 0xff, 0xe8, 0x66, 0xbd, 0x8e, 0x63, 0x27, 0x46, 0x05, 0xc7, 0xc5, 0xad, 0x67, 0x7b, 0xd7, 0x89, 0x7a, 0xde, 0x54, 0x3e),
+            //   tag: vec!(0x93, 0x84, 0x78, 0xa4, 0x1a, 0x32, 0x23, 0xa2, 0x19, 0x9f, 0x92, 0x76, 0xd1, 0x16, 0x21, 0x0f)
+            // },
+            // TestVector{
+            //   key: [0xb7, 0xe9, 0xb9, 0x0d, 0xc0, 0x2b, 0x5c, 0xd6, 0xdf, 0x5d, 0xf7, 0x28, 0x3e, 0xf2, 0x93, 0xed, 0x4d, 0xc0, 0x75, 0x13, 0xd9, 0xe6, 0x73, 0x31, 0xb6, 0x06, 0xf4, 0xd4, 0x2d, 0xec, 0x7d, 0x29],
+            //   nonce: [0xa6, 0xc1, 0x91, 0xf6, 0xd1, 0x81, 0x8f, 0x8e],
+            //   plain_text: vec!(0x2a, 0xda, 0x0e, 0x3c, 0x7c, 0xa6, 0xdb, 0x1f, 0x78, 0x0c, 0xe8, 0xc7, 0x94, 0x72, 0xaf, 0x4e, 0x8e, 0x95, 0x1d, 0xdc, 0x82, 0x8e, 0x0d, 0x6e, 0x8a, 0x67, 0xdf, 0x52, 0x06, 0x38, 0xff, 0x5f, 0x14, 0xa2, 0xf9, 0x5a, 0x5e, 0x59, 0x31, 0x74, 0x9a, 0xe2, 0xc4, 0xe9, 0x94, 0x6a, 0xe4, 0xd5, 0xeb, 0x5d, 0xe4, 0x2f, 0xb5, 0xb7, 0x7d, 0x22, 0x36, 0xe2, 0xe2, 0xbd, 0x81, 0x7d, 0xf5, 0x1b, 0xe4, 0x0b, 0x1b, 0x8a, 0x6c, 0x21, 0x01, 0x5a, 0x7c, 0x79, 0xfe, 0x06, 0xdb, 0xa4, 0xa0, 0x8b, 0x34, 0x01, 0x3d, 0xfa, 0x02, 0x74, 0x7b, 0x5f, 0x03, 0x93, 0x02, 0x68, 0x40, 0x4c, 0x45, 0x5d, 0xc5, 0x4a, 0x74, 0xd9, 0xc6, 0xe3, 0x54, 0x85, 0xe1, 0x00, 0x26, 0xda, 0x57, 0x3c, 0xb4, 0x1c, 0xd5, 0x0b, 0x64, 0xcf, 0xaf, 0xe4, 0xcf, 0xcd, 0xf3, 0xc9, 0x68, 0x4e, 0xf8, 0x77, 0xe4, 0x5d, 0x84, 0xe2, 0x2b, 0xd5, 0xe1, 0x5f, 0xa6, 0xc8, 0xfd, 0x5b, 0xe9, 0x21, 0x36, 0x6f, 0xf0, 0xdc, 0x6f, 0xe2, 0xdf, 0x45, 0xf7, 0x25, 0x29, 0x72, 0xc9, 0xb3, 0x03),
+            //   aad: vec!(0x0f, 0x42, 0x69, 0xed, 0x5e, 0xf0, 0xbf, 0xff, 0x7b, 0xe3, 0x99, 0x46, 0xa4, 0xe8, 0x6e, 0x8b, 0xf7, 0x9f, 0x84, 0xb7, 0x0c, 0xd0, 0xb1, 0x4f, 0xec, 0xb7, 0xbe, 0x3c, 0x07, 0x13, 0x16, 0xce, 0x86, 0xde, 0x3d, 0x99, 0xd6, 0x87, 0x1e, 0x0b, 0xa5, 0x66, 0x7d, 0x9d, 0x7b, 0xba, 0x7d, 0xca, 0xba, 0x10, 0xcb, 0x2a, 0x36, 0x66, 0x8b, 0x6c, 0x3e, 0x2f, 0xb6, 0xc1, 0x02, 0x93, 0x8b, 0x75, 0x00, 0x8b, 0xb9, 0xc2, 0x13, 0xeb, 0xf9, 0xb8, 0x5b, 0x5e, 0x91, 0xa8, 0x02, 0xdf, 0x0d, 0x31, 0xd7, 0xf1, 0x1d, 0x76, 0x4b, 0x22, 0x89, 0xf6, 0x22, 0x52, 0x12, 0x69, 0x4a, 0xb6, 0xb7, 0xc0, 0xe3, 0xff, 0x36, 0xe8, 0x42, 0x45, 0xd9, 0xf4, 0xf4, 0x3f, 0xc5, 0xf9, 0x8e, 0x65, 0x4d, 0xea, 0x7b, 0xa9, 0xbd, 0x91, 0x86, 0x58, 0x87, 0x9c, 0x5b, 0xb4, 0xa1, 0x64, 0x2a, 0xf0, 0xd8, 0x31, 0x13, 0xe3, 0xcf, 0x93, 0x5d, 0x3c, 0x0d, 0x52, 0x08, 0x31, 0x8f, 0x66, 0xf6, 0x54, 0xeb, 0x17, 0xd8, 0xc2, 0x8a, 0x60, 0x25, 0x43, 0xe7, 0x7a, 0xd3, 0xe8, 0x15),
+            //   cipher_text: vec!(0x22, 0x58, 0x6f, 0xe7, 0x33, 0x8e, 0x99, 0xcd, 0xaa, 0xd9, 0xf8, 0x5b, 0xd7, 0x24, 0xba, 0x4c, 0xfe, 0x62, 0x49, 0xb8, 0xa7, 0x13, 0x99, 0xf9, 0xa3, 0x70, 0x7b, 0x5c, 0x43, 0x23, 0xb8, 0xd9, 0x66, 0x79, 0x56, 0x8d, 0xfc, 0x8d, 0x23, 0x0a, 0xef, 0xb4, 0x53, 0xdf, 0x59, 0x6e, 0x13, 0xeb, 0x3e, 0x8a, 0x43, 0x92, 0x49, 0xbd, 0x64, 0xbc, 0x93, 0xa5, 0x8f, 0x95, 0x08, 0x9a, 0x62, 0xb9, 0x4f, 0x65, 0x62, 0xb8, 0x21, 0xc8, 0x3d, 0x91, 0xf5, 0x6c, 0x55, 0x14, 0x73, 0x81, 0xe9, 0xde, 0x4b, 0xeb, 0x4a, 0xe8, 0x1b, 0xd6, 0xfe, 0x7c, 0xae, 0xf7, 0xe7, 0xe9, 0xa2, 0x07, 0x8f, 0x2f, 0xba, 0x8f, 0x3e, 0x70, 0xd4, 0x91, 0x0d, 0xa9, 0xac, 0xcc, 0x92, 0xb8, 0xe8, 0x1a, 0x61, 0xb0, 0xfe, 0xfb, 0xec, 0xe4, 0xbd, 0x89, 0x44, 0x3e, 0x66, 0xe8, 0xdd, 0xda, 0x8e, 0x47, 0xa6, 0x6a, 0x62, 0xf1, 0x7f, 0xd0, 0xe7, 0xd0, 0xa4, 0x85, 0x2c, 0xe1, 0xa4, 0xd4, 0x3d, 0x72, 0xa0, 0xb5, 0xe8, 0x91, 0x4b, 0xbe, 0xc6, 0x98, 0xf0, 0x60, 0xf2, 0xb0, 0x92),
+            //   tag: vec!(0xc0, 0x82, 0x47, 0x02, 0x97, 0xda, 0x8c, 0x5f, 0x68, 0x2a, 0x16, 0x9d, 0x28, 0xbc, 0x02, 0x39)
+            // },
+            // TestVector{
+            //   key: [0x6b, 0x2c, 0xb2, 0x67, 0x8d, 0x11, 0x02, 0xf2, 0xfb, 0xbd, 0x02, 0x87, 0x94, 0xa7, 0x9f, 0x14, 0x58, 0x5c, 0x22, 0x3d, 0x40, 0x5e, 0x1a, 0xe9, 0x04, 0xc0, 0x36, 0x1e, 0x9b, 0x24, 0x1e, 0x99],
+            //   nonce: [0x7b, 0x3a, 0xe3, 0x1f, 0x8f, 0x93, 0x82, 0x51],
+            //   plain_text: vec!(0xb3, 0xcb, 0x74, 0x59, 0x30, 0xe0, 0x5f, 0x3a, 0xb8, 0xc9, 0x26, 0xc0, 0xa3, 0x43, 0xa6, 0xeb, 0x14, 0x80, 0x9f, 0xd2, 0x1b, 0x83, 0x90, 0xa6, 0xfc, 0xc5, 0x8a, 0xdb, 0x55, 0x79, 0xe5, 0x43, 0x20, 0x21, 0x76, 0x5b, 0x2d, 0x24, 0x9a, 0x0e, 0xcf, 0x6b, 0xa6, 0x78, 0x63, 0x4c, 0x4f, 0x53, 0xf7, 0x14, 0x95, 0x86, 0x5f, 0x03, 0x1e, 0xe9, 0x7a, 0xa1, 0x59, 0xf9, 0xea, 0xd3, 0xa3, 0xfc, 0xb8, 0x23, 0xee, 0x52, 0x38, 0xbd, 0xf1, 0x27, 0x06, 0xa9, 0xc6, 0x13, 0x7d, 0x23, 0x6e, 0x2e, 0x71, 0x10, 0xce, 0x65, 0x0c, 0x32, 0x1e, 0x41, 0xda, 0xf0, 0xaf, 0xd6, 0x2b, 0xab, 0x2a, 0x8f, 0xe5, 0x5d, 0x70, 0x18, 0xde, 0x49, 0xa1, 0x4e, 0xfe, 0x6d, 0x83, 0xa1, 0x5b, 0x2f, 0x25, 0x6d, 0x59, 0x5e, 0x99, 0x8d, 0x25, 0x30, 0x9f, 0x23, 0x63, 0x33, 0x60, 0xf5, 0x74, 0x5c, 0x50, 0xc4, 0xe5, 0xaf, 0x8c, 0xcc, 0x9a, 0x8a, 0x2c, 0xb4, 0x70, 0x64, 0x10, 0x5a, 0x02, 0x3e, 0x91, 0x9c, 0x77, 0x95, 0xd2, 0xdc, 0x33, 0x1d, 0x3f, 0x2a, 0xfb, 0x8c, 0x42, 0xe5, 0xc0, 0xbc, 0xc2, 0x6d),
+            //   aad: vec!(0x1c, 0x32, 0xfd, 0x3d, 0xf2, 0x2b, 0x3e, 0x44, 0x0e, 0x2a, 0x3c, 0x7a, 0x76, 0x24, 0x99, 0x01, 0x94, 0xcb, 0x16, 0xa5, 0xf7, 0x4a, 0xf3, 0x6f, 0x87, 0xfd, 0x6c, 0xa7, 0xd4, 0x10, 0xce, 0x90, 0x64, 0x31, 0x6a, 0x2d, 0x09, 0x19, 0x45, 0xde, 0xef, 0x7d, 0x9b, 0x35, 0xce, 0xec, 0x83, 0x96, 0x06, 0x93, 0x07, 0xca, 0xce, 0xd2, 0xb8, 0x0a, 0xfd, 0x7d, 0x53, 0xec, 0x47, 0x9c, 0x35, 0xce, 0xdf, 0x2d, 0xfd, 0x4c, 0x95, 0xc3, 0xdd, 0x84, 0x00, 0xf7, 0x1a, 0xd3, 0x40, 0x28, 0xc6, 0xe4, 0xf8, 0x68, 0x1d, 0x93, 0xd0, 0x77, 0x40, 0x64, 0xba, 0x38, 0xf3, 0xfb, 0x9b, 0x0c, 0x1d, 0xfa, 0x1f, 0x5f, 0x0c, 0x7d, 0x20, 0x67, 0x6a, 0x59, 0x11, 0xd9, 0x99, 0xfb, 0x6a, 0x1d, 0x41, 0x36, 0x7a, 0x8e, 0x99, 0xd8, 0x52, 0xbf, 0x3d, 0x3b, 0x7b, 0x3f, 0x4c, 0x23, 0x32, 0x49, 0xed, 0x1c, 0xa1, 0x35, 0x38, 0x9a, 0x67, 0x4f, 0xf4, 0x82, 0x32, 0xde, 0xd3, 0xf6, 0x80, 0x0a, 0x97, 0xb6, 0xd4, 0x09, 0xc4, 0x0e, 0x6c, 0xd7, 0x0d, 0x09, 0xbf, 0x9d, 0x2a, 0xd2, 0x5d, 0x9b, 0x94, 0x85),
+            //   cipher_text: vec!(0xef, 0x70, 0xc7, 0xde, 0x98, 0xab, 0x1d, 0x4a, 0xd8, 0x17, 0x02, 0x4a, 0x97, 0x0b, 0xe4, 0x63, 0x44, 0x36, 0x40, 0xeb, 0x0c, 0xd7, 0xff, 0x23, 0x4b, 0xdd, 0x00, 0xe6, 0x53, 0x07, 0x4a, 0x77, 0xa1, 0xd5, 0x74, 0x9e, 0x69, 0x8b, 0xd5, 0x26, 0xdc, 0x70, 0x9f, 0x82, 0xdf, 0x06, 0xf4, 0xc0, 0xe6, 0x40, 0x46, 0xb3, 0xdc, 0x5f, 0x3c, 0x70, 0x44, 0xae, 0xf5, 0x3a, 0xeb, 0xb8, 0x07, 0xd3, 0x22, 0x39, 0xd0, 0x65, 0x2d, 0xd9, 0x90, 0x36, 0x2c, 0x44, 0xec, 0x25, 0xbf, 0x5a, 0xea, 0xe6, 0x41, 0xe2, 0x7b, 0xf7, 0x16, 0xe0, 0xc4, 0xa1, 0xc9, 0xfb, 0xd3, 0x7b, 0xbf, 0x60, 0x2b, 0xb0, 0xd0, 0xc3, 0x5b, 0x06, 0x38, 0xbe, 0x20, 0xdd, 0x5d, 0x58, 0x91, 0xd4, 0x46, 0x13, 0x7e, 0x84, 0x2f, 0x92, 0xc0, 0xee, 0x07, 0x5c, 0x68, 0x22, 0x5e, 0x4d, 0xba, 0xcb, 0x63, 0xcc, 0x6f, 0xb3, 0x24, 0x42, 0xb4, 0xbc, 0xda, 0x5e, 0x62, 0xcb, 0x50, 0x0a, 0x4d, 0xf2, 0x74, 0x1a, 0x40, 0x59, 0x03, 0x4d, 0x2c, 0xcb, 0x71, 0xb0, 0xb8, 0xb0, 0x11, 0x2b, 0xf1, 0xc4, 0xca, 0x6e, 0xec, 0x74),
+            //   tag: vec!(0x39, 0x3a, 0xe2, 0x33, 0x84, 0x80, 0x34, 0x24, 0x8c, 0x19, 0x1a, 0xc0, 0xe3, 0x6b, 0x61, 0x23)
+            // },
+            // TestVector{
+            //   key: [0x4d, 0xbc, 0x80, 0xa4, 0x02, 0xc9, 0xfc, 0xea, 0xa7, 0x55, 0xe1, 0x10, 0x5d, 0xc4, 0x9e, 0xf6, 0x48, 0x90, 0x16, 0x77, 0x68, 0x83, 0xe0, 0x6f, 0xcf, 0x3a, 0xed, 0x93, 0xbf, 0x7f, 0x6a, 0xf7],
+            //   nonce: [0x23, 0x58, 0xae, 0x0c, 0xe3, 0xfb, 0x8e, 0x9f],
+            //   plain_text: vec!(0x19, 0x7c, 0x06, 0x40, 0x3e, 0xb8, 0x96, 0xd2, 0xfa, 0x64, 0x65, 0xe4, 0xd6, 0x44, 0x26, 0xd2, 0x4c, 0xc7, 0x47, 0x6a, 0xa1, 0xae, 0x41, 0x27, 0xcd, 0x2b, 0xd8, 0xa4, 0x8c, 0xe2, 0xc9, 0x9c, 0x16, 0xb1, 0xcb, 0xf3, 0x06, 0x48, 0x56, 0xe8, 0x40, 0x73, 0xb6, 0xcf, 0x12, 0xe7, 0x40, 0x66, 0x98, 0xef, 0x3d, 0xd1, 0x24, 0x0c, 0x02, 0x6c, 0xbd, 0x1a, 0xb0, 0x4e, 0xe6, 0x03, 0xe1, 0xe6, 0xe7, 0x35, 0xc9, 0xb7, 0x55, 0x1f, 0xd0, 0xd3, 0x55, 0x20, 0x2b, 0x4f, 0x64, 0xb4, 0x82, 0xdd, 0x4a, 0x7c, 0x7d, 0x82, 0xc4, 0xfe, 0x2e, 0xb4, 0x94, 0xd0, 0xd5, 0xe1, 0x77, 0x88, 0x98, 0x2d, 0x70, 0x4c, 0x13, 0x56, 0xc4, 0x1a, 0x94, 0x65, 0x55, 0x30, 0xde, 0xda, 0x23, 0x11, 0x8c, 0xba, 0x28, 0x1d, 0x0f, 0x71, 0x7e, 0x14, 0x9f, 0xbe, 0xb2, 0xc5, 0x9b, 0x22, 0xd0, 0xc0, 0x57, 0x4c, 0x1a, 0x2e, 0x64, 0x0a, 0xfa, 0xd1, 0xa6, 0xce, 0xb9, 0x2e, 0x1b, 0xf1, 0xdd, 0xe7, 0x17, 0x52, 0xa1, 0xc9, 0x91, 0xe9, 0xa5, 0x51, 0x7f, 0xe9, 0x86, 0x88, 0xa1, 0x6b, 0x07, 0x3d, 0xbf, 0x68, 0x84, 0xcf, 0xde, 0x61, 0xac),
+            //   aad: vec!(0xcf, 0x6c, 0xe7, 0xb8, 0x99, 0xfb, 0x70, 0x0a, 0x90, 0xd2, 0xa5, 0x46, 0x6d, 0x54, 0xd3, 0x13, 0x58, 0xec, 0xf0, 0x56, 0x2e, 0x02, 0xb3, 0x30, 0xa2, 0x7b, 0xa0, 0x13, 0x80, 0x06, 0xb3, 0x42, 0xb7, 0xed, 0x63, 0x49, 0xd7, 0x3c, 0x4c, 0x5c, 0x6d, 0x29, 0xbd, 0xe7, 0x5a, 0x25, 0x08, 0x9b, 0x11, 0xda, 0xc5, 0xb2, 0x7a, 0xde, 0xa7, 0xe7, 0x64, 0x0c, 0xa1, 0xa7, 0xce, 0xb0, 0x50, 0xe3, 0xaa, 0xe8, 0x4a, 0x47, 0xe1, 0x16, 0x40, 0xa6, 0xe4, 0x85, 0xbd, 0x54, 0xae, 0x9f, 0xdb, 0x54, 0x7e, 0xdc, 0x73, 0x13, 0xd2, 0x4a, 0x03, 0x28, 0x42, 0x9f, 0xcf, 0xfd, 0x8b, 0x18, 0xf3, 0x98, 0x80, 0xed, 0xd6, 0x16, 0x44, 0x73, 0x44, 0xeb, 0xee, 0xc9, 0xea, 0xdb, 0x2d, 0xcb, 0x1f, 0xa7, 0xe6, 0x71, 0x79, 0xe7, 0xf9, 0x13, 0xc1, 0x94, 0xeb, 0xd8, 0xf5, 0xa5, 0x8a, 0xea, 0x73, 0xb0, 0xc5, 0xd1, 0x13, 0x35, 0x61, 0x24, 0x5b, 0x6d, 0x9c, 0x5c, 0xfd, 0x8b, 0xb0, 0xc2, 0x5b, 0x38, 0xff, 0xb3, 0x7d, 0xb5, 0xe2, 0xde, 0x5c, 0xdd, 0xed, 0x6b, 0x57, 0x35, 0x5e, 0x9d, 0x21, 0x5c, 0xb0, 0x95, 0xb8, 0x73, 0x1f),
+            //   cipher_text: vec!(0xaa, 0x87, 0xf9, 0xa8, 0x30, 0x48, 0xb6, 0x91, 0x9c, 0x8f, 0x2b, 0x05, 0x03, 0x15, 0xdb, 0x4e, 0x2a, 0xda, 0xe4, 0xa9, 0xc2, 0xca, 0x01, 0x09, 0xb8, 0x19, 0x61, 0xb5, 0x20, 0xe6, 0x32, 0x99, 0xdc, 0xb0, 0x28, 0xce, 0xc0, 0xb9, 0xd3, 0x24, 0x9a, 0x94, 0x5e, 0xe6, 0x7d, 0xd0, 0x29, 0xb4, 0x0f, 0x36, 0x12, 0x45, 0xc7, 0x40, 0xf0, 0x04, 0xf8, 0xcf, 0x0d, 0x22, 0x14, 0xfc, 0xfa, 0x65, 0xe6, 0x12, 0x4a, 0x3e, 0x74, 0xb7, 0x8a, 0xa9, 0x43, 0x45, 0xc4, 0x6f, 0xdc, 0x15, 0x8d, 0x34, 0x82, 0x3e, 0xd2, 0x49, 0xee, 0x55, 0x04, 0x31, 0xea, 0xae, 0x92, 0x18, 0x36, 0x73, 0x21, 0xcd, 0xd6, 0xe6, 0xa4, 0x77, 0x65, 0x04, 0x69, 0xbb, 0x3c, 0xc1, 0x37, 0xa8, 0xf4, 0x8d, 0x9c, 0xf2, 0x79, 0x34, 0xb1, 0x67, 0x03, 0x60, 0x8b, 0x38, 0x3d, 0x21, 0x45, 0x65, 0x99, 0x22, 0xfb, 0x83, 0xbb, 0x2e, 0x7e, 0xe2, 0xef, 0x93, 0x8a, 0x90, 0xf2, 0xff, 0x84, 0x6a, 0x4a, 0x94, 0x91, 0x29, 0xb1, 0xfb, 0x74, 0xdd, 0xe5, 0x5c, 0x5a, 0xe0, 0x13, 0xc2, 0xf2, 0x85, 0xde, 0x84, 0xf7, 0xda, 0xc7, 0xd1, 0x66, 0x2f, 0x23),
+            //   tag: vec!(0x06, 0xb4, 0x31, 0x8a, 0xc7, 0xf6, 0x5d, 0x55, 0x6f, 0x78, 0x14, 0x28, 0xa0, 0x51, 0x4f, 0xfe)
+            // },
+            // TestVector{
+            //   key: [0x9e, 0x4a, 0x62, 0x01, 0x6d, 0xae, 0x4b, 0x32, 0x23, 0xfe, 0xd1, 0xd0, 0x1d, 0x07, 0x87, 0xe3, 0x1d, 0x30, 0x69, 0x4f, 0x79, 0xe8, 0x14, 0x22, 0x24, 0xfe, 0x4c, 0x47, 0x35, 0x24, 0x8a, 0x83],
+            //   nonce: [0x26, 0x3a, 0x2f, 0xc0, 0x6a, 0x28, 0x72, 0xe7],
+            //   plain_text: vec!(0x5a, 0x46, 0x94, 0x66, 0x01, 0xf9, 0x3a, 0x0c, 0xee, 0x59, 0x93, 0xc6, 0x95, 0x75, 0xe5, 0x99, 0xcc, 0x24, 0xf5, 0x1a, 0xaf, 0xa2, 0xd7, 0xc2, 0x8d, 0x81, 0x6a, 0x5b, 0x9b, 0x4d, 0xec, 0xda, 0x2e, 0x59, 0xc1, 0x11, 0x07, 0x5f, 0xb6, 0x0a, 0x90, 0x3d, 0x70, 0x1a, 0xd2, 0x68, 0x0b, 0xb1, 0x4a, 0xed, 0xa1, 0x4a, 0xf2, 0xae, 0x9c, 0x07, 0xa7, 0x59, 0xd8, 0x38, 0x8b, 0x30, 0x44, 0x6f, 0x28, 0xb8, 0x5f, 0x0a, 0x05, 0xcd, 0x15, 0x00, 0x50, 0xbd, 0x2e, 0x71, 0x5f, 0xf5, 0x50, 0xeb, 0xbd, 0x24, 0xda, 0x3e, 0xbb, 0x1e, 0xac, 0x15, 0xab, 0xa2, 0x3d, 0x44, 0x86, 0x59, 0xde, 0x34, 0xbe, 0x96, 0x2a, 0xb3, 0xab, 0x31, 0xcb, 0x17, 0x58, 0xdb, 0x76, 0xc4, 0x68, 0xb5, 0xbb, 0x8c, 0xe4, 0x4b, 0x06, 0xc4, 0xe4, 0xdb, 0x9b, 0xd2, 0xf0, 0x61, 0x5b, 0x1e, 0x72, 0x7f, 0x05, 0x3f, 0x6b, 0x4f, 0xfb, 0x63, 0x58, 0xd2, 0x48, 0xf0, 0x22, 0xbc, 0xad, 0x6c, 0xa9, 0x73, 0x04, 0x4b, 0xed, 0x23, 0xd3, 0x92, 0x09, 0x06, 0xa8, 0x9a, 0x9a, 0x9c, 0x5d, 0x80, 0x24, 0xec, 0x67, 0xd7, 0xf0, 0x61, 0xf6, 0x45, 0x29, 0xa9, 0x55, 0xce, 0x16, 0xb3),
+            //   aad: vec!(0x4c, 0xd6, 0x5f, 0x68, 0xf9, 0xf8, 0x8c, 0x05, 0x16, 0x23, 0x1f, 0x2a, 0x42, 0x5c, 0x8f, 0x8a, 0x28, 0x7d, 0xe4, 0x7d, 0x40, 0x9d, 0x5e, 0xcd, 0xe3, 0xad, 0x15, 0x1e, 0x90, 0x6b, 0x38, 0x39, 0xfb, 0x01, 0xbb, 0x91, 0xa4, 0x56, 0xf2, 0x0e, 0xa9, 0xd3, 0x94, 0xd4, 0xb0, 0x66, 0x04, 0xab, 0x1f, 0x90, 0x09, 0xef, 0x29, 0x01, 0x9a, 0xf7, 0x96, 0x8d, 0x96, 0x5d, 0x16, 0x43, 0x16, 0x1a, 0xb3, 0x3a, 0x53, 0x54, 0xcd, 0xa2, 0xfd, 0xc9, 0xf1, 0xd2, 0x1e, 0xc9, 0xcb, 0x71, 0xc3, 0x25, 0xc6, 0x59, 0x64, 0xa1, 0x4f, 0x9b, 0x26, 0xeb, 0x16, 0x56, 0x0b, 0xeb, 0x97, 0x92, 0x07, 0x5a, 0x15, 0x97, 0x39, 0x40, 0x00, 0xfd, 0x5f, 0x33, 0x1b, 0xd8, 0xb7, 0xd2, 0x0d, 0x88, 0xe5, 0xf8, 0x9c, 0xf8, 0xd0, 0xb3, 0x3e, 0x4e, 0x78, 0xe4, 0x90, 0x4b, 0xb5, 0x9c, 0x9c, 0x8d, 0x5d, 0x31, 0xac, 0x86, 0xb8, 0x93, 0xe4, 0xa0, 0x66, 0x7a, 0xf1, 0xbe, 0x85, 0xfd, 0xb7, 0x7f, 0x7e, 0xc3, 0xe2, 0x59, 0x4a, 0x68, 0x04, 0x8d, 0x20, 0xc2, 0xfb, 0x94, 0x22, 0xf5, 0x87, 0x90, 0x78, 0x77, 0x2e, 0xe2, 0x6a, 0x1c, 0x56, 0x0c, 0xbc, 0xbb, 0x21, 0x13),
+            //   cipher_text: vec!(0xe9, 0x44, 0xbb, 0x2a, 0xb0, 0x6d, 0x13, 0x8a, 0xd6, 0x33, 0xc1, 0x6c, 0xe8, 0x27, 0x06, 0xec, 0xf0, 0xef, 0x5d, 0x11, 0x9b, 0xe1, 0xf3, 0x46, 0x0c, 0x9c, 0xe1, 0x01, 0xd9, 0xc4, 0xe0, 0x4e, 0xf1, 0x67, 0x77, 0x07, 0xfc, 0xa4, 0x0d, 0x1f, 0x8c, 0xa1, 0x81, 0xe0, 0x72, 0x73, 0x70, 0x7b, 0x06, 0x62, 0x4d, 0x6d, 0x70, 0x63, 0xc3, 0xb7, 0xb0, 0xbb, 0x01, 0x51, 0xb7, 0x57, 0xb3, 0xe5, 0x23, 0x7f, 0xb8, 0x00, 0x4c, 0x16, 0x12, 0x33, 0xd8, 0xbc, 0x7e, 0x5f, 0x28, 0xea, 0x1c, 0x18, 0xda, 0x18, 0x74, 0xb3, 0xd5, 0x4c, 0x5a, 0xd6, 0xff, 0x08, 0x35, 0xee, 0xd3, 0x5c, 0x88, 0x53, 0x70, 0x45, 0x85, 0xcf, 0x83, 0x99, 0x6e, 0x5e, 0x7c, 0xec, 0x68, 0x18, 0x0a, 0xf4, 0x14, 0xe0, 0x4f, 0x08, 0x13, 0x4d, 0x3b, 0x03, 0x84, 0xeb, 0xdf, 0x03, 0x93, 0xc9, 0x31, 0x0b, 0x55, 0xd8, 0x69, 0x8f, 0xe1, 0x0c, 0xb3, 0x62, 0xde, 0xfc, 0x09, 0x95, 0xe9, 0xa1, 0x3b, 0x48, 0xb4, 0x2c, 0xff, 0x61, 0xff, 0xd9, 0xfe, 0x4c, 0x3c, 0x8c, 0x6d, 0xab, 0x35, 0x57, 0x13, 0xb8, 0x8f, 0x6e, 0x98, 0xa0, 0x2e, 0x72, 0x31, 0xa0, 0xc6, 0x64, 0x4e, 0xc4),
+            //   tag: vec!(0x27, 0xde, 0x0d, 0x4c, 0xa7, 0x64, 0x8f, 0x63, 0x96, 0xd5, 0x41, 0x9a, 0x7b, 0x12, 0x43, 0xb7)
+            // },
+            // TestVector{
+            //   key: [0x18, 0xca, 0x3e, 0xa3, 0xe8, 0xba, 0xee, 0xd1, 0xb3, 0x41, 0x18, 0x92, 0x97, 0xd3, 0x3c, 0xef, 0x7f, 0x4e, 0x0a, 0x2f, 0xab, 0x40, 0xec, 0x3b, 0x6b, 0xb6, 0x73, 0x85, 0xd0, 0x96, 0x9c, 0xfe],
+            //   nonce: [0xb6, 0xae, 0xf3, 0x4c, 0x75, 0x81, 0x8e, 0x7c],
+            //   plain_text: vec!(0xef, 0x6d, 0x1b, 0xb4, 0x09, 0x47, 0x82, 0xf6, 0x02, 0xfc, 0xf4, 0x15, 0x61, 0xcb, 0xa4, 0x97, 0x06, 0x79, 0x66, 0x1c, 0x63, 0xbe, 0xfe, 0x35, 0xff, 0x2c, 0xa7, 0xad, 0x1a, 0x28, 0x0b, 0xf6, 0xb1, 0xe7, 0xf1, 0x53, 0xfa, 0x84, 0x8e, 0xdf, 0xef, 0xfe, 0x25, 0x15, 0x3f, 0x54, 0x0b, 0x71, 0x25, 0x3e, 0x8b, 0xab, 0xa9, 0xae, 0xb7, 0x19, 0xa0, 0x27, 0x52, 0xcd, 0xa6, 0x0e, 0xa5, 0x93, 0x8a, 0xab, 0x33, 0x9e, 0xea, 0xd5, 0xaa, 0xbf, 0x81, 0xb1, 0x9b, 0x0f, 0xc5, 0xc1, 0xed, 0x55, 0x6b, 0xe6, 0xad, 0x89, 0x70, 0xea, 0x43, 0xc3, 0x03, 0xd3, 0x04, 0x62, 0x05, 0xb1, 0x2c, 0x41, 0x9d, 0xea, 0x71, 0xc4, 0x24, 0x5c, 0xfe, 0xdd, 0x0a, 0x31, 0xb0, 0xf4, 0x15, 0x0b, 0x5a, 0x9f, 0xe8, 0x00, 0x52, 0x79, 0x01, 0x88, 0x52, 0x9a, 0xb3, 0x2f, 0x5e, 0x61, 0xd8, 0xcc, 0xde, 0x59, 0x73, 0xed, 0x30, 0xbd, 0xf2, 0x90, 0xcb, 0xfb, 0xd5, 0xf0, 0x73, 0xc0, 0xc6, 0xa0, 0x20, 0xea, 0xc0, 0x33, 0x2f, 0xce, 0xd1, 0x7a, 0x9a, 0x08, 0xce, 0xf6, 0xf9, 0x21, 0x7b, 0xd6, 0xbe, 0xf6, 0x8c, 0x15, 0x05, 0xd6, 0xee, 0xd4, 0x09, 0x53, 0xe1, 0x55, 0x08, 0xd8, 0x7f, 0x08, 0xfc),
+            //   aad: vec!(0xf4, 0x0f, 0x03, 0xbe, 0xaa, 0x02, 0x3d, 0xb6, 0x31, 0x1b, 0xad, 0x9b, 0x4d, 0x5d, 0x0d, 0x66, 0xa5, 0x8d, 0x97, 0x8e, 0x0b, 0xcb, 0xbf, 0x78, 0xac, 0xeb, 0xde, 0x1f, 0x4e, 0xb9, 0xa2, 0x84, 0x09, 0x56, 0x28, 0x95, 0x5a, 0x0b, 0x15, 0xaf, 0xc4, 0x54, 0x15, 0x2f, 0x96, 0x2e, 0xc3, 0xea, 0x2b, 0x9a, 0x3b, 0x08, 0x9b, 0x99, 0x65, 0x8e, 0x68, 0xed, 0xe4, 0xde, 0xe5, 0xac, 0xd5, 0x66, 0x72, 0x02, 0x5e, 0xb7, 0x32, 0x3b, 0xcb, 0xc6, 0xba, 0x5d, 0x91, 0xc9, 0x43, 0x10, 0xf1, 0x8c, 0x91, 0x8e, 0x39, 0x14, 0xbb, 0xbf, 0x86, 0x9e, 0x1b, 0x87, 0x21, 0x47, 0x6f, 0x9d, 0xef, 0x31, 0xb9, 0xd3, 0x2c, 0x47, 0x1a, 0x54, 0x13, 0x24, 0x81, 0xaa, 0x89, 0xf6, 0xc7, 0x35, 0xab, 0x19, 0x33, 0x69, 0x49, 0x6d, 0x8d, 0xbe, 0xb4, 0x9b, 0x13, 0x0d, 0x85, 0xfb, 0xff, 0x3f, 0x9c, 0xb7, 0xdc, 0xce, 0xa4, 0xc1, 0xda, 0x7a, 0x28, 0x46, 0xee, 0xf5, 0xe6, 0x92, 0x9d, 0x90, 0x09, 0xa9, 0x14, 0x9e, 0x39, 0xc6, 0xc8, 0xec, 0x15, 0x0c, 0x9a, 0xb4, 0x9a, 0x09, 0xc1, 0x8c, 0x47, 0x49, 0xa0, 0xa9, 0xfc, 0xba, 0x77, 0x05, 0x7c, 0xde, 0xa6, 0xef, 0xd4, 0xd1, 0x42, 0x25, 0x6c),
+            //   cipher_text: vec!(0xc5, 0x31, 0x63, 0x3c, 0x0c, 0x98, 0x23, 0x0d, 0xcf, 0x05, 0x9c, 0x10, 0x81, 0xd1, 0xd6, 0x9c, 0x96, 0xba, 0xb7, 0x1c, 0x31, 0x43, 0xae, 0x60, 0xf9, 0xfc, 0x2b, 0x9c, 0xd1, 0x87, 0x62, 0x31, 0x44, 0x96, 0xab, 0x6e, 0x90, 0xbf, 0x67, 0x96, 0x25, 0x2c, 0xb9, 0xf6, 0x67, 0xa1, 0xf0, 0x8d, 0xa4, 0x7f, 0xc2, 0xb0, 0xee, 0xcd, 0xa8, 0x13, 0x22, 0x8c, 0xae, 0x00, 0xd4, 0xc0, 0xd7, 0x1f, 0x5e, 0x01, 0xb6, 0xce, 0x76, 0x2f, 0xa6, 0x36, 0xef, 0xff, 0xe5, 0x5d, 0x0e, 0x89, 0xfd, 0xc8, 0x9b, 0xa4, 0x25, 0x21, 0xcc, 0x01, 0x9a, 0xb9, 0xd4, 0x08, 0xfc, 0xd7, 0x9c, 0x14, 0x91, 0x4e, 0x8b, 0xbf, 0x0e, 0xa4, 0x4d, 0x8a, 0x1d, 0x35, 0x74, 0x3a, 0xd6, 0x28, 0x32, 0x7e, 0x43, 0x2f, 0xdc, 0xfe, 0xb0, 0xb6, 0x67, 0x9d, 0xdc, 0xa8, 0xc9, 0x2b, 0x99, 0x84, 0x73, 0x73, 0x2a, 0xbd, 0x55, 0xdb, 0xa5, 0x4e, 0xef, 0xff, 0x83, 0xc7, 0x84, 0x88, 0xee, 0xe5, 0xf9, 0x2b, 0x14, 0x5a, 0x74, 0xb6, 0x86, 0x65, 0x31, 0x47, 0x6f, 0xc4, 0x62, 0x79, 0xd4, 0xfd, 0xe2, 0x4d, 0x04, 0x9c, 0x1c, 0xe2, 0xb4, 0x23, 0x58, 0xff, 0x3a, 0xb2, 0xba, 0x3a, 0x88, 0x66, 0xe5, 0x47, 0xaf),
+            //   tag: vec!(0xa0, 0xa5, 0x24, 0x27, 0x59, 0xa6, 0xd9, 0xb1, 0xaa, 0x5b, 0xaf, 0x9a, 0x4e, 0xf8, 0x95, 0xa2)
+            // },
+            // TestVector{
+            //   key: [0x95, 0xfd, 0xd2, 0xd3, 0xd4, 0x29, 0x60, 0x69, 0x05, 0x5b, 0x6b, 0x79, 0xe5, 0xd1, 0x38, 0x76, 0x28, 0x25, 0x4a, 0x7b, 0xe6, 0x47, 0xba, 0xaf, 0xdf, 0x99, 0xdd, 0x8a, 0xf3, 0x54, 0xd8, 0x17],
+            //   nonce: [0xcd, 0x7e, 0xd9, 0xe7, 0x0f, 0x60, 0x86, 0x13],
+            //   plain_text: vec!(0x02, 0x48, 0x28, 0x4a, 0xcf, 0xfa, 0x4b, 0x2c, 0x46, 0x63, 0x6b, 0xdf, 0x8c, 0xc7, 0x00, 0x28, 0xdd, 0x15, 0x1a, 0x6d, 0x8e, 0x7a, 0x5a, 0x5b, 0xc2, 0xd3, 0x9a, 0xcc, 0x10, 0x20, 0xe7, 0x36, 0x88, 0x50, 0x31, 0xb2, 0x52, 0xbf, 0xe9, 0xf9, 0x64, 0x90, 0x92, 0x1f, 0x41, 0xd1, 0xe1, 0x74, 0xbf, 0x1a, 0xc0, 0x37, 0x07, 0xbc, 0x2a, 0xe5, 0x08, 0x8a, 0x12, 0x08, 0xa7, 0xc6, 0x64, 0x58, 0x38, 0x35, 0xe8, 0xbb, 0x93, 0xc7, 0x87, 0xb9, 0x6d, 0xea, 0x9f, 0xc4, 0xb8, 0x84, 0x93, 0x0c, 0x57, 0x79, 0x9e, 0x7b, 0x7a, 0x66, 0x49, 0xc6, 0x13, 0x40, 0x37, 0x6d, 0x04, 0x2b, 0x9f, 0x5f, 0xae, 0xe8, 0x95, 0x6c, 0x70, 0xa6, 0x3c, 0xf1, 0xcf, 0xf4, 0xfc, 0x2c, 0x7c, 0xb8, 0x53, 0x5c, 0x10, 0x21, 0x4e, 0x73, 0xce, 0xc6, 0xb7, 0x96, 0x69, 0xd8, 0x24, 0xf2, 0x3f, 0xf8, 0xc8, 0xa2, 0xca, 0x1c, 0x05, 0x97, 0x4d, 0xd6, 0x18, 0x9c, 0xfe, 0xe4, 0x84, 0xd0, 0x90, 0x6d, 0xf4, 0x87, 0xb6, 0xbd, 0x85, 0x67, 0x1c, 0xe2, 0xb2, 0x38, 0x25, 0x05, 0x2e, 0x44, 0xb8, 0x48, 0x03, 0xe2, 0x83, 0x9a, 0x96, 0x39, 0x1a, 0xbc, 0x25, 0x94, 0x5c, 0xb8, 0x67, 0xb5, 0x27, 0xcd, 0xd9, 0xb3, 0x73, 0xfb, 0xfb, 0x83),
+            //   aad: vec!(0x24, 0xa4, 0x5a, 0x3a, 0x00, 0x76, 0xa5, 0xbc, 0xfd, 0x5a, 0xfe, 0x1c, 0x54, 0xf7, 0xb7, 0x74, 0x96, 0x11, 0x7d, 0x29, 0xf4, 0xc0, 0x90, 0x9f, 0x1e, 0x69, 0x40, 0xb8, 0x1d, 0xde, 0x3a, 0xba, 0xcb, 0x71, 0xec, 0x71, 0xf0, 0xf4, 0xdb, 0x8a, 0x7e, 0x54, 0x0b, 0xd4, 0xc2, 0xc6, 0x0f, 0xae, 0xe2, 0x1d, 0xd3, 0xce, 0x72, 0x96, 0x38, 0x55, 0xbe, 0x1b, 0x0c, 0xe5, 0x4f, 0xb2, 0x0a, 0xd8, 0x2d, 0xbc, 0x45, 0xbe, 0x20, 0xcd, 0x6c, 0x17, 0x1e, 0x2b, 0xeb, 0xb7, 0x9e, 0x65, 0xe7, 0xd0, 0x15, 0x67, 0xad, 0x0e, 0xeb, 0x86, 0x98, 0x83, 0xe4, 0xe8, 0x14, 0xc9, 0x36, 0x88, 0x60, 0x7a, 0x12, 0xb3, 0xb7, 0x32, 0xc1, 0x70, 0x3b, 0x09, 0x56, 0x6c, 0x30, 0x8d, 0x29, 0xce, 0x67, 0x6a, 0x5c, 0x76, 0x2a, 0x85, 0x70, 0x06, 0x39, 0xb7, 0x0d, 0x82, 0xaa, 0xef, 0x40, 0x8c, 0xf9, 0x88, 0x21, 0xa3, 0x72, 0xc6, 0xa0, 0x61, 0x4a, 0x73, 0xba, 0x99, 0x18, 0xa7, 0x95, 0x1e, 0xa8, 0xb2, 0xbb, 0x77, 0xcd, 0x98, 0x96, 0xd2, 0x69, 0x88, 0x08, 0x6d, 0x85, 0x86, 0xd7, 0x2e, 0xdc, 0x92, 0xaf, 0x20, 0x42, 0xff, 0x5e, 0x5f, 0x14, 0x29, 0xa2, 0x2f, 0x61, 0x06, 0x5e, 0x03, 0xcf, 0xcd, 0x7e, 0xdc, 0x2a, 0x93),
+            //   cipher_text: vec!(0x40, 0xc6, 0x31, 0x8d, 0x9e, 0x38, 0x3e, 0x10, 0x7c, 0xdd, 0x3e, 0x1c, 0x89, 0x51, 0x56, 0x21, 0x93, 0xc3, 0xef, 0x64, 0xee, 0x44, 0x24, 0x32, 0xa6, 0x3e, 0x2e, 0xde, 0xfc, 0x78, 0xf3, 0x2a, 0xb0, 0x77, 0x72, 0xae, 0xac, 0x17, 0x2c, 0xb6, 0x7e, 0xcf, 0x4d, 0x21, 0xf8, 0xb4, 0x48, 0x42, 0x35, 0x27, 0xbb, 0xeb, 0x9d, 0x8d, 0xdd, 0x0b, 0x46, 0xbd, 0xb2, 0x7f, 0x74, 0x09, 0x6c, 0xeb, 0x24, 0xe4, 0x19, 0x63, 0xb4, 0xcd, 0xca, 0x17, 0x66, 0x76, 0xa7, 0x5b, 0xdb, 0xe3, 0xab, 0xc2, 0x70, 0xb3, 0x49, 0xac, 0x0c, 0x6c, 0xbd, 0x9c, 0x3a, 0x5c, 0xd5, 0xbc, 0xe2, 0x02, 0x02, 0xfc, 0x5c, 0xc0, 0xc1, 0xbd, 0xd4, 0xfd, 0x25, 0xe1, 0x21, 0xe0, 0xa2, 0x4b, 0xd7, 0xbb, 0xeb, 0x9b, 0x19, 0xb1, 0x91, 0x24, 0x67, 0xbf, 0x53, 0x38, 0xee, 0x2c, 0xe8, 0x8a, 0xa3, 0x83, 0xc0, 0x82, 0xb4, 0x2c, 0xc3, 0x99, 0xc9, 0x65, 0x4c, 0xa3, 0x25, 0xf3, 0x55, 0x23, 0xe8, 0x14, 0x38, 0xbe, 0xb3, 0xf8, 0x92, 0x6b, 0xe7, 0x9c, 0x37, 0x88, 0x22, 0xd7, 0xc8, 0xf7, 0x85, 0x61, 0x44, 0x08, 0xa5, 0xf7, 0xca, 0xc4, 0x9e, 0x45, 0x43, 0x18, 0x87, 0x25, 0x64, 0x3e, 0x6c, 0x1a, 0x70, 0xb4, 0x6d, 0x0e, 0xc4, 0x00),
+            //   tag: vec!(0x58, 0x01, 0xe8, 0x41, 0x92, 0xc7, 0x26, 0x7f, 0x66, 0xb0, 0xe0, 0x46, 0x07, 0xa3, 0x9a, 0x3e)
+            // },
+            // TestVector{
+            //   key: [0x6a, 0xe1, 0x10, 0x2f, 0x84, 0xed, 0x4d, 0xc1, 0x14, 0xbb, 0x9d, 0x63, 0xf4, 0xdc, 0x78, 0xd7, 0xdb, 0xb1, 0xab, 0x63, 0xf1, 0x65, 0x9d, 0xd9, 0x5f, 0x47, 0x94, 0x0a, 0x7b, 0x7a, 0x81, 0x1f],
+            //   nonce: [0xc9, 0x65, 0xd5, 0x78, 0xba, 0x91, 0xd2, 0x27],
+            //   plain_text: vec!(0xb8, 0x2a, 0x8a, 0x92, 0x09, 0x61, 0x8f, 0x1f, 0x5b, 0xe9, 0xc2, 0xc3, 0x2a, 0xba, 0x3d, 0xc4, 0x5b, 0x49, 0x47, 0x00, 0x7b, 0x14, 0xc8, 0x51, 0xcd, 0x69, 0x44, 0x56, 0xb3, 0x03, 0xad, 0x59, 0xa4, 0x65, 0x66, 0x28, 0x03, 0x00, 0x67, 0x05, 0x67, 0x3d, 0x6c, 0x3e, 0x29, 0xf1, 0xd3, 0x51, 0x0d, 0xfc, 0x04, 0x05, 0x46, 0x3c, 0x03, 0x41, 0x4e, 0x0e, 0x07, 0xe3, 0x59, 0xf1, 0xf1, 0x81, 0x6c, 0x68, 0xb2, 0x43, 0x4a, 0x19, 0xd3, 0xee, 0xe0, 0x46, 0x48, 0x73, 0xe2, 0x3c, 0x43, 0xf3, 0xab, 0x60, 0xa3, 0xf6, 0x06, 0xa0, 0xe5, 0xbe, 0x81, 0xe3, 0xab, 0x4a, 0xa2, 0x7f, 0xb7, 0x70, 0x7a, 0x57, 0xb9, 0x49, 0xf0, 0x0d, 0x6c, 0xd3, 0xa1, 0x1a, 0xe4, 0x82, 0x7d, 0x48, 0x89, 0xdd, 0x45, 0x5a, 0x0b, 0x6d, 0x39, 0xe9, 0x90, 0x12, 0xfd, 0x40, 0xdb, 0x23, 0xfb, 0x50, 0xe7, 0x9e, 0x11, 0xf8, 0xa6, 0x45, 0x16, 0x69, 0xbe, 0xb2, 0xfb, 0xd9, 0x13, 0xef, 0xfd, 0x49, 0xad, 0x1b, 0x43, 0x92, 0x63, 0x11, 0xf6, 0xe1, 0x3a, 0x6e, 0x7a, 0x09, 0xcf, 0x4b, 0xeb, 0xb1, 0xc0, 0xbf, 0x63, 0xce, 0x59, 0xcd, 0x5a, 0x08, 0xe4, 0xb8, 0xd8, 0xdb, 0xf9, 0xd0, 0x02, 0xe8, 0xa3, 0xd9, 0xe8, 0x0c, 0x79, 0x95, 0xbb, 0x0b, 0x48, 0x52, 0x80),
+            //   aad: vec!(0xdf, 0xd4, 0xac, 0x3e, 0x80, 0xb2, 0x90, 0x46, 0x23, 0xff, 0x79, 0xea, 0x8e, 0xe8, 0x78, 0x62, 0x26, 0x89, 0x39, 0xde, 0xcf, 0x53, 0x06, 0xc0, 0x7a, 0x17, 0x5b, 0x6b, 0x9d, 0xa0, 0xeb, 0x13, 0xac, 0x20, 0x9b, 0x4d, 0x16, 0x47, 0x55, 0x92, 0x9e, 0x03, 0x24, 0x0a, 0x0f, 0xe2, 0x65, 0x99, 0xf1, 0x36, 0xfb, 0x2a, 0xfd, 0xff, 0xd1, 0x2b, 0xb2, 0x03, 0x54, 0xaa, 0x1d, 0x20, 0xe5, 0x79, 0x98, 0x39, 0xab, 0xb6, 0x8a, 0xe4, 0x6d, 0x50, 0xc8, 0x97, 0x4e, 0x13, 0xe3, 0x61, 0xd8, 0x7e, 0xf5, 0x50, 0xfe, 0x6d, 0x82, 0xe8, 0xb5, 0xb1, 0x72, 0xcf, 0x5c, 0xd0, 0x84, 0x82, 0xef, 0xde, 0xf7, 0x93, 0xed, 0xe3, 0x53, 0x0d, 0x24, 0x66, 0x7f, 0xaf, 0x3a, 0x1e, 0x96, 0x34, 0x88, 0x67, 0xc2, 0x94, 0x26, 0x41, 0xf4, 0xc0, 0x36, 0x98, 0x1b, 0x83, 0xf5, 0x02, 0x36, 0xb8, 0xe8, 0xa1, 0x0b, 0x83, 0xeb, 0xf6, 0x90, 0x9a, 0xad, 0x00, 0x76, 0x30, 0x2f, 0x10, 0x83, 0xf7, 0x2d, 0xe4, 0xcf, 0x4a, 0x1a, 0x31, 0x83, 0xfe, 0x6e, 0xc6, 0xbf, 0xe2, 0xe7, 0x3e, 0x2a, 0xf8, 0xe1, 0xe8, 0xc9, 0xd8, 0x50, 0x79, 0x08, 0x3f, 0xd1, 0x79, 0xcc, 0xc2, 0xee, 0x9f, 0xf0, 0x02, 0xf2, 0x13, 0xdb, 0xd7, 0x33, 0x30, 0x53, 0xa4, 0x6c, 0x5e, 0x43),
+            //   cipher_text: vec!(0xa9, 0xae, 0xb8, 0xf0, 0xa2, 0xb3, 0xca, 0x14, 0x1a, 0xc7, 0x1a, 0x80, 0x8d, 0xcc, 0x0c, 0x97, 0x98, 0xac, 0x11, 0x7c, 0x5d, 0x2b, 0xd0, 0x9b, 0x3c, 0xfe, 0x62, 0x26, 0x93, 0xa9, 0xf8, 0xca, 0x62, 0xe8, 0x41, 0xb5, 0x8b, 0xdd, 0xb2, 0x04, 0x2f, 0x88, 0x8e, 0x30, 0x99, 0xb5, 0x36, 0x38, 0xb8, 0x8d, 0xfc, 0x93, 0x0b, 0x7a, 0x6e, 0xe4, 0x27, 0x2d, 0x77, 0xe4, 0xb1, 0xd7, 0xe4, 0x42, 0xba, 0xb6, 0xaf, 0xbd, 0xe9, 0x6a, 0xb0, 0xb4, 0x32, 0xf0, 0x09, 0x2d, 0x9c, 0xa5, 0x0e, 0xef, 0x42, 0xf6, 0x3c, 0x60, 0xc0, 0x9e, 0x7b, 0x8d, 0xe0, 0x19, 0xb3, 0x2e, 0xbe, 0x40, 0x30, 0xc3, 0x7b, 0x81, 0x83, 0xcc, 0x1e, 0x3b, 0x91, 0x3b, 0x0c, 0xe4, 0xee, 0x4d, 0x74, 0x43, 0x98, 0xfa, 0x03, 0xf9, 0xaf, 0x1c, 0x07, 0x0b, 0xed, 0x8c, 0xda, 0xfd, 0x65, 0xb3, 0xa8, 0x41, 0x40, 0xcb, 0x4d, 0xea, 0xdc, 0x70, 0x18, 0x4d, 0xe7, 0x57, 0x33, 0x2c, 0xe3, 0x78, 0x0a, 0xf8, 0x43, 0x53, 0xf5, 0x40, 0x75, 0x52, 0x27, 0xe8, 0x86, 0xa8, 0xd7, 0xad, 0x98, 0x0f, 0x3d, 0xd6, 0xfd, 0x68, 0x26, 0x3d, 0x82, 0xe9, 0x3f, 0x88, 0x33, 0x81, 0xde, 0xc8, 0x88, 0xbc, 0x9f, 0x4f, 0x48, 0x34, 0x9a, 0xa2, 0xb4, 0xc3, 0x42, 0xcb, 0x9f, 0x48, 0xc6),
+            //   tag: vec!(0xf2, 0x6b, 0x3a, 0xf8, 0xa4, 0x5c, 0x41, 0x62, 0x91, 0xce, 0x66, 0x33, 0x07, 0x33, 0xb2, 0xf8)
+            // },
+            // TestVector{
+            //   key: [0x40, 0x5b, 0xb7, 0xb9, 0x47, 0x15, 0xb8, 0x75, 0xdf, 0x06, 0x86, 0x55, 0xf0, 0x05, 0x13, 0xcb, 0x1a, 0xe2, 0x3f, 0xfa, 0xac, 0x97, 0x7c, 0xe2, 0x73, 0xe5, 0x7d, 0x3f, 0x83, 0xb4, 0x36, 0x63],
+            //   nonce: [0x5c, 0x6d, 0xa1, 0x25, 0x94, 0x51, 0x11, 0x9a],
+            //   plain_text: vec!(0xf9, 0xf1, 0x43, 0xc0, 0xc5, 0x2c, 0x94, 0xb4, 0xba, 0x7b, 0x06, 0x08, 0xb1, 0x44, 0x15, 0x6a, 0x49, 0xe7, 0xb5, 0xd2, 0x7c, 0x97, 0x31, 0x57, 0x43, 0xd1, 0x71, 0x91, 0x1e, 0x36, 0x45, 0xab, 0x79, 0x57, 0xc8, 0x09, 0x24, 0xe3, 0xc6, 0xb9, 0xc2, 0x2a, 0xb7, 0xa1, 0xca, 0xc4, 0xb7, 0xe9, 0xc0, 0xde, 0x84, 0xe4, 0x9f, 0xd5, 0xe4, 0xa2, 0xd1, 0xab, 0x51, 0xd7, 0x64, 0xfc, 0x56, 0x70, 0x31, 0x86, 0x88, 0xec, 0x94, 0x2f, 0x7a, 0xb3, 0x4c, 0x33, 0x1d, 0xce, 0x8f, 0x90, 0xfe, 0xa6, 0x97, 0x2e, 0x07, 0xf0, 0xda, 0xde, 0xc2, 0x9d, 0x8e, 0xb3, 0xb7, 0xb6, 0x52, 0x1d, 0xdd, 0x67, 0x8a, 0x65, 0x27, 0xa9, 0x62, 0xf4, 0xd8, 0xaf, 0x78, 0xc0, 0x77, 0xe2, 0x7f, 0x7a, 0x0b, 0x2e, 0xf7, 0xea, 0xbd, 0x19, 0xe9, 0x2b, 0x7f, 0x8c, 0x1e, 0x8f, 0xb1, 0x66, 0xd4, 0x76, 0x3c, 0xe9, 0xc4, 0x0c, 0x88, 0x8c, 0xf4, 0x9a, 0xa9, 0xcd, 0xfc, 0x3e, 0x99, 0x7c, 0x8f, 0xe1, 0xcc, 0xe3, 0xfe, 0x80, 0x24, 0x41, 0xbb, 0xd6, 0x98, 0xde, 0x26, 0x9f, 0xf3, 0x16, 0xf3, 0x1c, 0x19, 0x6e, 0x62, 0xd1, 0x2c, 0x6b, 0xb5, 0xcd, 0x93, 0xfb, 0x3c, 0x79, 0xca, 0x63, 0x69, 0xf8, 0xc1, 0xac, 0x91, 0x02, 0xda, 0xf8, 0x18, 0x97, 0x5e, 0xa7, 0xf5, 0x13, 0xbb, 0x38, 0x57, 0x6a),
+            //   aad: vec!(0x6f, 0xe6, 0x44, 0x65, 0x05, 0x67, 0x7b, 0xf0, 0x8b, 0x38, 0x5e, 0x2f, 0x6d, 0x83, 0xef, 0x70, 0xe1, 0x54, 0x77, 0x12, 0x20, 0x8d, 0x9c, 0xeb, 0xc0, 0x10, 0xcb, 0xa8, 0xc1, 0x6e, 0xa4, 0xec, 0xe0, 0x58, 0xd7, 0x3c, 0x72, 0x27, 0x3e, 0xed, 0x65, 0x0a, 0xfd, 0xc9, 0xf9, 0x54, 0xf3, 0x5a, 0xa1, 0xbd, 0xf9, 0x0f, 0x11, 0x18, 0xb1, 0x17, 0x33, 0x68, 0xac, 0xbc, 0x8d, 0x38, 0xd9, 0x3e, 0xbf, 0x85, 0xbd, 0x30, 0xd6, 0xdc, 0x6d, 0x1b, 0x90, 0x91, 0x37, 0x90, 0xc3, 0xef, 0xa5, 0x5f, 0x34, 0xd3, 0x15, 0x31, 0xf7, 0x0c, 0x95, 0x87, 0x59, 0xb2, 0xba, 0x6f, 0x95, 0x6c, 0x6f, 0xcd, 0xd2, 0x89, 0xb5, 0x8c, 0xb4, 0xc2, 0x6e, 0x95, 0x15, 0xbf, 0x55, 0x0f, 0x0f, 0xd7, 0x1a, 0xb8, 0x52, 0x7f, 0x06, 0x2c, 0x95, 0x05, 0xcb, 0xb1, 0x6e, 0x8e, 0x03, 0x7d, 0x34, 0xde, 0x17, 0x56, 0xbe, 0xf0, 0x2a, 0x13, 0x3d, 0xbf, 0x4a, 0x9c, 0x00, 0xac, 0x03, 0xbe, 0xfc, 0x3f, 0xb7, 0xf1, 0x37, 0xaf, 0x04, 0xe1, 0x25, 0x95, 0xce, 0x95, 0x60, 0xf9, 0x8b, 0x61, 0x24, 0x80, 0xfc, 0xdb, 0xa3, 0xb8, 0xbe, 0x01, 0xdb, 0x56, 0xeb, 0xec, 0x40, 0xf9, 0xde, 0xae, 0x53, 0x2c, 0x3b, 0x03, 0x70, 0xb5, 0xc2, 0x3a, 0x2a, 0x6b, 0x02, 0xa4, 0xde, 0x69, 0xef, 0xa8, 0x90, 0x0c),
+            //   cipher_text: vec!(0x1a, 0x4b, 0x07, 0x38, 0x81, 0x92, 0x2c, 0x63, 0x66, 0x68, 0x0c, 0xc9, 0xc2, 0xa1, 0x27, 0xb2, 0x6f, 0x26, 0x41, 0x48, 0x65, 0x1b, 0x29, 0xab, 0xb0, 0xc3, 0x88, 0xcf, 0x6c, 0x9b, 0x18, 0x65, 0xdb, 0xa5, 0xa9, 0x91, 0xe1, 0xf8, 0x30, 0x9e, 0xfb, 0xdb, 0x91, 0xbc, 0xe4, 0x4b, 0x27, 0x87, 0x72, 0xc5, 0x8f, 0xd4, 0x12, 0x73, 0x52, 0x6c, 0x33, 0xfe, 0xc8, 0x4b, 0xeb, 0x53, 0xd1, 0x68, 0x9b, 0x9d, 0xa8, 0x48, 0x3f, 0x71, 0xbe, 0x6d, 0xb7, 0x3a, 0x73, 0x41, 0x70, 0x69, 0xbb, 0x4c, 0xd3, 0xf1, 0x95, 0x23, 0x6e, 0x8d, 0x0a, 0x00, 0xd1, 0x24, 0xee, 0xd3, 0xa6, 0xb6, 0xf8, 0x94, 0x15, 0xb1, 0x9a, 0x27, 0xfb, 0xe3, 0x57, 0x74, 0xf6, 0xa1, 0xa6, 0xee, 0x4b, 0xd4, 0x35, 0x0b, 0x25, 0x2b, 0x97, 0x5f, 0x0d, 0xb2, 0xd2, 0xee, 0xa8, 0x2f, 0x48, 0x36, 0x35, 0x08, 0x50, 0xd6, 0x29, 0x09, 0x01, 0xe7, 0x26, 0xe8, 0xaf, 0x13, 0x64, 0x4e, 0x2d, 0x98, 0xbc, 0x1d, 0x56, 0x9c, 0x20, 0x80, 0x05, 0x21, 0xe6, 0xaf, 0xfe, 0x97, 0x6b, 0xd4, 0x07, 0x04, 0x9a, 0x2e, 0x6d, 0x9d, 0xd2, 0x3f, 0x88, 0xd5, 0x2e, 0x65, 0x13, 0x91, 0xec, 0xd2, 0xfc, 0x45, 0xb8, 0x64, 0x31, 0x08, 0x24, 0xaa, 0xad, 0xfa, 0x20, 0x37, 0x62, 0xa7, 0x7c, 0x1d, 0x64, 0x56, 0x2d, 0xae),
+            //   tag: vec!(0x00, 0x60, 0x02, 0x6d, 0x3e, 0xfc, 0x12, 0x0f, 0x11, 0xc0, 0x73, 0x99, 0x59, 0xae, 0x00, 0x66)
+            // },
+            // TestVector{
+            //   key: [0x8c, 0x60, 0x2b, 0xd9, 0x4c, 0x63, 0x0c, 0xd0, 0x0c, 0x7a, 0x9c, 0x50, 0x80, 0x67, 0xa5, 0xa9, 0xf1, 0x33, 0xd1, 0x2f, 0x06, 0xd9, 0xf6, 0xfe, 0x2a, 0x7b, 0x68, 0xdc, 0xe4, 0x78, 0x6d, 0x8a],
+            //   nonce: [0x76, 0x0d, 0xe0, 0xf7, 0xb7, 0xcb, 0x67, 0xe2],
+            //   plain_text: vec!(0xc3, 0xff, 0x55, 0x9c, 0xf1, 0xd6, 0xba, 0x6c, 0x0c, 0xc7, 0x93, 0xca, 0x09, 0xa0, 0xba, 0x57, 0x3a, 0x28, 0x35, 0x93, 0x86, 0xa6, 0xec, 0x93, 0xe1, 0xba, 0xcd, 0x8e, 0x63, 0x02, 0x09, 0xe0, 0xb4, 0x77, 0xa2, 0x0a, 0xed, 0xec, 0x3c, 0x9c, 0xbf, 0x51, 0x3e, 0xe6, 0xa1, 0xe3, 0x88, 0x71, 0x12, 0x21, 0x8d, 0x61, 0x55, 0xb9, 0x87, 0x5f, 0x7e, 0x6c, 0x4b, 0xbb, 0xa2, 0xc3, 0x19, 0x72, 0xe9, 0x05, 0xd1, 0x9f, 0x52, 0x9f, 0x4f, 0x0f, 0x95, 0x02, 0x99, 0x61, 0x99, 0xf9, 0x4f, 0x87, 0x28, 0xba, 0x8d, 0x64, 0x24, 0xbb, 0x15, 0xf8, 0x7f, 0xca, 0xcd, 0x88, 0xbb, 0x42, 0xc6, 0x3f, 0xcc, 0x51, 0x37, 0x59, 0x71, 0x2b, 0xd0, 0x17, 0x2b, 0x1e, 0x87, 0xc9, 0xda, 0x12, 0x2f, 0x19, 0x93, 0xff, 0xb7, 0xef, 0xd3, 0xa5, 0xc3, 0x4b, 0x24, 0x0d, 0xd3, 0xdb, 0x89, 0xdd, 0xde, 0xa3, 0x6d, 0xbe, 0xb2, 0x83, 0x6d, 0x9f, 0x86, 0x48, 0xf8, 0xe7, 0xcd, 0x42, 0x8c, 0x0f, 0x94, 0x80, 0x97, 0xaf, 0x75, 0x3b, 0x35, 0xf9, 0x87, 0x60, 0x59, 0xe7, 0x70, 0x20, 0x27, 0xbb, 0x00, 0xdc, 0x69, 0x07, 0x12, 0x06, 0xe7, 0x85, 0xf4, 0x8f, 0xcb, 0xf8, 0x1b, 0x39, 0xcc, 0x03, 0x43, 0x97, 0x4a, 0xc7, 0x07, 0x84, 0xa2, 0xe6, 0x0c, 0x0d, 0xf9, 0x3b, 0x40, 0x37, 0x9b, 0xea, 0x4a, 0xd8, 0xca, 0xc6, 0x25),
+            //   aad: vec!(0x9e, 0x14, 0x90, 0x7c, 0x3a, 0x8e, 0x96, 0xc2, 0x63, 0x6d, 0xb1, 0xf3, 0xd7, 0x8e, 0xb1, 0xf6, 0x73, 0xd6, 0xef, 0x04, 0x3c, 0xbb, 0xb3, 0x49, 0x46, 0x7f, 0x1f, 0xe2, 0x9b, 0xf6, 0x0f, 0x23, 0xd5, 0xd5, 0xd1, 0xc3, 0xb1, 0x33, 0xa8, 0xad, 0x72, 0x06, 0x5d, 0x82, 0x23, 0x47, 0x54, 0x1c, 0x13, 0xd1, 0x57, 0x4b, 0xaf, 0x73, 0x7e, 0xb3, 0xcc, 0x33, 0x82, 0xfb, 0x47, 0x9e, 0x6d, 0x51, 0x93, 0xb9, 0xc8, 0xe7, 0xd2, 0x44, 0x4c, 0x66, 0x97, 0x1e, 0xf0, 0x99, 0xdc, 0x7f, 0x37, 0xf6, 0xcd, 0x97, 0xb9, 0xf7, 0x95, 0x9d, 0x46, 0xe2, 0xcf, 0x25, 0xe8, 0xa5, 0xb3, 0x11, 0x1b, 0x4d, 0x9e, 0x2e, 0xf9, 0x06, 0xd9, 0x05, 0xf0, 0xee, 0x2d, 0x17, 0x58, 0x7f, 0x70, 0x82, 0xd7, 0xc8, 0xe9, 0xa5, 0x15, 0x09, 0xbd, 0xe0, 0x3d, 0x3d, 0x64, 0x33, 0x8e, 0x18, 0x38, 0xd7, 0x17, 0x00, 0xf1, 0xb4, 0xfc, 0xb1, 0x00, 0xb5, 0xe0, 0x40, 0x29, 0x69, 0xda, 0x46, 0x2f, 0x26, 0xf9, 0x74, 0xb4, 0xf9, 0xe7, 0x66, 0x12, 0x1f, 0x8f, 0xd5, 0x4b, 0xe9, 0x9f, 0xc1, 0x0b, 0xeb, 0x9a, 0x60, 0x6e, 0x13, 0xfb, 0xb1, 0xf9, 0x60, 0x06, 0x28, 0x15, 0xd1, 0x9e, 0x67, 0xf8, 0x00, 0x93, 0x36, 0x03, 0x24, 0x01, 0x30, 0x95, 0x71, 0x92, 0x73, 0xc6, 0x55, 0x42, 0xb0, 0xe3, 0x1b, 0x1a, 0x2a, 0x3d, 0x92, 0x8f),
+            //   cipher_text: vec!(0x27, 0x94, 0xe6, 0xe1, 0x33, 0xf6, 0x89, 0x2f, 0x23, 0x83, 0x7f, 0xff, 0x60, 0xcf, 0x7c, 0x28, 0xee, 0x99, 0x42, 0xf8, 0x98, 0x2e, 0xf8, 0x08, 0x9d, 0xb1, 0x17, 0x90, 0x3d, 0x01, 0x43, 0x29, 0x3f, 0xdf, 0x12, 0xea, 0x1c, 0xc0, 0x14, 0xbc, 0xd8, 0x80, 0x6f, 0xb8, 0x3c, 0x19, 0x57, 0x0e, 0xed, 0x7a, 0xf5, 0x22, 0xdb, 0x0d, 0xe4, 0x89, 0xbb, 0xc8, 0x71, 0x33, 0xa1, 0x34, 0x34, 0x51, 0x8b, 0xcf, 0xb9, 0xcd, 0xa4, 0xd9, 0xf6, 0xd8, 0x32, 0xa6, 0x92, 0x09, 0x65, 0x7a, 0x44, 0x7a, 0xbf, 0x8a, 0xfd, 0x81, 0x6a, 0xe1, 0x5f, 0x31, 0x3c, 0x7e, 0xa9, 0x5e, 0xc4, 0xbc, 0x69, 0x4e, 0xfc, 0x23, 0x86, 0xcd, 0xd8, 0xd9, 0x15, 0xdc, 0x47, 0x5e, 0x8f, 0xad, 0xf3, 0x42, 0x1f, 0xbb, 0x03, 0x19, 0xa3, 0xc0, 0xb3, 0xb6, 0xdf, 0xa8, 0x0c, 0xa3, 0xbb, 0x22, 0xc7, 0xaa, 0xb0, 0x7f, 0xe1, 0x4a, 0x3f, 0xea, 0x5f, 0x0a, 0xee, 0x17, 0xab, 0x13, 0x02, 0x33, 0x8e, 0xea, 0xc0, 0x10, 0xa0, 0x4e, 0x50, 0x5e, 0x20, 0x09, 0x6a, 0x95, 0xf3, 0x34, 0x7d, 0xc2, 0xb4, 0x51, 0x0f, 0x62, 0xd6, 0xa4, 0xc1, 0xfa, 0xe6, 0xb3, 0x69, 0x39, 0x50, 0x3a, 0x6a, 0xc2, 0x27, 0x80, 0xa6, 0x2d, 0x72, 0xf2, 0xfc, 0x38, 0x49, 0xd4, 0xef, 0x21, 0x26, 0x7f, 0xff, 0xde, 0xf2, 0x31, 0x96, 0xd8, 0x8f, 0xbb, 0x9b),
+            //   tag: vec!(0x45, 0x7c, 0xce, 0x6e, 0x07, 0x5f, 0xfd, 0xb1, 0x80, 0x76, 0x5a, 0xb2, 0xe1, 0x05, 0xc7, 0x07)
+            // },
+            // TestVector{
+            //   key: [0xbd, 0x68, 0xff, 0x5e, 0xb2, 0x96, 0xc7, 0x1c, 0xfe, 0x6b, 0xc9, 0x03, 0xc1, 0x49, 0x07, 0xf7, 0x72, 0x6b, 0xcb, 0x13, 0x31, 0xf0, 0xc7, 0x5f, 0x78, 0x01, 0xcd, 0x1b, 0x79, 0x48, 0xf3, 0xa1],
+            //   nonce: [0x65, 0xa7, 0x48, 0x00, 0x4b, 0x35, 0x2b, 0xa6],
+            //   plain_text: vec!(0x52, 0xbf, 0x78, 0xc0, 0x0f, 0x6e, 0x5d, 0xca, 0x2f, 0xc6, 0x0e, 0x2e, 0x9a, 0x52, 0xe8, 0x27, 0xdf, 0x97, 0x80, 0x8e, 0x9c, 0xf7, 0x27, 0x77, 0x38, 0x60, 0xca, 0xfc, 0x89, 0xf4, 0xb6, 0x41, 0x78, 0xa1, 0x9b, 0x30, 0xb4, 0x6e, 0xd8, 0x13, 0xfe, 0x00, 0xc8, 0xf0, 0x9b, 0x25, 0xa6, 0xa1, 0xb6, 0xe3, 0x50, 0xd5, 0xb0, 0x05, 0x12, 0x29, 0x34, 0xa5, 0x9b, 0xfb, 0xd5, 0xe6, 0xe0, 0xc6, 0x35, 0xc8, 0x4a, 0x52, 0x26, 0xc3, 0xf2, 0xf7, 0xdc, 0xf9, 0x51, 0x56, 0x0f, 0x18, 0xac, 0x22, 0x04, 0x53, 0xd5, 0x83, 0x01, 0x5f, 0xdb, 0x2e, 0x44, 0x6c, 0x69, 0xc6, 0xe6, 0xfd, 0xec, 0xf2, 0xe5, 0x95, 0xe0, 0x4f, 0xab, 0x1b, 0x0c, 0x50, 0x6e, 0x3c, 0x6b, 0xd5, 0xe4, 0x41, 0x4a, 0x35, 0xf1, 0x50, 0x21, 0xe9, 0x7f, 0x44, 0x7a, 0xa3, 0x34, 0xf5, 0x4a, 0x8f, 0x1e, 0xf9, 0x42, 0xde, 0xc6, 0x27, 0x35, 0x11, 0xb5, 0x66, 0x8b, 0x69, 0x6f, 0xca, 0x97, 0x18, 0x8f, 0xf1, 0x5e, 0xd8, 0x4b, 0x2f, 0x46, 0x14, 0x5c, 0xce, 0x03, 0x1c, 0x1a, 0x7f, 0x00, 0xbd, 0x88, 0xbb, 0x83, 0xd9, 0x07, 0x97, 0xed, 0xc4, 0x61, 0x61, 0xb3, 0xfd, 0xa7, 0xa2, 0x29, 0x91, 0x73, 0x49, 0x6d, 0x73, 0xb8, 0x12, 0x13, 0x95, 0x56, 0xe8, 0xb4, 0xeb, 0x31, 0x80, 0x78, 0xb9, 0xeb, 0x2a, 0xe5, 0x04, 0x6e, 0x83, 0xb7, 0x9d, 0xd3, 0xd4, 0x59, 0x50),
+            //   aad: vec!(0x55, 0x57, 0xb0, 0x8a, 0x50, 0x10, 0xcb, 0xc9, 0xf4, 0x6b, 0xb1, 0x40, 0xc2, 0x50, 0x5f, 0x68, 0x68, 0x4e, 0xb2, 0x48, 0x89, 0x32, 0x4b, 0xff, 0x44, 0xb2, 0x72, 0x34, 0xfd, 0x7a, 0x95, 0xa9, 0x9c, 0xfb, 0x4f, 0xf9, 0x0a, 0x8f, 0x99, 0x82, 0x08, 0x5b, 0x72, 0x5f, 0x78, 0xac, 0x42, 0xec, 0xa6, 0xce, 0x7f, 0x33, 0x14, 0xe4, 0x57, 0xdc, 0x41, 0xf4, 0x04, 0x00, 0x86, 0x81, 0xa9, 0xd2, 0x9b, 0xa7, 0x65, 0x66, 0x0d, 0xe2, 0xe0, 0x5b, 0xb6, 0x79, 0xd6, 0x5b, 0x81, 0xf5, 0xe7, 0x97, 0xd8, 0x41, 0x7b, 0x94, 0xeb, 0x9a, 0xab, 0xbd, 0x05, 0x76, 0xb5, 0xc5, 0x7f, 0x86, 0xea, 0xe2, 0x5f, 0x60, 0x50, 0xa7, 0x91, 0x8e, 0x4c, 0x80, 0x21, 0xa8, 0x5b, 0x47, 0xf7, 0xa8, 0x3b, 0x4c, 0x84, 0x46, 0x89, 0x84, 0x41, 0xc5, 0xcc, 0x4e, 0x02, 0x29, 0x77, 0x6e, 0xf3, 0xe8, 0x09, 0xcb, 0x08, 0x5d, 0x71, 0xf3, 0xc7, 0x5e, 0xc0, 0x33, 0x78, 0x73, 0x0c, 0xb0, 0x66, 0x15, 0x0f, 0x07, 0xe6, 0x0f, 0x96, 0xae, 0xc9, 0x83, 0xc0, 0xe7, 0xe7, 0x2b, 0xf6, 0xbf, 0x87, 0xae, 0x42, 0x22, 0x8d, 0xfd, 0xa1, 0x95, 0xf9, 0x78, 0x55, 0xfc, 0xdf, 0x4e, 0x6d, 0x1c, 0x44, 0x79, 0xd9, 0x78, 0xab, 0xcf, 0xa2, 0x76, 0xd1, 0x6e, 0xd6, 0x0e, 0xcb, 0xfb, 0xfc, 0x66, 0x40, 0x41, 0x33, 0x5c, 0xe6, 0x5a, 0x40, 0xa2, 0xca, 0x34, 0x24, 0xdf),
+            //   cipher_text: vec!(0xa5, 0xc8, 0xcf, 0x42, 0x28, 0x7d, 0x47, 0x60, 0xfc, 0xa7, 0x55, 0xe2, 0x11, 0x18, 0x17, 0xb9, 0x81, 0xc4, 0x7e, 0x85, 0xb0, 0x04, 0x7d, 0xe2, 0x70, 0xec, 0x30, 0x1c, 0xa5, 0xf7, 0xb3, 0x67, 0x9f, 0x47, 0x49, 0x21, 0x08, 0x92, 0xb6, 0xea, 0x65, 0x68, 0xf3, 0xa6, 0xa4, 0x34, 0x47, 0x34, 0xa0, 0xef, 0xc0, 0x12, 0x0f, 0xfe, 0xde, 0xcf, 0x21, 0x2d, 0x55, 0xcb, 0xcb, 0xb6, 0x78, 0x15, 0xac, 0x96, 0x48, 0x75, 0xaf, 0x45, 0xf7, 0x35, 0xb7, 0x00, 0x92, 0xa8, 0xf8, 0x43, 0x5f, 0x52, 0xfc, 0x01, 0xb9, 0x81, 0xae, 0x97, 0x1d, 0x48, 0x60, 0x26, 0xfb, 0x69, 0xa9, 0xc3, 0x92, 0x7a, 0xcf, 0xe1, 0xf2, 0xea, 0xb0, 0x34, 0x0a, 0xe9, 0x5f, 0x8d, 0xbe, 0xe4, 0x1b, 0x25, 0x48, 0xe4, 0x00, 0x80, 0x5e, 0xce, 0x19, 0x1d, 0xb5, 0xfd, 0x1f, 0x08, 0x04, 0x05, 0x3f, 0x1d, 0xbf, 0xaf, 0x7f, 0x8d, 0x6f, 0xde, 0xd3, 0x87, 0x4c, 0xb9, 0x2d, 0x99, 0xa2, 0x72, 0x9d, 0x3f, 0xaa, 0xa6, 0x05, 0x22, 0x06, 0x0c, 0xf0, 0xb8, 0x10, 0x1b, 0x46, 0x3b, 0x3e, 0xb3, 0x5b, 0x38, 0x0f, 0xcd, 0xdb, 0x64, 0x06, 0xc0, 0x27, 0xd7, 0x3f, 0xe7, 0x01, 0xa5, 0x09, 0x0c, 0x8d, 0xd5, 0x31, 0xc2, 0x03, 0xce, 0x97, 0x9e, 0x26, 0xb9, 0xce, 0xd3, 0x43, 0x1e, 0x2b, 0x72, 0x6a, 0x72, 0x44, 0xa2, 0x0d, 0x93, 0x77, 0xbd, 0x62, 0x95, 0x1b, 0xf5),
+            //   tag: vec!(0x45, 0x79, 0xfa, 0x1f, 0xdb, 0x4c, 0x67, 0x4c, 0xc3, 0xcd, 0x23, 0x2b, 0x8d, 0xa5, 0x2a, 0x97)
+            // },
+            // TestVector{
+            //   key: [0x93, 0x4f, 0xd0, 0x43, 0xc3, 0x2d, 0x16, 0xa8, 0x8f, 0xad, 0x01, 0xc3, 0x50, 0x64, 0x69, 0xb0, 0x77, 0xcb, 0x79, 0xd2, 0x58, 0xb5, 0x66, 0x4f, 0xa5, 0x5a, 0xd8, 0x52, 0x1a, 0xfd, 0xca, 0xa2],
+            //   nonce: [0xc7, 0x09, 0x1f, 0x6a, 0xfb, 0xbe, 0xb3, 0x60],
+            //   plain_text: vec!(0x2b, 0xdd, 0x1f, 0xc4, 0xf0, 0x11, 0xef, 0x97, 0xea, 0x52, 0xec, 0x64, 0x38, 0x19, 0x94, 0x1c, 0x7e, 0x0f, 0xb3, 0x90, 0x23, 0xc2, 0xf3, 0xc7, 0x68, 0x38, 0x04, 0xa0, 0xdd, 0xee, 0x14, 0xa5, 0xd1, 0x78, 0x4a, 0x52, 0x46, 0x96, 0x6d, 0x53, 0x3b, 0x35, 0x38, 0xed, 0xc7, 0xd8, 0x74, 0x2d, 0x27, 0x06, 0x1c, 0x3c, 0xab, 0x88, 0xdf, 0x03, 0x18, 0xab, 0x24, 0x21, 0x02, 0xde, 0x3a, 0x54, 0xd0, 0x36, 0x32, 0xee, 0xb8, 0x71, 0xb7, 0x2c, 0x7e, 0x8f, 0x80, 0x65, 0xb4, 0x9f, 0x4a, 0x91, 0xe9, 0x5e, 0x15, 0xf3, 0xf4, 0x6b, 0x29, 0xfd, 0x76, 0xb8, 0xfc, 0xea, 0x0d, 0x23, 0x57, 0x0c, 0x55, 0x30, 0xe3, 0xbb, 0xb8, 0xa6, 0xaa, 0xfa, 0x9a, 0xe3, 0x2c, 0x1b, 0x3e, 0xac, 0x65, 0x3c, 0x5e, 0xd5, 0xfd, 0xb2, 0xda, 0x5a, 0x98, 0x60, 0x75, 0x80, 0x8f, 0x63, 0x85, 0x87, 0x0c, 0x85, 0xb1, 0x91, 0x3e, 0x26, 0x04, 0x2a, 0x9d, 0x8e, 0x78, 0xf5, 0xbc, 0x2e, 0xa6, 0xde, 0x5a, 0x64, 0xf8, 0xae, 0xaf, 0xa2, 0x2a, 0xdc, 0xff, 0xc7, 0xf6, 0x93, 0x2d, 0x54, 0x3c, 0x29, 0xbb, 0x3a, 0x04, 0x61, 0x47, 0x83, 0xf9, 0x48, 0x68, 0x0e, 0x43, 0x3a, 0x71, 0x57, 0x35, 0x68, 0xd2, 0xce, 0x98, 0x4d, 0x24, 0x9f, 0xb4, 0xfc, 0x06, 0xa9, 0xf3, 0x58, 0xc7, 0x6a, 0xa3, 0xe6, 0x4a, 0x35, 0x7f, 0x4e, 0xae, 0x92, 0x4c, 0x13, 0x56, 0xbd, 0x5b, 0xac, 0xcf, 0x7e, 0x0f),
+            //   aad: vec!(0xf7, 0x37, 0xdd, 0x85, 0x63, 0x8e, 0xb3, 0x24, 0xdd, 0x38, 0x91, 0x21, 0x9c, 0x5e, 0xef, 0x7c, 0x2d, 0xd0, 0x53, 0xcf, 0xd0, 0x55, 0xd4, 0x47, 0xa4, 0x11, 0xeb, 0xa3, 0x04, 0xa4, 0xb2, 0x7d, 0xce, 0x98, 0x1d, 0x11, 0x2c, 0x45, 0x40, 0x59, 0x09, 0x33, 0xc1, 0x53, 0xd6, 0x03, 0x02, 0x2c, 0x91, 0xeb, 0xd2, 0xb4, 0xa5, 0x80, 0x69, 0xd2, 0x7e, 0x6c, 0xa1, 0x7a, 0x46, 0x2e, 0xf8, 0x22, 0xca, 0x41, 0xbf, 0xfa, 0x80, 0xb4, 0x3a, 0x68, 0xb1, 0xb5, 0x64, 0x64, 0x4c, 0xb3, 0xc5, 0xa7, 0xf0, 0xfd, 0xdf, 0x7a, 0x13, 0xa3, 0x0f, 0xf2, 0x44, 0x37, 0xfd, 0xdd, 0x8e, 0xf9, 0x3c, 0x6f, 0x6f, 0x20, 0x5d, 0x05, 0x4f, 0x81, 0x89, 0x0d, 0x98, 0x2b, 0xd4, 0xd4, 0xec, 0xe0, 0xb1, 0x56, 0x36, 0x77, 0xe8, 0x43, 0xfe, 0x48, 0xc1, 0xf5, 0x4e, 0x9a, 0x57, 0xed, 0x4d, 0xa6, 0x60, 0x61, 0x48, 0x27, 0x12, 0xe7, 0x10, 0xa4, 0x01, 0x07, 0x3b, 0xe5, 0x08, 0x0d, 0x5b, 0x8b, 0x96, 0x52, 0x5b, 0xff, 0xa6, 0x7d, 0xe5, 0xaf, 0x31, 0xd5, 0x03, 0x85, 0xfb, 0xbf, 0x1a, 0x87, 0xc2, 0x1b, 0xf0, 0xe0, 0xa1, 0xfd, 0xff, 0x69, 0xec, 0x32, 0xc7, 0xb7, 0x10, 0x3e, 0x0b, 0x8e, 0xe6, 0xc8, 0x44, 0x24, 0x5e, 0x0f, 0xc8, 0x4b, 0x9f, 0x89, 0xfc, 0xce, 0x62, 0x96, 0x6c, 0xea, 0x68, 0xe2, 0x87, 0x1d, 0x3b, 0x82, 0xe8, 0xdf, 0x42, 0x4c, 0x76, 0x30, 0x9f, 0xc8, 0x8d),
+            //   cipher_text: vec!(0xdd, 0x13, 0xfb, 0xf2, 0x2c, 0x8d, 0x18, 0x35, 0x4d, 0x77, 0x4b, 0xcd, 0x18, 0xf7, 0xeb, 0x81, 0x4e, 0x9b, 0x52, 0x8e, 0x9e, 0x42, 0x4a, 0xbc, 0x4e, 0x3f, 0x24, 0x63, 0x19, 0x5e, 0x80, 0x18, 0x57, 0x65, 0x65, 0xd1, 0x6a, 0xb4, 0x88, 0x45, 0xd1, 0x1c, 0x92, 0x77, 0xf2, 0x86, 0x5e, 0xbb, 0x4d, 0xc4, 0x12, 0xfd, 0x5b, 0x27, 0x07, 0x8f, 0x83, 0x25, 0xea, 0xdf, 0x97, 0x1e, 0x69, 0x44, 0xc6, 0x65, 0x42, 0xe3, 0x4d, 0x9d, 0xda, 0x97, 0x1e, 0x2a, 0xba, 0x70, 0xdb, 0xd3, 0xe9, 0x4a, 0x1e, 0x63, 0x8d, 0x52, 0x14, 0x77, 0xa0, 0x27, 0x77, 0x6b, 0x52, 0xac, 0xf9, 0x05, 0x20, 0xca, 0x22, 0x9e, 0xbc, 0x76, 0x0b, 0x73, 0x12, 0x88, 0x79, 0x47, 0x5d, 0x1c, 0xbe, 0x1f, 0x70, 0xfc, 0x59, 0x8b, 0x54, 0x9c, 0xd9, 0x2d, 0x8a, 0x9a, 0xc6, 0x83, 0x3e, 0x50, 0x0c, 0x13, 0x8c, 0x56, 0x47, 0x4d, 0xb8, 0x4c, 0xb3, 0xd7, 0x0b, 0x7a, 0xa4, 0xf2, 0x93, 0xa4, 0xc2, 0xb4, 0xd8, 0x18, 0xb0, 0xff, 0x9f, 0xd8, 0x59, 0x18, 0xdc, 0x59, 0x0a, 0x12, 0xa8, 0xc0, 0xe3, 0x75, 0xc4, 0xd9, 0x8b, 0x7f, 0xc8, 0x75, 0x96, 0x54, 0x7e, 0xb9, 0x60, 0x67, 0x6a, 0xad, 0x55, 0x59, 0x83, 0x45, 0x88, 0xf0, 0x0f, 0x25, 0x1a, 0x9d, 0x53, 0xf9, 0x5c, 0x47, 0xaf, 0x4d, 0xf3, 0xc4, 0x29, 0x91, 0x75, 0xd5, 0x21, 0x17, 0x79, 0xc1, 0x48, 0xcf, 0xc9, 0x88, 0xa5, 0xe9, 0xd9),
+            //   tag: vec!(0x47, 0x66, 0x16, 0xea, 0x15, 0x19, 0x0c, 0x10, 0x93, 0xfd, 0xc4, 0xa0, 0x87, 0x64, 0x3c, 0xae)
+            // },
+            // TestVector{
+            //   key: [0xf9, 0xf6, 0xeb, 0x9a, 0xd7, 0x36, 0xa8, 0xf6, 0x6e, 0x74, 0x59, 0xfe, 0xf5, 0xec, 0x28, 0x90, 0x18, 0x8d, 0xc2, 0x6b, 0xaf, 0x34, 0xa9, 0x5f, 0x6f, 0x03, 0x84, 0xe7, 0x9f, 0x5c, 0x65, 0x59],
+            //   nonce: [0x78, 0x58, 0xdf, 0xc0, 0x84, 0xfe, 0x4b, 0x0f],
+            //   plain_text: vec!(0xa6, 0x44, 0xca, 0x6e, 0x7c, 0xc0, 0x76, 0xe8, 0x7e, 0xb2, 0x92, 0x9f, 0xd2, 0x57, 0x69, 0x3f, 0xce, 0x0f, 0x6f, 0xb6, 0x4f, 0xd6, 0x32, 0xf7, 0xf0, 0x7c, 0x64, 0x8e, 0xbd, 0x03, 0x69, 0x6c, 0x8e, 0x26, 0x2e, 0x6a, 0x81, 0x0d, 0x7b, 0x7c, 0x4e, 0x5e, 0xef, 0x8c, 0x65, 0xb5, 0x32, 0x3c, 0x99, 0xdb, 0xba, 0x50, 0xa7, 0x0b, 0x4a, 0x9e, 0x5c, 0x2a, 0x9e, 0x73, 0x15, 0x97, 0x3c, 0xd6, 0x7f, 0x35, 0xd8, 0x05, 0x2c, 0xe9, 0xa8, 0x5a, 0x20, 0x64, 0x16, 0xdd, 0x30, 0x31, 0x92, 0x9f, 0x4f, 0x92, 0x9b, 0x13, 0xd0, 0xa5, 0xfb, 0x10, 0xcb, 0x73, 0xc6, 0x5f, 0x6c, 0x0a, 0xce, 0x01, 0x9d, 0xa1, 0x46, 0xb5, 0x1c, 0x52, 0x74, 0xa0, 0x99, 0xf4, 0x4e, 0x36, 0x69, 0xd2, 0x6a, 0xdd, 0x6f, 0x2f, 0xf0, 0x81, 0xe8, 0x86, 0xf3, 0xcf, 0x95, 0x2f, 0xe0, 0xdb, 0xbe, 0x6b, 0x05, 0x34, 0xc2, 0x3e, 0x30, 0x75, 0x74, 0xbd, 0x35, 0xfb, 0xd6, 0x57, 0xf5, 0xfc, 0xbd, 0x5d, 0xc1, 0x9f, 0xb3, 0x82, 0xa1, 0xdc, 0x0a, 0x2d, 0xc8, 0x28, 0x5a, 0x03, 0x50, 0xf7, 0x15, 0x54, 0xe4, 0xc6, 0x01, 0x49, 0x77, 0x49, 0xe3, 0x55, 0x67, 0xdd, 0x4a, 0x27, 0x3c, 0xdd, 0xc9, 0xa4, 0x8c, 0xe5, 0x3a, 0x5f, 0x1d, 0x29, 0x7f, 0xd8, 0xba, 0xf8, 0xd1, 0xb9, 0xfe, 0xb3, 0x5d, 0x91, 0x51, 0x11, 0x43, 0x45, 0xab, 0xad, 0xa4, 0xd9, 0x0d, 0xb9, 0x47, 0xbb, 0x9a, 0x74, 0x3c, 0x17, 0x5f, 0x56, 0x53, 0xd1),
+            //   aad: vec!(0x20, 0x48, 0xd1, 0xc2, 0xdd, 0xfb, 0x5e, 0xc3, 0x85, 0xb2, 0x01, 0x83, 0x2c, 0x7a, 0x99, 0x3f, 0x22, 0x9b, 0xa7, 0x2e, 0xc1, 0x6d, 0x6e, 0xbf, 0x72, 0x3e, 0xf0, 0xc5, 0x03, 0x2b, 0x99, 0x66, 0x20, 0x9a, 0x9e, 0x8a, 0x63, 0x15, 0x1b, 0x40, 0x41, 0x2e, 0x96, 0xb8, 0x2f, 0x86, 0x72, 0x8e, 0xa6, 0x58, 0x8c, 0x7e, 0x8e, 0x11, 0xac, 0x71, 0xcc, 0x8e, 0xab, 0xab, 0x8c, 0x4b, 0x54, 0xde, 0x86, 0x66, 0x58, 0xd9, 0xc5, 0x01, 0x1d, 0xef, 0x61, 0xfb, 0x3d, 0xbe, 0x4e, 0x63, 0x01, 0x58, 0xa4, 0x5e, 0xa4, 0x1a, 0x2e, 0xd5, 0x5e, 0xbd, 0x1e, 0xfb, 0x1a, 0xbe, 0xda, 0x76, 0x37, 0xde, 0x6f, 0xa5, 0xfd, 0x2f, 0x15, 0x1c, 0x6d, 0x2f, 0x38, 0x5b, 0xf6, 0xcd, 0x00, 0x2c, 0xa8, 0xb4, 0xa2, 0x89, 0x6e, 0x0d, 0x65, 0x94, 0x4e, 0xe9, 0x13, 0xe3, 0xc7, 0x84, 0x66, 0x9d, 0xd2, 0x01, 0xb1, 0x98, 0x5e, 0xf3, 0x57, 0x7f, 0x7f, 0x12, 0x3a, 0x5f, 0x9b, 0xcf, 0xfa, 0x17, 0x6c, 0x8f, 0x55, 0x7c, 0x4f, 0x72, 0x91, 0x33, 0xca, 0xc5, 0x18, 0x64, 0x2f, 0x27, 0xd9, 0xb2, 0x2c, 0xa9, 0xb9, 0x7f, 0xaa, 0xaf, 0xe5, 0xb6, 0x69, 0xa1, 0x0b, 0x79, 0xac, 0xe4, 0xa7, 0xd5, 0x72, 0x7d, 0xf1, 0x46, 0xc7, 0x7c, 0xe6, 0x81, 0x35, 0x7d, 0x69, 0xf9, 0xc2, 0xd6, 0x5b, 0x44, 0x01, 0xbd, 0x73, 0xcd, 0x11, 0x33, 0x87, 0xe3, 0xb3, 0xa0, 0x5d, 0x89, 0x7a, 0xda, 0xd7, 0xa2, 0x4c, 0x48, 0x5e, 0x7b),
+            //   cipher_text: vec!(0x41, 0x46, 0xfa, 0xff, 0xd7, 0x31, 0x3f, 0x5d, 0x9f, 0x62, 0x53, 0x70, 0xd2, 0x04, 0x13, 0xcc, 0x62, 0xab, 0x65, 0xf4, 0xac, 0xfa, 0x3c, 0x7e, 0xe1, 0x12, 0x5b, 0x93, 0x7d, 0xd7, 0xa3, 0x9f, 0x63, 0x8f, 0xc4, 0x6c, 0x8e, 0xd0, 0x04, 0xfb, 0x52, 0x56, 0x98, 0xde, 0x5d, 0x86, 0x20, 0xec, 0x15, 0x34, 0x35, 0x57, 0x18, 0x17, 0xc3, 0xde, 0x25, 0x7b, 0x0d, 0x0e, 0x64, 0x8e, 0xbb, 0x92, 0x94, 0x0c, 0x86, 0xa9, 0x82, 0x62, 0xd5, 0x4e, 0x76, 0x4f, 0x28, 0xcb, 0xdd, 0x4f, 0x7d, 0x9b, 0xea, 0x97, 0x02, 0x91, 0xf2, 0x11, 0x04, 0x14, 0xf6, 0x20, 0x64, 0xd7, 0x22, 0x9c, 0x63, 0x32, 0x23, 0x6c, 0x50, 0x7b, 0x3d, 0xac, 0x74, 0x2e, 0x65, 0x1d, 0x85, 0xa2, 0xa2, 0x2f, 0xb2, 0x43, 0xc0, 0xcc, 0x7c, 0xc2, 0xd0, 0x16, 0xe5, 0xbe, 0xa3, 0x8f, 0x33, 0xf9, 0xa9, 0xce, 0x04, 0x89, 0x44, 0xa5, 0xfe, 0x8b, 0x07, 0x8d, 0x71, 0xd2, 0x31, 0x68, 0xe1, 0x2d, 0xfe, 0x5a, 0x0f, 0x0b, 0x82, 0x97, 0x71, 0xed, 0xc7, 0x07, 0x3f, 0xb9, 0x60, 0x32, 0xb7, 0xbe, 0x47, 0x13, 0x37, 0xa3, 0x7a, 0xca, 0x0c, 0xf7, 0xc0, 0xcd, 0xd5, 0x43, 0xee, 0xd6, 0x86, 0xcd, 0x34, 0x93, 0x47, 0x17, 0xfd, 0x79, 0xa3, 0xf1, 0x84, 0x92, 0xee, 0xf7, 0x2f, 0x9f, 0x45, 0x0b, 0x88, 0x0a, 0xa7, 0xe2, 0xe1, 0xb6, 0x5e, 0x3b, 0x04, 0xc2, 0x2e, 0x72, 0x30, 0x13, 0x38, 0xb4, 0x3a, 0xa3, 0x2c, 0xee, 0xc2, 0xe6),
+            //   tag: vec!(0x10, 0xff, 0xaf, 0x2b, 0xe3, 0x16, 0x67, 0x6d, 0xa0, 0x2d, 0x74, 0x73, 0xa9, 0xdf, 0x87, 0xb9)
+            // },
+            // TestVector{
+            //   key: [0x29, 0xb1, 0x96, 0x36, 0xcd, 0xd3, 0x25, 0x07, 0xfd, 0x98, 0xec, 0x4e, 0xe2, 0x6c, 0xaa, 0xb1, 0xa9, 0x17, 0x64, 0x6f, 0xb8, 0xf0, 0x5b, 0x0d, 0xc0, 0x17, 0x28, 0xa9, 0xf4, 0xa1, 0x27, 0xf0],
+            //   nonce: [0x06, 0x69, 0x9d, 0x24, 0x59, 0x16, 0x68, 0x6d],
+            //   plain_text: vec!(0x5f, 0xdf, 0x91, 0x3a, 0xce, 0xab, 0x1d, 0x6d, 0xba, 0xf7, 0xd9, 0xa2, 0x93, 0x52, 0xfa, 0x8a, 0x3e, 0xb2, 0x27, 0x18, 0x04, 0x3a, 0x79, 0xcf, 0xfa, 0x2f, 0xe8, 0xc3, 0x5c, 0x82, 0x0a, 0xec, 0x7c, 0x07, 0x64, 0x4b, 0x87, 0x85, 0xdc, 0xf7, 0xa4, 0x33, 0xb4, 0x18, 0x9a, 0xbb, 0x25, 0x7f, 0xb1, 0x2b, 0x06, 0xfa, 0xe0, 0x66, 0x26, 0x41, 0x01, 0x1a, 0x06, 0x98, 0x73, 0xc3, 0xe3, 0xc5, 0xcc, 0xc7, 0x8e, 0x73, 0x58, 0x18, 0x4a, 0x62, 0xc2, 0x00, 0x5c, 0x44, 0xb8, 0xa9, 0x22, 0x54, 0x95, 0x8e, 0xb5, 0xff, 0x46, 0x0d, 0x73, 0xcd, 0x80, 0x28, 0x4d, 0x6d, 0xab, 0xa2, 0x2c, 0x3f, 0xab, 0xa0, 0x46, 0xc5, 0x42, 0x6f, 0xe8, 0xb7, 0xca, 0xce, 0xc6, 0x4b, 0x23, 0x5a, 0x8f, 0x8d, 0x3e, 0x26, 0x41, 0xe5, 0xbc, 0x37, 0x88, 0x30, 0x59, 0x4b, 0xcf, 0xb2, 0x7c, 0x17, 0x7a, 0xea, 0x74, 0x59, 0x51, 0xee, 0x57, 0x80, 0xa6, 0x37, 0x05, 0x72, 0x7e, 0xf4, 0x2c, 0x4a, 0xd3, 0xab, 0xf5, 0x56, 0xd8, 0x8e, 0x38, 0x30, 0xf3, 0xdb, 0x6b, 0x09, 0xe9, 0x3e, 0xdd, 0x09, 0x48, 0x5c, 0xbf, 0x90, 0x7f, 0x79, 0xde, 0x61, 0xf8, 0xdc, 0x5c, 0xb5, 0xfb, 0x76, 0x65, 0xff, 0xa0, 0xef, 0x53, 0xcb, 0x48, 0x70, 0x2f, 0x6a, 0x81, 0xd8, 0xad, 0x42, 0x1c, 0xef, 0x20, 0xc1, 0xdb, 0xdf, 0x40, 0x2b, 0x8f, 0xaf, 0xed, 0x56, 0xa5, 0x36, 0x1b, 0x2f, 0x93, 0xf9, 0x14, 0xa2, 0x38, 0x0f, 0xdd, 0x05, 0x57, 0xfa, 0xf1, 0xf4, 0xde),
+            //   aad: vec!(0x39, 0x11, 0x6c, 0x49, 0xcc, 0x13, 0xad, 0xb0, 0x65, 0xb9, 0x2c, 0xb7, 0x63, 0x5f, 0x73, 0xd5, 0xf6, 0xbf, 0x6b, 0x5c, 0xcb, 0xf7, 0x2a, 0x3f, 0x65, 0xa5, 0xdf, 0x6b, 0xd4, 0xa6, 0x61, 0x10, 0x50, 0x15, 0x35, 0x8d, 0x9e, 0x69, 0xf4, 0x2e, 0x98, 0xae, 0xd7, 0x95, 0xe8, 0x16, 0x12, 0x82, 0xbc, 0x11, 0x30, 0x58, 0xb7, 0xef, 0x3b, 0x9e, 0x23, 0xfc, 0xd8, 0xee, 0xab, 0x34, 0xa3, 0x92, 0xe0, 0x3f, 0x4d, 0x63, 0x29, 0xc1, 0x12, 0xcb, 0x96, 0x83, 0x85, 0xec, 0x52, 0xa7, 0xaf, 0xc9, 0x8b, 0xb8, 0x69, 0x57, 0x85, 0xaf, 0x6b, 0x27, 0xb7, 0x00, 0x97, 0x3c, 0xc9, 0x52, 0x63, 0x0b, 0x72, 0x47, 0xce, 0x22, 0x6b, 0x4f, 0xbb, 0x99, 0xb8, 0xa4, 0x86, 0x37, 0x0b, 0xf6, 0x34, 0x5d, 0x45, 0x16, 0xc5, 0x2c, 0x64, 0xe3, 0x3f, 0x40, 0x7c, 0x4f, 0x2d, 0x1b, 0xa9, 0x05, 0x45, 0xc8, 0x87, 0x32, 0xd9, 0x8b, 0xbd, 0x97, 0x97, 0x2a, 0xc5, 0xe9, 0x4c, 0x69, 0x46, 0x24, 0xa9, 0xb3, 0x78, 0x2b, 0x00, 0x99, 0x82, 0x46, 0x51, 0xcb, 0x75, 0x67, 0x91, 0x4d, 0x25, 0xb3, 0xe1, 0x31, 0x81, 0xa7, 0x91, 0xdb, 0xcd, 0x40, 0xe7, 0x6e, 0x83, 0x6b, 0x33, 0x50, 0xd3, 0x10, 0xa5, 0x21, 0x51, 0xbf, 0x83, 0x5d, 0x3c, 0x35, 0x7c, 0x98, 0x71, 0x48, 0x2c, 0x29, 0x28, 0xe8, 0x40, 0x4c, 0x6e, 0x53, 0x34, 0x06, 0xd4, 0xd6, 0xfa, 0x8f, 0x63, 0x36, 0x6f, 0x2c, 0x4e, 0xd8, 0x28, 0x14, 0x1f, 0x1f, 0xf0, 0x0f, 0x01, 0xa5, 0x36),
+            //   cipher_text: vec!(0x01, 0xe2, 0x37, 0x22, 0x0b, 0x61, 0x90, 0x54, 0xa1, 0xf3, 0x67, 0x09, 0x28, 0xfe, 0x67, 0xd4, 0x04, 0x84, 0xb5, 0xaf, 0x40, 0xfb, 0xd0, 0x4d, 0x03, 0x25, 0x00, 0xaa, 0xc5, 0xac, 0xaa, 0x3b, 0x45, 0x84, 0xdd, 0x99, 0xa5, 0x8c, 0x39, 0x06, 0x27, 0x63, 0x6a, 0x50, 0xde, 0x5d, 0x74, 0x4f, 0x76, 0xa5, 0x6a, 0x33, 0x20, 0x5f, 0x9e, 0x3b, 0x00, 0xe1, 0x61, 0x62, 0xeb, 0x47, 0xff, 0x33, 0x33, 0xe1, 0xe2, 0x08, 0xca, 0x20, 0x0f, 0x1a, 0x53, 0x38, 0xa8, 0x6e, 0x17, 0xbd, 0x92, 0xdd, 0x2d, 0x16, 0xaf, 0x8b, 0xb0, 0x22, 0xa7, 0xdc, 0x05, 0xb9, 0x23, 0xd0, 0x19, 0xe0, 0x52, 0x47, 0xf1, 0xa0, 0xd0, 0xb4, 0xbf, 0xcf, 0xce, 0x58, 0xdd, 0x6d, 0x83, 0x83, 0x07, 0x05, 0x70, 0x76, 0x76, 0xd5, 0x57, 0x39, 0xab, 0xee, 0x89, 0xfc, 0xd5, 0xcb, 0x94, 0xb8, 0xfd, 0xe0, 0x06, 0xa5, 0xda, 0x02, 0xdf, 0x64, 0xb0, 0x0a, 0x46, 0x7f, 0x45, 0x97, 0x0b, 0x5c, 0xa4, 0x40, 0xf2, 0x23, 0x19, 0xb9, 0x73, 0x5a, 0x55, 0xd4, 0x54, 0xb9, 0xfb, 0xa0, 0x58, 0x8f, 0xef, 0x0c, 0x59, 0xd3, 0xd8, 0x38, 0x23, 0xeb, 0xa6, 0xe0, 0x60, 0x1a, 0x96, 0xe1, 0x02, 0x33, 0x82, 0x6c, 0x5a, 0xde, 0xea, 0x6b, 0x2a, 0x51, 0xd3, 0x86, 0xa0, 0x7a, 0x9e, 0x04, 0x7a, 0xd4, 0x05, 0xb2, 0x3d, 0x4c, 0x3d, 0x89, 0xf3, 0x0c, 0x31, 0xe3, 0x19, 0x9f, 0x0c, 0x8f, 0x92, 0x7b, 0xfa, 0xc4, 0x3c, 0xee, 0xa1, 0xf9, 0x69, 0xde, 0x0a, 0x8c, 0x0f),
+            //   tag: vec!(0x09, 0x2f, 0x9f, 0x3c, 0x5d, 0x4f, 0x25, 0x70, 0xc9, 0x94, 0x6c, 0x87, 0x96, 0x7f, 0x45, 0x79)
+            // },
+            // TestVector{
+            //   key: [0xba, 0xe0, 0x6b, 0x9b, 0x54, 0x56, 0x70, 0x75, 0x51, 0xc7, 0xb0, 0xe2, 0x07, 0xaa, 0xe0, 0x2a, 0x19, 0xb4, 0x84, 0x8a, 0xd8, 0xca, 0x4c, 0xe4, 0x07, 0x05, 0xbf, 0x8c, 0x85, 0x6a, 0x6e, 0x52],
+            //   nonce: [0x9c, 0x27, 0x06, 0x5c, 0x3e, 0xf2, 0xd5, 0x22],
+            //   plain_text: vec!(0x50, 0xcd, 0xd8, 0x81, 0x37, 0xff, 0x42, 0x8a, 0x88, 0xe8, 0x7b, 0x58, 0x45, 0xbe, 0x49, 0x24, 0xf6, 0x38, 0x75, 0x37, 0xbb, 0x5c, 0x0b, 0x65, 0x4c, 0x80, 0x10, 0x7a, 0xb5, 0x69, 0x8d, 0xb7, 0x5b, 0x2e, 0x13, 0x18, 0x48, 0xe7, 0xae, 0xc1, 0x56, 0xd3, 0x1a, 0xed, 0x07, 0x66, 0xd3, 0x1c, 0x37, 0x9f, 0xec, 0xe4, 0x09, 0x5d, 0x38, 0x26, 0x4c, 0x6d, 0x59, 0x45, 0x97, 0x4d, 0x25, 0xf7, 0x29, 0xc3, 0xb0, 0xba, 0x11, 0xea, 0x85, 0x3e, 0x9c, 0xeb, 0xdb, 0x6f, 0x03, 0xbb, 0x67, 0x0f, 0xce, 0x08, 0xad, 0xff, 0x74, 0xd0, 0xa8, 0xf0, 0x2d, 0x63, 0x3f, 0xb3, 0x4e, 0x0f, 0xb7, 0x33, 0x7a, 0x8e, 0x66, 0xe1, 0xc1, 0x20, 0x84, 0xd9, 0x14, 0xfb, 0x61, 0x73, 0xb8, 0x10, 0x56, 0x84, 0xdb, 0x82, 0x27, 0x52, 0xc6, 0x75, 0x1a, 0x37, 0x2b, 0xb1, 0x66, 0x90, 0x28, 0x4d, 0x66, 0x1b, 0x8b, 0x8b, 0xc6, 0xa6, 0xdf, 0xbd, 0xdf, 0x45, 0xeb, 0xc2, 0x21, 0x95, 0x96, 0xf9, 0xf2, 0xf8, 0x78, 0xc1, 0x18, 0xdf, 0x69, 0x03, 0x0d, 0xe3, 0x8b, 0x4d, 0x99, 0xdd, 0xe4, 0x3b, 0x9b, 0x9e, 0x20, 0xa3, 0xda, 0xb6, 0x91, 0x64, 0x5d, 0xd5, 0x18, 0x34, 0x2f, 0x49, 0xb0, 0x6a, 0x0f, 0xe0, 0xa3, 0x97, 0xad, 0xf2, 0x61, 0xe9, 0x9f, 0x07, 0xaf, 0x5b, 0x0b, 0x37, 0x98, 0xb1, 0x02, 0x2b, 0xa0, 0x93, 0x9c, 0x42, 0xa5, 0x4d, 0x3b, 0x93, 0x64, 0x1c, 0xff, 0xa3, 0xc2, 0xe1, 0x74, 0xbc, 0xe9, 0xab, 0x7a, 0xd7, 0xe7, 0xc7, 0x92, 0x43, 0x08, 0xd1, 0xa7, 0x7a),
+            //   aad: vec!(0x5d, 0x55, 0x90, 0xdb, 0x1b, 0xd3, 0x16, 0xeb, 0x7a, 0x0e, 0x30, 0xe4, 0xc7, 0xa6, 0xdf, 0xdb, 0xef, 0x9d, 0x32, 0x87, 0xfd, 0xb8, 0xd8, 0x24, 0x38, 0x95, 0x99, 0xc3, 0xc2, 0xee, 0x26, 0x2b, 0x21, 0x92, 0xeb, 0x5b, 0x97, 0x08, 0xe6, 0x6e, 0x22, 0xdb, 0xc7, 0xec, 0xa8, 0x3f, 0xa1, 0xa9, 0x95, 0xda, 0x3c, 0xe6, 0x4c, 0x86, 0xfe, 0x5a, 0xa0, 0x8b, 0x82, 0x6d, 0x47, 0x6d, 0xc4, 0x39, 0x49, 0x7e, 0x2d, 0x12, 0xe2, 0x70, 0x2c, 0x63, 0xc8, 0xd2, 0x7a, 0xa7, 0xf0, 0x9f, 0xed, 0xee, 0x81, 0x6d, 0xc8, 0xbf, 0xfe, 0x13, 0x51, 0xd5, 0x32, 0x71, 0xa3, 0x4d, 0x42, 0x92, 0xb6, 0x13, 0xb7, 0xef, 0xce, 0xdb, 0x7e, 0x3c, 0xf3, 0xe6, 0xad, 0x38, 0x9e, 0xef, 0x12, 0x47, 0x1e, 0x9e, 0x20, 0xe3, 0x8e, 0x7a, 0xe2, 0x2a, 0x32, 0x3a, 0xbb, 0xad, 0xfe, 0x8f, 0x2e, 0x84, 0x27, 0x1b, 0xff, 0xb1, 0x81, 0x9f, 0xeb, 0x4f, 0x77, 0xb8, 0x28, 0x43, 0xcb, 0x87, 0x57, 0xcf, 0xae, 0x29, 0x36, 0x31, 0xbc, 0x6d, 0x39, 0x66, 0x91, 0x07, 0xe7, 0x01, 0x5c, 0x85, 0xd7, 0x34, 0x3f, 0xfa, 0x6f, 0xc1, 0xbb, 0xe6, 0xf5, 0xab, 0x4d, 0xe3, 0x0c, 0xd7, 0x52, 0xa2, 0x81, 0xe0, 0x30, 0x61, 0xea, 0x89, 0xde, 0x2a, 0x3f, 0x5e, 0x90, 0xe2, 0x0d, 0xa2, 0x2f, 0xd6, 0xe8, 0x52, 0x5c, 0x10, 0x07, 0x38, 0x66, 0x7f, 0x42, 0x21, 0x2b, 0x2c, 0xf4, 0x5f, 0xcb, 0x23, 0xbb, 0xb5, 0x4b, 0x21, 0xc1, 0x17, 0x48, 0x4b, 0x22, 0xc6, 0xe5, 0x14, 0x68, 0x53, 0x14, 0xdf),
+            //   cipher_text: vec!(0x66, 0xb7, 0xf6, 0x9a, 0xc4, 0x9f, 0xab, 0x4e, 0x59, 0x75, 0xae, 0xb6, 0xfa, 0x92, 0x87, 0xd8, 0xea, 0xc0, 0x2a, 0xc3, 0x12, 0xc4, 0xde, 0x78, 0xf7, 0x7f, 0x59, 0xda, 0x16, 0xcb, 0xcf, 0x87, 0x27, 0x4e, 0x66, 0x80, 0x1c, 0x4b, 0x86, 0x2c, 0x33, 0xea, 0x79, 0xcd, 0xc7, 0x65, 0x28, 0x86, 0x2b, 0xb2, 0x95, 0x6c, 0x06, 0xdb, 0x8b, 0x8a, 0xcf, 0xac, 0x47, 0x94, 0xeb, 0xf3, 0x9e, 0x35, 0xac, 0x03, 0xcc, 0x73, 0xa4, 0x35, 0x1a, 0x4f, 0xf7, 0x62, 0xf6, 0x81, 0xa4, 0x8d, 0x6f, 0x25, 0xca, 0xd3, 0x6e, 0x28, 0x14, 0xc9, 0xb5, 0xc4, 0x0b, 0x9a, 0xe9, 0x25, 0x09, 0xe5, 0x84, 0x29, 0x10, 0x68, 0x47, 0x78, 0x94, 0x54, 0xd3, 0x76, 0x83, 0x69, 0x36, 0xbe, 0xbc, 0x7a, 0x80, 0xe6, 0xc6, 0x6e, 0x7a, 0xa5, 0x29, 0x36, 0xd6, 0xb3, 0x61, 0x37, 0x8a, 0x41, 0xf8, 0x49, 0xad, 0x4e, 0x48, 0xf9, 0xee, 0x2d, 0x3e, 0x92, 0x21, 0x7a, 0x90, 0x8f, 0xa8, 0xeb, 0x35, 0x73, 0x6a, 0xc8, 0xad, 0xa7, 0xd3, 0x2a, 0xe0, 0x53, 0x91, 0xf2, 0xd8, 0x07, 0xbe, 0x35, 0x12, 0x54, 0x3c, 0x36, 0x13, 0x8a, 0x5f, 0xe6, 0x60, 0xdd, 0x4c, 0xd4, 0xcd, 0x18, 0x4b, 0xb4, 0x3b, 0x6b, 0xa6, 0xbc, 0x0b, 0xae, 0x63, 0x4e, 0x2f, 0xa9, 0x66, 0x93, 0x04, 0xcd, 0x51, 0x0e, 0xd5, 0x10, 0x3f, 0x63, 0x00, 0x68, 0xff, 0x76, 0xd3, 0x37, 0x57, 0x38, 0xde, 0x60, 0xa3, 0x81, 0x84, 0x2b, 0x42, 0x14, 0x77, 0xe2, 0x5a, 0x49, 0x0c, 0xdd, 0x68, 0x94, 0xb2, 0x70, 0x41, 0x25),
+            //   tag: vec!(0xc9, 0x99, 0x8a, 0x67, 0x7d, 0xfb, 0x0e, 0x91, 0x92, 0x4a, 0xec, 0x9d, 0xe0, 0xaf, 0xd5, 0x85)
+            // },
+            // TestVector{
+            //   key: [0x2c, 0xb3, 0x74, 0xcb, 0x04, 0x8c, 0x16, 0x8f, 0x2e, 0x43, 0x59, 0x7f, 0x02, 0x8d, 0x9e, 0x73, 0xca, 0xde, 0x1b, 0x45, 0x82, 0x84, 0xff, 0xc2, 0x60, 0xd4, 0xfc, 0x6b, 0x90, 0x11, 0xc4, 0x14],
+            //   nonce: [0x9f, 0xb9, 0x09, 0x16, 0x9b, 0xc9, 0xf4, 0xe9],
+            //   plain_text: vec!(0x39, 0xeb, 0x92, 0x94, 0x82, 0x78, 0x4b, 0x46, 0x35, 0x46, 0xf5, 0xd8, 0x4f, 0x80, 0x51, 0x0f, 0x20, 0x19, 0x92, 0x3d, 0x46, 0x5b, 0x99, 0xd1, 0x94, 0x24, 0x6d, 0x68, 0xc7, 0xae, 0x34, 0x3f, 0x91, 0x97, 0x1d, 0x8f, 0x70, 0x59, 0xce, 0xbb, 0x86, 0xaa, 0x5d, 0xd0, 0x99, 0x28, 0x9a, 0xa6, 0x48, 0x24, 0x8b, 0x8c, 0x5c, 0xa0, 0x4e, 0x66, 0xac, 0x5e, 0x9b, 0xf0, 0x67, 0x76, 0xe3, 0x88, 0x34, 0x95, 0x39, 0x76, 0x18, 0xa0, 0x22, 0x7f, 0x03, 0x56, 0x66, 0x80, 0x6e, 0x63, 0x68, 0x36, 0xb4, 0x7d, 0x3d, 0x2d, 0x25, 0x5a, 0x49, 0xdb, 0x79, 0x86, 0x6c, 0xf0, 0x0d, 0x9d, 0xda, 0xbd, 0xa2, 0x59, 0xc4, 0xf9, 0x68, 0xa1, 0xe0, 0x1e, 0x65, 0x1c, 0x78, 0x11, 0xce, 0xbb, 0xee, 0x2e, 0xe7, 0x18, 0x03, 0xea, 0x1d, 0x9d, 0x23, 0x48, 0x7e, 0xb2, 0x21, 0xf2, 0xd9, 0x55, 0x57, 0x56, 0x80, 0x0a, 0xba, 0x5e, 0x6a, 0xbb, 0xef, 0xd6, 0xfb, 0x72, 0xb3, 0x15, 0x1c, 0xc9, 0x9c, 0xed, 0x59, 0x9c, 0xd8, 0x6d, 0xf2, 0xa9, 0xb1, 0xce, 0x94, 0xf8, 0x9f, 0x34, 0x7e, 0xeb, 0x12, 0x4d, 0x9e, 0x7f, 0x0d, 0x9c, 0xc4, 0x8d, 0x3d, 0xed, 0xd8, 0x19, 0xe6, 0xd3, 0xdb, 0xac, 0x57, 0xec, 0xee, 0x19, 0x95, 0x47, 0xb2, 0x66, 0x11, 0x6a, 0x20, 0x35, 0xc9, 0xac, 0xc4, 0xc8, 0xca, 0x32, 0x71, 0xac, 0x74, 0x95, 0x23, 0x72, 0x89, 0x7c, 0x4a, 0x5f, 0x2c, 0xb8, 0x4e, 0x2d, 0x81, 0x81, 0x7f, 0xec, 0x9d, 0x67, 0x74, 0xf6, 0xd8, 0xa5, 0xb2, 0x02, 0x16, 0x84, 0x13, 0x2d, 0xb4, 0xfc, 0xa3),
+            //   aad: vec!(0x0c, 0x7b, 0xd4, 0xf3, 0xa3, 0x0e, 0xe9, 0x44, 0xcc, 0xf9, 0x48, 0x91, 0x81, 0xe6, 0x91, 0x16, 0x84, 0xdc, 0xff, 0xad, 0x45, 0x93, 0xa9, 0xb6, 0x5a, 0x67, 0xdf, 0xc8, 0x07, 0x18, 0xc6, 0x9b, 0x35, 0x89, 0x7d, 0x01, 0x28, 0x10, 0x16, 0xb7, 0x73, 0x1e, 0x12, 0xc1, 0x5c, 0xad, 0x84, 0x82, 0xe7, 0x94, 0x58, 0xe0, 0x8a, 0x75, 0x56, 0x22, 0xe3, 0xf3, 0xf2, 0x2a, 0x23, 0xef, 0x6c, 0x84, 0x87, 0xa3, 0x6a, 0xd1, 0x77, 0x1b, 0xa0, 0x6c, 0x64, 0x1f, 0x06, 0xf8, 0x5d, 0xe0, 0xdb, 0x37, 0x76, 0xcc, 0x6d, 0xf0, 0x6a, 0xd8, 0xfe, 0x3b, 0x4d, 0x60, 0xd5, 0x85, 0x08, 0xde, 0x94, 0x30, 0x83, 0xf1, 0x7c, 0xbb, 0x9d, 0xc0, 0xd3, 0x90, 0xac, 0x94, 0xd8, 0x42, 0x9e, 0x8c, 0x6f, 0xcf, 0xe0, 0x63, 0xf4, 0x24, 0xfb, 0xde, 0x0f, 0x62, 0xf6, 0xa7, 0xf9, 0x1a, 0x62, 0x6d, 0x19, 0x5d, 0xc4, 0x98, 0xa6, 0xe6, 0x9b, 0xd9, 0x31, 0x09, 0xc4, 0xe9, 0xba, 0x13, 0xe7, 0x33, 0x0a, 0xba, 0x45, 0x6d, 0x71, 0x0a, 0x4b, 0x0c, 0xc2, 0x79, 0xd4, 0x04, 0x56, 0x60, 0x40, 0x6e, 0x26, 0xd6, 0x1d, 0xff, 0x70, 0xd4, 0xa3, 0x3c, 0x4f, 0x10, 0x52, 0x86, 0x9f, 0x92, 0x48, 0x02, 0x4e, 0x7a, 0x0f, 0x85, 0xf1, 0xef, 0xfb, 0x32, 0xf6, 0xf7, 0xcc, 0xb1, 0xf8, 0x60, 0xf3, 0xef, 0x04, 0xe8, 0xf7, 0xb2, 0x90, 0x96, 0xe6, 0xbc, 0xf9, 0xd4, 0xb3, 0xe0, 0xce, 0x70, 0x3e, 0x9b, 0xf2, 0x28, 0xfd, 0xf5, 0x15, 0xc2, 0xff, 0x9c, 0xba, 0xbd, 0x16, 0x98, 0x7b, 0xe0, 0xf9, 0xba, 0xbd, 0x3d, 0x8a),
+            //   cipher_text: vec!(0x91, 0xdd, 0xad, 0xb8, 0x6b, 0x7e, 0xbe, 0xf7, 0x98, 0xdd, 0xaa, 0x59, 0xda, 0x51, 0xd7, 0x13, 0x16, 0xfc, 0xf6, 0xc9, 0x67, 0x81, 0x43, 0x17, 0x82, 0x27, 0xd7, 0x78, 0x75, 0x0d, 0xc9, 0x82, 0x7f, 0xc6, 0xcc, 0x21, 0xe6, 0x05, 0xc5, 0x05, 0x02, 0x3e, 0x6d, 0xb2, 0x58, 0x49, 0xdf, 0x7f, 0xb6, 0xfc, 0x1c, 0xa4, 0xd2, 0x23, 0xaa, 0x21, 0x5f, 0x8c, 0x85, 0xb7, 0x24, 0x64, 0x3c, 0x83, 0xbf, 0x82, 0x18, 0x81, 0x5a, 0x9f, 0x9e, 0x29, 0x52, 0x38, 0x4e, 0x0c, 0xa6, 0xa8, 0x0a, 0x37, 0x60, 0xb3, 0x9d, 0xaf, 0x91, 0xa3, 0xc6, 0x15, 0x4c, 0x47, 0x28, 0xc2, 0x37, 0x1f, 0xd1, 0x81, 0xfa, 0x37, 0x64, 0x75, 0x3d, 0x0b, 0x0c, 0x23, 0x80, 0x8a, 0x82, 0xcd, 0x8f, 0x04, 0x97, 0x24, 0x6e, 0x3a, 0x0f, 0x17, 0xf8, 0x90, 0x6a, 0x07, 0xc7, 0x25, 0xd2, 0x89, 0x1c, 0xe9, 0x68, 0xa9, 0xd4, 0x32, 0xc2, 0xb1, 0x02, 0xd8, 0x5c, 0x05, 0x51, 0x0b, 0x28, 0xe7, 0x15, 0xbb, 0x60, 0xd0, 0x40, 0x3a, 0x77, 0x49, 0x0e, 0x7f, 0x18, 0xbe, 0x81, 0x21, 0x8b, 0xc4, 0xf3, 0x92, 0x87, 0xb9, 0xbb, 0x09, 0xf5, 0x02, 0x27, 0xdd, 0x2f, 0x55, 0xe4, 0xfb, 0x70, 0xc4, 0x43, 0x8d, 0xa8, 0xba, 0x3c, 0x8f, 0xfb, 0xce, 0xd8, 0x7d, 0x90, 0x15, 0x59, 0x13, 0xfa, 0xa9, 0x97, 0x9f, 0xc5, 0x7e, 0x6c, 0xbe, 0xdd, 0xfa, 0xba, 0x3d, 0x3a, 0xb4, 0x16, 0x3c, 0x0e, 0xeb, 0xc7, 0xd9, 0x42, 0x79, 0xc2, 0x7d, 0x3e, 0xd5, 0x63, 0x38, 0x89, 0x3d, 0xba, 0x54, 0x2e, 0xae, 0xfb, 0xa3, 0x0f, 0x8c, 0x3b),
+            //   tag: vec!(0x72, 0x8e, 0x60, 0xf8, 0x12, 0x4e, 0xff, 0xba, 0xc2, 0x34, 0xf7, 0x0d, 0xa9, 0x25, 0x88, 0x1c)
+            // },
+            // TestVector{
+            //   key: [0xf0, 0xf1, 0x6b, 0x6f, 0x12, 0xb3, 0x84, 0x0b, 0xbd, 0x1c, 0x4a, 0x6a, 0x08, 0x11, 0xee, 0xf2, 0x37, 0xf1, 0x52, 0x1b, 0x45, 0xde, 0x99, 0x86, 0xda, 0xec, 0x9f, 0x28, 0xfc, 0xa6, 0x48, 0x5c],
+            //   nonce: [0x7a, 0xc9, 0x3e, 0x75, 0x4e, 0x29, 0x03, 0x23],
+            //   plain_text: vec!(0x05, 0x30, 0x55, 0x64, 0x24, 0xd8, 0x23, 0xf9, 0x0a, 0x7f, 0x1c, 0x52, 0x4c, 0x4b, 0xaa, 0x70, 0x6a, 0xad, 0x28, 0x07, 0xe2, 0x89, 0xe9, 0x47, 0x93, 0x01, 0xe3, 0xe7, 0xa7, 0x1f, 0x2a, 0x5e, 0x14, 0xe6, 0x23, 0x2e, 0xa7, 0x85, 0xf3, 0x39, 0xc6, 0x69, 0xaf, 0x2e, 0x6d, 0x25, 0xf1, 0xd5, 0xa2, 0x61, 0x09, 0x6a, 0x54, 0x8d, 0x23, 0x86, 0x49, 0x45, 0xc3, 0xa5, 0x89, 0xb6, 0x7b, 0x09, 0xb0, 0x30, 0x4a, 0x78, 0x4d, 0x61, 0xb4, 0x2b, 0x24, 0x19, 0x13, 0x94, 0x85, 0x24, 0x2e, 0x0d, 0x51, 0xfc, 0xbe, 0x9e, 0x8f, 0xed, 0x99, 0x6d, 0x21, 0x4d, 0xe8, 0x71, 0x7e, 0x6a, 0x71, 0xf8, 0x98, 0x7c, 0xca, 0xd6, 0x5e, 0xb9, 0x2e, 0x66, 0x70, 0x70, 0x34, 0xa5, 0xae, 0x38, 0xe6, 0x48, 0x6e, 0x26, 0xeb, 0x43, 0x74, 0xc5, 0x65, 0xaa, 0xd5, 0xdf, 0x94, 0x9d, 0xab, 0x20, 0x9f, 0x7f, 0x7b, 0xcd, 0x8e, 0xb6, 0xfc, 0x52, 0x76, 0x1a, 0x26, 0xcf, 0xe5, 0xd0, 0x1f, 0xd3, 0x49, 0xe5, 0x9f, 0x40, 0x42, 0xe6, 0xdb, 0xe6, 0xb2, 0x32, 0xf9, 0x30, 0x1b, 0x97, 0x1d, 0xee, 0x12, 0x1d, 0x8a, 0xa1, 0xe6, 0x2d, 0x40, 0xf0, 0x43, 0xa4, 0x2f, 0x3a, 0xa8, 0x59, 0xd8, 0x67, 0xeb, 0x80, 0x9b, 0x1c, 0xed, 0x5a, 0xe1, 0xec, 0x62, 0xca, 0xcf, 0x94, 0xa6, 0x9f, 0xaf, 0xd0, 0x63, 0x1a, 0x8b, 0x5d, 0xfd, 0x66, 0xd8, 0x55, 0x90, 0x0f, 0xb2, 0x95, 0xee, 0xc9, 0x0a, 0xe5, 0xfc, 0xbf, 0x77, 0xbe, 0xae, 0x26, 0x7a, 0x79, 0xd2, 0x40, 0x81, 0xbb, 0x32, 0x2d, 0x8c, 0x4e, 0x06, 0x30, 0xfe, 0xd2, 0x52, 0x54, 0x1b, 0x36),
+            //   aad: vec!(0x13, 0xbf, 0xcc, 0x17, 0xb8, 0x10, 0x09, 0x9c, 0xda, 0x31, 0xca, 0x53, 0xa1, 0x32, 0x3d, 0xb9, 0xb0, 0x76, 0x33, 0xce, 0xb2, 0x08, 0x8a, 0x42, 0x26, 0x3a, 0x4c, 0xbd, 0x6a, 0x4d, 0x47, 0x97, 0x87, 0x76, 0x00, 0x5c, 0x9a, 0x20, 0x20, 0x33, 0x19, 0xc3, 0xa3, 0xae, 0x43, 0x4e, 0x9a, 0x26, 0xfb, 0x54, 0x10, 0x47, 0xdc, 0x9d, 0xf3, 0x8d, 0xc3, 0x6c, 0x09, 0x52, 0x67, 0x27, 0x2e, 0x20, 0x3d, 0x0b, 0x24, 0xd1, 0x19, 0xa7, 0x0a, 0x7e, 0x96, 0x04, 0x1b, 0x6d, 0x82, 0xb7, 0xc4, 0xd5, 0x57, 0x0e, 0x1e, 0x4a, 0x1c, 0xf2, 0xf6, 0xe4, 0x4a, 0xe6, 0x3f, 0xe0, 0x05, 0xa1, 0xf5, 0xb9, 0x00, 0x77, 0x8c, 0x48, 0x2f, 0x7b, 0xd8, 0x9e, 0x2e, 0x02, 0x30, 0x5e, 0x35, 0xb8, 0xf6, 0x1b, 0x7b, 0xb2, 0xc7, 0x8a, 0x13, 0xae, 0xbf, 0xce, 0x01, 0x45, 0xd1, 0xc5, 0xaa, 0x0b, 0xf1, 0xd1, 0x0d, 0x23, 0x61, 0x6d, 0x5a, 0x3a, 0x44, 0x6d, 0xe5, 0x50, 0x30, 0x2f, 0x56, 0xf8, 0x1d, 0xc5, 0x6f, 0xe4, 0xf3, 0x70, 0x0f, 0x14, 0x24, 0x26, 0x88, 0xd9, 0xb9, 0x2d, 0x8a, 0x42, 0x79, 0x79, 0xb4, 0x03, 0xc8, 0xde, 0x8c, 0x49, 0x3a, 0x2c, 0xde, 0x51, 0x0e, 0xaf, 0x6b, 0x28, 0x5e, 0x66, 0x75, 0xb1, 0x73, 0xaa, 0x03, 0x14, 0xa3, 0x86, 0xb6, 0x35, 0xc7, 0x57, 0x7d, 0x5a, 0xff, 0x0d, 0x86, 0x8a, 0x0c, 0xb3, 0xf7, 0x3c, 0x8d, 0x20, 0x05, 0xf8, 0xc7, 0xc9, 0xda, 0xb5, 0xa0, 0x60, 0xef, 0x80, 0x10, 0x2c, 0x9d, 0x4a, 0x4a, 0xf9, 0x88, 0x83, 0x8a, 0xfe, 0x87, 0xaf, 0xf0, 0x4c, 0x06, 0x89, 0xe8, 0xc3, 0xc7, 0xf9),
+            //   cipher_text: vec!(0x2c, 0x14, 0xc3, 0x93, 0x1e, 0x98, 0xe8, 0x45, 0x07, 0xc4, 0xc1, 0x65, 0xc2, 0xed, 0x47, 0xad, 0x4a, 0x17, 0x8f, 0x0e, 0x21, 0x6c, 0xd7, 0xac, 0x24, 0x53, 0xbb, 0xbf, 0x9f, 0x85, 0xdd, 0x06, 0xbd, 0x8e, 0xf5, 0x4a, 0x9f, 0xf1, 0xfd, 0x3d, 0xd8, 0xe0, 0xca, 0xfb, 0x63, 0x5d, 0x8f, 0x2d, 0xe8, 0x61, 0xa0, 0xdb, 0x5b, 0x14, 0xd0, 0x3f, 0x17, 0xaa, 0xea, 0x8c, 0x89, 0xb3, 0x01, 0x07, 0x97, 0xc7, 0x1c, 0x13, 0xa0, 0xe6, 0x66, 0x89, 0x9d, 0x7f, 0xf6, 0xe5, 0x3c, 0x4f, 0x08, 0xbe, 0x8d, 0xdb, 0x3e, 0x37, 0x68, 0x8b, 0x5a, 0xfa, 0x08, 0x80, 0x79, 0xb6, 0xc7, 0x51, 0x9b, 0x83, 0x3e, 0x16, 0x56, 0x00, 0x73, 0xe6, 0x99, 0x53, 0x03, 0x02, 0x02, 0x8a, 0x34, 0x96, 0xe0, 0x5e, 0xdd, 0xde, 0xc0, 0x1a, 0x23, 0xa4, 0xc7, 0x98, 0x39, 0x56, 0x25, 0x0e, 0x8d, 0x9e, 0x61, 0x6f, 0x7b, 0x94, 0x08, 0x56, 0x95, 0x5c, 0xde, 0x81, 0xc1, 0xef, 0xab, 0xf6, 0xb7, 0xb9, 0x2f, 0x15, 0x3d, 0x03, 0xf4, 0xcd, 0x17, 0xe7, 0xf7, 0xd2, 0x90, 0x76, 0x70, 0xcf, 0xc8, 0x4d, 0x45, 0xc1, 0xd7, 0x93, 0x67, 0x75, 0xa3, 0xfc, 0xe4, 0x79, 0x68, 0x50, 0x42, 0x78, 0xff, 0xae, 0xca, 0xce, 0xa0, 0x87, 0x1b, 0x22, 0x7f, 0x25, 0x0e, 0x29, 0x79, 0x51, 0x6f, 0x6f, 0xa3, 0x10, 0xfe, 0xc0, 0xd8, 0xdf, 0x1a, 0xf7, 0x87, 0x2e, 0x5a, 0x53, 0x4e, 0x82, 0x87, 0x0a, 0xa0, 0x5f, 0x43, 0xef, 0x0a, 0x45, 0x58, 0x46, 0xb9, 0x3c, 0xe9, 0x38, 0x06, 0x4f, 0xa3, 0x3e, 0x92, 0xde, 0x26, 0x2e, 0x41, 0x56, 0xda, 0xe5, 0x67, 0x75),
+            //   tag: vec!(0xd9, 0x5d, 0x73, 0xbf, 0x9a, 0xeb, 0x71, 0xeb, 0xa9, 0x04, 0x23, 0x96, 0xf3, 0x72, 0x54, 0x24)
+            // },
+            // TestVector{
+            //   key: [0x37, 0x92, 0x94, 0x3c, 0x03, 0x96, 0xf1, 0x84, 0x04, 0x96, 0x91, 0x7c, 0xe8, 0xad, 0x89, 0x60, 0x83, 0x85, 0x00, 0x7e, 0x79, 0x6f, 0xeb, 0xee, 0xa3, 0x80, 0x5f, 0x3f, 0x4c, 0xbe, 0xcc, 0xf7],
+            //   nonce: [0x23, 0xb2, 0xf9, 0x06, 0x8b, 0x2c, 0x4c, 0x85],
+            //   plain_text: vec!(0xbe, 0x6b, 0x67, 0xeb, 0x94, 0x3e, 0xe7, 0xb5, 0xc7, 0x85, 0xcd, 0x88, 0x2f, 0x65, 0x3e, 0x73, 0xa8, 0xf7, 0x5b, 0x4a, 0x41, 0xa2, 0xa7, 0xc5, 0x6a, 0xe5, 0xa1, 0x0f, 0x72, 0x9c, 0xaf, 0x39, 0x94, 0x8f, 0xe4, 0x8a, 0xd0, 0xe5, 0x12, 0x40, 0xe2, 0xe7, 0xaa, 0x43, 0x19, 0x3c, 0x7e, 0xc6, 0xce, 0x7f, 0x49, 0x09, 0xfc, 0x94, 0xc9, 0xf9, 0x9e, 0x38, 0xe6, 0xa0, 0xad, 0x7e, 0x98, 0xeb, 0x29, 0xc5, 0xc2, 0xe6, 0x1c, 0x99, 0xe9, 0xcb, 0xe8, 0x90, 0xf1, 0x54, 0x18, 0x5c, 0xec, 0x21, 0x3a, 0x74, 0x72, 0x5d, 0x23, 0xc1, 0xa4, 0xe4, 0xd0, 0xcb, 0x9b, 0x1a, 0x36, 0xb7, 0x8c, 0x87, 0xe5, 0xee, 0xe2, 0x0d, 0x2a, 0xa2, 0x9a, 0xae, 0x80, 0xd4, 0x75, 0x9e, 0xb0, 0xc5, 0x1c, 0x5d, 0xc3, 0xa9, 0x5b, 0xdb, 0xbf, 0x7e, 0x14, 0xeb, 0x43, 0x44, 0x19, 0xa6, 0xc8, 0x8a, 0x95, 0x4a, 0xc0, 0x3d, 0x0c, 0x98, 0x73, 0x9f, 0x42, 0x11, 0xb8, 0x73, 0x2a, 0xcd, 0x71, 0xc2, 0x97, 0xf5, 0x78, 0xb8, 0xcb, 0x64, 0xcc, 0xac, 0x45, 0xf7, 0x23, 0x5d, 0xdc, 0x7f, 0x2a, 0x3f, 0x5f, 0x99, 0x75, 0x25, 0xc1, 0xed, 0x39, 0xdc, 0x55, 0x01, 0x26, 0xcd, 0xf9, 0xce, 0xda, 0xf5, 0x54, 0x25, 0x48, 0x90, 0x85, 0xe9, 0x1b, 0x17, 0x0b, 0xe6, 0x20, 0x5a, 0x5a, 0x39, 0x5f, 0x2d, 0xd4, 0x08, 0x4a, 0x3e, 0x8d, 0xbc, 0x4f, 0xd8, 0xb1, 0x32, 0x52, 0xf7, 0xef, 0xfa, 0xe0, 0x67, 0xb5, 0x71, 0xcb, 0x94, 0xa1, 0xe5, 0x4a, 0xba, 0x45, 0xb1, 0xb9, 0x84, 0x13, 0x08, 0xdb, 0x0c, 0xc7, 0x5b, 0x03, 0xcf, 0xce, 0x4d, 0xda, 0xfe, 0x89, 0xce, 0x20, 0xf2, 0xd1),
+            //   aad: vec!(0x7e, 0xb6, 0xd7, 0xb7, 0xbb, 0xaa, 0xa3, 0xc2, 0x02, 0xa4, 0xf0, 0xf1, 0xde, 0x22, 0x63, 0x76, 0x71, 0x69, 0xeb, 0x4a, 0x64, 0x85, 0x32, 0x40, 0xd4, 0x8c, 0x0f, 0x8d, 0x5d, 0x31, 0xb0, 0x8d, 0x5b, 0xaf, 0x42, 0x97, 0x76, 0x14, 0xa5, 0x7a, 0xad, 0x99, 0x42, 0x6c, 0xde, 0x76, 0xd2, 0x42, 0xcb, 0x37, 0xd2, 0x95, 0x6d, 0x8c, 0x77, 0xdc, 0x4f, 0xd6, 0x2a, 0x3a, 0xbf, 0x30, 0xe8, 0xac, 0x6c, 0xd5, 0x8c, 0x8e, 0xf3, 0x5e, 0x67, 0x49, 0x70, 0x22, 0x96, 0x01, 0x38, 0xc5, 0x77, 0x87, 0x81, 0x88, 0x92, 0x46, 0x0f, 0x3b, 0xfc, 0x16, 0xe3, 0x7f, 0xf3, 0x88, 0xb1, 0xed, 0xc6, 0xce, 0x2b, 0xc5, 0x3c, 0x22, 0x71, 0x7e, 0xdc, 0x7a, 0x03, 0xd4, 0xc7, 0x8b, 0x0d, 0xbb, 0xe9, 0x12, 0x1c, 0x7f, 0xd8, 0xa3, 0xe3, 0x99, 0x3b, 0x87, 0xa4, 0xfe, 0x38, 0x9b, 0xff, 0x13, 0xbd, 0xae, 0x3b, 0x34, 0x9d, 0xe0, 0xb6, 0xdb, 0x56, 0x16, 0x02, 0xc5, 0x3f, 0x74, 0x60, 0x22, 0xae, 0xb4, 0x48, 0x3c, 0x72, 0x3b, 0x67, 0x82, 0x50, 0x42, 0xf4, 0xaf, 0x20, 0xb7, 0xdd, 0x1e, 0x60, 0x31, 0xcf, 0x54, 0x21, 0x52, 0x66, 0x29, 0x5c, 0x52, 0x4a, 0xc8, 0xe1, 0x37, 0x04, 0x24, 0xc5, 0xc5, 0xe6, 0x07, 0xfb, 0x3e, 0x23, 0xe9, 0x7c, 0x8e, 0xeb, 0xe6, 0x46, 0x56, 0x77, 0x5e, 0xdf, 0x61, 0x64, 0x22, 0xa8, 0xb9, 0x74, 0xe1, 0xac, 0xf1, 0x3a, 0xb4, 0x5c, 0x9a, 0x36, 0x7a, 0x7d, 0xd9, 0xb2, 0xd6, 0x2f, 0x48, 0xbb, 0xc0, 0x58, 0x19, 0xb6, 0x5e, 0xcc, 0xb8, 0x13, 0xca, 0x81, 0x3f, 0x57, 0xb2, 0x2e, 0xe4, 0xc2, 0x80, 0xdb, 0xb5, 0xa9, 0xd8, 0xd5),
+            //   cipher_text: vec!(0x0b, 0x31, 0x6a, 0xb2, 0xbc, 0xf5, 0x35, 0x99, 0x00, 0xfa, 0x40, 0x82, 0xd5, 0xd2, 0x53, 0xb4, 0x9a, 0xd9, 0x4b, 0x70, 0xe3, 0xfa, 0xb5, 0x44, 0xf9, 0x8b, 0xd1, 0x11, 0xcb, 0xce, 0xf6, 0x76, 0x6c, 0xf9, 0x53, 0xde, 0xec, 0x08, 0xca, 0xe1, 0xf4, 0x89, 0xfe, 0x12, 0xf7, 0xac, 0xc0, 0x03, 0x2d, 0xb8, 0xa6, 0xb0, 0xc0, 0xee, 0xe0, 0xc2, 0x06, 0xea, 0x5f, 0xb9, 0x73, 0xfe, 0xae, 0xbf, 0x90, 0xf6, 0x90, 0xe8, 0x40, 0x09, 0x4d, 0xb5, 0xe1, 0x3f, 0xdd, 0x71, 0x57, 0xba, 0x12, 0x73, 0x68, 0xc9, 0x95, 0xb4, 0x26, 0x52, 0x94, 0x35, 0xa1, 0xbc, 0xdd, 0x1f, 0x14, 0xce, 0x91, 0x25, 0xb8, 0xa0, 0xe4, 0xc9, 0x6b, 0x6e, 0xc0, 0x9e, 0x3c, 0x36, 0xa1, 0x80, 0xad, 0xf8, 0x19, 0x41, 0xc0, 0x02, 0xd1, 0x9c, 0x19, 0xd5, 0x3c, 0x20, 0x09, 0xbe, 0x80, 0x3b, 0x98, 0x75, 0x04, 0x60, 0x6b, 0x7d, 0x43, 0xbd, 0xee, 0x5e, 0x0b, 0x32, 0xff, 0x23, 0xc4, 0x66, 0xb6, 0xcc, 0xcf, 0xcd, 0x0d, 0x4e, 0x88, 0xfd, 0x13, 0x32, 0xe7, 0x37, 0x12, 0xb5, 0xab, 0x72, 0x5c, 0x1a, 0x38, 0x3e, 0x58, 0x4f, 0x34, 0xf8, 0x0d, 0xaf, 0xf2, 0x9d, 0x28, 0x5a, 0xe5, 0xe4, 0x3c, 0xf1, 0xd0, 0xcc, 0x7a, 0x82, 0x8e, 0x75, 0xc2, 0x5d, 0xac, 0xed, 0x3a, 0x58, 0x1a, 0x93, 0xd7, 0xa5, 0x0f, 0x31, 0x3b, 0x33, 0xf3, 0x8d, 0xdd, 0xfa, 0xa2, 0x3c, 0xd5, 0xb9, 0x91, 0x47, 0x97, 0xdb, 0x82, 0x0e, 0xe2, 0x40, 0x0d, 0x52, 0xbf, 0x5f, 0xa9, 0x82, 0x27, 0x7f, 0xe9, 0xb5, 0x88, 0x1a, 0xc4, 0x29, 0x81, 0x63, 0x3b, 0x39, 0x57, 0xb0, 0xe9, 0x35, 0x05, 0x18, 0x28),
+            //   tag: vec!(0x01, 0x97, 0x3e, 0xe2, 0xe8, 0x1c, 0xef, 0x22, 0x75, 0x1a, 0x6a, 0x88, 0x31, 0xd7, 0x52, 0xef)
+            // },
+            // TestVector{
+            //   key: [0xfe, 0x4b, 0xe6, 0x05, 0x47, 0x73, 0xf6, 0x34, 0x35, 0x6a, 0xc3, 0x28, 0x59, 0x1f, 0xbc, 0x6f, 0x83, 0x3b, 0x0d, 0x1b, 0xee, 0xb3, 0x8d, 0xd5, 0xb6, 0xfe, 0xb7, 0x48, 0x1b, 0x44, 0x89, 0xd4],
+            //   nonce: [0x0b, 0x3f, 0x16, 0xf8, 0x98, 0xa5, 0xa7, 0xd5],
+            //   plain_text: vec!(0x76, 0xce, 0xd1, 0xad, 0xe6, 0xd1, 0xef, 0x40, 0x69, 0xaf, 0xdd, 0xb3, 0x2e, 0x74, 0x32, 0xd4, 0xff, 0x2f, 0xd0, 0x66, 0x85, 0x12, 0x1f, 0x7b, 0x16, 0x46, 0x4e, 0x7a, 0x72, 0xd3, 0x65, 0x74, 0x4f, 0x54, 0x7d, 0x2c, 0xcf, 0x53, 0x48, 0x63, 0x10, 0xe3, 0x8b, 0x42, 0xd8, 0xba, 0xca, 0xf7, 0x11, 0xe5, 0x4c, 0x54, 0x58, 0xd2, 0xd6, 0x8c, 0x4d, 0xbc, 0xc8, 0xde, 0x31, 0xab, 0x67, 0x32, 0xf4, 0x43, 0x0e, 0x88, 0xa6, 0x45, 0x65, 0xf5, 0xb2, 0x87, 0x64, 0x07, 0x75, 0xaa, 0xa2, 0xaf, 0x1c, 0xc4, 0x61, 0xd3, 0xe4, 0x15, 0xbb, 0x27, 0x5c, 0x62, 0x46, 0xb1, 0xb5, 0x85, 0x17, 0xaa, 0x72, 0x66, 0x7e, 0xae, 0x29, 0x1a, 0x29, 0x82, 0xed, 0xa1, 0x75, 0xd1, 0xb2, 0x2c, 0x5a, 0x58, 0xe6, 0xfe, 0xc2, 0xb3, 0x74, 0x3d, 0x55, 0x71, 0x2f, 0x20, 0x1c, 0xa2, 0x4b, 0xa5, 0xc0, 0xae, 0x8c, 0x25, 0x72, 0x48, 0x71, 0xb2, 0xec, 0x2f, 0xb9, 0x14, 0xa8, 0xda, 0x5a, 0x52, 0x67, 0x0a, 0xb9, 0xb4, 0x3a, 0x83, 0xb8, 0x56, 0x8c, 0xe7, 0x4d, 0xb5, 0xc6, 0x34, 0x06, 0x1c, 0xb8, 0x05, 0x30, 0xc8, 0x07, 0x0c, 0x38, 0xb8, 0xf4, 0x8c, 0x33, 0xba, 0x13, 0x6c, 0xb9, 0xf2, 0x15, 0x8e, 0xe7, 0xed, 0xa8, 0xb6, 0x5f, 0x21, 0x92, 0xfc, 0x94, 0xd1, 0x29, 0x1f, 0x18, 0x2f, 0x10, 0x17, 0x95, 0xb7, 0x19, 0x0c, 0x74, 0xb3, 0x19, 0xd2, 0xd3, 0xe0, 0x2a, 0x97, 0xc8, 0x24, 0xd9, 0xc9, 0x47, 0x1a, 0x83, 0x79, 0x7e, 0x49, 0x36, 0x31, 0x0b, 0x20, 0x7e, 0x3a, 0x1e, 0x0b, 0xcf, 0x75, 0xf7, 0xc3, 0xe3, 0xee, 0x48, 0xa7, 0x47, 0x64, 0x1c, 0xdc, 0x43, 0x77, 0xf2, 0xd5, 0x50, 0x82),
+            //   aad: vec!(0x83, 0x4c, 0xd7, 0x75, 0xcb, 0xef, 0xe4, 0xb3, 0x3a, 0x3c, 0xa5, 0x3a, 0x00, 0xc0, 0x6a, 0x3c, 0x4a, 0x66, 0x69, 0x83, 0xe4, 0x11, 0x5a, 0x02, 0x9f, 0x15, 0x72, 0x94, 0x60, 0xda, 0xa4, 0x5d, 0x15, 0x05, 0xe9, 0x51, 0x72, 0xd3, 0x69, 0x56, 0x25, 0xa1, 0x86, 0xb2, 0x8b, 0x8b, 0xe1, 0x73, 0xa9, 0x25, 0xaf, 0x04, 0x66, 0x5f, 0x20, 0x92, 0x67, 0xb3, 0xc5, 0x12, 0x3e, 0x8b, 0xe1, 0x3d, 0xa4, 0x47, 0xee, 0x1a, 0xe8, 0x56, 0xbb, 0x09, 0x25, 0xf3, 0x5a, 0xaa, 0x76, 0xe0, 0x4a, 0x7b, 0xca, 0x84, 0x60, 0xf7, 0x6c, 0x20, 0x24, 0xde, 0x21, 0x49, 0xf3, 0x8a, 0x8c, 0xfb, 0xa8, 0x16, 0x94, 0xb8, 0x54, 0x88, 0x5d, 0x72, 0x56, 0x81, 0x05, 0x57, 0x1b, 0x6b, 0x21, 0x3a, 0x0b, 0xc1, 0x88, 0xa4, 0x4c, 0xc7, 0xfe, 0x13, 0x15, 0x3c, 0xbf, 0x26, 0x14, 0x01, 0xb2, 0x38, 0xcf, 0x12, 0xa9, 0x5e, 0x23, 0xcb, 0x56, 0xf2, 0x40, 0x11, 0x4f, 0x16, 0xe2, 0xf1, 0xe3, 0xa5, 0x14, 0x61, 0x5a, 0xab, 0x44, 0x49, 0xc0, 0xc4, 0x9e, 0x4d, 0x90, 0x0b, 0x0e, 0x17, 0xd1, 0xa8, 0xda, 0xbb, 0x53, 0xd4, 0x3d, 0xca, 0x32, 0xfa, 0x05, 0x2d, 0x57, 0x6b, 0x73, 0xdd, 0x9b, 0x40, 0x85, 0x6b, 0x51, 0x5d, 0x6d, 0x7e, 0xfc, 0x2a, 0x5c, 0x17, 0xe0, 0xeb, 0xcb, 0x17, 0xbd, 0x59, 0xdc, 0x86, 0xf2, 0x2c, 0xe9, 0x09, 0x30, 0x1a, 0x26, 0x52, 0xf1, 0x34, 0xe8, 0x2e, 0xf0, 0xe4, 0x51, 0x94, 0x87, 0xed, 0x12, 0xd5, 0x15, 0x36, 0x02, 0x4f, 0x2a, 0xe8, 0xf7, 0x5d, 0x93, 0x7c, 0x42, 0xd0, 0x03, 0x07, 0x6e, 0x5d, 0xea, 0x8d, 0xe0, 0xc6, 0x84, 0xcd, 0xa1, 0xf3, 0x42, 0x53, 0xd8, 0xfc),
+            //   cipher_text: vec!(0xf8, 0xde, 0xfb, 0x6f, 0xe9, 0x5d, 0xfe, 0xc4, 0x99, 0xb9, 0x09, 0x99, 0x6a, 0x1f, 0x75, 0xa1, 0x98, 0xa9, 0x0e, 0x4d, 0x6c, 0x64, 0x64, 0xd0, 0x0a, 0x35, 0x7a, 0x55, 0x53, 0x11, 0xc4, 0x2f, 0xe9, 0x2d, 0xbb, 0xc4, 0xb7, 0x9c, 0x93, 0x5e, 0x4f, 0x0b, 0x1a, 0x95, 0xe4, 0x4f, 0xdb, 0xc1, 0x38, 0x0b, 0xeb, 0xab, 0xca, 0x28, 0xdb, 0x4d, 0xd0, 0xd2, 0x87, 0x0d, 0xaa, 0xaf, 0xc3, 0x8e, 0xf2, 0x79, 0x08, 0xc3, 0x50, 0x9e, 0x94, 0x57, 0x14, 0x80, 0x1c, 0xc5, 0x1f, 0x1a, 0x07, 0xb2, 0x43, 0x0c, 0x74, 0xfa, 0x64, 0xf2, 0xa7, 0xc2, 0xf7, 0xfd, 0x15, 0x51, 0xd2, 0x58, 0xc9, 0xc3, 0xbe, 0x02, 0x08, 0x73, 0xfc, 0x1b, 0xf1, 0x9f, 0x33, 0xab, 0x6c, 0x66, 0x09, 0x11, 0xdc, 0xf2, 0x31, 0x71, 0x95, 0xd0, 0xef, 0xee, 0x82, 0xd2, 0x0e, 0xc2, 0x6d, 0x22, 0x61, 0x1f, 0x9c, 0xf8, 0x6c, 0x51, 0xa6, 0x4e, 0x28, 0xb3, 0xa1, 0xf3, 0x44, 0x50, 0x00, 0x18, 0xe0, 0x85, 0x5c, 0x88, 0xda, 0xe3, 0xc0, 0x7a, 0xca, 0xea, 0xa1, 0x0b, 0x60, 0x38, 0x84, 0x84, 0xdc, 0xe9, 0x3e, 0x16, 0xe6, 0xe1, 0xa6, 0xe6, 0x9e, 0x89, 0x98, 0x06, 0x64, 0x8a, 0x92, 0x56, 0x8c, 0x87, 0x80, 0xe9, 0xf4, 0xba, 0xac, 0xd9, 0x8c, 0xbb, 0x35, 0x3a, 0xc2, 0xf9, 0x08, 0xe7, 0x75, 0xd9, 0x23, 0x03, 0xcf, 0xab, 0x84, 0x3f, 0x15, 0xbe, 0x0e, 0x0c, 0x32, 0x2a, 0x95, 0x88, 0x02, 0xfb, 0x1a, 0x60, 0xfc, 0xc7, 0x63, 0x1f, 0x15, 0x1f, 0x4c, 0x2b, 0x8c, 0xb9, 0x65, 0xd2, 0xd2, 0x96, 0xac, 0xef, 0x25, 0x02, 0x75, 0xa2, 0xfe, 0xcc, 0x0c, 0xea, 0x80, 0x3c, 0xe7, 0xc0, 0x58, 0xb1, 0x2d, 0xd2),
+            //   tag: vec!(0xad, 0xe5, 0x15, 0x09, 0x19, 0x30, 0xdd, 0x78, 0x61, 0xb2, 0x7f, 0x78, 0xa8, 0x7e, 0xf6, 0x0c)
+            // },
+            // TestVector{
+            //   key: [0xa2, 0x88, 0xb1, 0x1c, 0xe5, 0x38, 0x2e, 0xc7, 0x24, 0xce, 0x4a, 0xb2, 0xd7, 0xef, 0xa8, 0xe7, 0x77, 0xe9, 0x1e, 0xbd, 0x04, 0x36, 0x79, 0x35, 0xe1, 0x5f, 0x9d, 0xac, 0x48, 0x3e, 0x95, 0x96],
+            //   nonce: [0x87, 0x41, 0x44, 0xdb, 0xf6, 0x48, 0xb3, 0x25],
+            //   plain_text: vec!(0x4c, 0x91, 0x95, 0x28, 0x0a, 0x79, 0xa5, 0x09, 0x91, 0x9a, 0xf4, 0x94, 0x7e, 0x9e, 0x07, 0x23, 0x16, 0x95, 0xfd, 0x7c, 0x50, 0x88, 0x53, 0x9f, 0x23, 0x93, 0x6c, 0xe8, 0x87, 0x70, 0xce, 0x07, 0xd9, 0xad, 0x3a, 0xe4, 0xa4, 0x63, 0xb3, 0xa5, 0x7d, 0x06, 0x34, 0xd3, 0xa7, 0x7c, 0xea, 0xad, 0xf3, 0x47, 0xa3, 0x34, 0x68, 0x2b, 0x04, 0xbe, 0x8e, 0x58, 0xb8, 0xe8, 0x6f, 0xb9, 0x4a, 0x1f, 0x93, 0x25, 0x51, 0x32, 0xb8, 0xcd, 0xb0, 0xdf, 0x86, 0xf5, 0xbe, 0xa3, 0x54, 0xee, 0xa4, 0xe8, 0x31, 0x5f, 0xea, 0x83, 0xe3, 0xfd, 0xf6, 0xe5, 0x8a, 0xa9, 0xf2, 0x6e, 0x93, 0xca, 0xa0, 0x8e, 0x5e, 0x25, 0x51, 0xa9, 0x4b, 0xd9, 0x16, 0xa5, 0x1f, 0xed, 0x29, 0xec, 0x16, 0xf6, 0x68, 0x00, 0xcd, 0xa6, 0xa0, 0xaa, 0x24, 0xec, 0x30, 0x8b, 0xf5, 0xfb, 0x88, 0x5a, 0xfb, 0xa2, 0x72, 0x68, 0x5d, 0xe2, 0x7c, 0x1e, 0xdc, 0xdd, 0x36, 0x68, 0x04, 0x8e, 0xf0, 0x7b, 0x06, 0xe9, 0x0d, 0x46, 0x4a, 0x8a, 0xa2, 0x86, 0x64, 0x90, 0x3c, 0xac, 0x45, 0xe1, 0x54, 0xe8, 0xe1, 0xe3, 0x9c, 0x25, 0x7e, 0x1f, 0xf5, 0x06, 0xb9, 0xd9, 0x5c, 0xef, 0x4f, 0x30, 0x0b, 0xb7, 0x3b, 0x89, 0x9e, 0x78, 0x28, 0x60, 0x2c, 0x3c, 0x1d, 0x29, 0x0b, 0x8c, 0xf5, 0x5e, 0xe5, 0xfd, 0x72, 0xec, 0xce, 0x9e, 0x6e, 0xfc, 0x92, 0x93, 0xae, 0xbf, 0x67, 0x4a, 0x70, 0xe2, 0xa7, 0x67, 0x3e, 0x75, 0x62, 0x9c, 0x12, 0x95, 0x06, 0x22, 0xdf, 0xf7, 0x1d, 0x3e, 0xc0, 0x99, 0x2e, 0x57, 0x77, 0x6c, 0x78, 0x8c, 0x69, 0x27, 0xd3, 0x0b, 0x4e, 0x24, 0xb7, 0x49, 0x19, 0x1c, 0x3c, 0xe8, 0x01, 0x7f, 0x0a, 0xda, 0x62, 0x76, 0xe4, 0x37, 0x20),
+            //   aad: vec!(0x04, 0xab, 0xe8, 0x58, 0x8c, 0x8c, 0x8c, 0x39, 0xa1, 0x82, 0x09, 0x2e, 0x5e, 0x78, 0x40, 0x44, 0x2b, 0xd1, 0xc1, 0x14, 0x9d, 0xa1, 0x02, 0xc4, 0xee, 0x41, 0x2b, 0xd8, 0xb8, 0x2b, 0xaa, 0x50, 0x87, 0xef, 0x72, 0x91, 0xb5, 0xcd, 0x07, 0x7c, 0x17, 0x7c, 0x42, 0x77, 0x0b, 0x00, 0x23, 0xe0, 0xe4, 0x62, 0xb0, 0x6e, 0x75, 0x53, 0xf1, 0x91, 0xbc, 0xb0, 0x31, 0x5a, 0x34, 0x91, 0x8d, 0xcd, 0xbf, 0xfe, 0x2b, 0x99, 0xc3, 0xe0, 0x11, 0xb4, 0x22, 0x0c, 0xc1, 0x77, 0x5d, 0xeb, 0xcc, 0x0d, 0xb5, 0x5f, 0xa6, 0x0d, 0xf9, 0xb5, 0x22, 0x34, 0xf3, 0xd3, 0xfa, 0x96, 0x06, 0x50, 0x8b, 0xad, 0xc2, 0x6f, 0x30, 0xb4, 0x7c, 0xdb, 0x4f, 0x1c, 0x0f, 0x47, 0x08, 0xd4, 0x17, 0xb6, 0x85, 0x3e, 0x66, 0xc2, 0xf1, 0xf6, 0x7f, 0x62, 0x00, 0xda, 0xf7, 0x60, 0xce, 0xb6, 0x4f, 0xfc, 0x43, 0xdb, 0x27, 0xf0, 0x57, 0xad, 0x3e, 0xe9, 0x73, 0xe3, 0x1d, 0x7e, 0x5d, 0x5d, 0xeb, 0x05, 0x03, 0x15, 0xc1, 0xc6, 0x87, 0x98, 0x0c, 0x0c, 0x14, 0x8e, 0xe1, 0xa4, 0x92, 0xd4, 0x7a, 0xcf, 0xcd, 0x61, 0x32, 0x33, 0x41, 0x76, 0xc1, 0x12, 0x58, 0xc8, 0x9b, 0x19, 0xba, 0x02, 0xe6, 0xac, 0xc5, 0x5d, 0x85, 0x2f, 0x87, 0xb6, 0xa2, 0x16, 0x9e, 0xd3, 0x4a, 0x61, 0x47, 0xca, 0xa6, 0x09, 0x06, 0xac, 0x8c, 0x08, 0x13, 0xc0, 0xf0, 0x55, 0x22, 0xaf, 0x7b, 0x7f, 0x0f, 0xad, 0xdb, 0x4b, 0xc2, 0x97, 0x40, 0x5e, 0x28, 0xec, 0xf5, 0xa0, 0xf6, 0xaa, 0xc6, 0x25, 0x84, 0x22, 0xd2, 0x9c, 0xfe, 0x25, 0x0d, 0x61, 0x40, 0x28, 0x40, 0xf3, 0xc2, 0x7d, 0x0c, 0xe3, 0x9b, 0x3e, 0x2d, 0x5f, 0x1e, 0x52, 0x05, 0x41, 0xd2, 0x96, 0x5e),
+            //   cipher_text: vec!(0x0a, 0xfc, 0xe7, 0x70, 0xa1, 0x2f, 0x15, 0xd6, 0x7a, 0xc1, 0x04, 0xba, 0x06, 0x40, 0xaa, 0xb9, 0x59, 0x22, 0x39, 0x06, 0x07, 0x47, 0x3c, 0xbd, 0xa7, 0x13, 0x21, 0x15, 0x6a, 0x55, 0x59, 0x90, 0x6b, 0xe9, 0x33, 0xfb, 0x09, 0x80, 0xda, 0x56, 0xf2, 0x7e, 0x89, 0x79, 0x6e, 0xaa, 0x10, 0x54, 0xf5, 0xaa, 0xcf, 0x16, 0x68, 0xd9, 0xf2, 0x73, 0xcc, 0x69, 0x07, 0x1b, 0x9e, 0x8e, 0x22, 0xaf, 0x6a, 0x20, 0x5a, 0x6a, 0x88, 0xf7, 0xad, 0x91, 0x8e, 0x22, 0xf6, 0x16, 0xbd, 0xdb, 0xb0, 0x7c, 0x78, 0x91, 0x3c, 0x7e, 0x05, 0x6e, 0x76, 0x9e, 0x6f, 0xcf, 0x91, 0xc7, 0x60, 0x0c, 0x27, 0x40, 0x21, 0x2e, 0x3a, 0x17, 0x6e, 0x41, 0x10, 0xca, 0xc9, 0xe3, 0x61, 0xa5, 0x9a, 0x77, 0x34, 0x57, 0x06, 0x4d, 0x2d, 0xc6, 0x52, 0xdd, 0x11, 0x5d, 0x04, 0xf1, 0xc3, 0x75, 0x6c, 0x0e, 0x1d, 0x39, 0xf6, 0x73, 0x7a, 0x16, 0xb4, 0x50, 0x86, 0x63, 0xe3, 0x10, 0x93, 0x4c, 0x49, 0xc5, 0x80, 0x58, 0xb3, 0xc7, 0xb9, 0xaf, 0x7b, 0xb2, 0x33, 0x4c, 0x8a, 0x16, 0x36, 0x08, 0xc4, 0x24, 0x99, 0x65, 0x89, 0x86, 0x92, 0x7c, 0xda, 0x36, 0x5e, 0x2a, 0xea, 0xd3, 0xac, 0x29, 0xde, 0x16, 0xe4, 0x7e, 0x95, 0x43, 0x83, 0xea, 0x56, 0x6f, 0x8f, 0xb2, 0x45, 0xa4, 0xe5, 0xa9, 0x34, 0xc7, 0x67, 0xbb, 0x3b, 0xf7, 0xe0, 0xeb, 0x8a, 0x47, 0x7f, 0xd0, 0xe1, 0xf6, 0x1b, 0xcb, 0x23, 0x84, 0x62, 0xa0, 0xd1, 0x9c, 0x5c, 0xea, 0x92, 0x93, 0xca, 0x58, 0xad, 0xe7, 0x68, 0x29, 0x41, 0x32, 0x16, 0xa7, 0x88, 0x2c, 0xd2, 0x84, 0x63, 0x23, 0x04, 0x66, 0x94, 0xf7, 0x8c, 0xd8, 0xb0, 0x34, 0x77, 0x92, 0xeb, 0xb7, 0x5a, 0xbd, 0xc1),
+            //   tag: vec!(0x97, 0x3e, 0x58, 0xb1, 0xb8, 0xad, 0xb1, 0x76, 0xa6, 0xf1, 0xe5, 0xc9, 0x63, 0xbf, 0xdc, 0x5c)
+            // },
+            // TestVector{
+            //   key: [0x65, 0xb6, 0x3e, 0xd5, 0x37, 0x50, 0xc8, 0x8c, 0x50, 0x8c, 0x44, 0x88, 0x1a, 0xe5, 0x9e, 0x6f, 0xff, 0x69, 0xc6, 0x62, 0x88, 0xf3, 0xc1, 0x4c, 0xfe, 0xc5, 0x03, 0x39, 0x12, 0x62, 0xca, 0xfc],
+            //   nonce: [0x7f, 0x5e, 0x56, 0x0a, 0x1d, 0xe4, 0x34, 0xba],
+            //   plain_text: vec!(0x84, 0x5e, 0xf2, 0x7b, 0x66, 0x15, 0xfb, 0x69, 0x9d, 0x37, 0x97, 0x1d, 0xb6, 0xb5, 0x97, 0x93, 0x0a, 0x7e, 0xf1, 0xe6, 0xf9, 0x00, 0x54, 0x79, 0x1e, 0xb0, 0x4d, 0xdf, 0xe7, 0x25, 0x2b, 0x5f, 0x88, 0xfd, 0x60, 0xeb, 0xa5, 0xaf, 0x46, 0x9b, 0xc0, 0x96, 0x61, 0xc0, 0x98, 0x7a, 0x49, 0x6f, 0xa5, 0x40, 0x62, 0x1a, 0xfe, 0xec, 0x51, 0xbe, 0xbd, 0xa7, 0x86, 0x82, 0x68, 0x00, 0x94, 0x3d, 0x97, 0x70, 0x39, 0xde, 0xe7, 0x62, 0x35, 0x24, 0x81, 0x12, 0xff, 0x8b, 0x74, 0x3f, 0x25, 0xed, 0x5f, 0x3c, 0xb0, 0xd3, 0x30, 0x7f, 0x5e, 0x11, 0x8d, 0x84, 0xfd, 0xbb, 0x9c, 0x3f, 0x55, 0x31, 0xbc, 0x17, 0x7f, 0xb8, 0x45, 0x49, 0xc9, 0x94, 0xea, 0x44, 0x96, 0xc6, 0x5e, 0x52, 0x49, 0xda, 0x98, 0x7d, 0xd7, 0x55, 0xd4, 0x6d, 0xc1, 0x78, 0x8f, 0x58, 0x24, 0x10, 0x26, 0x6a, 0x10, 0xf2, 0x91, 0xc1, 0x47, 0x4f, 0x73, 0x21, 0x83, 0xa2, 0xa3, 0x9a, 0xfe, 0x60, 0x37, 0x71, 0xbb, 0x9c, 0x42, 0x3f, 0xe3, 0xe8, 0x90, 0x6f, 0x2b, 0xe4, 0x4a, 0x0c, 0x9a, 0x7c, 0x3f, 0x0c, 0xeb, 0x09, 0xd1, 0xd0, 0xf9, 0x2d, 0x94, 0x23, 0x83, 0xa8, 0x75, 0xc0, 0x56, 0x7c, 0x78, 0x69, 0xf0, 0x45, 0xe5, 0x6d, 0xd1, 0xa4, 0xd6, 0xe9, 0x0c, 0x58, 0xd4, 0x4f, 0xe0, 0xc5, 0x76, 0x0b, 0xb4, 0xfd, 0x01, 0xde, 0x55, 0x43, 0x9d, 0xb5, 0x2b, 0x56, 0x83, 0x1e, 0x5a, 0x26, 0xa4, 0x7d, 0xe1, 0x42, 0x49, 0x45, 0x3a, 0x4f, 0x8e, 0x7d, 0xa3, 0xcb, 0x32, 0x82, 0xc6, 0x62, 0x29, 0x16, 0x19, 0x7e, 0xbf, 0xaa, 0xd8, 0x5d, 0xd6, 0x5c, 0x61, 0xe7, 0xd2, 0xd3, 0xba, 0x62, 0x62, 0x76, 0x36, 0x67, 0x46, 0xf3, 0x96, 0x39, 0x4c, 0x1b, 0xf7, 0x5f, 0x51, 0xce),
+            //   aad: vec!(0x51, 0xa3, 0x58, 0x83, 0x98, 0x80, 0x8e, 0x1d, 0x6a, 0x98, 0x50, 0x5c, 0x6e, 0x56, 0x01, 0xae, 0x2a, 0x27, 0x66, 0xf1, 0xf2, 0x8f, 0x8f, 0x69, 0xd1, 0xcc, 0xbc, 0xad, 0x18, 0x03, 0x8c, 0x15, 0x7b, 0x41, 0x52, 0x5b, 0xe5, 0x8a, 0xe4, 0x52, 0x7a, 0x07, 0x37, 0x48, 0xb7, 0xa0, 0x48, 0x09, 0xe5, 0x2a, 0x5d, 0xf0, 0xc7, 0x98, 0x84, 0x17, 0x60, 0x77, 0x38, 0xe6, 0x3d, 0x7e, 0xad, 0x47, 0xdb, 0x79, 0x5a, 0x34, 0x6b, 0x04, 0xe7, 0x40, 0x18, 0x6e, 0x73, 0xcc, 0xad, 0x79, 0xf7, 0x25, 0xb5, 0x8e, 0xe2, 0x2d, 0xc6, 0xe3, 0x0d, 0x1f, 0x0a, 0x21, 0x8e, 0xda, 0x17, 0x91, 0xe2, 0x22, 0x9b, 0x25, 0x3d, 0x4a, 0xb2, 0xb9, 0x63, 0xa4, 0x3e, 0x12, 0x31, 0x8c, 0x8b, 0x07, 0x85, 0xc2, 0x0f, 0xca, 0x3a, 0xbc, 0xf2, 0x20, 0xc0, 0x87, 0x45, 0xd9, 0xf9, 0x60, 0x2f, 0x0e, 0xce, 0x54, 0x4a, 0x05, 0x73, 0x6d, 0x76, 0xb1, 0x2d, 0x24, 0x96, 0x99, 0xc9, 0xe3, 0xe9, 0x9f, 0x3f, 0x13, 0xcf, 0x4e, 0x5d, 0xc1, 0x3a, 0x04, 0x12, 0x5c, 0x94, 0x9a, 0x5b, 0x30, 0xd0, 0x34, 0xb2, 0x3c, 0xb3, 0x64, 0xc8, 0x78, 0x19, 0x64, 0xbc, 0x6c, 0x30, 0xe5, 0xe5, 0xca, 0x96, 0x73, 0xd5, 0x17, 0xef, 0x5f, 0x35, 0x96, 0x5d, 0x8a, 0x8c, 0xf1, 0xbe, 0x01, 0x7e, 0x34, 0x3d, 0xf9, 0x7b, 0x6b, 0xee, 0x37, 0xb3, 0x06, 0x38, 0xb1, 0x54, 0x28, 0x6d, 0x1f, 0x36, 0xd2, 0xf9, 0xa0, 0xea, 0xa2, 0x3c, 0xc4, 0x84, 0xea, 0xc5, 0xa0, 0x5b, 0x15, 0xd9, 0xef, 0xc5, 0x37, 0xd9, 0x89, 0xdb, 0xc8, 0xb3, 0x10, 0x6c, 0x0d, 0xc1, 0xa5, 0x6e, 0x97, 0xe6, 0xae, 0xc2, 0xef, 0xf5, 0x4a, 0x82, 0xcf, 0x7a, 0xe9, 0xdf, 0x2a, 0xf4, 0x6b, 0x4c, 0x86, 0x0f, 0x83),
+            //   cipher_text: vec!(0x02, 0x7b, 0x14, 0x19, 0x7b, 0x40, 0x12, 0x25, 0x6b, 0x13, 0x3b, 0x78, 0xdd, 0xc9, 0x4e, 0x72, 0xfb, 0x4d, 0x72, 0x4f, 0xef, 0xa4, 0xae, 0x32, 0x9f, 0x5a, 0x5f, 0xa3, 0xfa, 0x78, 0x4f, 0xe6, 0xd7, 0xe1, 0xe8, 0x05, 0xe3, 0xf7, 0xa7, 0x55, 0x57, 0xde, 0x64, 0xde, 0x50, 0x6d, 0x38, 0x23, 0x7b, 0x46, 0x7f, 0xa5, 0x77, 0xef, 0xb5, 0x9e, 0x7c, 0xfe, 0x23, 0x56, 0xbe, 0xd6, 0x65, 0x5c, 0x5a, 0xa4, 0xe2, 0x38, 0xdc, 0xfe, 0xb7, 0x5c, 0x16, 0x54, 0x9a, 0x09, 0x17, 0x26, 0x87, 0x68, 0xa9, 0x6a, 0xcb, 0x5e, 0x20, 0x54, 0x6a, 0x1f, 0xb7, 0xe3, 0xa7, 0xcf, 0xf8, 0x87, 0xf4, 0x9f, 0x2c, 0xd7, 0xa1, 0x35, 0xf7, 0x2a, 0x98, 0xa7, 0x79, 0x15, 0x0f, 0x32, 0x07, 0xbf, 0x73, 0x3e, 0x88, 0x86, 0x1f, 0xd7, 0x9e, 0xad, 0xbf, 0x77, 0xfa, 0x3b, 0xfe, 0x97, 0xbf, 0xe8, 0xb6, 0xa9, 0x91, 0xcb, 0x3b, 0xcc, 0x2c, 0xde, 0x82, 0x87, 0xf7, 0xe8, 0x93, 0x84, 0x84, 0x65, 0x61, 0x93, 0x4b, 0x0f, 0x3e, 0x05, 0xe0, 0x64, 0x6e, 0x0e, 0x19, 0x07, 0x77, 0x0d, 0xf6, 0x7a, 0x75, 0x94, 0x16, 0x1a, 0x4d, 0x07, 0x63, 0xfa, 0xa6, 0xfa, 0x84, 0x40, 0x80, 0x93, 0x21, 0x59, 0x99, 0x9d, 0x52, 0x8e, 0xe0, 0x55, 0x87, 0x10, 0x05, 0x8c, 0xe1, 0x6f, 0x97, 0xd1, 0x3a, 0xc9, 0xfd, 0x9b, 0xf5, 0x04, 0x41, 0x91, 0x18, 0x8b, 0xbf, 0xb5, 0x98, 0xd0, 0xfa, 0xfb, 0xdf, 0x79, 0x0b, 0x61, 0xce, 0x07, 0x81, 0xec, 0xc0, 0x42, 0x18, 0xa3, 0x0d, 0xed, 0x45, 0xef, 0xd4, 0x98, 0xcc, 0x9b, 0xa0, 0x35, 0x62, 0xed, 0x2b, 0x4a, 0x99, 0x3e, 0xe9, 0x88, 0x76, 0xb3, 0xab, 0x7a, 0x9b, 0xc0, 0x78, 0x29, 0xf1, 0xc4, 0xca, 0x6e, 0xad, 0x98, 0xc0, 0x6b),
+            //   tag: vec!(0xe4, 0xd1, 0x8a, 0x70, 0x1b, 0x83, 0x08, 0x69, 0x7b, 0x5e, 0x79, 0x14, 0x1e, 0xd7, 0x83, 0xc1)
+            // },
+            // TestVector{
+            //   key: [0x49, 0x86, 0xfd, 0x62, 0xd6, 0xcb, 0x86, 0xb2, 0xea, 0xf2, 0x19, 0x17, 0x4b, 0xec, 0x68, 0x1b, 0xeb, 0xcd, 0xef, 0x86, 0xc8, 0xbe, 0x29, 0x1f, 0x27, 0xd3, 0xe5, 0xdc, 0x69, 0xe2, 0xfe, 0xba],
+            //   nonce: [0xd0, 0x8d, 0x48, 0x66, 0x20, 0xed, 0x2e, 0x84],
+            //   plain_text: vec!(0x3a, 0x22, 0xad, 0x5d, 0xe3, 0x87, 0xdb, 0x4f, 0xdd, 0x5d, 0x62, 0xa1, 0xb7, 0x28, 0xc2, 0x3a, 0x8d, 0xdd, 0xc5, 0x0b, 0x1e, 0x89, 0xf5, 0x4f, 0x61, 0x98, 0xb9, 0x04, 0x99, 0xf9, 0xda, 0x31, 0x22, 0xeb, 0xeb, 0x38, 0xeb, 0xf5, 0xfd, 0xfe, 0x30, 0x30, 0x97, 0x34, 0xf7, 0x9a, 0xff, 0x01, 0xe3, 0xde, 0x1e, 0x19, 0x6b, 0x35, 0xbf, 0xfa, 0x33, 0xba, 0xe4, 0x51, 0xf3, 0x1f, 0x74, 0xb8, 0xae, 0xc0, 0x37, 0x63, 0xf9, 0xe0, 0x86, 0x1a, 0x34, 0xfe, 0x5d, 0xb0, 0xb4, 0x0c, 0x76, 0xe5, 0x7c, 0x7f, 0xc5, 0x82, 0xbf, 0xa1, 0x9c, 0x94, 0xee, 0x25, 0xb5, 0xe1, 0x68, 0x27, 0x0f, 0x37, 0x9b, 0xf9, 0xf8, 0xa0, 0xa1, 0x8b, 0xed, 0x05, 0xde, 0x25, 0x6f, 0x8f, 0x0d, 0xd7, 0xc2, 0x3b, 0xa2, 0xff, 0x1c, 0x7f, 0x72, 0x14, 0x09, 0x46, 0x2f, 0x04, 0xcc, 0x61, 0x1a, 0xd9, 0xbd, 0x4c, 0x3c, 0x9a, 0xcf, 0x30, 0x74, 0x2a, 0xcf, 0xb9, 0x51, 0x8a, 0x63, 0x75, 0xcb, 0xb1, 0x5d, 0x65, 0xa1, 0xbc, 0x69, 0x93, 0xea, 0x43, 0x48, 0x94, 0xf9, 0x3d, 0x4f, 0x6e, 0x05, 0x99, 0x6e, 0xbc, 0x1b, 0xd5, 0x65, 0x79, 0x29, 0x63, 0x09, 0xa2, 0xc6, 0xb8, 0xfd, 0xe9, 0x50, 0x72, 0x16, 0x8b, 0x5f, 0xd3, 0x19, 0x27, 0xc4, 0xc0, 0xab, 0xaa, 0x05, 0x6b, 0xcd, 0x16, 0x22, 0x1d, 0x5f, 0x22, 0x0b, 0xe4, 0x75, 0x91, 0xf4, 0x32, 0x55, 0x01, 0x3a, 0x26, 0x2d, 0xce, 0x43, 0x98, 0x17, 0xf5, 0x34, 0x83, 0x0b, 0xa8, 0x21, 0x55, 0x34, 0x7e, 0x5f, 0xe3, 0x10, 0x1f, 0x80, 0x11, 0xb8, 0x93, 0x65, 0xa6, 0x56, 0x82, 0x14, 0xed, 0x06, 0x61, 0x91, 0x4e, 0x8c, 0xb3, 0x43, 0x1d, 0x6c, 0x8f, 0x23, 0x47, 0xdf, 0xc1, 0x20, 0x9a, 0x3e, 0xca, 0x4a, 0xaf, 0x0a, 0x11, 0x1f, 0x47, 0xfe),
+            //   aad: vec!(0x7d, 0xd3, 0xf6, 0x56, 0xa0, 0x3c, 0x00, 0x1b, 0x45, 0xca, 0x06, 0x80, 0xbc, 0x3a, 0xc9, 0xd6, 0x8c, 0x6e, 0x96, 0xb5, 0x91, 0xd3, 0xc6, 0x9e, 0xb8, 0xc6, 0x5e, 0x48, 0x90, 0x09, 0xd8, 0x45, 0xcb, 0x33, 0x1c, 0x98, 0xb8, 0x2e, 0x62, 0x7e, 0x06, 0xd5, 0xbf, 0x01, 0xe7, 0x4c, 0x57, 0x3d, 0xf2, 0x68, 0xc2, 0x38, 0x6f, 0x12, 0x62, 0x8c, 0x01, 0x99, 0x51, 0xd4, 0x2f, 0x55, 0x99, 0x1f, 0xf2, 0x0d, 0x72, 0xa7, 0xb2, 0xc4, 0x5f, 0x41, 0xd0, 0xbe, 0x7a, 0xf4, 0x28, 0xc9, 0x2f, 0x32, 0x4a, 0xaa, 0xb8, 0xdf, 0x70, 0xd9, 0x00, 0x30, 0x1c, 0xdf, 0x09, 0xa3, 0xd9, 0x3e, 0xb7, 0x11, 0xc9, 0x19, 0xd3, 0x4a, 0x86, 0xff, 0xf9, 0xcb, 0x07, 0x83, 0x22, 0xee, 0x2e, 0x0a, 0xd4, 0x8d, 0xbd, 0xf3, 0xb7, 0x88, 0x4f, 0x0f, 0x2d, 0xc5, 0xc3, 0x62, 0x62, 0xc5, 0x9b, 0xcf, 0xd7, 0x5a, 0xc6, 0x20, 0x0f, 0x59, 0xc6, 0xfc, 0xd0, 0xce, 0x10, 0xff, 0x50, 0x05, 0xfe, 0xf5, 0xdf, 0x8f, 0x04, 0x32, 0x37, 0x7d, 0xfb, 0xfc, 0x1d, 0xb8, 0xf5, 0x59, 0xe2, 0x7e, 0x1a, 0xee, 0xf3, 0x38, 0x0e, 0xa3, 0x86, 0x48, 0x67, 0xd3, 0x6a, 0x25, 0xa1, 0x86, 0x54, 0x77, 0x9a, 0x75, 0x15, 0x86, 0xca, 0xd3, 0xb8, 0xa4, 0x6b, 0x90, 0x86, 0x4e, 0xe6, 0x97, 0xb0, 0x86, 0x05, 0x67, 0x3b, 0x8d, 0x21, 0x23, 0x43, 0x3c, 0x02, 0x0a, 0x21, 0xc4, 0xdb, 0x24, 0x3d, 0xde, 0x24, 0x20, 0xc1, 0x2f, 0xd4, 0xd5, 0x4a, 0x27, 0x04, 0xa0, 0xc8, 0xc3, 0x76, 0x45, 0x4a, 0x1b, 0x5e, 0x80, 0xfd, 0x6d, 0xb8, 0x9a, 0xab, 0xd5, 0x6d, 0x9b, 0x42, 0x1f, 0x29, 0x64, 0x9e, 0x47, 0x48, 0x24, 0xdf, 0xa5, 0x6c, 0xb5, 0xc6, 0x73, 0xc5, 0x04, 0xd1, 0x0b, 0xe5, 0x2b, 0x53, 0x75, 0x17, 0x09, 0xfe),
+            //   cipher_text: vec!(0xc4, 0x01, 0x80, 0xaf, 0xd5, 0x30, 0x01, 0x66, 0x3f, 0xf4, 0x83, 0x41, 0x10, 0xf5, 0x6e, 0x6b, 0x0f, 0x17, 0x8c, 0xd3, 0xc0, 0xe7, 0xf7, 0xde, 0x5d, 0x00, 0x89, 0xee, 0x41, 0xd8, 0x40, 0x3f, 0xfb, 0x98, 0xe8, 0x49, 0x22, 0x70, 0x65, 0x44, 0xa3, 0x44, 0xd7, 0xe2, 0x62, 0x5b, 0x12, 0xcf, 0x66, 0xb9, 0xc9, 0x66, 0xf9, 0xf5, 0x7d, 0x7b, 0x94, 0xe3, 0xe4, 0xb3, 0x4e, 0x6f, 0x0a, 0xae, 0xd1, 0x76, 0x3c, 0xe0, 0x12, 0x78, 0x2e, 0x2f, 0x5e, 0x16, 0x82, 0xe6, 0xc3, 0x43, 0xfc, 0x79, 0x61, 0xfe, 0xdd, 0xdd, 0x09, 0x19, 0xd0, 0xb9, 0x10, 0xe9, 0x92, 0x3c, 0x17, 0xe3, 0x64, 0x06, 0x97, 0x9b, 0x25, 0x6b, 0x85, 0xae, 0xc2, 0x4e, 0xe3, 0x52, 0xf0, 0x3b, 0x48, 0xc1, 0x30, 0x2e, 0xab, 0x41, 0x9c, 0x83, 0xdc, 0xcc, 0x53, 0x72, 0xcc, 0x05, 0x9e, 0x9d, 0xe5, 0x96, 0x22, 0x4f, 0xa7, 0x00, 0x98, 0xeb, 0x32, 0xfc, 0x95, 0x79, 0xe9, 0x79, 0x17, 0xb9, 0x23, 0x91, 0x4f, 0xa2, 0xef, 0xc3, 0x0a, 0xb2, 0x9b, 0x45, 0x7b, 0xf1, 0x4e, 0x45, 0x58, 0x3b, 0x37, 0x71, 0x48, 0x6b, 0xdc, 0x08, 0x76, 0xf3, 0xea, 0x6e, 0x1a, 0x64, 0x67, 0x46, 0xc4, 0xf8, 0xc5, 0xcb, 0x26, 0x41, 0xa1, 0x55, 0x7c, 0x84, 0x73, 0xe6, 0xea, 0x67, 0xd4, 0x81, 0x1a, 0x67, 0x48, 0x5a, 0xe9, 0xa6, 0x78, 0xff, 0x3a, 0x24, 0x08, 0xca, 0x84, 0x5c, 0x3b, 0x51, 0x95, 0x7e, 0x18, 0x9e, 0xef, 0x47, 0xdf, 0xc1, 0xd4, 0x6b, 0xde, 0x4b, 0x9d, 0x75, 0x4d, 0x7d, 0xf1, 0x3f, 0x82, 0x8d, 0xda, 0xdb, 0x06, 0xe4, 0xeb, 0xdd, 0xb5, 0xf0, 0xda, 0xfb, 0xdb, 0x28, 0xde, 0x4c, 0x5e, 0x60, 0x78, 0x92, 0x6f, 0x20, 0xcd, 0xf9, 0xe9, 0x7e, 0xcd, 0x58, 0xe3, 0x09, 0xe6, 0x40, 0xf7, 0x4f, 0x06),
+            //   tag: vec!(0xfd, 0x5e, 0x29, 0x33, 0x28, 0x32, 0xa1, 0x4a, 0x31, 0xa9, 0xce, 0x2c, 0xa8, 0x56, 0x84, 0x98)
+            // },
+            // TestVector{
+            //   key: [0x7d, 0x28, 0xa6, 0x08, 0x10, 0xe4, 0x3d, 0x3d, 0xfa, 0x32, 0xe9, 0x7c, 0x07, 0x95, 0x7e, 0xc0, 0x69, 0xfc, 0x80, 0xcc, 0x6a, 0x50, 0x06, 0x18, 0x30, 0xaa, 0x29, 0xb3, 0xaa, 0x77, 0x7d, 0xfc],
+            //   nonce: [0x47, 0x73, 0x8a, 0xc8, 0xf1, 0x0f, 0x2c, 0x3a],
+            //   plain_text: vec!(0xb5, 0x02, 0x78, 0xae, 0x0f, 0x0f, 0xa2, 0xf9, 0x18, 0xbb, 0x9a, 0x5e, 0xd3, 0xa0, 0x79, 0x7c, 0x32, 0x8e, 0x45, 0x29, 0x74, 0xd3, 0x3c, 0xbf, 0x26, 0xa1, 0xe2, 0x13, 0xaa, 0x20, 0xc0, 0x3d, 0x0d, 0x89, 0x49, 0x08, 0x69, 0x75, 0x4a, 0xbf, 0x84, 0xdb, 0xbe, 0x23, 0x1d, 0x7b, 0xcc, 0xdc, 0xed, 0x77, 0xd5, 0x3f, 0xd4, 0x52, 0x73, 0x56, 0xd8, 0xe0, 0x2b, 0x68, 0x1f, 0xc8, 0x9a, 0x53, 0x5a, 0xe8, 0x73, 0x08, 0xbf, 0x7f, 0xbc, 0x26, 0x19, 0x7a, 0x5e, 0xa8, 0x5b, 0xdb, 0x3a, 0xa0, 0x33, 0xb8, 0xda, 0x5c, 0xd1, 0x97, 0xea, 0x6d, 0x72, 0xf9, 0x6f, 0x63, 0xb0, 0x3f, 0x4e, 0xcc, 0x7a, 0xde, 0xdf, 0x39, 0x9a, 0x50, 0x43, 0x77, 0x6c, 0xdb, 0x32, 0xc0, 0x8f, 0x30, 0xb7, 0x7f, 0x34, 0xdf, 0x85, 0xf8, 0xad, 0xb8, 0xe0, 0x26, 0x49, 0xa0, 0x4b, 0x02, 0x0b, 0x03, 0xe1, 0x7d, 0x44, 0x5c, 0xa6, 0x3e, 0x4e, 0xd7, 0x3a, 0xe4, 0x32, 0xc4, 0x81, 0x39, 0x2e, 0x03, 0x1e, 0xba, 0x2f, 0x9d, 0x2f, 0x7f, 0x98, 0x1d, 0x1e, 0x50, 0x91, 0x78, 0x22, 0xbd, 0x6f, 0xf7, 0x1c, 0x23, 0x9d, 0x33, 0x44, 0x4a, 0xda, 0x35, 0x23, 0xa5, 0x9d, 0xfb, 0xce, 0x54, 0x57, 0xea, 0xde, 0xc1, 0xab, 0x92, 0x6c, 0x9e, 0x6c, 0x52, 0x99, 0xc7, 0x52, 0x1e, 0x3f, 0x20, 0x4b, 0x96, 0x90, 0x1a, 0x71, 0x25, 0x04, 0xfc, 0xc7, 0x82, 0xe8, 0xce, 0xa8, 0x0b, 0xa1, 0x2a, 0x7f, 0x7e, 0x71, 0xce, 0xc3, 0xd0, 0x87, 0x18, 0x99, 0xb6, 0xca, 0x05, 0x90, 0x61, 0xda, 0x03, 0x77, 0x15, 0xf7, 0xd1, 0x3f, 0xed, 0x01, 0xc9, 0xca, 0xde, 0x1e, 0x68, 0x7b, 0x4f, 0xbb, 0x1f, 0x4a, 0xc4, 0xb0, 0x40, 0xdb, 0x3b, 0x43, 0x80, 0x0f, 0x11, 0x2f, 0xb9, 0x00, 0xe4, 0xf7, 0x72, 0xd6, 0x1b, 0x92, 0x1c, 0xbc, 0xe4, 0xda, 0x6f),
+            //   aad: vec!(0x32, 0x42, 0x92, 0x81, 0x3b, 0x7d, 0xf1, 0x5b, 0xc0, 0x70, 0xcc, 0x5d, 0x8a, 0x4b, 0xf7, 0x4e, 0xad, 0x03, 0x64, 0x30, 0xbe, 0x63, 0xab, 0xc4, 0x33, 0x04, 0xcf, 0x65, 0x39, 0x59, 0xa2, 0x4a, 0x91, 0xc7, 0xde, 0x5a, 0x67, 0x1c, 0x50, 0xfa, 0x8a, 0x87, 0xe2, 0x1b, 0xb8, 0x2b, 0x06, 0x99, 0x99, 0xaa, 0xdf, 0xb6, 0x89, 0x5d, 0x8b, 0xda, 0x4c, 0x30, 0x83, 0xd1, 0x7b, 0x8c, 0xa5, 0x5b, 0x9a, 0xb1, 0x51, 0x1e, 0xd8, 0xc4, 0xb3, 0x9d, 0x8c, 0x28, 0xc1, 0x1a, 0x22, 0xef, 0x90, 0xc0, 0x8a, 0x98, 0x3e, 0x3f, 0xe2, 0xd9, 0x88, 0xdf, 0x9e, 0x02, 0xb1, 0x6a, 0x20, 0xb2, 0x4f, 0x39, 0xdd, 0xb2, 0x84, 0x29, 0x62, 0x5f, 0x51, 0x1d, 0xb0, 0x82, 0x98, 0xc4, 0xdc, 0x32, 0x1f, 0x6c, 0x26, 0x8f, 0xc8, 0x36, 0xa6, 0x19, 0x1d, 0xf6, 0x23, 0x2f, 0x51, 0xc4, 0x63, 0xa3, 0x97, 0xa8, 0xd8, 0xb3, 0x33, 0x74, 0xab, 0xe9, 0x4e, 0x62, 0xc0, 0xf5, 0xc3, 0x22, 0x38, 0x7e, 0x1f, 0xc4, 0xa1, 0xc1, 0x98, 0x0a, 0x04, 0xa1, 0xa3, 0xc2, 0xc3, 0x1b, 0x32, 0xf1, 0x83, 0xa1, 0x1c, 0x32, 0x68, 0xc6, 0xdc, 0xa5, 0x21, 0x14, 0x9d, 0xc1, 0x6a, 0xf1, 0x20, 0xa7, 0x8b, 0xe6, 0x62, 0x72, 0x10, 0xe8, 0xdd, 0xbc, 0x44, 0x47, 0x2b, 0xc2, 0x4d, 0x66, 0xce, 0x36, 0x81, 0xc7, 0x57, 0x9b, 0x3d, 0x9a, 0x42, 0x52, 0x12, 0xa7, 0x04, 0xa4, 0xf5, 0x10, 0x5c, 0xb8, 0x0f, 0x0d, 0x18, 0xee, 0x86, 0x09, 0x53, 0xd1, 0x0b, 0x59, 0xc1, 0x14, 0x82, 0x67, 0x79, 0xbb, 0xc3, 0x68, 0xd7, 0xa0, 0xee, 0xce, 0x9f, 0x22, 0x3e, 0x47, 0xcd, 0x8e, 0x5f, 0xd4, 0x53, 0x60, 0x7d, 0x10, 0x1d, 0x9d, 0x9c, 0x2b, 0xd9, 0xa6, 0x58, 0xd6, 0x52, 0x0b, 0x87, 0xd7, 0xb4, 0x26, 0x3f, 0x6d, 0x84, 0x5a, 0x52, 0x4a, 0x36, 0xe4),
+            //   cipher_text: vec!(0x2c, 0x21, 0x7e, 0x96, 0x9c, 0x04, 0x74, 0x0a, 0x1a, 0xcf, 0xa3, 0x01, 0x17, 0xeb, 0x5b, 0x32, 0xdc, 0x57, 0x3d, 0xf3, 0x35, 0x4f, 0x4c, 0xc3, 0xbf, 0x8f, 0x69, 0x6f, 0xf9, 0x05, 0xf1, 0xe6, 0x40, 0xf3, 0xb2, 0xc2, 0x50, 0x47, 0x3b, 0x37, 0x66, 0x22, 0xe0, 0xc9, 0xbd, 0xa1, 0x3b, 0x94, 0x64, 0x05, 0x21, 0xbe, 0x1e, 0xf0, 0xfc, 0x66, 0x0b, 0x4c, 0x10, 0xdb, 0xe2, 0xbf, 0xc0, 0x93, 0x03, 0x07, 0x53, 0xe0, 0x4f, 0x6a, 0xae, 0xcf, 0x81, 0x3b, 0x43, 0xb6, 0x1f, 0x96, 0x04, 0x55, 0x97, 0x4b, 0x8b, 0xb8, 0xa9, 0xb4, 0x61, 0xd1, 0xe8, 0xfd, 0x38, 0x02, 0x31, 0x5e, 0x86, 0x3c, 0x00, 0x44, 0x8f, 0x24, 0xdd, 0x38, 0xde, 0xb9, 0x0e, 0x13, 0x54, 0x93, 0x27, 0x4e, 0xb1, 0x4c, 0xcb, 0xde, 0x15, 0xc5, 0x0d, 0xca, 0xd7, 0x34, 0xed, 0x81, 0x5a, 0x80, 0x6b, 0xe6, 0x62, 0x24, 0x92, 0xa8, 0x4c, 0xd0, 0x62, 0xe3, 0xba, 0x56, 0x7b, 0x90, 0x9a, 0x20, 0x5a, 0x1d, 0x0d, 0x2b, 0xed, 0xd4, 0x01, 0x69, 0x69, 0x7d, 0x26, 0x1c, 0x7b, 0x6c, 0x2e, 0x0b, 0x1f, 0x06, 0x98, 0x53, 0xfd, 0x47, 0x0e, 0x8f, 0x36, 0x4a, 0x14, 0x2c, 0x38, 0x6c, 0x43, 0x9a, 0x6d, 0xbe, 0x19, 0x2d, 0xed, 0x5a, 0x3d, 0x0f, 0xbf, 0x73, 0x79, 0x9f, 0x58, 0x8c, 0x59, 0xe5, 0x8c, 0x60, 0x24, 0x9d, 0x98, 0x0d, 0xdc, 0xf0, 0xd9, 0x69, 0x36, 0x31, 0xcd, 0x9b, 0x3f, 0x97, 0x25, 0x09, 0xc3, 0xa7, 0x71, 0x23, 0xd3, 0x8d, 0x9e, 0x26, 0x7e, 0xca, 0xd0, 0x6e, 0x12, 0x08, 0xe3, 0xf1, 0xc0, 0xa6, 0x9f, 0xbc, 0xa7, 0xc3, 0xbb, 0x1a, 0x48, 0xfd, 0xa1, 0x94, 0x93, 0xd0, 0xf8, 0xf4, 0x83, 0x98, 0x82, 0x00, 0x57, 0xb9, 0x41, 0x20, 0xf3, 0xef, 0x97, 0xd8, 0x7e, 0x9e, 0x8a, 0x1b, 0x30, 0x1a, 0x25, 0x34, 0xc6, 0x8f),
+            //   tag: vec!(0x1f, 0xdd, 0x2d, 0xcd, 0x93, 0x5f, 0x55, 0x82, 0x2b, 0xf7, 0x23, 0x1a, 0x51, 0x6c, 0xa8, 0x41)
+            // },
+            // TestVector{
+            //   key: [0xa7, 0x6e, 0x9b, 0x91, 0x6f, 0x5a, 0x67, 0xb7, 0x8a, 0x59, 0x49, 0x65, 0x1c, 0x8c, 0x3a, 0x97, 0x41, 0xa1, 0xbc, 0x3c, 0x41, 0xcd, 0xf8, 0x5f, 0xd2, 0xc8, 0xf3, 0xe9, 0xa0, 0x61, 0x60, 0x98],
+            //   nonce: [0x08, 0x08, 0xda, 0x82, 0x92, 0xdc, 0x14, 0xe0],
+            //   plain_text: vec!(0x9c, 0x14, 0x9e, 0xeb, 0x09, 0x34, 0x5c, 0x3c, 0x22, 0x46, 0x2b, 0x03, 0xe4, 0x9e, 0xb4, 0xdb, 0xa6, 0xbc, 0x98, 0xb2, 0x69, 0xb1, 0x08, 0x6d, 0x75, 0x2b, 0xcd, 0x8e, 0xea, 0x53, 0xb8, 0x97, 0x7b, 0x23, 0x8a, 0x04, 0xa9, 0x94, 0xba, 0xf9, 0x15, 0x59, 0x16, 0x86, 0xba, 0xab, 0x90, 0xb7, 0x9a, 0x3b, 0xf7, 0xd9, 0xad, 0xb2, 0xc6, 0xc2, 0xe3, 0x1a, 0xcd, 0x3e, 0x72, 0xf0, 0x81, 0x3f, 0xb7, 0x45, 0xaa, 0x5f, 0xb2, 0xe3, 0xda, 0x40, 0x8f, 0x78, 0x00, 0x1c, 0x9c, 0x09, 0xbd, 0x26, 0xa1, 0xa2, 0x64, 0x60, 0x11, 0xb6, 0x12, 0x0a, 0xaa, 0x2b, 0xba, 0xcc, 0x4a, 0x16, 0xc3, 0x9f, 0xb5, 0x25, 0x7b, 0x9b, 0x2e, 0xa2, 0xad, 0x8b, 0xf7, 0x0b, 0xcc, 0x98, 0x55, 0xcf, 0x11, 0x84, 0x11, 0x16, 0xc2, 0x76, 0x73, 0x10, 0xcf, 0x3c, 0xd4, 0x9d, 0x1a, 0xa4, 0x4c, 0xd5, 0x05, 0xf0, 0x79, 0x76, 0x1e, 0x06, 0x4d, 0x5b, 0xc7, 0xce, 0xa4, 0xa7, 0x17, 0x3b, 0x08, 0x68, 0x82, 0xa7, 0x7d, 0x3f, 0xc1, 0x79, 0xef, 0xc8, 0x6f, 0xc4, 0xdb, 0x8a, 0x37, 0x34, 0x91, 0xd2, 0xed, 0x81, 0xea, 0xbc, 0x63, 0xc9, 0x50, 0xe8, 0x32, 0xdb, 0x17, 0xd0, 0x9f, 0x47, 0x4d, 0x4e, 0xc4, 0x6b, 0xde, 0x47, 0x83, 0x0c, 0xaf, 0x26, 0xfa, 0xba, 0xa0, 0x37, 0x2b, 0x81, 0xfc, 0xcc, 0x44, 0x9c, 0x0e, 0x19, 0xcc, 0xd6, 0x30, 0xca, 0xf6, 0x93, 0xa7, 0xb4, 0x3b, 0xb1, 0xc4, 0x08, 0xa5, 0x4e, 0x03, 0xf5, 0x0c, 0x44, 0x28, 0x0a, 0x05, 0xad, 0x89, 0xfb, 0x6e, 0x8f, 0x01, 0xd8, 0xac, 0x27, 0x8e, 0xdf, 0x55, 0x6e, 0x5d, 0x86, 0xce, 0xb4, 0xb6, 0x14, 0xfb, 0x2e, 0xf1, 0x33, 0x81, 0x9c, 0x6e, 0x1f, 0xf6, 0xab, 0xb8, 0x6c, 0x54, 0xa1, 0x35, 0x25, 0x62, 0x04, 0xb5, 0xcd, 0x40, 0x0b, 0x93, 0x62, 0x4d, 0x39, 0x32, 0xe7, 0xc2, 0xb0, 0x46),
+            //   aad: vec!(0x6a, 0xeb, 0x70, 0x31, 0xe4, 0xa2, 0xe2, 0x3e, 0xea, 0x93, 0xf0, 0x5f, 0xdc, 0x56, 0x2a, 0xa2, 0xbf, 0x43, 0xb8, 0x99, 0x8b, 0xea, 0x73, 0x44, 0x37, 0x7a, 0xad, 0xdc, 0x60, 0xfb, 0xdb, 0x7b, 0xcb, 0x14, 0x91, 0xd3, 0x79, 0xed, 0x0c, 0xb6, 0x13, 0xee, 0x75, 0x7c, 0xfb, 0x66, 0x49, 0x0d, 0xb6, 0x1b, 0xb4, 0x31, 0xd2, 0xfa, 0xd3, 0x4b, 0x38, 0xdd, 0xd5, 0x5b, 0xc5, 0xb2, 0x2a, 0xa6, 0xc4, 0x77, 0x3b, 0x99, 0x92, 0xf3, 0x4b, 0x87, 0x8c, 0x56, 0x63, 0xf6, 0xe8, 0xcd, 0xb5, 0xf8, 0x0a, 0x17, 0xf4, 0xd3, 0x12, 0xbf, 0x34, 0x24, 0x92, 0xe4, 0x8d, 0x1c, 0xe4, 0xc6, 0xd7, 0x54, 0x07, 0x6a, 0x63, 0x4f, 0xec, 0xe6, 0x15, 0x00, 0xac, 0xf8, 0x16, 0x8d, 0x47, 0x38, 0x1a, 0xf4, 0xfa, 0xf9, 0x80, 0xc6, 0xca, 0xc2, 0xbf, 0xd5, 0xda, 0x8c, 0x09, 0xb6, 0xed, 0xb0, 0xf5, 0x43, 0xbf, 0x0f, 0xe0, 0x26, 0x43, 0xe3, 0x8d, 0x73, 0xfa, 0x37, 0xd8, 0xae, 0x87, 0xfb, 0x66, 0x19, 0x3f, 0x22, 0xe5, 0x7f, 0xaf, 0x43, 0x93, 0xc0, 0x07, 0xd4, 0x8c, 0x86, 0x31, 0xa6, 0x85, 0xd5, 0x20, 0x57, 0x8f, 0x8f, 0x89, 0xdb, 0x68, 0x4f, 0xb3, 0x71, 0xea, 0x02, 0xf3, 0xa5, 0x8b, 0x1e, 0x21, 0x68, 0xf0, 0x21, 0x63, 0x21, 0x13, 0x94, 0x72, 0xe0, 0xd0, 0x3b, 0x6d, 0x90, 0xba, 0x8a, 0xab, 0x65, 0x40, 0x2e, 0x1c, 0x1a, 0xc4, 0xf9, 0x17, 0x2a, 0x60, 0xe2, 0x7e, 0x3d, 0x99, 0x7b, 0x9b, 0x05, 0xe2, 0xf6, 0x72, 0x12, 0x0d, 0x6c, 0x87, 0xbc, 0xaf, 0xa6, 0xd4, 0xc9, 0xb4, 0xcf, 0x8b, 0xa8, 0xa8, 0x29, 0x32, 0xd9, 0x28, 0x40, 0x36, 0x8f, 0xc5, 0x3d, 0xc5, 0xb4, 0x85, 0x26, 0x10, 0x3d, 0xca, 0xb5, 0xf1, 0x53, 0x10, 0x38, 0xaa, 0xbe, 0x89, 0x17, 0x13, 0x27, 0xac, 0x55, 0x9b, 0x98, 0xa3, 0xcf, 0x4e, 0xa7, 0x0b, 0xf0, 0x51),
+            //   cipher_text: vec!(0x9c, 0x3f, 0xaa, 0xb9, 0x26, 0x1a, 0x63, 0xce, 0xa9, 0x47, 0x7b, 0x32, 0x69, 0x00, 0x72, 0x83, 0x99, 0x5b, 0x06, 0xba, 0x77, 0xef, 0x83, 0xd9, 0xe6, 0x93, 0xf7, 0xe4, 0xee, 0x98, 0x55, 0x55, 0x0e, 0xef, 0x94, 0x85, 0x5b, 0xe3, 0x9a, 0x7a, 0x43, 0x5b, 0x6a, 0x35, 0x84, 0xb2, 0x02, 0x97, 0x37, 0x77, 0xc7, 0xb2, 0x48, 0x23, 0x76, 0xba, 0x47, 0xb4, 0x93, 0x11, 0x94, 0x7a, 0x64, 0x98, 0x3b, 0x60, 0x23, 0x67, 0x56, 0xee, 0x44, 0x55, 0xd4, 0xcf, 0xad, 0xa8, 0xc3, 0x6a, 0xf8, 0xeb, 0x06, 0xb0, 0x6b, 0xa2, 0xf6, 0xb7, 0x9f, 0xfb, 0x11, 0x85, 0xc8, 0x9f, 0x2b, 0x2a, 0x83, 0x1c, 0xfa, 0xa3, 0x85, 0x5f, 0xc1, 0x84, 0x1d, 0x89, 0x10, 0x90, 0x8b, 0xe5, 0x07, 0x83, 0x52, 0x01, 0x11, 0x68, 0xa6, 0x7d, 0x36, 0x37, 0x2d, 0x85, 0x1a, 0x32, 0x17, 0xca, 0xbf, 0x59, 0x3e, 0xa4, 0x62, 0xdc, 0xd3, 0x25, 0xcf, 0x9a, 0x4f, 0x67, 0xe8, 0x54, 0x18, 0xfd, 0x5c, 0x92, 0x4e, 0x9b, 0x92, 0xab, 0x02, 0x6c, 0xbe, 0xe4, 0xe7, 0xab, 0x10, 0x67, 0x06, 0x6c, 0xb5, 0x94, 0x9d, 0xfc, 0x69, 0x9a, 0x68, 0xfe, 0x53, 0x9e, 0x1a, 0xbb, 0x13, 0xce, 0xc3, 0x39, 0x04, 0xe5, 0x20, 0x7e, 0x69, 0x63, 0xd2, 0x4f, 0x5a, 0x0b, 0x77, 0x06, 0x13, 0xb8, 0xb0, 0x00, 0x14, 0xe7, 0x91, 0xbf, 0xff, 0x88, 0xf9, 0xc2, 0x5c, 0xa1, 0x26, 0x12, 0x7a, 0x2f, 0x8d, 0x1d, 0x1e, 0x97, 0x94, 0xef, 0xd2, 0x8d, 0xce, 0x98, 0xb5, 0x3e, 0x22, 0x80, 0x73, 0xfa, 0xae, 0x8d, 0x50, 0x47, 0x53, 0x0d, 0x50, 0x21, 0x84, 0xfc, 0x34, 0x13, 0x21, 0xc3, 0xf5, 0x5f, 0xcb, 0xf4, 0x11, 0x87, 0xfc, 0x31, 0x26, 0x2c, 0x32, 0x5b, 0x97, 0xf5, 0x19, 0x95, 0x9b, 0x6a, 0x29, 0xb3, 0x6c, 0x71, 0xf7, 0x6f, 0x60, 0x19, 0x6b, 0xb1, 0x45, 0x7b, 0x77, 0xc8, 0xbb),
+            //   tag: vec!(0xb4, 0x5d, 0xf1, 0x19, 0x04, 0x3d, 0x29, 0x00, 0x8f, 0xce, 0xf3, 0x6a, 0x16, 0x9e, 0xf8, 0x86)
+            // },
+            // TestVector{
+            //   key: [0x98, 0xcd, 0x24, 0x77, 0xa7, 0xa0, 0x72, 0xc6, 0x9f, 0x37, 0x5b, 0x88, 0xd0, 0x9e, 0xd9, 0xd7, 0xb9, 0xc3, 0xdf, 0x3f, 0x87, 0xe3, 0x6c, 0xe6, 0x21, 0x72, 0x6f, 0x76, 0xe3, 0xb4, 0x1a, 0x1d],
+            //   nonce: [0x77, 0xd1, 0x85, 0xaa, 0xf7, 0x15, 0xaa, 0x48],
+            //   plain_text: vec!(0x42, 0xb3, 0x1e, 0xef, 0xda, 0xca, 0xb0, 0xf0, 0x3e, 0xf6, 0x06, 0x01, 0x56, 0x00, 0x0c, 0x81, 0x95, 0xad, 0xb0, 0x97, 0x6c, 0xab, 0xbe, 0x1a, 0x42, 0xbf, 0xcc, 0x09, 0xf8, 0x56, 0x59, 0xc6, 0x0b, 0x98, 0x63, 0x84, 0x01, 0xf2, 0xd2, 0xe2, 0xfa, 0xcf, 0xb9, 0xa9, 0x7a, 0x62, 0x92, 0x6b, 0xb0, 0xce, 0xca, 0xf3, 0xaf, 0x01, 0x80, 0xa0, 0x1b, 0xfb, 0x6e, 0x57, 0x6b, 0xab, 0xf7, 0xfc, 0x43, 0x33, 0x19, 0x37, 0xa9, 0x2a, 0xbd, 0x30, 0xcd, 0xdf, 0xa3, 0xe4, 0x50, 0xf8, 0x95, 0xe9, 0xdd, 0x91, 0x4d, 0xea, 0x3f, 0xaf, 0xd7, 0x59, 0xc1, 0x36, 0xd6, 0x85, 0x31, 0x0e, 0xbc, 0xe2, 0x8a, 0xc0, 0x61, 0x3c, 0xcd, 0xbf, 0x30, 0x11, 0x59, 0x46, 0xc9, 0x63, 0x4b, 0x67, 0x51, 0x0b, 0x77, 0xd0, 0xe3, 0x7f, 0x07, 0x71, 0x4b, 0x2d, 0xda, 0xc9, 0xd7, 0x09, 0x5b, 0x8d, 0x4b, 0xd8, 0x87, 0xc1, 0x32, 0xc4, 0xa9, 0x12, 0x7e, 0xb0, 0x1c, 0x8d, 0xed, 0xb4, 0xc3, 0x9c, 0x87, 0xb9, 0x8a, 0x74, 0x13, 0x16, 0x65, 0x6f, 0x9a, 0x8d, 0x5a, 0x5b, 0x0c, 0x0a, 0xc8, 0x47, 0x89, 0xaa, 0x23, 0x47, 0xa5, 0xf9, 0x9c, 0xa5, 0xad, 0x55, 0xcd, 0x1b, 0xcf, 0x98, 0xf7, 0x03, 0xeb, 0x4b, 0x00, 0xba, 0xdb, 0x8a, 0x85, 0x55, 0xf3, 0x8b, 0x3b, 0x36, 0x8d, 0xb8, 0xba, 0x7c, 0xee, 0xa9, 0x4e, 0x8b, 0x21, 0x9f, 0x51, 0xed, 0xce, 0x75, 0xd8, 0x41, 0x66, 0xb5, 0x60, 0x21, 0x56, 0xed, 0x59, 0x62, 0xa9, 0x3a, 0x51, 0xdb, 0x73, 0xc5, 0x9d, 0x87, 0xe9, 0x06, 0x17, 0x9d, 0x7a, 0x74, 0xa2, 0xa2, 0xa6, 0x9d, 0x8a, 0xd9, 0x9f, 0x32, 0x32, 0x25, 0xc8, 0x7e, 0x47, 0x5d, 0x3f, 0x77, 0x1b, 0x4a, 0x20, 0x3a, 0x2e, 0x2b, 0x03, 0xb4, 0x58, 0x40, 0x10, 0x44, 0x64, 0x9f, 0xa6, 0x53, 0x6d, 0xfa, 0xb2, 0x4d, 0x70, 0x37, 0x80, 0x7d, 0xcb, 0xf6, 0x51, 0x8e, 0x65, 0x78),
+            //   aad: vec!(0xf5, 0xbb, 0x14, 0x96, 0x05, 0x2a, 0x43, 0x61, 0xdd, 0xdf, 0x72, 0xa2, 0x88, 0xe3, 0x69, 0x53, 0xa3, 0xd8, 0x15, 0xd6, 0x87, 0x6c, 0x01, 0x3f, 0x1d, 0x6b, 0xa8, 0x39, 0xe1, 0x27, 0xf7, 0x21, 0xb0, 0x52, 0xb1, 0xf7, 0xd8, 0xca, 0x20, 0xc7, 0xdc, 0x03, 0x86, 0xa7, 0xd4, 0x59, 0xeb, 0xd7, 0xeb, 0x9f, 0xc8, 0xcb, 0x08, 0x94, 0x1e, 0x6c, 0xa9, 0xdd, 0xb9, 0x80, 0xf3, 0x11, 0x5f, 0x65, 0xbc, 0x19, 0x28, 0xa4, 0x14, 0xd4, 0x41, 0xae, 0x71, 0xdc, 0xb8, 0x79, 0xd5, 0xbf, 0xe0, 0xcd, 0xe0, 0x56, 0x2b, 0xc3, 0x7f, 0x8f, 0xde, 0x0d, 0x52, 0x91, 0xad, 0x40, 0x5c, 0x92, 0xfc, 0xbb, 0x86, 0x0c, 0x43, 0xb5, 0x5a, 0xc0, 0xfe, 0x66, 0x3b, 0x54, 0xb3, 0xd0, 0x61, 0x6a, 0xca, 0x13, 0xa5, 0xc8, 0x2b, 0x7b, 0x5d, 0x34, 0x12, 0x5a, 0x05, 0xc2, 0xac, 0xb5, 0x53, 0x01, 0x41, 0x03, 0x0e, 0x6f, 0x2a, 0xa0, 0xc8, 0x32, 0x2b, 0x2c, 0x8f, 0xa3, 0x07, 0xe7, 0x51, 0x89, 0x18, 0xe5, 0x50, 0xe9, 0xf4, 0x89, 0x21, 0xc6, 0x16, 0x8f, 0x09, 0x4d, 0x87, 0x58, 0xe1, 0x6b, 0x9f, 0x81, 0x5f, 0xd0, 0x45, 0x80, 0x95, 0xc4, 0x14, 0x3f, 0x09, 0x22, 0xad, 0xb1, 0x84, 0x0d, 0x0e, 0x68, 0x56, 0x36, 0x82, 0x5a, 0x9c, 0x90, 0xee, 0x90, 0xee, 0x53, 0x7f, 0x4b, 0x8d, 0xce, 0xec, 0xbc, 0x42, 0x87, 0xc8, 0x2d, 0xc9, 0xa0, 0x0d, 0x7e, 0x51, 0x67, 0x1e, 0x37, 0xea, 0x28, 0x4e, 0xe3, 0xca, 0x50, 0x1b, 0x1b, 0x25, 0x96, 0x45, 0x9d, 0x3f, 0x59, 0x2f, 0x70, 0x18, 0x6f, 0x41, 0x12, 0x57, 0x39, 0xe3, 0x42, 0xc9, 0xf6, 0xbe, 0x92, 0x41, 0x97, 0x3b, 0x14, 0x14, 0xdf, 0xe5, 0xfb, 0x8c, 0xba, 0x1a, 0xf8, 0x2e, 0x67, 0x92, 0x78, 0xcf, 0xcf, 0x95, 0x42, 0x0d, 0xf0, 0xc5, 0x36, 0x4a, 0xf4, 0xd7, 0xe7, 0x2a, 0xd5, 0x7d, 0x5c, 0x87, 0x1f, 0xcb, 0xc3, 0x54, 0x62),
+            //   cipher_text: vec!(0x7a, 0x3b, 0xf3, 0xe3, 0xad, 0x5a, 0xe3, 0xab, 0x71, 0xfb, 0x1f, 0x71, 0x21, 0xc3, 0xd8, 0xfb, 0x51, 0x10, 0x99, 0x48, 0x4b, 0x50, 0xaf, 0x7c, 0xa1, 0x28, 0xee, 0x03, 0x37, 0xed, 0x4b, 0x82, 0x8d, 0xc4, 0xcd, 0xe0, 0xb8, 0x8d, 0xc1, 0xe8, 0x08, 0x91, 0x01, 0xfa, 0x82, 0xc9, 0xbe, 0xb3, 0xeb, 0x48, 0xfd, 0xcf, 0x0f, 0x5b, 0x16, 0xda, 0x44, 0x1f, 0x5a, 0x3f, 0xce, 0x9a, 0x59, 0x00, 0x22, 0xaf, 0x95, 0xa9, 0x4a, 0xed, 0x6a, 0x3e, 0x71, 0xe5, 0x05, 0xf6, 0x0f, 0x30, 0x3c, 0x78, 0xc3, 0x56, 0xf2, 0x74, 0xea, 0x85, 0xa5, 0x53, 0x54, 0x07, 0x85, 0x30, 0x66, 0x4e, 0xcd, 0xa3, 0x2c, 0x80, 0xe7, 0x7d, 0xc2, 0x09, 0x74, 0xb3, 0xb3, 0x8f, 0x48, 0x25, 0xb8, 0xfb, 0xee, 0x8c, 0x39, 0x70, 0x76, 0x9a, 0x2f, 0x42, 0xc5, 0x18, 0x16, 0x08, 0xa8, 0xd7, 0xd7, 0x6e, 0xf4, 0xd0, 0x93, 0x96, 0x1b, 0x66, 0x5e, 0xe4, 0x2b, 0x97, 0x08, 0xfc, 0xaf, 0xe2, 0xc8, 0x2d, 0x3a, 0x30, 0x71, 0x73, 0xe2, 0xa2, 0x5a, 0xd2, 0x52, 0x8c, 0x3b, 0xf8, 0x33, 0x52, 0xb9, 0x26, 0x5e, 0x45, 0xb7, 0x07, 0x22, 0xd7, 0xcf, 0x8c, 0x9b, 0x80, 0x82, 0x6d, 0x21, 0x33, 0x52, 0x34, 0xee, 0x3d, 0xb6, 0x9d, 0x0d, 0x37, 0x87, 0x1c, 0x83, 0x22, 0x23, 0x65, 0x90, 0x0c, 0x96, 0xc1, 0x7a, 0x7e, 0x9f, 0x57, 0x42, 0xd0, 0xbf, 0xe3, 0x83, 0xbe, 0x24, 0xd0, 0xd4, 0x45, 0x90, 0xd4, 0xb0, 0xf2, 0x9f, 0x7a, 0xbe, 0x0c, 0x65, 0xda, 0xaf, 0xfb, 0x96, 0x8b, 0x3f, 0x26, 0x57, 0xb1, 0xeb, 0x30, 0x05, 0x34, 0xea, 0xcb, 0x52, 0xec, 0x7a, 0x6b, 0x6f, 0x9f, 0x57, 0xa5, 0x0a, 0x91, 0xb1, 0x79, 0x9f, 0x49, 0x13, 0x61, 0xcf, 0x61, 0x3c, 0x93, 0x4b, 0x7f, 0x52, 0x0d, 0xc4, 0xee, 0xeb, 0x40, 0xff, 0xc4, 0x5e, 0x10, 0xbe, 0x0a, 0x95, 0xe7, 0x6f, 0x36, 0x6d, 0x4e, 0xac, 0x14),
+            //   tag: vec!(0xf6, 0x13, 0xb6, 0x52, 0x26, 0xaf, 0xb6, 0x4c, 0x61, 0x4f, 0xe6, 0x0d, 0x9c, 0x71, 0xed, 0x74)
+            // },
+            // TestVector{
+            //   key: [0x2f, 0x0f, 0x46, 0x31, 0xab, 0x1c, 0x1b, 0xcf, 0x8f, 0x3a, 0xd0, 0x55, 0x9c, 0x81, 0x8d, 0x50, 0xe0, 0xaf, 0x7d, 0x8c, 0xd6, 0x3f, 0xaa, 0x35, 0x7f, 0x20, 0x69, 0xf3, 0x08, 0x81, 0xd9, 0xcb],
+            //   nonce: [0x7d, 0x0c, 0xed, 0x2f, 0xdb, 0x1c, 0x91, 0x73],
+            //   plain_text: vec!(0x65, 0x16, 0xba, 0x1d, 0x29, 0x35, 0x71, 0x44, 0xee, 0xbf, 0xa4, 0x86, 0xd2, 0x1d, 0xec, 0xf2, 0x23, 0xda, 0x3a, 0xa7, 0x6e, 0xc2, 0x9b, 0xbf, 0xcb, 0xe7, 0xf1, 0xee, 0xaf, 0x4a, 0x84, 0x77, 0x10, 0xe5, 0x08, 0x01, 0x77, 0xf7, 0xe5, 0xa7, 0xc8, 0xb4, 0x75, 0x2c, 0x21, 0x9b, 0x1c, 0xc7, 0x0a, 0xef, 0x4d, 0xb8, 0x61, 0xba, 0x67, 0xd0, 0xfa, 0x62, 0x22, 0xd9, 0xf4, 0xa1, 0xdc, 0x75, 0x6a, 0x0b, 0xa4, 0x4e, 0x62, 0x90, 0x6f, 0x93, 0x74, 0xa9, 0x60, 0xc1, 0x61, 0x98, 0x86, 0x6d, 0x86, 0x78, 0x54, 0xd8, 0x8f, 0x52, 0x8a, 0x60, 0xe2, 0x12, 0xeb, 0x91, 0x64, 0x57, 0x87, 0xe7, 0x56, 0x85, 0xb2, 0xe2, 0x15, 0xc0, 0xa4, 0x19, 0x90, 0xab, 0xc3, 0x44, 0xa7, 0x72, 0x36, 0xec, 0x01, 0x86, 0xba, 0x63, 0xa6, 0x64, 0x59, 0x29, 0x38, 0xcc, 0x5a, 0x8a, 0xc1, 0xd3, 0xeb, 0x99, 0xc9, 0x5c, 0xe0, 0x0e, 0x19, 0xfb, 0xe2, 0x49, 0x26, 0x30, 0x83, 0xd8, 0x5b, 0x05, 0x2d, 0x48, 0xbf, 0xdf, 0xfc, 0x01, 0x58, 0x5d, 0xc5, 0x7b, 0xb2, 0xa2, 0xc6, 0xc4, 0xa8, 0x19, 0x60, 0x4c, 0x1e, 0xc0, 0x54, 0x8c, 0x6f, 0x0f, 0x78, 0xdc, 0x05, 0xe4, 0x41, 0x8b, 0x36, 0x27, 0x7d, 0xc0, 0x72, 0x33, 0xc7, 0x53, 0x2f, 0x9c, 0x28, 0x9d, 0x6a, 0xed, 0x0c, 0xc6, 0xbc, 0x7d, 0xf4, 0xfd, 0x0a, 0x53, 0x6c, 0x49, 0x7b, 0x98, 0x2e, 0x2d, 0xad, 0x2c, 0x30, 0xd2, 0xdb, 0x1c, 0x65, 0x45, 0xa8, 0x45, 0xc5, 0xdf, 0xa8, 0x3a, 0x4a, 0xc4, 0x9e, 0xf0, 0x6f, 0xc9, 0xc9, 0x19, 0x07, 0x9d, 0x3e, 0x29, 0x9e, 0x31, 0xb5, 0xc3, 0xbe, 0x37, 0x08, 0x14, 0xae, 0x50, 0x22, 0xae, 0x46, 0x9d, 0x3e, 0xe5, 0x52, 0x46, 0xa4, 0x1b, 0xd0, 0xdc, 0x4e, 0x64, 0x35, 0x1c, 0xc3, 0x8c, 0x3c, 0x09, 0xaf, 0x0a, 0x1a, 0xee, 0x3b, 0x38, 0x8a, 0x68, 0x92, 0xde, 0xff, 0x0d, 0xf3, 0xf9, 0x3c, 0xd9, 0x2d, 0x72, 0x2b),
+            //   aad: vec!(0x1c, 0xcf, 0xa1, 0xec, 0xec, 0xc8, 0xde, 0x1e, 0x20, 0x0d, 0x0e, 0xcc, 0x19, 0xdc, 0xf6, 0x7b, 0x7c, 0x96, 0xbe, 0xa3, 0xa2, 0x82, 0xc2, 0xbc, 0xcb, 0xa6, 0x10, 0x35, 0xdb, 0x5c, 0x14, 0x77, 0x63, 0x87, 0xb8, 0xb8, 0xf5, 0x8e, 0x57, 0x57, 0xde, 0xb0, 0x12, 0x9d, 0x4e, 0x5e, 0x31, 0x5f, 0x64, 0xdf, 0x35, 0x4a, 0x59, 0x85, 0xd2, 0xe4, 0x7e, 0xbb, 0xbe, 0xaf, 0xe0, 0xc9, 0x14, 0xf7, 0xcf, 0x1d, 0x63, 0xdd, 0x03, 0x11, 0xac, 0xe1, 0x9e, 0x69, 0xa8, 0xb6, 0xff, 0x0a, 0xb2, 0x5c, 0xc8, 0xdf, 0x04, 0x08, 0xd2, 0x21, 0x32, 0x20, 0x5e, 0x89, 0xe5, 0xeb, 0x67, 0x92, 0x68, 0xd8, 0x2b, 0x29, 0x13, 0xe6, 0x4e, 0x3f, 0x88, 0x5b, 0xbf, 0x4a, 0x6d, 0x37, 0x9b, 0x76, 0x0b, 0x94, 0x59, 0x0e, 0x31, 0x40, 0xdd, 0x72, 0x75, 0xab, 0x47, 0x13, 0xcb, 0x56, 0xd0, 0xb7, 0x16, 0xe2, 0x71, 0x8f, 0x11, 0x31, 0x66, 0x40, 0xcb, 0x39, 0x48, 0x02, 0x86, 0x2d, 0x39, 0xe7, 0x7a, 0x46, 0xd0, 0xc0, 0x65, 0xaf, 0x3c, 0xaf, 0x7d, 0xec, 0x14, 0xe8, 0x87, 0x03, 0x9d, 0x8a, 0xa8, 0xc3, 0xd3, 0xa8, 0xac, 0x1e, 0xe0, 0x60, 0x26, 0xf4, 0x9d, 0x00, 0xb2, 0xf5, 0x9d, 0x97, 0x1b, 0x54, 0x73, 0x5e, 0x95, 0xa5, 0x1f, 0x19, 0x93, 0x89, 0xa9, 0x3a, 0x4f, 0xc2, 0x4e, 0xba, 0xba, 0x1f, 0x7a, 0x2e, 0xef, 0x74, 0x12, 0xf6, 0x1f, 0xeb, 0xf7, 0x90, 0x84, 0xfb, 0xf4, 0x81, 0xaf, 0xc6, 0xfb, 0x6b, 0x20, 0x40, 0x84, 0xe5, 0xef, 0x5d, 0xf7, 0x1f, 0x30, 0x50, 0x64, 0x59, 0xde, 0xa0, 0x74, 0xf1, 0x1f, 0xc0, 0x55, 0xcd, 0x2a, 0x8c, 0x0f, 0xc9, 0x22, 0xc4, 0x81, 0x1a, 0x84, 0x99, 0x84, 0x35, 0x2a, 0x56, 0xa1, 0x56, 0x59, 0xb7, 0xd0, 0x7a, 0x4c, 0xc9, 0x0b, 0x88, 0x62, 0x36, 0x38, 0xea, 0x00, 0xc4, 0xc8, 0xbc, 0x13, 0x88, 0x4d, 0xf2, 0x23, 0x7b, 0x35, 0x9f, 0x28, 0x77, 0xaa, 0x41, 0xd6),
+            //   cipher_text: vec!(0xe5, 0x80, 0x09, 0x37, 0x89, 0xba, 0x17, 0xff, 0xb4, 0x66, 0x72, 0xdc, 0x32, 0x6f, 0x09, 0x27, 0x8a, 0xca, 0x08, 0x59, 0x8d, 0x3e, 0x54, 0x58, 0xea, 0xa5, 0x3e, 0x6e, 0xd4, 0x5d, 0x5c, 0x71, 0xa3, 0x96, 0xe3, 0x5b, 0x5e, 0xa3, 0xfe, 0x7b, 0x7c, 0x04, 0x96, 0xa7, 0x34, 0xd2, 0x4f, 0x1c, 0x75, 0x42, 0x06, 0x94, 0xbe, 0x2f, 0xf0, 0x95, 0xd5, 0x17, 0x2f, 0xd3, 0x40, 0x77, 0x94, 0xe4, 0xb9, 0x9f, 0xd7, 0xc3, 0x74, 0xfb, 0xe8, 0xd1, 0x56, 0x4a, 0x04, 0x86, 0x14, 0xd3, 0xf3, 0x55, 0xbf, 0xb5, 0x86, 0x6d, 0xe1, 0xa5, 0x3e, 0x1a, 0x51, 0xf9, 0xf5, 0xe8, 0x31, 0x22, 0x53, 0xcf, 0xd8, 0x2f, 0x36, 0xef, 0xaa, 0x18, 0x98, 0xc8, 0x50, 0xca, 0x0d, 0x97, 0x5a, 0xd1, 0xe8, 0xb0, 0xd9, 0x59, 0x7a, 0x5a, 0x9e, 0x65, 0x16, 0xfe, 0x2a, 0x3c, 0x92, 0xef, 0xb7, 0x49, 0x55, 0x57, 0xa8, 0xaf, 0xc3, 0xda, 0x15, 0xb0, 0xd3, 0xe2, 0xba, 0x58, 0xf6, 0x12, 0x51, 0x98, 0x36, 0x94, 0x6c, 0xf2, 0xd1, 0x5b, 0x89, 0x83, 0x20, 0xd1, 0x6a, 0x02, 0x6c, 0x8c, 0x00, 0xa1, 0xbe, 0x2e, 0x35, 0xf0, 0xeb, 0xe6, 0x8f, 0x28, 0xd9, 0x1c, 0x6c, 0x45, 0xd2, 0x4c, 0x3f, 0x3c, 0x15, 0x7c, 0xb1, 0x32, 0xfa, 0x65, 0x9b, 0x77, 0x94, 0xdf, 0x88, 0x3d, 0x90, 0x74, 0x1f, 0xa2, 0xd2, 0xaf, 0xcc, 0x4f, 0x27, 0x85, 0x8e, 0x13, 0xec, 0xd4, 0x1b, 0x15, 0x4a, 0x35, 0xd2, 0x49, 0x47, 0xae, 0x73, 0x61, 0x17, 0x00, 0x60, 0xc1, 0x07, 0xd8, 0xec, 0xac, 0xb3, 0x93, 0xea, 0x67, 0x10, 0x4b, 0x60, 0x45, 0x72, 0x78, 0xa3, 0x92, 0xfd, 0xf1, 0x79, 0x4b, 0xab, 0x97, 0xd3, 0xb0, 0x2b, 0x71, 0xa4, 0xeb, 0x01, 0x5e, 0xaa, 0x38, 0xa4, 0xb4, 0xc9, 0x44, 0xc2, 0xbc, 0x7c, 0xd5, 0xe3, 0x29, 0xda, 0x4a, 0x1a, 0xb2, 0x93, 0x7a, 0x6a, 0xf8, 0x1a, 0x6c, 0xaa, 0x5f, 0xce, 0x75, 0x23, 0x31, 0xfd, 0xef, 0xd4),
+            //   tag: vec!(0x0f, 0xd7, 0x41, 0x9c, 0x54, 0xbc, 0x84, 0x26, 0x5e, 0xd3, 0x10, 0xa3, 0x41, 0x1a, 0x3f, 0x2e)
+            // },
+            // TestVector{
+            //   key: [0xa4, 0x8b, 0x9b, 0x6d, 0xf4, 0x75, 0xe5, 0x66, 0xab, 0xa7, 0x67, 0x1f, 0xbd, 0x76, 0x77, 0x2c, 0xb0, 0xef, 0xf0, 0xb1, 0x24, 0x99, 0x96, 0x79, 0x78, 0xce, 0x3e, 0x25, 0xfa, 0xc9, 0x2f, 0xeb],
+            //   nonce: [0x2c, 0xcb, 0xf0, 0xd6, 0xc4, 0x0c, 0xb3, 0x02],
+            //   plain_text: vec!(0x09, 0xda, 0x1c, 0xac, 0xd0, 0x01, 0xdc, 0xe4, 0xf7, 0x57, 0x3a, 0x06, 0x5a, 0x44, 0x06, 0xfe, 0x0d, 0xa0, 0x4a, 0xb3, 0x67, 0xa2, 0xd8, 0x77, 0x80, 0xa2, 0x76, 0x2e, 0x16, 0x89, 0x57, 0xa8, 0x8d, 0x3f, 0xa7, 0x8f, 0x0a, 0x4b, 0x69, 0x78, 0xd4, 0x49, 0x02, 0x6e, 0x5a, 0x80, 0x1d, 0x32, 0x88, 0x4b, 0x6e, 0x14, 0xfd, 0xaa, 0xaf, 0x86, 0x42, 0x14, 0xf9, 0x28, 0xeb, 0xc0, 0x3d, 0xea, 0xd0, 0x81, 0xfe, 0xe9, 0x66, 0x83, 0xeb, 0xb0, 0x32, 0x36, 0x2d, 0x50, 0x88, 0xc4, 0xc2, 0xa3, 0xb1, 0xe2, 0x42, 0xf0, 0x55, 0xf2, 0x60, 0x49, 0x19, 0xf4, 0xdd, 0x55, 0x1d, 0xb7, 0x77, 0xa2, 0x58, 0xcf, 0x9d, 0xa6, 0xd9, 0x5a, 0x2b, 0xde, 0x24, 0x92, 0x47, 0x81, 0x2b, 0x9e, 0xfc, 0x79, 0x85, 0xcf, 0x08, 0x70, 0x76, 0x20, 0x80, 0x85, 0x24, 0xd6, 0xdd, 0x30, 0x79, 0xb0, 0xb6, 0x3b, 0xf0, 0xf7, 0x1e, 0xa5, 0xde, 0x83, 0x4c, 0xcb, 0x8b, 0x7c, 0x6a, 0x97, 0x12, 0x5f, 0xd6, 0xca, 0x49, 0x14, 0x8e, 0x86, 0x6d, 0x31, 0x34, 0xbb, 0xf1, 0xd8, 0xa6, 0xb7, 0x14, 0xe9, 0xa8, 0x0f, 0xe5, 0x49, 0xc8, 0xbf, 0xef, 0xe3, 0x42, 0xf4, 0x1b, 0xe2, 0xba, 0x23, 0x00, 0xe0, 0x02, 0x8f, 0x78, 0xce, 0xfa, 0xb6, 0x52, 0x74, 0x63, 0x2d, 0xfd, 0xbe, 0x70, 0xbf, 0x7d, 0x65, 0x5e, 0xc4, 0x03, 0x6d, 0xf5, 0x61, 0xf2, 0xd4, 0xfc, 0x4d, 0x56, 0xa4, 0x82, 0xbb, 0xe2, 0xf9, 0xf2, 0xae, 0x27, 0x9b, 0x3a, 0xa2, 0x16, 0xb3, 0x9a, 0xfe, 0xe7, 0x5e, 0x53, 0x60, 0x2d, 0xe3, 0x19, 0x48, 0x4d, 0xb8, 0x9a, 0x51, 0xe8, 0x44, 0xf3, 0x8c, 0x36, 0x16, 0x34, 0xe4, 0x74, 0xf8, 0xf1, 0xf0, 0x1c, 0x34, 0x0f, 0x3f, 0x35, 0x94, 0x86, 0x0d, 0x67, 0x13, 0x46, 0x44, 0x9c, 0x6d, 0x08, 0xee, 0x38, 0xde, 0x22, 0xd2, 0x46, 0x30, 0x9b, 0xc7, 0xe4, 0xa2, 0x52, 0xa2, 0x9c, 0x86, 0xaa, 0x6d, 0x94, 0xb5, 0xb4, 0xfa, 0x58, 0x90, 0x4c, 0x70),
+            //   aad: vec!(0x1c, 0x25, 0x03, 0xd5, 0xaa, 0x1a, 0xad, 0x19, 0x3f, 0x0d, 0xa1, 0x28, 0x74, 0x07, 0x4e, 0xa0, 0x43, 0x2b, 0xb7, 0x6a, 0x61, 0xcd, 0x43, 0xa3, 0x01, 0x70, 0x61, 0x51, 0x4d, 0xa0, 0x75, 0x98, 0x46, 0xa0, 0xf3, 0xae, 0x3a, 0x49, 0xfd, 0xb0, 0xb6, 0xd2, 0x9f, 0x71, 0x3d, 0xe6, 0x65, 0xbe, 0xac, 0xb6, 0x56, 0x8f, 0x26, 0x94, 0x11, 0x2c, 0xa3, 0x80, 0xd1, 0x3f, 0x3c, 0x16, 0x98, 0x31, 0x68, 0x66, 0xa7, 0xa7, 0xf8, 0x7f, 0x1d, 0x75, 0x03, 0xa9, 0x21, 0x76, 0xab, 0x84, 0xfc, 0x08, 0x97, 0x7b, 0x46, 0xba, 0x66, 0x45, 0x08, 0xa8, 0x58, 0xe7, 0x52, 0x57, 0x53, 0xc4, 0x55, 0x11, 0xb3, 0xd2, 0xf4, 0x07, 0xd5, 0xe9, 0x93, 0xc6, 0xed, 0xe7, 0x7f, 0x13, 0xd1, 0x29, 0x75, 0x70, 0x7e, 0x51, 0x95, 0x70, 0x49, 0x70, 0xa8, 0x9f, 0x71, 0xfc, 0x30, 0x82, 0x80, 0x49, 0xf9, 0x2f, 0x94, 0x4f, 0x3a, 0xa9, 0x3d, 0x6a, 0x52, 0x97, 0xe6, 0x78, 0xe0, 0x89, 0x52, 0x91, 0x9b, 0xeb, 0x7e, 0xac, 0x59, 0x19, 0xdf, 0x19, 0x19, 0xca, 0xb3, 0xc3, 0xda, 0x6a, 0xa6, 0x96, 0xa1, 0xee, 0xab, 0x63, 0x71, 0xf3, 0x10, 0xf7, 0xe8, 0x11, 0x43, 0xe7, 0xd2, 0x40, 0xb0, 0x21, 0x3a, 0xe5, 0x54, 0x52, 0x4b, 0x52, 0x00, 0x03, 0x06, 0x16, 0x0d, 0xd4, 0x87, 0x7b, 0xf1, 0x3b, 0xa0, 0xf1, 0x3b, 0xbe, 0x86, 0x7d, 0xa7, 0xc7, 0xd7, 0x07, 0xf3, 0x13, 0x35, 0xee, 0xf4, 0xcd, 0x94, 0x29, 0x38, 0xac, 0x89, 0x0a, 0x08, 0x29, 0xec, 0x66, 0xbd, 0x30, 0xae, 0x01, 0xa2, 0x18, 0x8a, 0x6e, 0x5e, 0xa0, 0xf1, 0x7c, 0xd7, 0xdc, 0x87, 0x5e, 0x17, 0xf0, 0x3c, 0x0a, 0xb5, 0xdd, 0x18, 0xe3, 0x6d, 0xb8, 0xa1, 0xfc, 0x1f, 0x72, 0x85, 0x9e, 0xe0, 0x46, 0xb6, 0x23, 0x68, 0xf1, 0x68, 0xb3, 0xbe, 0xa2, 0x23, 0x4e, 0x04, 0x32, 0xc0, 0x7b, 0x7d, 0x8e, 0x1b, 0x92, 0x77, 0xf2, 0x1e, 0x69, 0x2c, 0x51, 0x3b, 0x9e, 0x81, 0x6e, 0x68, 0x60),
+            //   cipher_text: vec!(0x7d, 0x35, 0xcf, 0xe4, 0xbe, 0x56, 0xbd, 0x6e, 0x0e, 0x09, 0xde, 0xdc, 0xd0, 0x17, 0x35, 0xb9, 0x15, 0xbc, 0x18, 0x91, 0xa4, 0xd1, 0xf6, 0xa5, 0x41, 0xab, 0xc4, 0xbc, 0xd0, 0xeb, 0xe8, 0x9d, 0xcb, 0x8e, 0x36, 0x5e, 0x58, 0x13, 0x74, 0x2e, 0x8e, 0xc6, 0x57, 0x77, 0xb6, 0x15, 0x94, 0x22, 0xfa, 0xda, 0x74, 0x7d, 0xa9, 0x93, 0x94, 0x25, 0x2b, 0xaf, 0x8a, 0x04, 0x6f, 0xc1, 0xb6, 0x0a, 0xd7, 0x97, 0x55, 0xf5, 0x45, 0xf4, 0x44, 0x86, 0x27, 0xb7, 0xac, 0xaf, 0x40, 0x30, 0x00, 0x89, 0x4f, 0x56, 0x41, 0xe7, 0x8d, 0x3f, 0x94, 0x6d, 0xfc, 0xa2, 0x9e, 0xc6, 0x17, 0xf0, 0x66, 0x0d, 0xcd, 0x6e, 0x8d, 0x88, 0x27, 0xe6, 0x7e, 0x10, 0x22, 0xa2, 0x45, 0xc5, 0x95, 0xd8, 0x6e, 0x60, 0xfb, 0xd1, 0x76, 0xbf, 0x72, 0x1b, 0x17, 0x1b, 0xbe, 0x5e, 0xca, 0xf4, 0xae, 0x67, 0x1b, 0x9f, 0x3d, 0xd3, 0x92, 0x01, 0x46, 0xe6, 0xad, 0x43, 0x1b, 0xd8, 0xfc, 0x43, 0x18, 0x20, 0xe1, 0x94, 0x54, 0xb6, 0xca, 0x20, 0x97, 0x23, 0xd8, 0x0f, 0xdb, 0xee, 0x18, 0x7f, 0xca, 0x9c, 0x93, 0x7c, 0x97, 0x92, 0x06, 0xae, 0x97, 0xbe, 0x55, 0xf6, 0xba, 0x73, 0x66, 0xa5, 0x60, 0x87, 0x70, 0xa1, 0x1d, 0x53, 0x73, 0x96, 0x48, 0x5e, 0xb0, 0xa6, 0x65, 0x86, 0x38, 0x5f, 0x4d, 0x4c, 0xf3, 0x90, 0x5d, 0x1f, 0xc9, 0x08, 0x31, 0xc3, 0xe1, 0x36, 0xd5, 0xd5, 0x13, 0xfa, 0x22, 0xbe, 0x28, 0x51, 0x93, 0x14, 0x29, 0x94, 0xa3, 0xed, 0x47, 0x71, 0x45, 0xba, 0xcd, 0xcb, 0xdd, 0x79, 0x1e, 0x8b, 0x3b, 0x88, 0xb0, 0xd4, 0xf1, 0xd1, 0x8b, 0x27, 0x38, 0x25, 0x50, 0xa8, 0x18, 0xc4, 0xfd, 0x88, 0x84, 0xbf, 0x36, 0xf6, 0x77, 0xc6, 0xc3, 0xff, 0x56, 0x77, 0x40, 0x6e, 0x51, 0x09, 0x11, 0xe6, 0x96, 0xaf, 0x75, 0xe5, 0xb3, 0xf8, 0x59, 0xbe, 0xf6, 0x99, 0xbd, 0xd1, 0x6e, 0x62, 0x15, 0xfd, 0xb9, 0x8d, 0x87, 0x40, 0x25, 0xea, 0xda, 0x50),
+            //   tag: vec!(0x2a, 0xab, 0xff, 0x35, 0x61, 0x1b, 0x3e, 0x00, 0x13, 0xf6, 0xae, 0x0d, 0xf1, 0x30, 0x79, 0x9b)
+            // },
+            // TestVector{
+            //   key: [0x92, 0x3d, 0x4b, 0x08, 0x6b, 0x9e, 0x43, 0xb9, 0x86, 0xf7, 0xb6, 0x5e, 0x4c, 0xea, 0x61, 0x13, 0xa3, 0xd8, 0xaa, 0xbe, 0xfa, 0x89, 0x32, 0x3c, 0x5e, 0x4d, 0x5b, 0x6f, 0x15, 0x8b, 0xb7, 0xe0],
+            //   nonce: [0xa0, 0xf7, 0x32, 0x97, 0xb8, 0x7f, 0x5d, 0xeb],
+            //   plain_text: vec!(0x21, 0x43, 0x5e, 0x8d, 0x5c, 0x8e, 0xdf, 0x06, 0x84, 0xf5, 0x8c, 0x2c, 0xba, 0x40, 0x70, 0xc1, 0x0b, 0x48, 0x01, 0xad, 0xf4, 0x6b, 0x6c, 0x4d, 0x32, 0x2e, 0xb3, 0x99, 0x0a, 0x38, 0xa9, 0xad, 0x33, 0x8a, 0xd7, 0x04, 0xb9, 0xdf, 0x65, 0x97, 0xf3, 0xe6, 0x8d, 0x66, 0xcd, 0x5b, 0x56, 0x29, 0x0c, 0x84, 0x66, 0xdb, 0x22, 0x31, 0xe5, 0x6d, 0x6b, 0xcb, 0x9c, 0x44, 0xe1, 0xbd, 0x08, 0x1f, 0x42, 0xca, 0x2a, 0x89, 0x4d, 0xad, 0x36, 0x9d, 0xf2, 0xbd, 0x0d, 0x2c, 0x63, 0xd6, 0xc8, 0x81, 0x73, 0x2d, 0x6e, 0xa2, 0x2b, 0xb2, 0x2b, 0x5b, 0xc9, 0xa6, 0x2e, 0xaf, 0xfa, 0x1b, 0x09, 0x4d, 0x08, 0x45, 0xf6, 0xb9, 0x66, 0xd2, 0xcb, 0x09, 0x5e, 0x7b, 0x3b, 0x8b, 0xcb, 0xc1, 0x5e, 0x70, 0x74, 0x49, 0xd3, 0x5c, 0x8d, 0xf4, 0xae, 0xa3, 0x0c, 0x3b, 0x72, 0x43, 0xe9, 0x77, 0xff, 0xfd, 0x59, 0xc8, 0x0f, 0x1c, 0x5c, 0x9a, 0xf4, 0xbb, 0x5a, 0x54, 0xb9, 0xc7, 0x86, 0xfb, 0xbe, 0x8d, 0x21, 0xb2, 0xb9, 0x06, 0xa8, 0x7a, 0x78, 0x6c, 0xae, 0xd8, 0x41, 0xa3, 0x4a, 0x3e, 0x0c, 0xc0, 0xac, 0x32, 0x09, 0xd8, 0x3c, 0x58, 0xaf, 0xba, 0x19, 0xed, 0xd6, 0x36, 0x22, 0xdd, 0x26, 0x15, 0x32, 0xd2, 0xcf, 0xb0, 0xb4, 0x9d, 0x52, 0x7d, 0x8e, 0xaa, 0x08, 0x87, 0xa0, 0x87, 0xf5, 0x12, 0x9d, 0x89, 0x7f, 0x66, 0x52, 0x64, 0xb2, 0x29, 0xf8, 0x60, 0x36, 0x3d, 0x71, 0xa8, 0x8b, 0x7d, 0x49, 0xc8, 0xdc, 0x63, 0x60, 0x18, 0x2b, 0x35, 0x7b, 0x06, 0x62, 0x39, 0x1b, 0xb4, 0x13, 0x37, 0xf4, 0x60, 0x10, 0xac, 0x32, 0xb9, 0xfa, 0xda, 0x2d, 0x60, 0xa2, 0xef, 0xcb, 0x99, 0x36, 0x5d, 0x3b, 0x27, 0xb7, 0xac, 0x39, 0x69, 0x00, 0xd1, 0xc8, 0x21, 0xd0, 0xdf, 0x8b, 0x86, 0xcc, 0x9c, 0xc1, 0xf2, 0x67, 0x32, 0x59, 0xa3, 0x3e, 0xfe, 0xa6, 0x10, 0xbf, 0x8e, 0x1d, 0x00, 0xd7, 0xe9, 0xdb, 0x2a, 0xfe, 0xa2, 0x1d, 0xa8, 0xf5, 0x8c, 0x55, 0xf7, 0x99, 0x99, 0x9d),
+            //   aad: vec!(0xc8, 0x53, 0xa8, 0xb3, 0x9c, 0x0d, 0xc5, 0x97, 0xd5, 0x62, 0xf1, 0x23, 0xcd, 0x22, 0x1e, 0x41, 0x04, 0xb6, 0x54, 0x23, 0xa0, 0x62, 0xa4, 0xf4, 0xba, 0x89, 0x0b, 0xa3, 0x44, 0xfe, 0xb8, 0x42, 0x90, 0xf6, 0x18, 0x17, 0xe2, 0x33, 0x30, 0xc3, 0x65, 0xf5, 0x8c, 0x35, 0x83, 0xce, 0x08, 0x36, 0x0d, 0x3c, 0x11, 0x71, 0x98, 0x2e, 0xad, 0x54, 0x96, 0xd5, 0x25, 0xac, 0x87, 0x8f, 0x23, 0xa5, 0x74, 0x80, 0xa6, 0xee, 0x39, 0xd4, 0xe6, 0x5a, 0xfd, 0x62, 0x68, 0x24, 0x5b, 0xb9, 0x82, 0xa2, 0x54, 0x5f, 0xa1, 0x19, 0x54, 0x27, 0xcd, 0xbb, 0xcd, 0x40, 0x4c, 0xda, 0xd5, 0x19, 0x8f, 0x55, 0xcc, 0xe2, 0xa5, 0xa0, 0x28, 0xfa, 0xe4, 0x35, 0xf7, 0x1b, 0x15, 0x92, 0x1d, 0x06, 0x6e, 0x8d, 0x43, 0x76, 0x6c, 0x32, 0xb2, 0xf2, 0xc3, 0xf5, 0x7c, 0x06, 0x74, 0xe1, 0x29, 0x60, 0x7d, 0xcd, 0x37, 0x03, 0xec, 0xa5, 0x29, 0x41, 0x4a, 0xda, 0xee, 0x79, 0xd8, 0x1f, 0xed, 0x43, 0x21, 0x53, 0xcc, 0xeb, 0x6f, 0x3f, 0xc5, 0x34, 0x04, 0x81, 0x0d, 0x8e, 0xc8, 0x78, 0xf7, 0xd9, 0x4b, 0xe5, 0xd3, 0x79, 0xd0, 0xe0, 0xe1, 0xaa, 0x9b, 0xc4, 0x04, 0xb4, 0xb5, 0xd3, 0x96, 0x03, 0x8a, 0x9d, 0x76, 0xa5, 0xce, 0x53, 0xc9, 0xf3, 0x75, 0x9b, 0x8e, 0x50, 0xfb, 0x33, 0x18, 0x58, 0xca, 0x58, 0xce, 0xe8, 0x1b, 0xfc, 0x3e, 0xe5, 0x8b, 0xae, 0xf5, 0xd1, 0x9c, 0x40, 0x2a, 0x3d, 0xc8, 0xb3, 0x63, 0x70, 0xec, 0x1a, 0xce, 0x5a, 0x4a, 0xa2, 0x52, 0x7f, 0xb9, 0x4b, 0x4f, 0x93, 0x3a, 0x4a, 0xb8, 0xcc, 0xaa, 0xf6, 0xa5, 0xaf, 0x5a, 0x77, 0x9e, 0xae, 0x56, 0x67, 0xc2, 0xa2, 0x4a, 0xb0, 0x27, 0xe7, 0x81, 0xc8, 0xd4, 0xf3, 0x0c, 0x37, 0x7a, 0xa5, 0x88, 0x5a, 0x2f, 0xda, 0xf6, 0x50, 0x7d, 0x18, 0xcd, 0x82, 0x4a, 0x84, 0x7c, 0x35, 0x36, 0x8b, 0x4e, 0xa9, 0x84, 0xd2, 0xc3, 0xc3, 0x82, 0x4a, 0x5b, 0x8b, 0xa3, 0x04, 0x2e, 0x18, 0x52, 0x50, 0x4a, 0x21, 0xa3),
+            //   cipher_text: vec!(0xf2, 0xe2, 0x10, 0x52, 0xee, 0xbb, 0xb8, 0x6a, 0x4f, 0x5e, 0x80, 0x33, 0x60, 0x85, 0x5d, 0x86, 0x32, 0xaa, 0x72, 0x7d, 0xca, 0x6f, 0x5e, 0x79, 0xdd, 0x74, 0xd7, 0xaf, 0xf1, 0x06, 0xe4, 0x42, 0x00, 0x19, 0x28, 0xd1, 0x13, 0x00, 0x5b, 0x03, 0x0f, 0x84, 0x46, 0xf8, 0xef, 0xf2, 0xee, 0x95, 0x1d, 0xb6, 0x63, 0x97, 0x8a, 0xbe, 0x43, 0x09, 0x0d, 0xd5, 0xad, 0x2c, 0x51, 0xba, 0x97, 0xa0, 0xec, 0xf9, 0x88, 0xc6, 0x07, 0xd9, 0x5e, 0x48, 0x6d, 0x02, 0x52, 0x4f, 0x69, 0x0f, 0xa3, 0xc2, 0x8d, 0x5c, 0x48, 0xc1, 0xf7, 0x5c, 0x1f, 0x55, 0x5e, 0x7b, 0x43, 0xfe, 0x7e, 0x46, 0xf2, 0xca, 0x2b, 0x9f, 0xdb, 0x40, 0x8e, 0xc4, 0xba, 0x18, 0xb6, 0xcd, 0xde, 0x2a, 0xf6, 0x73, 0x18, 0x3c, 0xb7, 0xb1, 0xa3, 0xc2, 0x3a, 0xe7, 0x7e, 0xdd, 0xd4, 0xca, 0xc7, 0x5e, 0x1e, 0xa1, 0x47, 0x43, 0xfc, 0x57, 0x1f, 0x8d, 0x31, 0xce, 0x2e, 0x96, 0x78, 0x75, 0x24, 0xcd, 0x48, 0xaa, 0xda, 0xa4, 0x74, 0x18, 0x1c, 0x09, 0x6a, 0x03, 0x21, 0x84, 0x57, 0x4d, 0xdc, 0x25, 0xa6, 0xe0, 0xac, 0x84, 0x41, 0xc2, 0x12, 0xbc, 0x36, 0x29, 0x87, 0x08, 0xe3, 0x3c, 0x96, 0x3a, 0xe9, 0x31, 0xe6, 0xc6, 0x24, 0x1d, 0x1a, 0xff, 0xee, 0xf7, 0xb6, 0xef, 0x75, 0x94, 0x95, 0xdf, 0x44, 0xb6, 0xab, 0x64, 0x74, 0x47, 0x69, 0x3c, 0xf7, 0x03, 0x56, 0x9e, 0x69, 0xaa, 0x72, 0xf1, 0xde, 0xf9, 0xa3, 0x42, 0xb8, 0x97, 0x8c, 0x1e, 0xde, 0xa9, 0x70, 0x3a, 0x42, 0x1c, 0xa7, 0x5b, 0x92, 0xca, 0xc4, 0xde, 0x14, 0xb8, 0x8c, 0x69, 0x32, 0x00, 0x02, 0x2b, 0x8a, 0x2e, 0xd2, 0x2b, 0x1c, 0x46, 0x78, 0xb9, 0x9f, 0x4d, 0x69, 0x5e, 0x08, 0x0d, 0xd1, 0x19, 0x6d, 0x71, 0x68, 0xe1, 0x4f, 0x0d, 0x0f, 0x8f, 0xf8, 0x80, 0xd7, 0x42, 0xe9, 0x7b, 0x9f, 0x6d, 0x00, 0xaf, 0x1f, 0x71, 0x18, 0xe1, 0x0b, 0x77, 0xc5, 0xef, 0x3e, 0xa6, 0xc5, 0x2f, 0x84, 0xa2, 0x0f, 0xd6, 0xea, 0x46, 0xdc),
+            //   tag: vec!(0xfa, 0x8e, 0xe1, 0x34, 0x00, 0xfb, 0x3f, 0x63, 0xb8, 0x99, 0xdf, 0x58, 0x2f, 0x2f, 0xec, 0x45)
+            // },
+            // TestVector{
+            //   key: [0xdf, 0x73, 0xad, 0xab, 0x27, 0x68, 0x55, 0x9e, 0xa9, 0x83, 0xcc, 0xe8, 0x54, 0x53, 0xfe, 0x81, 0xd7, 0x9b, 0xe3, 0xb3, 0xc5, 0x7f, 0x20, 0x2b, 0x31, 0xb9, 0x4d, 0x66, 0x35, 0xcf, 0x2e, 0x4b],
+            //   nonce: [0xe7, 0xa8, 0x7e, 0x6b, 0xf6, 0xb5, 0xa3, 0x54],
+            //   plain_text: vec!(0x00, 0x32, 0xa3, 0x7a, 0xbf, 0x66, 0x1f, 0xaa, 0x18, 0xc5, 0x87, 0xfd, 0x2a, 0xa8, 0x88, 0x85, 0xc0, 0x61, 0xde, 0xeb, 0xa8, 0x11, 0x05, 0xdd, 0x22, 0x19, 0x69, 0xbe, 0xd5, 0xd5, 0x9c, 0x72, 0x04, 0xb0, 0x9b, 0x1a, 0x8c, 0x4c, 0x8d, 0xe3, 0xb9, 0xf7, 0x48, 0xc7, 0xfc, 0x70, 0x62, 0x6e, 0xbe, 0xac, 0xa0, 0x60, 0x23, 0x3a, 0x57, 0xb1, 0x02, 0x22, 0x1b, 0x1b, 0xf0, 0xf3, 0xd9, 0xfd, 0xaa, 0xad, 0x3d, 0x2b, 0x14, 0x39, 0xc2, 0x4d, 0x08, 0xf9, 0xc6, 0x7f, 0x49, 0xf3, 0xc4, 0x71, 0x28, 0xf9, 0x2e, 0xe5, 0x30, 0xab, 0xf4, 0xc4, 0xf4, 0x57, 0x3b, 0xc6, 0x0a, 0xe4, 0xb3, 0x81, 0x09, 0xf5, 0x5b, 0xca, 0x3c, 0xa9, 0xe1, 0xba, 0x9f, 0x9f, 0xd6, 0xe3, 0x4b, 0xa0, 0xd1, 0x74, 0x89, 0x29, 0x77, 0xa5, 0x33, 0x56, 0xe1, 0xf5, 0xc8, 0x8c, 0x61, 0x4f, 0xe3, 0xff, 0x3b, 0x3d, 0xd0, 0x81, 0x8e, 0x7a, 0x22, 0x85, 0x41, 0x2e, 0x3b, 0x37, 0x44, 0x4b, 0xbe, 0x8a, 0x80, 0x94, 0x2e, 0xfc, 0xfd, 0x03, 0x95, 0x88, 0x09, 0xa6, 0x96, 0x6c, 0xda, 0x94, 0x30, 0xb2, 0xf0, 0xc9, 0xe5, 0x52, 0xf4, 0xbc, 0xed, 0x6e, 0x19, 0xeb, 0x3e, 0x85, 0xfc, 0x57, 0x58, 0xbd, 0x7b, 0x58, 0x82, 0x97, 0xcc, 0xbe, 0xd3, 0x7e, 0xd9, 0x4c, 0x3a, 0xdc, 0x8c, 0x08, 0xea, 0x8b, 0x05, 0x84, 0x62, 0xaa, 0xc9, 0xd5, 0x7a, 0x93, 0x9e, 0xc7, 0x11, 0xbc, 0x4e, 0xcf, 0xec, 0x94, 0x4d, 0x2b, 0x65, 0x3b, 0x7c, 0xfc, 0x7b, 0x02, 0xa6, 0x5d, 0x70, 0x57, 0xc9, 0xfd, 0xad, 0xd5, 0x1b, 0x9d, 0xa8, 0xcc, 0x4a, 0x3c, 0x68, 0xda, 0xe9, 0xda, 0x8b, 0x9c, 0x53, 0x19, 0xc1, 0xa2, 0xba, 0xa3, 0xd6, 0xc8, 0x91, 0xc5, 0xac, 0x4a, 0x39, 0x46, 0x14, 0x84, 0xb5, 0xa0, 0x1a, 0xbc, 0x64, 0xdf, 0x44, 0x7a, 0xda, 0x24, 0xc0, 0x4a, 0x43, 0x63, 0xe6, 0x05, 0xea, 0xcc, 0xf3, 0x39, 0xa9, 0xaa, 0x51, 0x5e, 0x72, 0x42, 0x06, 0x20, 0x6d, 0xa6, 0xd2, 0x2b, 0xbd, 0x2f, 0x52, 0xe6, 0x4c, 0xd7, 0xc8, 0x95),
+            //   aad: vec!(0xf8, 0x33, 0xe5, 0xab, 0x4f, 0x8b, 0xc8, 0x91, 0x67, 0xf8, 0x0f, 0x57, 0x6b, 0x1d, 0x6b, 0x22, 0xcd, 0xd0, 0xe3, 0x07, 0x21, 0xf5, 0xf7, 0x35, 0x79, 0x97, 0x46, 0xcf, 0x64, 0x5b, 0x6e, 0xff, 0x53, 0x1d, 0x4c, 0x7b, 0x03, 0x58, 0x4f, 0x3d, 0xfc, 0xb7, 0x3c, 0xbd, 0x35, 0xac, 0x42, 0x73, 0x62, 0x16, 0xdc, 0x7f, 0x0d, 0xe0, 0x98, 0xa4, 0xf4, 0x2c, 0x61, 0xce, 0xb4, 0xb2, 0x27, 0xee, 0x28, 0x8e, 0x47, 0xd6, 0x97, 0xa0, 0xa7, 0x6a, 0xfc, 0x76, 0x2f, 0x08, 0x4e, 0x8f, 0xdb, 0xf9, 0x35, 0x1c, 0x28, 0x34, 0x0c, 0x32, 0x47, 0x71, 0xc1, 0x09, 0xa4, 0x69, 0x34, 0x1a, 0xb1, 0x0c, 0xa1, 0x04, 0x83, 0xed, 0x2a, 0xf5, 0xe8, 0x78, 0xd7, 0xd3, 0xdc, 0x2b, 0xce, 0xd2, 0xf7, 0x2d, 0xa3, 0xd1, 0xa2, 0x58, 0x52, 0xb1, 0x03, 0xee, 0x98, 0x78, 0xe8, 0x15, 0x8e, 0xb4, 0x30, 0x9c, 0x1c, 0xe5, 0x28, 0xf3, 0xa1, 0x78, 0xac, 0xe1, 0x53, 0xb6, 0xd3, 0xae, 0x0a, 0xf0, 0xd5, 0x77, 0xcb, 0x3c, 0xb1, 0x54, 0x04, 0x89, 0xe8, 0x04, 0x27, 0xf7, 0x92, 0x21, 0x7a, 0xd8, 0xa0, 0x9b, 0x84, 0xf0, 0x27, 0xfc, 0xa7, 0xce, 0xb6, 0x51, 0xb4, 0x26, 0x4e, 0x98, 0xe9, 0x4b, 0x4c, 0xb8, 0xa3, 0x7b, 0x13, 0x33, 0x90, 0x89, 0x72, 0x33, 0xe8, 0xba, 0x91, 0x03, 0x62, 0x8d, 0x05, 0xb9, 0x60, 0x9e, 0x85, 0x52, 0xc4, 0xa4, 0xb1, 0x1e, 0x3f, 0x2f, 0xa8, 0xd5, 0x6a, 0xf3, 0x69, 0x57, 0x39, 0x0e, 0x88, 0xcb, 0xa4, 0x46, 0x56, 0xbe, 0x3e, 0xda, 0xce, 0x79, 0x8c, 0xf8, 0xcd, 0xf7, 0x77, 0x1b, 0xac, 0x33, 0x8a, 0x25, 0x6b, 0xc3, 0xcb, 0xa6, 0xdf, 0x97, 0x72, 0x8f, 0x22, 0x2f, 0x42, 0x3c, 0xa7, 0xc6, 0xd1, 0x49, 0xc9, 0x37, 0x2d, 0x66, 0x16, 0x3a, 0x98, 0xf7, 0x9a, 0x23, 0x4b, 0x00, 0xd4, 0xb7, 0x5f, 0xb2, 0xec, 0x86, 0x0d, 0xcc, 0x2d, 0x19, 0x98, 0x10, 0x5e, 0x4b, 0x9c, 0x01, 0xd6, 0x8f, 0x07, 0x9f, 0x3e, 0x0a, 0xa2, 0x1c, 0xc5, 0x34, 0x04, 0x7f, 0xc7, 0xb8, 0x58, 0xf8),
+            //   cipher_text: vec!(0xb8, 0x42, 0xea, 0xdf, 0xdf, 0x43, 0x1c, 0x13, 0x5b, 0xd6, 0x58, 0x1d, 0x3e, 0xcc, 0xae, 0x54, 0xe2, 0x26, 0x7d, 0x88, 0x90, 0x03, 0x6a, 0xa3, 0x3d, 0xfe, 0x2d, 0x2d, 0x97, 0x15, 0xc4, 0x46, 0x25, 0x44, 0x12, 0x10, 0xa3, 0xa0, 0xd6, 0x66, 0xd7, 0x08, 0xd3, 0x05, 0x88, 0xfe, 0x85, 0x1e, 0xc3, 0x6e, 0x10, 0xd8, 0xfa, 0x35, 0x84, 0xed, 0x77, 0xb0, 0x95, 0x14, 0x94, 0x94, 0xb7, 0xc5, 0x43, 0x79, 0xd6, 0x2c, 0x89, 0x35, 0xe1, 0xd2, 0xb9, 0xa8, 0xf4, 0x7e, 0x47, 0x59, 0xad, 0x0b, 0x34, 0x37, 0xfd, 0xf2, 0xcc, 0x2f, 0xb6, 0xc5, 0xea, 0x25, 0xad, 0x10, 0xe0, 0xbd, 0xc9, 0xdc, 0x5b, 0x05, 0x17, 0xfc, 0x23, 0x7e, 0xb7, 0x83, 0xcc, 0x46, 0x1c, 0x46, 0x66, 0x5e, 0x2b, 0x1d, 0x1a, 0x5b, 0x80, 0x08, 0xdb, 0xf4, 0x09, 0xea, 0x2a, 0x63, 0xfe, 0xa0, 0x27, 0x6d, 0xe2, 0x3a, 0x32, 0xc9, 0x9d, 0x92, 0xa4, 0x98, 0x80, 0x7a, 0x0f, 0x95, 0xe2, 0x08, 0xfc, 0x62, 0x62, 0x32, 0x1a, 0x78, 0xaa, 0xfa, 0xf0, 0xcc, 0x3f, 0x83, 0x3f, 0xff, 0x37, 0xbd, 0x4e, 0xfa, 0x66, 0xf6, 0x02, 0x3a, 0x25, 0xcd, 0xc6, 0x70, 0x2c, 0xee, 0x39, 0x12, 0x79, 0x95, 0x63, 0xd9, 0x08, 0xa5, 0x18, 0x3c, 0x99, 0x56, 0xa0, 0x6a, 0xa7, 0x10, 0x85, 0xd8, 0x55, 0xdc, 0x7c, 0x80, 0x9e, 0xd6, 0xe2, 0x88, 0x95, 0x92, 0xb3, 0x61, 0xab, 0x3a, 0xb3, 0x90, 0x60, 0xf8, 0xe4, 0x19, 0x15, 0x21, 0x87, 0xa7, 0x94, 0xa1, 0x9c, 0x2a, 0x11, 0x28, 0x88, 0x22, 0x01, 0x90, 0x0e, 0xa2, 0xcd, 0x59, 0x78, 0x60, 0x67, 0x4b, 0xf7, 0x8d, 0x97, 0x20, 0x64, 0x3d, 0xf8, 0x70, 0x16, 0x76, 0x71, 0x8f, 0xd2, 0x01, 0xba, 0xed, 0x49, 0x35, 0xa8, 0x8e, 0x50, 0x55, 0x8d, 0xaf, 0x86, 0xed, 0xd0, 0x8a, 0x9a, 0xb2, 0x27, 0xac, 0x7a, 0xfa, 0xe5, 0x5c, 0x97, 0x4b, 0x68, 0xde, 0x8d, 0xac, 0xad, 0x4a, 0x4d, 0x79, 0xb1, 0x3e, 0xd6, 0xdf, 0xe7, 0x40, 0x17, 0xa4, 0xcb, 0x91, 0x48, 0xe0, 0x33, 0x43, 0x6f, 0xb6),
+            //   tag: vec!(0x18, 0x40, 0x95, 0xb7, 0xa8, 0x19, 0x0a, 0xbe, 0xc0, 0x8b, 0xb7, 0x2d, 0x19, 0xee, 0xb1, 0x03)
+            // },
+            // TestVector{
+            //   key: [0x55, 0xa4, 0xbe, 0x24, 0x48, 0xb4, 0x64, 0xc2, 0xea, 0x52, 0xa2, 0xf2, 0x66, 0x4e, 0xd6, 0xab, 0xa8, 0x65, 0xc1, 0x4e, 0xa1, 0xfe, 0xa7, 0x7f, 0x46, 0x89, 0x33, 0x1f, 0xd1, 0x05, 0xc8, 0xd4],
+            //   nonce: [0xdb, 0x37, 0xc0, 0xa4, 0x05, 0xb4, 0x62, 0x6d],
+            //   plain_text: vec!(0xd2, 0x66, 0xe6, 0x62, 0x72, 0xe5, 0xd3, 0x46, 0x20, 0x81, 0xb0, 0x04, 0xcb, 0x42, 0x42, 0x9c, 0x8b, 0x97, 0x41, 0xe9, 0xf6, 0x78, 0x15, 0x37, 0x54, 0xd7, 0x26, 0xf6, 0xf9, 0xaa, 0x51, 0x34, 0x64, 0x76, 0x3c, 0x5e, 0x79, 0x3b, 0x48, 0x2f, 0xe5, 0x12, 0xfe, 0xce, 0x97, 0x58, 0x5f, 0x14, 0x26, 0x12, 0x0d, 0x4c, 0xef, 0xb3, 0xd0, 0xa8, 0xcc, 0x0a, 0x8d, 0xb4, 0xbd, 0xe9, 0x3f, 0xc7, 0x2c, 0x78, 0xf4, 0x4d, 0x4f, 0xec, 0xca, 0x14, 0x65, 0x0c, 0x66, 0x0d, 0x3e, 0x28, 0x5b, 0x32, 0x7e, 0x7c, 0xdd, 0x81, 0x30, 0x63, 0xe7, 0xe8, 0x67, 0xb8, 0xa2, 0xd0, 0x59, 0xa4, 0x1b, 0xab, 0x70, 0x43, 0x2b, 0x7f, 0x85, 0x71, 0x99, 0x89, 0x4d, 0xa9, 0x0d, 0xca, 0x3f, 0xe5, 0x27, 0x2b, 0xae, 0x1e, 0xc6, 0x94, 0xa1, 0xa0, 0x7b, 0x60, 0xb0, 0x5d, 0xf2, 0x75, 0x78, 0x4d, 0x49, 0x75, 0x63, 0x7e, 0x46, 0x73, 0x10, 0x9f, 0x3b, 0xa8, 0x46, 0xdf, 0xd1, 0xa0, 0x48, 0xb2, 0x02, 0xed, 0x8e, 0x89, 0x97, 0x3b, 0xe6, 0x08, 0xb9, 0x1e, 0xe4, 0x74, 0x3b, 0x1e, 0x75, 0x99, 0x00, 0xf1, 0x44, 0x30, 0x38, 0x95, 0x1f, 0xe6, 0x18, 0x9e, 0x80, 0x66, 0x38, 0x98, 0x5f, 0x3c, 0x16, 0x33, 0x8c, 0x3c, 0x60, 0x69, 0x5d, 0xf5, 0x8e, 0x62, 0x11, 0x54, 0xd7, 0x9b, 0xb9, 0x73, 0x85, 0x9c, 0x45, 0x58, 0xe9, 0xdc, 0xa9, 0x04, 0x70, 0xf7, 0x7c, 0x73, 0xf0, 0x04, 0x44, 0x3a, 0xd5, 0xdb, 0x07, 0x17, 0xab, 0xbe, 0x43, 0x26, 0x6f, 0x90, 0xe5, 0x73, 0x97, 0xb8, 0x3a, 0xc3, 0x4d, 0x1f, 0xef, 0x2e, 0x89, 0x7e, 0x24, 0x83, 0xd5, 0xbc, 0xdc, 0xb6, 0x27, 0xab, 0xd6, 0x4b, 0x0d, 0x1a, 0xef, 0x52, 0x58, 0x35, 0xf2, 0x5e, 0x76, 0xd6, 0xe9, 0x15, 0x82, 0x32, 0xcd, 0xde, 0x6d, 0xce, 0x97, 0x0b, 0x59, 0xf5, 0x8d, 0xe8, 0xa9, 0x8e, 0x65, 0x3b, 0xe3, 0x2f, 0xb5, 0x8e, 0xda, 0xbb, 0xce, 0xfa, 0x50, 0x65, 0xd7, 0x3a, 0xfd, 0xf1, 0xc9, 0xc4, 0xfb, 0xf5, 0x0c, 0x10, 0x22, 0xbd, 0x22, 0xbf, 0xcb, 0x98, 0xe4, 0xb4, 0x22),
+            //   aad: vec!(0xfd, 0x6a, 0x3f, 0xdd, 0x87, 0x9f, 0x88, 0x80, 0x84, 0x3e, 0xac, 0x20, 0xae, 0x01, 0xc1, 0xb9, 0xdc, 0x34, 0x87, 0xd2, 0x70, 0xa8, 0x06, 0x57, 0x20, 0x88, 0xef, 0x2d, 0xdc, 0x1f, 0x1e, 0x0d, 0xe4, 0x95, 0xe7, 0x1d, 0x48, 0x13, 0xbf, 0x5c, 0x50, 0x1a, 0xd3, 0x1e, 0x5d, 0x79, 0x1c, 0x4b, 0x5b, 0x3a, 0x0a, 0x71, 0xb6, 0x3f, 0xdd, 0xdc, 0xc8, 0xde, 0x4b, 0x05, 0x60, 0x64, 0xef, 0x46, 0x79, 0x89, 0xec, 0xcc, 0xc5, 0xd0, 0x16, 0x0d, 0x40, 0x3b, 0xf3, 0xa0, 0x25, 0xd4, 0x89, 0x2b, 0x3b, 0x1d, 0xe3, 0xe0, 0x62, 0xbc, 0x35, 0x81, 0xd4, 0x41, 0x0f, 0x27, 0x33, 0x38, 0x31, 0x1e, 0xb4, 0x63, 0x75, 0x29, 0xe4, 0xa6, 0x80, 0xa6, 0xe4, 0xa5, 0xe2, 0x6e, 0x30, 0x86, 0x30, 0xa5, 0xb6, 0xd4, 0x9e, 0xad, 0x6d, 0x54, 0x3f, 0x8f, 0x2b, 0xf9, 0x05, 0x0a, 0xa9, 0x4c, 0xe0, 0x91, 0x31, 0x87, 0x21, 0xe1, 0xd8, 0xb9, 0x6e, 0x27, 0x9f, 0x34, 0xb9, 0x75, 0x9b, 0x65, 0x03, 0x7b, 0xec, 0x4b, 0xf6, 0xcc, 0xda, 0x69, 0x29, 0x70, 0x5a, 0xee, 0xee, 0xbe, 0x49, 0xe3, 0x27, 0xe4, 0xd7, 0xa9, 0x16, 0x62, 0x0c, 0x9f, 0xaf, 0x37, 0x65, 0x12, 0x06, 0x58, 0xaf, 0x34, 0xc5, 0x3f, 0xbb, 0x97, 0xec, 0x07, 0x65, 0x7b, 0x3f, 0x08, 0x8f, 0xcb, 0xdc, 0x40, 0x1a, 0xa7, 0x94, 0x9d, 0xde, 0xda, 0x34, 0xd8, 0x85, 0x01, 0x8c, 0x2c, 0x23, 0xf4, 0xf0, 0xbb, 0x82, 0x18, 0xbf, 0x0d, 0x4f, 0xc9, 0x06, 0x43, 0x65, 0x8b, 0x4d, 0x88, 0x34, 0xf4, 0xa8, 0xc0, 0x8e, 0x59, 0x0c, 0x2a, 0x79, 0x09, 0x95, 0xba, 0xa9, 0xe7, 0x76, 0x27, 0xc3, 0x42, 0xd2, 0x83, 0xe4, 0x54, 0xf8, 0x4f, 0xcc, 0x05, 0xbe, 0x15, 0xe9, 0x62, 0x7a, 0x2d, 0x9b, 0xe3, 0x40, 0xc9, 0xd7, 0x2f, 0x22, 0x2b, 0xbd, 0xfc, 0x47, 0x90, 0x5f, 0x56, 0x61, 0x6c, 0xd9, 0xf9, 0x36, 0xd4, 0x9e, 0x47, 0x32, 0xf3, 0x19, 0xf0, 0x20, 0x51, 0x33, 0x40, 0xfb, 0x8b, 0x22, 0x82, 0x8d, 0xb2, 0x51, 0xb1, 0x02, 0xb6, 0xb1, 0x37, 0xc9, 0x53, 0x39, 0x36, 0xd6),
+            //   cipher_text: vec!(0xbd, 0x11, 0xed, 0x07, 0xb7, 0xb4, 0xb3, 0x0e, 0xea, 0xf2, 0x5d, 0x6a, 0x41, 0xa5, 0x49, 0xcc, 0xa0, 0xa5, 0xae, 0xe7, 0x1f, 0x99, 0x0a, 0xc5, 0x66, 0xa3, 0x72, 0x65, 0xd7, 0xaf, 0x2c, 0xe3, 0xc0, 0x37, 0x03, 0x42, 0x7e, 0xe0, 0xb2, 0x75, 0x5c, 0x2b, 0xdf, 0xc2, 0x9f, 0x9d, 0x82, 0x6a, 0xec, 0x6e, 0xe4, 0xad, 0x28, 0xaf, 0x48, 0x07, 0x9a, 0xc2, 0x3d, 0xb1, 0x65, 0x80, 0xb9, 0x74, 0x24, 0xf3, 0xa4, 0xe3, 0x5c, 0xc2, 0x36, 0x25, 0xd3, 0x9f, 0x95, 0x69, 0x9d, 0x9f, 0xf5, 0x14, 0x3e, 0x9a, 0x2b, 0xc2, 0x6f, 0xcf, 0xee, 0x4f, 0x12, 0x5f, 0x5a, 0xa2, 0xd9, 0x68, 0xcc, 0xfc, 0x2f, 0xaa, 0xf9, 0xdb, 0x3c, 0x28, 0x85, 0x0f, 0x67, 0x57, 0xf7, 0x35, 0xcb, 0xc5, 0x0c, 0x94, 0xc4, 0x98, 0xbc, 0xde, 0x4f, 0x23, 0xbf, 0xfa, 0xfa, 0x8d, 0xd5, 0xf7, 0x0d, 0x1a, 0x01, 0x1e, 0x35, 0xeb, 0x26, 0xe9, 0x05, 0xd4, 0xe6, 0x88, 0x48, 0xfe, 0xde, 0xbe, 0xb1, 0x97, 0xbe, 0x59, 0x5c, 0x08, 0x5b, 0xa3, 0x3f, 0x11, 0xba, 0x83, 0x98, 0x25, 0x84, 0x45, 0x05, 0x17, 0x51, 0x88, 0x8e, 0x9b, 0xba, 0x11, 0x1f, 0x80, 0x0f, 0x31, 0xb3, 0x7c, 0x44, 0x70, 0x74, 0xca, 0x6d, 0xce, 0x6d, 0x54, 0xb4, 0xdf, 0xad, 0x6c, 0xee, 0x51, 0x38, 0x64, 0x3d, 0x4f, 0x6a, 0xc0, 0x45, 0xe8, 0x04, 0x72, 0x48, 0x92, 0x4e, 0x88, 0xea, 0x42, 0x94, 0xc7, 0x87, 0x8b, 0xc2, 0x2c, 0x9b, 0x41, 0x92, 0x4c, 0xe3, 0x01, 0xf2, 0x26, 0x93, 0xc3, 0x37, 0x33, 0x10, 0x7b, 0xf1, 0xba, 0x85, 0xe3, 0x48, 0x06, 0xc5, 0xe4, 0x36, 0x6e, 0xa6, 0x6f, 0xc5, 0x2a, 0x5f, 0x89, 0xdd, 0x9b, 0xf2, 0x13, 0x23, 0x91, 0x58, 0xb3, 0xd4, 0xd2, 0x60, 0x0d, 0xde, 0x69, 0x6c, 0x61, 0xd7, 0x6c, 0x39, 0x8b, 0x9b, 0xf1, 0x0d, 0xe9, 0x11, 0x8e, 0x81, 0x2e, 0x89, 0x1c, 0x8f, 0x33, 0x55, 0xc0, 0xec, 0xc6, 0x40, 0x5f, 0x79, 0xbc, 0x32, 0xa5, 0x89, 0x05, 0xe3, 0x78, 0x88, 0xa1, 0xd8, 0x39, 0x5f, 0xbe, 0xdc, 0x3a, 0xc5, 0x4e, 0xca, 0x56, 0x9f),
+            //   tag: vec!(0xf7, 0xd3, 0xb5, 0x8a, 0x34, 0xa8, 0x6e, 0x99, 0x26, 0x7e, 0x5d, 0xb2, 0x06, 0xf1, 0x7b, 0xbe)
+            // },
+            // TestVector{
+            //   key: [0x33, 0x04, 0xe4, 0x91, 0x7a, 0xd7, 0x77, 0x7b, 0x86, 0xc2, 0x6a, 0x63, 0x62, 0x92, 0xc9, 0xcc, 0x4c, 0x10, 0xd3, 0x20, 0x03, 0xc4, 0x9e, 0x07, 0x20, 0x9e, 0xb0, 0xef, 0x85, 0x05, 0x03, 0x1a],
+            //   nonce: [0x4d, 0x57, 0x2d, 0x11, 0x6f, 0xbd, 0x8c, 0x4d],
+            //   plain_text: vec!(0x2f, 0x24, 0x2c, 0x2b, 0xa3, 0x37, 0x90, 0xec, 0xef, 0x86, 0x2b, 0x0e, 0x07, 0x7f, 0xf8, 0xb1, 0x5e, 0xb9, 0xd1, 0x0c, 0xf2, 0xff, 0x62, 0x1e, 0xd6, 0x59, 0x02, 0x49, 0x44, 0x31, 0xdc, 0xbd),
+            //   aad: vec!(0xe6, 0x99, 0xbb, 0xf2, 0x50, 0xcd, 0xd9, 0x3d, 0x22, 0x9d, 0x07, 0x40, 0xe4, 0x33, 0x89, 0x7e, 0x2d, 0x19, 0x13, 0x2e, 0x2b, 0x72, 0x2d, 0xf8, 0xb6, 0x9b, 0xb6, 0xa7, 0xc2, 0xcf, 0x3b, 0x93),
+            //   cipher_text: vec!(0xfb, 0x81, 0xe3, 0x04, 0x36, 0xe4, 0x37, 0xc7, 0xf6, 0x86, 0xf8, 0x6b, 0x1b, 0x65, 0xc7, 0x35, 0x49, 0xa9, 0xd0, 0x9d, 0xb8, 0x10, 0xd3, 0x20, 0x78, 0x5c, 0x36, 0x34, 0x93, 0x41, 0x50, 0xb3),
+            //   tag: vec!(0x8b)
+            // },
+            // TestVector{
+            //   key: [0xed, 0x60, 0x57, 0xbb, 0x16, 0x3f, 0x16, 0x09, 0xff, 0x28, 0xb9, 0x38, 0x12, 0x2f, 0x49, 0x5e, 0x3d, 0x5a, 0xe4, 0xec, 0x3d, 0xbd, 0x74, 0x56, 0xc9, 0xb5, 0xc8, 0x2e, 0x28, 0xe9, 0x52, 0xdc],
+            //   nonce: [0xe6, 0xff, 0x68, 0x52, 0xf3, 0xa3, 0xaf, 0xde],
+            //   plain_text: vec!(0x3c, 0x50, 0xed, 0xc9, 0x67, 0xeb, 0x0b, 0x3b, 0x23, 0x55, 0xf6, 0x40, 0x0e, 0x0a, 0x03, 0x6e, 0x79, 0x6c, 0x8b, 0x7d, 0x72, 0xc5, 0xe5, 0x83, 0xa8, 0x6e, 0x82, 0x0d, 0x53, 0xe7, 0x6c, 0x43),
+            //   aad: vec!(0x24, 0x41, 0xdb, 0x55, 0x14, 0x8e, 0x14, 0xe9, 0xe2, 0x41, 0xd6, 0x82, 0x96, 0xeb, 0x60, 0xd5, 0x29, 0x40, 0x8f, 0x05, 0x34, 0x14, 0x30, 0x89, 0x67, 0x1b, 0xce, 0x54, 0x6d, 0xb9, 0x6d, 0x88),
+            //   cipher_text: vec!(0x6e, 0xca, 0xbc, 0xce, 0xe3, 0x15, 0x19, 0x37, 0x4d, 0x4b, 0xed, 0x11, 0x29, 0x6e, 0x74, 0x83, 0xd1, 0xcb, 0x75, 0x9b, 0xea, 0x3f, 0x44, 0x46, 0xa9, 0x6b, 0xda, 0x8b, 0x4c, 0xa6, 0xd7, 0xac),
+            //   tag: vec!(0x35, 0x5f)
+            // },
+            // TestVector{
+            //   key: [0x73, 0x56, 0x81, 0x83, 0xc1, 0xf9, 0x72, 0x5a, 0xf3, 0x0e, 0x0f, 0x20, 0x67, 0x60, 0x6c, 0xe8, 0x02, 0xc3, 0xfe, 0x3a, 0xb5, 0xcf, 0xf8, 0xd0, 0x2b, 0x3d, 0xb8, 0xc3, 0x51, 0x76, 0xee, 0x0d],
+            //   nonce: [0x0b, 0xc9, 0xe1, 0x93, 0x21, 0xb3, 0xd0, 0x0a],
+            //   plain_text: vec!(0xec, 0x25, 0x90, 0xaf, 0x5c, 0xcd, 0x22, 0x6a, 0x32, 0xff, 0x75, 0x0c, 0x1b, 0x02, 0x9c, 0x11, 0xe3, 0xdd, 0x76, 0xc4, 0x69, 0xa5, 0x57, 0x9d, 0xa9, 0x41, 0x8e, 0x4c, 0x3f, 0xdc, 0x0d, 0x41),
+            //   aad: vec!(0xdf, 0x30, 0x16, 0x0a, 0xe0, 0xcb, 0xf2, 0xcf, 0x89, 0x92, 0x22, 0x1b, 0xd6, 0x2d, 0xff, 0xe6, 0x91, 0xdd, 0x60, 0x2a, 0xfa, 0x78, 0x4c, 0xa6, 0x91, 0x47, 0x9e, 0x95, 0x7a, 0xf3, 0xac, 0xf1),
+            //   cipher_text: vec!(0x9e, 0x8d, 0x8a, 0xc3, 0x06, 0x26, 0xf8, 0xb8, 0x31, 0x44, 0x8d, 0x69, 0x76, 0x93, 0x3a, 0xa5, 0xbb, 0x8c, 0x6d, 0xbc, 0x79, 0x4e, 0x1f, 0x4b, 0x7e, 0xeb, 0x0e, 0x4a, 0x59, 0x34, 0x2c, 0x07),
+            //   tag: vec!(0x9f, 0xd3, 0x6a)
+            // },
+            // TestVector{
+            //   key: [0x27, 0x3b, 0xcb, 0x3f, 0x8c, 0x06, 0x7d, 0xa4, 0xec, 0x34, 0x18, 0x79, 0x9a, 0xd4, 0x0e, 0x7e, 0x4a, 0xee, 0x74, 0xad, 0x7e, 0x62, 0x94, 0x99, 0xd6, 0x46, 0xdf, 0x4a, 0x7e, 0x58, 0x50, 0x25],
+            //   nonce: [0xf6, 0x0b, 0xe3, 0xeb, 0x89, 0x4b, 0x40, 0x30],
+            //   plain_text: vec!(0x69, 0x74, 0x98, 0xba, 0x96, 0x4d, 0x5e, 0xf4, 0x01, 0xda, 0x4d, 0x94, 0x84, 0x4f, 0xab, 0x1e, 0xfc, 0x63, 0x5e, 0x71, 0x57, 0xd0, 0x83, 0x1a, 0x32, 0x5b, 0xb5, 0xa4, 0xcf, 0x1f, 0xbd, 0x34),
+            //   aad: vec!(0x91, 0x29, 0x71, 0x5d, 0xea, 0xb1, 0x4f, 0x02, 0xc7, 0x6b, 0xa8, 0x17, 0x25, 0x71, 0xb1, 0xfa, 0x9d, 0x50, 0x36, 0x5c, 0xd7, 0x95, 0xbf, 0xcc, 0xdf, 0xc2, 0x8e, 0x7e, 0x7b, 0x4f, 0x66, 0xfc),
+            //   cipher_text: vec!(0xbd, 0x4c, 0xd5, 0xaf, 0x83, 0xbe, 0x1c, 0x13, 0x93, 0x33, 0x02, 0x67, 0x5d, 0x9f, 0xca, 0xf1, 0xc4, 0xca, 0xcd, 0xf2, 0x69, 0xf6, 0xff, 0x44, 0x1d, 0x1e, 0xa2, 0x21, 0x1c, 0x54, 0xe7, 0xed),
+            //   tag: vec!(0x7a, 0xb1, 0x2a, 0x37)
+            // },
+            // TestVector{
+            //   key: [0xad, 0x39, 0x61, 0x0c, 0x2e, 0x6a, 0x6d, 0x09, 0x61, 0x20, 0x73, 0x90, 0xe0, 0x76, 0xe9, 0x72, 0xc2, 0xed, 0xad, 0xca, 0x88, 0x5c, 0x92, 0x96, 0x5f, 0xa6, 0x48, 0xb2, 0xce, 0x34, 0xfd, 0xbf],
+            //   nonce: [0xa9, 0x0d, 0xb6, 0x90, 0xbb, 0xa8, 0x3b, 0x78],
+            //   plain_text: vec!(0x31, 0xc4, 0x9e, 0x3c, 0xd3, 0xd8, 0x0a, 0x82, 0xe6, 0xb9, 0x03, 0x16, 0xdf, 0xb9, 0x4b, 0x38, 0xb8, 0xa2, 0x30, 0x42, 0x51, 0x9b, 0xf4, 0x0c, 0x81, 0x81, 0xfe, 0xc8, 0x73, 0xc9, 0x90, 0x02),
+            //   aad: vec!(0xdd, 0xbd, 0x7d, 0x82, 0x1d, 0x18, 0xd4, 0x4c, 0x66, 0x29, 0x5a, 0xbf, 0x24, 0x5b, 0x22, 0x7b, 0x5c, 0xf4, 0x36, 0x68, 0x11, 0xb7, 0xb3, 0x4c, 0x07, 0x67, 0x96, 0x00, 0xab, 0xdb, 0xfc, 0x29),
+            //   cipher_text: vec!(0x94, 0x62, 0x8f, 0xc3, 0x03, 0xa0, 0x54, 0x6e, 0xdd, 0x51, 0xe9, 0x66, 0xf2, 0xbd, 0x87, 0x96, 0x8f, 0x37, 0x80, 0x0c, 0x60, 0x7d, 0x5e, 0x5a, 0x91, 0xf7, 0x27, 0xfc, 0x1f, 0xec, 0x40, 0x6f),
+            //   tag: vec!(0xc2, 0x2e, 0xc4, 0xe4, 0xc8)
+            // },
+            // TestVector{
+            //   key: [0x29, 0x98, 0x49, 0x54, 0x06, 0x0b, 0xa0, 0x6e, 0xce, 0x1b, 0xcf, 0xc0, 0xe5, 0x01, 0x95, 0xf4, 0x63, 0x2c, 0x6d, 0xf4, 0x8d, 0xa1, 0xe0, 0x2a, 0xe6, 0xc1, 0x4f, 0x70, 0x65, 0x66, 0x89, 0x71],
+            //   nonce: [0xcc, 0xe5, 0x3a, 0x25, 0xae, 0xea, 0xf7, 0x47],
+            //   plain_text: vec!(0xb9, 0xb8, 0x74, 0x33, 0xa9, 0x89, 0x4f, 0x3c, 0x9c, 0xa8, 0x21, 0x26, 0x23, 0xd6, 0x23, 0x69, 0xa5, 0x65, 0xa2, 0xed, 0xcd, 0xdd, 0x27, 0x6e, 0x07, 0xd6, 0x11, 0xed, 0xa3, 0x59, 0x74, 0x26),
+            //   aad: vec!(0x19, 0xfa, 0x9a, 0xa5, 0x96, 0x97, 0x55, 0x9d, 0x8b, 0x46, 0xd9, 0xcd, 0x49, 0xc3, 0xb7, 0x63, 0xc0, 0xb7, 0x3b, 0x26, 0xb9, 0xe3, 0x34, 0xa3, 0xee, 0xac, 0x2c, 0x86, 0xfd, 0xba, 0xca, 0x8d),
+            //   cipher_text: vec!(0xb6, 0x8c, 0x83, 0x39, 0x77, 0x70, 0xc3, 0x6f, 0x07, 0x37, 0x10, 0x88, 0x2f, 0xa8, 0x6d, 0x43, 0xb0, 0xe5, 0x4e, 0x8e, 0xfe, 0xf0, 0xff, 0x75, 0x07, 0x56, 0x04, 0xd0, 0xd7, 0xec, 0x4e, 0x1b),
+            //   tag: vec!(0x40, 0xd4, 0xab, 0x75, 0x2f, 0x3d)
+            // },
+            // TestVector{
+            //   key: [0x5c, 0x3b, 0x83, 0x8b, 0x84, 0x10, 0x0b, 0x2a, 0x81, 0x8c, 0x08, 0x42, 0xe9, 0xfe, 0x19, 0xa7, 0xc5, 0x0c, 0xf5, 0xf3, 0xea, 0x73, 0x36, 0x4c, 0x81, 0x6e, 0xf5, 0x88, 0xe5, 0x00, 0xff, 0x3f],
+            //   nonce: [0xfd, 0xf6, 0xb0, 0x22, 0x9e, 0x4b, 0xcc, 0x2a],
+            //   plain_text: vec!(0x2b, 0xa9, 0x19, 0x04, 0xc1, 0x43, 0xbe, 0x99, 0x29, 0x7b, 0x39, 0xf5, 0x28, 0x56, 0x90, 0x4a, 0xf4, 0x17, 0x05, 0xc1, 0x76, 0xc8, 0xc6, 0x55, 0x4b, 0x6b, 0xc8, 0x9b, 0xdd, 0xff, 0xbc, 0xc1),
+            //   aad: vec!(0x35, 0x39, 0xd9, 0xdd, 0x82, 0x1f, 0x00, 0x4f, 0x4c, 0xed, 0x16, 0x37, 0x07, 0x1f, 0x4b, 0xe6, 0xab, 0xd7, 0xfe, 0x98, 0xf0, 0x17, 0xf0, 0xa8, 0xce, 0x3f, 0x49, 0xdc, 0x8d, 0x49, 0x6f, 0x46),
+            //   cipher_text: vec!(0xff, 0x9d, 0x6d, 0x92, 0x4e, 0x73, 0x7a, 0x1d, 0xf8, 0xc2, 0xbd, 0x30, 0x47, 0xe4, 0x0a, 0xb4, 0x01, 0xf9, 0x03, 0xaa, 0x0e, 0x5b, 0x51, 0xac, 0xb9, 0x91, 0xba, 0xc3, 0x8a, 0xc2, 0xcc, 0x4d),
+            //   tag: vec!(0x1b, 0xca, 0xa4, 0x15, 0xa6, 0xa3, 0xc7)
+            // },
+            TestVector {
+                key: [
+                    0x6d, 0x65, 0xe6, 0x27, 0xca, 0xb6, 0xd5, 0xeb, 0x1a, 0x08, 0x8b, 0x25, 0xbd,
+                    0x6c, 0x3a, 0x8a, 0x00, 0x4a, 0x7a, 0x19, 0xcc, 0xca, 0xe9, 0x09, 0xd6, 0x2f,
+                    0xed, 0x35, 0x59, 0xc8, 0x12, 0xf7,
+                ],
+                nonce: [0x7f, 0xf0, 0x0a, 0x87, 0x98, 0xb7, 0x92, 0xde],
+                plain_text: vec![
+                    0x68, 0x48, 0xee, 0x4a, 0xc8, 0x20, 0x29, 0x1a, 0x2e, 0x1d, 0xc3, 0xba, 0xad,
+                    0x97, 0xf1, 0xad, 0x8b, 0x71, 0x60, 0xdf, 0xea, 0xa1, 0xbc, 0x83, 0xb2, 0x70,
+                    0x0a, 0xe4, 0x2b, 0x5a, 0x36, 0x6b,
+                ],
+                aad: vec![
+                    0xd2, 0x43, 0x7b, 0x13, 0x06, 0xbf, 0x0e, 0xa2, 0x11, 0x44, 0x9f, 0xac, 0x86,
+                    0x3c, 0xa0, 0xd1, 0x07, 0x4d, 0x84, 0xca, 0xee, 0x90, 0x09, 0xc5, 0xd5, 0x4b,
+                    0x9e, 0x9b, 0xdc, 0x8d, 0xe6, 0xb1,
+                ],
+                cipher_text: vec![
+                    0x2d, 0xa0, 0xab, 0xe2, 0xa7, 0x1e, 0x1c, 0x0b, 0x1a, 0xb3, 0x09, 0xc1, 0x60,
+                    0xa8, 0xce, 0xbe, 0x45, 0xc6, 0xe1, 0x61, 0x70, 0xaa, 0x55, 0x61, 0x80, 0x64,
+                    0x84, 0xba, 0x2b, 0x5b, 0x9a, 0x9a,
+                ],
+                tag: vec![0x56, 0x60, 0x03, 0xe1, 0xf7, 0x8d, 0x2a, 0x90],
+            },
+            TestVector {
+                key: [
+                    0x63, 0x40, 0x10, 0x46, 0xa9, 0x6e, 0xfb, 0xc8, 0xc6, 0x48, 0x3a, 0x2c, 0x39,
+                    0x6b, 0x2a, 0x59, 0x3d, 0x3f, 0xae, 0x0d, 0xb5, 0x65, 0x52, 0x5b, 0x85, 0x99,
+                    0x9f, 0xae, 0x13, 0xa4, 0x6b, 0x6a,
+                ],
+                nonce: [0x05, 0x13, 0x93, 0xd7, 0x75, 0xe6, 0x35, 0xee],
+                plain_text: vec![
+                    0x2b, 0x4b, 0x64, 0x77, 0x58, 0x03, 0x82, 0xaa, 0xe7, 0x82, 0xf8, 0xb5, 0x77,
+                    0x2c, 0x09, 0x48, 0xa4, 0x44, 0xd8, 0xd9, 0x5c, 0xaa, 0xcd, 0x85, 0xc0, 0x85,
+                    0x6c, 0x7e, 0x43, 0x93, 0xfe, 0x09,
+                ],
+                aad: vec![
+                    0x3d, 0x84, 0xd2, 0xe7, 0x0e, 0x9c, 0x06, 0x2d, 0x1f, 0x51, 0x1e, 0xb6, 0x85,
+                    0xa9, 0xa9, 0x0c, 0x8d, 0x5f, 0xa5, 0x0e, 0xad, 0xf8, 0x45, 0x5c, 0x71, 0x48,
+                    0x66, 0x6b, 0x3e, 0x71, 0x55, 0xe0,
+                ],
+                cipher_text: vec![
+                    0x88, 0x0c, 0x11, 0x23, 0xe5, 0x4f, 0xd8, 0xff, 0xb3, 0xc2, 0x93, 0x72, 0x0d,
+                    0xd1, 0x74, 0x91, 0x35, 0x72, 0xe6, 0x19, 0xef, 0x46, 0x50, 0x4c, 0xda, 0xa6,
+                    0x4f, 0xc4, 0x51, 0xb0, 0xec, 0x1c,
+                ],
+                tag: vec![0x33, 0x92, 0x74, 0x33, 0x9c, 0x88, 0xd5, 0x0a, 0xc0],
+            },
+            TestVector {
+                key: [
+                    0x29, 0x1f, 0xcc, 0xfc, 0xe0, 0x78, 0x2f, 0x17, 0x87, 0xd6, 0x2d, 0x4b, 0x92,
+                    0x93, 0xd2, 0xad, 0xa4, 0xc0, 0x4d, 0x37, 0xa8, 0x28, 0x8b, 0xa9, 0xba, 0x9a,
+                    0xae, 0x0d, 0x31, 0xaa, 0xd2, 0x04,
+                ],
+                nonce: [0x74, 0x50, 0xbb, 0xd6, 0x2e, 0x4a, 0xba, 0x7b],
+                plain_text: vec![
+                    0xad, 0xc2, 0x51, 0xe7, 0x93, 0x18, 0x1e, 0x5d, 0x4c, 0x4b, 0xd9, 0x83, 0xb8,
+                    0x53, 0xeb, 0x13, 0xf2, 0x09, 0x6c, 0xcb, 0x34, 0x09, 0x96, 0xb6, 0xec, 0xa4,
+                    0xcd, 0x21, 0x57, 0xef, 0xce, 0xc7,
+                ],
+                aad: vec![
+                    0x4c, 0x59, 0x8f, 0x6d, 0xee, 0xdc, 0x8c, 0x1d, 0x97, 0xda, 0x33, 0x65, 0x47,
+                    0x63, 0x49, 0x5c, 0xca, 0x35, 0x17, 0x43, 0x0e, 0xec, 0x4e, 0xdb, 0x00, 0x6b,
+                    0x10, 0xc9, 0x5e, 0x03, 0x1a, 0xe6,
+                ],
+                cipher_text: vec![
+                    0x28, 0xbd, 0xa2, 0x2e, 0x49, 0x22, 0xcd, 0x8f, 0xf6, 0x73, 0x9c, 0xd8, 0xa6,
+                    0xbd, 0xaf, 0xce, 0x03, 0x6d, 0x9c, 0x61, 0xa1, 0x45, 0xa6, 0x5c, 0xa1, 0xb8,
+                    0x6f, 0x6d, 0x4d, 0x32, 0x06, 0xa1,
+                ],
+                tag: vec![0xd9, 0x8f, 0xd4, 0x3f, 0xe7, 0xac, 0x74, 0xd4, 0xb0, 0x16],
+            },
+            TestVector {
+                key: [
+                    0xfa, 0x3a, 0x96, 0x74, 0xd4, 0xa0, 0xeb, 0x36, 0xb2, 0xf7, 0x54, 0x7c, 0x95,
+                    0x64, 0x43, 0xd0, 0x9e, 0x6b, 0x4e, 0x4a, 0xcf, 0xc9, 0xde, 0xda, 0x83, 0x8e,
+                    0xb7, 0xeb, 0xdb, 0x99, 0x9a, 0x8d,
+                ],
+                nonce: [0x0a, 0x25, 0x72, 0x59, 0x2c, 0x3b, 0xbb, 0xf6],
+                plain_text: vec![
+                    0xae, 0x27, 0xf7, 0x0f, 0xda, 0x9f, 0x5a, 0x5b, 0xe0, 0xf7, 0x04, 0xa2, 0x7f,
+                    0x0b, 0x8a, 0x9c, 0x04, 0xce, 0x83, 0xd3, 0xc2, 0xe0, 0xd7, 0xec, 0x15, 0x2d,
+                    0xa2, 0x5f, 0x47, 0x3b, 0x0c, 0x8a,
+                ],
+                aad: vec![
+                    0x6e, 0xe8, 0x70, 0x5a, 0x9a, 0x36, 0x55, 0xd1, 0x98, 0x49, 0x7a, 0xd4, 0x10,
+                    0xda, 0x02, 0x00, 0x58, 0x72, 0xec, 0xbe, 0x39, 0x78, 0x24, 0x85, 0x1b, 0x80,
+                    0xf4, 0x05, 0x0b, 0xfd, 0xd3, 0x11,
+                ],
+                cipher_text: vec![
+                    0xf3, 0x56, 0xcb, 0xd8, 0x8e, 0x4e, 0x2a, 0xff, 0x62, 0xd9, 0x1e, 0x3f, 0x91,
+                    0x40, 0x32, 0x08, 0x53, 0x88, 0x95, 0x5b, 0xbb, 0xa9, 0x95, 0xfd, 0xe0, 0x13,
+                    0x75, 0x8b, 0x87, 0x02, 0xe3, 0x8f,
+                ],
+                tag: vec![
+                    0x00, 0x32, 0x4c, 0x76, 0xfe, 0xcd, 0x3f, 0x50, 0xe1, 0xe3, 0xb8,
+                ],
+            },
+            TestVector {
+                key: [
+                    0x47, 0x1e, 0xc8, 0x7b, 0x99, 0x2b, 0x10, 0x4d, 0x36, 0x97, 0x48, 0xd9, 0x68,
+                    0x56, 0xb5, 0xf6, 0x61, 0x49, 0xcb, 0x45, 0xca, 0x05, 0xc1, 0x7f, 0x29, 0xd2,
+                    0x4e, 0xb9, 0x52, 0x6f, 0xe6, 0xdb,
+                ],
+                nonce: [0x23, 0xa2, 0xdf, 0x9e, 0xd0, 0xb4, 0x74, 0x39],
+                plain_text: vec![
+                    0x2b, 0x94, 0x52, 0xbc, 0xa0, 0xf4, 0x8e, 0x55, 0x19, 0xec, 0x3d, 0x07, 0x36,
+                    0x59, 0x76, 0x08, 0xdf, 0x6a, 0xd9, 0xce, 0x79, 0x9e, 0xba, 0x91, 0x3c, 0xff,
+                    0x71, 0x57, 0x3d, 0x79, 0xc0, 0x92,
+                ],
+                aad: vec![
+                    0xa5, 0x67, 0x22, 0xdd, 0xfa, 0xee, 0x5f, 0x1b, 0x64, 0x39, 0x8c, 0x22, 0x5e,
+                    0xe8, 0xbc, 0xdc, 0xfd, 0xe5, 0xc2, 0x12, 0x71, 0x01, 0xc3, 0x63, 0xbf, 0xac,
+                    0x52, 0xbc, 0x40, 0x9c, 0x10, 0x82,
+                ],
+                cipher_text: vec![
+                    0x7b, 0xbc, 0x46, 0x4a, 0xac, 0x5d, 0xd2, 0x9c, 0x25, 0x26, 0x2f, 0xe0, 0xb1,
+                    0x16, 0xc1, 0x76, 0xd8, 0x27, 0xc2, 0xcc, 0x8d, 0xd6, 0x34, 0x28, 0x39, 0x3b,
+                    0x0a, 0x91, 0x10, 0xf3, 0xc1, 0x94,
+                ],
+                tag: vec![
+                    0x2e, 0x87, 0xf4, 0xa6, 0x66, 0x3a, 0x62, 0xe4, 0x7c, 0x7e, 0x19, 0x7f,
+                ],
+            },
+            TestVector {
+                key: [
+                    0xa2, 0x9d, 0x1c, 0xfd, 0x4c, 0xcd, 0xc1, 0x88, 0x03, 0xfb, 0xca, 0x95, 0x00,
+                    0xf4, 0xbb, 0x29, 0xce, 0x99, 0xcf, 0xcb, 0xf8, 0xac, 0xc4, 0x1b, 0x82, 0x08,
+                    0xda, 0xe4, 0xb7, 0xee, 0x5d, 0x64,
+                ],
+                nonce: [0x63, 0x4f, 0x99, 0xe8, 0x8e, 0x23, 0x7e, 0xf0],
+                plain_text: vec![
+                    0x09, 0xee, 0x59, 0x82, 0xc5, 0x74, 0x3f, 0x39, 0x6d, 0x0c, 0x29, 0xc1, 0x3e,
+                    0x3f, 0xbb, 0x8f, 0xb8, 0x9f, 0x61, 0x70, 0x5d, 0xa0, 0x54, 0x66, 0x29, 0x1e,
+                    0x01, 0x0e, 0xff, 0xd5, 0x1a, 0x5c,
+                ],
+                aad: vec![
+                    0x56, 0x4d, 0xdd, 0xfc, 0xc3, 0x22, 0x7b, 0x41, 0x32, 0x44, 0xf1, 0x10, 0x5b,
+                    0x61, 0x0f, 0x19, 0x2d, 0xec, 0xf1, 0x5c, 0x4c, 0xfa, 0x06, 0x7f, 0x4d, 0x7f,
+                    0xcd, 0x6b, 0xd7, 0xaf, 0x11, 0xb8,
+                ],
+                cipher_text: vec![
+                    0x32, 0x91, 0x6b, 0x67, 0xa6, 0xf3, 0x27, 0x33, 0x62, 0x33, 0x44, 0xc9, 0x8c,
+                    0x49, 0x77, 0x3f, 0x3e, 0x72, 0x1d, 0xc2, 0xde, 0xd1, 0x05, 0xfb, 0x24, 0x57,
+                    0x99, 0x52, 0x5b, 0xc9, 0xc8, 0x4c,
+                ],
+                tag: vec![
+                    0xff, 0x46, 0x3c, 0x07, 0xe7, 0xef, 0x83, 0x13, 0x21, 0xd3, 0xfd, 0x77, 0x5f,
+                ],
+            },
+            TestVector {
+                key: [
+                    0x08, 0xba, 0x23, 0x61, 0x6d, 0x91, 0x11, 0x88, 0xf9, 0x1d, 0xa0, 0x63, 0x27,
+                    0x8b, 0xef, 0x12, 0x37, 0xdc, 0xbf, 0x17, 0xf5, 0x25, 0x85, 0xe5, 0x3c, 0x2c,
+                    0x4b, 0x6c, 0xf3, 0xac, 0x9f, 0x0d,
+                ],
+                nonce: [0x98, 0x9a, 0xe5, 0x93, 0xed, 0xdd, 0x38, 0x74],
+                plain_text: vec![
+                    0x74, 0x91, 0x52, 0xc9, 0x47, 0x89, 0x44, 0xc8, 0x27, 0x1c, 0x0c, 0x11, 0xe0,
+                    0x7b, 0xc1, 0xc5, 0x69, 0xee, 0xc0, 0x14, 0x93, 0xe6, 0x5b, 0x3b, 0x94, 0x84,
+                    0x2a, 0x1b, 0xf5, 0xd7, 0x21, 0xf8,
+                ],
+                aad: vec![
+                    0xa1, 0x2d, 0x1a, 0x45, 0xb7, 0xc9, 0xb9, 0x1a, 0xb0, 0x87, 0x51, 0xa7, 0x0b,
+                    0x75, 0x37, 0x14, 0x05, 0x2a, 0xd2, 0x4e, 0x0b, 0x26, 0x19, 0xfe, 0x8c, 0x3b,
+                    0xe3, 0x03, 0xc6, 0x5f, 0x2d, 0xbc,
+                ],
+                cipher_text: vec![
+                    0x34, 0xc4, 0x05, 0x38, 0xee, 0x1d, 0x22, 0xdd, 0xf8, 0xac, 0x29, 0x0d, 0xd7,
+                    0xd4, 0x23, 0xdf, 0xc6, 0x22, 0xb5, 0xcf, 0x8f, 0x34, 0x12, 0xa5, 0x34, 0x3e,
+                    0x27, 0x78, 0x22, 0xae, 0xa7, 0x13,
+                ],
+                tag: vec![
+                    0x01, 0x4c, 0x7c, 0x67, 0x8e, 0x09, 0x49, 0xe8, 0x80, 0x71, 0xd1, 0xfe, 0x35,
+                    0x31,
+                ],
+            },
+            TestVector {
+                key: [
+                    0xc2, 0xba, 0x8b, 0xed, 0x86, 0x34, 0x15, 0x6a, 0xfc, 0x6b, 0xfe, 0x37, 0x54,
+                    0xc9, 0x17, 0x44, 0xd4, 0x13, 0x1d, 0xe3, 0x9d, 0x05, 0x9f, 0x3a, 0x86, 0x63,
+                    0x99, 0xf9, 0x16, 0x55, 0x3b, 0x5c,
+                ],
+                nonce: [0x80, 0xfb, 0xf7, 0xb4, 0x33, 0xa4, 0xcd, 0x9c],
+                plain_text: vec![
+                    0x41, 0x9b, 0xe6, 0x62, 0x3e, 0x79, 0x64, 0xf9, 0xf2, 0x60, 0x68, 0xdd, 0x96,
+                    0x9e, 0x4a, 0x13, 0x96, 0x17, 0xe6, 0x7c, 0x5f, 0xfb, 0x26, 0x9b, 0x30, 0x13,
+                    0xc4, 0x33, 0xfe, 0x77, 0x1c, 0x77,
+                ],
+                aad: vec![
+                    0x39, 0x37, 0x59, 0x2d, 0xb7, 0x8a, 0x61, 0xff, 0x46, 0x96, 0x91, 0xb6, 0x80,
+                    0x07, 0x92, 0x01, 0x9b, 0xc2, 0xb3, 0xd4, 0x25, 0x12, 0xf2, 0x3c, 0x1b, 0x1a,
+                    0x66, 0xa8, 0x27, 0x44, 0x95, 0xcb,
+                ],
+                cipher_text: vec![
+                    0x9d, 0x5b, 0xd1, 0xc7, 0xe7, 0x66, 0x76, 0x3e, 0xb0, 0x06, 0x84, 0xc0, 0x38,
+                    0x04, 0x31, 0x11, 0xd8, 0xc6, 0x39, 0x0a, 0x8d, 0x6e, 0x17, 0xa1, 0x5e, 0xf9,
+                    0x7c, 0x02, 0xab, 0x16, 0xf0, 0x9c,
+                ],
+                tag: vec![
+                    0xa6, 0x4d, 0x0e, 0xeb, 0x4a, 0x01, 0x48, 0x1e, 0xc0, 0xce, 0xe8, 0xc1, 0xc3,
+                    0x57, 0xe3,
+                ],
+            },
+        ]
+    }
+}
+
+#[cfg(all(test, feature = "with-bench"))]
+mod bench {
+    use aead::{AeadDecryptor, AeadEncryptor};
+    use chacha20poly1305::ChaCha20Poly1305;
+    use test::Bencher;
+
+    #[bench]
+    pub fn chacha20poly1305_10(bh: &mut Bencher) {
+        let input = [1u8; 10];
+        let aad = [3u8; 10];
+        bh.iter(|| {
+            let mut cipher = ChaCha20Poly1305::new(&[0; 32], &[0; 8], &aad);
+            let mut decipher = ChaCha20Poly1305::new(&[0; 32], &[0; 8], &aad);
+
+            let mut output = [0u8; 10];
+            let mut tag = [0u8; 16];
+            let mut output2 = [0u8; 10];
+            cipher.encrypt(&input, &mut output, &mut tag);
+            decipher.decrypt(&output, &mut output2, &tag);
+        });
+        bh.bytes = 10u64;
+    }
+
+    #[bench]
+    pub fn chacha20poly1305_1k(bh: &mut Bencher) {
+        let input = [1u8; 1024];
+        let aad = [3u8; 1024];
+        bh.iter(|| {
+            let mut cipher = ChaCha20Poly1305::new(&[0; 32], &[0; 8], &aad);
+            let mut decipher = ChaCha20Poly1305::new(&[0; 32], &[0; 8], &aad);
+
+            let mut output = [0u8; 1024];
+            let mut tag = [0u8; 16];
+            let mut output2 = [0u8; 1024];
+
+            cipher.encrypt(&input, &mut output, &mut tag);
+            decipher.decrypt(&output, &mut output2, &tag);
+        });
+        bh.bytes = 1024u64;
+    }
+
+    #[bench]
+    pub fn chacha20poly1305_64k(bh: &mut Bencher) {
+        let input = [1u8; 65536];
+        let aad = [3u8; 65536];
+        bh.iter(|| {
+            let mut cipher = ChaCha20Poly1305::new(&[0; 32], &[0; 8], &aad);
+            let mut decipher = ChaCha20Poly1305::new(&[0; 32], &[0; 8], &aad);
+
+            let mut output = [0u8; 65536];
+            let mut tag = [0u8; 16];
+            let mut output2 = [0u8; 65536];
+
+            cipher.encrypt(&input, &mut output, &mut tag);
+            decipher.decrypt(&output, &mut output2, &tag);
+        });
+        bh.bytes = 65536u64;
+    }
+}
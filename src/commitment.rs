@@ -0,0 +1,80 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+ * This module implements hash-based commitments. A commitment to a value can be published without
+ * revealing the value, and later opened by revealing the value and the randomness used:
+ *
+ * `commitment = H(be64(len(randomness)) || randomness || be64(len(value)) || value)`
+ *
+ * Binding follows from the collision resistance of H; the length prefixes stop bytes from moving
+ * between randomness and value. Hiding requires the randomness to be secret and unpredictable, at
+ * least 32 bytes from a secure random number generator, and never reused.
+ */
+
+use sr_std::iter::repeat;
+use sr_std::prelude::*;
+
+use cryptoutil::write_u64_be;
+use digest::Digest;
+use util::fixed_time_eq;
+
+/**
+ * Commit to value using randomness. The commitment is digest.output_bytes() long.
+ */
+pub fn commit<D: Digest>(mut digest: D, value: &[u8], randomness: &[u8]) -> Vec<u8> {
+    let mut len = [0u8; 8];
+    digest.reset();
+    write_u64_be(&mut len, randomness.len() as u64);
+    digest.input(&len);
+    digest.input(randomness);
+    write_u64_be(&mut len, value.len() as u64);
+    digest.input(&len);
+    digest.input(value);
+    let mut out: Vec<u8> = repeat(0).take(digest.output_bytes()).collect();
+    digest.result(&mut out);
+    out
+}
+
+/**
+ * Check that value and randomness open commitment. The comparison runs in constant time.
+ */
+pub fn verify<D: Digest>(digest: D, commitment: &[u8], value: &[u8], randomness: &[u8]) -> bool {
+    fixed_time_eq(&commit(digest, value, randomness), commitment)
+}
+
+#[cfg(test)]
+mod test {
+    use commitment::{commit, verify};
+    use sha2::{Sha256, Sha512};
+    use sha3::Sha3;
+
+    #[test]
+    fn test_commit_verify() {
+        let randomness = [0x42u8; 32];
+        let c = commit(Sha256::new(), b"vote: yes", &randomness);
+        // SHA-256 of be64(32) || randomness || be64(9) || "vote: yes", from Python's hashlib.
+        let expected = "2c77186fe60a08bf0add4f0b14a0b7ff9ce806acc540dd3df326fc53bbc742c7";
+        assert_eq!(c, hex::decode(expected).unwrap());
+
+        assert!(verify(Sha256::new(), &c, b"vote: yes", &randomness));
+        // Binding: the commitment opens to nothing else.
+        assert!(!verify(Sha256::new(), &c, b"vote: no", &randomness));
+        assert!(!verify(Sha256::new(), &c, b"vote: yes", &[0x43; 32]));
+        assert!(!verify(Sha256::new(), &c[..31], b"vote: yes", &randomness));
+        // Moving bytes from the randomness into the value changes the commitment.
+        let shifted = [&randomness[..], b"v"].concat();
+        assert!(!verify(Sha256::new(), &c, b"ote: yes", &shifted));
+
+        // Hiding: different randomness gives unrelated commitments to the same value.
+        assert!(c != commit(Sha256::new(), b"vote: yes", &[0x43; 32]));
+
+        let c = commit(Sha512::new(), b"vote: yes", &randomness);
+        assert_eq!(c.len(), 64);
+        assert!(verify(Sha512::new(), &c, b"vote: yes", &randomness));
+        assert!(!verify(Sha3::sha3_512(), &c, b"vote: yes", &randomness));
+    }
+}
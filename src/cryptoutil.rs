@@ -168,6 +168,13 @@ pub fn read_u32_le(input: &[u8]) -> u32 {
     }
 }
 
+/// Read the value of a vector of bytes as a u64 value in little-endian format.
+pub fn read_u64_le(input: &[u8]) -> u64 {
+    let mut tmp = [0u8; 8];
+    tmp.copy_from_slice(&input[..8]);
+    u64::from_le_bytes(tmp)
+}
+
 /// Read the value of a vector of bytes as a u32 value in big-endian format.
 pub fn read_u32_be(input: &[u8]) -> u32 {
     //assert!(input.len() == 4);
@@ -289,7 +296,7 @@ pub fn add_bytes_to_bits(bits: u64, bytes: u64) -> u64 {
     let (new_high_bits, new_low_bits) = to_bits(bytes);
 
     if new_high_bits > 0 {
-        //        panic!("Numeric overflow occured.")
+        panic!("Numeric overflow occured.")
     }
 
     bits.checked_add(new_low_bits)
@@ -314,14 +321,14 @@ pub fn add_bytes_to_bits_tuple(bits: (u64, u64), bytes: u64) -> (u64, u64) {
             } else {
                 match hi.checked_add(new_high_bits) {
                     Some(y) => return (y, x),
-                    None => (0, 0), //panic!("Numeric overflow occured."),
+                    None => panic!("Numeric overflow occured."),
                 }
             }
         }
         None => {
             let z = match new_high_bits.checked_add(1) {
                 Some(w) => w,
-                None => 0, //panic!("Numeric overflow occured."),
+                None => panic!("Numeric overflow occured."),
             };
             match hi.checked_add(z) {
                 // This re-executes the addition that was already performed earlier when overflow
@@ -332,7 +339,7 @@ pub fn add_bytes_to_bits_tuple(bits: (u64, u64), bytes: u64) -> (u64, u64) {
                 // be UnsignedInt - overflow is not defined for Signed types. This function could
                 // be implemented for signed types as well if that were needed.
                 Some(y) => return (y, low.wrapping_add(new_low_bits)),
-                None => (0, 0), // panic!("Numeric overflow occured."),
+                None => panic!("Numeric overflow occured."),
             }
         }
     }
@@ -530,85 +537,201 @@ impl<T: FixedBuffer> StandardPadding for T {
     }
 }
 
-// #[cfg(test)]
-// pub mod test {
-//     use sr_std;
-//     use sr_std::iter::repeat;
-
-//     use rand::IsaacRng;
-//     use rand::distributions::{IndependentSample, Range};
-
-//     use cryptoutil::{add_bytes_to_bits, add_bytes_to_bits_tuple};
-//     use digest::Digest;
-
-//     /// Feed 1,000,000 'a's into the digest with varying input sizes and check that the result is
-//     /// correct.
-//     pub fn test_digest_1million_random<D: Digest>(digest: &mut D, blocksize: usize, expected: &str) {
-//         let total_size = 1000000;
-//         let buffer: Vec<u8> = repeat('a' as u8).take(blocksize * 2).collect();
-//         let mut rng = IsaacRng::new_unseeded();
-//         let range = Range::new(0, 2 * blocksize + 1);
-//         let mut count = 0;
-
-//         digest.reset();
-
-//         while count < total_size {
-//             let next = range.ind_sample(&mut rng);
-//             let remaining = total_size - count;
-//             let size = if next > remaining { remaining } else { next };
-//             digest.input(&buffer[..size]);
-//             count += size;
-//         }
-
-//         let result_str = digest.result_str();
-
-//         //assert!(expected == &result_str[..]);
-//     }
-
-//     // A normal addition - no overflow occurs
-//     #[test]
-//     fn test_add_bytes_to_bits_ok() {
-//         //assert!(add_bytes_to_bits(100, 10) == 180);
-//     }
-
-//     // A simple failure case - adding 1 to the max value
-//     #[test]
-//     #[should_panic]
-//     fn test_add_bytes_to_bits_overflow() {
-//         add_bytes_to_bits(core::u64::MAX, 1);
-//     }
-
-//     // A normal addition - no overflow occurs (fast path)
-//     #[test]
-//     fn test_add_bytes_to_bits_tuple_ok() {
-//         //assert!(add_bytes_to_bits_tuple((5, 100), 10) == (5, 180));
-//     }
-
-//     // The low order value overflows into the high order value
-//     #[test]
-//     fn test_add_bytes_to_bits_tuple_ok2() {
-//         //assert!(add_bytes_to_bits_tuple((5, core::u64::MAX), 1) == (6, 7));
-//     }
-
-//     // The value to add is too large to be converted into bits without overflowing its type
-//     #[test]
-//     fn test_add_bytes_to_bits_tuple_ok3() {
-//         //assert!(add_bytes_to_bits_tuple((5, 0), 0x4000000000000001) == (7, 8));
-//     }
-
-//     // A simple failure case - adding 1 to the max value
-//     #[test]
-//     #[should_panic]
-//     fn test_add_bytes_to_bits_tuple_overflow() {
-//         add_bytes_to_bits_tuple((core::u64::MAX, core::u64::MAX), 1);
-//     }
-
-//     // The value to add is too large to convert to bytes without overflowing its type, but the high
-//     // order value from this conversion overflows when added to the existing high order value
-//     #[test]
-//     #[should_panic]
-//     fn test_add_bytes_to_bits_tuple_overflow2() {
-//         let value: u64 = core::u64::MAX;
-//         add_bytes_to_bits_tuple((value - 1, 0), 0x8000000000000000);
-//     }
-// }
+#[cfg(test)]
+pub mod test {
+    use sr_std::iter::repeat;
+    use sr_std::prelude::*;
+
+    use cryptoutil::{add_bytes_to_bits, add_bytes_to_bits_tuple};
+    use digest::Digest;
+    use symmetriccipher::SynchronousStreamCipher;
+    use sr_std::{cmp, fmt, str};
+
+    /// A small xorshift generator used in place of an external RNG so that randomized tests are
+    /// reproducible between runs.
+    pub struct XorShift(pub u64);
+
+    impl XorShift {
+        pub fn next_below(&mut self, bound: usize) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % bound as u64) as usize
+        }
+    }
+
+    /// A fixed size fmt::Write target, so that Display impls can be checked without allocating.
+    pub struct FmtBuffer {
+        buf: [u8; 64],
+        len: usize,
+    }
+
+    impl FmtBuffer {
+        pub fn as_str(&self) -> &str {
+            str::from_utf8(&self.buf[..self.len]).unwrap()
+        }
+    }
+
+    impl fmt::Write for FmtBuffer {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            if end > self.buf.len() {
+                return Err(fmt::Error);
+            }
+            self.buf[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    /// Check a stream cipher against a reference keystream. Each step uses a fresh instance from
+    /// new_cipher: the raw keystream must match, encrypting a known plaintext in uneven pieces
+    /// must give plaintext XOR keystream, and decrypting that must give the plaintext back.
+    pub fn test_stream_cipher<C, F>(new_cipher: F, keystream: &[u8])
+    where
+        C: SynchronousStreamCipher,
+        F: Fn() -> C,
+    {
+        let len = keystream.len();
+        let mut out: Vec<u8> = repeat(0).take(len).collect();
+        new_cipher().keystream(&mut out);
+        assert_eq!(&out[..], keystream);
+
+        let plaintext: Vec<u8> = (0..len).map(|i| (i * 7 + 3) as u8).collect();
+        let expected: Vec<u8> = plaintext.iter().zip(keystream).map(|(p, k)| p ^ k).collect();
+        let mut cipher = new_cipher();
+        let mut pos = 0;
+        let mut step = 1;
+        while pos < len {
+            let end = cmp::min(pos + step, len);
+            cipher.process(&plaintext[pos..end], &mut out[pos..end]);
+            pos = end;
+            step = step * 3 % 17 + 1;
+        }
+        assert_eq!(out, expected);
+
+        let mut decrypted: Vec<u8> = repeat(0).take(len).collect();
+        new_cipher().process(&expected, &mut decrypted);
+        assert_eq!(decrypted, plaintext);
+    }
+
+    /// Format value with its Display impl into a FmtBuffer.
+    pub fn display<T: fmt::Display>(value: &T) -> FmtBuffer {
+        let mut out = FmtBuffer { buf: [0; 64], len: 0 };
+        fmt::write(&mut out, format_args!("{}", value)).unwrap();
+        out
+    }
+
+    /// Check that err formats as msg, and with the std feature, that it does so through the
+    /// std::error::Error trait object too.
+    #[cfg(feature = "std")]
+    pub fn check_display<E: ::std::error::Error>(err: &E, msg: &str) {
+        use std::error::Error;
+        assert_eq!(display(err).as_str(), msg);
+        let err: &dyn Error = err;
+        assert_eq!(err.to_string(), msg);
+    }
+
+    /// Check that err formats as msg.
+    #[cfg(not(feature = "std"))]
+    pub fn check_display<E: fmt::Display>(err: &E, msg: &str) {
+        assert_eq!(display(err).as_str(), msg);
+    }
+
+    /// Format value with its Debug impl into a FmtBuffer.
+    pub fn debug<T: fmt::Debug>(value: &T) -> FmtBuffer {
+        let mut out = FmtBuffer { buf: [0; 64], len: 0 };
+        fmt::write(&mut out, format_args!("{:?}", value)).unwrap();
+        out
+    }
+
+    /// Feed 1,000,000 'a's into the digest with varying input sizes and check that the result is
+    /// correct.
+    pub fn test_digest_1million_random<D: Digest>(digest: &mut D, blocksize: usize, expected: &str) {
+        let total_size = 1000000;
+        let buffer: Vec<u8> = repeat('a' as u8).take(blocksize * 2).collect();
+        let mut rng = XorShift(0x2545f4914f6cdd1d);
+        let mut count = 0;
+
+        digest.reset();
+
+        while count < total_size {
+            let next = rng.next_below(2 * blocksize + 1);
+            let remaining = total_size - count;
+            let size = if next > remaining { remaining } else { next };
+            digest.input(&buffer[..size]);
+            count += size;
+        }
+
+        let result_str = digest.result_str();
+
+        assert!(expected == &result_str[..]);
+    }
+
+    /// Hash a 10 MiB buffer with a single input() call and check that the result matches feeding
+    /// the same bytes in 1 KiB chunks.
+    pub fn test_digest_large_single_input<D: Digest>(digest: &mut D) {
+        let buffer: Vec<u8> = (0..10 << 20).map(|i: usize| (i ^ (i >> 11)) as u8).collect();
+        let mut whole: Vec<u8> = repeat(0).take(digest.output_bytes()).collect();
+        let mut chunked = whole.clone();
+
+        digest.reset();
+        digest.input(&buffer);
+        digest.result(&mut whole);
+
+        digest.reset();
+        for chunk in buffer.chunks(1024) {
+            digest.input(chunk);
+        }
+        digest.result(&mut chunked);
+
+        assert_eq!(whole, chunked);
+    }
+
+    // A normal addition - no overflow occurs
+    #[test]
+    fn test_add_bytes_to_bits_ok() {
+        assert!(add_bytes_to_bits(100, 10) == 180);
+    }
+
+    // A simple failure case - adding 1 to the max value
+    #[test]
+    #[should_panic]
+    fn test_add_bytes_to_bits_overflow() {
+        add_bytes_to_bits(u64::max_value(), 1);
+    }
+
+    // A normal addition - no overflow occurs (fast path)
+    #[test]
+    fn test_add_bytes_to_bits_tuple_ok() {
+        assert!(add_bytes_to_bits_tuple((5, 100), 10) == (5, 180));
+    }
+
+    // The low order value overflows into the high order value
+    #[test]
+    fn test_add_bytes_to_bits_tuple_ok2() {
+        assert!(add_bytes_to_bits_tuple((5, u64::max_value()), 1) == (6, 7));
+    }
+
+    // The value to add is too large to be converted into bits without overflowing its type
+    #[test]
+    fn test_add_bytes_to_bits_tuple_ok3() {
+        assert!(add_bytes_to_bits_tuple((5, 0), 0x4000000000000001) == (7, 8));
+    }
+
+    // A simple failure case - adding 1 to the max value
+    #[test]
+    #[should_panic]
+    fn test_add_bytes_to_bits_tuple_overflow() {
+        add_bytes_to_bits_tuple((u64::max_value(), u64::max_value()), 1);
+    }
+
+    // The value to add is too large to convert to bytes without overflowing its type, but the high
+    // order value from this conversion overflows when added to the existing high order value
+    #[test]
+    #[should_panic]
+    fn test_add_bytes_to_bits_tuple_overflow2() {
+        let value: u64 = u64::max_value();
+        add_bytes_to_bits_tuple((value - 1, 0), 0x8000000000000000);
+    }
+}
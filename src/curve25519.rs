@@ -1,9 +1,12 @@
+use hkdf::{hkdf_expand, hkdf_extract};
+use sha2::Sha256;
 use sr_std::cmp::{min, Eq, PartialEq};
+use sr_std::fmt;
 use sr_std::marker::*;
 use sr_std::ops::{Add, Mul, Sub};
 use sr_std::prelude::*;
 use step_by::RangeExt;
-use util::fixed_time_eq;
+use util::{fixed_time_eq, secure_memset};
 
 /*
 fe means field element.
@@ -30,6 +33,7 @@ static FE_ONE: Fe = Fe([1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
 static FE_SQRTM1: Fe = Fe([
     -32595792, -7943725, 9377950, 3500415, 12389472, -272473, -25146209, -2005654, 326686, 11406482,
 ]);
+static FE_A: Fe = Fe([486662, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
 static FE_D: Fe = Fe([
     -10913610, 13857413, -15372611, 6949391, 114729, -8787816, -6275908, -3247719, -18696448,
     -12055116,
@@ -768,6 +772,15 @@ impl Fe {
         ]);
     }
 
+    /*
+    Replace self with -self if cond == 1, leave it unchanged if cond == 0. Runs in constant time;
+    cond must be 0 or 1.
+    */
+    pub fn conditional_negate(&mut self, cond: u8) {
+        let negated = self.neg();
+        self.maybe_set(&negated, cond as i32);
+    }
+
     /*
     h = f * 121666
     Can overlap h with f.
@@ -845,7 +858,7 @@ impl Fe {
     /*
     See fe_mul.c for discussion of implementation strategy.
     */
-    fn square(&self) -> Fe {
+    pub fn square(&self) -> Fe {
         let &Fe(f) = self;
 
         let f0 = f[0];
@@ -1228,18 +1241,18 @@ impl Fe {
         !fixed_time_eq(bs.as_ref(), zero.as_ref())
     }
 
-    fn is_negative(&self) -> bool {
+    pub fn is_negative(&self) -> bool {
         (self.to_bytes()[0] & 1) != 0
     }
 
-    fn neg(&self) -> Fe {
+    pub fn neg(&self) -> Fe {
         let &Fe(f) = self;
         Fe([
             -f[0], -f[1], -f[2], -f[3], -f[4], -f[5], -f[6], -f[7], -f[8], -f[9],
         ])
     }
 
-    fn pow25523(&self) -> Fe {
+    pub fn pow25523(&self) -> Fe {
         let z2 = self.square();
         let z8 = (0..2).fold(z2, |x, _| x.square());
         let z9 = *self * z8;
@@ -1666,12 +1679,9 @@ impl GePrecomp {
         t.maybe_set(&GE_PRECOMP_BASE[pos][5], equal(babs, 6));
         t.maybe_set(&GE_PRECOMP_BASE[pos][6], equal(babs, 7));
         t.maybe_set(&GE_PRECOMP_BASE[pos][7], equal(babs, 8));
-        let minus_t = GePrecomp {
-            y_plus_x: t.y_minus_x,
-            y_minus_x: t.y_plus_x,
-            xy2d: t.xy2d.neg(),
-        };
-        t.maybe_set(&minus_t, bnegative as i32);
+        // -t swaps y+x with y-x and negates xy2d.
+        t.y_plus_x.maybe_swap_with(&mut t.y_minus_x, bnegative as i32);
+        t.xy2d.conditional_negate(bnegative);
         t
     }
 }
@@ -2592,6 +2602,50 @@ pub fn sc_muladd(s: &mut [u8], a: &[u8], b: &[u8], c: &[u8]) {
     s[31] = (s11 >> 17) as u8;
 }
 
+/*
+Output:
+    s[0]+256*s[1]+...+256^31*s[31] = ab mod l
+*/
+pub fn sc_mul(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let mut s = [0u8; 32];
+    sc_muladd(&mut s, a, b, &[0u8; 32]);
+    s
+}
+
+// l - 2, little-endian.
+static L_MINUS_2: [u8; 32] = [
+    0xeb, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9,
+    0xde, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x10,
+];
+
+/*
+Output:
+    s[0]+256*s[1]+...+256^31*s[31] = a^(l-2) mod l
+
+By Fermat's little theorem this is the inverse of a mod l when a is not a multiple of l, and 0
+otherwise. The sequence of squarings and multiplications depends only on the public exponent, so
+the running time does not depend on a.
+*/
+pub fn sc_invert(a: &[u8; 32]) -> [u8; 32] {
+    let mut s = [0u8; 32];
+    s[0] = 1;
+    for pos in (0usize..253).rev() {
+        s = sc_mul(&s, &s);
+        if (L_MINUS_2[pos / 8] >> (pos & 7)) & 1 == 1 {
+            s = sc_mul(&s, a);
+        }
+    }
+    s
+}
+
+/**
+ * X25519 (RFC 7748): multiply the u-coordinate p by the clamped scalar n. The Montgomery ladder
+ * always runs 255 steps, and the scalar bits only select which operands are swapped, through the
+ * masked maybe_swap_with, so neither the branches taken nor the memory accessed depend on n. The
+ * top bit of p is ignored, and a point of small order gives an all-zero result, which callers
+ * doing key exchange should reject.
+ */
 pub fn curve25519(n: &[u8], p: &[u8]) -> [u8; 32] {
     let mut e = [0u8; 32];
     let mut x2;
@@ -2649,6 +2703,7 @@ pub fn curve25519(n: &[u8], p: &[u8]) -> [u8; 32] {
     }
     x2.maybe_swap_with(&mut x3, swap);
     z2.maybe_swap_with(&mut z3, swap);
+    secure_memset(&mut e, 0);
 
     (z2.invert() * x2).to_bytes()
 }
@@ -2659,9 +2714,129 @@ pub fn curve25519_base(x: &[u8]) -> [u8; 32] {
     curve25519(x, base.as_ref())
 }
 
+// The square root of a, if a is a square: a^((p + 3) / 8), multiplied by sqrt(-1) when that
+// only squares to -a. Both candidates are computed and one is picked with maybe_set, so only
+// whether a is a square affects control flow.
+fn fe_sqrt(a: &Fe) -> Option<Fe> {
+    let b = *a * a.pow25523();
+    let b2 = b.square().to_bytes();
+    let is_root = fixed_time_eq(&b2, &a.to_bytes());
+    let is_neg_root = fixed_time_eq(&b2, &a.neg().to_bytes());
+    let mut root = b * FE_SQRTM1;
+    root.maybe_set(&b, is_root as i32);
+    if is_root | is_neg_root {
+        Some(root)
+    } else {
+        None
+    }
+}
+
+// Whether u is the u-coordinate of a point on curve25519 rather than on its twist, that is
+// whether u^3 + A u^2 + u is a square.
+fn is_on_curve(u: &Fe) -> bool {
+    fe_sqrt(&(*u * (u.square() + FE_A * *u + FE_ONE))).is_some()
+}
+
+/**
+ * The Elligator 2 map (RFC 9380, section 6.7.1, with Z = 2): map a field element r, given as 32
+ * little-endian bytes with the top bit ignored, to the u-coordinate of a point on curve25519. It
+ * computes w = -A / (1 + 2 r^2), or w = -A if the denominator is zero, and returns w if w is on
+ * the curve and -w - A otherwise. r and -r give the same point.
+ *
+ * The output may have small order and is not uniformly distributed over the curve; hash to curve
+ * constructions clear the cofactor and add two mapped points, as RFC 9380 describes.
+ */
+pub fn elligator2_map(field_bytes: &[u8; 32]) -> [u8; 32] {
+    let r = Fe::from_bytes(field_bytes);
+    let d = FE_ONE + r.square_and_double();
+    let mut w = FE_A.neg() * d.invert();
+    w.maybe_set(&FE_A.neg(), !d.is_nonzero() as i32);
+    let mut u = w.neg() - FE_A;
+    u.maybe_set(&w, is_on_curve(&w) as i32);
+    u.to_bytes()
+}
+
+/**
+ * The inverse of elligator2_map: find a representative r with elligator2_map(r) == u, for
+ * encoding a point so that it is indistinguishable from random bytes. Only about half of all
+ * points have one; None is returned for the rest and for u-coordinates on the twist, and callers
+ * should then generate a new key pair.
+ *
+ * The representative returned is the one of r and -r below (p - 1) / 2, so its top two bits are
+ * always zero. Encodings meant to look random should fill them with random bits, and clear them
+ * again before calling elligator2_map.
+ */
+pub fn elligator2_inverse(u_bytes: &[u8; 32]) -> Option<[u8; 32]> {
+    let u = Fe::from_bytes(u_bytes);
+    if !is_on_curve(&u) {
+        return None;
+    }
+    // w = u in elligator2_map, so r^2 = -(u + A) / 2u. u = 0 gives r = 0, which maps back to 0.
+    let r2 = (u + FE_A).neg() * (u + u).invert();
+    let mut r = fe_sqrt(&r2)?;
+    // r > (p - 1) / 2 exactly when 2r mod p is odd.
+    let large = (r + r).is_negative() as u8;
+    r.conditional_negate(large);
+    Some(r.to_bytes())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum X25519Error {
+    /// The peer's public key has small order, so the shared secret is all zeros.
+    WeakSharedSecret,
+}
+
+impl fmt::Display for X25519Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            X25519Error::WeakSharedSecret => f.write_str("X25519 shared secret is all zeros"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for X25519Error {}
+
+/**
+ * X25519 key agreement followed by HKDF-SHA256: fill okm with
+ * HKDF-Expand(HKDF-Extract(salt, X25519(secret_key, public_key)), info).
+ *
+ * If public_key is a point of small order the shared secret is all zeros whatever secret_key is,
+ * and an attacker who chose it would know the derived key. That case is detected with a constant
+ * time comparison and returns WeakSharedSecret before HKDF is run, leaving okm untouched.
+ */
+pub fn x25519_hkdf_sha256(
+    secret_key: &[u8; 32],
+    public_key: &[u8; 32],
+    salt: &[u8],
+    info: &[u8],
+    okm: &mut [u8],
+) -> Result<(), X25519Error> {
+    let mut shared = curve25519(secret_key, public_key);
+    if fixed_time_eq(&shared, &[0u8; 32]) {
+        return Err(X25519Error::WeakSharedSecret);
+    }
+    let mut prk = [0u8; 32];
+    hkdf_extract(Sha256::new(), salt, &shared, &mut prk);
+    hkdf_expand(Sha256::new(), &prk, info, okm);
+    secure_memset(&mut shared, 0);
+    secure_memset(&mut prk, 0);
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use curve25519::{curve25519_base, Fe};
+    use cryptoutil::test::XorShift;
+    use curve25519::{
+        curve25519, curve25519_base, elligator2_inverse, elligator2_map, sc_invert, sc_mul,
+        x25519_hkdf_sha256, Fe, X25519Error,
+    };
+
+    fn from_hex(s: &str) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        out.copy_from_slice(&hex::decode(s).unwrap()[..]);
+        out
+    }
 
     #[test]
     fn from_to_bytes_preserves() {
@@ -2693,6 +2868,53 @@ mod tests {
         //assert!(g == f_initial);
     }
 
+    #[test]
+    fn conditional_negate() {
+        let mut e: Vec<u8> = (0u8..32).map(|i| i.wrapping_mul(37)).collect();
+        e[31] &= 127;
+        let f = Fe::from_bytes(&e[..]);
+        assert_eq!(f.to_bytes().to_vec(), e);
+
+        let mut g = f;
+        g.conditional_negate(0);
+        assert_eq!(g.to_bytes(), f.to_bytes());
+
+        g.conditional_negate(1);
+        assert!(g.to_bytes() != f.to_bytes());
+        assert_eq!((g + f).to_bytes(), [0u8; 32]);
+
+        g.conditional_negate(1);
+        assert_eq!(g.to_bytes().to_vec(), e);
+    }
+
+    #[test]
+    fn sc_invert_inverts() {
+        let mut one = [0u8; 32];
+        one[0] = 1;
+        let mut two = [0u8; 32];
+        two[0] = 2;
+        // (l + 1) / 2
+        let half: [u8; 32] = [
+            0xf7, 0xe9, 0x7a, 0x2e, 0x8d, 0x31, 0x09, 0x2c, 0x6b, 0xce, 0x7b, 0x51, 0xef, 0x7c,
+            0x6f, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x08,
+        ];
+        assert_eq!(sc_invert(&two), half);
+        assert_eq!(sc_invert(&one), one);
+
+        for i in 0..8u32 {
+            let mut a: [u8; 32] = [0; 32];
+            for (idx, b) in a.iter_mut().enumerate() {
+                *b = (idx as u32 * (1289 + i * 761)) as u8;
+            }
+            // Also covers inputs that are not reduced mod l.
+            a[31] &= if i % 2 == 0 { 0x0f } else { 0xff };
+            assert_eq!(sc_mul(&a, &sc_invert(&a)), one);
+        }
+
+        assert_eq!(sc_invert(&[0u8; 32]), [0u8; 32]);
+    }
+
     struct CurveGen {
         which: u32,
     }
@@ -2746,6 +2968,149 @@ mod tests {
         }
     }
 
+    #[test]
+    fn rfc7748_vectors() {
+        // RFC 7748, section 5.2. The second u-coordinate has its top bit set, which is ignored.
+        let cases = [
+            (
+                "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+                "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+                "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552",
+            ),
+            (
+                "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+                "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493",
+                "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957",
+            ),
+        ];
+        for &(k, u, out) in cases.iter() {
+            assert_eq!(curve25519(&from_hex(k), &from_hex(u)), from_hex(out));
+        }
+    }
+
+    #[test]
+    fn rfc7748_iterated() {
+        // RFC 7748, section 5.2: k, u = X25519(k, u), k, starting from the base point.
+        let mut k = [0u8; 32];
+        k[0] = 9;
+        let mut u = k;
+        for i in 0..1000 {
+            let r = curve25519(&k, &u);
+            u = k;
+            k = r;
+            if i == 0 {
+                assert_eq!(
+                    k,
+                    from_hex("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079")
+                );
+            }
+        }
+        assert_eq!(k, from_hex("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51"));
+    }
+
+    #[test]
+    fn low_order_points() {
+        // Points of order 1, 2, 4 and 8, plus non-canonical encodings of some of them. Clamping
+        // makes the scalar a multiple of 8, so each of them maps to zero.
+        let points = [
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0100000000000000000000000000000000000000000000000000000000000000",
+            "e0eb7a7c3b41b8ae1656e3faf19fc46ada098deb9c32b1fd866205165f49b800",
+            "5f9c95bca3508c24b1d0b1559c83ef5b04445cc4581c8e86d8224eddd09f1157",
+            "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "eeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+        ];
+        let k = from_hex("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4");
+        for p in points.iter() {
+            assert_eq!(curve25519(&k, &from_hex(p)), [0u8; 32]);
+        }
+    }
+
+    #[test]
+    fn x25519_hkdf_sha256_derives_key() {
+        // RFC 7748 section 6.1 key pair; expected output from Python's cryptography HKDF.
+        let alice_sk = from_hex("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        let bob_pk = from_hex("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f");
+        let expected = hex::decode(
+            "8b0a3b324fe2ee20c5f9e6a803f18a23c60099d057408cbd804e1d719618fcec77db68070bf23e92f159",
+        )
+        .unwrap();
+        let mut okm = [0u8; 42];
+        x25519_hkdf_sha256(&alice_sk, &bob_pk, b"x25519 salt", b"x25519 info", &mut okm).unwrap();
+        assert_eq!(&okm[..], &expected[..]);
+    }
+
+    #[test]
+    fn x25519_hkdf_sha256_rejects_weak_secret() {
+        let sk = from_hex("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4");
+        // A point of order 8.
+        let pk = from_hex("e0eb7a7c3b41b8ae1656e3faf19fc46ada098deb9c32b1fd866205165f49b800");
+        let mut okm = [0xaau8; 32];
+        assert_eq!(
+            x25519_hkdf_sha256(&sk, &pk, b"", b"", &mut okm),
+            Err(X25519Error::WeakSharedSecret)
+        );
+        assert_eq!(okm, [0xaa; 32]);
+    }
+
+    // RFC 9380 writes field elements as big-endian integers.
+    fn from_be_hex(s: &str) -> [u8; 32] {
+        let mut out = from_hex(s);
+        out.reverse();
+        out
+    }
+
+    #[test]
+    fn elligator2_rfc9380_vectors() {
+        // RFC 9380 appendix J.7.2, curve25519_XMD:SHA-512_ELL2_NU_: u[0] and the mapped Q.x for
+        // the messages "" and "abc".
+        let tests = [
+            (
+                "608d892b641f0328523802a6603427c26e55e6f27e71a91a478148d45b5093cd",
+                "51125222da5e763d97f3c10fcc92ea6860b9ccbbd2eb1285728f566721c1e65b",
+            ),
+            (
+                "46f5b22494bfeaa7f232cc8d054be68561af50230234d7d1d63d1d9abeca8da5",
+                "7d56d1e08cb0ccb92baf069c18c49bb5a0dcd927eff8dcf75ca921ef7f3e6eeb",
+            ),
+        ];
+        for &(r, u) in tests.iter() {
+            assert_eq!(elligator2_map(&from_be_hex(r)), from_be_hex(u));
+        }
+        assert_eq!(elligator2_map(&[0; 32]), [0; 32]);
+    }
+
+    #[test]
+    fn elligator2_inverse_round_trip() {
+        let mut base = [0u8; 32];
+        base[0] = 9;
+        let r = from_be_hex("2ba9f0e0c4a1a7e7df7610ec47fd669eb1f6769d41eb8af04429dbc1ad2d76b9");
+        assert_eq!(elligator2_inverse(&base), Some(r));
+        assert_eq!(elligator2_map(&r), base);
+        assert_eq!(elligator2_inverse(&[0; 32]), Some([0; 32]));
+
+        let mut rng = XorShift(0x2545f4914f6cdd1d);
+        for _ in 0..32 {
+            let mut r = [0u8; 32];
+            for b in r.iter_mut() {
+                *b = rng.next_below(256) as u8;
+            }
+            r[31] &= 0x7f;
+            let u = elligator2_map(&r);
+            let r2 = elligator2_inverse(&u).unwrap();
+            assert_eq!(r2[31] & 0xc0, 0);
+            assert_eq!(elligator2_map(&r2), u);
+        }
+
+        // u = 18 is on the curve but has no representative; u = 2 is on the twist.
+        let mut u = [0u8; 32];
+        u[0] = 18;
+        assert_eq!(elligator2_inverse(&u), None);
+        u[0] = 2;
+        assert_eq!(elligator2_inverse(&u), None);
+    }
+
     #[test]
     fn base_example() {
         let sk: [u8; 32] = [
@@ -11,6 +11,16 @@
 use sr_std::iter::repeat;
 use sr_std::prelude::*;
 
+use blake2b::Blake2b;
+use blake2s::Blake2s;
+use cryptoutil::write_u32_be;
+use md5::Md5;
+use ripemd160::Ripemd160;
+use sha1::Sha1;
+use sha2::{Sha224, Sha256, Sha384, Sha512, Sha512Trunc224, Sha512Trunc256};
+use sha3::Sha3;
+use whirlpool::Whirlpool;
+
 /**
  * The Digest trait specifies an interface common to digest functions, such as SHA-1 and the SHA-2
  * family of digest functions.
@@ -57,6 +67,20 @@ pub trait Digest {
      */
     fn block_size(&self) -> usize;
 
+    /**
+     * Get the size in bytes of the state carried from one block to the next.
+     *
+     * For the Merkle-Damgard hashes (MD5, SHA-1, SHA-256, SHA-512, RIPEMD-160 and Whirlpool)
+     * this equals output_bytes(): the output is the whole state. Anyone who knows H(m) and the
+     * length of m can therefore resume hashing from it and compute H(m || padding || suffix)
+     * without knowing m. This is a length extension, and it is why H(key || message) must never
+     * be used as a MAC; use Hmac instead. SHA-224, SHA-384, SHA-512/224 and SHA-512/256 output
+     * only part of their state, and SHA-3 and BLAKE2 finalize in a way that cannot be resumed.
+     */
+    fn internal_state_len(&self) -> usize {
+        self.output_bytes()
+    }
+
     /**
      * Convenience function that feeds a string into a digest.
      *
@@ -79,3 +103,360 @@ pub trait Digest {
         hex::encode(&buf[..])
     }
 }
+
+/**
+ * An object-safe Digest that can also be cloned from behind a Box, for code that picks its hash
+ * function at runtime. Every Digest that is Clone implements it.
+ */
+pub trait DynDigest: Digest {
+    /**
+     * Clone the digest, including any input processed so far, into a new Box.
+     */
+    fn box_clone(&self) -> Box<dyn DynDigest>;
+}
+
+impl<D: Digest + Clone + 'static> DynDigest for D {
+    fn box_clone(&self) -> Box<dyn DynDigest> {
+        Box::new(self.clone())
+    }
+}
+
+/**
+ * A Digest whose unfinished state can be serialized and later restored, so that hashing can resume
+ * where it left off.
+ */
+pub trait DigestState: Digest + Sized {
+    /**
+     * Serialize the state of an unfinished digest. Panics if result() has already been called.
+     */
+    fn save_state(&self) -> Vec<u8>;
+
+    /**
+     * Resume a digest from a state produced by save_state(). Returns None if the state is
+     * malformed.
+     */
+    fn restore_state(state: &[u8]) -> Option<Self>;
+}
+
+/**
+ * Create a digest from its name, or return None if the name is unknown. The recognized names are
+ * md5, sha1, sha224, sha256, sha384, sha512, sha512/224, sha512/256, sha3-224, sha3-256,
+ * sha3-384, sha3-512, keccak224, keccak256, keccak384, keccak512, ripemd160, whirlpool,
+ * blake2b-512 and blake2s-256.
+ */
+pub fn from_name(name: &str) -> Option<Box<dyn DynDigest>> {
+    let digest: Box<dyn DynDigest> = match name {
+        "md5" => Box::new(Md5::new()),
+        "sha1" => Box::new(Sha1::new()),
+        "sha224" => Box::new(Sha224::new()),
+        "sha256" => Box::new(Sha256::new()),
+        "sha384" => Box::new(Sha384::new()),
+        "sha512" => Box::new(Sha512::new()),
+        "sha512/224" => Box::new(Sha512Trunc224::new()),
+        "sha512/256" => Box::new(Sha512Trunc256::new()),
+        "sha3-224" => Box::new(Sha3::sha3_224()),
+        "sha3-256" => Box::new(Sha3::sha3_256()),
+        "sha3-384" => Box::new(Sha3::sha3_384()),
+        "sha3-512" => Box::new(Sha3::sha3_512()),
+        "keccak224" => Box::new(Sha3::keccak224()),
+        "keccak256" => Box::new(Sha3::keccak256()),
+        "keccak384" => Box::new(Sha3::keccak384()),
+        "keccak512" => Box::new(Sha3::keccak512()),
+        "ripemd160" => Box::new(Ripemd160::new()),
+        "whirlpool" => Box::new(Whirlpool::new()),
+        "blake2b-512" => Box::new(Blake2b::new(64)),
+        "blake2s-256" => Box::new(Blake2s::new(32)),
+        _ => return None,
+    };
+    Some(digest)
+}
+
+/**
+ * MGF1, the mask generation function from PKCS #1 (RFC 8017, appendix B.2.1). Fills out with
+ * Hash(seed || C) for C = 0, 1, 2, ... encoded as 4 byte big-endian counters, truncating the last
+ * block. Used by RSA-OAEP and RSA-PSS.
+ *
+ * # Arguments
+ *
+ * * digest - The digest function to use; its current state is reset first.
+ * * seed - The seed to generate the mask from.
+ * * out - The buffer to fill with mask bytes.
+ */
+pub fn mgf1<D: Digest>(mut digest: D, seed: &[u8], out: &mut [u8]) {
+    let output_size = digest.output_bytes();
+    let mut block: Vec<u8> = repeat(0).take(output_size).collect();
+    let mut counter = [0u8; 4];
+    for (i, chunk) in out.chunks_mut(output_size).enumerate() {
+        digest.reset();
+        digest.input(seed);
+        write_u32_be(&mut counter, i as u32);
+        digest.input(&counter);
+        digest.result(&mut block[..]);
+        let len = chunk.len();
+        chunk.copy_from_slice(&block[..len]);
+    }
+}
+
+/**
+ * A source of unbounded output, such as a mask generator, read in pieces of any size. Successive
+ * reads continue the same stream, so reading n bytes and then m bytes yields the same bytes as
+ * reading n + m bytes at once.
+ */
+pub trait XofReader {
+    /**
+     * Fill out with the next out.len() bytes of the stream.
+     */
+    fn read(&mut self, out: &mut [u8]);
+}
+
+/**
+ * A mask generator over an extendable output function, as returned by mgf_xof().
+ */
+#[derive(Clone, Copy)]
+pub struct MgfXof<X> {
+    xof: X,
+}
+
+impl<X: Digest> XofReader for MgfXof<X> {
+    fn read(&mut self, out: &mut [u8]) {
+        self.xof.result(out);
+    }
+}
+
+/**
+ * A mask generation function built directly on an extendable output function: the mask is
+ * XOF(seed), read for as long as needed. With SHAKE128 or SHAKE256 this replaces MGF1, needing
+ * neither a counter nor one hash invocation per output block.
+ *
+ * # Arguments
+ *
+ * * xof - An extendable output Digest, one whose output_bits() is 0 such as Sha3::shake_256() or
+ *   Sha3XofCustom; its current state is reset first. Panics for fixed-size digests.
+ * * seed - The seed to generate the mask from.
+ */
+pub fn mgf_xof<X: Digest>(mut xof: X, seed: &[u8]) -> MgfXof<X> {
+    assert!(xof.output_bits() == 0, "mgf_xof requires an extendable output function");
+    xof.reset();
+    xof.input(seed);
+    MgfXof { xof: xof }
+}
+
+/**
+ * Hash a sequence of byte chunks, feeding each one to the digest in turn, and return the result.
+ * The digest's current state is reset first, so the output is the hash of the chunks'
+ * concatenation.
+ */
+pub fn hash_iter<D, I, C>(mut digest: D, chunks: I) -> Vec<u8>
+where
+    D: Digest,
+    I: IntoIterator<Item = C>,
+    C: AsRef<[u8]>,
+{
+    digest.reset();
+    for chunk in chunks {
+        digest.input(chunk.as_ref());
+    }
+    let mut out: Vec<u8> = repeat(0).take(digest.output_bytes()).collect();
+    digest.result(&mut out[..]);
+    out
+}
+
+/**
+ * Compute the fingerprint of a key or certificate blob, which is simply its hash under the given
+ * digest. For an SSH key the blob is the public key in wire format, the base64 decoded part of an
+ * authorized_keys line.
+ */
+pub fn fingerprint<D: Digest>(digest: D, data: &[u8]) -> Vec<u8> {
+    hash_iter(digest, Some(data))
+}
+
+/**
+ * Compute a fingerprint as with fingerprint() and format it as lowercase hex pairs separated by
+ * colons, like ab:cd:ef:01, as shown by older OpenSSH releases and most TLS tools.
+ */
+#[cfg(any(feature = "std", test))]
+pub fn fingerprint_hex<D: Digest>(digest: D, data: &[u8]) -> String {
+    let hash = fingerprint(digest, data);
+    let pairs: Vec<String> = hash.iter().map(|b| format!("{:02x}", b)).collect();
+    pairs.join(":")
+}
+
+#[cfg(test)]
+mod test {
+    use sr_std::cmp;
+    use sr_std::iter::repeat;
+    use sr_std::prelude::*;
+
+    use blake2b::Blake2b;
+    use blake2s::Blake2s;
+    use digest::{
+        fingerprint, fingerprint_hex, from_name, hash_iter, mgf1, mgf_xof, Digest, XofReader,
+    };
+    use md5::Md5;
+    use ripemd160::Ripemd160;
+    use sha1::Sha1;
+    use sha2::{Sha224, Sha256, Sha384, Sha512, Sha512Trunc224, Sha512Trunc256};
+    use sha3::{Sha3, Sha3XofCustom};
+    use whirlpool::Whirlpool;
+
+    fn hash_abc<D: Digest + ?Sized>(d: &mut D) -> Vec<u8> {
+        let mut out: Vec<u8> = repeat(0).take(d.output_bytes()).collect();
+        d.input(b"abc");
+        d.result(&mut out[..]);
+        out
+    }
+
+    #[test]
+    fn test_from_name() {
+        let concrete: Vec<(&str, Vec<u8>)> = vec![
+            ("md5", hash_abc(&mut Md5::new())),
+            ("sha1", hash_abc(&mut Sha1::new())),
+            ("sha224", hash_abc(&mut Sha224::new())),
+            ("sha256", hash_abc(&mut Sha256::new())),
+            ("sha384", hash_abc(&mut Sha384::new())),
+            ("sha512", hash_abc(&mut Sha512::new())),
+            ("sha512/224", hash_abc(&mut Sha512Trunc224::new())),
+            ("sha512/256", hash_abc(&mut Sha512Trunc256::new())),
+            ("sha3-224", hash_abc(&mut Sha3::sha3_224())),
+            ("sha3-256", hash_abc(&mut Sha3::sha3_256())),
+            ("sha3-384", hash_abc(&mut Sha3::sha3_384())),
+            ("sha3-512", hash_abc(&mut Sha3::sha3_512())),
+            ("keccak224", hash_abc(&mut Sha3::keccak224())),
+            ("keccak256", hash_abc(&mut Sha3::keccak256())),
+            ("keccak384", hash_abc(&mut Sha3::keccak384())),
+            ("keccak512", hash_abc(&mut Sha3::keccak512())),
+            ("ripemd160", hash_abc(&mut Ripemd160::new())),
+            ("whirlpool", hash_abc(&mut Whirlpool::new())),
+            ("blake2b-512", hash_abc(&mut Blake2b::new(64))),
+            ("blake2s-256", hash_abc(&mut Blake2s::new(32))),
+        ];
+
+        for &(name, ref expected) in concrete.iter() {
+            let mut d = from_name(name).unwrap();
+            assert_eq!(&hash_abc(&mut *d), expected);
+
+            // A clone taken mid-stream carries the input absorbed so far.
+            let mut d = from_name(name).unwrap();
+            d.input(b"a");
+            let mut forked = d.box_clone();
+            forked.input(b"bc");
+            let mut out: Vec<u8> = repeat(0).take(forked.output_bytes()).collect();
+            forked.result(&mut out[..]);
+            assert_eq!(&out, expected);
+        }
+
+        assert!(from_name("sha0").is_none());
+    }
+
+    #[test]
+    fn test_mgf1_sha1() {
+        // Test vectors from the PKCS #1 v2.1 test suite.
+        let mut out = [0u8; 3];
+        mgf1(Sha1::new(), b"foo", &mut out);
+        assert_eq!(hex::encode(&out[..]), "1ac907");
+
+        let mut out: Vec<u8> = repeat(0).take(50).collect();
+        mgf1(Sha1::new(), b"bar", &mut out[..]);
+        assert_eq!(
+            hex::encode(&out[..]),
+            "bc0c655e016bc2931d85a2e675181adcef7f581f76df2739da74faac41627be2f7f415c89e983fd0ce80c\
+             ed9878641cb4876"
+        );
+    }
+
+    #[test]
+    fn test_mgf1_sha256() {
+        let mut out: Vec<u8> = repeat(0).take(50).collect();
+        mgf1(Sha256::new(), b"bar", &mut out[..]);
+        assert_eq!(
+            hex::encode(&out[..]),
+            "382576a7841021cc28fc4c0948753fb8312090cea942ea4c4e735d10dc724b155f9f6069f289d61daca0c\
+             b814502ef04eae1"
+        );
+    }
+
+    #[test]
+    fn test_mgf_xof_chunked_reads() {
+        // SHAKE256 and SHAKE128 of "mask seed", from Python's hashlib.
+        let cases = [
+            (
+                Sha3::shake_256(),
+                "65c14d57f184345beb3029a1071828fde61aaa0559b431dad0a449a6c3244b3b1b022972e618d3919\
+                 357b8abede2e607",
+            ),
+            (
+                Sha3::shake_128(),
+                "4f5102761c292d69109a2e4502e785fa77ad0cf7d80471ee59b13cb0a88e8e97adb13dc5b712ab8c84\
+                 ef316dec372e9d",
+            ),
+        ];
+        for &(xof, expected) in cases.iter() {
+            let mut whole: Vec<u8> = repeat(0).take(1000).collect();
+            mgf_xof(xof, b"mask seed").read(&mut whole[..]);
+            assert_eq!(hex::encode(&whole[..48]), expected);
+
+            // Chunk sizes straddling the 136 and 168 byte rates
+            let mut chunked: Vec<u8> = repeat(0).take(1000).collect();
+            let mut reader = mgf_xof(xof, b"mask seed");
+            let mut pos = 0;
+            for &n in [1, 7, 128, 136, 0, 168, 33, 200].iter().cycle() {
+                let end = cmp::min(pos + n, chunked.len());
+                reader.read(&mut chunked[pos..end]);
+                pos = end;
+                if pos == chunked.len() {
+                    break;
+                }
+            }
+            assert_eq!(chunked, whole);
+        }
+
+        let mut custom: Vec<u8> = repeat(0).take(300).collect();
+        mgf_xof(Sha3XofCustom::new(136, 0x1f), b"mask seed").read(&mut custom[..]);
+        let mut shake: Vec<u8> = repeat(0).take(300).collect();
+        mgf_xof(Sha3::shake_256(), b"mask seed").read(&mut shake[..]);
+        assert_eq!(custom, shake);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mgf_xof_rejects_fixed_digest() {
+        mgf_xof(Sha256::new(), b"mask seed");
+    }
+
+    #[test]
+    fn test_hash_iter() {
+        let chunks: [&[u8]; 3] = [b"The quick brown fox ", b"jumps over ", b"the lazy dog"];
+        let expected = hash_iter(Sha256::new(), Some(chunks.concat()));
+        assert_eq!(hash_iter(Sha256::new(), chunks.iter()), expected);
+        assert_eq!(
+            hex::encode(&expected[..]),
+            "d7a8fbb307d7809469ca9abcb0082e4f8d5651e46d3cdb762d02d0bf37c9e592"
+        );
+
+        let owned: Vec<Vec<u8>> = chunks.iter().map(|c| c.to_vec()).collect();
+        assert_eq!(hash_iter(Sha256::new(), owned), expected);
+
+        let empty: [&[u8]; 0] = [];
+        assert_eq!(hash_iter(Sha256::new(), empty.iter()), hash_iter(Sha256::new(), Some(b"")));
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        // An ssh-ed25519 public key blob: string "ssh-ed25519" followed by string 00 01 ... 1f.
+        let mut blob = b"\x00\x00\x00\x0bssh-ed25519\x00\x00\x00\x20".to_vec();
+        blob.extend(0..32);
+        assert_eq!(
+            fingerprint(Md5::new(), &blob[..]),
+            hex::decode("0fa20ad7383e6545086b63841cffdcba").unwrap()
+        );
+        assert_eq!(
+            fingerprint_hex(Md5::new(), &blob[..]),
+            "0f:a2:0a:d7:38:3e:65:45:08:6b:63:84:1c:ff:dc:ba"
+        );
+        assert_eq!(
+            fingerprint_hex(Sha256::new(), &blob[..]),
+            "66:40:2c:94:68:c5:89:41:dd:19:ff:d6:50:bf:2b:42:\
+             f9:22:6f:83:d3:bd:06:ad:51:5d:0e:51:04:a7:70:20"
+        );
+    }
+}
@@ -1,12 +1,60 @@
-use curve25519::{curve25519, ge_scalarmult_base, sc_muladd, sc_reduce, Fe, GeP2, GeP3};
+use curve25519::{self, curve25519, ge_scalarmult_base, Fe, GeP2, GeP3};
 use digest::Digest;
 use sha2::Sha512;
+use sr_std::fmt;
 use sr_std::ops::{Add, Mul, Sub};
-use util::fixed_time_eq;
+use util::{fixed_time_eq, secure_memset};
 
+// The order of the base point, 2^252 + 27742317777372353535851937790883648493, little-endian.
 static L: [u8; 32] = [
-    0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x14, 0xde, 0xf9, 0xde, 0xa2, 0xf7, 0x9c, 0xd6, 0x58, 0x12, 0x63, 0x1a, 0x5c, 0xf5, 0xd3, 0xed,
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+
+// Encodings of the points of small order, ignoring the sign bit. Taken from libsodium.
+static SMALL_ORDER_BLOCKLIST: [[u8; 32]; 7] = [
+    // 0 (order 4)
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00,
+    ],
+    // 1 (order 1)
+    [
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00,
+    ],
+    // 2707385501144840649318225287225658788936804267575313519463743609750303402022 (order 8)
+    [
+        0x26, 0xe8, 0x95, 0x8f, 0xc2, 0xb2, 0x27, 0xb0, 0x45, 0xc3, 0xf4, 0x89, 0xf2, 0xef, 0x98,
+        0xf0, 0xd5, 0xdf, 0xac, 0x05, 0xd3, 0xc6, 0x33, 0x39, 0xb1, 0x38, 0x02, 0x88, 0x6d, 0x53,
+        0xfc, 0x05,
+    ],
+    // 55188659117513257062467267217118295137698188065244968500265048394206261417927 (order 8)
+    [
+        0xc7, 0x17, 0x6a, 0x70, 0x3d, 0x4d, 0xd8, 0x4f, 0xba, 0x3c, 0x0b, 0x76, 0x0d, 0x10, 0x67,
+        0x0f, 0x2a, 0x20, 0x53, 0xfa, 0x2c, 0x39, 0xcc, 0xc6, 0x4e, 0xc7, 0xfd, 0x77, 0x92, 0xac,
+        0x03, 0x7a,
+    ],
+    // p - 1 (order 2)
+    [
+        0xec, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x7f,
+    ],
+    // p (= 0, order 4)
+    [
+        0xed, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x7f,
+    ],
+    // p + 1 (= 1, order 1)
+    [
+        0xee, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x7f,
+    ],
 ];
 
 pub fn keypair(seed: &[u8]) -> ([u8; 64], [u8; 32]) {
@@ -52,7 +100,7 @@ pub fn signature(message: &[u8], secret_key: &[u8]) -> [u8; 64] {
         hasher.input(&az[32..64]);
         hasher.input(message);
         hasher.result(&mut hash_output);
-        sc_reduce(&mut hash_output[0..64]);
+        curve25519::sc_reduce(&mut hash_output[0..64]);
         hash_output
     };
 
@@ -71,8 +119,8 @@ pub fn signature(message: &[u8], secret_key: &[u8]) -> [u8; 64] {
         hasher.input(message);
         let mut hram: [u8; 64] = [0; 64];
         hasher.result(&mut hram);
-        sc_reduce(&mut hram);
-        sc_muladd(
+        curve25519::sc_reduce(&mut hram);
+        curve25519::sc_muladd(
             &mut signature[32..64],
             &hram[0..32],
             &az[0..32],
@@ -83,7 +131,28 @@ pub fn signature(message: &[u8], secret_key: &[u8]) -> [u8; 64] {
     signature
 }
 
-fn check_s_lt_l(s: &[u8]) -> bool {
+/// Reduce a 64 byte little-endian value modulo the group order L, as done to the SHA-512 outputs
+/// when signing. The result is canonical, and the running time does not depend on the input.
+pub fn sc_reduce(input: &[u8; 64]) -> [u8; 32] {
+    let mut s = *input;
+    curve25519::sc_reduce(&mut s);
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&s[..32]);
+    secure_memset(&mut s, 0);
+    out
+}
+
+/// Compute (a * b + c) mod L for little-endian scalars, the S = (k * a + r) mod L step of
+/// signing. The inputs need not be reduced, and the running time does not depend on them.
+pub fn sc_muladd(a: &[u8; 32], b: &[u8; 32], c: &[u8; 32]) -> [u8; 32] {
+    let mut s = [0u8; 32];
+    curve25519::sc_muladd(&mut s, a, b, c);
+    s
+}
+
+/// Check that the scalar s, encoded little-endian, is strictly less than the group order L. This
+/// runs in constant time. Signatures with S >= L are malleable, since S + L verifies as well.
+pub fn is_canonical_scalar(s: &[u8; 32]) -> bool {
     let mut c: u8 = 0;
     let mut n: u8 = 1;
 
@@ -98,11 +167,28 @@ fn check_s_lt_l(s: &[u8]) -> bool {
         }
     }
 
-    c == 0
+    c != 0
+}
+
+fn has_small_order(p: &[u8]) -> bool {
+    let mut c = [0u8; 7];
+    for (j, bad) in SMALL_ORDER_BLOCKLIST.iter().enumerate() {
+        for i in 0..31 {
+            c[j] |= p[i] ^ bad[i];
+        }
+        c[j] |= (p[31] & 0x7f) ^ bad[31];
+    }
+    let mut k: u32 = 0;
+    for x in c.iter() {
+        k |= (*x as u32).wrapping_sub(1);
+    }
+    (k >> 8) & 1 == 1
 }
 
 pub fn verify(message: &[u8], public_key: &[u8], signature: &[u8]) -> bool {
-    if check_s_lt_l(&signature[32..64]) {
+    let mut s = [0u8; 32];
+    s.copy_from_slice(&signature[32..64]);
+    if !is_canonical_scalar(&s) {
         return false;
     }
 
@@ -126,7 +212,7 @@ pub fn verify(message: &[u8], public_key: &[u8], signature: &[u8]) -> bool {
     hasher.input(message);
     let mut hash: [u8; 64] = [0; 64];
     hasher.result(&mut hash);
-    sc_reduce(&mut hash);
+    curve25519::sc_reduce(&mut hash);
 
     let r = GeP2::double_scalarmult_vartime(hash.as_ref(), a, &signature[32..64]);
     let rcheck = r.to_bytes();
@@ -134,25 +220,121 @@ pub fn verify(message: &[u8], public_key: &[u8], signature: &[u8]) -> bool {
     fixed_time_eq(rcheck.as_ref(), &signature[0..32])
 }
 
+/// Like verify, but additionally rejects public keys and R values of small order. With these
+/// excluded a signature cannot be valid for more than one message and key.
+pub fn verify_strict(message: &[u8], public_key: &[u8], signature: &[u8]) -> bool {
+    if has_small_order(public_key) || has_small_order(&signature[0..32]) {
+        return false;
+    }
+    verify(message, public_key, signature)
+}
+
 pub fn exchange(public_key: &[u8], private_key: &[u8]) -> [u8; 32] {
     let ed_y = Fe::from_bytes(&public_key);
     // Produce public key in Montgomery form.
     let mont_x = edwards_to_montgomery_x(ed_y);
 
-    // Produce private key from seed component (bytes 0 to 32)
-    // of the Ed25519 extended private key (64 bytes).
+    let mut secret = to_x25519_secret(private_key);
+    let shared_mont_x: [u8; 32] = curve25519(&secret, &mont_x.to_bytes()); // priv., pub.
+    secure_memset(&mut secret, 0);
+
+    shared_mont_x
+}
+
+/// Convert an Ed25519 public key to the X25519 public key of the same key pair, using the
+/// birational map u = (1 + y) / (1 - y) from the Edwards y coordinate to the Montgomery u
+/// coordinate. Returns None if the key is not the encoding of a curve point or is of small order,
+/// as libsodium's crypto_sign_ed25519_pk_to_curve25519 does.
+pub fn to_x25519_public(ed_pub: &[u8; 32]) -> Option<[u8; 32]> {
+    if has_small_order(ed_pub) || GeP3::from_bytes_negate_vartime(ed_pub).is_none() {
+        return None;
+    }
+    Some(edwards_to_montgomery_x(Fe::from_bytes(ed_pub)).to_bytes())
+}
+
+/// Convert an Ed25519 secret key, either the 32 byte seed or the 64 byte form returned by
+/// keypair, to the X25519 secret key of the same key pair: the first half of SHA-512(seed),
+/// clamped. curve25519_base of the result equals to_x25519_public of the Ed25519 public key.
+pub fn to_x25519_secret(ed_secret: &[u8]) -> [u8; 32] {
     let mut hasher = Sha512::new();
-    hasher.input(&private_key[0..32]);
+    hasher.input(&ed_secret[0..32]);
     let mut hash: [u8; 64] = [0; 64];
     hasher.result(&mut hash);
-    // Clamp the hash such that it is a valid private key
-    hash[0] &= 248;
-    hash[31] &= 127;
-    hash[31] |= 64;
 
-    let shared_mont_x: [u8; 32] = curve25519(&hash, &mont_x.to_bytes()); // priv., pub.
+    let mut secret = [0u8; 32];
+    secret.copy_from_slice(&hash[0..32]);
+    secure_memset(&mut hash, 0);
+    secret[0] &= 248;
+    secret[31] &= 127;
+    secret[31] |= 64;
+    secret
+}
+
+/// An Ed25519 public key. Equality is checked in constant time.
+#[derive(Clone, Copy)]
+pub struct PublicKey([u8; 32]);
 
-    shared_mont_x
+impl PublicKey {
+    pub fn from_bytes(bytes: &[u8; 32]) -> PublicKey {
+        PublicKey(*bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl PartialEq for PublicKey {
+    fn eq(&self, other: &PublicKey) -> bool {
+        fixed_time_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for PublicKey {}
+
+impl fmt::Debug for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("PublicKey(")?;
+        for b in self.0.iter() {
+            write!(f, "{:02x}", b)?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// An Ed25519 secret key in the 64 byte form returned by keypair: the seed followed by the public
+/// key. Equality is checked in constant time and Debug does not print the key.
+#[derive(Clone)]
+pub struct SecretKey([u8; 64]);
+
+impl SecretKey {
+    pub fn from_bytes(bytes: &[u8; 64]) -> SecretKey {
+        SecretKey(*bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 64] {
+        &self.0
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        let mut public_key = [0u8; 32];
+        public_key.copy_from_slice(&self.0[32..64]);
+        PublicKey(public_key)
+    }
+}
+
+impl PartialEq for SecretKey {
+    fn eq(&self, other: &SecretKey) -> bool {
+        fixed_time_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for SecretKey {}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SecretKey(<redacted>)")
+    }
 }
 
 fn edwards_to_montgomery_x(ed_y: Fe) -> Fe {
@@ -170,7 +352,11 @@ fn edwards_to_montgomery_x(ed_y: Fe) -> Fe {
 mod tests {
     use curve25519::{curve25519, curve25519_base};
     use digest::Digest;
-    use ed25519::{exchange, keypair, signature, verify};
+    use curve25519::ge_scalarmult_base;
+    use ed25519::{
+        exchange, is_canonical_scalar, keypair, sc_muladd, sc_reduce, signature, to_x25519_public,
+        to_x25519_secret, verify, verify_strict, PublicKey, SecretKey, L,
+    };
     use sha2::Sha512;
 
     fn do_keypair_case(seed: [u8; 32], expected_secret: [u8; 64], expected_public: [u8; 32]) {
@@ -246,6 +432,58 @@ mod tests {
         assert_eq!(edx_ss.to_vec(), cv_ss.to_vec());
     }
 
+    #[test]
+    fn x25519_conversion() {
+        // The RFC 8032 TEST 1 and TEST 2 keys, converted with Python: u = (1 + y) / (1 - y) for the
+        // public keys and the clamped SHA-512 of the seeds, checked against X25519 base point
+        // multiplication with the cryptography package.
+        let (secret1, public1) = keypair(
+            &hex::decode("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+                .unwrap(),
+        );
+        let (secret2, public2) = keypair(
+            &hex::decode("4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb")
+                .unwrap(),
+        );
+
+        let x_public1 = to_x25519_public(&public1).unwrap();
+        let x_public2 = to_x25519_public(&public2).unwrap();
+        let x_secret1 = to_x25519_secret(&secret1);
+        let x_secret2 = to_x25519_secret(&secret2[..32]);
+        assert_eq!(
+            hex::encode(&x_public1[..]),
+            "d85e07ec22b0ad881537c2f44d662d1a143cf830c57aca4305d85c7a90f6b62e"
+        );
+        assert_eq!(
+            hex::encode(&x_secret1[..]),
+            "307c83864f2833cb427a2ef1c00a013cfdff2768d980c0a3a520f006904de94f"
+        );
+        assert_eq!(
+            hex::encode(&x_public2[..]),
+            "25c704c594b88afc00a76b69d1ed2b984d7e22550f3ed0802d04fbcd07d38d47"
+        );
+        assert_eq!(curve25519_base(&x_secret1), x_public1);
+        assert_eq!(curve25519_base(&x_secret2), x_public2);
+
+        // Both sides arrive at the same shared secret, which exchange() computes too.
+        let shared = curve25519(&x_secret1, &x_public2);
+        assert_eq!(shared, curve25519(&x_secret2, &x_public1));
+        assert_eq!(
+            hex::encode(&shared[..]),
+            "5166f24a6918368e2af831a4affadd97af0ac326bdf143596c045967cc00230e"
+        );
+        assert_eq!(exchange(&public2, &secret1), shared);
+
+        // Small order points and encodings that are not points have no conversion.
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        assert!(to_x25519_public(&identity).is_none());
+        assert!(to_x25519_public(&[0u8; 32]).is_none());
+        let mut not_on_curve = [0u8; 32];
+        not_on_curve[0] = 2;
+        assert!(to_x25519_public(&not_on_curve).is_none());
+    }
+
     fn do_sign_verify_case(seed: [u8; 32], message: &[u8], expected_signature: [u8; 64]) {
         let (secret_key, public_key) = keypair(seed.as_ref());
         let mut actual_signature = signature(message, secret_key.as_ref());
@@ -334,4 +572,209 @@ mod tests {
             ],
         );
     }
+
+    fn add_le(a: &[u8], b: &[u8]) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        let mut carry = 0u16;
+        for i in 0..32 {
+            let t = a[i] as u16 + b[i] as u16 + carry;
+            out[i] = t as u8;
+            carry = t >> 8;
+        }
+        out
+    }
+
+    #[test]
+    fn signatures_are_deterministic_rfc8032() {
+        // RFC 8032 section 7.1, TESTs 1, 2, 3 and SHA(abc): (secret key, public key, message,
+        // signature). The nonce is SHA-512(prefix || message), so signing the same message twice
+        // must give identical bytes; any randomness in the nonce would break both checks below.
+        let cases = [
+            (
+                "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                "",
+                "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc6\
+                 1e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+            ),
+            (
+                "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+                "72",
+                "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e45\
+                 8f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+            ),
+            (
+                "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+                "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+                "af82",
+                "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae\
+                 67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+            ),
+            (
+                "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42",
+                "ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf",
+                "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836\
+                 ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+                "dc2a4459e7369633a52b1bf277839a00201009a3efbf3ecb69bea2186c26b58909351fc9ac90b3ecfd\
+                 fbc7c66431e0303dca179c138ac17ad9bef1177331a704",
+            ),
+        ];
+        for &(seed, public, message, expected) in cases.iter() {
+            let (secret_key, public_key) = keypair(&hex::decode(seed).unwrap());
+            assert_eq!(hex::encode(&public_key[..]), public);
+
+            let message = hex::decode(message).unwrap();
+            let first = signature(&message, &secret_key);
+            let second = signature(&message, &secret_key);
+            assert_eq!(&first[..], &second[..]);
+            assert_eq!(hex::encode(&first[..]), expected);
+            assert!(verify(&message, &public_key, &first));
+        }
+    }
+
+    #[test]
+    fn canonical_scalar_bounds() {
+        let zero = [0u8; 32];
+        assert!(is_canonical_scalar(&zero));
+
+        let mut l_minus_one = L;
+        l_minus_one[0] -= 1;
+        assert!(is_canonical_scalar(&l_minus_one));
+
+        assert!(!is_canonical_scalar(&L));
+
+        let mut one = [0u8; 32];
+        one[0] = 1;
+        assert!(!is_canonical_scalar(&add_le(&L, &one)));
+
+        let mut l_high = L;
+        l_high[31] += 1;
+        assert!(!is_canonical_scalar(&l_high));
+
+        assert!(!is_canonical_scalar(&[0xff; 32]));
+    }
+
+    #[test]
+    fn verify_rejects_malleated_s() {
+        let (secret, public) = keypair(&[0x42; 32]);
+        let message = b"malleability";
+        let sig = signature(message, &secret);
+        assert!(verify(message, &public, &sig));
+        assert!(verify_strict(message, &public, &sig));
+
+        // S + L is congruent to S, so without the canonical check this would verify as well.
+        let mut malleated = sig;
+        let s_plus_l = add_le(&sig[32..64], &L);
+        malleated[32..64].copy_from_slice(&s_plus_l);
+        assert!(!verify(message, &public, &malleated));
+        assert!(!verify_strict(message, &public, &malleated));
+    }
+
+    #[test]
+    fn verify_strict_rejects_small_order() {
+        // The identity as public key and R with S = 0 satisfies the verification equation for
+        // any message.
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        let mut sig = [0u8; 64];
+        sig[..32].copy_from_slice(&identity);
+        assert!(verify(b"any message", &identity, &sig));
+        assert!(!verify_strict(b"any message", &identity, &sig));
+    }
+
+    #[test]
+    fn key_wrappers_eq() {
+        let (secret, public) = keypair(&[1; 32]);
+        let (other_secret, other_public) = keypair(&[2; 32]);
+
+        let sk = SecretKey::from_bytes(&secret);
+        assert_eq!(sk, SecretKey::from_bytes(&secret));
+        assert!(sk != SecretKey::from_bytes(&other_secret));
+        assert_eq!(sk.as_bytes()[..], secret[..]);
+
+        let pk = PublicKey::from_bytes(&public);
+        assert_eq!(sk.public_key(), pk);
+        assert!(pk != PublicKey::from_bytes(&other_public));
+        assert_eq!(pk.as_bytes(), &public);
+    }
+
+    #[test]
+    fn secret_key_debug_is_redacted() {
+        let (secret, public) = keypair(&[0x5a; 32]);
+        let debug = format!("{:?}", SecretKey::from_bytes(&secret));
+        assert_eq!(debug, "SecretKey(<redacted>)");
+
+        let debug = format!("{:?}", PublicKey::from_bytes(&public));
+        assert!(debug.starts_with("PublicKey(") && debug.len() == "PublicKey()".len() + 64);
+    }
+
+    #[test]
+    fn sc_reduce_known_values() {
+        let mut wide = [0u8; 64];
+        wide[..32].copy_from_slice(&L);
+        assert_eq!(sc_reduce(&wide), [0u8; 32]);
+        wide[0] += 1;
+        let mut one = [0u8; 32];
+        one[0] = 1;
+        assert_eq!(sc_reduce(&wide), one);
+
+        // (2^512 - 1) mod L, computed with Python.
+        assert_eq!(
+            hex::encode(&sc_reduce(&[0xff; 64])[..]),
+            "000f9c44e31106a447938568a71b0ed065bef517d273ecce3d9a307c1b419903"
+        );
+
+        // (L - 1) * 1 + 1 wraps to zero.
+        let mut l_minus_one = L;
+        l_minus_one[0] -= 1;
+        assert_eq!(sc_muladd(&l_minus_one, &one, &one), [0u8; 32]);
+    }
+
+    #[test]
+    fn sc_reduce_muladd_rfc8032() {
+        // RFC 8032 section 7.1, TEST 1: recompute r, R, k and S = (r + k * a) mod L from the
+        // secret key and check them against the published signature.
+        let seed = hex::decode(
+            "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+        )
+        .unwrap();
+        let public = hex::decode(
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+        )
+        .unwrap();
+        let expected = hex::decode(
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39\
+             701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+        )
+        .unwrap();
+
+        let mut h = [0u8; 64];
+        let mut hasher = Sha512::new();
+        hasher.input(&seed);
+        hasher.result(&mut h);
+        let mut a = [0u8; 32];
+        a.copy_from_slice(&h[..32]);
+        a[0] &= 248;
+        a[31] &= 63;
+        a[31] |= 64;
+
+        let mut wide = [0u8; 64];
+        let mut hasher = Sha512::new();
+        hasher.input(&h[32..]);
+        hasher.result(&mut wide);
+        let r = sc_reduce(&wide);
+        assert_eq!(
+            hex::encode(&r[..]),
+            "f38907308c893deaf244787db4af53682249107418afc2edc58f75ac58a07404"
+        );
+        assert_eq!(&ge_scalarmult_base(&r).to_bytes()[..], &expected[..32]);
+
+        let mut hasher = Sha512::new();
+        hasher.input(&expected[..32]);
+        hasher.input(&public);
+        hasher.result(&mut wide);
+        let k = sc_reduce(&wide);
+        assert_eq!(&sc_muladd(&k, &a, &r)[..], &expected[32..]);
+    }
 }
@@ -11,6 +11,10 @@ use sr_std::mem;
 /// and C is the ciphertext. GHASH can be used as a keyed MAC, if C is left empty.
 ///
 /// In order to ensure constant time computation it uses the approach described in [2] section 5.2.
+/// With the `std` feature the multiples h * x^i are precomputed into a 2 KiB table that is read in
+/// full for every block; without it, as on embedded targets, only h is kept and the multiples are
+/// recomputed bit by bit, which is slower but keeps each GHASH state to a few dozen bytes. Both
+/// produce identical results and neither makes data-dependent memory accesses.
 ///
 /// [1] - "The Galois/Counter Mode of Operation (GCM)" - David A. McGrew and John Viega
 ///       <http://csrc.nist.gov/groups/ST/toolkit/BCM/documents/proposedmodes/gcm/gcm-spec.pdf>
@@ -74,17 +78,6 @@ impl Gf128 {
         self.cond_xor(r, self.times_x())
     }
 
-    // Adds y, and multiplies with h using a precomputed array of the values h * x^0 to h * x^127
-    fn add_and_mul(&mut self, y: Gf128, hs: &[Gf128; 128]) {
-        *self = *self ^ y;
-        let mut x = mem::replace(self, Gf128::new(0, 0, 0, 0));
-
-        for &y in hs.iter().rev() {
-            *self = x.cond_xor(y, *self);
-            x = x.times_x();
-        }
-    }
-
     // This XORs the value of y with x if the LSB of self is set, otherwise y is returned
     fn cond_xor(self, x: Gf128, y: Gf128) -> Gf128 {
         use simd::SimdExt;
@@ -105,12 +98,82 @@ impl BitXor for Gf128 {
     }
 }
 
+// The hash key h, in a form that supports adding a block to the state and multiplying by h
+trait HashKey: Copy {
+    fn new(h: Gf128) -> Self;
+
+    fn add_and_mul(&self, state: &mut Gf128, y: Gf128);
+}
+
+// Precomputed values for h * x^0 to h * x^127
+#[cfg(any(feature = "std", test))]
+#[derive(Clone, Copy)]
+struct TableKey([Gf128; 128]);
+
+#[cfg(any(feature = "std", test))]
+impl HashKey for TableKey {
+    fn new(mut h: Gf128) -> TableKey {
+        let mut table: [Gf128; 128] = unsafe { mem::MaybeUninit::uninit().assume_init() };
+        for poly in table.iter_mut() {
+            *poly = h;
+            h = h.times_x_reduce();
+        }
+        TableKey(table)
+    }
+
+    fn add_and_mul(&self, state: &mut Gf128, y: Gf128) {
+        *state = *state ^ y;
+        let mut x = mem::replace(state, Gf128::new(0, 0, 0, 0));
+
+        for &y in self.0.iter().rev() {
+            *state = x.cond_xor(y, *state);
+            x = x.times_x();
+        }
+    }
+}
+
+// Only h itself; h * x^i is recomputed for each bit of the multiplicand
+#[cfg(any(not(feature = "std"), test))]
+#[derive(Clone, Copy)]
+struct CompactKey(Gf128);
+
+#[cfg(any(not(feature = "std"), test))]
+impl HashKey for CompactKey {
+    fn new(h: Gf128) -> CompactKey {
+        CompactKey(h)
+    }
+
+    fn add_and_mul(&self, state: &mut Gf128, y: Gf128) {
+        let simd::u32x4(a, b, c, d) = (*state ^ y).d;
+        let mut h = self.0;
+        let mut z = Gf128::new(0, 0, 0, 0);
+
+        // The msb of d holds the coefficient of x^0 and the lsb of a that of x^127
+        for &word in [d, c, b, a].iter() {
+            for i in (0..32).rev() {
+                let m = 0u32.wrapping_sub((word >> i) & 1);
+                z = z ^ Gf128 {
+                    d: h.d & simd::u32x4(m, m, m, m),
+                };
+                h = h.times_x_reduce();
+            }
+        }
+
+        *state = z;
+    }
+}
+
+#[cfg(feature = "std")]
+type Key = TableKey;
+#[cfg(not(feature = "std"))]
+type Key = CompactKey;
+
 /// A structure representing the state of a GHASH computation
 #[derive(Copy)]
 pub struct Ghash {
-    hs: [Gf128; 128],
+    hs: Key,
     state: Gf128,
-    a_len: usize,
+    a_len: u64,
     rest: Option<[u8; 16]>,
     finished: bool,
 }
@@ -124,10 +187,10 @@ impl Clone for Ghash {
 /// A structure representing the state of a GHASH computation, after input for C was provided
 #[derive(Copy)]
 pub struct GhashWithC {
-    hs: [Gf128; 128],
+    hs: Key,
     state: Gf128,
-    a_len: usize,
-    c_len: usize,
+    a_len: u64,
+    c_len: u64,
     rest: Option<[u8; 16]>,
 }
 
@@ -137,16 +200,23 @@ impl Clone for GhashWithC {
     }
 }
 
+// GCM encodes the lengths of A and C in bits as 64 bit integers, so neither may exceed this many
+// bytes.
+const MAX_LEN: u64 = u64::max_value() / 8;
+
 fn update(
     state: &mut Gf128,
-    len: &mut usize,
+    len: &mut u64,
     data: &[u8],
     srest: &mut Option<[u8; 16]>,
-    hs: &[Gf128; 128],
+    hs: &Key,
 ) {
-    let rest_len = *len % 16;
+    let rest_len = (*len % 16) as usize;
     let data_len = data.len();
-    *len += data_len;
+    *len = match len.checked_add(data_len as u64) {
+        Some(new_len) if new_len <= MAX_LEN => new_len,
+        _ => panic!("GHASH input length overflows its 64 bit length field"),
+    };
 
     let data = match srest.take() {
         None => data,
@@ -159,16 +229,17 @@ fn update(
 
             let (fill, data) = data.split_at(16 - rest_len);
             copy_memory(fill, &mut rest[rest_len..]);
-            state.add_and_mul(Gf128::from_bytes(&rest), hs);
+            hs.add_and_mul(state, Gf128::from_bytes(&rest));
             data
         }
     };
 
-    let (data, rest) = data.split_at(data_len - data_len % 16);
+    let full = data.len() - data.len() % 16;
+    let (data, rest) = data.split_at(full);
 
     for chunk in data.chunks(16) {
         let x = Gf128::from_bytes(chunk);
-        state.add_and_mul(x, hs);
+        hs.add_and_mul(state, x);
     }
 
     if rest.len() != 0 {
@@ -183,17 +254,8 @@ impl Ghash {
     #[inline]
     pub fn new(h: &[u8]) -> Ghash {
         //assert!(h.len() == 16);
-        let mut table: [Gf128; 128] = unsafe { mem::MaybeUninit::uninit().assume_init() };
-
-        // Precompute values for h * x^0 to h * x^127
-        let mut h = Gf128::from_bytes(h);
-        for poly in table.iter_mut() {
-            *poly = h;
-            h = h.times_x_reduce();
-        }
-
         Ghash {
-            hs: table,
+            hs: Key::new(Gf128::from_bytes(h)),
             state: Gf128::new(0, 0, 0, 0),
             a_len: 0,
             rest: None,
@@ -203,7 +265,7 @@ impl Ghash {
 
     fn flush(&mut self) {
         for rest in self.rest.take().iter() {
-            self.state.add_and_mul(Gf128::from_bytes(rest), &self.hs);
+            self.hs.add_and_mul(&mut self.state, Gf128::from_bytes(rest));
         }
     }
 
@@ -252,9 +314,9 @@ impl Ghash {
         if !self.finished {
             self.flush();
 
-            let a_len = self.a_len as u64 * 8;
+            let a_len = self.a_len * 8;
             let lens = Gf128::new(0, 0, a_len as u32, (a_len >> 32) as u32);
-            self.state.add_and_mul(lens, &self.hs);
+            self.hs.add_and_mul(&mut self.state, lens);
 
             self.finished = true;
         }
@@ -281,18 +343,18 @@ impl GhashWithC {
     #[inline]
     pub fn result(mut self) -> [u8; 16] {
         for rest in self.rest.take().iter() {
-            self.state.add_and_mul(Gf128::from_bytes(rest), &self.hs);
+            self.hs.add_and_mul(&mut self.state, Gf128::from_bytes(rest));
         }
 
-        let a_len = self.a_len as u64 * 8;
-        let c_len = self.c_len as u64 * 8;
+        let a_len = self.a_len * 8;
+        let c_len = self.c_len * 8;
         let lens = Gf128::new(
             c_len as u32,
             (c_len >> 32) as u32,
             a_len as u32,
             (a_len >> 32) as u32,
         );
-        self.state.add_and_mul(lens, &self.hs);
+        self.hs.add_and_mul(&mut self.state, lens);
 
         self.state.to_bytes()
     }
@@ -328,9 +390,9 @@ impl Mac for Ghash {
         if !self.finished {
             self.flush();
 
-            let a_len = self.a_len as u64 * 8;
+            let a_len = self.a_len * 8;
             let lens = Gf128::new(0, 0, a_len as u32, (a_len >> 32) as u32);
-            self.state.add_and_mul(lens, &self.hs);
+            self.hs.add_and_mul(&mut self.state, lens);
 
             self.finished = true;
         }
@@ -345,7 +407,8 @@ impl Mac for Ghash {
 
 #[cfg(test)]
 mod test {
-    use ghash::Ghash;
+    use ghash::{CompactKey, Gf128, Ghash, HashKey, TableKey, MAX_LEN};
+    use mac::Mac;
 
     // Test cases from:
     // <http://csrc.nist.gov/groups/ST/toolkit/BCM/documents/proposedmodes/gcm/gcm-spec.pdf>
@@ -704,6 +767,38 @@ mod test {
         }
     }
 
+    #[test]
+    fn length_near_limit() {
+        // Simulate having already absorbed almost 2^61 bytes, the most whose length in bits still
+        // fits in GCM's 64 bit length field.
+        let mut ghash = Ghash::new(&[0x42; 16]);
+        ghash.a_len = MAX_LEN - 15;
+        Mac::input(&mut ghash, &[1; 15]);
+        assert_eq!(ghash.a_len, MAX_LEN);
+        Mac::result(&mut ghash);
+
+        let mut ghash = Ghash::new(&[0x42; 16]).input_c(&[]);
+        ghash.c_len = MAX_LEN - 15;
+        let ghash = ghash.input_c(&[1; 15]);
+        assert_eq!(ghash.c_len, MAX_LEN);
+    }
+
+    #[test]
+    #[should_panic]
+    fn a_length_overflow() {
+        let mut ghash = Ghash::new(&[0x42; 16]);
+        ghash.a_len = MAX_LEN - 15;
+        Mac::input(&mut ghash, &[1; 16]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn c_length_overflow() {
+        let mut ghash = Ghash::new(&[0x42; 16]).input_c(&[]);
+        ghash.c_len = MAX_LEN - 15;
+        ghash.input_c(&[1; 16]);
+    }
+
     #[test]
     fn split_input() {
         for &(h, a, c, g) in CASES.iter() {
@@ -721,6 +816,47 @@ mod test {
             );
         }
     }
+
+    // Computes GHASH block by block with the given key representation
+    fn ghash_with<K: HashKey>(h: &[u8], a: &[u8], c: &[u8]) -> [u8; 16] {
+        let key = K::new(Gf128::from_bytes(h));
+        let mut state = Gf128::new(0, 0, 0, 0);
+        for chunk in a.chunks(16).chain(c.chunks(16)) {
+            let mut block = [0u8; 16];
+            block[..chunk.len()].copy_from_slice(chunk);
+            key.add_and_mul(&mut state, Gf128::from_bytes(&block));
+        }
+        let a_len = a.len() as u64 * 8;
+        let c_len = c.len() as u64 * 8;
+        let lens = Gf128::new(
+            c_len as u32,
+            (c_len >> 32) as u32,
+            a_len as u32,
+            (a_len >> 32) as u32,
+        );
+        key.add_and_mul(&mut state, lens);
+        state.to_bytes()
+    }
+
+    #[test]
+    fn compact_key_matches_table_key() {
+        for &(h, a, c, g) in CASES.iter() {
+            assert_eq!(&ghash_with::<TableKey>(h, a, c)[..], g);
+            assert_eq!(&ghash_with::<CompactKey>(h, a, c)[..], g);
+        }
+
+        // Single products over keys and blocks with every bit position exercised
+        let mut h = [0u8; 16];
+        let mut y = [0u8; 16];
+        for i in 0..128 {
+            h[i / 8] ^= 0x80 >> (i % 8);
+            y[15 - i / 8] ^= 1 << (i % 8);
+            let (mut t, mut s) = (Gf128::new(0, 0, 0, 0), Gf128::new(0, 0, 0, 0));
+            TableKey::new(Gf128::from_bytes(&h)).add_and_mul(&mut t, Gf128::from_bytes(&y));
+            CompactKey::new(Gf128::from_bytes(&h)).add_and_mul(&mut s, Gf128::from_bytes(&y));
+            assert_eq!(t.to_bytes(), s.to_bytes());
+        }
+    }
 }
 
 #[cfg(all(test, feature = "with-bench"))]
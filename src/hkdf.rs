@@ -21,9 +21,11 @@ use mac::Mac;
 /// # Arguments
 /// * digest - The digest function to use.
 /// * salt - The optional salt value (a non-secret random value) to use.
+///   An empty salt is the same as HashLen zero bytes, the default
+///   RFC 5869 specifies, since HMAC pads its key with zeros.
 /// * ikm - The input keying material to use.
 /// * prk - The output buffer to fill with a digest.output_bytes() length
-///         pseudo random key.
+///   pseudo random key.
 pub fn hkdf_extract<D: Digest>(mut digest: D, salt: &[u8], ikm: &[u8], prk: &mut [u8]) {
     //assert!(prk.len() == digest.output_bytes());
     digest.reset();
@@ -34,6 +36,29 @@ pub fn hkdf_extract<D: Digest>(mut digest: D, salt: &[u8], ikm: &[u8], prk: &mut
     mac.reset();
 }
 
+/// Execute HKDF-Extract over input keying material supplied in segments, as
+/// when a protocol combines several shared secrets. The segments are fed to
+/// HMAC in order, so the PRK is the one hkdf_extract gives for their
+/// concatenation. Segment boundaries are not encoded; protocols that need
+/// them to be unambiguous must use fixed length segments.
+///
+/// # Arguments
+/// * digest - The digest function to use.
+/// * salt - The optional salt value (a non-secret random value) to use.
+/// * ikms - The input keying material segments to use.
+/// * prk - The output buffer to fill with a digest.output_bytes() length
+///   pseudo random key.
+pub fn extract_multi<D: Digest>(mut digest: D, salt: &[u8], ikms: &[&[u8]], prk: &mut [u8]) {
+    digest.reset();
+
+    let mut mac = Hmac::new(digest, salt);
+    for ikm in ikms.iter() {
+        mac.input(ikm);
+    }
+    mac.raw_result(prk);
+    mac.reset();
+}
+
 /// Execute the HKDF-Expand function.  Applications MUST NOT use this for
 /// password hashing.
 ///
@@ -46,6 +71,59 @@ pub fn hkdf_expand<D: Digest>(mut digest: D, prk: &[u8], info: &[u8], okm: &mut
     digest.reset();
 
     let mut mac = Hmac::new(digest, prk);
+    expand(&mut mac, info, okm);
+}
+
+/// Holds an HMAC keyed with a PRK so that several independent HKDF-Expand
+/// outputs, as in TLS-style key schedules, can be derived without setting up
+/// the HMAC key again for each one.
+pub struct HkdfExpander<D: Digest> {
+    mac: Hmac<D>,
+}
+
+impl<D: Digest> HkdfExpander<D> {
+    pub fn new(mut digest: D, prk: &[u8]) -> HkdfExpander<D> {
+        digest.reset();
+        HkdfExpander {
+            mac: Hmac::new(digest, prk),
+        }
+    }
+
+    /// Fill okm with HKDF-Expand(PRK, info, okm.len()). Each call is
+    /// independent of the previous ones.
+    pub fn expand(&mut self, info: &[u8], okm: &mut [u8]) {
+        expand(&mut self.mac, info, okm);
+    }
+}
+
+/// Run HKDF-Expand once for each (info, length) request with the same PRK,
+/// returning one OKM per request in order. Each OKM is exactly what
+/// hkdf_expand would give for that info and length.
+///
+/// Fails without deriving anything if any length exceeds 255 * HashLen, the
+/// most RFC 5869 allows.
+pub fn expand_multi<D: Digest>(
+    digest: D,
+    prk: &[u8],
+    requests: &[(&[u8], usize)],
+) -> Result<Vec<Vec<u8>>, &'static str> {
+    let max_len = 255 * digest.output_bytes();
+    if requests.iter().any(|r| r.1 > max_len) {
+        return Err("HKDF size limit exceeded.");
+    }
+    let mut expander = HkdfExpander::new(digest, prk);
+    Ok(requests
+        .iter()
+        .map(|&(info, len)| {
+            let mut okm: Vec<u8> = repeat(0).take(len).collect();
+            expander.expand(info, &mut okm);
+            okm
+        })
+        .collect())
+}
+
+fn expand<D: Digest>(mac: &mut Hmac<D>, info: &[u8], okm: &mut [u8]) {
+    mac.reset();
     let os = mac.output_bytes();
     let mut t: Vec<u8> = repeat(0).take(os).collect();
     let mut n: u8 = 0;
@@ -72,7 +150,7 @@ mod test {
     use sr_std::iter::repeat;
 
     use digest::Digest;
-    use hkdf::{hkdf_expand, hkdf_extract};
+    use hkdf::{expand_multi, extract_multi, hkdf_expand, hkdf_extract, HkdfExpander};
     use sha1::Sha1;
     use sha2::Sha256;
 
@@ -231,4 +309,92 @@ mod test {
             //assert!(okm == t.okm);
         }
     }
+
+    #[test]
+    fn test_hkdf_expander_matches_one_shot() {
+        let mut prk = [0u8; 32];
+        hkdf_extract(Sha256::new(), b"salt", b"input key material", &mut prk);
+
+        let mut expander = HkdfExpander::new(Sha256::new(), &prk);
+        let infos: [&[u8]; 4] = [b"client key", b"server key", b"", b"client key"];
+        let lens = [16, 32, 42, 80];
+        for (info, &len) in infos.iter().zip(lens.iter()) {
+            let mut expected: Vec<u8> = repeat(0).take(len).collect();
+            hkdf_expand(Sha256::new(), &prk, info, &mut expected);
+            let mut actual: Vec<u8> = repeat(0).take(len).collect();
+            expander.expand(info, &mut actual);
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_expand_multi() {
+        let mut prk = [0u8; 32];
+        hkdf_extract(Sha256::new(), b"salt", b"input key material", &mut prk);
+
+        let requests: [(&[u8], usize); 4] =
+            [(b"client key", 16), (b"server key", 32), (b"", 0), (b"iv", 8160)];
+        let okms = expand_multi(Sha256::new(), &prk, &requests).unwrap();
+        assert_eq!(okms.len(), requests.len());
+        for (okm, &(info, len)) in okms.iter().zip(requests.iter()) {
+            let mut expected: Vec<u8> = repeat(0).take(len).collect();
+            hkdf_expand(Sha256::new(), &prk, info, &mut expected);
+            assert_eq!(okm, &expected);
+        }
+
+        let too_long: [(&[u8], usize); 2] = [(b"ok", 16), (b"too long", 8161)];
+        assert!(expand_multi(Sha256::new(), &prk, &too_long).is_err());
+        assert_eq!(expand_multi(Sha1::new(), &prk, &[]).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_extract_multi_matches_concatenation() {
+        let segments: [&[u8]; 3] = [b"dh1 shared secret", b"", b"psk"];
+        let mut expected = [0u8; 32];
+        hkdf_extract(Sha256::new(), b"salt", b"dh1 shared secretpsk", &mut expected);
+
+        let mut prk = [0u8; 32];
+        extract_multi(Sha256::new(), b"salt", &segments, &mut prk);
+        assert_eq!(prk, expected);
+
+        extract_multi(Sha256::new(), b"salt", &[b"dh1 shared secretpsk"], &mut prk);
+        assert_eq!(prk, expected);
+
+        hkdf_extract(Sha256::new(), b"salt", b"", &mut expected);
+        extract_multi(Sha256::new(), b"salt", &[], &mut prk);
+        assert_eq!(prk, expected);
+    }
+
+    #[test]
+    fn test_hkdf_extract_empty_salt() {
+        // RFC 5869 test cases 3 (SHA-256) and 7 (SHA-1), where the salt is not provided.
+        let ikm: Vec<u8> = repeat(0x0b).take(22).collect();
+        let mut prk = [0u8; 32];
+        let mut prk_zeros = [0u8; 32];
+        hkdf_extract(Sha256::new(), &[], &ikm[..], &mut prk);
+        hkdf_extract(Sha256::new(), &[0; 32], &ikm[..], &mut prk_zeros);
+        assert_eq!(prk, prk_zeros);
+        assert_eq!(
+            prk,
+            [
+                0x19, 0xef, 0x24, 0xa3, 0x2c, 0x71, 0x7b, 0x16, 0x7f, 0x33, 0xa9, 0x1d, 0x6f, 0x64,
+                0x8b, 0xdf, 0x96, 0x59, 0x67, 0x76, 0xaf, 0xdb, 0x63, 0x77, 0xac, 0x43, 0x4c, 0x1c,
+                0x29, 0x3c, 0xcb, 0x04,
+            ]
+        );
+
+        let ikm: Vec<u8> = repeat(0x0c).take(22).collect();
+        let mut prk = [0u8; 20];
+        let mut prk_zeros = [0u8; 20];
+        hkdf_extract(Sha1::new(), &[], &ikm[..], &mut prk);
+        hkdf_extract(Sha1::new(), &[0; 20], &ikm[..], &mut prk_zeros);
+        assert_eq!(prk, prk_zeros);
+        assert_eq!(
+            prk,
+            [
+                0x2a, 0xdc, 0xca, 0xda, 0x18, 0x77, 0x9e, 0x7c, 0x20, 0x77, 0xad, 0x2e, 0xb1, 0x9d,
+                0x3f, 0x3e, 0x73, 0x13, 0x85, 0xdd,
+            ]
+        );
+    }
 }
@@ -10,8 +10,8 @@
 
 use sr_std::iter::repeat;
 
-use cryptoutil;
-use digest::Digest;
+use cryptoutil::{self, read_u32_be, write_u32_be};
+use digest::{Digest, DigestState};
 use mac::{Mac, MacResult};
 use sr_std::prelude::*;
 
@@ -20,11 +20,25 @@ use sr_std::prelude::*;
  */
 pub struct Hmac<D> {
     digest: D,
-    i_key: Vec<u8>,
-    o_key: Vec<u8>,
+    pads: Pads<D>,
     finished: bool,
 }
 
+// What an Hmac starts its inner and outer hashes from: either the padded keys, or the digests
+// left after absorbing them when the Hmac was created by import_keyed_state(). The latter are
+// restored once and cloned for each message.
+enum Pads<D> {
+    Keys {
+        i_key: Vec<u8>,
+        o_key: Vec<u8>,
+    },
+    States {
+        inner: D,
+        outer: D,
+        clone: fn(&D) -> D,
+    },
+}
+
 fn derive_key(key: &mut [u8], mask: u8) {
     for elem in key.iter_mut() {
         *elem ^= mask;
@@ -73,11 +87,96 @@ impl<D: Digest> Hmac<D> {
         digest.input(&i_key[..]);
         Hmac {
             digest: digest,
-            i_key: i_key,
-            o_key: o_key,
+            pads: Pads::Keys {
+                i_key: i_key,
+                o_key: o_key,
+            },
             finished: false,
         }
     }
+
+    // Put the digest at the start of the inner or the outer hash.
+    fn start(&mut self, outer_hash: bool) {
+        match self.pads {
+            Pads::Keys {
+                ref i_key,
+                ref o_key,
+            } => {
+                self.digest.reset();
+                self.digest.input(if outer_hash { &o_key[..] } else { &i_key[..] });
+            }
+            Pads::States {
+                ref inner,
+                ref outer,
+                clone,
+            } => {
+                self.digest = clone(if outer_hash { outer } else { inner });
+            }
+        }
+    }
+}
+
+impl<D: DigestState + Clone> Hmac<D> {
+    /**
+     * Serialize the digest states left after keying, so that an Hmac with the same key can be
+     * recreated with import_keyed_state() without repeating the key setup. Any input given since
+     * the last reset is not included.
+     *
+     * The key itself is not stored, but the exported state is enough to compute MACs for any
+     * message, so it must be protected exactly like the key.
+     */
+    pub fn export_keyed_state(&self) -> Vec<u8> {
+        let (inner, outer) = match self.pads {
+            Pads::Keys {
+                ref i_key,
+                ref o_key,
+            } => {
+                let mut digest = self.digest.clone();
+                digest.reset();
+                digest.input(&i_key[..]);
+                let inner = digest.save_state();
+                digest.reset();
+                digest.input(&o_key[..]);
+                (inner, digest.save_state())
+            }
+            Pads::States {
+                ref inner,
+                ref outer,
+                ..
+            } => (inner.save_state(), outer.save_state()),
+        };
+        let mut state: Vec<u8> = repeat(0).take(4).collect();
+        write_u32_be(&mut state[..], inner.len() as u32);
+        state.extend_from_slice(&inner[..]);
+        state.extend_from_slice(&outer[..]);
+        state
+    }
+
+    /**
+     * Recreate an Hmac from a state produced by export_keyed_state(), ready to process a new
+     * message. Returns None if the state is malformed.
+     */
+    pub fn import_keyed_state(state: &[u8]) -> Option<Hmac<D>> {
+        if state.len() < 4 {
+            return None;
+        }
+        let inner_len = read_u32_be(&state[..4]) as usize;
+        if state.len() - 4 < inner_len {
+            return None;
+        }
+        let (inner, outer) = state[4..].split_at(inner_len);
+        let inner = D::restore_state(inner)?;
+        let outer = D::restore_state(outer)?;
+        Some(Hmac {
+            digest: inner.clone(),
+            pads: Pads::States {
+                inner: inner,
+                outer: outer,
+                clone: D::clone,
+            },
+            finished: false,
+        })
+    }
 }
 
 impl<D: Digest> Mac for Hmac<D> {
@@ -87,8 +186,7 @@ impl<D: Digest> Mac for Hmac<D> {
     }
 
     fn reset(&mut self) {
-        self.digest.reset();
-        self.digest.input(&self.i_key[..]);
+        self.start(false);
         self.finished = false;
     }
 
@@ -105,8 +203,7 @@ impl<D: Digest> Mac for Hmac<D> {
         if !self.finished {
             self.digest.result(output);
 
-            self.digest.reset();
-            self.digest.input(&self.o_key[..]);
+            self.start(true);
             self.digest.input(output);
 
             self.finished = true;
@@ -124,9 +221,12 @@ impl<D: Digest> Mac for Hmac<D> {
 mod test {
     use sr_std::iter::repeat;
 
+    use sr_std::prelude::*;
+
     use hmac::Hmac;
     use mac::{Mac, MacResult};
     use md5::Md5;
+    use sha2::{Sha256, Sha512};
 
     struct Test {
         key: Vec<u8>,
@@ -198,4 +298,33 @@ mod test {
             //assert!(result == expected);
         }
     }
+
+    #[test]
+    fn test_hmac_keyed_state() {
+        // RFC 4231 test case 2.
+        let sha256_tag = "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843";
+        let sha512_tag = "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea250554\
+                          9758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737";
+        let data = b"what do ya want for nothing?";
+
+        let state = Hmac::new(Sha256::new(), b"Jefe").export_keyed_state();
+        let mut hmac = Hmac::<Sha256>::import_keyed_state(&state[..]).unwrap();
+        for _ in 0..2 {
+            hmac.input(data);
+            assert_eq!(hex::encode(hmac.result().code()), sha256_tag);
+            hmac.reset();
+        }
+        assert_eq!(hmac.export_keyed_state(), state);
+
+        // Input already given to the Hmac is not part of the exported state.
+        let mut hmac = Hmac::new(Sha512::new(), b"Jefe");
+        hmac.input(b"ignored");
+        let mut hmac = Hmac::<Sha512>::import_keyed_state(&hmac.export_keyed_state()).unwrap();
+        hmac.input(data);
+        assert_eq!(hex::encode(hmac.result().code()), sha512_tag);
+
+        assert!(Hmac::<Sha256>::import_keyed_state(&state[..3]).is_none());
+        assert!(Hmac::<Sha256>::import_keyed_state(&state[..state.len() - 1]).is_none());
+        assert!(Hmac::<Sha512>::import_keyed_state(&state[..]).is_none());
+    }
 }
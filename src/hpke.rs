@@ -0,0 +1,347 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+ * Single-shot HPKE (RFC 9180) in base mode, with the DHKEM(X25519, HKDF-SHA256), HKDF-SHA256 and
+ * ChaCha20Poly1305 ciphersuite.
+ *
+ * The sender encapsulates to the recipient's X25519 public key, producing enc, a fresh ephemeral
+ * public key that travels with the ciphertext. Each call seals exactly one message with sequence
+ * number 0; protocols needing several messages under one context, or the PSK and Auth modes,
+ * should build on the primitives directly.
+ */
+
+use sr_std::fmt;
+use sr_std::iter::repeat;
+use sr_std::prelude::*;
+
+use chacha20::ChaCha20;
+use chacha20poly1305::poly1305_key;
+use cryptoutil::write_u64_le;
+use curve25519::{curve25519, curve25519_base};
+#[cfg(all(feature = "os-rng", feature = "std", unix))]
+use fortuna::secure_random_bytes;
+use hkdf::{extract_multi, hkdf_expand};
+use mac::Mac;
+use poly1305::Poly1305;
+use sha2::Sha256;
+use symmetriccipher::SynchronousStreamCipher;
+use util::{fixed_time_eq, secure_memset};
+
+const VERSION_LABEL: &'static [u8] = b"HPKE-v1";
+// "KEM" || I2OSP(kem_id, 2) for DHKEM(X25519, HKDF-SHA256).
+const KEM_SUITE_ID: &'static [u8] = b"KEM\x00\x20";
+// "HPKE" || kem_id || kdf_id (HKDF-SHA256) || aead_id (ChaCha20Poly1305).
+const HPKE_SUITE_ID: &'static [u8] = b"HPKE\x00\x20\x00\x01\x00\x03";
+const MODE_BASE: u8 = 0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HpkeError {
+    /// The X25519 shared secret is all zeros, because a public key has small order.
+    WeakSharedSecret,
+    /// No ephemeral key could be generated because the OS entropy source failed.
+    RandomFailed,
+    /// The ciphertext, enc, info or aad were altered, or the wrong private key was used.
+    AuthenticationFailed,
+}
+
+impl fmt::Display for HpkeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            HpkeError::WeakSharedSecret => f.write_str("HPKE shared secret is all zeros"),
+            HpkeError::RandomFailed => f.write_str("HPKE ephemeral key generation failed"),
+            HpkeError::AuthenticationFailed => f.write_str("HPKE authentication failed"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for HpkeError {}
+
+fn labeled_extract(suite_id: &[u8], salt: &[u8], label: &[u8], ikm: &[u8]) -> [u8; 32] {
+    let mut prk = [0u8; 32];
+    extract_multi(Sha256::new(), salt, &[VERSION_LABEL, suite_id, label, ikm], &mut prk);
+    prk
+}
+
+fn labeled_expand(suite_id: &[u8], prk: &[u8], label: &[u8], info: &[u8], out: &mut [u8]) {
+    let mut labeled_info = Vec::new();
+    labeled_info.push((out.len() >> 8) as u8);
+    labeled_info.push(out.len() as u8);
+    labeled_info.extend_from_slice(VERSION_LABEL);
+    labeled_info.extend_from_slice(suite_id);
+    labeled_info.extend_from_slice(label);
+    labeled_info.extend_from_slice(info);
+    hkdf_expand(Sha256::new(), prk, &labeled_info, out);
+}
+
+/**
+ * DeriveKeyPair of RFC 9180 section 7.1.3: deterministically derive an X25519 key pair, returned
+ * as (private key, public key), from at least 32 bytes of input keying material.
+ */
+pub fn derive_key_pair(ikm: &[u8]) -> ([u8; 32], [u8; 32]) {
+    let mut dkp_prk = labeled_extract(KEM_SUITE_ID, b"", b"dkp_prk", ikm);
+    let mut sk = [0u8; 32];
+    labeled_expand(KEM_SUITE_ID, &dkp_prk, b"sk", b"", &mut sk);
+    secure_memset(&mut dkp_prk, 0);
+    let pk = curve25519_base(&sk);
+    (sk, pk)
+}
+
+// The DHKEM shared secret for the ephemeral public key enc sent to pk_r.
+fn kem_shared_secret(
+    sk: &[u8; 32],
+    peer: &[u8; 32],
+    enc: &[u8; 32],
+    pk_r: &[u8; 32],
+) -> Result<[u8; 32], HpkeError> {
+    let mut dh = curve25519(sk, peer);
+    if fixed_time_eq(&dh, &[0u8; 32]) {
+        return Err(HpkeError::WeakSharedSecret);
+    }
+    let mut eae_prk = labeled_extract(KEM_SUITE_ID, b"", b"eae_prk", &dh);
+    secure_memset(&mut dh, 0);
+    let mut kem_context = [0u8; 64];
+    kem_context[..32].copy_from_slice(enc);
+    kem_context[32..].copy_from_slice(pk_r);
+    let mut shared_secret = [0u8; 32];
+    labeled_expand(KEM_SUITE_ID, &eae_prk, b"shared_secret", &kem_context, &mut shared_secret);
+    secure_memset(&mut eae_prk, 0);
+    Ok(shared_secret)
+}
+
+// The base mode key schedule, returning the AEAD key and the nonce for sequence number 0.
+fn key_schedule(shared_secret: &[u8; 32], info: &[u8]) -> ([u8; 32], [u8; 12]) {
+    let psk_id_hash = labeled_extract(HPKE_SUITE_ID, b"", b"psk_id_hash", b"");
+    let info_hash = labeled_extract(HPKE_SUITE_ID, b"", b"info_hash", info);
+    let mut context = [0u8; 65];
+    context[0] = MODE_BASE;
+    context[1..33].copy_from_slice(&psk_id_hash);
+    context[33..].copy_from_slice(&info_hash);
+
+    let mut secret = labeled_extract(HPKE_SUITE_ID, shared_secret, b"secret", b"");
+    let mut key = [0u8; 32];
+    let mut nonce = [0u8; 12];
+    labeled_expand(HPKE_SUITE_ID, &secret, b"key", &context, &mut key);
+    labeled_expand(HPKE_SUITE_ID, &secret, b"base_nonce", &context, &mut nonce);
+    secure_memset(&mut secret, 0);
+    (key, nonce)
+}
+
+// The RFC 8439 ChaCha20-Poly1305 tag. ChaCha20Poly1305 in this crate implements the unpadded
+// draft-agl construction, which HPKE does not use.
+fn aead_tag(key: &[u8; 32], nonce: &[u8; 12], aad: &[u8], ciphertext: &[u8]) -> [u8; 16] {
+    let mut mac_key = poly1305_key(key, nonce);
+    let mut mac = Poly1305::new(&mac_key);
+    secure_memset(&mut mac_key, 0);
+    let padding = [0u8; 15];
+    mac.input(aad);
+    mac.input(&padding[..(16 - aad.len() % 16) % 16]);
+    mac.input(ciphertext);
+    mac.input(&padding[..(16 - ciphertext.len() % 16) % 16]);
+    let mut lens = [0u8; 16];
+    write_u64_le(&mut lens[..8], aad.len() as u64);
+    write_u64_le(&mut lens[8..], ciphertext.len() as u64);
+    mac.input(&lens);
+    let mut tag = [0u8; 16];
+    mac.raw_result(&mut tag);
+    tag
+}
+
+fn aead_seal(key: &[u8; 32], nonce: &[u8; 12], aad: &[u8], plaintext: &[u8]) -> Vec<u8> {
+    let mut sealed: Vec<u8> = repeat(0).take(plaintext.len() + 16).collect();
+    let len = plaintext.len();
+    let mut cipher = ChaCha20::new_ietf(key, nonce);
+    cipher.set_counter(1);
+    cipher.process(plaintext, &mut sealed[..len]);
+    let tag = aead_tag(key, nonce, aad, &sealed[..len]);
+    sealed[len..].copy_from_slice(&tag);
+    sealed
+}
+
+fn aead_open(
+    key: &[u8; 32],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    sealed: &[u8],
+) -> Result<Vec<u8>, HpkeError> {
+    if sealed.len() < 16 {
+        return Err(HpkeError::AuthenticationFailed);
+    }
+    let (ciphertext, tag) = sealed.split_at(sealed.len() - 16);
+    if !fixed_time_eq(&aead_tag(key, nonce, aad, ciphertext), tag) {
+        return Err(HpkeError::AuthenticationFailed);
+    }
+    let mut plaintext: Vec<u8> = repeat(0).take(ciphertext.len()).collect();
+    let mut cipher = ChaCha20::new_ietf(key, nonce);
+    cipher.set_counter(1);
+    cipher.process(ciphertext, &mut plaintext);
+    Ok(plaintext)
+}
+
+/**
+ * Seal plaintext to the recipient public key pk_r using an ephemeral key pair derived from ikm_e
+ * with derive_key_pair(). Returns enc and the ciphertext followed by its 16 byte tag.
+ *
+ * ikm_e must be fresh secret randomness for every message; reusing it reuses the AEAD key and
+ * nonce. This exists for test vectors and for callers with their own RNG, others should use
+ * seal_base().
+ */
+pub fn seal_base_with_ikm(
+    ikm_e: &[u8],
+    pk_r: &[u8; 32],
+    info: &[u8],
+    aad: &[u8],
+    plaintext: &[u8],
+) -> Result<([u8; 32], Vec<u8>), HpkeError> {
+    let (mut sk_e, enc) = derive_key_pair(ikm_e);
+    let shared_secret = kem_shared_secret(&sk_e, pk_r, &enc, pk_r);
+    secure_memset(&mut sk_e, 0);
+    let mut shared_secret = shared_secret?;
+    let (mut key, nonce) = key_schedule(&shared_secret, info);
+    secure_memset(&mut shared_secret, 0);
+    let ciphertext = aead_seal(&key, &nonce, aad, plaintext);
+    secure_memset(&mut key, 0);
+    Ok((enc, ciphertext))
+}
+
+/**
+ * Seal plaintext to the recipient public key pk_r with a fresh ephemeral key from
+ * fortuna::secure_random_bytes(). Returns enc, which must be sent along, and the ciphertext
+ * followed by its 16 byte tag.
+ */
+#[cfg(all(feature = "os-rng", feature = "std", unix))]
+pub fn seal_base(
+    pk_r: &[u8; 32],
+    info: &[u8],
+    aad: &[u8],
+    plaintext: &[u8],
+) -> Result<([u8; 32], Vec<u8>), HpkeError> {
+    let mut ikm_e = [0u8; 32];
+    secure_random_bytes(&mut ikm_e).map_err(|_| HpkeError::RandomFailed)?;
+    let result = seal_base_with_ikm(&ikm_e, pk_r, info, aad, plaintext);
+    secure_memset(&mut ikm_e, 0);
+    result
+}
+
+/**
+ * Open a ciphertext sealed to the public key of sk_r, given the sender's enc. info and aad must
+ * be the ones used to seal.
+ */
+pub fn open_base(
+    sk_r: &[u8; 32],
+    enc: &[u8; 32],
+    info: &[u8],
+    aad: &[u8],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, HpkeError> {
+    let pk_r = curve25519_base(sk_r);
+    let mut shared_secret = kem_shared_secret(sk_r, enc, enc, &pk_r)?;
+    let (mut key, nonce) = key_schedule(&shared_secret, info);
+    secure_memset(&mut shared_secret, 0);
+    let result = aead_open(&key, &nonce, aad, ciphertext);
+    secure_memset(&mut key, 0);
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use hpke::{derive_key_pair, open_base, seal_base_with_ikm, HpkeError};
+
+    fn h32(s: &str) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        out.copy_from_slice(&hex::decode(s).unwrap());
+        out
+    }
+
+    #[test]
+    fn test_rfc9180_a2_base() {
+        // RFC 9180 appendix A.2.1: DHKEM(X25519, HKDF-SHA256), HKDF-SHA256, ChaCha20Poly1305,
+        // base mode, the encryption with sequence number 0.
+        let ikm_e = h32("909a9b35d3dc4713a5e72a4da274b55d3d3821a37e5d099e74a647db583a904b");
+        let ikm_r = h32("1ac01f181fdf9f352797655161c58b75c656a6cc2716dcb66372da835542e1df");
+        let info = b"Ode on a Grecian Urn";
+        let aad = b"Count-0";
+        let plaintext = b"Beauty is truth, truth beauty";
+
+        let (sk_r, pk_r) = derive_key_pair(&ikm_r);
+        assert_eq!(
+            sk_r,
+            h32("8057991eef8f1f1af18f4a9491d16a1ce333f695d4db8e38da75975c4478e0fb")
+        );
+        assert_eq!(
+            pk_r,
+            h32("4310ee97d88cc1f088a5576c77ab0cf5c3ac797f3d95139c6c84b5429c59662a")
+        );
+
+        let (enc, ciphertext) = seal_base_with_ikm(&ikm_e, &pk_r, info, aad, plaintext).unwrap();
+        assert_eq!(
+            enc,
+            h32("1afa08d3dec047a643885163f1180476fa7ddb54c6a8029ea33f95796bf2ac4a")
+        );
+        assert_eq!(
+            hex::encode(&ciphertext),
+            "1c5250d8034ec2b784ba2cfd69dbdb8af406cfe3ff938e131f0def8c8b60b4db21993c62ce81883d2dd1b5\
+             1a28"
+        );
+
+        let opened = open_base(&sk_r, &enc, info, aad, &ciphertext).unwrap();
+        assert_eq!(&opened[..], &plaintext[..]);
+    }
+
+    #[test]
+    fn test_open_rejects_tampering() {
+        let (sk_r, pk_r) = derive_key_pair(&[7u8; 32]);
+        let (enc, ciphertext) =
+            seal_base_with_ikm(&[9u8; 32], &pk_r, b"info", b"aad", b"msg").unwrap();
+
+        let mut bad = ciphertext.clone();
+        bad[0] ^= 1;
+        assert_eq!(
+            open_base(&sk_r, &enc, b"info", b"aad", &bad),
+            Err(HpkeError::AuthenticationFailed)
+        );
+        assert_eq!(
+            open_base(&sk_r, &enc, b"other", b"aad", &ciphertext),
+            Err(HpkeError::AuthenticationFailed)
+        );
+        assert_eq!(
+            open_base(&sk_r, &enc, b"info", b"other", &ciphertext),
+            Err(HpkeError::AuthenticationFailed)
+        );
+        let (other_sk, _) = derive_key_pair(&[8u8; 32]);
+        assert_eq!(
+            open_base(&other_sk, &enc, b"info", b"aad", &ciphertext),
+            Err(HpkeError::AuthenticationFailed)
+        );
+    }
+
+    #[test]
+    fn test_small_order_public_key() {
+        let zero = [0u8; 32];
+        assert_eq!(
+            seal_base_with_ikm(&[9u8; 32], &zero, b"", b"", b"msg"),
+            Err(HpkeError::WeakSharedSecret)
+        );
+        let (sk_r, _) = derive_key_pair(&[7u8; 32]);
+        assert_eq!(
+            open_base(&sk_r, &zero, b"", b"", &[0u8; 16]),
+            Err(HpkeError::WeakSharedSecret)
+        );
+    }
+
+    #[cfg(all(feature = "os-rng", feature = "std", unix))]
+    #[test]
+    fn test_seal_base_round_trip() {
+        use hpke::seal_base;
+
+        let (sk_r, pk_r) = derive_key_pair(&[7u8; 32]);
+        let (enc, ciphertext) = seal_base(&pk_r, b"info", b"aad", b"msg").unwrap();
+        let (enc2, _) = seal_base(&pk_r, b"info", b"aad", b"msg").unwrap();
+        assert!(enc != enc2);
+        assert_eq!(open_base(&sk_r, &enc, b"info", b"aad", &ciphertext).unwrap(), b"msg".to_vec());
+    }
+}
@@ -18,7 +18,6 @@ extern crate sr_std;
 // extern crate rand;
 extern crate base64;
 extern crate hex;
-extern crate libc;
 //extern crate time;
 
 #[cfg(all(test, feature = "with-bench"))]
@@ -37,6 +36,7 @@ pub mod blowfish;
 pub mod buffer;
 pub mod chacha20;
 pub mod chacha20poly1305;
+pub mod commitment;
 mod cryptoutil;
 pub mod curve25519;
 pub mod digest;
@@ -46,12 +46,18 @@ pub mod ghash;
 pub mod hc128;
 pub mod hkdf;
 pub mod hmac;
+pub mod hpke;
+pub mod kdf;
 pub mod mac;
 pub mod md5;
+pub mod otp;
 pub mod pbkdf2;
+#[cfg(feature = "std")]
+pub mod phc;
 pub mod poly1305;
 pub mod rc4;
 pub mod ripemd160;
+pub mod ristretto255;
 pub mod salsa20;
 pub mod scrypt;
 pub mod sha1;
@@ -60,7 +66,10 @@ pub mod sha3;
 mod simd;
 pub mod sosemanuk;
 mod step_by;
+#[cfg(feature = "std")]
+pub mod stream;
 pub mod symmetriccipher;
+pub mod transcript;
 pub mod util;
 pub mod whirlpool;
 
@@ -8,8 +8,9 @@
  * The mac module defines the Message Authentication Code (Mac) trait.
  */
 
+use sr_std::cmp::min;
 use sr_std::prelude::*;
-use util::fixed_time_eq;
+use util::fixed_time_eq_internal;
 
 /**
  * The Mac trait defines methods for a Message Authentication function.
@@ -48,7 +49,9 @@ pub trait Mac {
 }
 
 /**
- * A MacResult wraps a Mac code and provides a safe Eq implementation that runs in fixed time.
+ * A MacResult wraps a Mac code and provides a safe Eq implementation that runs in fixed time. The
+ * code may be truncated, e.g. to the 10 bytes of HMAC-SHA1-80; its length is the logical tag
+ * length and is not treated as secret.
  */
 pub struct MacResult {
     code: Vec<u8>,
@@ -78,14 +81,86 @@ impl MacResult {
     pub fn code<'s>(&'s self) -> &'s [u8] {
         &self.code[..]
     }
+
+    /**
+     * The length of the code, in bytes.
+     */
+    pub fn len(&self) -> usize {
+        self.code.len()
+    }
+
+    /**
+     * Whether the code is empty.
+     */
+    pub fn is_empty(&self) -> bool {
+        self.code.is_empty()
+    }
+
+    /**
+     * Keep only the first len bytes of the code, as for truncated tags such as HMAC-SHA1-80.
+     * Panics if len is larger than the current length.
+     */
+    pub fn truncated(mut self, len: usize) -> MacResult {
+        assert!(len <= self.code.len());
+        self.code.truncate(len);
+        self
+    }
 }
 
 impl PartialEq for MacResult {
     fn eq(&self, x: &MacResult) -> bool {
         let lhs = self.code();
         let rhs = x.code();
-        fixed_time_eq(lhs, rhs)
+        // Only the lengths, which are public, affect the number of bytes compared. The contents
+        // of the common prefix are always compared in full.
+        let len = min(lhs.len(), rhs.len());
+        let prefix_eq = len == 0 || fixed_time_eq_internal(&lhs[..len], &rhs[..len]);
+        prefix_eq & (lhs.len() == rhs.len())
     }
 }
 
 impl Eq for MacResult {}
+
+#[cfg(test)]
+mod test {
+    use hmac::Hmac;
+    use mac::{Mac, MacResult};
+    use sha1::Sha1;
+
+    fn hmac_sha1_80(key: &[u8], data: &[u8]) -> MacResult {
+        let mut m = Hmac::new(Sha1::new(), key);
+        m.input(data);
+        m.result().truncated(10)
+    }
+
+    #[test]
+    fn test_truncated_hmac_sha1_80() {
+        // RFC 2202 test case 5, whose tag is given truncated to 96 bits: 4c1a03424b55e07fe7f27be1.
+        let key = [0x0c; 20];
+        let tag = hmac_sha1_80(&key, b"Test With Truncation");
+        assert_eq!(tag.len(), 10);
+        assert!(!tag.is_empty());
+        assert!(MacResult::new(&[]).is_empty());
+
+        let expected = [0x4c, 0x1a, 0x03, 0x42, 0x4b, 0x55, 0xe0, 0x7f, 0xe7, 0xf2];
+        assert!(tag == MacResult::new(&expected));
+        assert!(tag == hmac_sha1_80(&key, b"Test With Truncation"));
+        assert!(tag != hmac_sha1_80(&key, b"Test With Truncation!"));
+
+        // Differences in the first and last byte are both caught.
+        for &i in [0usize, 9].iter() {
+            let mut wrong = expected;
+            wrong[i] ^= 1;
+            assert!(tag != MacResult::new(&wrong));
+        }
+
+        // A tag never equals a longer or shorter one, even if one is a prefix of the other.
+        let mut full = Hmac::new(Sha1::new(), &key);
+        full.input(b"Test With Truncation");
+        let full = full.result();
+        assert!(tag != full.truncated(12));
+        assert!(tag != MacResult::new(&tag.code()[..9]));
+        assert!(MacResult::new(&[]) == MacResult::new(&[]));
+        assert!(MacResult::new(&[]) != tag);
+    }
+}
@@ -217,7 +217,7 @@ impl Digest for Md5 {
         //assert!(!self.finished);
         // Unlike Sha1 and Sha2, the length value in MD5 is defined as the length of the message mod
         // 2^64 - ie: integer overflow is OK.
-        self.length_bytes += input.len() as u64;
+        self.length_bytes = self.length_bytes.wrapping_add(input.len() as u64);
         let self_state = &mut self.state;
         self.buffer.input(input, |d: &[u8]| {
             self_state.process_block(d);
@@ -327,6 +327,22 @@ mod tests {
         let mut sh = Md5::new();
         test_digest_1million_random(&mut sh, 64, "7707d6ae4e027c70eea2a935c2296f21");
     }
+
+    #[test]
+    fn test_length_wraps() {
+        // MD5 only encodes the message length mod 2^64 bits, so the counter wraps instead of
+        // overflowing.
+        let mut sh = Md5::new();
+        sh.length_bytes = (u64::max_value() >> 3) - 1;
+        sh.input(b"abc");
+        assert_eq!(sh.length_bytes, (u64::max_value() >> 3) + 2);
+        assert_eq!(sh.length_bytes << 3, 8);
+
+        let mut sh = Md5::new();
+        sh.length_bytes = u64::max_value();
+        sh.input(b"a");
+        assert_eq!(sh.length_bytes, 0);
+    }
 }
 
 #[cfg(all(test, feature = "with-bench"))]
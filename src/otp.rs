@@ -0,0 +1,175 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+ * This module implements the HMAC-based one-time password algorithm (HOTP) as specified in
+ * RFC 4226 and the time-based variant (TOTP) as specified in RFC 6238.
+ */
+
+use cryptoutil::{read_u32_be, write_u64_be};
+use digest::Digest;
+use hmac::Hmac;
+use mac::Mac;
+use sha1::Sha1;
+use sr_std::iter::repeat;
+use sr_std::prelude::*;
+
+/**
+ * Compute an HOTP value using HMAC-SHA1, as in RFC 4226.
+ *
+ * # Arguments
+ * * secret - The shared secret.
+ * * counter - The moving factor.
+ * * digits - The number of decimal digits in the result, from 6 to 10.
+ *
+ * Returns None if digits is out of range.
+ *
+ */
+pub fn hotp(secret: &[u8], counter: u64, digits: u32) -> Option<u32> {
+    hotp_digest(Sha1::new(), secret, counter, digits)
+}
+
+/**
+ * Compute an HOTP value using HMAC with the given digest, for example Sha256 or Sha512 as allowed
+ * by RFC 6238. Returns None if digits is out of range, as hotp() does, or if the digest is
+ * shorter than the 20 bytes that dynamic truncation reads from.
+ */
+pub fn hotp_digest<D: Digest>(
+    digest: D,
+    secret: &[u8],
+    counter: u64,
+    digits: u32,
+) -> Option<u32> {
+    // RFC 4226 requires at least 6 digits, and the 31 bit code has no more than 10.
+    if digits < 6 || digits > 10 {
+        return None;
+    }
+    // The offset is at most 15, so the 4 byte window needs at least 19 bytes of HMAC output; the
+    // RFC only defines truncation for SHA-1 and longer.
+    if digest.output_bytes() < 20 {
+        return None;
+    }
+
+    let mut mac = Hmac::new(digest, secret);
+    let mut msg = [0u8; 8];
+    write_u64_be(&mut msg, counter);
+    mac.input(&msg);
+
+    let mut code: Vec<u8> = repeat(0).take(mac.output_bytes()).collect();
+    mac.raw_result(&mut code);
+
+    // Dynamic truncation: the low nibble of the last byte selects a 4 byte window.
+    let offset = (code[code.len() - 1] & 0xf) as usize;
+    let bin_code = read_u32_be(&code[offset..offset + 4]) & 0x7fffffff;
+
+    Some((bin_code as u64 % 10u64.pow(digits)) as u32)
+}
+
+/**
+ * Compute a TOTP value using HMAC-SHA1, as in RFC 6238.
+ *
+ * # Arguments
+ * * secret - The shared secret.
+ * * unix_time - The current time in seconds since the Unix epoch.
+ * * step - The time step in seconds, usually 30.
+ * * t0 - The Unix time to start counting time steps from, usually 0.
+ * * digits - The number of decimal digits in the result, from 6 to 10.
+ *
+ * Returns None if digits is out of range, step is zero or unix_time is before t0.
+ *
+ */
+pub fn totp(secret: &[u8], unix_time: u64, step: u64, t0: u64, digits: u32) -> Option<u32> {
+    totp_digest(Sha1::new(), secret, unix_time, step, t0, digits)
+}
+
+/**
+ * Compute a TOTP value using HMAC with the given digest. Returns None for the same arguments as
+ * totp() does, or for a digest that hotp_digest() rejects.
+ */
+pub fn totp_digest<D: Digest>(
+    digest: D,
+    secret: &[u8],
+    unix_time: u64,
+    step: u64,
+    t0: u64,
+    digits: u32,
+) -> Option<u32> {
+    if step == 0 || unix_time < t0 {
+        return None;
+    }
+    hotp_digest(digest, secret, (unix_time - t0) / step, digits)
+}
+
+#[cfg(test)]
+mod test {
+    use md5::Md5;
+    use otp::{hotp, hotp_digest, totp, totp_digest};
+    use sha2::{Sha256, Sha512};
+    use sha3::Sha3;
+
+    #[test]
+    fn test_rfc4226_vectors() {
+        let secret = b"12345678901234567890";
+        let expected = [
+            755224, 287082, 359152, 969429, 338314, 254676, 287922, 162583, 399871, 520489,
+        ];
+        for (counter, &value) in expected.iter().enumerate() {
+            assert_eq!(hotp(secret, counter as u64, 6), Some(value));
+        }
+    }
+
+    #[test]
+    fn test_rfc6238_vectors() {
+        let secret_sha1 = b"12345678901234567890";
+        let secret_sha256 = b"12345678901234567890123456789012";
+        let secret_sha512 =
+            b"1234567890123456789012345678901234567890123456789012345678901234";
+
+        // (time, SHA1, SHA256, SHA512)
+        let tests: [(u64, u32, u32, u32); 6] = [
+            (59, 94287082, 46119246, 90693936),
+            (1111111109, 7081804, 68084774, 25091201),
+            (1111111111, 14050471, 67062674, 99943326),
+            (1234567890, 89005924, 91819424, 93441116),
+            (2000000000, 69279037, 90698825, 38618901),
+            (20000000000, 65353130, 77737706, 47863826),
+        ];
+
+        for &(time, sha1, sha256, sha512) in tests.iter() {
+            assert_eq!(totp(secret_sha1, time, 30, 0, 8), Some(sha1));
+            assert_eq!(
+                totp_digest(Sha256::new(), secret_sha256, time, 30, 0, 8),
+                Some(sha256)
+            );
+            assert_eq!(
+                totp_digest(Sha512::new(), secret_sha512, time, 30, 0, 8),
+                Some(sha512)
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        let secret = b"12345678901234567890";
+        assert_eq!(hotp(secret, 0, 5), None);
+        assert_eq!(hotp(secret, 0, 11), None);
+        assert_eq!(hotp(secret, 0, 20), None);
+        assert_eq!(hotp(secret, 0, 10), Some(1284755224));
+        assert_eq!(totp(secret, 59, 0, 0, 8), None);
+        assert_eq!(totp(secret, 59, 30, 60, 8), None);
+        assert_eq!(totp(secret, 60, 30, 60, 8), hotp(secret, 0, 8));
+    }
+
+    #[test]
+    fn test_short_digest() {
+        let secret = b"12345678901234567890";
+        for counter in 0..64 {
+            assert_eq!(hotp_digest(Md5::new(), secret, counter, 6), None);
+        }
+        assert_eq!(hotp_digest(Sha3::shake_128(), secret, 0, 6), None);
+        assert_eq!(totp_digest(Md5::new(), secret, 59, 30, 0, 8), None);
+    }
+}
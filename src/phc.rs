@@ -0,0 +1,300 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+ * This module encodes and parses password hashes in the PHC string format:
+ *
+ * `$<id>[$v=<version>][$<param>=<value>(,<param>=<value>)*][$<salt>[$<hash>]]`
+ *
+ * The salt and hash are encoded with the standard base64 alphabet without padding. Parameter
+ * values are kept as strings; interpreting them is left to the algorithm named by the id.
+ * scrypt::scrypt_phc() and pbkdf2::pbkdf2_phc() hash passwords into this format, and
+ * scrypt_check() and pbkdf2_check() accept it.
+ */
+
+use sr_std::prelude::*;
+
+static ERR_STR: &'static str = "String is not in PHC format.";
+
+static B64_CHARS: &'static [u8] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/**
+ * A parsed PHC string. An empty salt or hash means that field was absent.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PhcString {
+    pub id: String,
+    pub version: Option<u32>,
+    pub params: Vec<(String, String)>,
+    pub salt: Vec<u8>,
+    pub hash: Vec<u8>,
+}
+
+impl PhcString {
+    /**
+     * Look up the value of a parameter by name.
+     */
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params.iter().find(|p| p.0 == name).map(|p| &p.1[..])
+    }
+
+    /**
+     * Encode back into a PHC string, including the version if there is one.
+     */
+    pub fn encode(&self) -> String {
+        let params: Vec<(&str, &str)> =
+            self.params.iter().map(|p| (&p.0[..], &p.1[..])).collect();
+        encode_with_version(&self.id, self.version, &params, &self.salt, &self.hash)
+    }
+}
+
+/**
+ * Encode a PHC string with no version field. An empty salt omits the salt and hash; a hash
+ * without a salt cannot be represented.
+ *
+ * Panics if the id or a parameter name is not 1 to 32 characters of `[a-z0-9-]`, or if a
+ * parameter value contains characters other than `[a-zA-Z0-9/+.-]`.
+ */
+pub fn encode(id: &str, params: &[(&str, &str)], salt: &[u8], hash: &[u8]) -> String {
+    encode_with_version(id, None, params, salt, hash)
+}
+
+fn encode_with_version(
+    id: &str,
+    version: Option<u32>,
+    params: &[(&str, &str)],
+    salt: &[u8],
+    hash: &[u8],
+) -> String {
+    assert!(valid_name(id));
+    assert!(!salt.is_empty() || hash.is_empty());
+
+    let mut result = String::new();
+    result.push('$');
+    result.push_str(id);
+    if let Some(v) = version {
+        result.push_str("$v=");
+        result.push_str(&v.to_string());
+    }
+    for (i, &(name, value)) in params.iter().enumerate() {
+        assert!(valid_name(name) && valid_value(value));
+        result.push(if i == 0 { '$' } else { ',' });
+        result.push_str(name);
+        result.push('=');
+        result.push_str(value);
+    }
+    if !salt.is_empty() {
+        result.push('$');
+        b64_encode(salt, &mut result);
+        if !hash.is_empty() {
+            result.push('$');
+            b64_encode(hash, &mut result);
+        }
+    }
+    result
+}
+
+/**
+ * Parse a PHC string. Fails if the string is malformed or a salt or hash is not canonical
+ * unpadded base64.
+ */
+pub fn parse(s: &str) -> Result<PhcString, &'static str> {
+    let mut fields = s.split('$');
+
+    // The string must start with a "$"
+    match fields.next() {
+        Some("") => {}
+        _ => return Err(ERR_STR),
+    }
+
+    let id = match fields.next() {
+        Some(id) if valid_name(id) => id.to_string(),
+        _ => return Err(ERR_STR),
+    };
+
+    let mut field = fields.next();
+
+    let mut version = None;
+    if let Some(f) = field {
+        if f.starts_with("v=") && !f.contains(',') {
+            version = Some(parse_decimal(&f[2..])?);
+            field = fields.next();
+        }
+    }
+
+    let mut params = Vec::new();
+    if let Some(f) = field {
+        if f.contains('=') {
+            for param in f.split(',') {
+                let mut kv = param.splitn(2, '=');
+                match (kv.next(), kv.next()) {
+                    (Some(name), Some(value)) if valid_name(name) && valid_value(value) => {
+                        if params.iter().any(|p: &(String, String)| p.0 == name) {
+                            return Err(ERR_STR);
+                        }
+                        params.push((name.to_string(), value.to_string()));
+                    }
+                    _ => return Err(ERR_STR),
+                }
+            }
+            field = fields.next();
+        }
+    }
+
+    let salt = match field {
+        Some(f) => b64_decode(f)?,
+        None => Vec::new(),
+    };
+    let hash = match fields.next() {
+        Some(f) => b64_decode(f)?,
+        None => Vec::new(),
+    };
+
+    // Make sure there is no trailing data
+    if fields.next().is_some() {
+        return Err(ERR_STR);
+    }
+
+    Ok(PhcString {
+        id: id,
+        version: version,
+        params: params,
+        salt: salt,
+        hash: hash,
+    })
+}
+
+fn valid_name(name: &str) -> bool {
+    name.len() >= 1
+        && name.len() <= 32
+        && name.bytes().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == b'-')
+}
+
+fn valid_value(value: &str) -> bool {
+    value
+        .bytes()
+        .all(|c| c.is_ascii_alphanumeric() || c == b'/' || c == b'+' || c == b'.' || c == b'-')
+}
+
+fn parse_decimal(s: &str) -> Result<u32, &'static str> {
+    // Leading zeros are not allowed, so every version has exactly one encoding
+    if s.is_empty() || (s.len() > 1 && s.starts_with('0')) {
+        return Err(ERR_STR);
+    }
+    s.parse().map_err(|_| ERR_STR)
+}
+
+fn b64_encode(input: &[u8], out: &mut String) {
+    for chunk in input.chunks(3) {
+        let mut n = 0u32;
+        for (i, &b) in chunk.iter().enumerate() {
+            n |= (b as u32) << (16 - 8 * i);
+        }
+        for i in 0..chunk.len() + 1 {
+            out.push(B64_CHARS[((n >> (18 - 6 * i)) & 0x3f) as usize] as char);
+        }
+    }
+}
+
+fn b64_decode(input: &str) -> Result<Vec<u8>, &'static str> {
+    if input.len() % 4 == 1 {
+        return Err(ERR_STR);
+    }
+    let mut result = Vec::with_capacity(input.len() * 3 / 4);
+    for chunk in input.as_bytes().chunks(4) {
+        let mut n = 0u32;
+        for (i, &c) in chunk.iter().enumerate() {
+            let v = match B64_CHARS.iter().position(|&x| x == c) {
+                Some(v) => v as u32,
+                None => return Err(ERR_STR),
+            };
+            n |= v << (18 - 6 * i);
+        }
+        let bytes = chunk.len() - 1;
+        // Unused trailing bits must be zero, so every value has exactly one encoding
+        if n & (0xffffff >> (8 * bytes)) != 0 {
+            return Err(ERR_STR);
+        }
+        for i in 0..bytes {
+            result.push((n >> (16 - 8 * i)) as u8);
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test {
+    use phc::{encode, parse};
+    use scrypt::{scrypt, ScryptParams};
+
+    #[test]
+    fn argon2id_round_trip() {
+        let s = "$argon2id$v=19$m=65536,t=2,p=1$c29tZXNhbHQ$RdescudvJCsgt3ub+b+dWRWJTmaaJObG";
+        let phc = parse(s).unwrap();
+        assert_eq!(phc.id, "argon2id");
+        assert_eq!(phc.version, Some(19));
+        assert_eq!(phc.param("m"), Some("65536"));
+        assert_eq!(phc.param("t"), Some("2"));
+        assert_eq!(phc.param("p"), Some("1"));
+        assert_eq!(phc.param("x"), None);
+        assert_eq!(&phc.salt[..], b"somesalt");
+        assert_eq!(phc.hash.len(), 24);
+        assert_eq!(phc.encode(), s);
+    }
+
+    #[test]
+    fn scrypt_round_trip() {
+        // Hash computed with Python's hashlib.scrypt.
+        let s = "$scrypt$ln=10,r=8,p=16$TmFDbC1zYWx0LXZhbHVlIQ$\
+                 FxbwNP5sTsDSyecfTD24lMjuKtnVvmaYWsa/dejtOBk";
+        let phc = parse(s).unwrap();
+        assert_eq!(phc.id, "scrypt");
+        assert_eq!(phc.version, None);
+        assert_eq!(&phc.salt[..], b"NaCl-salt-value!");
+
+        let log_n: u8 = phc.param("ln").unwrap().parse().unwrap();
+        let r: u32 = phc.param("r").unwrap().parse().unwrap();
+        let p: u32 = phc.param("p").unwrap().parse().unwrap();
+        let mut dk = [0u8; 32];
+        scrypt(b"password", &phc.salt, &ScryptParams::new(log_n, r, p), &mut dk);
+        assert_eq!(&dk[..], &phc.hash[..]);
+
+        let params = [("ln", "10"), ("r", "8"), ("p", "16")];
+        assert_eq!(encode("scrypt", &params, &phc.salt, &dk), s);
+    }
+
+    #[test]
+    fn optional_fields() {
+        assert_eq!(encode("pbkdf2-sha256", &[], b"", b""), "$pbkdf2-sha256");
+        let phc = parse("$pbkdf2-sha256").unwrap();
+        assert!(phc.params.is_empty() && phc.salt.is_empty() && phc.hash.is_empty());
+
+        let phc = parse("$bcrypt$c2FsdA").unwrap();
+        assert_eq!(&phc.salt[..], b"salt");
+        assert!(phc.hash.is_empty());
+        assert_eq!(phc.encode(), "$bcrypt$c2FsdA");
+    }
+
+    #[test]
+    fn rejects_malformed() {
+        let bad = [
+            "",
+            "argon2id",
+            "$",
+            "$Argon2id",
+            "$argon2id$v=019",
+            "$argon2id$m=1,m=2",
+            "$argon2id$m=1,=2",
+            "$argon2id$m=1$c29tZXNhbHQ=",
+            "$argon2id$m=1$c29tZXNhbHR",
+            "$argon2id$m=1$c29tZXNhbHQ$aGFzaA$",
+        ];
+        for s in bad.iter() {
+            assert!(parse(s).is_err(), "{}", s);
+        }
+    }
+}
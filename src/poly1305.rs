@@ -9,128 +9,415 @@
 
 use sr_std::cmp::min;
 
-use cryptoutil::{read_u32_le, write_u32_le};
 use mac::{Mac, MacResult};
 use sr_std::marker::*;
 use sr_std::prelude::*;
 
+// The state of the accumulator and key, independent of buffering. Two layouts are provided:
+// five 26 bit limbs multiplied with 32x32->64 bit products, which suits 32 bit targets, and three
+// 44 bit limbs multiplied with 64x64->128 bit products, which is much faster where 64 bit
+// multiplies are native. Both produce identical tags.
+trait PolyState: Copy {
+    fn new(key: &[u8]) -> Self;
+
+    // Process every full 16 byte block in m. The caller must ensure that m.len() is a multiple
+    // of 16. hibit is false only for the final, padded partial block.
+    fn blocks(&mut self, m: &[u8], hibit: bool);
+
+    // Reduce the accumulator, add the pad and write the tag.
+    fn finish(&mut self, output: &mut [u8]);
+
+    fn reset(&mut self);
+}
+
+#[cfg_attr(target_pointer_width = "64", allow(dead_code))]
+mod limb26 {
+    use cryptoutil::{read_u32_le, write_u32_le};
+    use super::PolyState;
+
+    #[derive(Clone, Copy)]
+    pub struct State {
+        r: [u32; 5],
+        h: [u32; 5],
+        pad: [u32; 4],
+    }
+
+    impl PolyState for State {
+        fn new(key: &[u8]) -> State {
+            let mut poly = State {
+                r: [0u32; 5],
+                h: [0u32; 5],
+                pad: [0u32; 4],
+            };
+
+            // r &= 0xffffffc0ffffffc0ffffffc0fffffff
+            poly.r[0] = (read_u32_le(&key[0..4])) & 0x3ffffff;
+            poly.r[1] = (read_u32_le(&key[3..7]) >> 2) & 0x3ffff03;
+            poly.r[2] = (read_u32_le(&key[6..10]) >> 4) & 0x3ffc0ff;
+            poly.r[3] = (read_u32_le(&key[9..13]) >> 6) & 0x3f03fff;
+            poly.r[4] = (read_u32_le(&key[12..16]) >> 8) & 0x00fffff;
+
+            poly.pad[0] = read_u32_le(&key[16..20]);
+            poly.pad[1] = read_u32_le(&key[20..24]);
+            poly.pad[2] = read_u32_le(&key[24..28]);
+            poly.pad[3] = read_u32_le(&key[28..32]);
+
+            poly
+        }
+
+        // The accumulator is kept in locals for the whole run so that bulk input does not pay
+        // for loading and storing the state on every block.
+        fn blocks(&mut self, m: &[u8], hibit: bool) {
+            let hibit: u32 = if hibit { 1 << 24 } else { 0 };
+
+            let r0 = self.r[0];
+            let r1 = self.r[1];
+            let r2 = self.r[2];
+            let r3 = self.r[3];
+            let r4 = self.r[4];
+
+            let s1 = r1 * 5;
+            let s2 = r2 * 5;
+            let s3 = r3 * 5;
+            let s4 = r4 * 5;
+
+            let mut h0 = self.h[0];
+            let mut h1 = self.h[1];
+            let mut h2 = self.h[2];
+            let mut h3 = self.h[3];
+            let mut h4 = self.h[4];
+
+            for m in m.chunks(16) {
+                // h += m
+                h0 += (read_u32_le(&m[0..4])) & 0x3ffffff;
+                h1 += (read_u32_le(&m[3..7]) >> 2) & 0x3ffffff;
+                h2 += (read_u32_le(&m[6..10]) >> 4) & 0x3ffffff;
+                h3 += (read_u32_le(&m[9..13]) >> 6) & 0x3ffffff;
+                h4 += (read_u32_le(&m[12..16]) >> 8) | hibit;
+
+                // h *= r
+                let d0 = (h0 as u64 * r0 as u64)
+                    + (h1 as u64 * s4 as u64)
+                    + (h2 as u64 * s3 as u64)
+                    + (h3 as u64 * s2 as u64)
+                    + (h4 as u64 * s1 as u64);
+                let mut d1 = (h0 as u64 * r1 as u64)
+                    + (h1 as u64 * r0 as u64)
+                    + (h2 as u64 * s4 as u64)
+                    + (h3 as u64 * s3 as u64)
+                    + (h4 as u64 * s2 as u64);
+                let mut d2 = (h0 as u64 * r2 as u64)
+                    + (h1 as u64 * r1 as u64)
+                    + (h2 as u64 * r0 as u64)
+                    + (h3 as u64 * s4 as u64)
+                    + (h4 as u64 * s3 as u64);
+                let mut d3 = (h0 as u64 * r3 as u64)
+                    + (h1 as u64 * r2 as u64)
+                    + (h2 as u64 * r1 as u64)
+                    + (h3 as u64 * r0 as u64)
+                    + (h4 as u64 * s4 as u64);
+                let mut d4 = (h0 as u64 * r4 as u64)
+                    + (h1 as u64 * r3 as u64)
+                    + (h2 as u64 * r2 as u64)
+                    + (h3 as u64 * r1 as u64)
+                    + (h4 as u64 * r0 as u64);
+
+                // (partial) h %= p
+                let mut c: u32;
+                c = (d0 >> 26) as u32;
+                h0 = d0 as u32 & 0x3ffffff;
+                d1 += c as u64;
+                c = (d1 >> 26) as u32;
+                h1 = d1 as u32 & 0x3ffffff;
+                d2 += c as u64;
+                c = (d2 >> 26) as u32;
+                h2 = d2 as u32 & 0x3ffffff;
+                d3 += c as u64;
+                c = (d3 >> 26) as u32;
+                h3 = d3 as u32 & 0x3ffffff;
+                d4 += c as u64;
+                c = (d4 >> 26) as u32;
+                h4 = d4 as u32 & 0x3ffffff;
+                h0 += c * 5;
+                c = h0 >> 26;
+                h0 = h0 & 0x3ffffff;
+                h1 += c;
+            }
+
+            self.h[0] = h0;
+            self.h[1] = h1;
+            self.h[2] = h2;
+            self.h[3] = h3;
+            self.h[4] = h4;
+        }
+
+        fn finish(&mut self, output: &mut [u8]) {
+            // fully carry h
+            let mut h0 = self.h[0];
+            let mut h1 = self.h[1];
+            let mut h2 = self.h[2];
+            let mut h3 = self.h[3];
+            let mut h4 = self.h[4];
+
+            let mut c: u32;
+            c = h1 >> 26;
+            h1 = h1 & 0x3ffffff;
+            h2 += c;
+            c = h2 >> 26;
+            h2 = h2 & 0x3ffffff;
+            h3 += c;
+            c = h3 >> 26;
+            h3 = h3 & 0x3ffffff;
+            h4 += c;
+            c = h4 >> 26;
+            h4 = h4 & 0x3ffffff;
+            h0 += c * 5;
+            c = h0 >> 26;
+            h0 = h0 & 0x3ffffff;
+            h1 += c;
+
+            // compute h + -p
+            let mut g0 = h0.wrapping_add(5);
+            c = g0 >> 26;
+            g0 &= 0x3ffffff;
+            let mut g1 = h1.wrapping_add(c);
+            c = g1 >> 26;
+            g1 &= 0x3ffffff;
+            let mut g2 = h2.wrapping_add(c);
+            c = g2 >> 26;
+            g2 &= 0x3ffffff;
+            let mut g3 = h3.wrapping_add(c);
+            c = g3 >> 26;
+            g3 &= 0x3ffffff;
+            let mut g4 = h4.wrapping_add(c).wrapping_sub(1 << 26);
+
+            // select h if h < p, or h + -p if h >= p
+            let mut mask = (g4 >> (32 - 1)).wrapping_sub(1);
+            g0 &= mask;
+            g1 &= mask;
+            g2 &= mask;
+            g3 &= mask;
+            g4 &= mask;
+            mask = !mask;
+            h0 = (h0 & mask) | g0;
+            h1 = (h1 & mask) | g1;
+            h2 = (h2 & mask) | g2;
+            h3 = (h3 & mask) | g3;
+            h4 = (h4 & mask) | g4;
+
+            // h = h % (2^128)
+            h0 |= h1 << 26;
+            h1 = (h1 >> 6) | (h2 << 20);
+            h2 = (h2 >> 12) | (h3 << 14);
+            h3 = (h3 >> 18) | (h4 << 8);
+
+            // h = mac = (h + pad) % (2^128)
+            let mut f: u64;
+            f = h0 as u64 + self.pad[0] as u64;
+            h0 = f as u32;
+            f = h1 as u64 + self.pad[1] as u64 + (f >> 32);
+            h1 = f as u32;
+            f = h2 as u64 + self.pad[2] as u64 + (f >> 32);
+            h2 = f as u32;
+            f = h3 as u64 + self.pad[3] as u64 + (f >> 32);
+            h3 = f as u32;
+
+            write_u32_le(&mut output[0..4], h0);
+            write_u32_le(&mut output[4..8], h1);
+            write_u32_le(&mut output[8..12], h2);
+            write_u32_le(&mut output[12..16], h3);
+        }
+
+        fn reset(&mut self) {
+            self.h = [0u32; 5];
+        }
+    }
+}
+
+#[cfg_attr(not(target_pointer_width = "64"), allow(dead_code))]
+mod limb44 {
+    use cryptoutil::{read_u64_le, write_u64_le};
+    use super::PolyState;
+
+    const MASK44: u64 = 0xfffffffffff;
+    const MASK42: u64 = 0x3ffffffffff;
+
+    #[derive(Clone, Copy)]
+    pub struct State {
+        r: [u64; 3],
+        h: [u64; 3],
+        pad: [u64; 2],
+    }
+
+    impl PolyState for State {
+        fn new(key: &[u8]) -> State {
+            // r &= 0xffffffc0ffffffc0ffffffc0fffffff
+            let t0 = read_u64_le(&key[0..8]);
+            let t1 = read_u64_le(&key[8..16]);
+
+            State {
+                r: [
+                    t0 & 0xffc0fffffff,
+                    ((t0 >> 44) | (t1 << 20)) & 0xfffffc0ffff,
+                    (t1 >> 24) & 0x00ffffffc0f,
+                ],
+                h: [0u64; 3],
+                pad: [read_u64_le(&key[16..24]), read_u64_le(&key[24..32])],
+            }
+        }
+
+        fn blocks(&mut self, m: &[u8], hibit: bool) {
+            let hibit: u64 = if hibit { 1 << 40 } else { 0 };
+
+            let r0 = self.r[0];
+            let r1 = self.r[1];
+            let r2 = self.r[2];
+
+            let s1 = r1 * (5 << 2);
+            let s2 = r2 * (5 << 2);
+
+            let mut h0 = self.h[0];
+            let mut h1 = self.h[1];
+            let mut h2 = self.h[2];
+
+            for m in m.chunks(16) {
+                // h += m
+                let t0 = read_u64_le(&m[0..8]);
+                let t1 = read_u64_le(&m[8..16]);
+
+                h0 += t0 & MASK44;
+                h1 += ((t0 >> 44) | (t1 << 20)) & MASK44;
+                h2 += ((t1 >> 24) & MASK42) | hibit;
+
+                // h *= r
+                let d0 = (h0 as u128 * r0 as u128)
+                    + (h1 as u128 * s2 as u128)
+                    + (h2 as u128 * s1 as u128);
+                let mut d1 = (h0 as u128 * r1 as u128)
+                    + (h1 as u128 * r0 as u128)
+                    + (h2 as u128 * s2 as u128);
+                let mut d2 = (h0 as u128 * r2 as u128)
+                    + (h1 as u128 * r1 as u128)
+                    + (h2 as u128 * r0 as u128);
+
+                // (partial) h %= p
+                let mut c: u64;
+                c = (d0 >> 44) as u64;
+                h0 = d0 as u64 & MASK44;
+                d1 += c as u128;
+                c = (d1 >> 44) as u64;
+                h1 = d1 as u64 & MASK44;
+                d2 += c as u128;
+                c = (d2 >> 42) as u64;
+                h2 = d2 as u64 & MASK42;
+                h0 += c * 5;
+                c = h0 >> 44;
+                h0 = h0 & MASK44;
+                h1 += c;
+            }
+
+            self.h[0] = h0;
+            self.h[1] = h1;
+            self.h[2] = h2;
+        }
+
+        fn finish(&mut self, output: &mut [u8]) {
+            // fully carry h
+            let mut h0 = self.h[0];
+            let mut h1 = self.h[1];
+            let mut h2 = self.h[2];
+
+            let mut c: u64;
+            c = h1 >> 44;
+            h1 &= MASK44;
+            h2 += c;
+            c = h2 >> 42;
+            h2 &= MASK42;
+            h0 += c * 5;
+            c = h0 >> 44;
+            h0 &= MASK44;
+            h1 += c;
+            c = h1 >> 44;
+            h1 &= MASK44;
+            h2 += c;
+            c = h2 >> 42;
+            h2 &= MASK42;
+            h0 += c * 5;
+            c = h0 >> 44;
+            h0 &= MASK44;
+            h1 += c;
+
+            // compute h + -p
+            let mut g0 = h0 + 5;
+            c = g0 >> 44;
+            g0 &= MASK44;
+            let mut g1 = h1 + c;
+            c = g1 >> 44;
+            g1 &= MASK44;
+            let mut g2 = (h2 + c).wrapping_sub(1 << 42);
+
+            // select h if h < p, or h + -p if h >= p
+            let mut mask = (g2 >> 63).wrapping_sub(1);
+            g0 &= mask;
+            g1 &= mask;
+            g2 &= mask;
+            mask = !mask;
+            h0 = (h0 & mask) | g0;
+            h1 = (h1 & mask) | g1;
+            h2 = (h2 & mask) | g2;
+
+            // h = (h + pad) % (2^128)
+            let t0 = self.pad[0];
+            let t1 = self.pad[1];
+
+            h0 += t0 & MASK44;
+            c = h0 >> 44;
+            h0 &= MASK44;
+            h1 += (((t0 >> 44) | (t1 << 20)) & MASK44) + c;
+            c = h1 >> 44;
+            h1 &= MASK44;
+            h2 += ((t1 >> 24) & MASK42) + c;
+            h2 &= MASK42;
+
+            // mac = h % (2^128)
+            h0 = h0 | (h1 << 44);
+            h1 = (h1 >> 20) | (h2 << 24);
+
+            write_u64_le(&mut output[0..8], h0);
+            write_u64_le(&mut output[8..16], h1);
+        }
+
+        fn reset(&mut self) {
+            self.h = [0u64; 3];
+        }
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+type State = limb44::State;
+
+#[cfg(not(target_pointer_width = "64"))]
+type State = limb26::State;
+
 #[derive(Clone, Copy)]
 pub struct Poly1305 {
-    r: [u32; 5],
-    h: [u32; 5],
-    pad: [u32; 4],
+    state: State,
     leftover: usize,
     buffer: [u8; 16],
     finalized: bool,
+    mac: [u8; 16],
 }
 
 impl Poly1305 {
     pub fn new(key: &[u8]) -> Poly1305 {
         //assert!(key.len() == 32);
-        let mut poly = Poly1305 {
-            r: [0u32; 5],
-            h: [0u32; 5],
-            pad: [0u32; 4],
+        Poly1305 {
+            state: State::new(key),
             leftover: 0,
             buffer: [0u8; 16],
             finalized: false,
-        };
-
-        // r &= 0xffffffc0ffffffc0ffffffc0fffffff
-        poly.r[0] = (read_u32_le(&key[0..4])) & 0x3ffffff;
-        poly.r[1] = (read_u32_le(&key[3..7]) >> 2) & 0x3ffff03;
-        poly.r[2] = (read_u32_le(&key[6..10]) >> 4) & 0x3ffc0ff;
-        poly.r[3] = (read_u32_le(&key[9..13]) >> 6) & 0x3f03fff;
-        poly.r[4] = (read_u32_le(&key[12..16]) >> 8) & 0x00fffff;
-
-        poly.pad[0] = read_u32_le(&key[16..20]);
-        poly.pad[1] = read_u32_le(&key[20..24]);
-        poly.pad[2] = read_u32_le(&key[24..28]);
-        poly.pad[3] = read_u32_le(&key[28..32]);
-
-        poly
-    }
-
-    fn block(&mut self, m: &[u8]) {
-        let hibit: u32 = if self.finalized { 0 } else { 1 << 24 };
-
-        let r0 = self.r[0];
-        let r1 = self.r[1];
-        let r2 = self.r[2];
-        let r3 = self.r[3];
-        let r4 = self.r[4];
-
-        let s1 = r1 * 5;
-        let s2 = r2 * 5;
-        let s3 = r3 * 5;
-        let s4 = r4 * 5;
-
-        let mut h0 = self.h[0];
-        let mut h1 = self.h[1];
-        let mut h2 = self.h[2];
-        let mut h3 = self.h[3];
-        let mut h4 = self.h[4];
-
-        // h += m
-        h0 += (read_u32_le(&m[0..4])) & 0x3ffffff;
-        h1 += (read_u32_le(&m[3..7]) >> 2) & 0x3ffffff;
-        h2 += (read_u32_le(&m[6..10]) >> 4) & 0x3ffffff;
-        h3 += (read_u32_le(&m[9..13]) >> 6) & 0x3ffffff;
-        h4 += (read_u32_le(&m[12..16]) >> 8) | hibit;
-
-        // h *= r
-        let d0 = (h0 as u64 * r0 as u64)
-            + (h1 as u64 * s4 as u64)
-            + (h2 as u64 * s3 as u64)
-            + (h3 as u64 * s2 as u64)
-            + (h4 as u64 * s1 as u64);
-        let mut d1 = (h0 as u64 * r1 as u64)
-            + (h1 as u64 * r0 as u64)
-            + (h2 as u64 * s4 as u64)
-            + (h3 as u64 * s3 as u64)
-            + (h4 as u64 * s2 as u64);
-        let mut d2 = (h0 as u64 * r2 as u64)
-            + (h1 as u64 * r1 as u64)
-            + (h2 as u64 * r0 as u64)
-            + (h3 as u64 * s4 as u64)
-            + (h4 as u64 * s3 as u64);
-        let mut d3 = (h0 as u64 * r3 as u64)
-            + (h1 as u64 * r2 as u64)
-            + (h2 as u64 * r1 as u64)
-            + (h3 as u64 * r0 as u64)
-            + (h4 as u64 * s4 as u64);
-        let mut d4 = (h0 as u64 * r4 as u64)
-            + (h1 as u64 * r3 as u64)
-            + (h2 as u64 * r2 as u64)
-            + (h3 as u64 * r1 as u64)
-            + (h4 as u64 * r0 as u64);
-
-        // (partial) h %= p
-        let mut c: u32;
-        c = (d0 >> 26) as u32;
-        h0 = d0 as u32 & 0x3ffffff;
-        d1 += c as u64;
-        c = (d1 >> 26) as u32;
-        h1 = d1 as u32 & 0x3ffffff;
-        d2 += c as u64;
-        c = (d2 >> 26) as u32;
-        h2 = d2 as u32 & 0x3ffffff;
-        d3 += c as u64;
-        c = (d3 >> 26) as u32;
-        h3 = d3 as u32 & 0x3ffffff;
-        d4 += c as u64;
-        c = (d4 >> 26) as u32;
-        h4 = d4 as u32 & 0x3ffffff;
-        h0 += c * 5;
-        c = h0 >> 26;
-        h0 = h0 & 0x3ffffff;
-        h1 += c;
-
-        self.h[0] = h0;
-        self.h[1] = h1;
-        self.h[2] = h2;
-        self.h[3] = h3;
-        self.h[4] = h4;
+            mac: [0u8; 16],
+        }
     }
 
     fn finish(&mut self) {
@@ -139,85 +426,11 @@ impl Poly1305 {
             for i in self.leftover + 1..16 {
                 self.buffer[i] = 0;
             }
-            self.finalized = true;
             let tmp = self.buffer;
-            self.block(&tmp);
+            self.state.blocks(&tmp, false);
         }
-
-        // fully carry h
-        let mut h0 = self.h[0];
-        let mut h1 = self.h[1];
-        let mut h2 = self.h[2];
-        let mut h3 = self.h[3];
-        let mut h4 = self.h[4];
-
-        let mut c: u32;
-        c = h1 >> 26;
-        h1 = h1 & 0x3ffffff;
-        h2 += c;
-        c = h2 >> 26;
-        h2 = h2 & 0x3ffffff;
-        h3 += c;
-        c = h3 >> 26;
-        h3 = h3 & 0x3ffffff;
-        h4 += c;
-        c = h4 >> 26;
-        h4 = h4 & 0x3ffffff;
-        h0 += c * 5;
-        c = h0 >> 26;
-        h0 = h0 & 0x3ffffff;
-        h1 += c;
-
-        // compute h + -p
-        let mut g0 = h0.wrapping_add(5);
-        c = g0 >> 26;
-        g0 &= 0x3ffffff;
-        let mut g1 = h1.wrapping_add(c);
-        c = g1 >> 26;
-        g1 &= 0x3ffffff;
-        let mut g2 = h2.wrapping_add(c);
-        c = g2 >> 26;
-        g2 &= 0x3ffffff;
-        let mut g3 = h3.wrapping_add(c);
-        c = g3 >> 26;
-        g3 &= 0x3ffffff;
-        let mut g4 = h4.wrapping_add(c).wrapping_sub(1 << 26);
-
-        // select h if h < p, or h + -p if h >= p
-        let mut mask = (g4 >> (32 - 1)).wrapping_sub(1);
-        g0 &= mask;
-        g1 &= mask;
-        g2 &= mask;
-        g3 &= mask;
-        g4 &= mask;
-        mask = !mask;
-        h0 = (h0 & mask) | g0;
-        h1 = (h1 & mask) | g1;
-        h2 = (h2 & mask) | g2;
-        h3 = (h3 & mask) | g3;
-        h4 = (h4 & mask) | g4;
-
-        // h = h % (2^128)
-        h0 = ((h0) | (h1 << 26)) & 0xffffffff;
-        h1 = ((h1 >> 6) | (h2 << 20)) & 0xffffffff;
-        h2 = ((h2 >> 12) | (h3 << 14)) & 0xffffffff;
-        h3 = ((h3 >> 18) | (h4 << 8)) & 0xffffffff;
-
-        // h = mac = (h + pad) % (2^128)
-        let mut f: u64;
-        f = h0 as u64 + self.pad[0] as u64;
-        h0 = f as u32;
-        f = h1 as u64 + self.pad[1] as u64 + (f >> 32);
-        h1 = f as u32;
-        f = h2 as u64 + self.pad[2] as u64 + (f >> 32);
-        h2 = f as u32;
-        f = h3 as u64 + self.pad[3] as u64 + (f >> 32);
-        h3 = f as u32;
-
-        self.h[0] = h0;
-        self.h[1] = h1;
-        self.h[2] = h2;
-        self.h[3] = h3;
+        self.finalized = true;
+        self.state.finish(&mut self.mac);
     }
 }
 
@@ -238,16 +451,17 @@ impl Mac for Poly1305 {
                 return;
             }
 
-            // self.block(self.buffer[..]);
+            // self.blocks(self.buffer[..]);
             let tmp = self.buffer;
-            self.block(&tmp);
+            self.state.blocks(&tmp, true);
 
             self.leftover = 0;
         }
 
-        while m.len() >= 16 {
-            self.block(&m[0..16]);
-            m = &m[16..];
+        let full = m.len() & !15;
+        if full > 0 {
+            self.state.blocks(&m[..full], true);
+            m = &m[full..];
         }
 
         for i in 0..m.len() {
@@ -257,7 +471,7 @@ impl Mac for Poly1305 {
     }
 
     fn reset(&mut self) {
-        self.h = [0u32; 5];
+        self.state.reset();
         self.leftover = 0;
         self.finalized = false;
     }
@@ -273,10 +487,7 @@ impl Mac for Poly1305 {
         if !self.finalized {
             self.finish();
         }
-        write_u32_le(&mut output[0..4], self.h[0]);
-        write_u32_le(&mut output[4..8], self.h[1]);
-        write_u32_le(&mut output[8..12], self.h[2]);
-        write_u32_le(&mut output[12..16], self.h[3]);
+        output[..16].copy_from_slice(&self.mac);
     }
 
     fn output_bytes(&self) -> usize {
@@ -288,8 +499,9 @@ impl Mac for Poly1305 {
 mod test {
     use sr_std::iter::repeat;
 
+    use cryptoutil::test::XorShift;
     use mac::Mac;
-    use poly1305::Poly1305;
+    use poly1305::{limb26, limb44, Poly1305, PolyState};
 
     fn poly1305(key: &[u8], msg: &[u8], mac: &mut [u8]) {
         let mut poly = Poly1305::new(key);
@@ -409,6 +621,218 @@ mod test {
         poly1305(key, msg, &mut mac);
         assert_eq!(&mac[..], &expected[..]);
     }
+
+    fn tag_with<S: PolyState>(key: &[u8], msg: &[u8]) -> [u8; 16] {
+        let mut state = S::new(key);
+        let full = msg.len() & !15;
+        state.blocks(&msg[..full], true);
+        if full < msg.len() {
+            let mut last = [0u8; 16];
+            let rest = &msg[full..];
+            last[..rest.len()].copy_from_slice(rest);
+            last[rest.len()] = 1;
+            state.blocks(&last, false);
+        }
+        let mut mac = [0u8; 16];
+        state.finish(&mut mac);
+        mac
+    }
+
+    #[test]
+    fn test_limb_layouts_rfc8439() {
+        // RFC 8439 section 2.5.2
+        let key = [
+            0x85, 0xd6, 0xbe, 0x78, 0x57, 0x55, 0x6d, 0x33, 0x7f, 0x44, 0x52, 0xfe, 0x42, 0xd5,
+            0x06, 0xa8, 0x01, 0x03, 0x80, 0x8a, 0xfb, 0x0d, 0xb2, 0xfd, 0x4a, 0xbf, 0xf6, 0xaf,
+            0x41, 0x49, 0xf5, 0x1b,
+        ];
+        let msg = b"Cryptographic Forum Research Group";
+        let expected = [
+            0xa8, 0x06, 0x1d, 0xc1, 0x30, 0x51, 0x36, 0xc6, 0xc2, 0x2b, 0x8b, 0xaf, 0x0c, 0x01,
+            0x27, 0xa9,
+        ];
+
+        assert_eq!(tag_with::<limb26::State>(&key, msg), expected);
+        assert_eq!(tag_with::<limb44::State>(&key, msg), expected);
+
+        let mut mac = [0u8; 16];
+        poly1305(&key, msg, &mut mac);
+        assert_eq!(mac, expected);
+    }
+
+    #[test]
+    fn test_unaligned_chunks_rfc8439() {
+        // RFC 8439 section 2.5.2, fed in chunks that straddle block boundaries.
+        let key = [
+            0x85, 0xd6, 0xbe, 0x78, 0x57, 0x55, 0x6d, 0x33, 0x7f, 0x44, 0x52, 0xfe, 0x42, 0xd5,
+            0x06, 0xa8, 0x01, 0x03, 0x80, 0x8a, 0xfb, 0x0d, 0xb2, 0xfd, 0x4a, 0xbf, 0xf6, 0xaf,
+            0x41, 0x49, 0xf5, 0x1b,
+        ];
+        let msg = b"Cryptographic Forum Research Group";
+        let expected = [
+            0xa8, 0x06, 0x1d, 0xc1, 0x30, 0x51, 0x36, 0xc6, 0xc2, 0x2b, 0x8b, 0xaf, 0x0c, 0x01,
+            0x27, 0xa9,
+        ];
+
+        for &chunk_size in [1usize, 7, 16, 33].iter() {
+            let mut poly = Poly1305::new(&key);
+            for chunk in msg.chunks(chunk_size) {
+                poly.input(chunk);
+            }
+            let mut mac = [0u8; 16];
+            poly.raw_result(&mut mac);
+            assert_eq!(mac, expected, "chunk size {}", chunk_size);
+        }
+
+        // Empty inputs in between change nothing.
+        let mut poly = Poly1305::new(&key);
+        poly.input(&msg[..5]);
+        poly.input(&[]);
+        poly.input(&msg[5..]);
+        poly.input(&[]);
+        let mut mac = [0u8; 16];
+        poly.raw_result(&mut mac);
+        assert_eq!(mac, expected);
+    }
+
+    #[test]
+    fn test_rfc8439_appendix_a3() {
+        // RFC 8439 appendix A.3, test vectors #1 to #11. #5 to #11 are chosen to exercise carry
+        // propagation and the final reduction modulo 2^130 - 5.
+        let ietf = &b"Any submission to the IETF intended by the Contributor for publication as \
+                      all or part of an IETF Internet-Draft or RFC and any statement made within \
+                      the context of an IETF activity is considered an \"IETF Contribution\". \
+                      Such statements include oral statements in IETF sessions, as well as \
+                      written and electronic communications made at any time or place, which \
+                      are addressed to"[..];
+        let jabberwocky = &b"'Twas brillig, and the slithy toves\nDid gyre and gimble in the \
+                             wabe:\nAll mimsy were the borogoves,\nAnd the mome raths \
+                             outgrabe."[..];
+        let h = |s: &str| hex::decode(s).unwrap();
+        let r1 = "01000000000000000000000000000000";
+        let r2 = "02000000000000000000000000000000";
+        let r10 = "01000000000000000400000000000000";
+        let zero = "00000000000000000000000000000000";
+        let ones = "ffffffffffffffffffffffffffffffff";
+        let msg10 = h(
+            "e33594d7505e43b900000000000000003394d7505e4379cd0100000000000000\
+             0000000000000000000000000000000001000000000000000000000000000000",
+        );
+        let tests: Vec<(Vec<u8>, Vec<u8>, &str)> = vec![
+            (h(&[zero, zero].concat()), vec![0; 64], zero),
+            (
+                h(&[zero, "36e5f6b5c5e06070f0efca96227a863e"].concat()),
+                ietf.to_vec(),
+                "36e5f6b5c5e06070f0efca96227a863e",
+            ),
+            (
+                h(&["36e5f6b5c5e06070f0efca96227a863e", zero].concat()),
+                ietf.to_vec(),
+                "f3477e7cd95417af89a6b8794c310cf0",
+            ),
+            (
+                h("1c9240a5eb55d38af333888604f6b5f0473917c1402b80099dca5cbc207075c0"),
+                jabberwocky.to_vec(),
+                "4541669a7eaaee61e708dc7cbcc5eb62",
+            ),
+            (h(&[r2, zero].concat()), h(ones), "03000000000000000000000000000000"),
+            (h(&[r2, ones].concat()), h(r2), "03000000000000000000000000000000"),
+            (
+                h(&[r1, zero].concat()),
+                h(&[ones, "f0ffffffffffffffffffffffffffffff", "11000000000000000000000000000000"]
+                    .concat()),
+                "05000000000000000000000000000000",
+            ),
+            (
+                h(&[r1, zero].concat()),
+                h(&[ones, "fbfefefefefefefefefefefefefefefe", "01010101010101010101010101010101"]
+                    .concat()),
+                zero,
+            ),
+            (
+                h(&[r2, zero].concat()),
+                h("fdffffffffffffffffffffffffffffff"),
+                "faffffffffffffffffffffffffffffff",
+            ),
+            (h(&[r10, zero].concat()), msg10.clone(), "14000000000000005500000000000000"),
+            (h(&[r10, zero].concat()), msg10[..48].to_vec(), "13000000000000000000000000000000"),
+        ];
+
+        for (i, &(ref key, ref msg, tag)) in tests.iter().enumerate() {
+            let expected = h(tag);
+            let mut mac = [0u8; 16];
+            poly1305(key, msg, &mut mac);
+            assert_eq!(&mac[..], &expected[..], "test vector #{}", i + 1);
+            assert_eq!(&tag_with::<limb26::State>(key, msg)[..], &expected[..]);
+            assert_eq!(&tag_with::<limb44::State>(key, msg)[..], &expected[..]);
+        }
+    }
+
+    #[test]
+    fn test_limb_layouts_agree() {
+        let mut rng = XorShift(0x5eed);
+        let mut key = [0u8; 32];
+        let mut msg = [0u8; 300];
+        for len in 0..300 {
+            for b in key.iter_mut().chain(msg.iter_mut()) {
+                *b = rng.next_below(256) as u8;
+            }
+            // Push the accumulator towards 2^130 - 5 to exercise the final reduction.
+            if len % 7 == 0 {
+                for b in msg.iter_mut() {
+                    *b = 0xff;
+                }
+            }
+            assert_eq!(
+                tag_with::<limb26::State>(&key, &msg[..len]),
+                tag_with::<limb44::State>(&key, &msg[..len])
+            );
+        }
+    }
+
+    #[test]
+    fn test_lengths_around_block_boundary() {
+        // Reference tags computed with a straightforward big integer implementation.
+        let key: Vec<u8> = (1..33).collect();
+        let tests: [(usize, [u8; 16]); 5] = [
+            (0, [
+                0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e,
+                0x1f, 0x20,
+            ]),
+            (15, [
+                0xa2, 0x10, 0x31, 0xba, 0x84, 0x6e, 0xfe, 0xe9, 0x09, 0x3c, 0x07, 0x21, 0x62, 0xa8,
+                0x06, 0x37,
+            ]),
+            (16, [
+                0x56, 0xbf, 0x1a, 0x26, 0x22, 0x09, 0xd4, 0x41, 0x93, 0xc2, 0xc8, 0x64, 0xd7, 0x1a,
+                0x28, 0xfe,
+            ]),
+            (17, [
+                0xb2, 0x9e, 0x52, 0x23, 0x55, 0x1b, 0xe5, 0xb3, 0x4d, 0x57, 0x74, 0x8f, 0xfb, 0xdb,
+                0x15, 0xd5,
+            ]),
+            (1000, [
+                0xcc, 0x49, 0x32, 0x01, 0xce, 0x8a, 0x84, 0xa7, 0x61, 0xbd, 0x4d, 0xdb, 0x4b, 0x65,
+                0x65, 0xcd,
+            ]),
+        ];
+
+        for &(len, ref expected) in tests.iter() {
+            let msg: Vec<u8> = (0..len).map(|i| (i * 13 + 5) as u8).collect();
+
+            let mut mac = [0u8; 16];
+            poly1305(&key[..], &msg[..], &mut mac);
+            assert_eq!(&mac[..], &expected[..]);
+
+            // Byte at a time input only ever goes through the partial block path.
+            let mut poly = Poly1305::new(&key[..]);
+            for b in msg.iter() {
+                poly.input(&[*b]);
+            }
+            poly.raw_result(&mut mac);
+            assert_eq!(&mac[..], &expected[..]);
+        }
+    }
 }
 
 #[cfg(all(test, feature = "with-bench"))]
@@ -455,4 +879,19 @@ mod bench {
         });
         bh.bytes = bytes.len() as u64;
     }
+
+    #[bench]
+    pub fn poly1305_64k_unaligned(bh: &mut Bencher) {
+        let mut mac = [0u8; 16];
+        let key = [0u8; 32];
+        let bytes = [1u8; 65536];
+        bh.iter(|| {
+            let mut poly = Poly1305::new(&key);
+            for chunk in bytes.chunks(1000) {
+                poly.input(chunk);
+            }
+            poly.raw_result(&mut mac);
+        });
+        bh.bytes = bytes.len() as u64;
+    }
 }
@@ -30,24 +30,33 @@ impl Clone for Rc4 {
 
 impl Rc4 {
     pub fn new(key: &[u8]) -> Rc4 {
-        //assert!(key.len() >= 1 && key.len() <= 256);
         let mut rc4 = Rc4 {
             i: 0,
             j: 0,
             state: [0; 256],
         };
-        for (i, x) in rc4.state.iter_mut().enumerate() {
+        rc4.reset_with_key(key);
+        rc4
+    }
+
+    /// Re-run the key schedule in place. The instance afterwards behaves exactly like one
+    /// freshly created with `Rc4::new(key)`.
+    pub fn reset_with_key(&mut self, key: &[u8]) {
+        //assert!(key.len() >= 1 && key.len() <= 256);
+        self.i = 0;
+        self.j = 0;
+        for (i, x) in self.state.iter_mut().enumerate() {
             *x = i as u8;
         }
         let mut j: u8 = 0;
         for i in 0..256 {
             j = j
-                .wrapping_add(rc4.state[i])
+                .wrapping_add(self.state[i])
                 .wrapping_add(key[i % key.len()]);
-            rc4.state.swap(i, j as usize);
+            self.state.swap(i, j as usize);
         }
-        rc4
     }
+
     fn next(&mut self) -> u8 {
         self.i = self.i.wrapping_add(1);
         self.j = self.j.wrapping_add(self.state[self.i as usize]);
@@ -93,6 +102,7 @@ impl Decryptor for Rc4 {
 mod test {
     use sr_std::iter::repeat;
 
+    use cryptoutil::test::test_stream_cipher;
     use rc4::Rc4;
     use symmetriccipher::SynchronousStreamCipher;
 
@@ -132,9 +142,34 @@ mod test {
             let mut rc4 = Rc4::new(t.key.as_bytes());
             let mut result: Vec<u8> = repeat(0).take(t.output.len()).collect();
             rc4.process(t.input.as_bytes(), &mut result);
-            //assert!(result == t.output);
+            assert!(result == t.output);
+        }
+    }
+
+    #[test]
+    fn test_reset_with_key() {
+        let tests = tests();
+        let mut rc4 = Rc4::new(b"some other key");
+        let mut scratch = [0u8; 37];
+        rc4.process(&[0u8; 37], &mut scratch);
+        for t in tests.iter() {
+            rc4.reset_with_key(t.key.as_bytes());
+            let mut result: Vec<u8> = repeat(0).take(t.output.len()).collect();
+            rc4.process(t.input.as_bytes(), &mut result);
+            assert_eq!(result, t.output);
         }
     }
+
+    #[test]
+    fn test_harness() {
+        // RFC 6229, 40 bit key, offset 0.
+        let keystream = [
+            0xb2, 0x39, 0x63, 0x05, 0xf0, 0x3d, 0xc0, 0x27, 0xcc, 0xc3, 0x52, 0x4a, 0x0a, 0x11,
+            0x18, 0xa8, 0x69, 0x82, 0x94, 0x4f, 0x18, 0xfc, 0x82, 0xd5, 0x89, 0xc4, 0x03, 0xa4,
+            0x7a, 0x0d, 0x09, 0x19,
+        ];
+        test_stream_cipher(|| Rc4::new(&[1, 2, 3, 4, 5]), &keystream);
+    }
 }
 
 #[cfg(all(test, feature = "with-bench"))]
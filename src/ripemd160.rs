@@ -26,6 +26,7 @@ use cryptoutil::{
     add_bytes_to_bits, read_u32v_le, write_u32_le, FixedBuffer, FixedBuffer64, StandardPadding,
 };
 use digest::Digest;
+use sha2::Sha256;
 use sr_std::marker::*;
 use sr_std::prelude::*;
 
@@ -416,11 +417,73 @@ impl Digest for Ripemd160 {
     }
 }
 
+/**
+ * Compute RIPEMD-160(SHA-256(data)), the "hash160" used for Bitcoin style addresses.
+ */
+pub fn hash160(data: &[u8]) -> [u8; 20] {
+    let mut h = Hash160::new();
+    h.input(data);
+    let mut out = [0u8; 20];
+    h.result(&mut out);
+    out
+}
+
+/// Streaming form of `hash160`. Input is fed to SHA-256 as it arrives and RIPEMD-160 is only run
+/// over the SHA-256 output when the result is requested.
+#[derive(Clone, Copy)]
+pub struct Hash160 {
+    sha256: Sha256,
+}
+
+impl Hash160 {
+    pub fn new() -> Hash160 {
+        Hash160 {
+            sha256: Sha256::new(),
+        }
+    }
+}
+
+impl Default for Hash160 {
+    fn default() -> Hash160 {
+        Hash160::new()
+    }
+}
+
+impl Digest for Hash160 {
+    fn reset(&mut self) {
+        self.sha256.reset();
+    }
+
+    fn input(&mut self, msg: &[u8]) {
+        self.sha256.input(msg);
+    }
+
+    /**
+     * Note: `out` must be at least 20 bytes (160 bits)
+     */
+    fn result(&mut self, out: &mut [u8]) {
+        let mut inner = [0u8; 32];
+        self.sha256.result(&mut inner);
+        let mut ripemd = Ripemd160::new();
+        ripemd.input(&inner);
+        ripemd.result(out);
+    }
+
+    fn output_bits(&self) -> usize {
+        160
+    }
+
+    fn block_size(&self) -> usize {
+        self.sha256.block_size()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use cryptoutil::test::test_digest_1million_random;
     use digest::Digest;
-    use ripemd160::Ripemd160;
+    use ripemd160::{hash160, Hash160, Ripemd160};
+    use sr_std::prelude::*;
 
     #[derive(Clone)]
     struct Test {
@@ -510,6 +573,60 @@ mod tests {
         let mut sh = Ripemd160::new();
         test_digest_1million_random(&mut sh, 64, "52783243c1697bdbe16d37f97f68f08325dc1528");
     }
+
+    #[test]
+    fn test_hash160() {
+        // Checked with Python's hashlib.
+        assert_eq!(
+            hash160(b""),
+            [
+                0xb4, 0x72, 0xa2, 0x66, 0xd0, 0xbd, 0x89, 0xc1, 0x37, 0x06, 0xa4, 0x13, 0x2c, 0xcf,
+                0xb1, 0x6f, 0x7c, 0x3b, 0x9f, 0xcb,
+            ]
+        );
+        assert_eq!(
+            hash160(b"abc"),
+            [
+                0xbb, 0x1b, 0xe9, 0x8c, 0x14, 0x24, 0x44, 0xd7, 0xa5, 0x6a, 0xa3, 0x98, 0x1c, 0x39,
+                0x42, 0xa9, 0x78, 0xe4, 0xdc, 0x33,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hash160_streaming() {
+        let data: Vec<u8> = (0..5000u32).map(|i| (i * 31 + i / 7) as u8).collect();
+        let expected = hash160(&data[..]);
+
+        let mut h = Hash160::new();
+        for chunk in data.chunks(333) {
+            h.input(chunk);
+        }
+        let mut out = [0u8; 20];
+        h.result(&mut out);
+        assert_eq!(out, expected);
+
+        h.reset();
+        h.input(&data[..]);
+        h.result(&mut out);
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_length_near_limit() {
+        let mut sh = Ripemd160::new();
+        sh.length_bits = u64::max_value() - 15;
+        sh.input(b"a");
+        assert_eq!(sh.length_bits, u64::max_value() - 7);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_length_overflow() {
+        let mut sh = Ripemd160::new();
+        sh.length_bits = u64::max_value() - 15;
+        sh.input(b"ab");
+    }
 }
 
 #[cfg(all(test, feature = "with-bench"))]
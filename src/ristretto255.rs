@@ -0,0 +1,492 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+ * The ristretto255 prime-order group (RFC 9496), built on the curve25519 field arithmetic.
+ *
+ * Ristretto encodes the points of edwards25519 modulo its cofactor, so every valid 32-byte
+ * encoding names exactly one element of a group of prime order
+ * l = 2^252 + 27742317777372353535851937790883648493. Protocols such as VOPRFs and
+ * zero-knowledge proofs can use it without the small-subgroup checks edwards25519 needs.
+ *
+ * Decoding, encoding, addition and scalar multiplication run in constant time with respect to
+ * the point and scalar values.
+ */
+
+use sr_std::cmp::{Eq, PartialEq};
+use sr_std::ops::{Add, Neg, Sub};
+
+use curve25519::Fe;
+use util::fixed_time_eq;
+
+static D: [u8; 32] = [
+    0xa3, 0x78, 0x59, 0x13, 0xca, 0x4d, 0xeb, 0x75,
+    0xab, 0xd8, 0x41, 0x41, 0x4d, 0x0a, 0x70, 0x00,
+    0x98, 0xe8, 0x79, 0x77, 0x79, 0x40, 0xc7, 0x8c,
+    0x73, 0xfe, 0x6f, 0x2b, 0xee, 0x6c, 0x03, 0x52,
+];
+static SQRT_M1: [u8; 32] = [
+    0xb0, 0xa0, 0x0e, 0x4a, 0x27, 0x1b, 0xee, 0xc4,
+    0x78, 0xe4, 0x2f, 0xad, 0x06, 0x18, 0x43, 0x2f,
+    0xa7, 0xd7, 0xfb, 0x3d, 0x99, 0x00, 0x4d, 0x2b,
+    0x0b, 0xdf, 0xc1, 0x4f, 0x80, 0x24, 0x83, 0x2b,
+];
+static SQRT_AD_MINUS_ONE: [u8; 32] = [
+    0x1b, 0x2e, 0x7b, 0x49, 0xa0, 0xf6, 0x97, 0x7e,
+    0xbd, 0x54, 0x78, 0x1b, 0x0c, 0x8e, 0x9d, 0xaf,
+    0xfd, 0xd1, 0xf5, 0x31, 0xc9, 0xfc, 0x3c, 0x0f,
+    0xac, 0x48, 0x83, 0x2b, 0xbf, 0x31, 0x69, 0x37,
+];
+static INVSQRT_A_MINUS_D: [u8; 32] = [
+    0xea, 0x40, 0x5d, 0x80, 0xaa, 0xfd, 0xc8, 0x99,
+    0xbe, 0x72, 0x41, 0x5a, 0x17, 0x16, 0x2f, 0x9d,
+    0x40, 0xd8, 0x01, 0xfe, 0x91, 0x7b, 0xc2, 0x16,
+    0xa2, 0xfc, 0xaf, 0xcf, 0x05, 0x89, 0x6c, 0x78,
+];
+static ONE_MINUS_D_SQ: [u8; 32] = [
+    0x76, 0xc1, 0x5f, 0x94, 0xc1, 0x09, 0x7c, 0xe2,
+    0x0f, 0x35, 0x5e, 0xcd, 0x38, 0xa1, 0x81, 0x2c,
+    0xe4, 0xdf, 0x70, 0xbe, 0xdd, 0xab, 0x94, 0x99,
+    0xd7, 0xe0, 0xb3, 0xb2, 0xa8, 0x72, 0x90, 0x02,
+];
+static D_MINUS_ONE_SQ: [u8; 32] = [
+    0x20, 0x4d, 0xed, 0x44, 0xaa, 0x5a, 0xad, 0x31,
+    0x99, 0x19, 0x1e, 0xb0, 0x2c, 0x4a, 0x9e, 0xd2,
+    0xeb, 0x4e, 0x9b, 0x52, 0x2f, 0xd3, 0xdc, 0x4c,
+    0x41, 0x22, 0x6c, 0xf6, 0x7a, 0xb3, 0x68, 0x59,
+];
+static BASE: [u8; 32] = [
+    0xe2, 0xf2, 0xae, 0x0a, 0x6a, 0xbc, 0x4e, 0x71,
+    0xa8, 0x84, 0xa9, 0x61, 0xc5, 0x00, 0x51, 0x5f,
+    0x58, 0xe3, 0x0b, 0x6a, 0xa5, 0x82, 0xdd, 0x8d,
+    0xb6, 0xa6, 0x59, 0x45, 0xe0, 0x8d, 0x2d, 0x76,
+];
+
+fn fe_zero() -> Fe {
+    Fe([0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+}
+
+fn fe_one() -> Fe {
+    Fe([1, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+}
+
+fn fe_eq(f: &Fe, g: &Fe) -> bool {
+    fixed_time_eq(&f.to_bytes(), &g.to_bytes())
+}
+
+fn fe_select(f: &Fe, g: &Fe, choose_g: bool) -> Fe {
+    let mut r = *f;
+    r.maybe_set(g, choose_g as i32);
+    r
+}
+
+fn fe_abs(f: &Fe) -> Fe {
+    let mut r = *f;
+    r.conditional_negate(f.is_negative() as u8);
+    r
+}
+
+/*
+Returns (was_square, r) where r is the non-negative square root of u/v if it exists, or of
+sqrt(-1)*u/v otherwise. was_square is false when v is zero and u is not.
+*/
+fn sqrt_ratio_m1(u: &Fe, v: &Fe) -> (bool, Fe) {
+    let sqrt_m1 = Fe::from_bytes(&SQRT_M1);
+    let v3 = v.square() * *v;
+    let v7 = v3.square() * *v;
+    let r = (*u * v3) * (*u * v7).pow25523();
+    let check = *v * r.square();
+
+    let u_neg = u.neg();
+    let correct = fe_eq(&check, u);
+    let flipped = fe_eq(&check, &u_neg);
+    let flipped_i = fe_eq(&check, &(u_neg * sqrt_m1));
+
+    let r = fe_select(&r, &(r * sqrt_m1), flipped | flipped_i);
+    (correct | flipped, fe_abs(&r))
+}
+
+/**
+ * An element of the ristretto255 group, held as an edwards25519 point in extended coordinates.
+ */
+#[derive(Clone, Copy)]
+pub struct RistrettoPoint {
+    x: Fe,
+    y: Fe,
+    z: Fe,
+    t: Fe,
+}
+
+impl RistrettoPoint {
+    /**
+     * The identity element, which encodes as 32 zero bytes.
+     */
+    pub fn identity() -> RistrettoPoint {
+        RistrettoPoint {
+            x: fe_zero(),
+            y: fe_one(),
+            z: fe_one(),
+            t: fe_zero(),
+        }
+    }
+
+    /**
+     * The canonical generator of the group.
+     */
+    pub fn base() -> RistrettoPoint {
+        RistrettoPoint::decode(&BASE).expect("the ristretto255 base point encoding is valid")
+    }
+
+    /**
+     * Decodes a canonical 32-byte encoding. Returns None for non-canonical field elements,
+     * negative values of s, and encodings that do not correspond to a group element.
+     */
+    pub fn decode(bytes: &[u8; 32]) -> Option<RistrettoPoint> {
+        let s = Fe::from_bytes(bytes);
+        let canonical = fixed_time_eq(&s.to_bytes(), bytes);
+        let s_negative = s.is_negative();
+
+        let one = fe_one();
+        let ss = s.square();
+        let u1 = one - ss;
+        let u2 = one + ss;
+        let u2_sqr = u2.square();
+        let v = (Fe::from_bytes(&D) * u1.square()).neg() - u2_sqr;
+
+        let (was_square, invsqrt) = sqrt_ratio_m1(&one, &(v * u2_sqr));
+        let den_x = invsqrt * u2;
+        let den_y = invsqrt * den_x * v;
+
+        let x = fe_abs(&((s + s) * den_x));
+        let y = u1 * den_y;
+        let t = x * y;
+
+        let y_zero = fe_eq(&y, &fe_zero());
+        if !canonical | s_negative | !was_square | t.is_negative() | y_zero {
+            None
+        } else {
+            Some(RistrettoPoint { x: x, y: y, z: one, t: t })
+        }
+    }
+
+    /**
+     * Encodes the point to its canonical 32-byte representation.
+     */
+    pub fn encode(&self) -> [u8; 32] {
+        let sqrt_m1 = Fe::from_bytes(&SQRT_M1);
+        let u1 = (self.z + self.y) * (self.z - self.y);
+        let u2 = self.x * self.y;
+
+        let (_, invsqrt) = sqrt_ratio_m1(&fe_one(), &(u1 * u2.square()));
+        let den1 = invsqrt * u1;
+        let den2 = invsqrt * u2;
+        let z_inv = den1 * den2 * self.t;
+
+        let ix = self.x * sqrt_m1;
+        let iy = self.y * sqrt_m1;
+        let enchanted_denominator = den1 * Fe::from_bytes(&INVSQRT_A_MINUS_D);
+
+        let rotate = (self.t * z_inv).is_negative();
+        let x = fe_select(&self.x, &iy, rotate);
+        let mut y = fe_select(&self.y, &ix, rotate);
+        let den_inv = fe_select(&den2, &enchanted_denominator, rotate);
+
+        y.conditional_negate((x * z_inv).is_negative() as u8);
+        fe_abs(&(den_inv * (self.z - y))).to_bytes()
+    }
+
+    /**
+     * Maps 64 uniformly random bytes, typically the output of a hash, to a group element.
+     * The result is indistinguishable from a uniformly chosen element.
+     */
+    pub fn from_uniform_bytes(bytes: &[u8; 64]) -> RistrettoPoint {
+        let p1 = RistrettoPoint::elligator_map(&Fe::from_bytes(&bytes[..32]));
+        let p2 = RistrettoPoint::elligator_map(&Fe::from_bytes(&bytes[32..]));
+        p1 + p2
+    }
+
+    fn elligator_map(t: &Fe) -> RistrettoPoint {
+        let d = Fe::from_bytes(&D);
+        let one = fe_one();
+        let minus_one = one.neg();
+
+        let r = Fe::from_bytes(&SQRT_M1) * t.square();
+        let u = (r + one) * Fe::from_bytes(&ONE_MINUS_D_SQ);
+        let v = (minus_one - r * d) * (r + d);
+
+        let (was_square, s) = sqrt_ratio_m1(&u, &v);
+        let s_prime = fe_abs(&(s * *t)).neg();
+        let s = fe_select(&s_prime, &s, was_square);
+        let c = fe_select(&r, &minus_one, was_square);
+
+        let n = c * (r - one) * Fe::from_bytes(&D_MINUS_ONE_SQ) - v;
+
+        let s_sq = s.square();
+        let w0 = (s + s) * v;
+        let w1 = n * Fe::from_bytes(&SQRT_AD_MINUS_ONE);
+        let w2 = one - s_sq;
+        let w3 = one + s_sq;
+
+        RistrettoPoint {
+            x: w0 * w3,
+            y: w2 * w1,
+            z: w1 * w3,
+            t: w0 * w2,
+        }
+    }
+
+    /**
+     * Multiplies the point by a 32-byte little-endian scalar. The scalar need not be reduced
+     * modulo the group order.
+     */
+    pub fn scalar_mul(&self, scalar: &[u8; 32]) -> RistrettoPoint {
+        let mut acc = RistrettoPoint::identity();
+        for i in (0..256).rev() {
+            acc = acc + acc;
+            let sum = acc + *self;
+            let bit = ((scalar[i >> 3] >> (i & 7)) & 1) as i32;
+            acc.maybe_set(&sum, bit);
+        }
+        acc
+    }
+
+    fn maybe_set(&mut self, other: &RistrettoPoint, do_swap: i32) {
+        self.x.maybe_set(&other.x, do_swap);
+        self.y.maybe_set(&other.y, do_swap);
+        self.z.maybe_set(&other.z, do_swap);
+        self.t.maybe_set(&other.t, do_swap);
+    }
+}
+
+impl Add for RistrettoPoint {
+    type Output = RistrettoPoint;
+
+    /*
+    add-2008-hwcd-3 for a = -1. The formula is complete on edwards25519, so it also doubles.
+    */
+    fn add(self, other: RistrettoPoint) -> RistrettoPoint {
+        let a = (self.y - self.x) * (other.y - other.x);
+        let b = (self.y + self.x) * (other.y + other.x);
+        let c = (self.t + self.t) * Fe::from_bytes(&D) * other.t;
+        let d = (self.z + self.z) * other.z;
+        let e = b - a;
+        let f = d - c;
+        let g = d + c;
+        let h = b + a;
+        RistrettoPoint {
+            x: e * f,
+            y: g * h,
+            z: f * g,
+            t: e * h,
+        }
+    }
+}
+
+impl Neg for RistrettoPoint {
+    type Output = RistrettoPoint;
+
+    fn neg(self) -> RistrettoPoint {
+        RistrettoPoint {
+            x: self.x.neg(),
+            y: self.y,
+            z: self.z,
+            t: self.t.neg(),
+        }
+    }
+}
+
+impl Sub for RistrettoPoint {
+    type Output = RistrettoPoint;
+
+    fn sub(self, other: RistrettoPoint) -> RistrettoPoint {
+        self + (-other)
+    }
+}
+
+/*
+Two representatives name the same group element iff x1*y2 == y1*x2 or y1*y2 == x1*x2.
+*/
+impl PartialEq for RistrettoPoint {
+    fn eq(&self, other: &RistrettoPoint) -> bool {
+        let same = fe_eq(&(self.x * other.y), &(self.y * other.x));
+        let rotated = fe_eq(&(self.y * other.y), &(self.x * other.x));
+        same | rotated
+    }
+}
+
+impl Eq for RistrettoPoint {}
+
+#[cfg(test)]
+mod tests {
+    use super::RistrettoPoint;
+
+    fn to_array(hex_str: &str) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        out.copy_from_slice(&hex::decode(hex_str).unwrap());
+        out
+    }
+
+    // RFC 9496 appendix A.1: encodings of B * i for i in 0..16.
+    static BASE_MULTIPLES: [&'static str; 16] = [
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76",
+        "6a493210f7499cd17fecb510ae0cea23a110e8d5b901f8acadd3095c73a3b919",
+        "94741f5d5d52755ece4f23f044ee27d5d1ea1e2bd196b462166b16152a9d0259",
+        "da80862773358b466ffadfe0b3293ab3d9fd53c5ea6c955358f568322daf6a57",
+        "e882b131016b52c1d3337080187cf768423efccbb517bb495ab812c4160ff44e",
+        "f64746d3c92b13050ed8d80236a7f0007c3b3f962f5ba793d19a601ebb1df403",
+        "44f53520926ec81fbd5a387845beb7df85a96a24ece18738bdcfa6a7822a176d",
+        "903293d8f2287ebe10e2374dc1a53e0bc887e592699f02d077d5263cdd55601c",
+        "02622ace8f7303a31cafc63f8fc48fdc16e1c8c8d234b2f0d6685282a9076031",
+        "20706fd788b2720a1ed2a5dad4952b01f413bcf0e7564de8cdc816689e2db95f",
+        "bce83f8ba5dd2fa572864c24ba1810f9522bc6004afe95877ac73241cafdab42",
+        "e4549ee16b9aa03099ca208c67adafcafa4c3f3e4e5303de6026e3ca8ff84460",
+        "aa52e000df2e16f55fb1032fc33bc42742dad6bd5a8fc0be0167436c5948501f",
+        "46376b80f409b29dc2b5f6f0c52591990896e5716f41477cd30085ab7f10301e",
+        "e0c418f7c8d9c4cdd7395b93ea124f3ad99021bb681dfc3302a9d99a2e53e64e",
+    ];
+
+    #[test]
+    fn base_multiples_by_addition() {
+        let base = RistrettoPoint::base();
+        let mut p = RistrettoPoint::identity();
+        for expected in BASE_MULTIPLES.iter() {
+            assert_eq!(hex::encode(&p.encode()[..]), *expected);
+            p = p + base;
+        }
+    }
+
+    #[test]
+    fn base_multiples_by_scalar_mul() {
+        let base = RistrettoPoint::base();
+        for (i, expected) in BASE_MULTIPLES.iter().enumerate() {
+            let mut scalar = [0u8; 32];
+            scalar[0] = i as u8;
+            assert_eq!(hex::encode(&base.scalar_mul(&scalar).encode()[..]), *expected);
+        }
+    }
+
+    #[test]
+    fn decode_encode_round_trip() {
+        for encoding in BASE_MULTIPLES.iter() {
+            let bytes = to_array(encoding);
+            let p = RistrettoPoint::decode(&bytes).unwrap();
+            assert_eq!(p.encode(), bytes);
+        }
+    }
+
+    // RFC 9496 appendix A.2.
+    #[test]
+    fn decode_rejects_invalid_encodings() {
+        let bad = [
+            // Non-canonical field encodings.
+            "00ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "f3ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            // Negative field elements.
+            "0100000000000000000000000000000000000000000000000000000000000000",
+            "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "ed57ffd8c914fb201471d1c3d245ce3c746fcbe63a3679d51b6a516ebebe0e20",
+            "c34c4e1826e5d403b78e246e88aa051c36ccf0aafebffe137d148a2bf9104562",
+            "c940e5a4404157cfb1628b108db051a8d439e1a421394ec4ebccb9ec92a8ac78",
+            "47cfc5497c53dc8e61c91d17fd626ffb1c49e2bca94eed052281b510b1117a24",
+            "f1c6165d33367351b0da8f6e4511010c68174a03b6581212c71c0e1d026c3c72",
+            "87260f7a2f12495118360f02c26a470f450dadf34a413d21042b43b9d93e1309",
+            // Non-square x^2.
+            "26948d35ca62e643e26a83177332e6b6afeb9d08e4268b650f1f5bbd8d81d371",
+            "4eac077a713c57b4f4397629a4145982c661f48044dd3f96427d40b147d9742f",
+            "de6a7b00deadc788eb6b6c8d20c0ae96c2f2019078fa604fee5b87d6e989ad7b",
+            "bcab477be20861e01e4a0e295284146a510150d9817763caf1a6f4b422d67042",
+            "2a292df7e32cabbbd9de088d1d1abec9fc0e732d0ebbaa6a0a8aa6a2c6a87b0a",
+            "f4a9e534fc0d216c44b218fa0c42d99635a0127ee2e53c712f70609649fdff22",
+            "8268436f8c4126196cf64b3c7ddbda90746a378625f9813dd9b8457077256731",
+            "2810e5cbc2cc4d4eece54f61c6f69758e289aa7ab440b3cbeaa21995c2f4232b",
+            // Negative xy value.
+            "3eb858e78f5a7254d8c9731174a94f76755fd3941c0ac93735c07ba14579630e",
+            "a45fdc55c76448c049a1ab33f17023edfb2be3581e9c7aade8a6125215e04220",
+            "d483fe813c6ba647ebbfd3ec41adca1c6130c2beeee9d9bf065c8d151c5f396e",
+            "8a2e1d30050198c65a54483123960ccc38aef6848e1ec8f5f780e8523769ba32",
+            "32888462f8b486c68ad7dd9610be5192bbeaf3b443951ac1a8118419d9fa097b",
+            "227142501b9d4355ccba290404bde41575b037693cef1f438c47f8fbf35d1165",
+            "5c37cc491da847cfeb9281d407efc41e15144c876e0170b499a96a22ed31e01e",
+            "445425117cb8c90edcbc7c1cc0e74f747f2c1efa5630a967c64f287792a48a4b",
+            // s = -1, which causes y = 0.
+            "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+        ];
+        for encoding in bad.iter() {
+            assert!(RistrettoPoint::decode(&to_array(encoding)).is_none(), "{}", encoding);
+        }
+    }
+
+    // RFC 9496 appendix A.3.
+    #[test]
+    fn from_uniform_bytes_vectors() {
+        let vectors = [
+            (
+                "5d1be09e3d0c82fc538112490e35701979d99e06ca3e2b5b54bffe8b4dc772c1\
+                 4d98b696a1bbfb5ca32c436cc61c16563790306c79eaca7705668b47dffe5bb6",
+                "3066f82a1a747d45120d1740f14358531a8f04bbffe6a819f86dfe50f44a0a46",
+            ),
+            (
+                "f116b34b8f17ceb56e8732a60d913dd10cce47a6d53bee9204be8b44f6678b27\
+                 0102a56902e2488c46120e9276cfe54638286b9e4b3cdb470b542d46c2068d38",
+                "f26e5b6f7d362d2d2a94c5d0e7602cb4773c95a2e5c31a64f133189fa76ed61b",
+            ),
+            (
+                "8422e1bbdaab52938b81fd602effb6f89110e1e57208ad12d9ad767e2e25510c\
+                 27140775f9337088b982d83d7fcf0b2fa1edffe51952cbe7365e95c86eaf325c",
+                "006ccd2a9e6867e6a2c5cea83d3302cc9de128dd2a9a57dd8ee7b9d7ffe02826",
+            ),
+            (
+                "ac22415129b61427bf464e17baee8db65940c233b98afce8d17c57beeb7876c2\
+                 150d15af1cb1fb824bbd14955f2b57d08d388aab431a391cfc33d5bafb5dbbaf",
+                "f8f0c87cf237953c5890aec3998169005dae3eca1fbb04548c635953c817f92a",
+            ),
+            (
+                "165d697a1ef3d5cf3c38565beefcf88c0f282b8e7dbd28544c483432f1cec767\
+                 5debea8ebb4e5fe7d6f6e5db15f15587ac4d4d4a1de7191e0c1ca6664abcc413",
+                "ae81e7dedf20a497e10c304a765c1767a42d6e06029758d2d7e8ef7cc4c41179",
+            ),
+            (
+                "a836e6c9a9ca9f1e8d486273ad56a78c70cf18f0ce10abb1c7172ddd605d7fd2\
+                 979854f47ae1ccf204a33102095b4200e5befc0465accc263175485f0e17ea5c",
+                "e2705652ff9f5e44d3e841bf1c251cf7dddb77d140870d1ab2ed64f1a9ce8628",
+            ),
+            (
+                "2cdc11eaeb95daf01189417cdddbf95952993aa9cb9c640eb5058d09702c7462\
+                 2c9965a697a3b345ec24ee56335b556e677b30e6f90ac77d781064f866a3c982",
+                "80bd07262511cdde4863f8a7434cef696750681cb9510eea557088f76d9e5065",
+            ),
+        ];
+        for &(input, expected) in vectors.iter() {
+            let mut bytes = [0u8; 64];
+            bytes.copy_from_slice(&hex::decode(input).unwrap());
+            let p = RistrettoPoint::from_uniform_bytes(&bytes);
+            assert_eq!(hex::encode(&p.encode()[..]), expected);
+        }
+    }
+
+    #[test]
+    fn group_order_annihilates_base() {
+        let l = to_array("edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010");
+        let p = RistrettoPoint::base().scalar_mul(&l);
+        assert!(p == RistrettoPoint::identity());
+        assert_eq!(p.encode(), [0u8; 32]);
+    }
+
+    #[test]
+    fn add_sub_neg() {
+        let mut scalar = [0u8; 32];
+        scalar[0] = 7;
+        scalar[17] = 0x5a;
+        let p = RistrettoPoint::base().scalar_mul(&scalar);
+        let q = RistrettoPoint::base() + RistrettoPoint::base();
+        assert!(p + q - q == p);
+        assert!(p + (-p) == RistrettoPoint::identity());
+        assert_eq!((p - p).encode(), [0u8; 32]);
+    }
+}
@@ -86,6 +86,8 @@ fn rowround(state: &mut SalsaState) -> () {
 }
 
 impl Salsa20 {
+    /// Create Salsa20 with an 8 byte nonce. The block counter is 64 bits wide (x8 and x9 of the
+    /// state) and starts at zero.
     pub fn new(key: &[u8], nonce: &[u8]) -> Salsa20 {
         //assert!(key.len() == 16 || key.len() == 32);
         //assert!(nonce.len() == 8);
@@ -112,6 +114,31 @@ impl Salsa20 {
         xsalsa20
     }
 
+    /// Re-key this instance in place, reusing it rather than constructing a new one. The
+    /// instance afterwards behaves exactly like `Salsa20::new(key, nonce)`.
+    pub fn reset_with_key_nonce(&mut self, key: &[u8], nonce: &[u8]) {
+        self.state = Salsa20::expand(key, nonce);
+        self.output = [0; 64];
+        self.offset = 64;
+    }
+
+    /// Fill `out` with raw keystream without XORing any input. The stream position advances
+    /// exactly as if `out.len()` bytes had been passed to `process`.
+    pub fn keystream(&mut self, out: &mut [u8]) {
+        let len = out.len();
+        let mut i = 0;
+        while i < len {
+            if self.offset == 64 {
+                self.hash();
+            }
+
+            let count = cmp::min(64 - self.offset, len - i);
+            out[i..i + count].copy_from_slice(&self.output[self.offset..self.offset + count]);
+            i += count;
+            self.offset += count;
+        }
+    }
+
     fn expand(key: &[u8], nonce: &[u8]) -> SalsaState {
         let constant = match key.len() {
             16 => b"expand 16-byte k",
@@ -192,7 +219,7 @@ impl Salsa20 {
         }
 
         self.state.b = self.state.b + u32x4(1, 0, 0, 0);
-        let u32x4(_, _, _, ctr_lo) = self.state.b;
+        let u32x4(ctr_lo, _, _, _) = self.state.b;
         if ctr_lo == 0 {
             self.state.a = self.state.a + u32x4(0, 1, 0, 0);
         }
@@ -242,6 +269,10 @@ impl SynchronousStreamCipher for Salsa20 {
             self.offset += count;
         }
     }
+
+    fn keystream(&mut self, out: &mut [u8]) {
+        Salsa20::keystream(self, out);
+    }
 }
 
 impl Encryptor for Salsa20 {
@@ -281,11 +312,53 @@ pub fn hsalsa20(key: &[u8], nonce: &[u8], out: &mut [u8]) {
 mod test {
     use sr_std::iter::repeat;
 
+    use cryptoutil::test::test_stream_cipher;
     use salsa20::Salsa20;
     use symmetriccipher::SynchronousStreamCipher;
 
     use digest::Digest;
     use sha2::Sha256;
+    use simd::u32x4;
+
+    #[test]
+    fn test_salsa20_64bit_counter() {
+        // Blocks 0, 1, 2^32 - 1 and 2^32 computed with a reference implementation of the Salsa20
+        // specification. The second nonce word is zero, which must not affect the counter.
+        let key: Vec<u8> = (0..32).collect();
+        let nonce = [1, 2, 3, 4, 0, 0, 0, 0];
+        let expected = [
+            0x9b, 0xfd, 0xa0, 0xd4, 0x14, 0xf1, 0x84, 0x5d, 0x10, 0xff, 0xa6, 0x8b, 0xd6, 0x41,
+            0xe4, 0x1e, 0x0c, 0xda, 0xe1, 0xc8, 0x8b, 0x57, 0x51, 0xb8, 0x04, 0x18, 0xd8, 0x01,
+            0x01, 0xe0, 0x24, 0x7d, 0x20, 0xb5, 0xa1, 0x88, 0xc0, 0xb4, 0xd2, 0x27, 0x0b, 0xc6,
+            0xa4, 0xae, 0xed, 0x03, 0xb5, 0xc3, 0xd4, 0xed, 0xe7, 0x03, 0x01, 0xef, 0x45, 0x9e,
+            0x8a, 0xb0, 0x65, 0xe6, 0xf0, 0xcb, 0x5b, 0x0c,
+            0xe1, 0x9c, 0x1f, 0xaa, 0x71, 0x3c, 0xc4, 0xdc, 0xc6, 0xd8, 0x17, 0x0d, 0xdd, 0x93,
+            0xd0, 0x3b, 0x39, 0xe7, 0x85, 0x59, 0xe7, 0x9b, 0xe9, 0x57, 0x52, 0xba, 0xd1, 0xfa,
+            0xd6, 0x89, 0xd9, 0x57, 0x4d, 0x77, 0xe0, 0xba, 0x25, 0x55, 0xbe, 0x89, 0x98, 0x5b,
+            0x95, 0xf6, 0x55, 0xd6, 0xa3, 0x2a, 0x29, 0xd4, 0x5c, 0x2f, 0x80, 0x6c, 0xce, 0x0d,
+            0xd4, 0x1e, 0xb6, 0x55, 0x65, 0x4a, 0x30, 0xc9,
+            0x9b, 0xd6, 0x18, 0xcf, 0xfe, 0x63, 0xcf, 0xa4, 0xf7, 0x82, 0xa5, 0xd5, 0xe8, 0x23,
+            0xe0, 0x24, 0xe2, 0x6b, 0xbf, 0xd2, 0x00, 0xcd, 0x03, 0x05, 0x2b, 0x90, 0xdb, 0x85,
+            0x18, 0x5e, 0x83, 0x97, 0x94, 0x85, 0xf2, 0xd7, 0xe4, 0xda, 0x25, 0x3d, 0xd2, 0x53,
+            0xbf, 0x44, 0x27, 0x9a, 0x12, 0x75, 0x1a, 0x9b, 0x9f, 0x33, 0x87, 0x8c, 0xc4, 0x95,
+            0xd2, 0xda, 0x71, 0x69, 0xb5, 0x22, 0xc5, 0xb5,
+            0x9a, 0x7a, 0x4c, 0xb5, 0x6e, 0xf2, 0xa2, 0xee, 0xd7, 0x5a, 0x10, 0x4d, 0x85, 0xe1,
+            0x87, 0x9d, 0x39, 0xc8, 0xd8, 0xb5, 0x4c, 0xa3, 0x96, 0x0b, 0xf7, 0x23, 0x81, 0x14,
+            0x5b, 0x8c, 0x96, 0x68, 0xf4, 0x38, 0xeb, 0x0d, 0xd4, 0xab, 0x9a, 0x0b, 0x41, 0xac,
+            0xdd, 0xcb, 0x48, 0x02, 0xb0, 0x70, 0xeb, 0xe8, 0x84, 0xf0, 0x2c, 0xd9, 0xdc, 0xd3,
+            0x77, 0x3a, 0xc2, 0xd1, 0x0c, 0xf3, 0xa1, 0xee,
+        ];
+
+        let mut salsa20 = Salsa20::new(&key[..], &nonce);
+        let mut stream = [0u8; 128];
+        salsa20.process(&[0u8; 128], &mut stream);
+        assert_eq!(&stream[..], &expected[..128]);
+
+        let mut salsa20 = Salsa20::new(&key[..], &nonce);
+        salsa20.state.b = salsa20.state.b + u32x4(u32::max_value(), 0, 0, 0);
+        salsa20.process(&[0u8; 128], &mut stream);
+        assert_eq!(&stream[..], &expected[128..]);
+    }
 
     #[test]
     fn test_salsa20_128bit_ecrypt_set_1_vector_0() {
@@ -303,7 +376,7 @@ mod test {
 
         let mut salsa20 = Salsa20::new(&key, &nonce);
         salsa20.process(&input, &mut stream);
-        //assert!(stream[..] == result[..]);
+        assert!(stream[..] == result[..]);
     }
 
     #[test]
@@ -325,7 +398,7 @@ mod test {
 
         let mut salsa20 = Salsa20::new(&key, &nonce);
         salsa20.process(&input, &mut stream);
-        //assert!(stream[..] == result[..]);
+        assert!(stream[..] == result[..]);
     }
 
     #[test]
@@ -346,7 +419,7 @@ mod test {
         let mut sh = Sha256::new();
         sh.input(stream.as_ref());
         let out_str = sh.result_str();
-        //assert!(&out_str[..] == output_str);
+        assert!(&out_str[..] == output_str);
     }
 
     #[test]
@@ -377,7 +450,69 @@ mod test {
 
         let mut xsalsa20 = Salsa20::new_xsalsa20(&key, &nonce);
         xsalsa20.process(&input, &mut stream);
-        //assert!(stream[..] == result[..]);
+        assert!(stream[..] == result[..]);
+    }
+
+    #[test]
+    fn test_salsa20_keystream_matches_process() {
+        let key = [0x42u8; 32];
+        let nonce = [0x24u8; 8];
+        let data: Vec<u8> = (0..200).map(|i| (i * 7) as u8).collect();
+
+        let mut c1 = Salsa20::new(&key, &nonce);
+        let mut expected: Vec<u8> = repeat(0).take(data.len()).collect();
+        c1.process(&data[..], &mut expected[..]);
+
+        // Pull the keystream in uneven pieces to cross block boundaries.
+        let mut c2 = Salsa20::new(&key, &nonce);
+        let mut ks: Vec<u8> = repeat(0).take(data.len()).collect();
+        c2.keystream(&mut ks[..13]);
+        c2.keystream(&mut ks[13..100]);
+        c2.keystream(&mut ks[100..]);
+        let xored: Vec<u8> = data.iter().zip(ks.iter()).map(|(d, k)| d ^ k).collect();
+        assert_eq!(xored, expected);
+
+        // Both instances must now be at the same stream position.
+        let mut a = [0u8; 64];
+        let mut b = [0u8; 64];
+        c1.process(&[0u8; 64], &mut a);
+        c2.keystream(&mut b);
+        assert_eq!(&a[..], &b[..]);
+    }
+
+    #[test]
+    fn test_salsa20_reset_with_key_nonce() {
+        let key = [0x42u8; 32];
+        let nonce = [0x24u8; 8];
+        let input = [0u8; 150];
+
+        let mut fresh = Salsa20::new(&key, &nonce);
+        let mut expected = [0u8; 150];
+        fresh.process(&input, &mut expected);
+
+        // Leave the reused instance part way through a block under a different key.
+        let mut reused = Salsa20::new(&[0x11u8; 32], &[0x22u8; 8]);
+        let mut scratch = [0u8; 70];
+        reused.process(&[0u8; 70], &mut scratch);
+        reused.reset_with_key_nonce(&key, &nonce);
+        let mut output = [0u8; 150];
+        reused.process(&input, &mut output);
+        assert_eq!(&output[..], &expected[..]);
+    }
+
+    #[test]
+    fn test_harness() {
+        // eSTREAM Salsa20/20 128 bit key, set 1, vector 0.
+        let keystream = [
+            0x4D, 0xFA, 0x5E, 0x48, 0x1D, 0xA2, 0x3E, 0xA0, 0x9A, 0x31, 0x02, 0x20, 0x50, 0x85,
+            0x99, 0x36, 0xDA, 0x52, 0xFC, 0xEE, 0x21, 0x80, 0x05, 0x16, 0x4F, 0x26, 0x7C, 0xB6,
+            0x5F, 0x5C, 0xFD, 0x7F, 0x2B, 0x4F, 0x97, 0xE0, 0xFF, 0x16, 0x92, 0x4A, 0x52, 0xDF,
+            0x26, 0x95, 0x15, 0x11, 0x0A, 0x07, 0xF9, 0xE4, 0x60, 0xBC, 0x65, 0xEF, 0x95, 0xDA,
+            0x58, 0xF7, 0x40, 0xB7, 0xD1, 0xDB, 0xB0, 0xAA,
+        ];
+        let mut key = [0u8; 16];
+        key[0] = 0x80;
+        test_stream_cipher(|| Salsa20::new(&key, &[0; 8]), &keystream);
     }
 }
 
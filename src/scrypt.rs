@@ -1,528 +1,794 @@
-// // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
-// // http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
-// // <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
-// // option. This file may not be copied, modified, or distributed
-// // except according to those terms.
-
-// /*!
-//  * This module implements the Scrypt key derivation function as specified in [1].
-//  *
-//  * # References
-//  * [1] - C. Percival. Stronger Key Derivation Via Sequential Memory-Hard Functions.
-//  *       http://www.tarsnap.com/scrypt/scrypt.pdf
-//  */
-// use sr_std;
-// use sr_std::iter::repeat;
-// use sr_std::io;
-// use sr_std::mem::size_of;
-// use cryptoutil::copy_memory;
-
-// use rand::{OsRng, Rng};
-
-// use cryptoutil::{read_u32_le, read_u32v_le, write_u32_le};
-// use hmac::Hmac;
-// use pbkdf2::pbkdf2;
-// use sha2::Sha256;
-// use util::fixed_time_eq;
-
-// // The salsa20/8 core function.
-// fn salsa20_8(input: &[u8], output: &mut [u8]) {
-
-//     let mut x = [0u32; 16];
-//     read_u32v_le(&mut x, input);
-
-//     let rounds = 8;
-
-//     macro_rules! run_round (
-//         ($($set_idx:expr, $idx_a:expr, $idx_b:expr, $rot:expr);*) => { {
-//             $( x[$set_idx] ^= x[$idx_a].wrapping_add(x[$idx_b]).rotate_left($rot); )*
-//         } }
-//     );
-
-//     for _ in 0..rounds / 2 {
-//         run_round!(
-//             0x4, 0x0, 0xc, 7;
-//             0x8, 0x4, 0x0, 9;
-//             0xc, 0x8, 0x4, 13;
-//             0x0, 0xc, 0x8, 18;
-//             0x9, 0x5, 0x1, 7;
-//             0xd, 0x9, 0x5, 9;
-//             0x1, 0xd, 0x9, 13;
-//             0x5, 0x1, 0xd, 18;
-//             0xe, 0xa, 0x6, 7;
-//             0x2, 0xe, 0xa, 9;
-//             0x6, 0x2, 0xe, 13;
-//             0xa, 0x6, 0x2, 18;
-//             0x3, 0xf, 0xb, 7;
-//             0x7, 0x3, 0xf, 9;
-//             0xb, 0x7, 0x3, 13;
-//             0xf, 0xb, 0x7, 18;
-//             0x1, 0x0, 0x3, 7;
-//             0x2, 0x1, 0x0, 9;
-//             0x3, 0x2, 0x1, 13;
-//             0x0, 0x3, 0x2, 18;
-//             0x6, 0x5, 0x4, 7;
-//             0x7, 0x6, 0x5, 9;
-//             0x4, 0x7, 0x6, 13;
-//             0x5, 0x4, 0x7, 18;
-//             0xb, 0xa, 0x9, 7;
-//             0x8, 0xb, 0xa, 9;
-//             0x9, 0x8, 0xb, 13;
-//             0xa, 0x9, 0x8, 18;
-//             0xc, 0xf, 0xe, 7;
-//             0xd, 0xc, 0xf, 9;
-//             0xe, 0xd, 0xc, 13;
-//             0xf, 0xe, 0xd, 18
-//         )
-//     }
-
-//     for i in 0..16 {
-//         write_u32_le(
-//             &mut output[i * 4..(i + 1) * 4],
-//             x[i].wrapping_add(read_u32_le(&input[i * 4..(i + 1) * 4])));
-//     }
-// }
-
-// fn xor(x: &[u8], y: &[u8], output: &mut [u8]) {
-//     for ((out, &x_i), &y_i) in output.iter_mut().zip(x.iter()).zip(y.iter()) {
-//         *out = x_i ^ y_i;
-//     }
-// }
-
-// // Execute the BlockMix operation
-// // input - the input vector. The length must be a multiple of 128.
-// // output - the output vector. Must be the same length as input.
-// fn scrypt_block_mix(input: &[u8], output: &mut [u8]) {
-//     let mut x = [0u8; 64];
-//     copy_memory(&input[input.len() - 64..], &mut x);
-
-//     let mut t = [0u8; 64];
-
-//     for (i, chunk) in input.chunks(64).enumerate() {
-//         xor(&x, chunk, &mut t);
-//         salsa20_8(&t, &mut x);
-//         let pos = if i % 2 == 0 { (i / 2) * 64 } else { (i / 2) * 64 + input.len() / 2 };
-//         copy_memory(&x, &mut output[pos..pos + 64]);
-//     }
-// }
-
-// // Execute the ROMix operation in-place.
-// // b - the data to operate on
-// // v - a temporary variable to store the vector V
-// // t - a temporary variable to store the result of the xor
-// // n - the scrypt parameter N
-// fn scrypt_ro_mix(b: &mut [u8], v: &mut [u8], t: &mut [u8], n: usize) {
-//     fn integerify(x: &[u8], n: usize) -> usize {
-//         // n is a power of 2, so n - 1 gives us a bitmask that we can use to perform a calculation
-//         // mod n using a simple bitwise and.
-//         let mask = n - 1;
-//         // This cast is safe since we're going to get the value mod n (which is a power of 2), so we
-//         // don't have to care about truncating any of the high bits off
-//         let result = (read_u32_le(&x[x.len() - 64..x.len() - 60]) as usize) & mask;
-//         result
-//     }
-
-//     let len = b.len();
-
-//     for chunk in v.chunks_mut(len) {
-//         copy_memory(b, chunk);
-//         scrypt_block_mix(chunk, b);
-//     }
-
-//     for _ in 0..n {
-//         let j = integerify(b, n);
-//         xor(b, &v[j * len..(j + 1) * len], t);
-//         scrypt_block_mix(t, b);
-//     }
-// }
-
-// /**
-//  * The Scrypt parameter values.
-//  */
-// #[derive(Clone, Copy)]
-// pub struct ScryptParams {
-//     log_n: u8,
-//     r: u32,
-//     p: u32
-// }
-
-// impl ScryptParams {
-//     /**
-//      * Create a new instance of ScryptParams.
-//      *
-//      * # Arguments
-//      *
-//      * * log_n - The log2 of the Scrypt parameter N
-//      * * r - The Scrypt parameter r
-//      * * p - The Scrypt parameter p
-//      *
-//      */
-//     pub fn new(log_n: u8, r: u32, p: u32) -> ScryptParams {
-//         //assert!(r > 0);
-//         //assert!(p > 0);
-//         //assert!(log_n > 0);
-//         //assert!((log_n as usize) < size_of::<usize>() * 8);
-//         //assert!(size_of::<usize>() >= size_of::<u32>() || (r <= core::usize::MAX as u32 && p < core::usize::MAX as u32));
-
-//         let r = r as usize;
-//         let p = p as usize;
-
-//         let n: usize = 1 << log_n;
-
-//         // check that r * 128 doesn't overflow
-//         let r128 = match r.checked_mul(128) {
-//             Some(x) => x,
-//             None => panic!("Invalid Scrypt parameters.")
-//         };
-
-//         // check that n * r * 128 doesn't overflow
-//         match r128.checked_mul(n) {
-//             Some(_) => { },
-//             None => panic!("Invalid Scrypt parameters.")
-//         };
-
-//         // check that p * r * 128 doesn't overflow
-//         match r128.checked_mul(p) {
-//             Some(_) => { },
-//             None => panic!("Invalid Scrypt parameters.")
-//         };
-
-//         // This check required by Scrypt:
-//         // check: n < 2^(128 * r / 8)
-//         // r * 16 won't overflow since r128 didn't
-//         //assert!((log_n as usize) < r * 16);
-
-//         // This check required by Scrypt:
-//         // check: p <= ((2^32-1) * 32) / (128 * r)
-//         // It takes a bit of re-arranging to get the check above into this form, but, it is indeed
-//         // the same.
-//         //assert!(r * p < 0x40000000);
-
-//         ScryptParams {
-//             log_n: log_n,
-//             r: r as u32,
-//             p: p as u32
-//         }
-//     }
-// }
-
-// /**
-//  * The scrypt key derivation function.
-//  *
-//  * # Arguments
-//  *
-//  * * password - The password to process as a byte vector
-//  * * salt - The salt value to use as a byte vector
-//  * * params - The ScryptParams to use
-//  * * output - The resulting derived key is returned in this byte vector.
-//  *
-//  */
-// pub fn scrypt(password: &[u8], salt: &[u8], params: &ScryptParams, output: &mut [u8]) {
-//     // This check required by Scrypt:
-//     // check output.len() > 0 && output.len() <= (2^32 - 1) * 32
-//     //assert!(output.len() > 0);
-//     //assert!(output.len() / 32 <= 0xffffffff);
-
-//     // The checks in the ScryptParams constructor guarantee that the following is safe:
-//     let n = 1 << params.log_n;
-//     let r128 = (params.r as usize) * 128;
-//     let pr128 = (params.p as usize) * r128;
-//     let nr128 = n * r128;
-
-//     let mut mac = Hmac::new(Sha256::new(), password);
-
-//     let mut b: Vec<u8> = repeat(0).take(pr128).collect();
-//     pbkdf2(&mut mac, salt, 1, &mut b);
-
-//     let mut v: Vec<u8> = repeat(0).take(nr128).collect();
-//     let mut t: Vec<u8> = repeat(0).take(r128).collect();
-
-//     for chunk in &mut b.chunks_mut(r128) {
-//         scrypt_ro_mix(chunk, &mut v, &mut t, n);
-//     }
-
-//     pbkdf2(&mut mac, &*b, 1, output);
-// }
-
-// /**
-//  * scrypt_simple is a helper function that should be sufficient for the majority of cases where
-//  * an application needs to use Scrypt to hash a password for storage. The result is a String that
-//  * contains the parameters used as part of its encoding. The scrypt_check function may be used on
-//  * a password to check if it is equal to a hashed value.
-//  *
-//  * # Format
-//  *
-//  * The format of the output is a modified version of the Modular Crypt Format that encodes algorithm
-//  * used and the parameter values. If all parameter values can each fit within a single byte, a
-//  * compact format is used (format 0). However, if any value cannot, an expanded format where the r
-//  * and p parameters are encoded using 4 bytes (format 1) is used. Both formats use a 128-bit salt
-//  * and a 256-bit hash. The format is indicated as "rscrypt" which is short for "Rust Scrypt format."
-//  *
-//  * $rscrypt$<format>$<base64(log_n,r,p)>$<base64(salt)>$<based64(hash)>$
-//  *
-//  * # Arguments
-//  *
-//  * * password - The password to process as a str
-//  * * params - The ScryptParams to use
-//  *
-//  */
-// // pub fn scrypt_simple(password: &str, params: &ScryptParams) -> io::Result<String> {
-// //     let mut rng = try!(OsRng::new());
-
-// //     // 128-bit salt
-// //     let salt: Vec<u8> = rng.gen_iter::<u8>().take(16).collect();
-
-// //     // 256-bit derived key
-// //     let mut dk = [0u8; 32];
-
-// //     scrypt(password.as_bytes(), &*salt, params, &mut dk);
-
-// //     let mut result = "$rscrypt$".to_string();
-// //     if params.r < 256 && params.p < 256 {
-// //         result.push_str("0$");
-// //         let mut tmp = [0u8; 3];
-// //         tmp[0] = params.log_n;
-// //         tmp[1] = params.r as u8;
-// //         tmp[2] = params.p as u8;
-// //         result.push_str(&*base64::encode_config(&tmp, base64::STANDARD));
-// //     } else {
-// //         result.push_str("1$");
-// //         let mut tmp = [0u8; 9];
-// //         tmp[0] = params.log_n;
-// //         write_u32_le(&mut tmp[1..5], params.r);
-// //         write_u32_le(&mut tmp[5..9], params.p);
-// //         result.push_str(&*base64::encode_config(&tmp, base64::STANDARD));
-// //     }
-// //     result.push('$');
-// //     result.push_str(&*base64::encode_config(&salt, base64::STANDARD));
-// //     result.push('$');
-// //     result.push_str(&*base64::encode_config(&dk, base64::STANDARD));
-// //     result.push('$');
-
-// //     Ok(result)
-// // }
-
-// /**
-//  * scrypt_check compares a password against the result of a previous call to scrypt_simple and
-//  * returns true if the passed in password hashes to the same value.
-//  *
-//  * # Arguments
-//  *
-//  * * password - The password to process as a str
-//  * * hashed_value - A string representing a hashed password returned by scrypt_simple()
-//  *
-//  */
-// pub fn scrypt_check(password: &str, hashed_value: &str) -> Result<bool, &'static str> {
-//     static ERR_STR: &'static str = "Hash is not in Rust Scrypt format.";
-
-//     let mut iter = hashed_value.split('$');
-
-//     // Check that there are no characters before the first "$"
-//     match iter.next() {
-//         Some(x) => if x != "" { return Err(ERR_STR); },
-//         None => return Err(ERR_STR)
-//     }
-
-//     // Check the name
-//     match iter.next() {
-//         Some(t) => if t != "rscrypt" { return Err(ERR_STR); },
-//         None => return Err(ERR_STR)
-//     }
-
-//     // Parse format - currenlty only version 0 (compact) and 1 (expanded) are supported
-//     let params: ScryptParams;
-//     match iter.next() {
-//         Some(fstr) => {
-//             // Parse the parameters - the size of them depends on the if we are using the compact or
-//             // expanded format
-//             let pvec = match iter.next() {
-//                 Some(pstr) => match base64::decode(pstr) {
-//                     Ok(x) => x,
-//                     Err(_) => return Err(ERR_STR)
-//                 },
-//                 None => return Err(ERR_STR)
-//             };
-//             match fstr {
-//                 "0" => {
-//                     if pvec.len() != 3 { return Err(ERR_STR); }
-//                     let log_n = pvec[0];
-//                     let r = pvec[1] as u32;
-//                     let p = pvec[2] as u32;
-//                     params = ScryptParams::new(log_n, r, p);
-//                 }
-//                 "1" => {
-//                     if pvec.len() != 9 { return Err(ERR_STR); }
-//                     let log_n = pvec[0];
-//                     let mut pval = [0u32; 2];
-//                     read_u32v_le(&mut pval, &pvec[1..9]);
-//                     params = ScryptParams::new(log_n, pval[0], pval[1]);
-//                 }
-//                 _ => return Err(ERR_STR)
-//             }
-//         }
-//         None => return Err(ERR_STR)
-//     }
-
-//     // Salt
-//     let salt = match iter.next() {
-//         Some(sstr) => match base64::decode(sstr) {
-//             Ok(salt) => salt,
-//             Err(_) => return Err(ERR_STR)
-//         },
-//         None => return Err(ERR_STR)
-//     };
-
-//     // Hashed value
-//     let hash = match iter.next() {
-//         Some(hstr) => match base64::decode(hstr) {
-//             Ok(hash) => hash,
-//             Err(_) => return Err(ERR_STR)
-//         },
-//         None => return Err(ERR_STR)
-//     };
-
-//     // Make sure that the input ends with a "$"
-//     match iter.next() {
-//         Some(x) => if x != "" { return Err(ERR_STR); },
-//         None => return Err(ERR_STR)
-//     }
-
-//     // Make sure there is no trailing data after the final "$"
-//     match iter.next() {
-//         Some(_) => return Err(ERR_STR),
-//         None => { }
-//     }
-
-//     let mut output: Vec<u8> = repeat(0).take(hash.len()).collect();
-//     scrypt(password.as_bytes(), &*salt, &params, &mut output);
-
-//     // Be careful here - its important that the comparison be done using a fixed time equality
-//     // check. Otherwise an adversary that can measure how long this step takes can learn about the
-//     // hashed value which would allow them to mount an offline brute force attack against the
-//     // hashed password.
-//     Ok(fixed_time_eq(&*output, &*hash))
-// }
-
-// #[cfg(test)]
-// mod test {
-//     use sr_std::iter::repeat;
-
-//     use scrypt::{scrypt, scrypt_simple, scrypt_check, ScryptParams};
-
-//     struct Test {
-//         password: &'static str,
-//         salt: &'static str,
-//         log_n: u8,
-//         r: u32,
-//         p: u32,
-//         expected: Vec<u8>
-//     }
-
-//     // Test vectors from [1]. The last test vector is omitted because it takes too long to run.
-
-//     fn tests() -> Vec<Test> {
-//         vec![
-//             Test {
-//                 password: "",
-//                 salt: "",
-//                 log_n: 4,
-//                 r: 1,
-//                 p: 1,
-//                 expected: vec![
-//                     0x77, 0xd6, 0x57, 0x62, 0x38, 0x65, 0x7b, 0x20,
-//                     0x3b, 0x19, 0xca, 0x42, 0xc1, 0x8a, 0x04, 0x97,
-//                     0xf1, 0x6b, 0x48, 0x44, 0xe3, 0x07, 0x4a, 0xe8,
-//                     0xdf, 0xdf, 0xfa, 0x3f, 0xed, 0xe2, 0x14, 0x42,
-//                     0xfc, 0xd0, 0x06, 0x9d, 0xed, 0x09, 0x48, 0xf8,
-//                     0x32, 0x6a, 0x75, 0x3a, 0x0f, 0xc8, 0x1f, 0x17,
-//                     0xe8, 0xd3, 0xe0, 0xfb, 0x2e, 0x0d, 0x36, 0x28,
-//                     0xcf, 0x35, 0xe2, 0x0c, 0x38, 0xd1, 0x89, 0x06 ]
-//             },
-//             Test {
-//                 password: "password",
-//                 salt: "NaCl",
-//                 log_n: 10,
-//                 r: 8,
-//                 p: 16,
-//                 expected: vec![
-//                     0xfd, 0xba, 0xbe, 0x1c, 0x9d, 0x34, 0x72, 0x00,
-//                     0x78, 0x56, 0xe7, 0x19, 0x0d, 0x01, 0xe9, 0xfe,
-//                     0x7c, 0x6a, 0xd7, 0xcb, 0xc8, 0x23, 0x78, 0x30,
-//                     0xe7, 0x73, 0x76, 0x63, 0x4b, 0x37, 0x31, 0x62,
-//                     0x2e, 0xaf, 0x30, 0xd9, 0x2e, 0x22, 0xa3, 0x88,
-//                     0x6f, 0xf1, 0x09, 0x27, 0x9d, 0x98, 0x30, 0xda,
-//                     0xc7, 0x27, 0xaf, 0xb9, 0x4a, 0x83, 0xee, 0x6d,
-//                     0x83, 0x60, 0xcb, 0xdf, 0xa2, 0xcc, 0x06, 0x40 ]
-//             },
-//             Test {
-//                 password: "pleaseletmein",
-//                 salt: "SodiumChloride",
-//                 log_n: 14,
-//                 r: 8,
-//                 p: 1,
-//                 expected: vec![
-//                     0x70, 0x23, 0xbd, 0xcb, 0x3a, 0xfd, 0x73, 0x48,
-//                     0x46, 0x1c, 0x06, 0xcd, 0x81, 0xfd, 0x38, 0xeb,
-//                     0xfd, 0xa8, 0xfb, 0xba, 0x90, 0x4f, 0x8e, 0x3e,
-//                     0xa9, 0xb5, 0x43, 0xf6, 0x54, 0x5d, 0xa1, 0xf2,
-//                     0xd5, 0x43, 0x29, 0x55, 0x61, 0x3f, 0x0f, 0xcf,
-//                     0x62, 0xd4, 0x97, 0x05, 0x24, 0x2a, 0x9a, 0xf9,
-//                     0xe6, 0x1e, 0x85, 0xdc, 0x0d, 0x65, 0x1e, 0x40,
-//                     0xdf, 0xcf, 0x01, 0x7b, 0x45, 0x57, 0x58, 0x87 ]
-//             },
-//         ]
-//     }
-
-//     #[test]
-//     fn test_scrypt() {
-//         let tests = tests();
-//         for t in tests.iter() {
-//             let mut result: Vec<u8> = repeat(0).take(t.expected.len()).collect();
-//             let params = ScryptParams::new(t.log_n, t.r, t.p);
-//             scrypt(t.password.as_bytes(), t.salt.as_bytes(), &params, &mut result);
-//             //assert!(result == t.expected);
-//         }
-//     }
-
-//     fn test_scrypt_simple(log_n: u8, r: u32, p: u32) {
-//         let password = "password";
-
-//         let params = ScryptParams::new(log_n, r, p);
-//         let out1 = scrypt_simple(password, &params).unwrap();
-//         let out2 = scrypt_simple(password, &params).unwrap();
-
-//         // This just makes sure that a salt is being applied. It doesn't verify that that salt is
-//         // cryptographically strong, however.
-//         //assert!(out1 != out2);
-
-//         match scrypt_check(password, &out1[..]) {
-//             Ok(r) => //assert!(r),
-//             Err(_) => panic!()
-//         }
-//         match scrypt_check(password, &out2[..]) {
-//             Ok(r) => //assert!(r),
-//             Err(_) => panic!()
-//         }
-
-//         match scrypt_check("wrong", &out1[..]) {
-//             Ok(r) => //assert!(!r),
-//             Err(_) => panic!()
-//         }
-//         match scrypt_check("wrong", &out2[..]) {
-//             Ok(r) => //assert!(!r),
-//             Err(_) => panic!()
-//         }
-//     }
-
-//     #[test]
-//     fn test_scrypt_simple_compact() {
-//         // These parameters are intentionally very weak - the goal is to make the test run quickly!
-//         test_scrypt_simple(7, 8, 1);
-//     }
-
-//     #[test]
-//     fn test_scrypt_simple_expanded() {
-//         // These parameters are intentionally very weak - the goal is to make the test run quickly!
-//         test_scrypt_simple(3, 1, 256);
-//     }
-// }
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+ * This module implements the Scrypt key derivation function as specified in [1].
+ *
+ * # References
+ * [1] - C. Percival. Stronger Key Derivation Via Sequential Memory-Hard Functions.
+ *       http://www.tarsnap.com/scrypt/scrypt.pdf
+ */
+use sr_std::iter::repeat;
+use sr_std::prelude::*;
+use cryptoutil::copy_memory;
+
+use cryptoutil::{read_u32_le, read_u32v_le, write_u32_le};
+use hmac::Hmac;
+use pbkdf2::pbkdf2;
+#[cfg(feature = "std")]
+use phc;
+use sha2::Sha256;
+use util::{fixed_time_eq, secure_memset};
+
+// The salsa20/8 core function.
+fn salsa20_8(input: &[u8], output: &mut [u8]) {
+
+    let mut x = [0u32; 16];
+    read_u32v_le(&mut x, input);
+
+    let rounds = 8;
+
+    macro_rules! run_round (
+        ($($set_idx:expr, $idx_a:expr, $idx_b:expr, $rot:expr);*) => { {
+            $( x[$set_idx] ^= x[$idx_a].wrapping_add(x[$idx_b]).rotate_left($rot); )*
+        } }
+    );
+
+    for _ in 0..rounds / 2 {
+        run_round!(
+            0x4, 0x0, 0xc, 7;
+            0x8, 0x4, 0x0, 9;
+            0xc, 0x8, 0x4, 13;
+            0x0, 0xc, 0x8, 18;
+            0x9, 0x5, 0x1, 7;
+            0xd, 0x9, 0x5, 9;
+            0x1, 0xd, 0x9, 13;
+            0x5, 0x1, 0xd, 18;
+            0xe, 0xa, 0x6, 7;
+            0x2, 0xe, 0xa, 9;
+            0x6, 0x2, 0xe, 13;
+            0xa, 0x6, 0x2, 18;
+            0x3, 0xf, 0xb, 7;
+            0x7, 0x3, 0xf, 9;
+            0xb, 0x7, 0x3, 13;
+            0xf, 0xb, 0x7, 18;
+            0x1, 0x0, 0x3, 7;
+            0x2, 0x1, 0x0, 9;
+            0x3, 0x2, 0x1, 13;
+            0x0, 0x3, 0x2, 18;
+            0x6, 0x5, 0x4, 7;
+            0x7, 0x6, 0x5, 9;
+            0x4, 0x7, 0x6, 13;
+            0x5, 0x4, 0x7, 18;
+            0xb, 0xa, 0x9, 7;
+            0x8, 0xb, 0xa, 9;
+            0x9, 0x8, 0xb, 13;
+            0xa, 0x9, 0x8, 18;
+            0xc, 0xf, 0xe, 7;
+            0xd, 0xc, 0xf, 9;
+            0xe, 0xd, 0xc, 13;
+            0xf, 0xe, 0xd, 18
+        )
+    }
+
+    for i in 0..16 {
+        write_u32_le(
+            &mut output[i * 4..(i + 1) * 4],
+            x[i].wrapping_add(read_u32_le(&input[i * 4..(i + 1) * 4])));
+    }
+}
+
+fn xor(x: &[u8], y: &[u8], output: &mut [u8]) {
+    for ((out, &x_i), &y_i) in output.iter_mut().zip(x.iter()).zip(y.iter()) {
+        *out = x_i ^ y_i;
+    }
+}
+
+// Execute the BlockMix operation
+// input - the input vector. The length must be a multiple of 128.
+// output - the output vector. Must be the same length as input.
+fn scrypt_block_mix(input: &[u8], output: &mut [u8]) {
+    let mut x = [0u8; 64];
+    copy_memory(&input[input.len() - 64..], &mut x);
+
+    let mut t = [0u8; 64];
+
+    for (i, chunk) in input.chunks(64).enumerate() {
+        xor(&x, chunk, &mut t);
+        salsa20_8(&t, &mut x);
+        let pos = if i % 2 == 0 { (i / 2) * 64 } else { (i / 2) * 64 + input.len() / 2 };
+        copy_memory(&x, &mut output[pos..pos + 64]);
+    }
+}
+
+// n is a power of 2, so n - 1 gives us a bitmask that we can use to perform a calculation mod n
+// using a simple bitwise and.
+fn integerify(x: &[u8], n: usize) -> usize {
+    let mask = n - 1;
+    // This cast is safe since we're going to get the value mod n (which is a power of 2), so we
+    // don't have to care about truncating any of the high bits off
+    let result = (read_u32_le(&x[x.len() - 64..x.len() - 60]) as usize) & mask;
+    result
+}
+
+// Execute the ROMix operation in-place.
+// b - the data to operate on
+// v - a temporary variable to store the vector V
+// t - a temporary variable to store the result of the xor
+// n - the scrypt parameter N
+fn scrypt_ro_mix(b: &mut [u8], v: &mut [u8], t: &mut [u8], n: usize) {
+    let len = b.len();
+
+    for chunk in v.chunks_mut(len) {
+        copy_memory(b, chunk);
+        scrypt_block_mix(chunk, b);
+    }
+
+    for _ in 0..n {
+        let j = integerify(b, n);
+        xor(b, &v[j * len..(j + 1) * len], t);
+        scrypt_block_mix(t, b);
+    }
+}
+
+// Execute the ROMix operation in-place, storing only every stride-th entry of V and regenerating
+// the others from the nearest stored entry when they are needed.
+// b - the data to operate on
+// v - a temporary variable to store every stride-th entry of V
+// t - a temporary variable to store the result of the xor
+// u - a temporary variable to regenerate an entry of V in
+// n - the scrypt parameter N
+fn scrypt_ro_mix_low_memory(
+    b: &mut [u8],
+    v: &mut [u8],
+    t: &mut [u8],
+    u: &mut [u8],
+    n: usize,
+    stride: usize,
+) {
+    let len = b.len();
+
+    for i in 0..n {
+        if i % stride == 0 {
+            let k = i / stride;
+            copy_memory(b, &mut v[k * len..(k + 1) * len]);
+        }
+        scrypt_block_mix(b, t);
+        copy_memory(t, b);
+    }
+
+    for _ in 0..n {
+        let j = integerify(b, n);
+        let k = j / stride;
+        copy_memory(&v[k * len..(k + 1) * len], u);
+        for _ in 0..j % stride {
+            scrypt_block_mix(u, t);
+            copy_memory(t, u);
+        }
+        xor(b, u, t);
+        scrypt_block_mix(t, b);
+    }
+}
+
+/**
+ * The Scrypt parameter values.
+ */
+#[derive(Clone, Copy)]
+pub struct ScryptParams {
+    log_n: u8,
+    r: u32,
+    p: u32
+}
+
+impl ScryptParams {
+    /**
+     * Create a new instance of ScryptParams.
+     *
+     * # Arguments
+     *
+     * * log_n - The log2 of the Scrypt parameter N
+     * * r - The Scrypt parameter r
+     * * p - The Scrypt parameter p
+     *
+     * # Panics
+     *
+     * If the parameters are rejected by try_new().
+     *
+     */
+    pub fn new(log_n: u8, r: u32, p: u32) -> ScryptParams {
+        match ScryptParams::try_new(log_n, r, p) {
+            Ok(params) => params,
+            Err(_) => panic!("Invalid Scrypt parameters.")
+        }
+    }
+
+    /**
+     * Create a new instance of ScryptParams, returning an error instead of panicking if the
+     * parameters are out of range. r, p and log_n must be non-zero, N = 2^log_n must be less
+     * than 2^(16 * r), r * p must be less than 2^30, and the N * r * 128 and p * r * 128 byte
+     * buffers scrypt() allocates must fit in a usize.
+     */
+    pub fn try_new(log_n: u8, r: u32, p: u32) -> Result<ScryptParams, &'static str> {
+        static ERR_STR: &'static str = "Invalid Scrypt parameters.";
+
+        if r == 0 || p == 0 || log_n == 0 {
+            return Err(ERR_STR);
+        }
+        if log_n as u32 >= usize::BITS {
+            return Err(ERR_STR);
+        }
+
+        let r = r as usize;
+        let p = p as usize;
+
+        let n: usize = 1 << log_n;
+
+        // check that r * 128 doesn't overflow
+        let r128 = match r.checked_mul(128) {
+            Some(x) => x,
+            None => return Err(ERR_STR)
+        };
+
+        // check that n * r * 128 doesn't overflow
+        if r128.checked_mul(n).is_none() {
+            return Err(ERR_STR);
+        }
+
+        // check that p * r * 128 doesn't overflow
+        if r128.checked_mul(p).is_none() {
+            return Err(ERR_STR);
+        }
+
+        // This check required by Scrypt:
+        // check: n < 2^(128 * r / 8)
+        // r * 16 won't overflow since r128 didn't
+        if (log_n as usize) >= r * 16 {
+            return Err(ERR_STR);
+        }
+
+        // This check required by Scrypt:
+        // check: p <= ((2^32-1) * 32) / (128 * r)
+        // It takes a bit of re-arranging to get the check above into this form, but, it is indeed
+        // the same. r * p can't overflow since p * r * 128 didn't.
+        if r * p >= 0x40000000 {
+            return Err(ERR_STR);
+        }
+
+        Ok(ScryptParams {
+            log_n: log_n,
+            r: r as u32,
+            p: p as u32
+        })
+    }
+
+    /**
+     * Parameters for interactive logins: N = 2^14, r = 8, p = 1, using 16 MiB. This matches
+     * libsodium's crypto_pwhash_scryptsalsa208sha256 interactive limits.
+     */
+    pub fn interactive() -> ScryptParams {
+        ScryptParams::new(14, 8, 1)
+    }
+
+    /**
+     * Parameters for highly sensitive, non-interactive uses such as key files: N = 2^20, r = 8,
+     * p = 1, using 1 GiB. This matches libsodium's sensitive limits.
+     */
+    pub fn sensitive() -> ScryptParams {
+        ScryptParams::new(20, 8, 1)
+    }
+
+    /**
+     * A general purpose default for password storage as of 2024: N = 2^17, r = 8, p = 1, using
+     * 128 MiB, as recommended by the OWASP Password Storage Cheat Sheet.
+     */
+    pub fn recommended_2024() -> ScryptParams {
+        ScryptParams::new(17, 8, 1)
+    }
+
+    /**
+     * The size in bytes of the N * 128 * r byte table that dominates the memory use of scrypt().
+     * scrypt_low_memory() trades extra computation for needing less.
+     */
+    pub fn estimate_memory_bytes(&self) -> usize {
+        // try_new() rejects parameters where this overflows, but saturate rather than wrap anyway
+        let n = match 1usize.checked_shl(self.log_n as u32) {
+            Some(n) => n,
+            None => return usize::MAX
+        };
+        (self.r as usize)
+            .checked_mul(128)
+            .and_then(|r128| r128.checked_mul(n))
+            .unwrap_or(usize::MAX)
+    }
+}
+
+/**
+ * The scrypt key derivation function.
+ *
+ * # Arguments
+ *
+ * * password - The password to process as a byte vector
+ * * salt - The salt value to use as a byte vector
+ * * params - The ScryptParams to use
+ * * output - The resulting derived key is returned in this byte vector.
+ *
+ */
+pub fn scrypt(password: &[u8], salt: &[u8], params: &ScryptParams, output: &mut [u8]) {
+    // This check required by Scrypt:
+    // check output.len() > 0 && output.len() <= (2^32 - 1) * 32
+    //assert!(output.len() > 0);
+    //assert!(output.len() / 32 <= 0xffffffff);
+
+    // The checks in the ScryptParams constructor guarantee that the following is safe:
+    let n = 1 << params.log_n;
+    let r128 = (params.r as usize) * 128;
+    let pr128 = (params.p as usize) * r128;
+    let nr128 = n * r128;
+
+    let mut mac = Hmac::new(Sha256::new(), password);
+
+    let mut b: Vec<u8> = repeat(0).take(pr128).collect();
+    pbkdf2(&mut mac, salt, 1, &mut b);
+
+    let mut v: Vec<u8> = repeat(0).take(nr128).collect();
+    let mut t: Vec<u8> = repeat(0).take(r128).collect();
+
+    for chunk in &mut b.chunks_mut(r128) {
+        scrypt_ro_mix(chunk, &mut v, &mut t, n);
+    }
+
+    pbkdf2(&mut mac, &*b, 1, output);
+
+    // V holds every intermediate state of the password-derived B, so wipe it along with B and T.
+    secure_memset(&mut b[..], 0);
+    secure_memset(&mut v[..], 0);
+    secure_memset(&mut t[..], 0);
+}
+
+/**
+ * A variant of scrypt that trades CPU time for memory. Only every stride-th entry of the ROMix
+ * vector V is stored, reducing its size from N * r * 128 bytes to ceil(N / stride) * r * 128
+ * bytes, and the missing entries are recomputed on demand at an average cost of about
+ * (stride - 1) / 2 extra BlockMix operations per lookup. The output is identical to scrypt() with
+ * the same parameters, and a stride of 1 is equivalent to it.
+ *
+ * This weakens the memory-hardness that scrypt's parameters are chosen for: an attacker can make
+ * the same trade, so the parameters say less about the memory an attack needs. Use it only when
+ * the full array does not fit, and prefer the smallest stride that does.
+ *
+ * # Arguments
+ *
+ * * password - The password to process as a byte vector
+ * * salt - The salt value to use as a byte vector
+ * * params - The ScryptParams to use
+ * * stride - The spacing of the stored entries of V. Must be at least 1.
+ * * output - The resulting derived key is returned in this byte vector.
+ *
+ */
+pub fn scrypt_low_memory(
+    password: &[u8],
+    salt: &[u8],
+    params: &ScryptParams,
+    stride: usize,
+    output: &mut [u8],
+) {
+    assert!(stride > 0);
+
+    let n = 1 << params.log_n;
+    let r128 = (params.r as usize) * 128;
+    let pr128 = (params.p as usize) * r128;
+    let stored = (n - 1) / stride + 1;
+
+    let mut mac = Hmac::new(Sha256::new(), password);
+
+    let mut b: Vec<u8> = repeat(0).take(pr128).collect();
+    pbkdf2(&mut mac, salt, 1, &mut b);
+
+    let mut v: Vec<u8> = repeat(0).take(stored * r128).collect();
+    let mut t: Vec<u8> = repeat(0).take(r128).collect();
+    let mut u: Vec<u8> = repeat(0).take(r128).collect();
+
+    for chunk in &mut b.chunks_mut(r128) {
+        scrypt_ro_mix_low_memory(chunk, &mut v, &mut t, &mut u, n, stride);
+    }
+
+    pbkdf2(&mut mac, &*b, 1, output);
+
+    secure_memset(&mut b[..], 0);
+    secure_memset(&mut v[..], 0);
+    secure_memset(&mut t[..], 0);
+    secure_memset(&mut u[..], 0);
+}
+
+// The most memory scrypt_check() will allocate for a stored hash, the amount used by
+// ScryptParams::sensitive(). The parameters come from the hash string, which may be untrusted.
+const MAX_CHECK_MEMORY_BYTES: usize = 1 << 30;
+
+fn checked_params(log_n: u8, r: u32, p: u32) -> Result<ScryptParams, &'static str> {
+    let params = match ScryptParams::try_new(log_n, r, p) {
+        Ok(params) => params,
+        Err(_) => return Err("Hash has invalid Scrypt parameters.")
+    };
+    // try_new() has checked that p * r * 128 doesn't overflow
+    if params.estimate_memory_bytes() > MAX_CHECK_MEMORY_BYTES
+        || (p as usize) * (r as usize) * 128 > MAX_CHECK_MEMORY_BYTES {
+        return Err("Hash has Scrypt parameters that need too much memory.");
+    }
+    Ok(params)
+}
+
+/**
+ * Hash a password for storage as a PHC string, `$scrypt$ln=<log_n>,r=<r>,p=<p>$<salt>$<hash>`,
+ * with a 256 bit hash. The salt should be 16 random bytes, unique to each password. The result
+ * may be checked with scrypt_check().
+ *
+ * # Arguments
+ *
+ * * password - The password to process as a str
+ * * salt - The salt, which must not be empty
+ * * params - The ScryptParams to use
+ *
+ */
+#[cfg(feature = "std")]
+pub fn scrypt_phc(password: &str, salt: &[u8], params: &ScryptParams) -> String {
+    let mut dk = [0u8; 32];
+    scrypt(password.as_bytes(), salt, params, &mut dk);
+
+    let log_n = params.log_n.to_string();
+    let r = params.r.to_string();
+    let p = params.p.to_string();
+    let result = phc::encode("scrypt", &[("ln", &log_n), ("r", &r), ("p", &p)], salt, &dk);
+    secure_memset(&mut dk, 0);
+    result
+}
+
+// Check a password against a PHC string produced by scrypt_phc().
+#[cfg(feature = "std")]
+fn scrypt_check_phc(password: &str, hashed_value: &str) -> Result<bool, &'static str> {
+    static ERR_STR: &'static str = "Hash is not in PHC scrypt format.";
+
+    let hashed = match phc::parse(hashed_value) {
+        Ok(hashed) => hashed,
+        Err(_) => return Err(ERR_STR)
+    };
+    if hashed.id != "scrypt" || hashed.version.is_some() || hashed.params.len() != 3 {
+        return Err(ERR_STR);
+    }
+    let log_n = hashed.param("ln").and_then(|v| v.parse().ok());
+    let r = hashed.param("r").and_then(|v| v.parse().ok());
+    let p = hashed.param("p").and_then(|v| v.parse().ok());
+    let params = match (log_n, r, p) {
+        (Some(log_n), Some(r), Some(p)) => checked_params(log_n, r, p)?,
+        _ => return Err(ERR_STR)
+    };
+    if hashed.salt.is_empty() || hashed.hash.is_empty() {
+        return Err(ERR_STR);
+    }
+
+    let mut output: Vec<u8> = repeat(0).take(hashed.hash.len()).collect();
+    scrypt(password.as_bytes(), &hashed.salt, &params, &mut output);
+    Ok(fixed_time_eq(&output, &hashed.hash))
+}
+
+/**
+ * scrypt_check compares a password against a hashed value produced by scrypt_phc() and returns
+ * true if the passed in password hashes to the same value. Hashes in the older "Rust Scrypt
+ * format", $rscrypt$<format>$<base64(log_n,r,p)>$<base64(salt)>$<base64(hash)>$, are accepted as
+ * well, which is the only format understood without the std feature. Format 0 encodes log_n, r
+ * and p in a single byte each; format 1 encodes r and p using 4 bytes each.
+ *
+ * # Arguments
+ *
+ * * password - The password to process as a str
+ * * hashed_value - A string representing a hashed password returned by scrypt_phc()
+ *
+ */
+pub fn scrypt_check(password: &str, hashed_value: &str) -> Result<bool, &'static str> {
+    static ERR_STR: &'static str = "Hash is not in Rust Scrypt format.";
+
+    #[cfg(feature = "std")]
+    {
+        if hashed_value.starts_with("$scrypt$") {
+            return scrypt_check_phc(password, hashed_value);
+        }
+    }
+
+    let mut iter = hashed_value.split('$');
+
+    // Check that there are no characters before the first "$"
+    match iter.next() {
+        Some(x) => if x != "" { return Err(ERR_STR); },
+        None => return Err(ERR_STR)
+    }
+
+    // Check the name
+    match iter.next() {
+        Some(t) => if t != "rscrypt" { return Err(ERR_STR); },
+        None => return Err(ERR_STR)
+    }
+
+    // Parse format - currenlty only version 0 (compact) and 1 (expanded) are supported
+    let params: ScryptParams;
+    match iter.next() {
+        Some(fstr) => {
+            // Parse the parameters - the size of them depends on the if we are using the compact or
+            // expanded format
+            let pvec = match iter.next() {
+                Some(pstr) => match base64::decode(pstr) {
+                    Ok(x) => x,
+                    Err(_) => return Err(ERR_STR)
+                },
+                None => return Err(ERR_STR)
+            };
+            match fstr {
+                "0" => {
+                    if pvec.len() != 3 { return Err(ERR_STR); }
+                    let log_n = pvec[0];
+                    let r = pvec[1] as u32;
+                    let p = pvec[2] as u32;
+                    params = checked_params(log_n, r, p)?;
+                }
+                "1" => {
+                    if pvec.len() != 9 { return Err(ERR_STR); }
+                    let log_n = pvec[0];
+                    let mut pval = [0u32; 2];
+                    read_u32v_le(&mut pval, &pvec[1..9]);
+                    params = checked_params(log_n, pval[0], pval[1])?;
+                }
+                _ => return Err(ERR_STR)
+            }
+        }
+        None => return Err(ERR_STR)
+    }
+
+    // Salt
+    let salt = match iter.next() {
+        Some(sstr) => match base64::decode(sstr) {
+            Ok(salt) => salt,
+            Err(_) => return Err(ERR_STR)
+        },
+        None => return Err(ERR_STR)
+    };
+
+    // Hashed value
+    let hash = match iter.next() {
+        Some(hstr) => match base64::decode(hstr) {
+            Ok(hash) => hash,
+            Err(_) => return Err(ERR_STR)
+        },
+        None => return Err(ERR_STR)
+    };
+
+    // Make sure that the input ends with a "$"
+    match iter.next() {
+        Some(x) => if x != "" { return Err(ERR_STR); },
+        None => return Err(ERR_STR)
+    }
+
+    // Make sure there is no trailing data after the final "$"
+    match iter.next() {
+        Some(_) => return Err(ERR_STR),
+        None => { }
+    }
+
+    let mut output: Vec<u8> = repeat(0).take(hash.len()).collect();
+    scrypt(password.as_bytes(), &*salt, &params, &mut output);
+
+    // Be careful here - its important that the comparison be done using a fixed time equality
+    // check. Otherwise an adversary that can measure how long this step takes can learn about the
+    // hashed value which would allow them to mount an offline brute force attack against the
+    // hashed password.
+    Ok(fixed_time_eq(&*output, &*hash))
+}
+
+#[cfg(test)]
+mod test {
+    use sr_std::iter::repeat;
+
+    #[cfg(feature = "std")]
+    use scrypt::scrypt_phc;
+    use scrypt::{scrypt, scrypt_check, scrypt_low_memory, ScryptParams};
+
+    struct Test {
+        password: &'static str,
+        salt: &'static str,
+        log_n: u8,
+        r: u32,
+        p: u32,
+        expected: Vec<u8>
+    }
+
+    // Test vectors from [1]. The last test vector is omitted because it takes too long to run.
+
+    fn tests() -> Vec<Test> {
+        vec![
+            Test {
+                password: "",
+                salt: "",
+                log_n: 4,
+                r: 1,
+                p: 1,
+                expected: vec![
+                    0x77, 0xd6, 0x57, 0x62, 0x38, 0x65, 0x7b, 0x20,
+                    0x3b, 0x19, 0xca, 0x42, 0xc1, 0x8a, 0x04, 0x97,
+                    0xf1, 0x6b, 0x48, 0x44, 0xe3, 0x07, 0x4a, 0xe8,
+                    0xdf, 0xdf, 0xfa, 0x3f, 0xed, 0xe2, 0x14, 0x42,
+                    0xfc, 0xd0, 0x06, 0x9d, 0xed, 0x09, 0x48, 0xf8,
+                    0x32, 0x6a, 0x75, 0x3a, 0x0f, 0xc8, 0x1f, 0x17,
+                    0xe8, 0xd3, 0xe0, 0xfb, 0x2e, 0x0d, 0x36, 0x28,
+                    0xcf, 0x35, 0xe2, 0x0c, 0x38, 0xd1, 0x89, 0x06 ]
+            },
+            Test {
+                password: "password",
+                salt: "NaCl",
+                log_n: 10,
+                r: 8,
+                p: 16,
+                expected: vec![
+                    0xfd, 0xba, 0xbe, 0x1c, 0x9d, 0x34, 0x72, 0x00,
+                    0x78, 0x56, 0xe7, 0x19, 0x0d, 0x01, 0xe9, 0xfe,
+                    0x7c, 0x6a, 0xd7, 0xcb, 0xc8, 0x23, 0x78, 0x30,
+                    0xe7, 0x73, 0x76, 0x63, 0x4b, 0x37, 0x31, 0x62,
+                    0x2e, 0xaf, 0x30, 0xd9, 0x2e, 0x22, 0xa3, 0x88,
+                    0x6f, 0xf1, 0x09, 0x27, 0x9d, 0x98, 0x30, 0xda,
+                    0xc7, 0x27, 0xaf, 0xb9, 0x4a, 0x83, 0xee, 0x6d,
+                    0x83, 0x60, 0xcb, 0xdf, 0xa2, 0xcc, 0x06, 0x40 ]
+            },
+            Test {
+                password: "pleaseletmein",
+                salt: "SodiumChloride",
+                log_n: 14,
+                r: 8,
+                p: 1,
+                expected: vec![
+                    0x70, 0x23, 0xbd, 0xcb, 0x3a, 0xfd, 0x73, 0x48,
+                    0x46, 0x1c, 0x06, 0xcd, 0x81, 0xfd, 0x38, 0xeb,
+                    0xfd, 0xa8, 0xfb, 0xba, 0x90, 0x4f, 0x8e, 0x3e,
+                    0xa9, 0xb5, 0x43, 0xf6, 0x54, 0x5d, 0xa1, 0xf2,
+                    0xd5, 0x43, 0x29, 0x55, 0x61, 0x3f, 0x0f, 0xcf,
+                    0x62, 0xd4, 0x97, 0x05, 0x24, 0x2a, 0x9a, 0xf9,
+                    0xe6, 0x1e, 0x85, 0xdc, 0x0d, 0x65, 0x1e, 0x40,
+                    0xdf, 0xcf, 0x01, 0x7b, 0x45, 0x57, 0x58, 0x87 ]
+            },
+        ]
+    }
+
+    #[test]
+    fn test_scrypt() {
+        let tests = tests();
+        for t in tests.iter() {
+            let mut result: Vec<u8> = repeat(0).take(t.expected.len()).collect();
+            let params = ScryptParams::new(t.log_n, t.r, t.p);
+            scrypt(t.password.as_bytes(), t.salt.as_bytes(), &params, &mut result);
+            assert!(result == t.expected);
+        }
+    }
+
+    #[test]
+    fn test_scrypt_repeatable() {
+        // The scratch buffers are wiped after use; make sure that doesn't affect the output.
+        let t = &tests()[0];
+        let params = ScryptParams::new(t.log_n, t.r, t.p);
+        for _ in 0..2 {
+            let mut result: Vec<u8> = repeat(0).take(t.expected.len()).collect();
+            scrypt(t.password.as_bytes(), t.salt.as_bytes(), &params, &mut result);
+            assert_eq!(result, t.expected);
+        }
+    }
+
+    #[test]
+    fn test_scrypt_low_memory() {
+        // Large strides make lookups expensive, so only try them with the smallest N.
+        for t in tests().iter().take(2) {
+            let params = ScryptParams::new(t.log_n, t.r, t.p);
+            let n = 1usize << t.log_n;
+            let strides = if n <= 16 { vec![1, 2, 3, 7, n, n + 1] } else { vec![4] };
+            for &stride in strides.iter() {
+                let mut result: Vec<u8> = repeat(0).take(t.expected.len()).collect();
+                scrypt_low_memory(
+                    t.password.as_bytes(),
+                    t.salt.as_bytes(),
+                    &params,
+                    stride,
+                    &mut result,
+                );
+                assert_eq!(result, t.expected);
+            }
+        }
+
+        // A non-power-of-two output length and p > 1, checked against scrypt() itself.
+        let params = ScryptParams::new(5, 2, 3);
+        let mut expected = [0u8; 45];
+        scrypt(b"password", b"salt", &params, &mut expected);
+        let mut result = [0u8; 45];
+        scrypt_low_memory(b"password", b"salt", &params, 5, &mut result);
+        assert_eq!(&result[..], &expected[..]);
+    }
+
+    #[test]
+    fn test_presets() {
+        let cases = [
+            (ScryptParams::interactive(), 14, 16 << 20),
+            (ScryptParams::sensitive(), 20, 1 << 30),
+            (ScryptParams::recommended_2024(), 17, 128 << 20),
+        ];
+        for &(params, log_n, memory) in cases.iter() {
+            assert_eq!((params.log_n, params.r, params.p), (log_n, 8, 1));
+            assert_eq!(params.estimate_memory_bytes(), memory);
+            assert_eq!(params.estimate_memory_bytes(), 128 * params.r as usize * (1 << log_n));
+        }
+
+        let params = ScryptParams::new(3, 5, 7);
+        assert_eq!(params.estimate_memory_bytes(), 128 * 5 * 8);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_scrypt_phc() {
+        // Generated with Python's hashlib.scrypt(b"password", salt=bytes(range(16)), n=16, r=1,
+        // p=1, dklen=32)
+        let expected = "$scrypt$ln=4,r=1,p=1$AAECAwQFBgcICQoLDA0ODw$\
+                        lFEuM8RaBUmZHelzdbTRsexKGdJd7ykiDRDzFEWdrME";
+        let salt: Vec<u8> = (0..16).collect();
+        let hashed = scrypt_phc("password", &salt, &ScryptParams::new(4, 1, 1));
+        assert_eq!(hashed, expected);
+        assert_eq!(scrypt_check("password", &hashed), Ok(true));
+        assert_eq!(scrypt_check("wrong", &hashed), Ok(false));
+
+        // Missing, invalid, too costly or unknown parameters, and a missing hash, are errors.
+        let salt_and_hash = "$AAECAwQFBgcICQoLDA0ODw$lFEuM8RaBUmZHelzdbTRsexKGdJd7ykiDRDzFEWdrME";
+        for params in ["ln=4,r=1", "ln=4,r=0,p=1", "ln=40,r=1,p=1", "ln=4,r=1,p=1,x=2"].iter() {
+            let hashed = format!("$scrypt${}{}", params, salt_and_hash);
+            assert!(scrypt_check("password", &hashed).is_err(), "{}", hashed);
+        }
+        assert!(scrypt_check("password", "$scrypt$ln=4,r=1,p=1$AAECAwQFBgcICQoLDA0ODw").is_err());
+    }
+
+    #[test]
+    fn test_params_validation() {
+        assert!(ScryptParams::try_new(14, 8, 1).is_ok());
+        assert!(ScryptParams::try_new(0, 8, 1).is_err());
+        assert!(ScryptParams::try_new(14, 0, 1).is_err());
+        assert!(ScryptParams::try_new(14, 8, 0).is_err());
+        // N must be less than 2^(16 * r)
+        assert!(ScryptParams::try_new(15, 1, 1).is_ok());
+        assert!(ScryptParams::try_new(16, 1, 1).is_err());
+        // r * p must be less than 2^30
+        assert!(ScryptParams::try_new(1, 1 << 15, 1 << 15).is_err());
+        assert!(ScryptParams::try_new(255, 0xffffffff, 1).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_panics_on_invalid_params() {
+        ScryptParams::new(14, 0, 1);
+    }
+
+    #[test]
+    fn test_scrypt_check() {
+        // Generated with Python's hashlib.scrypt(b"password", salt=bytes(range(16)), ...)
+        let compact = "$rscrypt$0$BwgB$AAECAwQFBgcICQoLDA0ODw==$\
+                       5IVl61vB3LFTLdCjbnmd/IAe7B9NGh1uhq8cM3fEMH0=$";
+        assert_eq!(scrypt_check("password", compact), Ok(true));
+        assert_eq!(scrypt_check("wrong", compact), Ok(false));
+
+        let expanded = "$rscrypt$1$AwEAAAAAAQAA$AAECAwQFBgcICQoLDA0ODw==$\
+                        +uYyxo4y2QrhQ3i3bn31MuCPc4Z9N5i0aCqIFGUcz7w=$";
+        assert_eq!(scrypt_check("password", expanded), Ok(true));
+        assert_eq!(scrypt_check("wrong", expanded), Ok(false));
+
+        assert!(scrypt_check("password", "$rscrypt$0$BwgB$").is_err());
+
+        // Parameters from the hash are validated before any work is done: r = 0, p = 0,
+        // log_n = 0 and a 2^40 * 128 byte table are all rejected.
+        let salt_and_hash = "AAECAwQFBgcICQoLDA0ODw==$\
+                             5IVl61vB3LFTLdCjbnmd/IAe7B9NGh1uhq8cM3fEMH0=$";
+        for p in ["BwAB", "BwgA", "AAgB", "KAEB"].iter() {
+            let hashed = format!("$rscrypt$0${}${}", p, salt_and_hash);
+            assert!(scrypt_check("password", &hashed).is_err());
+        }
+    }
+}
@@ -556,6 +556,22 @@ mod tests {
         let mut sh = Sha1::new();
         test_digest_1million_random(&mut sh, 64, "34aa973cd4c4daa4f61eeb2bdbad27316534016f");
     }
+
+    #[test]
+    fn test_length_near_limit() {
+        let mut sh = Sha1::new();
+        sh.length_bits = u64::max_value() - 15;
+        sh.input(b"a");
+        assert_eq!(sh.length_bits, u64::max_value() - 7);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_length_overflow() {
+        let mut sh = Sha1::new();
+        sh.length_bits = u64::max_value() - 15;
+        sh.input(b"ab");
+    }
 }
 
 #[cfg(all(test, feature = "with-bench"))]
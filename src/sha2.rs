@@ -71,10 +71,12 @@ assert_eq!(hex,
  */
 
 use cryptoutil::{
-    add_bytes_to_bits, add_bytes_to_bits_tuple, read_u32v_be, read_u64v_be, write_u32_be,
-    write_u64_be, FixedBuffer, FixedBuffer128, FixedBuffer64, StandardPadding,
+    add_bytes_to_bits, add_bytes_to_bits_tuple, copy_memory, read_u32v_be, read_u64v_be,
+    write_u32_be, write_u64_be, FixedBuffer, FixedBuffer128, FixedBuffer64, StandardPadding,
 };
-use digest::Digest;
+use digest::{Digest, DigestState};
+use hmac::Hmac;
+use mac::Mac;
 
 use simd::{u32x4, u64x2};
 
@@ -652,6 +654,7 @@ pub fn sha512_digest_block(state: &mut [u64; 8], block: &[u8]) {
     read_u64v_be(&mut block2[..], block);
     sha512_digest_block_u64(state, &block2);
 }
+use sr_std::iter::repeat;
 use sr_std::marker::*;
 use sr_std::prelude::*;
 // A structure that represents that state of a digest computation for the SHA-2 512 family
@@ -857,6 +860,40 @@ impl Engine512 {
 
         self.finished = true;
     }
+
+    // The saved state is the chaining variables and the 128 bit message length in bits, all
+    // big-endian, followed by the input buffered towards the next block.
+    fn save_state(&self) -> Vec<u8> {
+        assert!(!self.finished);
+        let mut buffer = self.buffer;
+        let pending = buffer.current_buffer();
+        let mut state: Vec<u8> = repeat(0).take(80 + pending.len()).collect();
+        for (i, h) in self.state.h.iter().enumerate() {
+            write_u64_be(&mut state[i * 8..(i + 1) * 8], *h);
+        }
+        write_u64_be(&mut state[64..72], self.length_bits.0);
+        write_u64_be(&mut state[72..80], self.length_bits.1);
+        copy_memory(pending, &mut state[80..]);
+        state
+    }
+
+    fn restore_state(state: &[u8]) -> Option<Engine512> {
+        if state.len() < 80 {
+            return None;
+        }
+        let mut length_bits = [0u64; 2];
+        read_u64v_be(&mut length_bits, &state[64..80]);
+        if length_bits[1] % 8 != 0 || state.len() != 80 + ((length_bits[1] / 8) % 128) as usize {
+            return None;
+        }
+        let mut h = [0u64; STATE_LEN];
+        read_u64v_be(&mut h, &state[..64]);
+        let mut engine = Engine512::new(&h);
+        engine.length_bits = (length_bits[0], length_bits[1]);
+        // Less than a block, so no block is processed
+        engine.buffer.input(&state[80..], |_: &[u8]| {});
+        Some(engine)
+    }
 }
 
 /// The SHA-512 hash algorithm with the SHA-512 initial hash value.
@@ -874,6 +911,36 @@ impl Sha512 {
             engine: Engine512::new(&H512),
         }
     }
+
+    /**
+     * Serialize the state of an unfinished digest so that hashing can be resumed later, for
+     * example after a crash, with restore_state(). Panics if result() has already been called.
+     *
+     * The state contains up to a block of the most recent input and is enough to compute the
+     * hash of the input followed by anything else, so it must be protected like the data being
+     * hashed.
+     */
+    pub fn save_state(&self) -> Vec<u8> {
+        self.engine.save_state()
+    }
+
+    /**
+     * Resume a digest from a state produced by save_state(). Returns None if the state is
+     * malformed.
+     */
+    pub fn restore_state(state: &[u8]) -> Option<Sha512> {
+        Engine512::restore_state(state).map(|engine| Sha512 { engine: engine })
+    }
+}
+
+impl DigestState for Sha512 {
+    fn save_state(&self) -> Vec<u8> {
+        Sha512::save_state(self)
+    }
+
+    fn restore_state(state: &[u8]) -> Option<Sha512> {
+        Sha512::restore_state(state)
+    }
 }
 
 impl Digest for Sha512 {
@@ -962,6 +1029,10 @@ impl Digest for Sha384 {
     fn block_size(&self) -> usize {
         128
     }
+
+    fn internal_state_len(&self) -> usize {
+        64
+    }
 }
 
 static H384: [u64; STATE_LEN] = [
@@ -1017,6 +1088,22 @@ impl Digest for Sha512Trunc256 {
     fn block_size(&self) -> usize {
         128
     }
+
+    fn internal_state_len(&self) -> usize {
+        64
+    }
+}
+
+/**
+ * HMAC-SHA-512/256 of data under key. HMAC pads the key to the 128 byte SHA-512 block size, so the
+ * result differs from HMAC-SHA-512 truncated to 32 bytes, which uses different initial values.
+ */
+pub fn hmac_sha512_256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    let mut mac = Hmac::new(Sha512Trunc256::new(), key);
+    mac.input(data);
+    let mut out = [0u8; 32];
+    mac.raw_result(&mut out);
+    out
 }
 
 static H512_TRUNC_256: [u64; STATE_LEN] = [
@@ -1072,6 +1159,10 @@ impl Digest for Sha512Trunc224 {
     fn block_size(&self) -> usize {
         128
     }
+
+    fn internal_state_len(&self) -> usize {
+        64
+    }
 }
 
 static H512_TRUNC_224: [u64; STATE_LEN] = [
@@ -1188,6 +1279,39 @@ impl Engine256 {
 
         self.finished = true;
     }
+
+    // The saved state is the chaining variables and the 64 bit message length in bits, all
+    // big-endian, followed by the input buffered towards the next block.
+    fn save_state(&self) -> Vec<u8> {
+        assert!(!self.finished);
+        let mut buffer = self.buffer;
+        let pending = buffer.current_buffer();
+        let mut state: Vec<u8> = repeat(0).take(40 + pending.len()).collect();
+        for (i, h) in self.state.h.iter().enumerate() {
+            write_u32_be(&mut state[i * 4..(i + 1) * 4], *h);
+        }
+        write_u64_be(&mut state[32..40], self.length_bits);
+        copy_memory(pending, &mut state[40..]);
+        state
+    }
+
+    fn restore_state(state: &[u8]) -> Option<Engine256> {
+        if state.len() < 40 {
+            return None;
+        }
+        let mut length_bits = [0u64; 1];
+        read_u64v_be(&mut length_bits, &state[32..40]);
+        if length_bits[0] % 8 != 0 || state.len() != 40 + ((length_bits[0] / 8) % 64) as usize {
+            return None;
+        }
+        let mut h = [0u32; STATE_LEN];
+        read_u32v_be(&mut h, &state[..32]);
+        let mut engine = Engine256::new(&h);
+        engine.length_bits = length_bits[0];
+        // Less than a block, so no block is processed
+        engine.buffer.input(&state[40..], |_: &[u8]| {});
+        Some(engine)
+    }
 }
 
 /// The SHA-256 hash algorithm with the SHA-256 initial hash value.
@@ -1205,6 +1329,36 @@ impl Sha256 {
             engine: Engine256::new(&H256),
         }
     }
+
+    /**
+     * Serialize the state of an unfinished digest so that hashing can be resumed later, for
+     * example after a crash, with restore_state(). Panics if result() has already been called.
+     *
+     * The state contains up to a block of the most recent input and is enough to compute the
+     * hash of the input followed by anything else, so it must be protected like the data being
+     * hashed.
+     */
+    pub fn save_state(&self) -> Vec<u8> {
+        self.engine.save_state()
+    }
+
+    /**
+     * Resume a digest from a state produced by save_state(). Returns None if the state is
+     * malformed.
+     */
+    pub fn restore_state(state: &[u8]) -> Option<Sha256> {
+        Engine256::restore_state(state).map(|engine| Sha256 { engine: engine })
+    }
+}
+
+impl DigestState for Sha256 {
+    fn save_state(&self) -> Vec<u8> {
+        Sha256::save_state(self)
+    }
+
+    fn restore_state(state: &[u8]) -> Option<Sha256> {
+        Sha256::restore_state(state)
+    }
 }
 
 impl Digest for Sha256 {
@@ -1286,6 +1440,10 @@ impl Digest for Sha224 {
     fn block_size(&self) -> usize {
         64
     }
+
+    fn internal_state_len(&self) -> usize {
+        32
+    }
 }
 
 static H224: [u32; STATE_LEN] = [
@@ -1294,9 +1452,11 @@ static H224: [u32; STATE_LEN] = [
 
 #[cfg(test)]
 mod tests {
-    use cryptoutil::test::test_digest_1million_random;
+    use cryptoutil::test::{test_digest_1million_random, test_digest_large_single_input};
+    use cryptoutil::write_u64_be;
     use digest::Digest;
-    use sha2::{Sha224, Sha256, Sha384, Sha512, Sha512Trunc224, Sha512Trunc256};
+    use sr_std::iter::repeat;
+    use sha2::{hmac_sha512_256, Sha224, Sha256, Sha384, Sha512, Sha512Trunc224, Sha512Trunc256};
 
     struct Test {
         input: &'static str,
@@ -1381,6 +1541,68 @@ mod tests {
         test_hash(&mut *sh, &tests[..]);
     }
 
+    #[test]
+    fn test_sha384_nist_and_padding_boundary() {
+        let a111: String = repeat('a').take(111).collect();
+        let a112: String = repeat('a').take(112).collect();
+        let tests: [(&str, &str); 4] = [
+            (
+                "abc",
+                "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7",
+            ),
+            (
+                "abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+                "09330c33f71147e83d192fc782cd1b4753111b173b3b05d22fa08086e3b0f712fcc7c71a557e2db966c3e9fa91746039",
+            ),
+            // 888 bits: the padding byte and the 128 bit length still fit in the same block.
+            (
+                &a111[..],
+                "3c37955051cb5c3026f94d551d5b5e2ac38d572ae4e07172085fed81f8466b8f90dc23a8ffcdea0b8d8e58e8fdacc80a",
+            ),
+            // 896 bits: one byte more forces the length into an additional block.
+            (
+                &a112[..],
+                "187d4e07cb306103c69967bf544d0dfbe9042577599c73c330abc0cb64c61236d5ed565ee19119d8c31779a38f791fcd",
+            ),
+        ];
+
+        let mut sh = Sha384::new();
+        for &(input, output_str) in tests.iter() {
+            sh.input_str(input);
+            assert_eq!(sh.result_str(), output_str);
+            sh.reset();
+
+            // Single byte chunks must give the same result as one call.
+            for b in input.as_bytes().iter() {
+                sh.input(&[*b]);
+            }
+            assert_eq!(sh.result_str(), output_str);
+            sh.reset();
+        }
+    }
+
+    #[test]
+    fn test_sha384_reset_reuse() {
+        let mut sh = Sha384::new();
+        sh.input_str("abc");
+        let first = sh.result_str();
+
+        sh.reset();
+        sh.input_str("some buffered data that is never finished");
+        sh.reset();
+        sh.input_str("abc");
+        assert_eq!(sh.result_str(), first);
+    }
+
+    #[test]
+    fn test_1million_random_sha384() {
+        let mut sh = Sha384::new();
+        test_digest_1million_random(
+            &mut sh,
+            128,
+            "9d0e1809716474cb086e834e310a4a1ced149e9c00f248527972cec5704c2a5b07b8b3dc38ecc4ebae97ddd87f3d8985");
+    }
+
     #[test]
     fn test_sha512_256() {
         // Examples from wikipedia
@@ -1406,6 +1628,30 @@ mod tests {
         test_hash(&mut *sh, &tests[..]);
     }
 
+    #[test]
+    fn test_hmac_sha512_256() {
+        assert_eq!(Sha512Trunc256::new().block_size(), 128);
+
+        // Keys from RFC 4231 test cases 1 and 6; outputs from Python's hmac with sha512_256.
+        let mac = hmac_sha512_256(&[0x0b; 20], b"Hi There");
+        let expected = [
+            0x9f, 0x91, 0x26, 0xc3, 0xd9, 0xc3, 0xc3, 0x30, 0xd7, 0x60, 0x42, 0x5c, 0xa8, 0xa2,
+            0x17, 0xe3, 0x1f, 0xea, 0xe3, 0x1b, 0xfe, 0x70, 0x19, 0x6f, 0xf8, 0x16, 0x42, 0xb8,
+            0x68, 0x40, 0x2e, 0xab,
+        ];
+        assert_eq!(mac, expected);
+
+        let key: Vec<u8> = repeat(0xaa).take(131).collect();
+        let mac =
+            hmac_sha512_256(&key, b"Test Using Larger Than Block-Size Key - Hash Key First");
+        let expected = [
+            0x87, 0x12, 0x3c, 0x45, 0xf7, 0xc5, 0x37, 0xa4, 0x04, 0xf8, 0xf4, 0x7c, 0xdb, 0xed,
+            0xda, 0x1f, 0xc9, 0xbe, 0xc6, 0x0e, 0xeb, 0x97, 0x19, 0x82, 0xce, 0x7e, 0xf1, 0x0e,
+            0x77, 0x4e, 0x65, 0x39,
+        ];
+        assert_eq!(mac, expected);
+    }
+
     #[test]
     fn test_sha512_224() {
         // Examples from wikipedia
@@ -1481,6 +1727,71 @@ mod tests {
         test_hash(&mut *sh, &tests[..]);
     }
 
+    #[test]
+    fn test_sha224_nist() {
+        // Examples from FIPS 180-4 / NIST CSRC example values. The 448 and 896 bit messages
+        // exercise the case where the length does not fit in the final block.
+        let nist_tests = [
+            Test {
+                input: "",
+                output_str: "d14a028c2a3a2bc9476102bb288234c415a2b01f828ea62ac5b3e42f",
+            },
+            Test {
+                input: "abc",
+                output_str: "23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7",
+            },
+            Test {
+                input: "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+                output_str: "75388b16512776cc5dba5da1fd890150b0c6455cb4f58b1952522525",
+            },
+            Test {
+                input: "abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+                output_str: "c97ca9a559850ce97a04a96def6d99a9e0e0e2ab14e6b8df265fc0b3",
+            },
+        ];
+
+        let mut sh = Sha224::new();
+        assert_eq!(sh.output_bytes(), 28);
+
+        for t in nist_tests.iter() {
+            sh.input_str(t.input);
+            assert_eq!(sh.result_str(), t.output_str);
+            sh.reset();
+        }
+    }
+
+    #[test]
+    fn test_sha224_reset_reuse() {
+        let mut sh = Sha224::new();
+
+        // Leave some unprocessed data in the buffer before resetting.
+        sh.input_str("The quick brown fox jumps over the lazy dog");
+        sh.reset();
+        sh.input_str("abc");
+        assert_eq!(
+            sh.result_str(),
+            "23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7"
+        );
+
+        // A finished instance must produce the same digest as a fresh one after reset.
+        sh.reset();
+        sh.input_str("abc");
+        assert_eq!(
+            sh.result_str(),
+            "23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7"
+        );
+    }
+
+    #[test]
+    fn test_1million_random_sha224() {
+        let mut sh = Sha224::new();
+        test_digest_1million_random(
+            &mut sh,
+            64,
+            "20794655980c91d8bbb4c1ea97618a4bf03f42581948b2ee4ee7ad67",
+        );
+    }
+
     #[test]
     fn test_1million_random_sha512() {
         let mut sh = Sha512::new();
@@ -1490,6 +1801,12 @@ mod tests {
             "e718483d0ce769644e2e42c7bc15b4638e1f98b13b2044285632a803afa973ebde0ff244877ea60a4cb0432ce577c31beb009c5c2c49aa2e4eadb217ad8cc09b");
     }
 
+    #[test]
+    fn test_large_single_input() {
+        test_digest_large_single_input(&mut Sha256::new());
+        test_digest_large_single_input(&mut Sha512::new());
+    }
+
     #[test]
     fn test_1million_random_sha256() {
         let mut sh = Sha256::new();
@@ -1499,6 +1816,130 @@ mod tests {
             "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0",
         );
     }
+
+    #[test]
+    fn test_length_near_limit() {
+        let mut sh = Sha256::new();
+        sh.engine.length_bits = u64::max_value() - 15;
+        sh.input(b"a");
+        assert_eq!(sh.engine.length_bits, u64::max_value() - 7);
+
+        let mut sh = Sha512::new();
+        sh.engine.length_bits = (u64::max_value(), u64::max_value() - 15);
+        sh.input(b"a");
+        assert_eq!(sh.engine.length_bits, (u64::max_value(), u64::max_value() - 7));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sha256_length_overflow() {
+        let mut sh = Sha256::new();
+        sh.engine.length_bits = u64::max_value() - 15;
+        sh.input(b"ab");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sha512_length_overflow() {
+        let mut sh = Sha512::new();
+        sh.engine.length_bits = (u64::max_value(), u64::max_value() - 15);
+        sh.input(b"ab");
+    }
+
+    #[test]
+    fn test_save_restore_state() {
+        let data: Vec<u8> = (0..1000u32).map(|i| (i * 7 + 3) as u8).collect();
+        for &split in [0, 1, 64, 127, 128, 333, 1000].iter() {
+            let mut sh = Sha256::new();
+            sh.input(&data[..split]);
+            let state = sh.save_state();
+            assert_eq!(state.len(), 40 + split % 64);
+            let mut resumed = Sha256::restore_state(&state[..]).unwrap();
+            resumed.input(&data[split..]);
+            let mut one_shot = Sha256::new();
+            one_shot.input(&data[..]);
+            assert_eq!(resumed.result_str(), one_shot.result_str());
+
+            let mut sh = Sha512::new();
+            sh.input(&data[..split]);
+            let state = sh.save_state();
+            assert_eq!(state.len(), 80 + split % 128);
+            let mut resumed = Sha512::restore_state(&state[..]).unwrap();
+            resumed.input(&data[split..]);
+            let mut one_shot = Sha512::new();
+            one_shot.input(&data[..]);
+            assert_eq!(resumed.result_str(), one_shot.result_str());
+        }
+    }
+
+    #[test]
+    fn test_restore_state_rejects_malformed() {
+        let mut sh = Sha256::new();
+        sh.input(b"abc");
+        let state = sh.save_state();
+        assert!(Sha256::restore_state(&state[..state.len() - 1]).is_none());
+        assert!(Sha256::restore_state(&[0; 39]).is_none());
+        let mut odd_bits = state.clone();
+        odd_bits[39] ^= 1;
+        assert!(Sha256::restore_state(&odd_bits[..]).is_none());
+
+        let mut sh = Sha512::new();
+        sh.input(b"abc");
+        let state = sh.save_state();
+        assert!(Sha512::restore_state(&state[..state.len() - 1]).is_none());
+        assert!(Sha512::restore_state(&[0; 79]).is_none());
+    }
+
+    #[test]
+    fn test_internal_state_len() {
+        assert_eq!(Sha256::new().internal_state_len(), 32);
+        assert_eq!(Sha224::new().internal_state_len(), 32);
+        assert_eq!(Sha512::new().internal_state_len(), 64);
+        assert_eq!(Sha384::new().internal_state_len(), 64);
+        assert_eq!(Sha512Trunc256::new().internal_state_len(), 64);
+        assert_eq!(Sha512Trunc224::new().internal_state_len(), 64);
+    }
+
+    // Why SHA-256(key || message) is not a MAC: given only its output and the length of
+    // key || message, the hash of a longer message can be forged without the key.
+    #[test]
+    fn test_length_extension() {
+        let secret = b"sixteen byte key";
+        let message = b"user=alice&amount=10";
+        let mut sh = Sha256::new();
+        sh.input(secret);
+        sh.input(message);
+        let mut tag = [0u8; 32];
+        sh.result(&mut tag);
+        assert_eq!(sh.internal_state_len(), tag.len());
+
+        // The attacker knows tag and the length of secret || message, but not secret.
+        let len = secret.len() + message.len();
+        let mut glue: Vec<u8> = vec![0x80];
+        while (len + glue.len()) % 64 != 56 {
+            glue.push(0);
+        }
+        let mut bits = [0u8; 8];
+        write_u64_be(&mut bits, (len * 8) as u64);
+        glue.extend_from_slice(&bits);
+        // Resume from the output as the chaining value, with everything so far processed.
+        let mut state = tag[..sh.internal_state_len()].to_vec();
+        write_u64_be(&mut bits, ((len + glue.len()) * 8) as u64);
+        state.extend_from_slice(&bits);
+        let mut forged = Sha256::restore_state(&state[..]).unwrap();
+        forged.input(b"&amount=1000000");
+        let mut forged_tag = [0u8; 32];
+        forged.result(&mut forged_tag);
+
+        let mut sh = Sha256::new();
+        sh.input(secret);
+        sh.input(message);
+        sh.input(&glue[..]);
+        sh.input(b"&amount=1000000");
+        let mut expected = [0u8; 32];
+        sh.result(&mut expected);
+        assert_eq!(forged_tag, expected);
+    }
 }
 
 #[cfg(all(test, feature = "with-bench"))]
@@ -47,7 +47,7 @@ assert_eq!(hex, "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe2451143153
 
 use sr_std::cmp;
 
-use cryptoutil::{read_u64v_le, write_u64v_le, zero};
+use cryptoutil::{read_u64_le, read_u64v_le, write_u64v_le, zero};
 use digest::Digest;
 
 const B: usize = 200;
@@ -91,16 +91,20 @@ fn rotl64(v: u64, n: usize) -> u64 {
     ((v << (n % 64)) & 0xffffffffffffffff) ^ (v >> (64 - (n % 64)))
 }
 
-// Code based on Keccak-compact64.c from ref implementation.
 fn keccak_f(state: &mut [u8]) {
     //assert!(state.len() == B);
 
     let mut s: [u64; 25] = [0; 25];
+    read_u64v_le(&mut s, state);
+    keccak_p(&mut s);
+    write_u64v_le(state, &s);
+}
+
+// Code based on Keccak-compact64.c from ref implementation.
+fn keccak_p(s: &mut [u64; 25]) {
     let mut t: [u64; 1] = [0; 1];
     let mut c: [u64; 5] = [0; 5];
 
-    read_u64v_le(&mut s, state);
-
     for round in 0..NROUNDS {
         // Theta
         for x in 0..5 {
@@ -134,9 +138,27 @@ fn keccak_f(state: &mut [u8]) {
         // Iota
         s[0] = s[0] ^ RC[round];
     }
+}
 
+// Absorb the whole rate sized blocks at the start of data into a state holding no buffered
+// input, and return the number of bytes absorbed. The state stays in lanes across the
+// permutations instead of being converted to and from bytes for each block. rate must be a
+// multiple of 8.
+fn absorb_blocks(state: &mut [u8; B], rate: usize, data: &[u8]) -> usize {
+    let mut s: [u64; 25] = [0; 25];
+    read_u64v_le(&mut s, state);
+    let mut pos = 0;
+    while data.len() - pos >= rate {
+        for (lane, chunk) in s.iter_mut().zip(data[pos..pos + rate].chunks(8)) {
+            *lane ^= read_u64_le(chunk);
+        }
+        keccak_p(&mut s);
+        pos += rate;
+    }
     write_u64v_le(state, &s);
+    pos
 }
+use sr_std::iter::repeat;
 use sr_std::marker::*;
 use sr_std::prelude::*;
 /// SHA-3 Modes.
@@ -322,7 +344,7 @@ impl Sha3 {
 
         let p_len = pad_len(ds_len, self.offset * 8, self.rate() * 8);
 
-        let mut p: Vec<u8> = vec![0; p_len];
+        let mut p: Vec<u8> = repeat(0).take(p_len).collect();
 
         if ds_len != 0 {
             set_domain_sep(self.output_bits(), &mut p);
@@ -355,6 +377,10 @@ impl Digest for Sha3 {
         // Absorb
         while in_pos < in_len {
             let offset = self.offset;
+            if offset == 0 && in_len - in_pos >= r {
+                in_pos += absorb_blocks(&mut self.state, r, &data[in_pos..]);
+                continue;
+            }
             let nread = cmp::min(r - offset, in_len - in_pos);
             for i in 0..nread {
                 self.state[offset + i] = self.state[offset + i] ^ data[in_pos + i];
@@ -439,6 +465,10 @@ impl Digest for Sha3 {
     fn block_size(&self) -> usize {
         B - self.mode.capacity()
     }
+
+    fn internal_state_len(&self) -> usize {
+        B
+    }
 }
 
 impl Copy for Sha3 {}
@@ -449,10 +479,210 @@ impl Clone for Sha3 {
     }
 }
 
+/// A Keccak sponge XOF with a caller chosen rate and domain separation byte, for building custom
+/// sponge based KDFs. `Sha3XofCustom::new(136, 0x1f)` is SHAKE256 and `new(168, 0x1f)` is
+/// SHAKE128.
+///
+/// The security level of a sponge is half its capacity, and the capacity is `200 - rate_bytes`
+/// bytes. Rates above the standard ones therefore weaken the construction: a rate of 192 leaves
+/// only 32 bits of security. Nonstandard rates or domain bytes also produce outputs that no
+/// other implementation will reproduce. Prefer SHAKE128 or SHAKE256 unless a protocol demands
+/// otherwise.
+#[derive(Clone, Copy)]
+pub struct Sha3XofCustom {
+    state: [u8; B],
+    rate: usize,
+    domain: u8,
+    offset: usize,
+    squeezing: bool,
+}
+
+impl Sha3XofCustom {
+    /// Create a sponge absorbing and squeezing rate_bytes per permutation. rate_bytes must be a
+    /// nonzero multiple of 8 below 200. domain holds the domain separation bits followed by the
+    /// first padding bit, as in FIPS 202 (0x1f for SHAKE, 0x06 for SHA-3, 0x01 for Keccak), so it
+    /// must be nonzero and below 0x80.
+    pub fn new(rate_bytes: usize, domain: u8) -> Sha3XofCustom {
+        assert!(rate_bytes > 0 && rate_bytes < B && rate_bytes % 8 == 0);
+        assert!(domain != 0 && domain < 0x80);
+        Sha3XofCustom {
+            state: [0; B],
+            rate: rate_bytes,
+            domain: domain,
+            offset: 0,
+            squeezing: false,
+        }
+    }
+
+    fn pad(&mut self) {
+        self.state[self.offset] ^= self.domain;
+        self.state[self.rate - 1] ^= 0x80;
+        keccak_f(&mut self.state);
+        self.offset = 0;
+        self.squeezing = true;
+    }
+}
+
+impl Digest for Sha3XofCustom {
+    /// Absorb more input. Input supplied after the first call to result() is ignored, as with
+    /// the SHAKE modes of Sha3.
+    fn input(&mut self, data: &[u8]) {
+        if self.squeezing {
+            return;
+        }
+        let mut data = data;
+        while !data.is_empty() {
+            if self.offset == 0 && data.len() >= self.rate {
+                let n = absorb_blocks(&mut self.state, self.rate, data);
+                data = &data[n..];
+                continue;
+            }
+            self.state[self.offset] ^= data[0];
+            data = &data[1..];
+            self.offset += 1;
+            if self.offset == self.rate {
+                keccak_f(&mut self.state);
+                self.offset = 0;
+            }
+        }
+    }
+
+    /// Squeeze out.len() more bytes of output. Successive calls continue the output stream.
+    fn result(&mut self, out: &mut [u8]) {
+        if !self.squeezing {
+            self.pad();
+        }
+        for b in out.iter_mut() {
+            if self.offset == self.rate {
+                keccak_f(&mut self.state);
+                self.offset = 0;
+            }
+            *b = self.state[self.offset];
+            self.offset += 1;
+        }
+    }
+
+    fn reset(&mut self) {
+        zero(&mut self.state);
+        self.offset = 0;
+        self.squeezing = false;
+    }
+
+    fn output_bits(&self) -> usize {
+        0
+    }
+
+    fn block_size(&self) -> usize {
+        self.rate
+    }
+
+    fn internal_state_len(&self) -> usize {
+        B
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use cryptoutil::test::test_digest_large_single_input;
     use digest::Digest;
-    use sha3::{Sha3, Sha3Mode};
+    use sha3::{Sha3, Sha3Mode, Sha3XofCustom};
+    use sr_std::iter::repeat;
+    use sr_std::prelude::*;
+
+    #[test]
+    fn test_custom_xof_is_shake256() {
+        // SHAKE256("abc"), from Python's hashlib.
+        let expected_prefix = [
+            0x48, 0x33, 0x66, 0x60, 0x13, 0x60, 0xa8, 0x77, 0x1c, 0x68, 0x63, 0x08, 0x0c, 0xc4,
+            0x11, 0x4d, 0x8d, 0xb4, 0x45, 0x30, 0xf8, 0xf1, 0xe1, 0xee, 0x4f, 0x94, 0xea, 0x37,
+            0xe7, 0x8b, 0x57, 0x39,
+        ];
+        let mut custom = Sha3XofCustom::new(136, 0x1f);
+        custom.input(b"a");
+        custom.input(b"bc");
+        // Squeeze in pieces that cross the 136 byte rate boundary.
+        let mut out: Vec<u8> = repeat(0).take(300).collect();
+        custom.result(&mut out[..100]);
+        custom.result(&mut out[100..]);
+        assert_eq!(&out[..32], &expected_prefix[..]);
+
+        let mut shake = Sha3::shake_256();
+        shake.input(b"abc");
+        let mut expected: Vec<u8> = repeat(0).take(300).collect();
+        shake.result(&mut expected[..]);
+        assert_eq!(out, expected);
+
+        // Absorbing more than one block.
+        let data: Vec<u8> = (0..500u32).map(|i| i as u8).collect();
+        custom.reset();
+        custom.input(&data[..]);
+        custom.result(&mut out[..]);
+        let mut shake = Sha3::shake_256();
+        shake.input(&data[..]);
+        shake.result(&mut expected[..]);
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_large_single_input() {
+        test_digest_large_single_input(&mut Sha3::sha3_256());
+        test_digest_large_single_input(&mut Sha3::keccak512());
+    }
+
+    #[test]
+    fn test_bulk_absorb_matches_bytewise() {
+        // 2 MiB made of a repeated 64 KiB block whose length is not a multiple of any rate, so
+        // bulk input mixes whole blocks with buffered tails. The expected values are from
+        // Python's hashlib.
+        let block: Vec<u8> = (0..65536 + 13)
+            .map(|i: usize| ((i * 7) ^ (i >> 8)) as u8)
+            .collect();
+        let tests = [
+            (
+                Sha3Mode::Sha3_256,
+                136,
+                0x06,
+                "b9fcd951d7d0c6af3f52cbbf23aa80b9f532f997f70e5e2a7e38f15fd190b3d1",
+            ),
+            (
+                Sha3Mode::Shake128,
+                168,
+                0x1f,
+                "92e54570434b1c8d4e23000750fb6bc0f4dd7ca155bf64574ec4a97be5088d36",
+            ),
+        ];
+        for &(mode, rate, domain, expected) in tests.iter() {
+            let mut sha3_bulk = Sha3::new(mode);
+            let mut sha3_bytewise = Sha3::new(mode);
+            let mut custom_bulk = Sha3XofCustom::new(rate, domain);
+            let mut custom_bytewise = Sha3XofCustom::new(rate, domain);
+            for _ in 0..32 {
+                sha3_bulk.input(&block);
+                custom_bulk.input(&block);
+                for b in block.chunks(1) {
+                    sha3_bytewise.input(b);
+                    custom_bytewise.input(b);
+                }
+            }
+            let mut digests: [&mut dyn Digest; 4] = [
+                &mut sha3_bulk,
+                &mut sha3_bytewise,
+                &mut custom_bulk,
+                &mut custom_bytewise,
+            ];
+            for d in digests.iter_mut() {
+                let mut out = [0u8; 32];
+                d.result(&mut out);
+                assert_eq!(hex::encode(&out[..]), expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_custom_xof_rejects_bad_rate() {
+        Sha3XofCustom::new(140, 0x1f);
+    }
 
     struct Test {
         input: &'static str,
@@ -6759,3 +6989,42 @@ mod tests {
         test_hash(&mut *sh, &test_cases[..]);
     }
 }
+
+#[cfg(all(test, feature = "with-bench"))]
+mod bench {
+    use digest::Digest;
+    use sha3::{Sha3, Sha3XofCustom};
+    use test::Bencher;
+
+    #[bench]
+    pub fn sha3_256_64k(bh: &mut Bencher) {
+        let mut sh = Sha3::sha3_256();
+        let bytes = [1u8; 65536];
+        bh.iter(|| {
+            sh.input(&bytes);
+        });
+        bh.bytes = bytes.len() as u64;
+    }
+
+    #[bench]
+    pub fn sha3_256_64k_bytewise(bh: &mut Bencher) {
+        let mut sh = Sha3::sha3_256();
+        let bytes = [1u8; 65536];
+        bh.iter(|| {
+            for b in bytes.chunks(1) {
+                sh.input(b);
+            }
+        });
+        bh.bytes = bytes.len() as u64;
+    }
+
+    #[bench]
+    pub fn custom_xof_64k(bh: &mut Bencher) {
+        let mut sh = Sha3XofCustom::new(168, 0x1f);
+        let bytes = [1u8; 65536];
+        bh.iter(|| {
+            sh.input(&bytes);
+        });
+        bh.bytes = bytes.len() as u64;
+    }
+}
@@ -2441,6 +2441,7 @@ impl Decryptor for Sosemanuk {
 
 #[cfg(test)]
 mod test {
+    use cryptoutil::test::test_stream_cipher;
     use sosemanuk::Sosemanuk;
     use symmetriccipher::SynchronousStreamCipher;
 
@@ -2461,7 +2462,7 @@ mod test {
         let mut sosemanuk = Sosemanuk::new(key.as_ref(), nonce.as_ref());
         sosemanuk.process(&input, &mut output);
         let expected: &[u8] = expected_output.as_ref();
-        //assert!(output.as_ref() == expected);
+        assert!(output.as_ref() == expected);
     }
 
     #[test]
@@ -2478,7 +2479,7 @@ mod test {
         let mut sosemanuk = Sosemanuk::new(key.as_ref(), nonce.as_ref());
         sosemanuk.process(&input, &mut output);
         let expected: &[u8] = expected_output.as_ref();
-        //assert!(output.as_ref() == expected);
+        assert!(output.as_ref() == expected);
     }
 
     #[test]
@@ -2496,7 +2497,7 @@ mod test {
         let mut sosemanuk = Sosemanuk::new(key.as_ref(), nonce.as_ref());
         sosemanuk.process(&input, &mut output);
         let expected: &[u8] = expected_output.as_ref();
-        //assert!(output.as_ref() == expected);
+        assert!(output.as_ref() == expected);
     }
 
     #[test]
@@ -2514,7 +2515,7 @@ mod test {
         let mut sosemanuk = Sosemanuk::new(key.as_ref(), nonce.as_ref());
         sosemanuk.process(&input, &mut output);
         let expected: &[u8] = expected_output.as_ref();
-        //assert!(output.as_ref() == expected);
+        assert!(output.as_ref() == expected);
     }
 
     #[test]
@@ -2532,7 +2533,7 @@ mod test {
         let mut sosemanuk = Sosemanuk::new(key.as_ref(), nonce.as_ref());
         sosemanuk.process(&input, &mut output);
         let expected: &[u8] = expected_output.as_ref();
-        //assert!(output.as_ref() == expected);
+        assert!(output.as_ref() == expected);
     }
 
     #[test]
@@ -2550,7 +2551,7 @@ mod test {
         let mut sosemanuk = Sosemanuk::new(key.as_ref(), nonce.as_ref());
         sosemanuk.process(&input, &mut output);
         let expected: &[u8] = expected_output.as_ref();
-        //assert!(output.as_ref() == expected);
+        assert!(output.as_ref() == expected);
     }
 
     // From TEST_VECTOR_128.txt from reference C implementation
@@ -2569,7 +2570,7 @@ mod test {
         let mut sosemanuk = Sosemanuk::new(key.as_ref(), nonce.as_ref());
         sosemanuk.process(&input, &mut output);
         let expected: &[u8] = expected_output.as_ref();
-        //assert!(output.as_ref() == expected);
+        assert!(output.as_ref() == expected);
     }
 
     #[test]
@@ -2586,7 +2587,30 @@ mod test {
         let mut sosemanuk = Sosemanuk::new(key.as_ref(), nonce.as_ref());
         sosemanuk.process(&input, &mut output);
         let expected: &[u8] = expected_output.as_ref();
-        //assert!(output.as_ref() == expected);
+        assert!(output.as_ref() == expected);
+    }
+
+    #[test]
+    fn test_harness() {
+        // eSTREAM Sosemanuk, set 1 vector 0 and set 2 vector 63.
+        let vectors = [
+            (
+                "8000000000000000000000000000000000000000000000000000000000000000",
+                "1782FABFF497A0E89E16E1BCF22F0FE8AA8C566D293AA35B2425E4F26E31C3E7\
+                 701C08A0D614AF3D3861A7DFF7D6A38A0EFE84A29FADF68D390A3D15B75C972D",
+            ),
+            (
+                "3F3F3F3F3F3F3F3F3F3F3F3F3F3F3F3F",
+                "7D755F30A2B747A50D7D28147EDF0B3E3FAB6856A7373C7306C00D1D40769693\
+                 54D7AB4343C0115E7839502C5C699ED06DB119968AEBFD08D8B968A7161D613F",
+            ),
+        ];
+        let nonce = [0u8; 16];
+        for &(key, keystream) in vectors.iter() {
+            let key = hex::decode(key).unwrap();
+            let keystream = hex::decode(keystream).unwrap();
+            test_stream_cipher(|| Sosemanuk::new(&key, &nonce), &keystream);
+        }
     }
 }
 
@@ -0,0 +1,219 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+ * This module encrypts byte streams of any length with the STREAM construction of Hoang,
+ * Reyhanitabar, Rogaway and Vizár, using ChaCha20Poly1305 for each chunk.
+ *
+ * The plaintext is split into chunks of chunk_size bytes, the last of which may be shorter, and is
+ * empty only for an empty plaintext. Chunk i is sealed on its own with the 12 byte nonce
+ *
+ * `nonce_prefix (7 bytes) || be32(i) || last (1 byte, 1 for the final chunk and 0 otherwise)`
+ *
+ * and written as its ciphertext followed by its 16 byte tag. The first 11 bytes of each nonce
+ * come from an aead::PrefixedCounterNonce with a 4 byte counter.
+ *
+ * Because the position and the final flag are part of each nonce, decryption detects chunks that
+ * were reordered, dropped or duplicated, and streams that were truncated at any point.
+ *
+ * A nonce prefix must never be used twice with the same key. Decryption writes each chunk's
+ * plaintext as soon as its tag verifies, so when it fails, whatever was already written must be
+ * discarded.
+ */
+
+use std::io::{self, Read, Write};
+
+use sr_std::iter::repeat;
+use sr_std::prelude::*;
+
+use aead::{AeadDecryptor, AeadEncryptor, AeadError, PrefixedCounterNonce};
+use chacha20poly1305::ChaCha20Poly1305;
+
+const TAG_LEN: usize = 16;
+
+fn chunk_nonces(nonce_prefix: &[u8; 7]) -> PrefixedCounterNonce {
+    PrefixedCounterNonce::new(nonce_prefix, 4)
+}
+
+// The nonce for the next chunk: the prefix and counter, followed by the final chunk flag.
+fn next_nonce(nonces: &mut PrefixedCounterNonce, last: bool) -> io::Result<Vec<u8>> {
+    let mut nonce = nonces
+        .next_nonce()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    nonce.push(last as u8);
+    Ok(nonce)
+}
+
+// Read until buf is full or the reader is exhausted, returning the number of bytes read.
+fn read_full<R: Read>(r: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match r.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+// Fill buf[start..] from r. Returns the number of valid bytes in buf and whether the reader
+// still had data after the first `chunk` of them, which is what decides if that chunk is the
+// last one.
+fn read_chunk<R: Read>(
+    r: &mut R,
+    buf: &mut [u8],
+    start: usize,
+    chunk: usize,
+) -> io::Result<(usize, bool)> {
+    let len = start + read_full(r, &mut buf[start..])?;
+    Ok((len, len > chunk))
+}
+
+fn check_chunk_size(chunk_size: usize) -> io::Result<()> {
+    if chunk_size == 0 {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "chunk_size must not be zero"));
+    }
+    Ok(())
+}
+
+/**
+ * Read plaintext from r until it is exhausted, and write the STREAM encryption of it to w.
+ */
+pub fn encrypt_reader_to_writer<R: Read, W: Write>(
+    key: &[u8; 32],
+    nonce_prefix: &[u8; 7],
+    chunk_size: usize,
+    r: &mut R,
+    w: &mut W,
+) -> io::Result<()> {
+    check_chunk_size(chunk_size)?;
+    // One byte of lookahead tells whether the current chunk is the last.
+    let mut buf: Vec<u8> = repeat(0).take(chunk_size + 1).collect();
+    let mut out: Vec<u8> = repeat(0).take(chunk_size + TAG_LEN).collect();
+    let mut nonces = chunk_nonces(nonce_prefix);
+    let mut start = 0;
+    loop {
+        let (len, more) = read_chunk(r, &mut buf, start, chunk_size)?;
+        let n = if more { chunk_size } else { len };
+        let nonce = next_nonce(&mut nonces, !more)?;
+        let (ct, tag) = out[..n + TAG_LEN].split_at_mut(n);
+        ChaCha20Poly1305::new(key, &nonce, &[]).encrypt(&buf[..n], ct, tag);
+        w.write_all(&out[..n + TAG_LEN])?;
+        if !more {
+            return Ok(());
+        }
+        buf[0] = buf[chunk_size];
+        start = 1;
+    }
+}
+
+/**
+ * Read a STREAM ciphertext produced by encrypt_reader_to_writer() with the same key, nonce prefix
+ * and chunk size from r, and write the plaintext to w. Fails with an InvalidData error if any
+ * chunk does not authenticate, including when the stream was truncated or reordered.
+ */
+pub fn decrypt_reader_to_writer<R: Read, W: Write>(
+    key: &[u8; 32],
+    nonce_prefix: &[u8; 7],
+    chunk_size: usize,
+    r: &mut R,
+    w: &mut W,
+) -> io::Result<()> {
+    check_chunk_size(chunk_size)?;
+    let sealed_size = chunk_size + TAG_LEN;
+    let mut buf: Vec<u8> = repeat(0).take(sealed_size + 1).collect();
+    let mut out: Vec<u8> = repeat(0).take(chunk_size).collect();
+    let mut nonces = chunk_nonces(nonce_prefix);
+    let mut start = 0;
+    loop {
+        let (len, more) = read_chunk(r, &mut buf, start, sealed_size)?;
+        let n = if more { sealed_size } else { len };
+        if n < TAG_LEN {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, AeadError::AuthenticationFailed));
+        }
+        let nonce = next_nonce(&mut nonces, !more)?;
+        let (ct, tag) = buf[..n].split_at(n - TAG_LEN);
+        let pt = &mut out[..n - TAG_LEN];
+        if !ChaCha20Poly1305::new(key, &nonce, &[]).decrypt(ct, pt, tag) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, AeadError::AuthenticationFailed));
+        }
+        w.write_all(pt)?;
+        if !more {
+            return Ok(());
+        }
+        buf[0] = buf[sealed_size];
+        start = 1;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{Cursor, ErrorKind};
+
+    use stream::{decrypt_reader_to_writer, encrypt_reader_to_writer};
+
+    const KEY: [u8; 32] = [0x11; 32];
+    const PREFIX: [u8; 7] = [1, 2, 3, 4, 5, 6, 7];
+
+    fn encrypt(plaintext: &[u8], chunk_size: usize) -> Vec<u8> {
+        let mut out = Vec::new();
+        encrypt_reader_to_writer(&KEY, &PREFIX, chunk_size, &mut Cursor::new(plaintext), &mut out)
+            .unwrap();
+        out
+    }
+
+    fn decrypt(ciphertext: &[u8], chunk_size: usize) -> Result<Vec<u8>, ErrorKind> {
+        let mut out = Vec::new();
+        decrypt_reader_to_writer(&KEY, &PREFIX, chunk_size, &mut Cursor::new(ciphertext), &mut out)
+            .map_err(|e| e.kind())?;
+        Ok(out)
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let plaintext: Vec<u8> = (0..1000).map(|i| i as u8).collect();
+        for &chunk_size in [1usize, 7, 64, 100, 999, 1000, 1001, 4096].iter() {
+            for &len in [0usize, 1, 63, 64, 65, 100, 1000].iter() {
+                let ct = encrypt(&plaintext[..len], chunk_size);
+                // A final chunk that is exactly full is not followed by an empty one.
+                let chunks = if len == 0 { 1 } else { (len + chunk_size - 1) / chunk_size };
+                assert_eq!(ct.len(), len + 16 * chunks);
+                assert_eq!(decrypt(&ct, chunk_size).unwrap(), &plaintext[..len]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rejects_modified_streams() {
+        let plaintext: Vec<u8> = (0..200).map(|i| i as u8).collect();
+        // Four chunks of 48 bytes and a final chunk of 8, each followed by a 16 byte tag.
+        let ct = encrypt(&plaintext, 48);
+        assert_eq!(ct.len(), 200 + 5 * 16);
+
+        // Truncated at a chunk boundary, inside a chunk, and to nothing at all.
+        assert_eq!(decrypt(&ct[..4 * 64], 48), Err(ErrorKind::InvalidData));
+        assert_eq!(decrypt(&ct[..100], 48), Err(ErrorKind::InvalidData));
+        assert_eq!(decrypt(&[], 48), Err(ErrorKind::InvalidData));
+
+        // The first two chunks swapped.
+        let swapped = [&ct[64..128], &ct[..64], &ct[128..]].concat();
+        assert_eq!(decrypt(&swapped, 48), Err(ErrorKind::InvalidData));
+
+        // Extra data after the final chunk.
+        let extended = [&ct[..], &ct[..64]].concat();
+        assert_eq!(decrypt(&extended, 48), Err(ErrorKind::InvalidData));
+
+        let mut flipped = ct.clone();
+        flipped[70] ^= 1;
+        assert_eq!(decrypt(&flipped, 48), Err(ErrorKind::InvalidData));
+
+        // A different chunk size.
+        assert_eq!(decrypt(&ct, 32), Err(ErrorKind::InvalidData));
+        assert_eq!(decrypt(&ct, 0), Err(ErrorKind::InvalidInput));
+    }
+}
@@ -27,14 +27,27 @@ pub trait BlockDecryptorX8 {
     fn decrypt_block_x8(&self, input: &[u8], output: &mut [u8]);
 }
 
+use sr_std::fmt;
 use sr_std::marker::*;
 use sr_std::prelude::*;
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SymmetricCipherError {
     InvalidLength,
     InvalidPadding,
 }
 
+impl fmt::Display for SymmetricCipherError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            SymmetricCipherError::InvalidLength => "invalid input length",
+            SymmetricCipherError::InvalidPadding => "invalid padding",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for SymmetricCipherError {}
+
 pub trait Encryptor {
     fn encrypt(
         &mut self,
@@ -55,6 +68,17 @@ pub trait Decryptor {
 
 pub trait SynchronousStreamCipher {
     fn process(&mut self, input: &[u8], output: &mut [u8]);
+
+    /// Fill `out` with raw keystream. The stream position advances exactly as if `out.len()`
+    /// bytes had been passed to `process`. The default encrypts zeros; ciphers that can copy
+    /// their keystream out directly override it.
+    fn keystream(&mut self, out: &mut [u8]) {
+        let zeros = [0u8; 64];
+        for chunk in out.chunks_mut(64) {
+            let n = chunk.len();
+            self.process(&zeros[..n], chunk);
+        }
+    }
 }
 
 // TODO - Its a bit unclear to me why this is necessary
@@ -63,6 +87,11 @@ impl SynchronousStreamCipher for Box<dyn SynchronousStreamCipher + 'static> {
         let me = &mut **self;
         me.process(input, output);
     }
+
+    fn keystream(&mut self, out: &mut [u8]) {
+        let me = &mut **self;
+        me.keystream(out);
+    }
 }
 
 impl Encryptor for Box<dyn SynchronousStreamCipher + 'static> {
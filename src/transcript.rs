@@ -0,0 +1,200 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+ * A Transcript absorbs labeled, length-prefixed messages into a Digest so that protocols can
+ * derive Fiat-Shamir challenges that are bound to everything sent so far.
+ *
+ * Every message is framed as `len(label) || label || len(msg) || msg`, with lengths encoded as
+ * 64 bit big-endian integers, so that no two different sequences of appends produce the same
+ * digest input.
+ */
+
+use sr_std::iter::repeat;
+use sr_std::prelude::*;
+
+use cryptoutil::{write_u32_be, write_u64_be};
+use digest::Digest;
+
+pub struct Transcript<D> {
+    digest: D,
+}
+
+impl<D: Digest + Clone> Transcript<D> {
+    /**
+     * Create a new Transcript. The label separates transcripts of different protocols.
+     */
+    pub fn new(mut digest: D, label: &[u8]) -> Transcript<D> {
+        digest.reset();
+        let mut transcript = Transcript { digest: digest };
+        transcript.append_message(b"transcript", label);
+        transcript
+    }
+
+    fn append_len(&mut self, len: usize) {
+        let mut buf = [0u8; 8];
+        write_u64_be(&mut buf, len as u64);
+        self.digest.input(&buf);
+    }
+
+    /**
+     * Absorb a labeled message.
+     */
+    pub fn append_message(&mut self, label: &[u8], msg: &[u8]) {
+        self.append_len(label.len());
+        self.digest.input(label);
+        self.append_len(msg.len());
+        self.digest.input(msg);
+    }
+
+    /**
+     * Fill out with challenge bytes derived from everything absorbed so far. The label and the
+     * number of bytes requested are absorbed first, and the challenge itself is absorbed
+     * afterwards so that later challenges depend on earlier ones.
+     *
+     * A fixed size digest is run in counter mode to produce more than one output block. An
+     * extendable output function such as SHAKE256, which reports an output size of zero, is
+     * squeezed for all of out directly.
+     */
+    pub fn challenge_bytes(&mut self, label: &[u8], out: &mut [u8]) {
+        self.append_len(label.len());
+        self.digest.input(label);
+        self.append_len(out.len());
+
+        let output_size = self.digest.output_bytes();
+        if output_size == 0 {
+            let mut d = self.digest.clone();
+            d.result(out);
+            self.digest.input(out);
+            return;
+        }
+
+        let mut block: Vec<u8> = repeat(0).take(output_size).collect();
+        let mut counter = [0u8; 4];
+        for (i, chunk) in out.chunks_mut(output_size).enumerate() {
+            let mut d = self.digest.clone();
+            write_u32_be(&mut counter, i as u32);
+            d.input(&counter);
+            d.result(&mut block[..]);
+            let len = chunk.len();
+            chunk.copy_from_slice(&block[..len]);
+        }
+
+        self.digest.input(out);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use digest::Digest;
+    use sha2::Sha256;
+    use sha3::Sha3;
+    use transcript::Transcript;
+
+    fn challenge(appends: &[(&[u8], &[u8])]) -> [u8; 32] {
+        let mut t = Transcript::new(Sha256::new(), b"test protocol");
+        for &(label, msg) in appends.iter() {
+            t.append_message(label, msg);
+        }
+        let mut out = [0u8; 32];
+        t.challenge_bytes(b"challenge", &mut out);
+        out
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let a = challenge(&[(b"a", b"one"), (b"b", b"two")]);
+        let b = challenge(&[(b"a", b"one"), (b"b", b"two")]);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_reordering_changes_challenge() {
+        let a = challenge(&[(b"a", b"one"), (b"b", b"two")]);
+        let b = challenge(&[(b"b", b"two"), (b"a", b"one")]);
+        assert!(a != b);
+    }
+
+    #[test]
+    fn test_framing_is_unambiguous() {
+        // Moving bytes between the label and the message must change the challenge.
+        let a = challenge(&[(b"ab", b"c")]);
+        let b = challenge(&[(b"a", b"bc")]);
+        assert!(a != b);
+
+        let a = challenge(&[(b"a", b"bc"), (b"d", b"")]);
+        let b = challenge(&[(b"a", b"b"), (b"cd", b"")]);
+        assert!(a != b);
+    }
+
+    #[test]
+    fn test_matches_manual_framing() {
+        let mut t = Transcript::new(Sha256::new(), b"proto");
+        t.append_message(b"msg", b"hello");
+        let mut out = [0u8; 32];
+        t.challenge_bytes(b"c", &mut out);
+
+        let mut d = Sha256::new();
+        d.input(&[0, 0, 0, 0, 0, 0, 0, 10]);
+        d.input(b"transcript");
+        d.input(&[0, 0, 0, 0, 0, 0, 0, 5]);
+        d.input(b"proto");
+        d.input(&[0, 0, 0, 0, 0, 0, 0, 3]);
+        d.input(b"msg");
+        d.input(&[0, 0, 0, 0, 0, 0, 0, 5]);
+        d.input(b"hello");
+        d.input(&[0, 0, 0, 0, 0, 0, 0, 1]);
+        d.input(b"c");
+        d.input(&[0, 0, 0, 0, 0, 0, 0, 32]);
+        d.input(&[0, 0, 0, 0]);
+        let mut expected = [0u8; 32];
+        d.result(&mut expected);
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_long_challenge_and_chaining() {
+        let mut t = Transcript::new(Sha256::new(), b"proto");
+        t.append_message(b"msg", b"hello");
+        let mut first = [0u8; 80];
+        t.challenge_bytes(b"c", &mut first);
+        assert!(&first[..32] != &first[32..64]);
+
+        let mut second = [0u8; 80];
+        t.challenge_bytes(b"c", &mut second);
+        assert!(&first[..] != &second[..]);
+    }
+
+    #[test]
+    fn test_xof_challenge() {
+        let mut t = Transcript::new(Sha3::shake_256(), b"proto");
+        t.append_message(b"msg", b"hello");
+        let mut out = [0u8; 200];
+        t.challenge_bytes(b"c", &mut out);
+
+        // The XOF is squeezed directly, with no block counter.
+        let mut d = Sha3::shake_256();
+        d.input(&[0, 0, 0, 0, 0, 0, 0, 10]);
+        d.input(b"transcript");
+        d.input(&[0, 0, 0, 0, 0, 0, 0, 5]);
+        d.input(b"proto");
+        d.input(&[0, 0, 0, 0, 0, 0, 0, 3]);
+        d.input(b"msg");
+        d.input(&[0, 0, 0, 0, 0, 0, 0, 5]);
+        d.input(b"hello");
+        d.input(&[0, 0, 0, 0, 0, 0, 0, 1]);
+        d.input(b"c");
+        d.input(&[0, 0, 0, 0, 0, 0, 0, 200]);
+        let mut expected = [0u8; 200];
+        d.result(&mut expected);
+        assert_eq!(&out[..], &expected[..]);
+        assert!(out.iter().any(|&x| x != 0));
+
+        let mut second = [0u8; 200];
+        t.challenge_bytes(b"c", &mut second);
+        assert!(&out[..] != &second[..]);
+    }
+}
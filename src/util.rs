@@ -4,7 +4,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use libc;
+use sr_std::{fmt, str};
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 extern "C" {
@@ -44,23 +44,167 @@ pub fn fixed_time_eq(lhs: &[u8], rhs: &[u8]) -> bool {
 
 #[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "arm")))]
 pub fn fixed_time_eq_internal(lhs: &[u8], rhs: &[u8]) -> bool {
-    lhs == rhs
+    fixed_time_eq_portable(lhs, rhs)
+}
+
+/// The comparison for targets without the assembly helper. Slice equality would stop at the
+/// first differing byte and so reveal how long a matching prefix is, as with a MAC tag checked
+/// against a forgery; instead every byte pair is XORed and the differences ORed together.
+#[cfg(any(test, not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "arm"))))]
+fn fixed_time_eq_portable(lhs: &[u8], rhs: &[u8]) -> bool {
+    let mut diff = 0u8;
+    for (a, b) in lhs.iter().zip(rhs.iter()) {
+        diff |= a ^ b;
+    }
+    diff == 0
 }
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "arm"))]
 pub fn fixed_time_eq_internal(lhs: &[u8], rhs: &[u8]) -> bool {
     let count = lhs.len() as usize;
 
-    unsafe {
-        let lhsp = lhs.get_unchecked(0);
-        let rhsp = rhs.get_unchecked(0);
-        rust_crypto_util_fixed_time_eq_asm(lhsp, rhsp, count) == 0
+    // as_ptr() rather than indexing, since the slices may be empty
+    unsafe { rust_crypto_util_fixed_time_eq_asm(lhs.as_ptr(), rhs.as_ptr(), count) == 0 }
+}
+
+/// Compare two arrays of the same fixed size, such as two digests, using a fixed number of
+/// operations. The lengths are checked at compile time, so unlike fixed_time_eq there is no
+/// length mismatch case, and nothing is allocated.
+pub fn ct_eq_array<const N: usize>(a: &[u8; N], b: &[u8; N]) -> bool {
+    // The assembly helper treats a zero length as unequal, but two empty arrays are equal.
+    N == 0 || fixed_time_eq_internal(&a[..], &b[..])
+}
+
+/// Up to N bytes of secret data, such as a key, kept inline and wiped with secure_memset when
+/// dropped. It is deliberately not Clone or Copy, and its Debug output does not reveal the bytes.
+pub struct SecretBytes<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> SecretBytes<N> {
+    /// Copy bytes, which must be at most N bytes long, into a new SecretBytes.
+    pub fn new(bytes: &[u8]) -> SecretBytes<N> {
+        assert!(bytes.len() <= N);
+        let mut secret = SecretBytes {
+            bytes: [0u8; N],
+            len: bytes.len(),
+        };
+        secret.bytes[..bytes.len()].copy_from_slice(bytes);
+        secret
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<const N: usize> Drop for SecretBytes<N> {
+    fn drop(&mut self) {
+        secure_memset(&mut self.bytes, 0);
+    }
+}
+
+impl<const N: usize> fmt::Debug for SecretBytes<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SecretBytes(<{} bytes redacted>)", self.len)
+    }
+}
+
+/// Returned by to_hex_into when the output buffer cannot hold the hex encoding. needed is the
+/// number of bytes the buffer must have.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BufferTooSmall {
+    pub needed: usize,
+}
+
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "output buffer too small, {} bytes needed", self.needed)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for BufferTooSmall {}
+
+/// Write the lowercase hex encoding of bytes into the start of out and return it as a str. out
+/// must be at least twice as long as bytes. Unlike hex::encode this does not allocate, so it can
+/// be used to print digests on targets without String.
+pub fn to_hex_into<'a>(bytes: &[u8], out: &'a mut [u8]) -> Result<&'a str, BufferTooSmall> {
+    const DIGITS: &'static [u8; 16] = b"0123456789abcdef";
+    let needed = 2 * bytes.len();
+    if out.len() < needed {
+        return Err(BufferTooSmall { needed: needed });
     }
+    for (b, pair) in bytes.iter().zip(out.chunks_mut(2)) {
+        pair[0] = DIGITS[(b >> 4) as usize];
+        pair[1] = DIGITS[(b & 0xf) as usize];
+    }
+    Ok(str::from_utf8(&out[..needed]).unwrap())
 }
 
 #[cfg(test)]
 mod test {
-    use util::fixed_time_eq;
+    use sr_std::prelude::*;
+
+    use sr_std::mem::ManuallyDrop;
+    use sr_std::ptr;
+
+    use cryptoutil::test::{check_display, debug};
+    use util::{
+        ct_eq_array, fixed_time_eq, fixed_time_eq_portable, to_hex_into, BufferTooSmall,
+        SecretBytes,
+    };
+
+    #[test]
+    fn test_secret_bytes() {
+        let mut secret = ManuallyDrop::new(SecretBytes::<32>::new(&[0x5a; 20]));
+        assert_eq!(secret.as_bytes(), &[0x5a; 20][..]);
+        assert_eq!(secret.len(), 20);
+        assert!(!secret.is_empty());
+        assert_eq!(debug(&*secret).as_str(), "SecretBytes(<20 bytes redacted>)");
+        // Run the destructor but keep the storage around so it can be inspected.
+        unsafe { ptr::drop_in_place(&mut *secret) };
+        assert_eq!(secret.bytes, [0; 32]);
+
+        assert!(SecretBytes::<16>::new(&[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_secret_bytes_too_long() {
+        SecretBytes::<16>::new(&[0; 17]);
+    }
+
+    #[test]
+    fn test_to_hex_into() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let mut out = [0u8; 512];
+        assert_eq!(to_hex_into(&bytes[..], &mut out).unwrap(), hex::encode(&bytes[..]));
+
+        let mut out = [0xffu8; 9];
+        assert_eq!(to_hex_into(&[0xde, 0xad, 0xbe, 0xef], &mut out), Ok("deadbeef"));
+        assert_eq!(out[8], 0xff);
+
+        assert_eq!(to_hex_into(&[], &mut []), Ok(""));
+    }
+
+    #[test]
+    fn test_to_hex_into_too_small() {
+        let mut out = [0u8; 7];
+        assert_eq!(
+            to_hex_into(&[0xde, 0xad, 0xbe, 0xef], &mut out),
+            Err(BufferTooSmall { needed: 8 })
+        );
+    }
 
     #[test]
     pub fn test_fixed_time_eq() {
@@ -72,13 +216,45 @@ mod test {
         let f = [2, 2, 2];
         let g = [0, 0, 0];
 
-        //assert!(fixed_time_eq(&a, &a));
-        //assert!(fixed_time_eq(&a, &b));
+        assert!(fixed_time_eq(&a, &a));
+        assert!(fixed_time_eq(&a, &b));
+
+        assert!(!fixed_time_eq(&a, &c));
+        assert!(!fixed_time_eq(&a, &d));
+        assert!(!fixed_time_eq(&a, &e));
+        assert!(!fixed_time_eq(&a, &f));
+        assert!(!fixed_time_eq(&a, &g));
+    }
+
+    #[test]
+    fn test_fixed_time_eq_portable() {
+        let a: [u8; 16] = [0x5a; 16];
+        assert!(fixed_time_eq_portable(&a, &a.clone()));
+        for &i in [0, 7, 15].iter() {
+            for &bit in [0x01, 0x80].iter() {
+                let mut b = a;
+                b[i] ^= bit;
+                assert!(!fixed_time_eq_portable(&a, &b));
+            }
+        }
+        assert!(fixed_time_eq_portable(&[], &[]));
+    }
 
-        //assert!(!fixed_time_eq(&a, &c));
-        //assert!(!fixed_time_eq(&a, &d));
-        //assert!(!fixed_time_eq(&a, &e));
-        //assert!(!fixed_time_eq(&a, &f));
-        //assert!(!fixed_time_eq(&a, &g));
+    #[test]
+    fn test_ct_eq_array() {
+        let a: [u8; 32] = [0x5a; 32];
+        assert!(ct_eq_array(&a, &a.clone()));
+        for &i in [0, 15, 31].iter() {
+            let mut b = a;
+            b[i] ^= 0x01;
+            assert!(!ct_eq_array(&a, &b));
+        }
+        assert!(!ct_eq_array(&[0u8; 32], &[0xffu8; 32]));
+        assert!(ct_eq_array(&[], &[]));
+    }
+
+    #[test]
+    fn test_buffer_too_small_display() {
+        check_display(&BufferTooSmall { needed: 8 }, "output buffer too small, 8 bytes needed");
     }
 }
@@ -96,6 +96,9 @@ impl Digest for Whirlpool {
             let pos = self.bit_length.len() - i - 1;
             self.bit_length[pos] = (x & 0xff) as u8;
         }
+        if carry {
+            panic!("Numeric overflow occured.");
+        }
 
         // process the data itself
         let hash = &mut self.hash;
@@ -212,6 +215,7 @@ fn process_buffer(hash: &mut [u64; 8], buffer: &[u8]) {
 #[cfg(test)]
 mod test {
     use super::*;
+    use cryptoutil::test::test_digest_large_single_input;
     use digest::Digest;
     use sr_std::ascii::AsciiExt;
 
@@ -263,6 +267,29 @@ mod test {
         //assert!(s.len() == 500000);
         //assert!(d.result_str().to_ascii_uppercase() == "0C99005BEB57EFF50A7CF005560DDF5D29057FD86B20BFD62DECA0F1CCEA4AF51FC15490EDDC47AF32BB2B66C34FF9AD8C6008AD677F77126953B226E4ED8B01");
     }
+
+    #[test]
+    fn large_single_input() {
+        test_digest_large_single_input(&mut Whirlpool::new());
+    }
+
+    #[test]
+    fn length_near_limit() {
+        let mut sh = Whirlpool::new();
+        sh.bit_length = [0xff; 32];
+        sh.bit_length[31] = 0xf7;
+        sh.input(b"a");
+        assert_eq!(sh.bit_length, [0xff; 32]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn length_overflow() {
+        let mut sh = Whirlpool::new();
+        sh.bit_length = [0xff; 32];
+        sh.bit_length[31] = 0xf7;
+        sh.input(b"ab");
+    }
 }
 
 static R: usize = 10;